@@ -1,5 +1,6 @@
 use node_runtime::{
-    days, hours, ExpectedBlockTime, ProjectTokenAccountBloatBond, ProjectTokenConfig,
+    constants::currency, days, dollars, hours, ExpectedBlockTime, ProjectTokenAccountBloatBond,
+    ProjectTokenConfig,
 };
 use sp_runtime::Permill;
 
@@ -11,6 +12,7 @@ pub fn production_config() -> ProjectTokenConfig {
         min_revenue_split_duration: days!(21),
         min_revenue_split_time_to_start: 0,
         sale_platform_fee: Permill::from_percent(2),
+        min_order_value: dollars!(1),
         ..Default::default()
     }
 }
@@ -7,10 +7,14 @@ use crate::Module as ArgoBridge;
 use balances::Pallet as Balances;
 use core::convert::{TryFrom, TryInto};
 use frame_benchmarking::v1::{account, benchmarks};
+use frame_support::traits::OnInitialize;
 use frame_system::Pallet as System;
 use frame_system::{EventRecord, RawOrigin};
 
-use crate::{BridgeConstraints, BridgeStatus, RemoteAccount, RemoteTransfer};
+use crate::{
+    BridgeConstraints, BridgeStatus, BridgeableTokenConstraints, RemoteAccount, RemoteTransfer,
+};
+use common::bridge::CreatorTokenBridge;
 
 const SEED: u32 = 0;
 
@@ -84,6 +88,90 @@ where
     .unwrap();
 }
 
+// Queues an inbound transfer of `amount` from the last remote chain, releasable at the next block
+fn queue_delayed_inbound_transfer<T: Config>(
+    operator_account: T::AccountId,
+    amount: BalanceOf<T>,
+) -> RemoteTransfer
+where
+    T::AccountId: CreateAccountId,
+{
+    let parameters = BridgeConstraints {
+        operator_account: None,
+        pauser_accounts: None,
+        bridging_fee: None,
+        thawn_duration: None,
+        remote_chains: None,
+        relayer_accounts: None,
+        relayer_threshold: None,
+        mint_limits: Some(MintLimits {
+            window: 100u32.into(),
+            max_minted_per_window: Some(amount),
+            max_minted_per_chain_per_window: Some(amount),
+            max_transfer_amount: Some(amount),
+            delayed_transfer_threshold: Some(Zero::zero()),
+            transfer_delay: 1u32.into(),
+        }),
+    };
+    ArgoBridge::<T>::update_bridge_constrains(RawOrigin::Root.into(), parameters).unwrap();
+    set_bridge_mint_allowance::<T>(amount, ArgoBridge::<T>::bridging_fee());
+
+    let remote_transfer = RemoteTransfer {
+        id: 0,
+        chain_id: MAX_REMOTE_CHAINS - 1,
+    };
+    ArgoBridge::<T>::finalize_inbound_transfer(
+        RawOrigin::Signed(operator_account).into(),
+        remote_transfer.clone(),
+        T::AccountId::create_account_id(2u32),
+        amount,
+    )
+    .unwrap();
+    remote_transfer
+}
+
+fn setup_active_bridge<T: Config>() -> T::AccountId
+where
+    T::AccountId: CreateAccountId,
+{
+    let remote_chains: Vec<u32> = (0..MAX_REMOTE_CHAINS).collect();
+    let pauser_acount = T::AccountId::create_account_id(1u32);
+    let operator_account = T::AccountId::create_account_id(1u32);
+    let parameters = BridgeConstraints {
+        operator_account: Some(operator_account.clone()),
+        pauser_accounts: Some(vec![pauser_acount.clone()]),
+        bridging_fee: None,
+        thawn_duration: Some(1u32.into()),
+        remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap()),
+        relayer_accounts: None,
+        relayer_threshold: None,
+        mint_limits: None,
+    };
+    ArgoBridge::<T>::update_bridge_constrains(RawOrigin::Root.into(), parameters).unwrap();
+    activate_bridge::<T>(&pauser_acount, &operator_account);
+    operator_account
+}
+
+// Creates a creator token approved for bridging, with `amount` tokens escrowed by the bridge
+fn setup_bridgeable_token<T: Config>(
+    fee: BalanceOf<T>,
+    amount: TokenBalanceOf<T>,
+) -> (TokenIdOf<T>, TokenMemberIdOf<T>)
+where
+    T::AccountId: CreateAccountId,
+{
+    let sender = T::AccountId::create_account_id(1u32);
+    let _ =
+        Balances::<T>::deposit_creating(&sender, T::ExistentialDeposit::get() + fee + 10u32.into());
+    let (token_id, member_id) = T::CreatorTokens::create_bridgeable_token(&sender, amount).unwrap();
+    let parameters = BridgeableTokenConstraints {
+        bridging_fee: Some(fee),
+        paused: None,
+    };
+    ArgoBridge::<T>::update_bridgeable_token(RawOrigin::Root.into(), token_id, parameters).unwrap();
+    (token_id, member_id)
+}
+
 benchmarks! {
     where_clause {
         where
@@ -103,7 +191,10 @@ benchmarks! {
             pauser_accounts: Some(vec![pauser_acount.clone()]),
             bridging_fee: Some(fee),
             thawn_duration: Some(1u32.into()),
-            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap())
+            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap()),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None
         };
 
         ArgoBridge::<T>::update_bridge_constrains(
@@ -142,7 +233,10 @@ benchmarks! {
             pauser_accounts: Some(vec![pauser_acount.clone()]),
             bridging_fee: Some(fee),
             thawn_duration: Some(1u32.into()),
-            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap())
+            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap()),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None
         };
 
         ArgoBridge::<T>::update_bridge_constrains(
@@ -154,7 +248,7 @@ benchmarks! {
         let revert_amount: u32 = 1030u32;
         set_bridge_mint_allowance::<T>(revert_amount.into(), fee);
 
-        let transfer_id = 1u64;
+        let transfer_id = 0u64;
         let rationale = vec![0u8; (MAX_BYTES_RATIONALE) as usize];
         let revert_account = T::AccountId::create_account_id(2u32);
     }: _(RawOrigin::Signed(operator_account), transfer_id, revert_account.clone(), revert_amount.into(), rationale.clone().try_into().unwrap())
@@ -177,7 +271,10 @@ benchmarks! {
             pauser_accounts: Some(vec![pauser_acount.clone()]),
             bridging_fee: Some(fee),
             thawn_duration: Some(1u32.into()),
-            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap())
+            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap()),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None
         };
         ArgoBridge::<T>::update_bridge_constrains(
             RawOrigin::Root.into(),
@@ -210,7 +307,10 @@ benchmarks! {
             pauser_accounts: Some(pauser_accounts.clone()),
             bridging_fee: None,
             thawn_duration: Some(1u32.into()),
-            remote_chains: None
+            remote_chains: None,
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None
         };
         ArgoBridge::<T>::update_bridge_constrains(
             RawOrigin::Root.into(),
@@ -239,7 +339,10 @@ benchmarks! {
             pauser_accounts: Some(pauser_accounts.clone()),
             bridging_fee: None,
             thawn_duration: Some(1u32.into()),
-            remote_chains: None
+            remote_chains: None,
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None
         };
         ArgoBridge::<T>::update_bridge_constrains(
             RawOrigin::Root.into(),
@@ -269,7 +372,10 @@ benchmarks! {
             pauser_accounts: Some(pauser_accounts.clone()),
             bridging_fee: None,
             thawn_duration: Some(1u32.into()),
-            remote_chains: None
+            remote_chains: None,
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None
         };
         ArgoBridge::<T>::update_bridge_constrains(
             RawOrigin::Root.into(),
@@ -298,13 +404,26 @@ benchmarks! {
         .collect();
         let operator_account = T::AccountId::create_account_id(1u32);
         let remote_chains: Vec<u32> = (0..MAX_REMOTE_CHAINS).collect();
+        let relayer_accounts: Vec<T::AccountId> = (0..MAX_RELAYER_ACCOUNTS)
+        .map(T::AccountId::create_account_id)
+        .collect();
 
         let parameters = BridgeConstraints {
             operator_account: Some(operator_account),
             pauser_accounts: Some(pauser_accounts),
             bridging_fee: Some(fee),
             thawn_duration: Some(1u32.into()),
-            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap())
+            remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap()),
+            relayer_accounts: Some(relayer_accounts),
+            relayer_threshold: Some(MAX_RELAYER_ACCOUNTS),
+            mint_limits: Some(MintLimits {
+                window: 100u32.into(),
+                max_minted_per_window: Some(fee),
+                max_minted_per_chain_per_window: Some(fee),
+                max_transfer_amount: Some(fee),
+                delayed_transfer_threshold: Some(fee),
+                transfer_delay: 100u32.into(),
+            })
         };
 
     }: _(RawOrigin::Root, parameters.clone())
@@ -312,6 +431,229 @@ benchmarks! {
         assert_last_event::<T>(
             RawEvent::BridgeConfigUpdated(parameters).into());
     }
+
+    // Worst case scenario:
+    // - max number of remote chains being use
+    // - using the last chain
+    request_creator_token_outbound_transfer{
+        let fee: BalanceOf<T> = 10u32.into();
+        setup_active_bridge::<T>();
+        let amount: TokenBalanceOf<T> = 100u32.into();
+        let (token_id, member_id) = setup_bridgeable_token::<T>(fee, amount);
+
+        let sender = T::AccountId::create_account_id(1u32);
+        let dest_account = RemoteAccount {
+            account: [0; 32],
+            chain_id: MAX_REMOTE_CHAINS - 1,
+        };
+        let transfer_id = ArgoBridge::<T>::next_transfer_id();
+    }: _(RawOrigin::Signed(sender), token_id, member_id, dest_account, amount, fee)
+    verify {
+        assert_last_event::<T>(
+            RawEvent::CreatorTokenOutboundTransferRequested(transfer_id, token_id, member_id, dest_account, amount, fee).into());
+    }
+
+    // Worst case scenario:
+    // - max number of remote chains being use
+    // - using the last chain
+    finalize_creator_token_inbound_transfer{
+        let fee: BalanceOf<T> = 10u32.into();
+        let operator_account = setup_active_bridge::<T>();
+        let amount: TokenBalanceOf<T> = 100u32.into();
+        let (token_id, member_id) = setup_bridgeable_token::<T>(fee, amount);
+        let dest_account = RemoteAccount {
+            account: [0; 32],
+            chain_id: MAX_REMOTE_CHAINS - 1,
+        };
+        ArgoBridge::<T>::request_creator_token_outbound_transfer(
+            RawOrigin::Signed(T::AccountId::create_account_id(1u32)).into(),
+            token_id,
+            member_id,
+            dest_account,
+            amount,
+            fee
+        ).unwrap();
+
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: MAX_REMOTE_CHAINS - 1 };
+    }: _(RawOrigin::Signed(operator_account), remote_transfer.clone(), token_id, member_id, amount)
+    verify {
+        assert_last_event::<T>(
+            RawEvent::CreatorTokenInboundTransferFinalized(remote_transfer, token_id, member_id, amount).into());
+    }
+
+    // Worse case scenario
+    // - rationale of the maximum size
+    revert_creator_token_outbound_transfer{
+        let fee: BalanceOf<T> = 10u32.into();
+        let operator_account = setup_active_bridge::<T>();
+        let amount: TokenBalanceOf<T> = 100u32.into();
+        let (token_id, member_id) = setup_bridgeable_token::<T>(fee, amount);
+        let dest_account = RemoteAccount {
+            account: [0; 32],
+            chain_id: MAX_REMOTE_CHAINS - 1,
+        };
+        ArgoBridge::<T>::request_creator_token_outbound_transfer(
+            RawOrigin::Signed(T::AccountId::create_account_id(1u32)).into(),
+            token_id,
+            member_id,
+            dest_account,
+            amount,
+            fee
+        ).unwrap();
+
+        let transfer_id = 0u64;
+        let rationale = vec![0u8; (MAX_BYTES_RATIONALE) as usize];
+    }: _(RawOrigin::Signed(operator_account), transfer_id, token_id, member_id, amount, rationale.clone().try_into().unwrap())
+    verify {
+        assert_last_event::<T>(
+            RawEvent::CreatorTokenOutboundTransferReverted(transfer_id, token_id, member_id, amount, rationale.try_into().unwrap()).into());
+    }
+
+    // Worst case scenario:
+    // - token not yet approved
+    // - update all parameters
+    update_bridgeable_token{
+        let sender = T::AccountId::create_account_id(1u32);
+        let (token_id, _) =
+            T::CreatorTokens::create_bridgeable_token(&sender, 100u32.into()).unwrap();
+        let parameters = BridgeableTokenConstraints {
+            bridging_fee: Some(10u32.into()),
+            paused: Some(false),
+        };
+    }: _(RawOrigin::Root, token_id, parameters.clone())
+    verify {
+        assert_last_event::<T>(
+            RawEvent::BridgeableTokenUpdated(token_id, parameters).into());
+    }
+
+    // Worst case scenario:
+    // - max number of relayer accounts, the last one reaching the threshold
+    // - creator token transfer being finalized
+    attest_inbound_transfer{
+        let fee: BalanceOf<T> = 10u32.into();
+        setup_active_bridge::<T>();
+        let amount: TokenBalanceOf<T> = 100u32.into();
+        let (token_id, member_id) = setup_bridgeable_token::<T>(fee, amount);
+        let dest_account = RemoteAccount {
+            account: [0; 32],
+            chain_id: MAX_REMOTE_CHAINS - 1,
+        };
+        ArgoBridge::<T>::request_creator_token_outbound_transfer(
+            RawOrigin::Signed(T::AccountId::create_account_id(1u32)).into(),
+            token_id,
+            member_id,
+            dest_account,
+            amount,
+            fee
+        ).unwrap();
+
+        let relayer_accounts: Vec<T::AccountId> = (0..MAX_RELAYER_ACCOUNTS)
+        .map(T::AccountId::create_account_id)
+        .collect();
+        let parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: None,
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            relayer_accounts: Some(relayer_accounts.clone()),
+            relayer_threshold: Some(MAX_RELAYER_ACCOUNTS),
+            mint_limits: None
+        };
+        ArgoBridge::<T>::update_bridge_constrains(RawOrigin::Root.into(), parameters).unwrap();
+
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: MAX_REMOTE_CHAINS - 1 };
+        let transfer = InboundTransfer::CreatorToken { token_id, dest_member_id: member_id, amount };
+        let (last_relayer, relayers) = relayer_accounts.split_last().unwrap();
+        for relayer in relayers {
+            ArgoBridge::<T>::attest_inbound_transfer(
+                RawOrigin::Signed(relayer.clone()).into(),
+                remote_transfer.clone(),
+                transfer.clone()
+            ).unwrap();
+        }
+    }: _(RawOrigin::Signed(last_relayer.clone()), remote_transfer.clone(), transfer)
+    verify {
+        assert_last_event::<T>(
+            RawEvent::CreatorTokenInboundTransferFinalized(remote_transfer, token_id, member_id, amount).into());
+    }
+
+    // Worst case scenario:
+    // - destination account not existing yet
+    release_delayed_inbound_transfer{
+        let operator_account = setup_active_bridge::<T>();
+        let amount: BalanceOf<T> = 1000u32.into();
+        let remote_transfer = queue_delayed_inbound_transfer::<T>(operator_account, amount);
+        System::<T>::set_block_number(System::<T>::block_number() + 1u32.into());
+
+        let dest_account = T::AccountId::create_account_id(2u32);
+    }: _(RawOrigin::Signed(dest_account.clone()), remote_transfer.clone())
+    verify {
+        assert_last_event::<T>(
+            RawEvent::DelayedInboundTransferReleased(remote_transfer, dest_account, amount).into());
+    }
+
+    // Worst case scenario:
+    // - max number of pauser accounts being use
+    // - using the last pauser account
+    cancel_delayed_inbound_transfer{
+        let operator_account = setup_active_bridge::<T>();
+        let pauser_accounts: Vec<T::AccountId> = (0..T::MaxPauserAccounts::get())
+        .map(T::AccountId::create_account_id)
+        .collect();
+        let parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: Some(pauser_accounts),
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None
+        };
+        ArgoBridge::<T>::update_bridge_constrains(RawOrigin::Root.into(), parameters).unwrap();
+        let remote_transfer = queue_delayed_inbound_transfer::<T>(operator_account, 1000u32.into());
+        let pauser_account = T::AccountId::create_account_id(T::MaxPauserAccounts::get() - 1);
+    }: _(RawOrigin::Signed(pauser_account.clone()), remote_transfer.clone())
+    verify {
+        assert_last_event::<T>(
+            RawEvent::DelayedInboundTransferCancelled(remote_transfer, pauser_account).into());
+    }
+
+    // Worst case scenario:
+    // - `a` expired outbound transfers pruned
+    // - `c` remote chains being checked for processed inbound transfers
+    on_initialize{
+        let a in 0 .. T::MaxPrunedTransfersPerBlock::get();
+        let c in 0 .. MAX_REMOTE_CHAINS;
+
+        setup_active_bridge::<T>();
+        let fee = ArgoBridge::<T>::bridging_fee();
+        let sender = T::AccountId::create_account_id(1u32);
+        let transfer_amount: BalanceOf<T> = 100u32.into();
+        let _ = Balances::<T>::deposit_creating(
+            &sender,
+            T::ExistentialDeposit::get() + (transfer_amount + fee) * a.into()
+        );
+        let dest_account = RemoteAccount {
+            account: [0; 32],
+            chain_id: MAX_REMOTE_CHAINS - 1,
+        };
+        for _ in 0..a {
+            ArgoBridge::<T>::request_outbound_transfer(
+                RawOrigin::Signed(sender.clone()).into(),
+                dest_account,
+                transfer_amount,
+                fee
+            ).unwrap();
+        }
+        let remote_chains: Vec<u32> = (0..c).collect();
+        RemoteChains::put(BoundedVec::try_from(remote_chains).unwrap());
+        let now = System::<T>::block_number() + T::OutboundTransferRetentionPeriod::get();
+    }: { ArgoBridge::<T>::on_initialize(now); }
+    verify {
+        assert_eq!(ArgoBridge::<T>::next_pruned_outbound_transfer_id(), a as u64);
+    }
 }
 
 #[cfg(test)]
@@ -369,4 +711,60 @@ mod tests {
             assert_ok!(ArgoBridge::test_benchmark_update_bridge_constrains());
         });
     }
+
+    #[test]
+    fn test_request_creator_token_outbound_transfer() {
+        with_test_externalities(|| {
+            assert_ok!(ArgoBridge::test_benchmark_request_creator_token_outbound_transfer());
+        });
+    }
+
+    #[test]
+    fn test_finalize_creator_token_inbound_transfer() {
+        with_test_externalities(|| {
+            assert_ok!(ArgoBridge::test_benchmark_finalize_creator_token_inbound_transfer());
+        });
+    }
+
+    #[test]
+    fn test_revert_creator_token_outbound_transfer() {
+        with_test_externalities(|| {
+            assert_ok!(ArgoBridge::test_benchmark_revert_creator_token_outbound_transfer());
+        });
+    }
+
+    #[test]
+    fn test_update_bridgeable_token() {
+        with_test_externalities(|| {
+            assert_ok!(ArgoBridge::test_benchmark_update_bridgeable_token());
+        });
+    }
+
+    #[test]
+    fn test_attest_inbound_transfer() {
+        with_test_externalities(|| {
+            assert_ok!(ArgoBridge::test_benchmark_attest_inbound_transfer());
+        });
+    }
+
+    #[test]
+    fn test_release_delayed_inbound_transfer() {
+        with_test_externalities(|| {
+            assert_ok!(ArgoBridge::test_benchmark_release_delayed_inbound_transfer());
+        });
+    }
+
+    #[test]
+    fn test_cancel_delayed_inbound_transfer() {
+        with_test_externalities(|| {
+            assert_ok!(ArgoBridge::test_benchmark_cancel_delayed_inbound_transfer());
+        });
+    }
+
+    #[test]
+    fn test_on_initialize() {
+        with_test_externalities(|| {
+            assert_ok!(ArgoBridge::test_benchmark_on_initialize());
+        });
+    }
 }
@@ -38,6 +38,64 @@ decl_error! {
         ThawnNotFinished,
 
         /// ChainId is not on the list of the supported chains
-        NotSupportedRemoteChainId
+        NotSupportedRemoteChainId,
+
+        /// Creator token was not approved for bridging
+        NotBridgeableToken,
+
+        /// Creator token issuer has not enabled bridging
+        TokenBridgingNotEnabled,
+
+        /// Transfers of the creator token are paused
+        TokenBridgePaused,
+
+        /// Not enough creator token mint allowance for transaction
+        InsufficientTokenMintAllowance,
+
+        /// Inbound remote transfer was already finalized
+        InboundTransferAlreadyProcessed,
+
+        /// Inbound remote transfer id is below the chain watermark
+        /// (more than `InboundTransfersWindow` ids behind the highest finalized one)
+        InboundTransferBelowWatermark,
+
+        /// Outbound transfer does not exist, was already reverted or pruned
+        OutboundTransferNotFound,
+
+        /// Outbound transfer was not made with the reverted asset
+        OutboundTransferAssetMismatch,
+
+        /// Revert amount exceeds the outbound transfer amount
+        RevertAmountExceedsTransferAmount,
+
+        /// Number of relayer accounts over the maximum allowed
+        InvalidNumberOfRelayerAccounts,
+
+        /// Relayer account is listed more than once
+        DuplicateRelayerAccount,
+
+        /// Relayer threshold is greater than the number of relayer accounts
+        InvalidRelayerThreshold,
+
+        /// Origin is not a relayer account
+        NotRelayerAccount,
+
+        /// Relayer threshold is not set, inbound transfers are finalized by the operator
+        RelayerThresholdNotSet,
+
+        /// Inbound transfers must be attested by the relayers
+        RelayerAttestationRequired,
+
+        /// Relayer already attested the inbound transfer
+        InboundTransferAlreadyAttested,
+
+        /// Windowed mint limits require a non-zero window
+        InvalidMintLimitsWindow,
+
+        /// Delayed inbound transfer does not exist, was already released or cancelled
+        DelayedInboundTransferNotFound,
+
+        /// Delayed inbound transfer cannot be released yet
+        InboundTransferDelayNotElapsed
     }
 }
@@ -19,6 +19,11 @@ decl_event!(
         Balance = BalanceOf<T>,
         BridgeConstraints = BridgeConstraintsOf<T>,
         BlockNumber = <T as frame_system::Config>::BlockNumber,
+        TokenId = TokenIdOf<T>,
+        MemberId = TokenMemberIdOf<T>,
+        TokenBalance = TokenBalanceOf<T>,
+        BridgeableTokenConstraints = BridgeableTokenConstraintsOf<T>,
+        InboundTransfer = InboundTransferOf<T>,
     {
         OutboundTransferRequested(TransferId, AccountId, RemoteAccount, Balance, Balance),
         InboundTransferFinalized(RemoteTransfer, AccountId, Balance),
@@ -32,5 +37,28 @@ decl_event!(
         BridgeThawnStarted(AccountId, BlockNumber),
         BridgeThawnFinished(),
         BridgeConfigUpdated(BridgeConstraints),
+        CreatorTokenOutboundTransferRequested(
+            TransferId,
+            TokenId,
+            MemberId,
+            RemoteAccount,
+            TokenBalance,
+            Balance,
+        ),
+        CreatorTokenInboundTransferFinalized(RemoteTransfer, TokenId, MemberId, TokenBalance),
+        CreatorTokenOutboundTransferReverted(
+            TransferId,
+            TokenId,
+            MemberId,
+            TokenBalance,
+            BoundedVec<u8, ConstU32<MAX_BYTES_RATIONALE>>,
+        ),
+        BridgeableTokenUpdated(TokenId, BridgeableTokenConstraints),
+        InboundTransferAttested(RemoteTransfer, AccountId, InboundTransfer),
+        InboundTransferAttestationConflict(RemoteTransfer, AccountId, InboundTransfer),
+        InboundTransferQueued(RemoteTransfer, AccountId, Balance, BlockNumber),
+        InboundTransferBlocked(RemoteTransfer, AccountId, Balance),
+        DelayedInboundTransferReleased(RemoteTransfer, AccountId, Balance),
+        DelayedInboundTransferCancelled(RemoteTransfer, AccountId),
     }
 );
@@ -18,11 +18,16 @@ use frame_support::{
     ensure,
     storage::bounded_vec::BoundedVec,
     traits::{ConstU32, Currency, Get},
+    weights::Weight,
 };
 use frame_system::{ensure_root, ensure_signed};
 use sp_runtime::DispatchError;
-use sp_runtime::{traits::CheckedAdd, SaturatedConversion};
+use sp_runtime::{
+    traits::{CheckedAdd, CheckedSub, Saturating, Zero},
+    Perquintill, SaturatedConversion,
+};
 
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec;
 
 // crate modules
@@ -35,6 +40,7 @@ pub mod weights;
 pub use weights::WeightInfo;
 
 // crate imports
+use common::bridge::CreatorTokenBridge;
 use common::costs::{burn_from_usable, has_sufficient_balance_for_payment};
 pub use errors::Error;
 pub use events::{Event, RawEvent};
@@ -53,6 +59,19 @@ pub trait Config: frame_system::Config + balances::Config {
 
     /// Defines the default bridging fee.
     type DefaultBridgingFee: Get<BalanceOf<Self>>;
+
+    /// Creator tokens escrowed / released by the bridge
+    type CreatorTokens: CreatorTokenBridge<Self::AccountId>;
+
+    /// Number of blocks during which an outbound transfer can be reverted
+    type OutboundTransferRetentionPeriod: Get<Self::BlockNumber>;
+
+    /// Max number of transfer ledger entries pruned in a single block
+    type MaxPrunedTransfersPerBlock: Get<u32>;
+
+    /// Number of remote transfer ids, counted back from the highest finalized one,
+    /// within which inbound transfers of a chain can still be finalized
+    type InboundTransfersWindow: Get<TransferId>;
 }
 
 decl_storage! { generate_storage_info
@@ -77,6 +96,57 @@ decl_storage! { generate_storage_info
         pub NextTransferId get(fn next_transfer_id): TransferId;
 
         pub RemoteChains get(fn remote_chains): BoundedVec<ChainId, ConstU32<MAX_REMOTE_CHAINS>>;
+
+        /// Creator tokens approved for bridging
+        pub BridgeableTokens get(fn bridgeable_token):
+            map hasher(blake2_128_concat) TokenIdOf<T> => Option<BridgeableTokenOf<T>>;
+
+        /// Finalized inbound remote transfers not yet pruned
+        pub ProcessedInboundTransfers get(fn processed_inbound_transfer):
+            double_map hasher(blake2_128_concat) ChainId, hasher(blake2_128_concat) TransferId => bool;
+
+        /// Remote transfer id below which inbound transfers of the chain are rejected,
+        /// trails the highest finalized transfer id by `InboundTransfersWindow`
+        pub InboundTransfersWatermark get(fn inbound_transfers_watermark):
+            map hasher(blake2_128_concat) ChainId => TransferId;
+
+        /// Id of the oldest processed inbound transfer of the chain not yet pruned
+        pub NextPrunedInboundTransferId get(fn next_pruned_inbound_transfer_id):
+            map hasher(blake2_128_concat) ChainId => TransferId;
+
+        /// Outbound transfers that can still be reverted
+        pub PendingOutboundTransfers get(fn pending_outbound_transfer):
+            map hasher(blake2_128_concat) TransferId => Option<PendingOutboundTransferOf<T>>;
+
+        /// Id of the oldest outbound transfer not yet pruned
+        pub NextPrunedOutboundTransferId get(fn next_pruned_outbound_transfer_id): TransferId;
+
+        /// List of account IDs attesting inbound transfers
+        pub RelayerAccounts get(fn relayer_accounts): BoundedVec<T::AccountId, ConstU32<MAX_RELAYER_ACCOUNTS>>;
+
+        /// Number of relayer attestations needed to finalize an inbound transfer,
+        /// inbound transfers are finalized by the operator when set to 0
+        pub RelayerThreshold get(fn relayer_threshold): u32;
+
+        /// Relayer attestations of inbound transfers not yet finalized
+        pub InboundTransferAttestations get(fn inbound_transfer_attestation):
+            double_map hasher(blake2_128_concat) ChainId, hasher(blake2_128_concat) TransferId
+            => Option<InboundTransferAttestationOf<T>>;
+
+        /// Limits on the JOY minted by inbound transfers
+        pub MintLimits get(fn mint_limits): MintLimitsOf<T>;
+
+        /// JOY minted by inbound transfers within the rolling window
+        pub MintedInWindow get(fn minted_in_window): MintedAmountOf<T>;
+
+        /// JOY minted by inbound transfers of each remote chain within the rolling window
+        pub ChainMintedInWindow get(fn chain_minted_in_window):
+            map hasher(blake2_128_concat) ChainId => MintedAmountOf<T>;
+
+        /// Inbound transfers above the delayed transfer threshold waiting to be released
+        pub DelayedInboundTransfers get(fn delayed_inbound_transfer):
+            double_map hasher(blake2_128_concat) ChainId, hasher(blake2_128_concat) TransferId
+            => Option<DelayedInboundTransferOf<T>>;
     }
 }
 
@@ -89,6 +159,11 @@ decl_module! {
         /// Default deposit_event() handler
         fn deposit_event() = default;
 
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            let (pruned, chains) = Self::prune_transfer_ledger(now);
+            WeightInfoArgo::<T>::on_initialize(pruned, chains)
+        }
+
         #[weight = WeightInfoArgo::<T>::request_outbound_transfer()]
         pub fn request_outbound_transfer(origin, dest_account: RemoteAccount, amount: BalanceOf<T>, expected_fee: BalanceOf<T>) -> DispatchResult {
             Self::ensure_bridge_active()?;
@@ -110,6 +185,7 @@ decl_module! {
 
             burn_from_usable::<T>(&sender, amount_with_fees)?;
             <MintAllowance<T>>::put(Self::mint_allowance() + amount);
+            Self::add_pending_outbound_transfer(transfer_id, OutboundTransferAmount::Joy(amount));
 
             Self::deposit_event(RawEvent::OutboundTransferRequested(transfer_id, sender, dest_account, amount, fee));
             NextTransferId::put(next_transfer_id);
@@ -123,19 +199,13 @@ decl_module! {
 
             Self::ensure_operator_origin(origin)?;
 
-            Self::ensure_chain_supported(remote_transfer.chain_id)?;
-
-            Self::ensure_mint_allowance(amount)?;
+            Self::ensure_operator_finalization_allowed()?;
 
-            //
-            // == MUTATION SAFE ==
-            //
-
-            Self::mint_tokens(&dest_account, amount);
+            Self::ensure_chain_supported(remote_transfer.chain_id)?;
 
-            Self::deposit_event(RawEvent::InboundTransferFinalized(remote_transfer, dest_account, amount));
+            Self::ensure_inbound_transfer_not_processed(&remote_transfer)?;
 
-            Ok(())
+            Self::do_finalize_inbound_transfer(&remote_transfer, &InboundTransfer::Joy { dest_account, amount })
         }
 
         #[weight = WeightInfoArgo::<T>::revert_outbound_transfer()]
@@ -150,12 +220,19 @@ decl_module! {
 
             Self::ensure_operator_origin(origin)?;
 
+            let transfer = Self::ensure_pending_outbound_transfer(transfer_id)?;
+            match transfer.amount {
+                OutboundTransferAmount::Joy(amount) => ensure!(revert_amount <= amount, Error::<T>::RevertAmountExceedsTransferAmount),
+                OutboundTransferAmount::CreatorToken(..) => return Err(Error::<T>::OutboundTransferAssetMismatch.into()),
+            }
+
             Self::ensure_mint_allowance(revert_amount)?;
 
             //
             // == MUTATION SAFE ==
             //
 
+            <PendingOutboundTransfers<T>>::remove(transfer_id);
             Self::mint_tokens(&revert_account, revert_amount);
 
             Self::deposit_event(RawEvent::OutboundTransferReverted(transfer_id, revert_account, revert_amount, rationale));
@@ -239,6 +316,25 @@ decl_module! {
         pub fn update_bridge_constrains(origin, parameters: BridgeConstraintsOf<T>) -> DispatchResult {
             ensure_root(origin)?;
 
+            if let Some(ref new_relayer_accounts) = parameters.relayer_accounts {
+                ensure!(new_relayer_accounts.len().saturated_into::<u32>() <= MAX_RELAYER_ACCOUNTS, Error::<T>::InvalidNumberOfRelayerAccounts);
+                let unique_relayer_accounts: BTreeSet<_> = new_relayer_accounts.iter().collect();
+                ensure!(unique_relayer_accounts.len() == new_relayer_accounts.len(), Error::<T>::DuplicateRelayerAccount);
+            }
+            let relayers_number = parameters
+                .relayer_accounts
+                .as_ref()
+                .map_or_else(|| Self::relayer_accounts().len(), |relayers| relayers.len())
+                .saturated_into::<u32>();
+            let relayer_threshold = parameters.relayer_threshold.unwrap_or_else(Self::relayer_threshold);
+            ensure!(relayer_threshold <= relayers_number, Error::<T>::InvalidRelayerThreshold);
+
+            if let Some(ref new_mint_limits) = parameters.mint_limits {
+                let windowed_limits = new_mint_limits.max_minted_per_window.is_some()
+                    || new_mint_limits.max_minted_per_chain_per_window.is_some();
+                ensure!(!windowed_limits || !new_mint_limits.window.is_zero(), Error::<T>::InvalidMintLimitsWindow);
+            }
+
             if let Some(ref new_operator_account) = parameters.operator_account {
                 <OperatorAccount<T>>::put(new_operator_account);
             }
@@ -261,11 +357,300 @@ decl_module! {
                 RemoteChains::put(new_remote_chains);
              }
 
+            if let Some(ref new_relayer_accounts) = parameters.relayer_accounts {
+                <RelayerAccounts<T>>::put(BoundedVec::truncate_from(new_relayer_accounts.to_vec()));
+            }
+
+            if let Some(new_relayer_threshold) = parameters.relayer_threshold {
+                RelayerThreshold::put(new_relayer_threshold);
+            }
+
+            if let Some(ref new_mint_limits) = parameters.mint_limits {
+                <MintLimits<T>>::put(new_mint_limits);
+            }
+
              Self::deposit_event(RawEvent::BridgeConfigUpdated(parameters));
 
             Ok(())
         }
 
+        /// Attest an inbound transfer as a relayer, the transfer is finalized once attested
+        /// by `RelayerThreshold` relayers.
+        /// Conflicting attestations for the same remote transfer pause the bridge and discard
+        /// the previous attestations.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// # </weight>
+        #[weight = WeightInfoArgo::<T>::attest_inbound_transfer()]
+        pub fn attest_inbound_transfer(
+            origin,
+            remote_transfer: RemoteTransfer,
+            transfer: InboundTransferOf<T>,
+        ) -> DispatchResult {
+            Self::ensure_bridge_active()?;
+
+            let relayer = Self::ensure_relayer_origin(origin)?;
+
+            let threshold = Self::relayer_threshold();
+            ensure!(!threshold.is_zero(), Error::<T>::RelayerThresholdNotSet);
+
+            Self::ensure_chain_supported(remote_transfer.chain_id)?;
+
+            Self::ensure_inbound_transfer_not_processed(&remote_transfer)?;
+
+            let mut attestation = match Self::inbound_transfer_attestation(remote_transfer.chain_id, remote_transfer.id) {
+                Some(attestation) if attestation.transfer != transfer => {
+                    //
+                    // == MUTATION SAFE ==
+                    //
+
+                    <InboundTransferAttestations<T>>::remove(remote_transfer.chain_id, remote_transfer.id);
+                    <Status<T>>::put(BridgeStatus::Paused);
+
+                    Self::deposit_event(RawEvent::InboundTransferAttestationConflict(remote_transfer, relayer, transfer));
+
+                    return Ok(());
+                }
+                Some(attestation) => attestation,
+                None => InboundTransferAttestation {
+                    transfer: transfer.clone(),
+                    relayers: BoundedVec::default(),
+                },
+            };
+
+            // Attestations of relayers removed from the relayer set are no longer counted
+            let relayers = Self::relayer_accounts();
+            attestation.relayers.retain(|account| relayers.contains(account));
+            ensure!(!attestation.relayers.contains(&relayer), Error::<T>::InboundTransferAlreadyAttested);
+            attestation
+                .relayers
+                .try_push(relayer.clone())
+                .map_err(|_| Error::<T>::InvalidNumberOfRelayerAccounts)?;
+
+            Self::deposit_event(RawEvent::InboundTransferAttested(remote_transfer.clone(), relayer, transfer.clone()));
+
+            if attestation.relayers.len().saturated_into::<u32>() >= threshold {
+                Self::do_finalize_inbound_transfer(&remote_transfer, &transfer)?;
+                <InboundTransferAttestations<T>>::remove(remote_transfer.chain_id, remote_transfer.id);
+            } else {
+                <InboundTransferAttestations<T>>::insert(remote_transfer.chain_id, remote_transfer.id, attestation);
+            }
+
+            Ok(())
+        }
+
+        /// Mint the JOY of an inbound transfer queued for being above the delayed transfer
+        /// threshold, once its delay has elapsed.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// # </weight>
+        #[weight = WeightInfoArgo::<T>::release_delayed_inbound_transfer()]
+        pub fn release_delayed_inbound_transfer(origin, remote_transfer: RemoteTransfer) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            Self::ensure_bridge_active()?;
+
+            let delayed_transfer = Self::ensure_delayed_inbound_transfer(&remote_transfer)?;
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(current_block >= delayed_transfer.release_at, Error::<T>::InboundTransferDelayNotElapsed);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <DelayedInboundTransfers<T>>::remove(remote_transfer.chain_id, remote_transfer.id);
+            let _ = balances::Pallet::<T>::deposit_creating(&delayed_transfer.dest_account, delayed_transfer.amount);
+
+            Self::deposit_event(RawEvent::DelayedInboundTransferReleased(
+                remote_transfer,
+                delayed_transfer.dest_account,
+                delayed_transfer.amount
+            ));
+
+            Ok(())
+        }
+
+        /// Cancel an inbound transfer queued for being above the delayed transfer threshold,
+        /// its amount is given back to the mint allowance.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// # </weight>
+        #[weight = WeightInfoArgo::<T>::cancel_delayed_inbound_transfer()]
+        pub fn cancel_delayed_inbound_transfer(origin, remote_transfer: RemoteTransfer) -> DispatchResult {
+            let caller = Self::ensure_pauser_origin(origin)?;
+
+            let delayed_transfer = Self::ensure_delayed_inbound_transfer(&remote_transfer)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <DelayedInboundTransfers<T>>::remove(remote_transfer.chain_id, remote_transfer.id);
+            <MintAllowance<T>>::put(Self::mint_allowance().saturating_add(delayed_transfer.amount));
+
+            Self::deposit_event(RawEvent::DelayedInboundTransferCancelled(remote_transfer, caller));
+
+            Ok(())
+        }
+
+        #[weight = WeightInfoArgo::<T>::request_creator_token_outbound_transfer()]
+        pub fn request_creator_token_outbound_transfer(
+            origin,
+            token_id: TokenIdOf<T>,
+            member_id: TokenMemberIdOf<T>,
+            dest_account: RemoteAccount,
+            amount: TokenBalanceOf<T>,
+            expected_fee: BalanceOf<T>,
+        ) -> DispatchResult {
+            Self::ensure_bridge_active()?;
+
+            Self::ensure_chain_supported(dest_account.chain_id)?;
+
+            let token = Self::ensure_bridgeable_token_active(token_id)?;
+            ensure!(T::CreatorTokens::is_bridging_enabled(token_id), Error::<T>::TokenBridgingNotEnabled);
+
+            let fee = token.bridging_fee;
+            ensure!(fee == expected_fee, Error::<T>::FeeDifferentThanExpected);
+
+            let sender = ensure_signed(origin)?;
+            ensure!(has_sufficient_balance_for_payment::<T>(&sender, fee), Error::<T>::InsufficientJoyBalance);
+            let mint_allowance = token.mint_allowance.checked_add(&amount).ok_or(Error::<T>::ArithmeticError)?;
+            let transfer_id = NextTransferId::get();
+            let next_transfer_id = transfer_id.checked_add(1).ok_or(Error::<T>::ArithmeticError)?;
+
+            // Call to CreatorTokens - should be the first call before MUTATION SAFE!
+            T::CreatorTokens::escrow_bridged_tokens(token_id, member_id, &sender, amount)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            burn_from_usable::<T>(&sender, fee)?;
+            <BridgeableTokens<T>>::insert(token_id, BridgeableToken { mint_allowance, ..token });
+            Self::add_pending_outbound_transfer(transfer_id, OutboundTransferAmount::CreatorToken(token_id, amount));
+
+            Self::deposit_event(RawEvent::CreatorTokenOutboundTransferRequested(transfer_id, token_id, member_id, dest_account, amount, fee));
+            NextTransferId::put(next_transfer_id);
+
+            Ok(())
+        }
+
+        #[weight = WeightInfoArgo::<T>::finalize_creator_token_inbound_transfer()]
+        pub fn finalize_creator_token_inbound_transfer(
+            origin,
+            remote_transfer: RemoteTransfer,
+            token_id: TokenIdOf<T>,
+            dest_member_id: TokenMemberIdOf<T>,
+            amount: TokenBalanceOf<T>,
+        ) -> DispatchResult {
+            Self::ensure_bridge_active()?;
+
+            Self::ensure_operator_origin(origin)?;
+
+            Self::ensure_operator_finalization_allowed()?;
+
+            Self::ensure_chain_supported(remote_transfer.chain_id)?;
+
+            Self::ensure_inbound_transfer_not_processed(&remote_transfer)?;
+
+            Self::do_finalize_inbound_transfer(
+                &remote_transfer,
+                &InboundTransfer::CreatorToken { token_id, dest_member_id, amount },
+            )
+        }
+
+        #[weight = WeightInfoArgo::<T>::revert_creator_token_outbound_transfer()]
+        pub fn revert_creator_token_outbound_transfer(
+            origin,
+            transfer_id: TransferId,
+            token_id: TokenIdOf<T>,
+            revert_member_id: TokenMemberIdOf<T>,
+            revert_amount: TokenBalanceOf<T>,
+            rationale: BoundedVec<u8, ConstU32<MAX_BYTES_RATIONALE>>,
+        ) -> DispatchResult {
+            Self::ensure_bridge_active()?;
+
+            Self::ensure_operator_origin(origin)?;
+
+            let transfer = Self::ensure_pending_outbound_transfer(transfer_id)?;
+            match transfer.amount {
+                OutboundTransferAmount::CreatorToken(transfer_token_id, amount) if transfer_token_id == token_id => {
+                    ensure!(revert_amount <= amount, Error::<T>::RevertAmountExceedsTransferAmount)
+                }
+                _ => return Err(Error::<T>::OutboundTransferAssetMismatch.into()),
+            }
+
+            let token = Self::ensure_bridgeable_token_active(token_id)?;
+            let mint_allowance = Self::ensure_token_mint_allowance(&token, revert_amount)?;
+
+            // Call to CreatorTokens - should be the first call before MUTATION SAFE!
+            T::CreatorTokens::release_bridged_tokens(token_id, revert_member_id, revert_amount)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <PendingOutboundTransfers<T>>::remove(transfer_id);
+            <BridgeableTokens<T>>::insert(token_id, BridgeableToken { mint_allowance, ..token });
+
+            Self::deposit_event(RawEvent::CreatorTokenOutboundTransferReverted(transfer_id, token_id, revert_member_id, revert_amount, rationale));
+
+            Ok(())
+        }
+
+        /// Allow Governance to approve a creator token for bridging and set its constraints
+        /// Preconditions:
+        /// - origin is signed by `root`
+        /// - token issuer enabled bridging, in case the token is not yet approved
+        /// PostConditions:
+        /// - token bridge parameters set to the provided values
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// # </weight>
+        #[weight = WeightInfoArgo::<T>::update_bridgeable_token()]
+        pub fn update_bridgeable_token(
+            origin,
+            token_id: TokenIdOf<T>,
+            parameters: BridgeableTokenConstraintsOf<T>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let token = match Self::bridgeable_token(token_id) {
+                Some(token) => token,
+                None => {
+                    ensure!(T::CreatorTokens::is_bridging_enabled(token_id), Error::<T>::TokenBridgingNotEnabled);
+                    BridgeableToken {
+                        mint_allowance: Zero::zero(),
+                        bridging_fee: T::DefaultBridgingFee::get(),
+                        paused: false,
+                    }
+                }
+            };
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <BridgeableTokens<T>>::insert(token_id, BridgeableToken {
+                bridging_fee: parameters.bridging_fee.unwrap_or(token.bridging_fee),
+                paused: parameters.paused.unwrap_or(token.paused),
+                ..token
+            });
+
+            Self::deposit_event(RawEvent::BridgeableTokenUpdated(token_id, parameters));
+
+            Ok(())
+        }
+
     }
 }
 
@@ -289,6 +674,23 @@ impl<T: Config> Module<T> {
         }
     }
 
+    pub fn ensure_relayer_origin(origin: T::RuntimeOrigin) -> Result<T::AccountId, DispatchError> {
+        let caller = ensure_signed(origin)?;
+        ensure!(
+            Self::relayer_accounts().contains(&caller),
+            Error::<T>::NotRelayerAccount
+        );
+        Ok(caller)
+    }
+
+    pub fn ensure_operator_finalization_allowed() -> DispatchResult {
+        ensure!(
+            Self::relayer_threshold().is_zero(),
+            Error::<T>::RelayerAttestationRequired
+        );
+        Ok(())
+    }
+
     pub fn ensure_pauser_origin(origin: T::RuntimeOrigin) -> Result<T::AccountId, DispatchError> {
         let caller = ensure_signed(origin)?;
         let accounts = Self::pauser_accounts();
@@ -296,6 +698,13 @@ impl<T: Config> Module<T> {
         Ok(caller)
     }
 
+    pub fn ensure_delayed_inbound_transfer(
+        remote_transfer: &RemoteTransfer,
+    ) -> Result<DelayedInboundTransferOf<T>, DispatchError> {
+        Self::delayed_inbound_transfer(remote_transfer.chain_id, remote_transfer.id)
+            .ok_or_else(|| Error::<T>::DelayedInboundTransferNotFound.into())
+    }
+
     pub fn ensure_mint_allowance(amount: BalanceOf<T>) -> DispatchResult {
         ensure!(
             amount <= Self::mint_allowance(),
@@ -312,6 +721,295 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    pub fn ensure_bridgeable_token_active(
+        token_id: TokenIdOf<T>,
+    ) -> Result<BridgeableTokenOf<T>, DispatchError> {
+        let token = Self::bridgeable_token(token_id).ok_or(Error::<T>::NotBridgeableToken)?;
+        ensure!(!token.paused, Error::<T>::TokenBridgePaused);
+        Ok(token)
+    }
+
+    /// Returns the token mint allowance left after releasing `amount`
+    pub fn ensure_token_mint_allowance(
+        token: &BridgeableTokenOf<T>,
+        amount: TokenBalanceOf<T>,
+    ) -> Result<TokenBalanceOf<T>, DispatchError> {
+        token
+            .mint_allowance
+            .checked_sub(&amount)
+            .ok_or_else(|| Error::<T>::InsufficientTokenMintAllowance.into())
+    }
+
+    pub fn ensure_inbound_transfer_not_processed(
+        remote_transfer: &RemoteTransfer,
+    ) -> DispatchResult {
+        ensure!(
+            remote_transfer.id >= Self::inbound_transfers_watermark(remote_transfer.chain_id),
+            Error::<T>::InboundTransferBelowWatermark
+        );
+        ensure!(
+            !Self::processed_inbound_transfer(remote_transfer.chain_id, remote_transfer.id),
+            Error::<T>::InboundTransferAlreadyProcessed
+        );
+        Ok(())
+    }
+
+    /// Records the inbound transfer as processed and moves the chain watermark so that it
+    /// trails the transfer id by at most `InboundTransfersWindow`. The watermark advances by
+    /// at most `InboundTransfersWindow` per transfer, which bounds the ids walked by pruning.
+    fn add_processed_inbound_transfer(remote_transfer: &RemoteTransfer) {
+        let window = T::InboundTransfersWindow::get();
+        ProcessedInboundTransfers::insert(remote_transfer.chain_id, remote_transfer.id, true);
+        InboundTransfersWatermark::mutate(remote_transfer.chain_id, |watermark| {
+            let window_start = remote_transfer
+                .id
+                .saturating_add(1)
+                .saturating_sub(window)
+                .min(watermark.saturating_add(window));
+            *watermark = (*watermark).max(window_start);
+        });
+    }
+
+    pub fn ensure_pending_outbound_transfer(
+        transfer_id: TransferId,
+    ) -> Result<PendingOutboundTransferOf<T>, DispatchError> {
+        Self::pending_outbound_transfer(transfer_id)
+            .ok_or_else(|| Error::<T>::OutboundTransferNotFound.into())
+    }
+
+    /// Mints JOY / releases creator tokens of an inbound transfer, all the checks
+    /// are performed before the storage is mutated.
+    /// JOY transfers exceeding the mint limits are blocked without being processed,
+    /// while the ones above the delayed transfer threshold are queued.
+    fn do_finalize_inbound_transfer(
+        remote_transfer: &RemoteTransfer,
+        transfer: &InboundTransferOf<T>,
+    ) -> DispatchResult {
+        match transfer {
+            InboundTransfer::Joy {
+                dest_account,
+                amount,
+            } => {
+                Self::ensure_mint_allowance(*amount)?;
+
+                let limits = Self::mint_limits();
+                let now = <frame_system::Pallet<T>>::block_number();
+                let minted_in_window =
+                    Self::minted_in_window_with(remote_transfer.chain_id, *amount, &limits, now);
+                let (minted, chain_minted) = match minted_in_window {
+                    Some(minted_in_window)
+                        if limits
+                            .max_transfer_amount
+                            .map_or(true, |max| *amount <= max) =>
+                    {
+                        minted_in_window
+                    }
+                    _ => {
+                        Self::deposit_event(RawEvent::InboundTransferBlocked(
+                            remote_transfer.clone(),
+                            dest_account.clone(),
+                            *amount,
+                        ));
+                        return Ok(());
+                    }
+                };
+
+                //
+                // == MUTATION SAFE ==
+                //
+
+                Self::add_processed_inbound_transfer(remote_transfer);
+                <MintedInWindow<T>>::put(minted);
+                <ChainMintedInWindow<T>>::insert(remote_transfer.chain_id, chain_minted);
+
+                if limits
+                    .delayed_transfer_threshold
+                    .map_or(false, |threshold| *amount > threshold)
+                {
+                    let release_at = now.saturating_add(limits.transfer_delay);
+                    <MintAllowance<T>>::put(Self::mint_allowance().saturating_sub(*amount));
+                    <DelayedInboundTransfers<T>>::insert(
+                        remote_transfer.chain_id,
+                        remote_transfer.id,
+                        DelayedInboundTransfer {
+                            dest_account: dest_account.clone(),
+                            amount: *amount,
+                            release_at,
+                        },
+                    );
+
+                    Self::deposit_event(RawEvent::InboundTransferQueued(
+                        remote_transfer.clone(),
+                        dest_account.clone(),
+                        *amount,
+                        release_at,
+                    ));
+                } else {
+                    Self::mint_tokens(dest_account, *amount);
+
+                    Self::deposit_event(RawEvent::InboundTransferFinalized(
+                        remote_transfer.clone(),
+                        dest_account.clone(),
+                        *amount,
+                    ));
+                }
+            }
+            InboundTransfer::CreatorToken {
+                token_id,
+                dest_member_id,
+                amount,
+            } => {
+                let token = Self::ensure_bridgeable_token_active(*token_id)?;
+                let mint_allowance = Self::ensure_token_mint_allowance(&token, *amount)?;
+
+                // Call to CreatorTokens - should be the first call before MUTATION SAFE!
+                T::CreatorTokens::release_bridged_tokens(*token_id, *dest_member_id, *amount)?;
+
+                //
+                // == MUTATION SAFE ==
+                //
+
+                Self::add_processed_inbound_transfer(remote_transfer);
+                <BridgeableTokens<T>>::insert(
+                    token_id,
+                    BridgeableToken {
+                        mint_allowance,
+                        ..token
+                    },
+                );
+
+                Self::deposit_event(RawEvent::CreatorTokenInboundTransferFinalized(
+                    remote_transfer.clone(),
+                    *token_id,
+                    *dest_member_id,
+                    *amount,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the amounts minted within the window overall and by the chain including `amount`,
+    /// or `None` if a windowed limit would be exceeded
+    fn minted_in_window_with(
+        chain_id: ChainId,
+        amount: BalanceOf<T>,
+        limits: &MintLimitsOf<T>,
+        now: T::BlockNumber,
+    ) -> Option<(MintedAmountOf<T>, MintedAmountOf<T>)> {
+        let minted = Self::minted_in_window_at(
+            Self::minted_in_window(),
+            limits.max_minted_per_window,
+            limits.window,
+            now,
+        )
+        .saturating_add(amount);
+        let chain_minted = Self::minted_in_window_at(
+            Self::chain_minted_in_window(chain_id),
+            limits.max_minted_per_chain_per_window,
+            limits.window,
+            now,
+        )
+        .saturating_add(amount);
+
+        let within_limit =
+            |minted: BalanceOf<T>, max: Option<BalanceOf<T>>| max.map_or(true, |max| minted <= max);
+        if !within_limit(minted, limits.max_minted_per_window)
+            || !within_limit(chain_minted, limits.max_minted_per_chain_per_window)
+        {
+            return None;
+        }
+
+        Some((
+            MintedAmount {
+                amount: minted,
+                updated_at: now,
+            },
+            MintedAmount {
+                amount: chain_minted,
+                updated_at: now,
+            },
+        ))
+    }
+
+    // The minted amount decreases by `max` over a whole window
+    fn minted_in_window_at(
+        minted: MintedAmountOf<T>,
+        max: Option<BalanceOf<T>>,
+        window: T::BlockNumber,
+        now: T::BlockNumber,
+    ) -> BalanceOf<T> {
+        let max = match max {
+            Some(max) => max,
+            None => return Zero::zero(),
+        };
+        let elapsed = now.saturating_sub(minted.updated_at);
+        let released = Perquintill::from_rational(
+            elapsed.saturated_into::<u64>(),
+            window.saturated_into::<u64>(),
+        )
+        .mul_floor(max);
+        minted.amount.saturating_sub(released)
+    }
+
+    fn add_pending_outbound_transfer(
+        transfer_id: TransferId,
+        amount: OutboundTransferAmount<BalanceOf<T>, TokenIdOf<T>, TokenBalanceOf<T>>,
+    ) {
+        <PendingOutboundTransfers<T>>::insert(
+            transfer_id,
+            PendingOutboundTransfer {
+                amount,
+                requested_at: <frame_system::Pallet<T>>::block_number(),
+            },
+        );
+    }
+
+    /// Prunes at most `MaxPrunedTransfersPerBlock` ledger entries, returns the number of pruned
+    /// entries and the number of remote chains checked:
+    /// - outbound transfers older than `OutboundTransferRetentionPeriod` (or already reverted)
+    /// - processed inbound transfers below the chain watermark, which remain rejected
+    fn prune_transfer_ledger(now: T::BlockNumber) -> (u32, u32) {
+        let max_pruned = T::MaxPrunedTransfersPerBlock::get();
+        let mut pruned = 0u32;
+
+        let next_transfer_id = NextTransferId::get();
+        let mut transfer_id = Self::next_pruned_outbound_transfer_id();
+        while pruned < max_pruned && transfer_id < next_transfer_id {
+            if let Some(transfer) = Self::pending_outbound_transfer(transfer_id) {
+                let expires_at = transfer
+                    .requested_at
+                    .saturating_add(T::OutboundTransferRetentionPeriod::get());
+                if now < expires_at {
+                    break;
+                }
+                <PendingOutboundTransfers<T>>::remove(transfer_id);
+            }
+            transfer_id = transfer_id.saturating_add(1);
+            pruned = pruned.saturating_add(1);
+        }
+        if transfer_id != Self::next_pruned_outbound_transfer_id() {
+            NextPrunedOutboundTransferId::put(transfer_id);
+        }
+
+        let remote_chains = Self::remote_chains();
+        for &chain_id in remote_chains.iter() {
+            let watermark = Self::inbound_transfers_watermark(chain_id);
+            let initial_transfer_id = Self::next_pruned_inbound_transfer_id(chain_id);
+            let mut transfer_id = initial_transfer_id;
+            while pruned < max_pruned && transfer_id < watermark {
+                ProcessedInboundTransfers::remove(chain_id, transfer_id);
+                transfer_id = transfer_id.saturating_add(1);
+                pruned = pruned.saturating_add(1);
+            }
+            if transfer_id != initial_transfer_id {
+                NextPrunedInboundTransferId::insert(chain_id, transfer_id);
+            }
+        }
+
+        (pruned, remote_chains.len().saturated_into())
+    }
+
     pub fn mint_tokens(dest_account: &T::AccountId, amount: BalanceOf<T>) {
         <MintAllowance<T>>::put(Self::mint_allowance() - amount);
         let _ = balances::Pallet::<T>::deposit_creating(dest_account, amount);
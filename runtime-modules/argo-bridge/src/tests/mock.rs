@@ -3,10 +3,15 @@
 use crate as argo_bridge;
 use crate::*;
 
+use common::bridge::CreatorTokenBridge;
 use frame_support::{
+    dispatch::DispatchResult,
     parameter_types,
     traits::{OnFinalize, OnInitialize},
 };
+use sp_runtime::DispatchError;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 
 use frame_support::traits::{ConstU16, ConstU32, ConstU64};
 use sp_runtime::testing::{Header, H256};
@@ -20,6 +25,9 @@ type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 pub type AccountId = <Test as frame_system::Config>::AccountId;
 pub type BlockNumber = <Test as frame_system::Config>::BlockNumber;
+pub type TokenId = u64;
+pub type MemberId = u64;
+pub type TokenBalance = u128;
 
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
@@ -34,6 +42,9 @@ parameter_types! {
 parameter_types! {
     pub const MaxPauserAccounts: u32 = 10;
     pub const DefaultBridgingFee: Balance = 1;
+    pub const OutboundTransferRetentionPeriod: BlockNumber = 10;
+    pub const MaxPrunedTransfersPerBlock: u32 = 5;
+    pub const InboundTransfersWindow: u64 = 2;
 }
 
 #[macro_export]
@@ -110,6 +121,97 @@ impl Config for Test {
     type MaxPauserAccounts = MaxPauserAccounts;
     type WeightInfo = argo_bridge::weights::SubstrateWeight<Test>;
     type DefaultBridgingFee = DefaultBridgingFee;
+    type CreatorTokens = TestCreatorTokens;
+    type OutboundTransferRetentionPeriod = OutboundTransferRetentionPeriod;
+    type MaxPrunedTransfersPerBlock = MaxPrunedTransfersPerBlock;
+    type InboundTransfersWindow = InboundTransfersWindow;
+}
+
+thread_local! {
+    pub static TOKEN_BRIDGING_ENABLED: RefCell<BTreeMap<TokenId, bool>> = RefCell::new(BTreeMap::new());
+    pub static TOKEN_BALANCES: RefCell<BTreeMap<(TokenId, MemberId), TokenBalance>> = RefCell::new(BTreeMap::new());
+    pub static TOKEN_ESCROWED: RefCell<BTreeMap<TokenId, TokenBalance>> = RefCell::new(BTreeMap::new());
+}
+
+// Creator tokens mock, member ids are the same as their controller account ids
+pub struct TestCreatorTokens;
+
+impl TestCreatorTokens {
+    pub fn set_bridging_enabled(token_id: TokenId, enabled: bool) {
+        TOKEN_BRIDGING_ENABLED.with(|v| v.borrow_mut().insert(token_id, enabled));
+    }
+
+    pub fn set_balance(token_id: TokenId, member_id: MemberId, amount: TokenBalance) {
+        TOKEN_BALANCES.with(|v| v.borrow_mut().insert((token_id, member_id), amount));
+    }
+
+    pub fn balance(token_id: TokenId, member_id: MemberId) -> TokenBalance {
+        TOKEN_BALANCES.with(|v| v.borrow().get(&(token_id, member_id)).copied().unwrap_or(0))
+    }
+
+    pub fn escrowed(token_id: TokenId) -> TokenBalance {
+        TOKEN_ESCROWED.with(|v| v.borrow().get(&token_id).copied().unwrap_or(0))
+    }
+}
+
+impl CreatorTokenBridge<AccountId> for TestCreatorTokens {
+    type TokenId = TokenId;
+    type MemberId = MemberId;
+    type Balance = TokenBalance;
+
+    fn is_bridging_enabled(token_id: TokenId) -> bool {
+        TOKEN_BRIDGING_ENABLED.with(|v| v.borrow().get(&token_id).copied().unwrap_or(false))
+    }
+
+    fn escrow_bridged_tokens(
+        token_id: TokenId,
+        member_id: MemberId,
+        sender: &AccountId,
+        amount: TokenBalance,
+    ) -> DispatchResult {
+        if *sender != member_id {
+            return Err(DispatchError::Other("sender not member controller"));
+        }
+        if !Self::is_bridging_enabled(token_id) {
+            return Err(DispatchError::Other("token bridging not enabled"));
+        }
+        let balance = Self::balance(token_id, member_id);
+        if balance < amount {
+            return Err(DispatchError::Other("insufficient token balance"));
+        }
+        Self::set_balance(token_id, member_id, balance - amount);
+        TOKEN_ESCROWED.with(|v| *v.borrow_mut().entry(token_id).or_default() += amount);
+        Ok(())
+    }
+
+    fn release_bridged_tokens(
+        token_id: TokenId,
+        member_id: MemberId,
+        amount: TokenBalance,
+    ) -> DispatchResult {
+        let escrowed = Self::escrowed(token_id);
+        if escrowed < amount {
+            return Err(DispatchError::Other("insufficient bridged tokens"));
+        }
+        TOKEN_ESCROWED.with(|v| v.borrow_mut().insert(token_id, escrowed - amount));
+        Self::set_balance(
+            token_id,
+            member_id,
+            Self::balance(token_id, member_id) + amount,
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_bridgeable_token(
+        account_id: &AccountId,
+        amount: TokenBalance,
+    ) -> Result<(TokenId, MemberId), DispatchError> {
+        let token_id = 1;
+        Self::set_bridging_enabled(token_id, true);
+        Self::set_balance(token_id, *account_id, amount);
+        Ok((token_id, *account_id))
+    }
 }
 
 pub fn default_genesis_config() -> argo_bridge::GenesisConfig<Test> {
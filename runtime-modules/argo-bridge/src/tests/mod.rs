@@ -4,13 +4,16 @@ use core::convert::{TryFrom, TryInto};
 
 use crate::tests::mock::*;
 use frame_support::dispatch::DispatchResult;
+use frame_support::traits::OnInitialize;
 use frame_support::{assert_err, assert_ok};
-use sp_runtime::BoundedVec;
+use sp_runtime::{BoundedVec, DispatchError};
 
 use crate::{
     account, joy, last_event_eq,
     tests::mock::{increase_block_number_by, AccountId, Balance, Balances, BlockNumber, Test},
-    BridgeConstraints, BridgeStatus, RawEvent, RemoteAccount, RemoteTransfer,
+    types::{ChainId, DelayedInboundTransfer, InboundTransfer, MintLimits, MAX_RELAYER_ACCOUNTS},
+    BridgeConstraints, BridgeStatus, BridgeableTokenConstraints, RawEvent, RemoteAccount,
+    RemoteTransfer, TransferId,
 };
 
 use self::mock::{
@@ -32,6 +35,9 @@ fn request_outbound_transfer_success() {
             bridging_fee: Some(fee),
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -83,6 +89,9 @@ fn request_outbound_transfer_with_bridge_paused() {
             bridging_fee: Some(fee),
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
 
@@ -115,6 +124,9 @@ fn request_outbound_transfer_with_insufficient_balance() {
             bridging_fee: Some(fee),
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -154,6 +166,9 @@ fn request_outbound_transfer_with_unexpected_fee() {
             bridging_fee: Some(joy!(20)),
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -192,6 +207,9 @@ fn request_outbound_transfer_with_not_supported_remote_chain() {
             bridging_fee: Some(joy!(20)),
             thawn_duration: None,
             remote_chains: Some(BoundedVec::try_from(vec![1u32]).unwrap()),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -232,6 +250,9 @@ fn request_outbound_transfer_with_overflow() {
             bridging_fee: Some(fee),
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -266,6 +287,9 @@ fn finalize_inbound_transfer_success() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
         assert_ok!(activate_bridge(account!(2), account!(1)));
@@ -298,6 +322,9 @@ fn finalize_inbound_transfer_with_unauthorized_account() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -326,6 +353,9 @@ fn finalize_inbound_transfer_with_insufficient_bridge_mint() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -354,6 +384,9 @@ fn finalize_inbound_transfer_with_bridge_paused() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
 
@@ -378,11 +411,14 @@ fn revert_outbound_transfer_success() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
         assert_ok!(activate_bridge(account!(2), account!(1)));
+        let transfer_id = request_outbound_transfer(account!(3), joy!(1000));
 
-        let transfer_id = 1u64;
         let revert_amount = joy!(123);
         let revert_account = account!(2);
         let rationale = "test".as_bytes().to_vec();
@@ -395,6 +431,7 @@ fn revert_outbound_transfer_success() {
         );
         assert_ok!(result);
         assert_eq!(Balances::free_balance(revert_account), revert_amount);
+        assert_eq!(ArgoBridge::pending_outbound_transfer(transfer_id), None);
         last_event_eq!(RawEvent::OutboundTransferReverted(
             transfer_id,
             revert_account,
@@ -414,6 +451,9 @@ fn revert_outbound_transfer_with_unauthorized_account() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -442,16 +482,27 @@ fn revert_outbound_transfer_with_insufficient_bridge_mint() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
             parameters
         ));
         assert_ok!(activate_bridge(account!(2), account!(1)));
+        let transfer_id = request_outbound_transfer(account!(3), joy!(100));
+        ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            account!(2),
+            joy!(100),
+        )
+        .unwrap();
 
         let result = ArgoBridge::revert_outbound_transfer(
             RuntimeOrigin::signed(account!(1)),
-            1u64,
+            transfer_id,
             account!(2),
             joy!(100),
             vec![].try_into().unwrap(),
@@ -470,6 +521,9 @@ fn revert_outbound_transfer_with_bridge_paused() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: Some(remote_chains),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
 
@@ -484,6 +538,274 @@ fn revert_outbound_transfer_with_bridge_paused() {
     });
 }
 
+#[test]
+fn finalize_inbound_transfer_with_already_processed_transfer() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: 1 };
+        ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            remote_transfer.clone(),
+            account!(2),
+            joy!(500),
+        )
+        .unwrap();
+
+        let result = ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            remote_transfer,
+            account!(2),
+            joy!(500),
+        );
+        assert_err!(result, Error::<Test>::InboundTransferAlreadyProcessed);
+    });
+}
+
+#[test]
+fn finalize_inbound_transfer_with_same_id_on_other_chain() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        let remote_chains = BoundedVec::try_from(vec![1u32, 2u32]).unwrap();
+        let parameters = BridgeConstraints {
+            operator_account: Some(account!(1)),
+            pauser_accounts: Some(vec![account!(2)]),
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: Some(remote_chains),
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
+        };
+        ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+        activate_bridge(account!(2), account!(1)).unwrap();
+        ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            account!(2),
+            joy!(500),
+        )
+        .unwrap();
+
+        let result = ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 2 },
+            account!(2),
+            joy!(500),
+        );
+        assert_ok!(result);
+        assert!(ArgoBridge::processed_inbound_transfer(2, 0));
+    });
+}
+
+#[test]
+fn revert_outbound_transfer_with_unknown_transfer() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+
+        let result = ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            0u64,
+            account!(2),
+            joy!(100),
+            vec![].try_into().unwrap(),
+        );
+        assert_err!(result, Error::<Test>::OutboundTransferNotFound);
+    });
+}
+
+#[test]
+fn revert_outbound_transfer_with_already_reverted_transfer() {
+    with_test_externalities(|| {
+        setup_active_bridge();
+        let transfer_id = request_outbound_transfer(account!(3), joy!(1000));
+        ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id,
+            account!(3),
+            joy!(500),
+            vec![].try_into().unwrap(),
+        )
+        .unwrap();
+
+        let result = ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id,
+            account!(3),
+            joy!(500),
+            vec![].try_into().unwrap(),
+        );
+        assert_err!(result, Error::<Test>::OutboundTransferNotFound);
+    });
+}
+
+#[test]
+fn revert_outbound_transfer_with_amount_exceeding_transfer_amount() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        let transfer_id = request_outbound_transfer(account!(3), joy!(1000));
+
+        let result = ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id,
+            account!(3),
+            joy!(1001),
+            vec![].try_into().unwrap(),
+        );
+        assert_err!(result, Error::<Test>::RevertAmountExceedsTransferAmount);
+    });
+}
+
+#[test]
+fn revert_outbound_transfer_with_creator_token_transfer() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        request_creator_token_outbound_transfer(1000).unwrap();
+
+        let result = ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            0u64,
+            account!(1),
+            joy!(100),
+            vec![].try_into().unwrap(),
+        );
+        assert_err!(result, Error::<Test>::OutboundTransferAssetMismatch);
+    });
+}
+
+#[test]
+fn expired_outbound_transfers_are_pruned() {
+    with_test_externalities(|| {
+        setup_active_bridge();
+        let transfer_id = request_outbound_transfer(account!(3), joy!(1000));
+
+        increase_block_number_by(OutboundTransferRetentionPeriod::get() - 1);
+        assert!(ArgoBridge::pending_outbound_transfer(transfer_id).is_some());
+
+        increase_block_number_by(1);
+        assert_eq!(ArgoBridge::pending_outbound_transfer(transfer_id), None);
+        assert_eq!(
+            ArgoBridge::next_pruned_outbound_transfer_id(),
+            transfer_id + 1
+        );
+
+        let result = ArgoBridge::revert_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id,
+            account!(3),
+            joy!(1000),
+            vec![].try_into().unwrap(),
+        );
+        assert_err!(result, Error::<Test>::OutboundTransferNotFound);
+    });
+}
+
+fn finalize_inbound_transfers(chain_id: ChainId, ids: &[TransferId]) {
+    for &id in ids {
+        ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id, chain_id },
+            account!(2),
+            joy!(10),
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn processed_inbound_transfers_are_pruned_with_replay_protection() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        let window = InboundTransfersWindow::get();
+        finalize_inbound_transfers(1, &[0, 1, window + 1]);
+
+        // watermark trails the highest finalized id by the window
+        assert_eq!(ArgoBridge::inbound_transfers_watermark(1), 2);
+        increase_block_number_by(1);
+
+        assert_eq!(ArgoBridge::next_pruned_inbound_transfer_id(1), 2);
+        assert!(!ArgoBridge::processed_inbound_transfer(1, 0));
+        assert!(!ArgoBridge::processed_inbound_transfer(1, 1));
+        assert!(ArgoBridge::processed_inbound_transfer(1, window + 1));
+        let result = ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 1, chain_id: 1 },
+            account!(2),
+            joy!(100),
+        );
+        assert_err!(result, Error::<Test>::InboundTransferBelowWatermark);
+        let result = ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer {
+                id: window + 1,
+                chain_id: 1,
+            },
+            account!(2),
+            joy!(100),
+        );
+        assert_err!(result, Error::<Test>::InboundTransferAlreadyProcessed);
+        // transfer within the window still accepted
+        assert_ok!(ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 2, chain_id: 1 },
+            account!(2),
+            joy!(100),
+        ));
+    });
+}
+
+#[test]
+fn inbound_transfer_id_gap_does_not_stop_pruning() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        let window = InboundTransfersWindow::get();
+        // transfer 1 is never finalized
+        finalize_inbound_transfers(1, &[0, 2, 2 + window, 2 + 2 * window]);
+
+        increase_block_number_by(1);
+
+        let watermark = ArgoBridge::inbound_transfers_watermark(1);
+        assert_eq!(watermark, 3 + window);
+        assert_eq!(ArgoBridge::next_pruned_inbound_transfer_id(1), watermark);
+        assert!(!ArgoBridge::processed_inbound_transfer(1, 0));
+        assert!(!ArgoBridge::processed_inbound_transfer(1, 2));
+        assert!(!ArgoBridge::processed_inbound_transfer(1, 2 + window));
+        assert!(ArgoBridge::processed_inbound_transfer(1, 2 + 2 * window));
+    });
+}
+
+#[test]
+fn inbound_transfers_watermark_advances_by_at_most_window() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        let window = InboundTransfersWindow::get();
+
+        finalize_inbound_transfers(1, &[100 * window]);
+
+        assert_eq!(ArgoBridge::inbound_transfers_watermark(1), window);
+    });
+}
+
+#[test]
+fn transfer_ledger_pruning_is_bounded() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        let max_pruned = MaxPrunedTransfersPerBlock::get() as u64;
+        let window = InboundTransfersWindow::get();
+        let ids: Vec<_> = (0..max_pruned + window + 1).collect();
+        finalize_inbound_transfers(1, &ids);
+
+        ArgoBridge::on_initialize(System::block_number());
+        assert_eq!(ArgoBridge::next_pruned_inbound_transfer_id(1), max_pruned);
+        assert!(ArgoBridge::processed_inbound_transfer(1, max_pruned));
+
+        ArgoBridge::on_initialize(System::block_number());
+        assert_eq!(
+            ArgoBridge::next_pruned_inbound_transfer_id(1),
+            max_pruned + 1
+        );
+        assert!(!ArgoBridge::processed_inbound_transfer(1, max_pruned));
+    });
+}
+
 #[test]
 fn pause_bridge_success() {
     with_test_externalities(|| {
@@ -494,6 +816,9 @@ fn pause_bridge_success() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: None,
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -519,6 +844,9 @@ fn pause_bridge_with_unauthorized_account() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: None,
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         assert_ok!(ArgoBridge::update_bridge_constrains(
             RuntimeOrigin::root(),
@@ -539,6 +867,9 @@ fn unpause_bridge_success() {
             bridging_fee: None,
             thawn_duration: Some(1),
             remote_chains: None,
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
 
@@ -567,6 +898,9 @@ fn init_unpause_bridge_active() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: None,
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
         assert_ok!(activate_bridge(account!(2), account!(1)));
@@ -587,6 +921,9 @@ fn unpause_bridge_during_thawn() {
             bridging_fee: None,
             thawn_duration: Some(thawn_duration),
             remote_chains: None,
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
 
@@ -616,6 +953,9 @@ fn unpause_bridge_without_operator_account() {
             bridging_fee: None,
             thawn_duration: Some(thawn_duration),
             remote_chains: None,
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
         ArgoBridge::init_unpause_bridge(RuntimeOrigin::signed(account!(2))).unwrap();
@@ -636,6 +976,9 @@ fn init_unpause_bridge_with_unauthorized_account() {
             bridging_fee: None,
             thawn_duration: None,
             remote_chains: None,
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: None,
         };
         ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
 
@@ -646,6 +989,1148 @@ fn init_unpause_bridge_with_unauthorized_account() {
     });
 }
 
+#[test]
+fn update_bridgeable_token_success() {
+    with_test_externalities(|| {
+        TestCreatorTokens::set_bridging_enabled(1, true);
+        let parameters = BridgeableTokenConstraints {
+            bridging_fee: None,
+            paused: None,
+        };
+
+        let result =
+            ArgoBridge::update_bridgeable_token(RuntimeOrigin::root(), 1, parameters.clone());
+
+        assert_ok!(result);
+        let token = ArgoBridge::bridgeable_token(1).unwrap();
+        assert_eq!(token.bridging_fee, DefaultBridgingFee::get());
+        assert_eq!(token.mint_allowance, 0);
+        assert!(!token.paused);
+        last_event_eq!(RawEvent::BridgeableTokenUpdated(1, parameters));
+    });
+}
+
+#[test]
+fn update_bridgeable_token_with_bridging_not_enabled() {
+    with_test_externalities(|| {
+        let parameters = BridgeableTokenConstraints {
+            bridging_fee: None,
+            paused: None,
+        };
+
+        let result = ArgoBridge::update_bridgeable_token(RuntimeOrigin::root(), 1, parameters);
+
+        assert_err!(result, Error::<Test>::TokenBridgingNotEnabled);
+    });
+}
+
+#[test]
+fn update_bridgeable_token_with_unauthorized_account() {
+    with_test_externalities(|| {
+        TestCreatorTokens::set_bridging_enabled(1, true);
+        let parameters = BridgeableTokenConstraints {
+            bridging_fee: None,
+            paused: None,
+        };
+
+        let result =
+            ArgoBridge::update_bridgeable_token(RuntimeOrigin::signed(account!(1)), 1, parameters);
+
+        assert_err!(result, DispatchError::BadOrigin);
+    });
+}
+
+#[test]
+fn update_bridgeable_token_preserves_mint_allowance() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        request_creator_token_outbound_transfer(1000).unwrap();
+        let parameters = BridgeableTokenConstraints {
+            bridging_fee: Some(joy!(20)),
+            paused: Some(true),
+        };
+
+        let result = ArgoBridge::update_bridgeable_token(RuntimeOrigin::root(), 1, parameters);
+
+        assert_ok!(result);
+        let token = ArgoBridge::bridgeable_token(1).unwrap();
+        assert_eq!(token.bridging_fee, joy!(20));
+        assert_eq!(token.mint_allowance, 1000);
+        assert!(token.paused);
+    });
+}
+
+#[test]
+fn request_creator_token_outbound_transfer_success() {
+    with_test_externalities(|| {
+        let fee = joy!(10);
+        setup_bridgeable_token(fee);
+        let initial_balance = Balances::free_balance(account!(1));
+        let transfer_id = ArgoBridge::next_transfer_id();
+
+        let result = request_creator_token_outbound_transfer(1000);
+
+        assert_ok!(result);
+        assert_eq!(
+            ArgoBridge::bridgeable_token(1).unwrap().mint_allowance,
+            1000
+        );
+        assert_eq!(TestCreatorTokens::balance(1, account!(1)), 0);
+        assert_eq!(TestCreatorTokens::escrowed(1), 1000);
+        assert_eq!(Balances::free_balance(account!(1)), initial_balance - fee);
+        assert_eq!(ArgoBridge::next_transfer_id(), transfer_id + 1);
+        last_event_eq!(RawEvent::CreatorTokenOutboundTransferRequested(
+            transfer_id,
+            1,
+            account!(1),
+            RemoteAccount {
+                account: [0; 32],
+                chain_id: 1,
+            },
+            1000,
+            fee
+        ));
+    });
+}
+
+#[test]
+fn request_creator_token_outbound_transfer_with_not_bridgeable_token() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+
+        let result = ArgoBridge::request_creator_token_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            2,
+            account!(1),
+            RemoteAccount {
+                account: [0; 32],
+                chain_id: 1,
+            },
+            1000,
+            joy!(10),
+        );
+
+        assert_err!(result, Error::<Test>::NotBridgeableToken);
+    });
+}
+
+#[test]
+fn request_creator_token_outbound_transfer_with_token_bridge_paused() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        let parameters = BridgeableTokenConstraints {
+            bridging_fee: None,
+            paused: Some(true),
+        };
+        ArgoBridge::update_bridgeable_token(RuntimeOrigin::root(), 1, parameters).unwrap();
+
+        let result = request_creator_token_outbound_transfer(1000);
+
+        assert_err!(result, Error::<Test>::TokenBridgePaused);
+    });
+}
+
+#[test]
+fn request_creator_token_outbound_transfer_with_bridging_disabled_by_issuer() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        TestCreatorTokens::set_bridging_enabled(1, false);
+
+        let result = request_creator_token_outbound_transfer(1000);
+
+        assert_err!(result, Error::<Test>::TokenBridgingNotEnabled);
+    });
+}
+
+#[test]
+fn request_creator_token_outbound_transfer_with_unexpected_fee() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+
+        let result = ArgoBridge::request_creator_token_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            1,
+            account!(1),
+            RemoteAccount {
+                account: [0; 32],
+                chain_id: 1,
+            },
+            1000,
+            joy!(5),
+        );
+
+        assert_err!(result, Error::<Test>::FeeDifferentThanExpected);
+    });
+}
+
+#[test]
+fn request_creator_token_outbound_transfer_with_insufficient_joy_balance() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        Balances::set_balance(RuntimeOrigin::root(), account!(1), joy!(9), joy!(0)).unwrap();
+
+        let result = request_creator_token_outbound_transfer(1000);
+
+        assert_err!(result, Error::<Test>::InsufficientJoyBalance);
+    });
+}
+
+#[test]
+fn request_creator_token_outbound_transfer_with_insufficient_token_balance() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+
+        let result = request_creator_token_outbound_transfer(1001);
+
+        assert_err!(result, DispatchError::Other("insufficient token balance"));
+    });
+}
+
+#[test]
+fn request_creator_token_outbound_transfer_with_bridge_paused() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        ArgoBridge::pause_bridge(RuntimeOrigin::signed(account!(2))).unwrap();
+
+        let result = request_creator_token_outbound_transfer(1000);
+
+        assert_err!(result, Error::<Test>::BridgeNotActive);
+    });
+}
+
+#[test]
+fn finalize_creator_token_inbound_transfer_success() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        request_creator_token_outbound_transfer(1000).unwrap();
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: 1 };
+
+        let result = ArgoBridge::finalize_creator_token_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            remote_transfer.clone(),
+            1,
+            account!(4),
+            600,
+        );
+
+        assert_ok!(result);
+        assert_eq!(ArgoBridge::bridgeable_token(1).unwrap().mint_allowance, 400);
+        assert_eq!(TestCreatorTokens::balance(1, account!(4)), 600);
+        assert_eq!(TestCreatorTokens::escrowed(1), 400);
+        last_event_eq!(RawEvent::CreatorTokenInboundTransferFinalized(
+            remote_transfer,
+            1,
+            account!(4),
+            600
+        ));
+    });
+}
+
+#[test]
+fn finalize_creator_token_inbound_transfer_with_bridging_disabled_by_issuer() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        request_creator_token_outbound_transfer(1000).unwrap();
+        TestCreatorTokens::set_bridging_enabled(1, false);
+
+        let result = ArgoBridge::finalize_creator_token_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            1,
+            account!(4),
+            1000,
+        );
+
+        assert_ok!(result);
+        assert_eq!(TestCreatorTokens::balance(1, account!(4)), 1000);
+    });
+}
+
+#[test]
+fn finalize_creator_token_inbound_transfer_with_unauthorized_account() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        request_creator_token_outbound_transfer(1000).unwrap();
+
+        let result = ArgoBridge::finalize_creator_token_inbound_transfer(
+            RuntimeOrigin::signed(account!(2)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            1,
+            account!(4),
+            1000,
+        );
+
+        assert_err!(result, Error::<Test>::NotOperatorAccount);
+    });
+}
+
+#[test]
+fn finalize_creator_token_inbound_transfer_with_insufficient_token_mint_allowance() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        request_creator_token_outbound_transfer(1000).unwrap();
+
+        let result = ArgoBridge::finalize_creator_token_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            1,
+            account!(4),
+            1001,
+        );
+
+        assert_err!(result, Error::<Test>::InsufficientTokenMintAllowance);
+    });
+}
+
+#[test]
+fn finalize_creator_token_inbound_transfer_with_token_bridge_paused() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        request_creator_token_outbound_transfer(1000).unwrap();
+        let parameters = BridgeableTokenConstraints {
+            bridging_fee: None,
+            paused: Some(true),
+        };
+        ArgoBridge::update_bridgeable_token(RuntimeOrigin::root(), 1, parameters).unwrap();
+
+        let result = ArgoBridge::finalize_creator_token_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            1,
+            account!(4),
+            1000,
+        );
+
+        assert_err!(result, Error::<Test>::TokenBridgePaused);
+    });
+}
+
+#[test]
+fn revert_creator_token_outbound_transfer_success() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        request_creator_token_outbound_transfer(1000).unwrap();
+        let rationale: BoundedVec<u8, _> = vec![0u8; 10].try_into().unwrap();
+
+        let result = ArgoBridge::revert_creator_token_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            0,
+            1,
+            account!(1),
+            1000,
+            rationale.clone(),
+        );
+
+        assert_ok!(result);
+        assert_eq!(ArgoBridge::bridgeable_token(1).unwrap().mint_allowance, 0);
+        assert_eq!(TestCreatorTokens::balance(1, account!(1)), 1000);
+        assert_eq!(TestCreatorTokens::escrowed(1), 0);
+        last_event_eq!(RawEvent::CreatorTokenOutboundTransferReverted(
+            0,
+            1,
+            account!(1),
+            1000,
+            rationale
+        ));
+    });
+}
+
+#[test]
+fn finalize_creator_token_inbound_transfer_with_already_processed_transfer() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        request_creator_token_outbound_transfer(1000).unwrap();
+        ArgoBridge::finalize_creator_token_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            1,
+            account!(4),
+            500,
+        )
+        .unwrap();
+
+        let result = ArgoBridge::finalize_creator_token_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            1,
+            account!(4),
+            500,
+        );
+
+        assert_err!(result, Error::<Test>::InboundTransferAlreadyProcessed);
+    });
+}
+
+#[test]
+fn revert_creator_token_outbound_transfer_with_joy_transfer() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        let transfer_id = request_outbound_transfer(account!(1), joy!(100));
+
+        let result = ArgoBridge::revert_creator_token_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            transfer_id,
+            1,
+            account!(1),
+            100,
+            vec![].try_into().unwrap(),
+        );
+
+        assert_err!(result, Error::<Test>::OutboundTransferAssetMismatch);
+    });
+}
+
+#[test]
+fn revert_creator_token_outbound_transfer_with_amount_exceeding_transfer_amount() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        TestCreatorTokens::set_balance(1, account!(1), 2000);
+        request_creator_token_outbound_transfer(1000).unwrap();
+        request_creator_token_outbound_transfer(1000).unwrap();
+
+        let result = ArgoBridge::revert_creator_token_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            0,
+            1,
+            account!(1),
+            1001,
+            vec![].try_into().unwrap(),
+        );
+
+        assert_err!(result, Error::<Test>::RevertAmountExceedsTransferAmount);
+    });
+}
+
+#[test]
+fn revert_creator_token_outbound_transfer_with_insufficient_token_mint_allowance() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        request_creator_token_outbound_transfer(1000).unwrap();
+        ArgoBridge::finalize_creator_token_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            1,
+            account!(4),
+            1000,
+        )
+        .unwrap();
+
+        let result = ArgoBridge::revert_creator_token_outbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            0,
+            1,
+            account!(1),
+            1000,
+            vec![0u8; 10].try_into().unwrap(),
+        );
+
+        assert_err!(result, Error::<Test>::InsufficientTokenMintAllowance);
+    });
+}
+
+#[test]
+fn attest_inbound_transfer_success() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        setup_relayers(vec![account!(4), account!(5), account!(6)], 2);
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: 1 };
+        let transfer = InboundTransfer::Joy {
+            dest_account: account!(7),
+            amount: joy!(1000),
+        };
+        ArgoBridge::attest_inbound_transfer(
+            RuntimeOrigin::signed(account!(4)),
+            remote_transfer.clone(),
+            transfer.clone(),
+        )
+        .unwrap();
+
+        let result = ArgoBridge::attest_inbound_transfer(
+            RuntimeOrigin::signed(account!(6)),
+            remote_transfer.clone(),
+            transfer,
+        );
+
+        assert_ok!(result);
+        assert_eq!(Balances::free_balance(account!(7)), joy!(1000));
+        assert!(ArgoBridge::inbound_transfer_attestation(1, 0).is_none());
+        assert!(ArgoBridge::processed_inbound_transfer(1, 0));
+        last_event_eq!(RawEvent::InboundTransferFinalized(
+            remote_transfer,
+            account!(7),
+            joy!(1000)
+        ));
+    });
+}
+
+#[test]
+fn attest_inbound_transfer_below_relayer_threshold() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        setup_relayers(vec![account!(4), account!(5), account!(6)], 2);
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: 1 };
+        let transfer = InboundTransfer::Joy {
+            dest_account: account!(7),
+            amount: joy!(1000),
+        };
+
+        let result = ArgoBridge::attest_inbound_transfer(
+            RuntimeOrigin::signed(account!(4)),
+            remote_transfer.clone(),
+            transfer.clone(),
+        );
+
+        assert_ok!(result);
+        assert_eq!(Balances::free_balance(account!(7)), joy!(0));
+        let attestation = ArgoBridge::inbound_transfer_attestation(1, 0).unwrap();
+        assert_eq!(attestation.transfer, transfer);
+        assert_eq!(attestation.relayers.into_inner(), vec![account!(4)]);
+        last_event_eq!(RawEvent::InboundTransferAttested(
+            remote_transfer,
+            account!(4),
+            transfer
+        ));
+    });
+}
+
+#[test]
+fn attest_creator_token_inbound_transfer_success() {
+    with_test_externalities(|| {
+        setup_bridgeable_token(joy!(10));
+        request_creator_token_outbound_transfer(1000).unwrap();
+        setup_relayers(vec![account!(4), account!(5)], 2);
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: 1 };
+        let transfer = InboundTransfer::CreatorToken {
+            token_id: 1,
+            dest_member_id: account!(7),
+            amount: 600,
+        };
+        ArgoBridge::attest_inbound_transfer(
+            RuntimeOrigin::signed(account!(4)),
+            remote_transfer.clone(),
+            transfer.clone(),
+        )
+        .unwrap();
+
+        let result = ArgoBridge::attest_inbound_transfer(
+            RuntimeOrigin::signed(account!(5)),
+            remote_transfer.clone(),
+            transfer,
+        );
+
+        assert_ok!(result);
+        assert_eq!(TestCreatorTokens::balance(1, account!(7)), 600);
+        assert_eq!(ArgoBridge::bridgeable_token(1).unwrap().mint_allowance, 400);
+        last_event_eq!(RawEvent::CreatorTokenInboundTransferFinalized(
+            remote_transfer,
+            1,
+            account!(7),
+            600
+        ));
+    });
+}
+
+#[test]
+fn attest_inbound_transfer_with_conflicting_transfer() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        setup_relayers(vec![account!(4), account!(5), account!(6)], 2);
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: 1 };
+        ArgoBridge::attest_inbound_transfer(
+            RuntimeOrigin::signed(account!(4)),
+            remote_transfer.clone(),
+            InboundTransfer::Joy {
+                dest_account: account!(7),
+                amount: joy!(1000),
+            },
+        )
+        .unwrap();
+        let conflicting_transfer = InboundTransfer::Joy {
+            dest_account: account!(8),
+            amount: joy!(1000),
+        };
+
+        let result = ArgoBridge::attest_inbound_transfer(
+            RuntimeOrigin::signed(account!(5)),
+            remote_transfer.clone(),
+            conflicting_transfer.clone(),
+        );
+
+        assert_ok!(result);
+        assert_eq!(ArgoBridge::status(), BridgeStatus::Paused);
+        assert!(ArgoBridge::inbound_transfer_attestation(1, 0).is_none());
+        assert_eq!(Balances::free_balance(account!(7)), joy!(0));
+        assert_eq!(Balances::free_balance(account!(8)), joy!(0));
+        last_event_eq!(RawEvent::InboundTransferAttestationConflict(
+            remote_transfer,
+            account!(5),
+            conflicting_transfer
+        ));
+    });
+}
+
+#[test]
+fn attest_inbound_transfer_with_already_attested_transfer() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        setup_relayers(vec![account!(4), account!(5), account!(6)], 2);
+        let transfer = InboundTransfer::Joy {
+            dest_account: account!(7),
+            amount: joy!(1000),
+        };
+        ArgoBridge::attest_inbound_transfer(
+            RuntimeOrigin::signed(account!(4)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            transfer.clone(),
+        )
+        .unwrap();
+
+        let result = ArgoBridge::attest_inbound_transfer(
+            RuntimeOrigin::signed(account!(4)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            transfer,
+        );
+
+        assert_err!(result, Error::<Test>::InboundTransferAlreadyAttested);
+    });
+}
+
+#[test]
+fn attest_inbound_transfer_with_unauthorized_account() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        setup_relayers(vec![account!(4), account!(5)], 2);
+
+        let result = ArgoBridge::attest_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            InboundTransfer::Joy {
+                dest_account: account!(7),
+                amount: joy!(1000),
+            },
+        );
+
+        assert_err!(result, Error::<Test>::NotRelayerAccount);
+    });
+}
+
+#[test]
+fn attest_inbound_transfer_with_relayer_threshold_not_set() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        setup_relayers(vec![account!(4), account!(5)], 0);
+
+        let result = ArgoBridge::attest_inbound_transfer(
+            RuntimeOrigin::signed(account!(4)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            InboundTransfer::Joy {
+                dest_account: account!(7),
+                amount: joy!(1000),
+            },
+        );
+
+        assert_err!(result, Error::<Test>::RelayerThresholdNotSet);
+    });
+}
+
+#[test]
+fn finalize_inbound_transfer_with_relayer_threshold_set() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        setup_relayers(vec![account!(4), account!(5)], 1);
+
+        let result = ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            RemoteTransfer { id: 0, chain_id: 1 },
+            account!(7),
+            joy!(1000),
+        );
+
+        assert_err!(result, Error::<Test>::RelayerAttestationRequired);
+    });
+}
+
+#[test]
+fn update_bridge_constrains_with_relayer_threshold_over_relayers_number() {
+    with_test_externalities(|| {
+        let parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: None,
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            relayer_accounts: Some(vec![account!(4), account!(5)]),
+            relayer_threshold: Some(3),
+            mint_limits: None,
+        };
+
+        let result = ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters);
+
+        assert_err!(result, Error::<Test>::InvalidRelayerThreshold);
+    });
+}
+
+#[test]
+fn update_bridge_constrains_with_duplicate_relayer_account() {
+    with_test_externalities(|| {
+        let parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: None,
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            relayer_accounts: Some(vec![account!(4), account!(4)]),
+            relayer_threshold: Some(1),
+            mint_limits: None,
+        };
+
+        let result = ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters);
+
+        assert_err!(result, Error::<Test>::DuplicateRelayerAccount);
+    });
+}
+
+#[test]
+fn update_bridge_constrains_with_too_many_relayer_accounts() {
+    with_test_externalities(|| {
+        let parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: None,
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            relayer_accounts: Some((0..=MAX_RELAYER_ACCOUNTS as u64).collect()),
+            relayer_threshold: None,
+            mint_limits: None,
+        };
+
+        let result = ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters);
+
+        assert_err!(result, Error::<Test>::InvalidNumberOfRelayerAccounts);
+    });
+}
+
+#[test]
+fn update_bridge_constrains_removing_relayers_below_threshold() {
+    with_test_externalities(|| {
+        setup_relayers(vec![account!(4), account!(5)], 2);
+        let parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: None,
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            relayer_accounts: Some(vec![account!(4)]),
+            relayer_threshold: None,
+            mint_limits: None,
+        };
+
+        let result = ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters);
+
+        assert_err!(result, Error::<Test>::InvalidRelayerThreshold);
+    });
+}
+
+#[test]
+fn finalize_inbound_transfer_above_max_transfer_amount() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        set_mint_limits(MintLimits {
+            max_transfer_amount: Some(joy!(500)),
+            ..Default::default()
+        });
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: 1 };
+
+        let result = ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            remote_transfer.clone(),
+            account!(7),
+            joy!(501),
+        );
+
+        assert_ok!(result);
+        assert_eq!(Balances::free_balance(account!(7)), joy!(0));
+        assert_eq!(ArgoBridge::mint_allowance(), joy!(1000));
+        assert!(!ArgoBridge::processed_inbound_transfer(1, 0));
+        last_event_eq!(RawEvent::InboundTransferBlocked(
+            remote_transfer,
+            account!(7),
+            joy!(501)
+        ));
+    });
+}
+
+#[test]
+fn finalize_inbound_transfer_exceeding_window_mint_limit() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        set_mint_limits(MintLimits {
+            window: 10,
+            max_minted_per_window: Some(joy!(500)),
+            ..Default::default()
+        });
+        finalize_inbound_transfer(0, 1, joy!(300)).unwrap();
+        let remote_transfer = RemoteTransfer { id: 1, chain_id: 1 };
+
+        let result = ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            remote_transfer.clone(),
+            account!(7),
+            joy!(300),
+        );
+
+        assert_ok!(result);
+        assert_eq!(Balances::free_balance(account!(7)), joy!(300));
+        assert!(!ArgoBridge::processed_inbound_transfer(1, 1));
+        last_event_eq!(RawEvent::InboundTransferBlocked(
+            remote_transfer,
+            account!(7),
+            joy!(300)
+        ));
+    });
+}
+
+#[test]
+fn finalize_inbound_transfer_with_window_mint_limit_partially_released() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        set_mint_limits(MintLimits {
+            window: 10,
+            max_minted_per_window: Some(joy!(500)),
+            ..Default::default()
+        });
+        finalize_inbound_transfer(0, 1, joy!(500)).unwrap();
+        System::set_block_number(System::block_number() + 5);
+
+        finalize_inbound_transfer(1, 1, joy!(250)).unwrap();
+        assert_eq!(Balances::free_balance(account!(7)), joy!(750));
+        assert_eq!(ArgoBridge::minted_in_window().amount, joy!(500));
+
+        finalize_inbound_transfer(2, 1, joy!(1)).unwrap();
+        assert_eq!(Balances::free_balance(account!(7)), joy!(750));
+    });
+}
+
+#[test]
+fn finalize_inbound_transfer_exceeding_chain_window_mint_limit() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        update_remote_chains(vec![1, 2]);
+        set_mint_limits(MintLimits {
+            window: 10,
+            max_minted_per_chain_per_window: Some(joy!(500)),
+            ..Default::default()
+        });
+        finalize_inbound_transfer(0, 1, joy!(500)).unwrap();
+
+        finalize_inbound_transfer(1, 1, joy!(1)).unwrap();
+        assert!(!ArgoBridge::processed_inbound_transfer(1, 1));
+
+        finalize_inbound_transfer(0, 2, joy!(500)).unwrap();
+        assert!(ArgoBridge::processed_inbound_transfer(2, 0));
+        assert_eq!(Balances::free_balance(account!(7)), joy!(1000));
+    });
+}
+
+#[test]
+fn finalize_inbound_transfer_above_delayed_transfer_threshold() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        set_mint_limits(MintLimits {
+            delayed_transfer_threshold: Some(joy!(100)),
+            transfer_delay: 5,
+            ..Default::default()
+        });
+        let remote_transfer = RemoteTransfer { id: 0, chain_id: 1 };
+
+        let result = ArgoBridge::finalize_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            remote_transfer.clone(),
+            account!(7),
+            joy!(200),
+        );
+
+        assert_ok!(result);
+        let release_at = System::block_number() + 5;
+        assert_eq!(Balances::free_balance(account!(7)), joy!(0));
+        assert_eq!(ArgoBridge::mint_allowance(), joy!(800));
+        assert!(ArgoBridge::processed_inbound_transfer(1, 0));
+        assert_eq!(
+            ArgoBridge::delayed_inbound_transfer(1, 0),
+            Some(DelayedInboundTransfer {
+                dest_account: account!(7),
+                amount: joy!(200),
+                release_at,
+            })
+        );
+        last_event_eq!(RawEvent::InboundTransferQueued(
+            remote_transfer,
+            account!(7),
+            joy!(200),
+            release_at
+        ));
+    });
+}
+
+#[test]
+fn release_delayed_inbound_transfer_success() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        let remote_transfer = queue_delayed_inbound_transfer(joy!(200));
+        System::set_block_number(System::block_number() + 5);
+
+        let result = ArgoBridge::release_delayed_inbound_transfer(
+            RuntimeOrigin::signed(account!(8)),
+            remote_transfer.clone(),
+        );
+
+        assert_ok!(result);
+        assert_eq!(Balances::free_balance(account!(7)), joy!(200));
+        assert!(ArgoBridge::delayed_inbound_transfer(1, 0).is_none());
+        last_event_eq!(RawEvent::DelayedInboundTransferReleased(
+            remote_transfer,
+            account!(7),
+            joy!(200)
+        ));
+    });
+}
+
+#[test]
+fn release_delayed_inbound_transfer_with_delay_not_elapsed() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        let remote_transfer = queue_delayed_inbound_transfer(joy!(200));
+        System::set_block_number(System::block_number() + 4);
+
+        let result = ArgoBridge::release_delayed_inbound_transfer(
+            RuntimeOrigin::signed(account!(8)),
+            remote_transfer,
+        );
+
+        assert_err!(result, Error::<Test>::InboundTransferDelayNotElapsed);
+    });
+}
+
+#[test]
+fn release_delayed_inbound_transfer_with_bridge_paused() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        let remote_transfer = queue_delayed_inbound_transfer(joy!(200));
+        System::set_block_number(System::block_number() + 5);
+        ArgoBridge::pause_bridge(RuntimeOrigin::signed(account!(2))).unwrap();
+
+        let result = ArgoBridge::release_delayed_inbound_transfer(
+            RuntimeOrigin::signed(account!(8)),
+            remote_transfer,
+        );
+
+        assert_err!(result, Error::<Test>::BridgeNotActive);
+    });
+}
+
+#[test]
+fn cancel_delayed_inbound_transfer_success() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        let remote_transfer = queue_delayed_inbound_transfer(joy!(200));
+
+        let result = ArgoBridge::cancel_delayed_inbound_transfer(
+            RuntimeOrigin::signed(account!(2)),
+            remote_transfer.clone(),
+        );
+
+        assert_ok!(result);
+        assert_eq!(ArgoBridge::mint_allowance(), joy!(1000));
+        assert!(ArgoBridge::delayed_inbound_transfer(1, 0).is_none());
+        last_event_eq!(RawEvent::DelayedInboundTransferCancelled(
+            remote_transfer,
+            account!(2)
+        ));
+    });
+}
+
+#[test]
+fn cancel_delayed_inbound_transfer_with_unauthorized_account() {
+    with_test_externalities_custom_mint_allowance(joy!(1000), || {
+        setup_active_bridge();
+        let remote_transfer = queue_delayed_inbound_transfer(joy!(200));
+
+        let result = ArgoBridge::cancel_delayed_inbound_transfer(
+            RuntimeOrigin::signed(account!(1)),
+            remote_transfer,
+        );
+
+        assert_err!(result, Error::<Test>::NotPauserAccount);
+    });
+}
+
+#[test]
+fn update_bridge_constrains_with_window_mint_limit_and_zero_window() {
+    with_test_externalities(|| {
+        let parameters = BridgeConstraints {
+            operator_account: None,
+            pauser_accounts: None,
+            bridging_fee: None,
+            thawn_duration: None,
+            remote_chains: None,
+            relayer_accounts: None,
+            relayer_threshold: None,
+            mint_limits: Some(MintLimits {
+                max_minted_per_chain_per_window: Some(joy!(500)),
+                ..Default::default()
+            }),
+        };
+
+        let result = ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters);
+
+        assert_err!(result, Error::<Test>::InvalidMintLimitsWindow);
+    });
+}
+
+// Activates the bridge with `account!(1)` as operator, `account!(2)` as pauser and chain 1 supported
+fn setup_active_bridge() {
+    let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+    let parameters = BridgeConstraints {
+        operator_account: Some(account!(1)),
+        pauser_accounts: Some(vec![account!(2)]),
+        bridging_fee: None,
+        thawn_duration: None,
+        remote_chains: Some(remote_chains),
+        relayer_accounts: None,
+        relayer_threshold: None,
+        mint_limits: None,
+    };
+    ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+    activate_bridge(account!(2), account!(1)).unwrap();
+}
+
+fn setup_relayers(relayer_accounts: Vec<AccountId>, relayer_threshold: u32) {
+    let parameters = BridgeConstraints {
+        operator_account: None,
+        pauser_accounts: None,
+        bridging_fee: None,
+        thawn_duration: None,
+        remote_chains: None,
+        relayer_accounts: Some(relayer_accounts),
+        relayer_threshold: Some(relayer_threshold),
+        mint_limits: None,
+    };
+    ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+}
+
+fn set_mint_limits(mint_limits: MintLimits<Balance, BlockNumber>) {
+    let parameters = BridgeConstraints {
+        operator_account: None,
+        pauser_accounts: None,
+        bridging_fee: None,
+        thawn_duration: None,
+        remote_chains: None,
+        relayer_accounts: None,
+        relayer_threshold: None,
+        mint_limits: Some(mint_limits),
+    };
+    ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+}
+
+fn update_remote_chains(remote_chains: Vec<ChainId>) {
+    let parameters = BridgeConstraints {
+        operator_account: None,
+        pauser_accounts: None,
+        bridging_fee: None,
+        thawn_duration: None,
+        remote_chains: Some(BoundedVec::try_from(remote_chains).unwrap()),
+        relayer_accounts: None,
+        relayer_threshold: None,
+        mint_limits: None,
+    };
+    ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+}
+
+// Finalizes an inbound transfer to `account!(7)` as the operator
+fn finalize_inbound_transfer(id: TransferId, chain_id: ChainId, amount: Balance) -> DispatchResult {
+    ArgoBridge::finalize_inbound_transfer(
+        RuntimeOrigin::signed(account!(1)),
+        RemoteTransfer { id, chain_id },
+        account!(7),
+        amount,
+    )
+}
+
+// Queues an inbound transfer to `account!(7)` releasable in 5 blocks
+fn queue_delayed_inbound_transfer(amount: Balance) -> RemoteTransfer {
+    set_mint_limits(MintLimits {
+        delayed_transfer_threshold: Some(joy!(0)),
+        transfer_delay: 5,
+        ..Default::default()
+    });
+    finalize_inbound_transfer(0, 1, amount).unwrap();
+    RemoteTransfer { id: 0, chain_id: 1 }
+}
+
+fn request_outbound_transfer(sender: AccountId, amount: Balance) -> TransferId {
+    let fee = ArgoBridge::bridging_fee();
+    Balances::set_balance(
+        RuntimeOrigin::root(),
+        sender,
+        amount + fee + ExistentialDeposit::get(),
+        joy!(0),
+    )
+    .unwrap();
+    let transfer_id = ArgoBridge::next_transfer_id();
+    ArgoBridge::request_outbound_transfer(
+        RuntimeOrigin::signed(sender),
+        RemoteAccount {
+            account: [0; 32],
+            chain_id: 1,
+        },
+        amount,
+        fee,
+    )
+    .unwrap();
+    transfer_id
+}
+
+// Activates the bridge and approves token 1 (holding 1000 tokens at `account!(1)`) for bridging
+fn setup_bridgeable_token(fee: Balance) {
+    let remote_chains = BoundedVec::try_from(vec![1u32]).unwrap();
+    let parameters = BridgeConstraints {
+        operator_account: Some(account!(1)),
+        pauser_accounts: Some(vec![account!(2), account!(3)]),
+        bridging_fee: None,
+        thawn_duration: None,
+        remote_chains: Some(remote_chains),
+        relayer_accounts: None,
+        relayer_threshold: None,
+        mint_limits: None,
+    };
+    ArgoBridge::update_bridge_constrains(RuntimeOrigin::root(), parameters).unwrap();
+    activate_bridge(account!(2), account!(1)).unwrap();
+
+    Balances::set_balance(RuntimeOrigin::root(), account!(1), joy!(1000), joy!(0)).unwrap();
+    TestCreatorTokens::set_bridging_enabled(1, true);
+    TestCreatorTokens::set_balance(1, account!(1), 1000);
+    let token_parameters = BridgeableTokenConstraints {
+        bridging_fee: Some(fee),
+        paused: None,
+    };
+    ArgoBridge::update_bridgeable_token(RuntimeOrigin::root(), 1, token_parameters).unwrap();
+}
+
+fn request_creator_token_outbound_transfer(amount: TokenBalance) -> DispatchResult {
+    let fee = ArgoBridge::bridgeable_token(1).unwrap().bridging_fee;
+    ArgoBridge::request_creator_token_outbound_transfer(
+        RuntimeOrigin::signed(account!(1)),
+        1,
+        account!(1),
+        RemoteAccount {
+            account: [0; 32],
+            chain_id: 1,
+        },
+        amount,
+        fee,
+    )
+}
+
 pub fn activate_bridge(pauser_account_id: u64, operator_account_id: u64) -> DispatchResult {
     let pauser_origin = RuntimeOrigin::signed(pauser_account_id);
     ArgoBridge::init_unpause_bridge(pauser_origin)?;
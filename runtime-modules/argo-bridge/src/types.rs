@@ -1,4 +1,5 @@
 use codec::{Decode, Encode, MaxEncodedLen};
+use common::bridge::CreatorTokenBridge;
 use frame_support::{storage::bounded_vec::BoundedVec, traits::ConstU32};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
@@ -9,6 +10,17 @@ use sp_std::vec::Vec;
 // Balance type alias
 pub type BalanceOf<T> = <T as balances::Config>::Balance;
 
+// Creator token type aliases
+pub type TokenIdOf<T> = <<T as crate::Config>::CreatorTokens as CreatorTokenBridge<
+    <T as frame_system::Config>::AccountId,
+>>::TokenId;
+pub type TokenMemberIdOf<T> = <<T as crate::Config>::CreatorTokens as CreatorTokenBridge<
+    <T as frame_system::Config>::AccountId,
+>>::MemberId;
+pub type TokenBalanceOf<T> = <<T as crate::Config>::CreatorTokens as CreatorTokenBridge<
+    <T as frame_system::Config>::AccountId,
+>>::Balance;
+
 pub type ChainId = u32;
 pub type TransferId = u64;
 
@@ -16,6 +28,8 @@ pub const MAX_REMOTE_CHAINS: u32 = 10;
 
 pub const MAX_BYTES_RATIONALE: u32 = 200;
 
+pub const MAX_RELAYER_ACCOUNTS: u32 = 10;
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct BridgeConstraints<AccountId, Balance, BlockNumber> {
@@ -24,6 +38,9 @@ pub struct BridgeConstraints<AccountId, Balance, BlockNumber> {
     pub bridging_fee: Option<Balance>,
     pub thawn_duration: Option<BlockNumber>,
     pub remote_chains: Option<BoundedVec<ChainId, ConstU32<MAX_REMOTE_CHAINS>>>,
+    pub relayer_accounts: Option<Vec<AccountId>>,
+    pub relayer_threshold: Option<u32>,
+    pub mint_limits: Option<MintLimits<Balance, BlockNumber>>,
 }
 
 pub type BridgeConstraintsOf<T> = BridgeConstraints<
@@ -32,6 +49,74 @@ pub type BridgeConstraintsOf<T> = BridgeConstraints<
     <T as frame_system::Config>::BlockNumber,
 >;
 
+/// Limits on the JOY minted by inbound transfers, limits set to `None` are not enforced
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct MintLimits<Balance, BlockNumber> {
+    /// Number of blocks of the rolling window the minted amount limits apply to
+    pub window: BlockNumber,
+    /// Max amount minted within the window by all the remote chains
+    pub max_minted_per_window: Option<Balance>,
+    /// Max amount minted within the window by each remote chain
+    pub max_minted_per_chain_per_window: Option<Balance>,
+    /// Max amount of a single inbound transfer
+    pub max_transfer_amount: Option<Balance>,
+    /// Inbound transfers above this amount are queued for `transfer_delay` blocks
+    pub delayed_transfer_threshold: Option<Balance>,
+    /// Number of blocks a queued inbound transfer waits before it can be released
+    pub transfer_delay: BlockNumber,
+}
+
+pub type MintLimitsOf<T> = MintLimits<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Amount minted within the rolling window, it decreases linearly as the window moves on
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct MintedAmount<Balance, BlockNumber> {
+    pub amount: Balance,
+    pub updated_at: BlockNumber,
+}
+
+pub type MintedAmountOf<T> = MintedAmount<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Inbound transfer queued until the pausers had the chance to intervene
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct DelayedInboundTransfer<AccountId, Balance, BlockNumber> {
+    pub dest_account: AccountId,
+    pub amount: Balance,
+    pub release_at: BlockNumber,
+}
+
+pub type DelayedInboundTransferOf<T> = DelayedInboundTransfer<
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+/// Bridge parameters of a creator token approved by the governance
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct BridgeableToken<Balance, TokenBalance> {
+    /// Number of tokens that the bridge pallet is able to release
+    pub mint_allowance: TokenBalance,
+    /// Amount of JOY burned as a fee for each transfer
+    pub bridging_fee: Balance,
+    /// Whether the transfers of the token are paused
+    pub paused: bool,
+}
+
+pub type BridgeableTokenOf<T> = BridgeableToken<BalanceOf<T>, TokenBalanceOf<T>>;
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct BridgeableTokenConstraints<Balance> {
+    pub bridging_fee: Option<Balance>,
+    pub paused: Option<bool>,
+}
+
+pub type BridgeableTokenConstraintsOf<T> = BridgeableTokenConstraints<BalanceOf<T>>;
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct RemoteAccount {
@@ -48,6 +133,68 @@ pub struct RemoteTransfer {
     pub chain_id: ChainId,
 }
 
+/// Amount burned (JOY) or escrowed (creator token) by an outbound transfer
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum OutboundTransferAmount<Balance, TokenId, TokenBalance> {
+    Joy(Balance),
+    CreatorToken(TokenId, TokenBalance),
+}
+
+/// Outbound transfer that can still be reverted by the operator
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PendingOutboundTransfer<Balance, TokenId, TokenBalance, BlockNumber> {
+    pub amount: OutboundTransferAmount<Balance, TokenId, TokenBalance>,
+    pub requested_at: BlockNumber,
+}
+
+pub type PendingOutboundTransferOf<T> = PendingOutboundTransfer<
+    BalanceOf<T>,
+    TokenIdOf<T>,
+    TokenBalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+/// Inbound transfer attested by the relayers
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum InboundTransfer<AccountId, Balance, TokenId, MemberId, TokenBalance> {
+    Joy {
+        dest_account: AccountId,
+        amount: Balance,
+    },
+    CreatorToken {
+        token_id: TokenId,
+        dest_member_id: MemberId,
+        amount: TokenBalance,
+    },
+}
+
+pub type InboundTransferOf<T> = InboundTransfer<
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T>,
+    TokenIdOf<T>,
+    TokenMemberIdOf<T>,
+    TokenBalanceOf<T>,
+>;
+
+/// Relayer attestations of an inbound transfer not yet finalized
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct InboundTransferAttestation<AccountId, Balance, TokenId, MemberId, TokenBalance> {
+    pub transfer: InboundTransfer<AccountId, Balance, TokenId, MemberId, TokenBalance>,
+    pub relayers: BoundedVec<AccountId, ConstU32<MAX_RELAYER_ACCOUNTS>>,
+}
+
+pub type InboundTransferAttestationOf<T> = InboundTransferAttestation<
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T>,
+    TokenIdOf<T>,
+    TokenMemberIdOf<T>,
+    TokenBalanceOf<T>,
+>;
+
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub enum BridgeStatus<BlockNumber> {
@@ -51,6 +51,14 @@ pub trait WeightInfo {
 	fn init_unpause_bridge() -> Weight;
 	fn finish_unpause_bridge() -> Weight;
 	fn update_bridge_constrains() -> Weight;
+	fn request_creator_token_outbound_transfer() -> Weight;
+	fn finalize_creator_token_inbound_transfer() -> Weight;
+	fn revert_creator_token_outbound_transfer() -> Weight;
+	fn update_bridgeable_token() -> Weight;
+	fn on_initialize(_a: u32, _c: u32, ) -> Weight;
+	fn attest_inbound_transfer() -> Weight;
+	fn release_delayed_inbound_transfer() -> Weight;
+	fn cancel_delayed_inbound_transfer() -> Weight;
 }
 
 /// Weights for argo_bridge using the Substrate node and recommended hardware.
@@ -106,15 +114,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: ArgoBridge MintAllowance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RelayerThreshold (r:1 w:0)
+	// Proof: ArgoBridge RelayerThreshold (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ArgoBridge MintLimits (r:1 w:0)
+	// Proof: ArgoBridge MintLimits (max_values: Some(1), max_size: Some(76), added: 571, mode: MaxEncodedLen)
+	// Storage: ArgoBridge MintedInWindow (r:1 w:1)
+	// Proof: ArgoBridge MintedInWindow (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	// Storage: ArgoBridge ChainMintedInWindow (r:1 w:1)
+	// Proof: ArgoBridge ChainMintedInWindow (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+	// Storage: ArgoBridge InboundTransfersWatermark (r:1 w:1)
+	// Proof: ArgoBridge InboundTransfersWatermark (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ArgoBridge ProcessedInboundTransfers (r:1 w:1)
+	// Proof: ArgoBridge ProcessedInboundTransfers (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
 	fn finalize_inbound_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `473`
-		//  Estimated: `9627`
-		// Minimum execution time: 37_314 nanoseconds.
-		Weight::from_parts(38_704_000, 0u64)
-			.saturating_add(Weight::from_parts(0, 9627))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		//  Measured:  `561`
+		//  Estimated: `18750`
+		// Minimum execution time: 49_372 nanoseconds.
+		Weight::from_parts(51_014_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 18750))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	// Storage: ArgoBridge PauserAccounts (r:1 w:0)
 	// Proof: ArgoBridge PauserAccounts (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
@@ -170,15 +190,220 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: ArgoBridge BridgingFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	// Storage: ArgoBridge ThawnDuration (r:0 w:1)
 	// Proof: ArgoBridge ThawnDuration (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RelayerAccounts (r:1 w:1)
+	// Proof: ArgoBridge RelayerAccounts (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RelayerThreshold (r:1 w:1)
+	// Proof: ArgoBridge RelayerThreshold (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ArgoBridge MintLimits (r:0 w:1)
+	// Proof: ArgoBridge MintLimits (max_values: Some(1), max_size: Some(76), added: 571, mode: MaxEncodedLen)
 	fn update_bridge_constrains() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 17_157 nanoseconds.
-		Weight::from_parts(17_598_000, 0u64)
-			.saturating_add(Weight::from_parts(0, 0))
+		//  Estimated: `1315`
+		// Minimum execution time: 17_902 nanoseconds.
+		Weight::from_parts(18_987_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 1315))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	// Storage: ArgoBridge Status (r:1 w:0)
+	// Proof: ArgoBridge Status (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RemoteChains (r:1 w:0)
+	// Proof: ArgoBridge RemoteChains (max_values: Some(1), max_size: Some(41), added: 536, mode: MaxEncodedLen)
+	// Storage: ArgoBridge BridgeableTokens (r:1 w:1)
+	// Proof: ArgoBridge BridgeableTokens (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Token TokenBridgingById (r:1 w:1)
+	// Proof: Token TokenBridgingById (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ArgoBridge NextTransferId (r:1 w:1)
+	// Proof: ArgoBridge NextTransferId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Token PalletFrozen (r:1 w:0)
+	// Proof: Token PalletFrozen (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Proof: Token TokenInfoById (max_values: None, max_size: Some(352), added: 2827, mode: MaxEncodedLen)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	// Proof: Token AccountInfoByTokenAndMember (max_values: None, max_size: Some(489), added: 2964, mode: MaxEncodedLen)
+	fn request_creator_token_outbound_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1247`
+		//  Estimated: `18077`
+		// Minimum execution time: 98_412 nanoseconds.
+		Weight::from_parts(101_236_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 18077))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	// Storage: ArgoBridge Status (r:1 w:0)
+	// Proof: ArgoBridge Status (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
+	// Storage: ArgoBridge OperatorAccount (r:1 w:0)
+	// Proof: ArgoBridge OperatorAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RemoteChains (r:1 w:0)
+	// Proof: ArgoBridge RemoteChains (max_values: Some(1), max_size: Some(41), added: 536, mode: MaxEncodedLen)
+	// Storage: ArgoBridge BridgeableTokens (r:1 w:1)
+	// Proof: ArgoBridge BridgeableTokens (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Token PalletFrozen (r:1 w:0)
+	// Proof: Token PalletFrozen (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Proof: Token TokenInfoById (max_values: None, max_size: Some(352), added: 2827, mode: MaxEncodedLen)
+	// Storage: Token TokenBridgingById (r:1 w:1)
+	// Proof: Token TokenBridgingById (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	// Proof: Token AccountInfoByTokenAndMember (max_values: None, max_size: Some(489), added: 2964, mode: MaxEncodedLen)
+	// Storage: ArgoBridge InboundTransfersWatermark (r:1 w:1)
+	// Proof: ArgoBridge InboundTransfersWatermark (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ArgoBridge ProcessedInboundTransfers (r:1 w:1)
+	// Proof: ArgoBridge ProcessedInboundTransfers (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	fn finalize_creator_token_inbound_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1109`
+		//  Estimated: `17921`
+		// Minimum execution time: 66_902 nanoseconds.
+		Weight::from_parts(68_735_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 17921))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: ArgoBridge Status (r:1 w:0)
+	// Proof: ArgoBridge Status (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
+	// Storage: ArgoBridge OperatorAccount (r:1 w:0)
+	// Proof: ArgoBridge OperatorAccount (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	// Storage: ArgoBridge BridgeableTokens (r:1 w:1)
+	// Proof: ArgoBridge BridgeableTokens (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Token PalletFrozen (r:1 w:0)
+	// Proof: Token PalletFrozen (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Proof: Token TokenInfoById (max_values: None, max_size: Some(352), added: 2827, mode: MaxEncodedLen)
+	// Storage: Token TokenBridgingById (r:1 w:1)
+	// Proof: Token TokenBridgingById (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	// Proof: Token AccountInfoByTokenAndMember (max_values: None, max_size: Some(489), added: 2964, mode: MaxEncodedLen)
+	fn revert_creator_token_outbound_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1076`
+		//  Estimated: `12362`
+		// Minimum execution time: 60_317 nanoseconds.
+		Weight::from_parts(62_104_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 12362))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: ArgoBridge BridgeableTokens (r:1 w:1)
+	// Proof: ArgoBridge BridgeableTokens (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Token TokenBridgingById (r:1 w:0)
+	// Proof: Token TokenBridgingById (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	fn update_bridgeable_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `298`
+		//  Estimated: `5048`
+		// Minimum execution time: 19_782 nanoseconds.
+		Weight::from_parts(20_611_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 5048))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: ArgoBridge NextTransferId (r:1 w:0)
+	// Proof: ArgoBridge NextTransferId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ArgoBridge NextPrunedOutboundTransferId (r:1 w:1)
+	// Proof: ArgoBridge NextPrunedOutboundTransferId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ArgoBridge PendingOutboundTransfers (r:51 w:50)
+	// Proof: ArgoBridge PendingOutboundTransfers (max_values: None, max_size: Some(74), added: 2549, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RemoteChains (r:1 w:0)
+	// Proof: ArgoBridge RemoteChains (max_values: Some(1), max_size: Some(41), added: 536, mode: MaxEncodedLen)
+	// Storage: ArgoBridge InboundTransfersWatermark (r:10 w:0)
+	// Proof: ArgoBridge InboundTransfersWatermark (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ArgoBridge NextPrunedInboundTransferId (r:10 w:10)
+	// Proof: ArgoBridge NextPrunedInboundTransferId (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	/// The range of component `a` is `[0, 50]`.
+	/// The range of component `c` is `[0, 10]`.
+	fn on_initialize(a: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `411 + a * (79 ±0) + c * (4 ±0)`
+		//  Estimated: `3053 + a * (2549 ±0) + c * (5006 ±0)`
+		// Minimum execution time: 14_273 nanoseconds.
+		Weight::from_parts(12_918_407, 0u64)
+			.saturating_add(Weight::from_parts(0, 3053))
+			// Standard Error: 85_842
+			.saturating_add(Weight::from_parts(4_893_000, 0u64).saturating_mul(a.into()))
+			// Standard Error: 431_907
+			.saturating_add(Weight::from_parts(3_516_000, 0u64).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2549).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 5006).saturating_mul(c.into()))
+	}
+	// Storage: ArgoBridge Status (r:1 w:0)
+	// Proof: ArgoBridge Status (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RelayerAccounts (r:1 w:0)
+	// Proof: ArgoBridge RelayerAccounts (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RelayerThreshold (r:1 w:0)
+	// Proof: ArgoBridge RelayerThreshold (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ArgoBridge RemoteChains (r:1 w:0)
+	// Proof: ArgoBridge RemoteChains (max_values: Some(1), max_size: Some(41), added: 536, mode: MaxEncodedLen)
+	// Storage: ArgoBridge InboundTransfersWatermark (r:1 w:1)
+	// Proof: ArgoBridge InboundTransfersWatermark (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ArgoBridge ProcessedInboundTransfers (r:1 w:1)
+	// Proof: ArgoBridge ProcessedInboundTransfers (max_values: None, max_size: Some(45), added: 2520, mode: MaxEncodedLen)
+	// Storage: ArgoBridge InboundTransferAttestations (r:1 w:1)
+	// Proof: ArgoBridge InboundTransferAttestations (max_values: None, max_size: Some(414), added: 2889, mode: MaxEncodedLen)
+	// Storage: ArgoBridge BridgeableTokens (r:1 w:1)
+	// Proof: ArgoBridge BridgeableTokens (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Token PalletFrozen (r:1 w:0)
+	// Proof: Token PalletFrozen (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Proof: Token TokenInfoById (max_values: None, max_size: Some(352), added: 2827, mode: MaxEncodedLen)
+	// Storage: Token TokenBridgingById (r:1 w:1)
+	// Proof: Token TokenBridgingById (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	// Proof: Token AccountInfoByTokenAndMember (max_values: None, max_size: Some(489), added: 2964, mode: MaxEncodedLen)
+	fn attest_inbound_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1742`
+		//  Estimated: `21598`
+		// Minimum execution time: 98_413 nanoseconds.
+		Weight::from_parts(101_256_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 21598))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: ArgoBridge Status (r:1 w:0)
+	// Proof: ArgoBridge Status (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
+	// Storage: ArgoBridge DelayedInboundTransfers (r:1 w:1)
+	// Proof: ArgoBridge DelayedInboundTransfers (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn release_delayed_inbound_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `5674`
+		// Minimum execution time: 31_207 nanoseconds.
+		Weight::from_parts(32_481_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 5674))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: ArgoBridge PauserAccounts (r:1 w:0)
+	// Proof: ArgoBridge PauserAccounts (max_values: Some(1), max_size: Some(321), added: 816, mode: MaxEncodedLen)
+	// Storage: ArgoBridge DelayedInboundTransfers (r:1 w:1)
+	// Proof: ArgoBridge DelayedInboundTransfers (max_values: None, max_size: Some(96), added: 2571, mode: MaxEncodedLen)
+	// Storage: ArgoBridge MintAllowance (r:1 w:1)
+	// Proof: ArgoBridge MintAllowance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn cancel_delayed_inbound_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `598`
+		//  Estimated: `3898`
+		// Minimum execution time: 22_865 nanoseconds.
+		Weight::from_parts(23_719_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 3898))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // Default implementation for tests
@@ -204,4 +429,28 @@ impl WeightInfo for () {
 	fn update_bridge_constrains() -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn request_creator_token_outbound_transfer() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn finalize_creator_token_inbound_transfer() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn revert_creator_token_outbound_transfer() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn update_bridgeable_token() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn on_initialize(a: u32, c: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn attest_inbound_transfer() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn release_delayed_inbound_transfer() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn cancel_delayed_inbound_transfer() -> Weight {
+		Weight::from_parts(0, 0)
+	}
 }
@@ -2,7 +2,7 @@
 
 use crate::{
     AssuranceContractType, BalanceOf, Bounties, BountyActor, BountyCreationParameters,
-    BountyMilestone, Call, Config, Entries, Event, FundingType, Module as Bounty,
+    BountyMilestone, Call, Config, Entries, Event, FundingType, JudgmentDisputes, Module as Bounty,
     OracleWorkEntryJudgment, Pallet,
 };
 use balances::Pallet as Balances;
@@ -211,6 +211,69 @@ fn create_funded_bounty<T: Config>(params: BountyCreationParameters<T>) -> T::Bo
     bounty_id
 }
 
+// Creates a funded bounty with the judgment dispute period and submits the oracle judgment
+// with a single winner. The winner gets enough balance for the judgment dispute bond.
+fn create_bounty_with_pending_judgment<T>() -> (T::BountyId, T::EntryId, T::AccountId, T::MemberId)
+where
+    T: Config + membership::Config,
+    T::AccountId: CreateAccountId,
+{
+    let funding_amount: BalanceOf<T> = 100u32.into();
+    let (oracle_account_id, oracle_member_id) = member_funded_account::<T>(0);
+
+    let params = BountyCreationParameters::<T> {
+        creator: BountyActor::Council,
+        cherry: 100u32.into(),
+        oracle_reward: 100u32.into(),
+        entrant_stake: T::MinWorkEntrantStake::get(),
+        funding_type: FundingType::Perpetual {
+            target: funding_amount,
+        },
+        oracle: BountyActor::Member(oracle_member_id),
+        judgment_dispute_period: 10u32.into(),
+        oracle_stake: 100u32.into(),
+        ..Default::default()
+    };
+
+    let bounty_id = create_funded_bounty::<T>(params);
+
+    let entry_id = announce_entry_and_submit_work::<T>(&bounty_id, 1);
+    let entry = <Entries<T>>::get(bounty_id, entry_id).unwrap();
+
+    Bounty::<T>::end_working_period(
+        RawOrigin::Signed(oracle_account_id.clone()).into(),
+        bounty_id,
+    )
+    .unwrap();
+
+    let judgment = vec![(
+        entry_id,
+        OracleWorkEntryJudgment::Winner {
+            reward: funding_amount,
+        },
+    )]
+    .into_iter()
+    .collect::<BTreeMap<_, _>>();
+
+    Bounty::<T>::submit_oracle_judgment(
+        RawOrigin::Signed(oracle_account_id).into(),
+        bounty_id,
+        judgment,
+        Vec::new(),
+    )
+    .unwrap();
+
+    let _ =
+        Balances::<T>::deposit_creating(&entry.staking_account_id, T::JudgmentDisputeBond::get());
+
+    (
+        bounty_id,
+        entry_id,
+        entry.staking_account_id,
+        entry.member_id,
+    )
+}
+
 const MAX_KILOBYTES_METADATA: u32 = 100;
 const SEED: u32 = 0;
 const _MAX_MEMBERS: u32 = 150; //Same as mocks
@@ -1975,6 +2038,80 @@ benchmarks! {
             Event::<T>::BountyCreatorRemarked(creator, bounty_id, msg).into()
         );
     }
+
+    dispute_oracle_judgment {
+        let i in 0 .. MAX_KILOBYTES_METADATA;
+
+        let rationale = vec![0u8].repeat((i * 1000) as usize);
+
+        let (bounty_id, entry_id, work_account_id, work_member_id) =
+            create_bounty_with_pending_judgment::<T>();
+
+    }: _(
+        RawOrigin::Signed(work_account_id),
+        work_member_id,
+        bounty_id,
+        Some(entry_id),
+        rationale.clone())
+    verify {
+        assert!(JudgmentDisputes::<T>::contains_key(bounty_id));
+
+        assert_last_event::<T>(
+            Event::<T>::OracleJudgmentDisputed(bounty_id, work_member_id, rationale).into()
+        );
+    }
+
+    resolve_oracle_judgment_dispute {
+        let (bounty_id, entry_id, work_account_id, work_member_id) =
+            create_bounty_with_pending_judgment::<T>();
+
+        Bounty::<T>::dispute_oracle_judgment(
+            RawOrigin::Signed(work_account_id).into(),
+            work_member_id,
+            bounty_id,
+            Some(entry_id),
+            Vec::new()).unwrap();
+
+        // The upheld judgment is more expensive: it slashes the bond and finalizes the judgment.
+    }: _(RawOrigin::Root, bounty_id, false, None)
+    verify {
+        assert!(!JudgmentDisputes::<T>::contains_key(bounty_id));
+
+        assert_was_fired::<T>(
+            Event::<T>::JudgmentDisputeRejected(
+                bounty_id,
+                T::JudgmentDisputeBond::get()).into()
+        );
+
+        assert_last_event::<T>(Event::<T>::OracleJudgmentFinalized(bounty_id).into());
+    }
+
+    finalize_oracle_judgment {
+        let (bounty_id, entry_id, work_account_id, work_member_id) =
+            create_bounty_with_pending_judgment::<T>();
+
+        Bounty::<T>::dispute_oracle_judgment(
+            RawOrigin::Signed(work_account_id.clone()).into(),
+            work_member_id,
+            bounty_id,
+            Some(entry_id),
+            Vec::new()).unwrap();
+
+        // The undecided dispute bond is returned on finalization.
+        let disputed_at = System::<T>::block_number();
+        run_to_block::<T>(disputed_at + T::JudgmentDisputeDecisionPeriod::get() + One::one());
+
+    }: _(RawOrigin::Signed(work_account_id), bounty_id)
+    verify {
+        assert!(!JudgmentDisputes::<T>::contains_key(bounty_id));
+
+        assert_eq!(
+            Bounties::<T>::get(bounty_id).milestone,
+            BountyMilestone::JudgmentSubmitted { successful_bounty: true }
+        );
+
+        assert_last_event::<T>(Event::<T>::OracleJudgmentFinalized(bounty_id).into());
+    }
 }
 
 #[cfg(test)]
@@ -2203,4 +2340,25 @@ mod tests {
             assert_ok!(Bounty::test_benchmark_creator_remark());
         });
     }
+
+    #[test]
+    fn dispute_oracle_judgment() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Bounty::test_benchmark_dispute_oracle_judgment());
+        });
+    }
+
+    #[test]
+    fn resolve_oracle_judgment_dispute() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Bounty::test_benchmark_resolve_oracle_judgment_dispute());
+        });
+    }
+
+    #[test]
+    fn finalize_oracle_judgment() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Bounty::test_benchmark_finalize_oracle_judgment());
+        });
+    }
 }
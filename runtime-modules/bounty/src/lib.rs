@@ -8,11 +8,23 @@
 //! - Judgment - working periods ended and the oracle should provide their judgment,
 //!     winner work entrants receive their rewards, losers are slashed.
 //!  for his work.
-//! - SuccessfulBountyWithdrawal - contributors' funder state bloat bonds can be withdrawn,
+//!     A bounty can be split into work milestones with funding shares and deadlines: each
+//!     successful judgment pays the current milestone share and returns the bounty to the
+//!     WorkSubmission stage until the last milestone is judged.
+//! - JudgmentDisputeWindow - the oracle judgment of a bounty with the judgment dispute period
+//!     can be disputed by a work entrant or a contributor with a bond.
+//!     Each work milestone judgment can be disputed.
+//! - JudgmentDisputed - the council decides the dispute: an overturned judgment fails the bounty
+//!     (or is replaced by the corrected judgment of the council), slashes the oracle stake and
+//!     forfeits the oracle reward to the funders, an upheld judgment slashes the dispute bond.
+//! - PendingJudgmentFinalization - the dispute period (or the dispute decision period) ended,
+//!     anyone can make the oracle judgment final.
+//! - SuccessfulBountyWithdrawal - contributors' funder state bloat bonds can be withdrawn
+//!     (along with a split oracle reward forfeited after the overturned judgment),
 //!     none judged work entrants can unlock their stakes, Oracle can withdraw his reward
 //! - FailedBountyWithdrawal - contributors' funds +  funder state bloat bonds can be withdrawn
-//!     along with a split cherry, none judged work entrants can unlock their stakes,
-//!     Oracle can withdraw his reward,
+//!     along with a split cherry (and a split forfeited oracle reward), none judged work
+//!     entrants can unlock their stakes, Oracle can withdraw his reward (unless forfeited),
 //!
 //! A detailed description could be found [here](https://github.com/Joystream/joystream/issues/1998).
 //!
@@ -44,9 +56,23 @@
 //! by another one.
 //! - [terminate_bounty](./struct.Module.html#method.terminate_bounty) - terminate bounty (into failed stage or remove bounty).
 //!
+//! #### JudgmentDisputeWindow stage
+//! - [dispute_oracle_judgment](./struct.Module.html#method.dispute_oracle_judgment) - dispute
+//! the oracle judgment by a work entrant or a contributor.
+//!
+//! #### JudgmentDisputed stage
+//! - [resolve_oracle_judgment_dispute](./struct.Module.html#method.resolve_oracle_judgment_dispute) -
+//! overturn (optionally with the corrected judgment) or uphold the disputed oracle judgment
+//! by the council.
+//!
+//! #### PendingJudgmentFinalization stage
+//! - [finalize_oracle_judgment](./struct.Module.html#method.finalize_oracle_judgment) - make
+//! the oracle judgment final.
+//!
 //! #### SuccessfulBountyWithdrawal stage
 //! - [withdraw_entrant_stake](./struct.Module.html#method.withdraw_entrant_stake) -
-//! unlock stake accounts refering to none judged work entries.
+//! unlock stake accounts refering to none judged work entries, claim the winner reward or
+//! unlock the slashed stake for the final judgment kept for the dispute period.
 //!  - [withdraw_funding](./struct.Module.html#method.withdraw_funding) -
 //! withdraw contributor's state bloat bond.
 //!
@@ -85,12 +111,16 @@ mod stages;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 
 pub use weights::WeightInfo;
 
 type WeightInfoBounty<T> = <T as Config>::WeightInfo;
 
+// Storage version of the pallet.
+const CURRENT_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 pub(crate) use actors::BountyActorManager;
 
 // use council::Balance;
@@ -108,12 +138,12 @@ use common::membership::{
 use common::to_kb;
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::storage::bounded_btree_set::BoundedBTreeSet;
-use frame_support::traits::{Currency, ExistenceRequirement, Get, LockIdentifier};
+use frame_support::traits::{Currency, ExistenceRequirement, Get, LockIdentifier, StorageVersion};
 use frame_support::weights::Weight;
 use frame_support::{
-    decl_error, decl_event, decl_module, decl_storage, ensure, PalletId, Parameter,
+    decl_error, decl_event, decl_module, decl_storage, ensure, BoundedVec, PalletId, Parameter,
 };
-use frame_system::ensure_root;
+use frame_system::{ensure_root, ensure_signed};
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::{One, Saturating, Zero};
 use sp_runtime::traits::AccountIdConversion;
@@ -173,6 +203,16 @@ pub trait Config:
     /// Current state bloat bond a creator has to pay to create a bounty.
     /// The creator can withdraw the bond after he or someone else removes the bounty
     type CreatorStateBloatBondAmount: Get<BalanceOf<Self>>;
+
+    /// Defines max work milestone number for a bounty.
+    type MaxWorkMilestones: Get<u32>;
+
+    /// Bond a work entrant or a contributor has to pay to dispute the oracle judgment.
+    /// The bond is slashed if the council upholds the judgment.
+    type JudgmentDisputeBond: Get<BalanceOf<Self>>;
+
+    /// Number of blocks the council has to decide the oracle judgment dispute.
+    type JudgmentDisputeDecisionPeriod: Get<Self::BlockNumber>;
 }
 
 /// Alias type for the BountyParameters.
@@ -181,6 +221,7 @@ pub type BountyCreationParameters<T> = BountyParameters<
     <T as frame_system::Config>::BlockNumber,
     <T as MembershipTypes>::MemberId,
     BTreeSet<<T as MembershipTypes>::MemberId>,
+    Vec<WorkMilestoneOf<T>>,
 >;
 
 /// Alias type for stored BountyParameters.
@@ -189,6 +230,7 @@ pub type BountyStoredCreationParameters<T> = BountyParameters<
     <T as frame_system::Config>::BlockNumber,
     <T as MembershipTypes>::MemberId,
     ClosedContractWhitelist<T>,
+    WorkMilestones<T>,
 >;
 
 /// Defines who can submit the work.
@@ -211,6 +253,24 @@ impl<ClosedContractWhitelist> Default for AssuranceContractType<ClosedContractWh
 pub type ClosedContractWhitelist<T> =
     BoundedBTreeSet<<T as MembershipTypes>::MemberId, <T as Config>::ClosedContractSizeLimit>;
 
+/// Alias type for the WorkMilestone.
+pub type WorkMilestoneOf<T> = WorkMilestone<<T as frame_system::Config>::BlockNumber>;
+
+/// Alias type for the stored work milestone list.
+pub type WorkMilestones<T> = BoundedVec<WorkMilestoneOf<T>, <T as Config>::MaxWorkMilestones>;
+
+/// Defines a stage of the bounty work delivery with its own oracle judgment.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct WorkMilestone<BlockNumber> {
+    /// The share of the total funding paid to the winners of the milestone.
+    pub funding_share: Perbill,
+
+    /// Number of blocks since the work period start after which no more work can be submitted
+    /// for the milestone.
+    pub deadline: BlockNumber,
+}
+
 /// Defines funding conditions.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
@@ -242,7 +302,13 @@ impl<BlockNumber, Balance: Default> Default for FundingType<BlockNumber, Balance
 /// Defines parameters for the bounty creation.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct BountyParameters<Balance, BlockNumber, MemberId: Ord, ClosedContractWhitelist> {
+pub struct BountyParameters<
+    Balance,
+    BlockNumber,
+    MemberId: Ord,
+    ClosedContractWhitelist,
+    WorkMilestones,
+> {
     /// Origin that will select winner(s), is either a given member or a council.
     pub oracle: BountyActor<MemberId>,
 
@@ -269,23 +335,58 @@ pub struct BountyParameters<Balance, BlockNumber, MemberId: Ord, ClosedContractW
 
     /// Defines parameters for different funding types.
     pub funding_type: FundingType<BlockNumber, Balance>,
+
+    /// Work milestones with their funding shares and deadlines. The bounty has a single
+    /// work period and a single oracle judgment if the list is empty.
+    pub work_milestones: WorkMilestones,
+
+    /// Number of blocks the oracle judgment can be disputed after its submission.
+    /// The judgment is final right after the submission if the period is zero.
+    pub judgment_dispute_period: BlockNumber,
+
+    /// Amount the oracle has to stake when submitting a judgment which can be disputed.
+    /// The stake is slashed if the council overturns the judgment.
+    pub oracle_stake: Balance,
 }
 
-impl<Balance: Clone, BlockNumber: Clone, MemberId: Ord + Clone, ClosedContractSizeLimit>
-    TryFrom<BountyParameters<Balance, BlockNumber, MemberId, BTreeSet<MemberId>>>
+impl<
+        Balance: Clone,
+        BlockNumber: Clone,
+        MemberId: Ord + Clone,
+        ClosedContractSizeLimit,
+        MaxWorkMilestones,
+    >
+    TryFrom<
+        BountyParameters<
+            Balance,
+            BlockNumber,
+            MemberId,
+            BTreeSet<MemberId>,
+            Vec<WorkMilestone<BlockNumber>>,
+        >,
+    >
     for BountyParameters<
         Balance,
         BlockNumber,
         MemberId,
         BoundedBTreeSet<MemberId, ClosedContractSizeLimit>,
+        BoundedVec<WorkMilestone<BlockNumber>, MaxWorkMilestones>,
     >
 where
     BoundedBTreeSet<MemberId, ClosedContractSizeLimit>: TryFrom<BTreeSet<MemberId>>,
+    BoundedVec<WorkMilestone<BlockNumber>, MaxWorkMilestones>:
+        TryFrom<Vec<WorkMilestone<BlockNumber>>>,
 {
     type Error = ();
 
     fn try_from(
-        params: BountyParameters<Balance, BlockNumber, MemberId, BTreeSet<MemberId>>,
+        params: BountyParameters<
+            Balance,
+            BlockNumber,
+            MemberId,
+            BTreeSet<MemberId>,
+            Vec<WorkMilestone<BlockNumber>>,
+        >,
     ) -> Result<Self, Self::Error> {
         let contract_type = match params.contract_type.clone() {
             AssuranceContractType::Closed(whitelist) => {
@@ -294,6 +395,7 @@ where
             }
             AssuranceContractType::Open => AssuranceContractType::Open,
         };
+        let work_milestones = params.work_milestones.try_into().map_err(|_| ())?;
 
         Ok(Self {
             contract_type,
@@ -303,6 +405,9 @@ where
             oracle_reward: params.oracle_reward,
             entrant_stake: params.entrant_stake,
             funding_type: params.funding_type,
+            work_milestones,
+            judgment_dispute_period: params.judgment_dispute_period,
+            oracle_stake: params.oracle_stake,
         })
     }
 }
@@ -338,11 +443,23 @@ pub enum BountyStage {
     NoFundingContributed,
 
     /// A bounty has gathered necessary funds and ready to accept work submissions.
+    /// For a bounty with work milestones it accepts the work for the current milestone.
     WorkSubmission,
 
     /// Working periods ended and the oracle should provide their judgment.
+    /// For a bounty with work milestones the judgment is for the current milestone.
     Judgment,
 
+    /// The oracle judgment was submitted and can be disputed by a work entrant or a contributor.
+    JudgmentDisputeWindow,
+
+    /// The oracle judgment was disputed and the council should decide the dispute.
+    JudgmentDisputed,
+
+    /// The judgment dispute period or the dispute decision period ended.
+    /// Anyone can make the oracle judgment final.
+    PendingJudgmentFinalization,
+
     /// Indicates a withdrawal on bounty success. Workers get rewards and their stake.
     SuccessfulBountyWithdrawal,
 
@@ -364,6 +481,9 @@ pub enum BountyMilestone<BlockNumber> {
     /// - BountyStage::NoFundingContributed if now > (created_at + funding_period) and
     ///     has_contributions is false (total_funding = 0)
     /// - BountyStage::WorkSubmission if total_funding >= target and if now > (created_at + funding_period)
+    ///
+    /// For a bounty with work milestones the work submission stage ends after the deadline of
+    /// the current work milestone counted from the work period start.
     Created {
         /// Bounty creation block.
         created_at: BlockNumber,
@@ -383,6 +503,14 @@ pub enum BountyMilestone<BlockNumber> {
     /// - BountyStage::Judgment if active_work_entry_count > 0
     WorkSubmitted,
 
+    /// A successful judgment was submitted for a work milestone which is not the last one.
+    ///
+    /// This state will tranlate into:
+    /// - BountyStage::WorkSubmission while now <= deadline of the next work milestone
+    /// - BountyStage::Judgment if now > deadline of the next work milestone and
+    ///     active_work_entry_count > 0
+    WorkMilestoneCompleted,
+
     /// Council terminated this bounty
     ///
     /// This state will tranlate into
@@ -400,6 +528,37 @@ pub enum BountyMilestone<BlockNumber> {
         ///This flag indicates the judgment result (there is at least one work entrant winner),
         successful_bounty: bool,
     },
+
+    /// A judgment was submitted for a bounty with the judgment dispute period.
+    ///
+    /// This state will tranlate into:
+    /// - BountyStage::JudgmentDisputeWindow while now <= (submitted_at + judgment_dispute_period)
+    /// - BountyStage::PendingJudgmentFinalization if now > (submitted_at + judgment_dispute_period)
+    JudgmentPending {
+        ///This flag indicates the judgment result (there is at least one work entrant winner),
+        successful_bounty: bool,
+        /// Judgment submission block.
+        submitted_at: BlockNumber,
+    },
+
+    /// The oracle judgment was disputed by a work entrant or a contributor.
+    ///
+    /// This state will tranlate into:
+    /// - BountyStage::JudgmentDisputed while now <= (disputed_at + JudgmentDisputeDecisionPeriod)
+    /// - BountyStage::PendingJudgmentFinalization if
+    ///     now > (disputed_at + JudgmentDisputeDecisionPeriod)
+    JudgmentDisputed {
+        ///This flag indicates the judgment result (there is at least one work entrant winner),
+        successful_bounty: bool,
+        /// Judgment dispute block.
+        disputed_at: BlockNumber,
+    },
+
+    /// The council overturned the disputed oracle judgment.
+    ///
+    /// This state will tranlate into:
+    /// - BountyStage::FailedBountyWithdrawal
+    JudgmentOverturned,
 }
 
 impl<BlockNumber: Default> Default for BountyMilestone<BlockNumber> {
@@ -417,14 +576,22 @@ pub type Bounty<T> = BountyRecord<
     <T as frame_system::Config>::BlockNumber,
     <T as common::membership::MembershipTypes>::MemberId,
     ClosedContractWhitelist<T>,
+    WorkMilestones<T>,
 >;
 
 /// Crowdfunded bounty record.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct BountyRecord<Balance, BlockNumber, MemberId: Ord, ClosedContractWhitelist> {
+pub struct BountyRecord<
+    Balance,
+    BlockNumber,
+    MemberId: Ord,
+    ClosedContractWhitelist,
+    WorkMilestones,
+> {
     /// Bounty creation parameters.
-    pub creation_params: BountyParameters<Balance, BlockNumber, MemberId, ClosedContractWhitelist>,
+    pub creation_params:
+        BountyParameters<Balance, BlockNumber, MemberId, ClosedContractWhitelist, WorkMilestones>,
 
     /// Total funding balance reached so far.
     /// Includes initial funding by a creator and other members funding.
@@ -439,10 +606,30 @@ pub struct BountyRecord<Balance, BlockNumber, MemberId: Ord, ClosedContractWhite
 
     ///This flag is set to false, if oracle called withdraw_oracle_reward.
     pub has_unpaid_oracle_reward: bool,
+
+    /// Number of the work milestones with a successful judgment.
+    pub completed_work_milestones: u32,
+
+    /// Block when the work period started (optional). Set on reaching the target funding,
+    /// otherwise the work period starts when the funding period ends.
+    pub work_period_started_at: Option<BlockNumber>,
+
+    /// Number of the work entries judged by the oracle judgment kept for the judgment
+    /// dispute period.
+    pub pending_judgment_count: u32,
+
+    /// Oracle reward forfeited after the overturned oracle judgment. It is split between
+    /// the funders.
+    pub forfeited_oracle_reward: Balance,
 }
 
-impl<Balance: PartialOrd + Clone, BlockNumber: Clone, MemberId: Ord, ClosedContractWhitelist>
-    BountyRecord<Balance, BlockNumber, MemberId, ClosedContractWhitelist>
+impl<
+        Balance: PartialOrd + Clone,
+        BlockNumber: Clone,
+        MemberId: Ord,
+        ClosedContractWhitelist,
+        WorkMilestones,
+    > BountyRecord<Balance, BlockNumber, MemberId, ClosedContractWhitelist, WorkMilestones>
 {
     // Increments bounty active work entry counter.
     fn increment_active_work_entry_counter(&mut self) {
@@ -480,12 +667,13 @@ pub type Entry<T> = EntryRecord<
     <T as frame_system::Config>::AccountId,
     <T as common::membership::MembershipTypes>::MemberId,
     <T as frame_system::Config>::BlockNumber,
+    BalanceOf<T>,
 >;
 
 /// Work entry.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
-pub struct EntryRecord<AccountId, MemberId, BlockNumber> {
+pub struct EntryRecord<AccountId, MemberId, BlockNumber, Balance> {
     /// Work entrant member ID.
     pub member_id: MemberId,
 
@@ -497,6 +685,16 @@ pub struct EntryRecord<AccountId, MemberId, BlockNumber> {
 
     /// Signifies that an entry has at least one submitted work.
     pub work_submitted: bool,
+
+    /// Index of the work milestone the work was last submitted for. Judging the work milestone
+    /// resets the work submission of every entry: it doesn't count for the next milestones.
+    pub work_milestone: u32,
+
+    /// The oracle judgment for the entry kept until the judgment dispute period ends.
+    pub pending_judgment: Option<PendingWorkEntryJudgment<Balance>>,
+
+    /// Index of the work milestone the pending judgment was given for.
+    pub judged_work_milestone: u32,
 }
 
 /// Defines the oracle judgment for the work entry.
@@ -517,11 +715,58 @@ pub enum OracleWorkEntryJudgment<Balance> {
     },
 }
 
-impl<Balance> OracleWorkEntryJudgment<Balance> {
+impl<Balance: Clone> OracleWorkEntryJudgment<Balance> {
     // Work entry judgment helper. Returns true for winners.
     pub(crate) fn is_winner(&self) -> bool {
         matches!(*self, Self::Winner { .. })
     }
+
+    // Work entry judgment helper. Returns the judgment to keep for the judgment dispute period.
+    pub(crate) fn to_pending_judgment(&self) -> PendingWorkEntryJudgment<Balance> {
+        match self {
+            Self::Winner { reward } => PendingWorkEntryJudgment::Winner {
+                reward: reward.clone(),
+            },
+            Self::Rejected { slashing_share, .. } => PendingWorkEntryJudgment::Rejected {
+                slashing_share: *slashing_share,
+            },
+        }
+    }
+}
+
+/// Defines the oracle judgment for the work entry kept until the judgment dispute period ends.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum PendingWorkEntryJudgment<Balance> {
+    /// The work entry is selected as a winner.
+    Winner { reward: Balance },
+
+    /// The work entry is considered harmful. The stake will be slashed.
+    Rejected {
+        ///The percent share (0 - 1) to slash.
+        slashing_share: Perbill,
+    },
+}
+
+/// Alias type for the JudgmentDispute.
+pub type JudgmentDisputeOf<T> = JudgmentDispute<
+    <T as frame_system::Config>::AccountId,
+    <T as common::membership::MembershipTypes>::MemberId,
+    BalanceOf<T>,
+>;
+
+/// The oracle judgment dispute.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct JudgmentDispute<AccountId, MemberId, Balance> {
+    /// Member ID of the work entrant or the contributor who disputed the judgment.
+    pub disputant: MemberId,
+
+    /// Account ID the dispute bond was paid from.
+    pub bond_account_id: AccountId,
+
+    /// Dispute bond amount.
+    pub bond: Balance,
 }
 
 /// Balance alias for `balances` module.
@@ -608,6 +853,10 @@ decl_storage! { generate_storage_info
 
         /// Count of all work entries that have been created.
         pub EntryCount get(fn entry_count): u32;
+
+        /// Oracle judgment disputes waiting for the council decision.
+        pub JudgmentDisputes get(fn judgment_dispute_by_bounty_id): map
+            hasher(blake2_128_concat) T::BountyId => Option<JudgmentDisputeOf<T>>;
     }
 }
 
@@ -712,6 +961,43 @@ decl_event! {
         /// - rationale
         OracleJudgmentSubmitted(BountyId, BountyActor<MemberId>, OracleJudgment, Vec<u8>),
 
+        /// The oracle judgment was disputed.
+        /// Params:
+        /// - bounty ID
+        /// - disputant member ID
+        /// - rationale
+        OracleJudgmentDisputed(BountyId, MemberId, Vec<u8>),
+
+        /// The council overturned the disputed oracle judgment.
+        /// Params:
+        /// - bounty ID
+        /// - slashed oracle stake
+        OracleJudgmentOverturned(BountyId, Balance),
+
+        /// The council replaced the overturned oracle judgment with the corrected one.
+        /// Params:
+        /// - bounty ID
+        /// - corrected judgment data
+        OracleJudgmentCorrected(BountyId, OracleJudgment),
+
+        /// The council upheld the disputed oracle judgment.
+        /// Params:
+        /// - bounty ID
+        /// - slashed dispute bond
+        JudgmentDisputeRejected(BountyId, Balance),
+
+        /// The oracle judgment became final after the judgment dispute period.
+        /// Params:
+        /// - bounty ID
+        OracleJudgmentFinalized(BountyId),
+
+        /// A work milestone received a successful oracle judgment and its share was paid.
+        /// Params:
+        /// - bounty ID
+        /// - work milestone index
+        /// - work milestone payout
+        WorkMilestoneCompleted(BountyId, u32, Balance),
+
         /// Work entry was slashed.
         /// Params:
         /// - bounty ID
@@ -828,6 +1114,15 @@ decl_error! {
         /// Unexpected bounty stage for an operation: Judgment.
         InvalidStageUnexpectedJudgment,
 
+        /// Unexpected bounty stage for an operation: JudgmentDisputeWindow.
+        InvalidStageUnexpectedJudgmentDisputeWindow,
+
+        /// Unexpected bounty stage for an operation: JudgmentDisputed.
+        InvalidStageUnexpectedJudgmentDisputed,
+
+        /// Unexpected bounty stage for an operation: PendingJudgmentFinalization.
+        InvalidStageUnexpectedPendingJudgmentFinalization,
+
         /// Unexpected bounty stage for an operation: SuccessfulBountyWithdrawal.
         InvalidStageUnexpectedSuccessfulBountyWithdrawal,
 
@@ -906,7 +1201,37 @@ decl_error! {
         WorkEntryDoesntBelongToWorker,
 
         ///Oracle have already been withdrawn
-        OracleRewardAlreadyWithdrawn
+        OracleRewardAlreadyWithdrawn,
+
+        /// Cannot create a bounty with work milestone list larger than allowed.
+        WorkMilestoneListIsTooLarge,
+
+        /// Work milestone funding shares should be non-zero and sum up to the total funding.
+        InvalidWorkMilestoneFundingShares,
+
+        /// Work milestone deadlines should be non-zero and increasing.
+        InvalidWorkMilestoneDeadline,
+
+        /// The total reward for winners should be equal to the current work milestone payout.
+        TotalRewardShouldBeEqualToWorkMilestonePayout,
+
+        /// Cannot create a bounty with the oracle stake and without the judgment dispute period.
+        OracleStakeRequiresJudgmentDisputePeriod,
+
+        /// There is not enough balance for the oracle stake.
+        InsufficientBalanceForOracleStake,
+
+        /// There is not enough balance for the judgment dispute bond.
+        InsufficientBalanceForJudgmentDisputeBond,
+
+        /// Judgment dispute doesn't exist.
+        JudgmentDisputeDoesntExist,
+
+        /// The corrected judgment can be provided only for the overturned oracle judgment.
+        CorrectedJudgmentRequiresOverturn,
+
+        /// The corrected judgment should judge all the work entries judged by the oracle.
+        CorrectedJudgmentShouldJudgeAllJudgedWorkEntries
     }
 }
 
@@ -916,6 +1241,8 @@ decl_module! {
         /// Predefined errors
         type Error = Error<T>;
 
+        type StorageVersion = CURRENT_STORAGE_VERSION;
+
         /// Emits an event. Default substrate implementation.
         fn deposit_event() = default;
 
@@ -931,6 +1258,16 @@ decl_module! {
         /// Exports const - creator state bloat bond amount for a bounty.
         const CreatorStateBloatBondAmount: BalanceOf<T> = T::CreatorStateBloatBondAmount::get();
 
+        /// Exports const - max work milestone number for a bounty.
+        const MaxWorkMilestones: u32 = T::MaxWorkMilestones::get();
+
+        /// Exports const - oracle judgment dispute bond.
+        const JudgmentDisputeBond: BalanceOf<T> = T::JudgmentDisputeBond::get();
+
+        /// Exports const - oracle judgment dispute decision period.
+        const JudgmentDisputeDecisionPeriod: T::BlockNumber =
+            T::JudgmentDisputeDecisionPeriod::get();
+
         /// Creates a bounty. Metadata stored in the transaction log but discarded after that.
         /// <weight>
         ///
@@ -979,7 +1316,11 @@ decl_module! {
                 creation_params: stored_creation_params,
                 milestone: created_bounty_milestone,
                 active_work_entry_count: 0,
-                has_unpaid_oracle_reward: params.oracle_reward > Zero::zero()
+                has_unpaid_oracle_reward: params.oracle_reward > Zero::zero(),
+                completed_work_milestones: 0,
+                work_period_started_at: None,
+                pending_judgment_count: 0,
+                forfeited_oracle_reward: Zero::zero(),
             };
 
             <Bounties<T>>::insert(bounty_id, bounty);
@@ -1054,6 +1395,10 @@ decl_module! {
                 //Updates only the funds not the bloat bond.
                 bounty.total_funding = bounty.total_funding.saturating_add(adjusted_amount);
                 bounty.milestone = new_milestone;
+
+                if is_target_funding_reached && bounty.work_period_started_at.is_none() {
+                    bounty.work_period_started_at = Some(Self::current_block());
+                }
             });
 
             //Update member funding record
@@ -1245,6 +1590,7 @@ decl_module! {
                 ValidWithdrawalStage::SuccessfulBountyWithdrawal => {
                     Self::withdraw_funding_state_bloat_bond_mutation(
                         &bounty_id,
+                        &bounty,
                         funder,
                         &bounty_funder_manager,
                         funding);
@@ -1315,6 +1661,9 @@ decl_module! {
                 staking_account_id: staking_account_id.clone(),
                 submitted_at: Self::current_block(),
                 work_submitted: false,
+                work_milestone: 0,
+                pending_judgment: None,
+                judged_work_milestone: 0,
             };
 
             <Entries<T>>::insert(bounty_id, entry_id, entry);
@@ -1361,7 +1710,7 @@ decl_module! {
 
             Self::ensure_bounty_stage(current_bounty_stage, BountyStage::WorkSubmission)?;
 
-            let entry = Self::ensure_work_entry_exists(&bounty_id, &entry_id)?;
+            let entry = Self::ensure_active_work_entry_exists(&bounty_id, &bounty, &entry_id)?;
 
             Self::ensure_work_entry_ownership(&entry, &member_id)?;
 
@@ -1371,7 +1720,10 @@ decl_module! {
 
             // Update entry
             <Entries<T>>::mutate(bounty_id, entry_id, |entry| {
-                if let Some(e) = entry.as_mut() { e.work_submitted = true; };
+                if let Some(e) = entry.as_mut() {
+                    e.work_submitted = true;
+                    e.work_milestone = bounty.completed_work_milestones;
+                };
             });
 
             Self::deposit_event(RawEvent::WorkSubmitted(bounty_id, entry_id, member_id, work_data));
@@ -1407,8 +1759,11 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            let work_period_started_at = Self::get_work_period_start(&bounty);
+
             <Bounties<T>>::mutate(bounty_id, |bounty| {
                 bounty.milestone = BountyMilestone::WorkSubmitted;
+                bounty.work_period_started_at = work_period_started_at;
             });
             Self::deposit_event(RawEvent::WorkSubmissionPeriodEnded(bounty_id, current_oracle));
         }
@@ -1438,7 +1793,7 @@ decl_module! {
             rationale: Vec<u8>,
         ) {
             let bounty = Self::ensure_bounty_exists(&bounty_id)?;
-            BountyActorManager::<T>::ensure_bounty_actor_manager(
+            let bounty_oracle_manager = BountyActorManager::<T>::ensure_bounty_actor_manager(
                 origin,
                 bounty.creation_params.oracle.clone(),
             )?;
@@ -1454,74 +1809,45 @@ decl_module! {
             // Lookup for any winners in the judgment.
             let successful_bounty = Self::judgment_has_winners(&judgment);
 
+            // The judgment becomes final after the judgment dispute period.
+            let judgment_can_be_disputed =
+                !bounty.creation_params.judgment_dispute_period.is_zero();
+            if judgment_can_be_disputed {
+                bounty_oracle_manager
+                    .validate_balance_sufficiency(bounty.creation_params.oracle_stake)
+                    .map_err(|_| Error::<T>::InsufficientBalanceForOracleStake)?;
+            }
+
             //
             // == MUTATION SAFE ==
             //
 
-            // Return a cherry to a creator.
-            if successful_bounty {
-                Self::return_bounty_cherry_to_creator(bounty_id, &bounty, &bounty_creator_manager);
-            }
-
-            // Update bounty record.
-            <Bounties<T>>::mutate(bounty_id, |bounty| {
-                bounty.milestone = BountyMilestone::JudgmentSubmitted{
-                    successful_bounty
-                };
-            });
-
-            // Judgments triage.
-            for (entry_id, work_entry_judgment) in judgment.iter() {
-
-                let entry = Self::ensure_work_entry_exists(&bounty_id, entry_id)?;
-
-                // Update work entries for winners.
-                match *work_entry_judgment{
-                    OracleWorkEntryJudgment::Winner{ reward } => {
-
-                        // Unstake the full work entry state.
-                        let worker_account_id = T::Membership::controller_account_id(entry.member_id)?;
-
-                        T::StakingHandler::unlock(&entry.staking_account_id);
-                        // Claim the winner reward.
-
-                        Self::transfer_funds_from_bounty_account(
-                            &worker_account_id,
-                            bounty_id,
-                            reward
-                        );
-                        // Delete the work entry record from the storage.
-                        Self::remove_work_entry(&bounty_id, entry_id);
-
-                        // Fire an event.
-                        Self::deposit_event(RawEvent::WorkEntrantFundsWithdrawn(bounty_id, *entry_id, entry.member_id));
-
-                    },
-                    OracleWorkEntryJudgment::Rejected{
-                        slashing_share,
-                        ..
-                    } => {
-
-                        let slashing_amount = slashing_share * bounty.creation_params.entrant_stake;
+            if judgment_can_be_disputed {
+                Self::keep_oracle_judgment_for_dispute_period(
+                    bounty_id,
+                    &bounty,
+                    &bounty_oracle_manager,
+                    &judgment,
+                    successful_bounty,
+                )?;
 
-                        if slashing_amount > Zero::zero() {
-                            T::StakingHandler::slash(&entry.staking_account_id, Some(slashing_amount));
-                        }
+                Self::deposit_event(RawEvent::OracleJudgmentSubmitted(
+                    bounty_id,
+                    bounty.creation_params.oracle,
+                    judgment,
+                    rationale,
+                ));
 
-                        T::StakingHandler::unlock(&entry.staking_account_id);
+                return Ok(());
+            }
 
-                        Self::remove_work_entry(&bounty_id, entry_id);
+            Self::apply_oracle_judgment_mutation(
+                bounty_id,
+                &bounty,
+                &bounty_creator_manager,
+                &judgment,
+            )?;
 
-                        // Fire a WorkEntrantStakeSlashed event.
-                        Self::deposit_event(RawEvent::WorkEntrantStakeSlashed(
-                            bounty_id,
-                            *entry_id,
-                            entry.staking_account_id,
-                            slashing_amount
-                        ));
-                    }
-                }
-            }
             // Fire a judgment event.
             Self::deposit_event(RawEvent::OracleJudgmentSubmitted(
                 bounty_id,
@@ -1531,134 +1857,387 @@ decl_module! {
             ));
         }
 
-        ///Unlocks the stake related to a work entry
-        ///After the oracle makes the judgment or the council terminates the bounty by calling terminate_bounty(...),
-        ///each worker whose entry has not been judged, can unlock the totality of their stake.
+        /// Disputes the oracle judgment during the judgment dispute period. Can be called by
+        /// a work entrant (entry ID provided) or a member contributor of the bounty.
+        /// The dispute bond is slashed if the council upholds the judgment.
         /// # <weight>
         ///
         /// ## weight
-        /// `O (1)`
+        /// `O (R)`
+        /// - `R` is rationale size in kilobytes,
         /// - db:
         ///    - `O(1)` doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoBounty::<T>::withdraw_entrant_stake()]
-        pub fn withdraw_entrant_stake(
+        #[weight = WeightInfoBounty::<T>::dispute_oracle_judgment(
+            to_kb(rationale.len().saturated_into())
+        )]
+        pub fn dispute_oracle_judgment(
             origin,
             member_id: MemberId<T>,
             bounty_id: T::BountyId,
-            entry_id: T::EntryId,
+            entry_id: Option<T::EntryId>,
+            rationale: Vec<u8>,
         ) {
-            T::Membership::ensure_member_controller_account_origin(origin, member_id)?;
+            let account_id =
+                T::Membership::ensure_member_controller_account_origin(origin, member_id)?;
 
             let bounty = Self::ensure_bounty_exists(&bounty_id)?;
 
             let current_bounty_stage = Self::get_bounty_stage(&bounty);
 
-            ensure!(
-                matches!(current_bounty_stage,
-                    BountyStage::FailedBountyWithdrawal |
-                    BountyStage::SuccessfulBountyWithdrawal ),
-                Self::unexpected_bounty_stage_error(current_bounty_stage)
-            );
+            Self::ensure_bounty_stage(current_bounty_stage, BountyStage::JudgmentDisputeWindow)?;
 
-            let entry = Self::ensure_work_entry_exists(&bounty_id, &entry_id)?;
+            match entry_id {
+                Some(entry_id) => {
+                    let entry =
+                        Self::ensure_active_work_entry_exists(&bounty_id, &bounty, &entry_id)?;
 
-            Self::ensure_work_entry_ownership(&entry, &member_id)?;
+                    Self::ensure_work_entry_ownership(&entry, &member_id)?;
+                }
+                None => {
+                    Self::ensure_bounty_contribution_exists(
+                        &bounty_id,
+                        &BountyActor::Member(member_id),
+                    )?;
+                }
+            }
 
-            let bounty_creator_manager = BountyActorManager::<T>::get_bounty_actor_manager(
-                bounty.creation_params.creator.clone(),
-            )?;
+            let bond = T::JudgmentDisputeBond::get();
+
+            ensure!(
+                Self::check_balance_for_account(bond, &account_id),
+                Error::<T>::InsufficientBalanceForJudgmentDisputeBond
+            );
 
             //
             // == MUTATION SAFE ==
             //
 
-            T::StakingHandler::unlock(&entry.staking_account_id);
+            Self::transfer_funds_to_bounty_account(&account_id, bounty_id, bond);
 
-            Self::deposit_event(
-                RawEvent::WorkEntrantStakeUnlocked(
-                    bounty_id,
-                    entry_id,
-                    entry.staking_account_id)
-            );
+            <JudgmentDisputes<T>>::insert(bounty_id, JudgmentDispute {
+                disputant: member_id,
+                bond_account_id: account_id,
+                bond,
+            });
 
-            Self::remove_work_entry(&bounty_id, &entry_id);
+            <Bounties<T>>::mutate(bounty_id, |bounty| {
+                bounty.milestone = BountyMilestone::JudgmentDisputed {
+                    successful_bounty: Self::judgment_is_successful(bounty),
+                    disputed_at: Self::current_block(),
+                };
+            });
 
-            if Self::can_remove_bounty(&bounty_id, &bounty) {
-                Self::remove_bounty(
-                    &bounty_id,
-                    &bounty,
-                    &bounty_creator_manager
-                );
-            }
+            Self::deposit_event(RawEvent::OracleJudgmentDisputed(bounty_id, member_id, rationale));
         }
 
-        ///Withraws the oracle reward to oracle
-        ///If bounty is successfully, Failed or Cancelled oracle must call this
-        ///extrinsic to withdraw the oracle reward,
+        /// Decides the oracle judgment dispute. Requires root origin (the council through the
+        /// `Resolve Bounty Judgment Dispute` proposal).
+        /// Overturned judgment: the oracle stake is slashed in favor of the disputant, the dispute
+        /// bond is returned and the oracle reward is forfeited to the funders. The bounty fails
+        /// unless the corrected judgment is provided: it should judge all the work entries judged
+        /// by the oracle and is applied instead of the oracle judgment.
+        /// Upheld judgment: the dispute bond is slashed and the judgment becomes final.
         /// # <weight>
         ///
         /// ## weight
-        /// `O (1)`
+        /// `O (K + W + R)`
+        /// - `K` is the sum of all action_justification sizes (in kilobytes) inside the corrected
+        ///    judgment,
+        /// - `W` is number of winner corrected judgment entries,
+        /// - `R` is number of rejected corrected judgment entries,
         /// - db:
-        ///    - `O(1)` doesn't depend on the state or parameters
+        ///    - `O(W + R)`
         /// # </weight>
-        #[weight = WeightInfoBounty::<T>::withdraw_oracle_reward_by_oracle_council()
-        .max(WeightInfoBounty::<T>::withdraw_oracle_reward_by_oracle_member())]
-        pub fn withdraw_oracle_reward(
+        #[weight = Module::<T>::resolve_oracle_judgment_dispute_weight(corrected_judgment)]
+        pub fn resolve_oracle_judgment_dispute(
             origin,
             bounty_id: T::BountyId,
+            overturn_judgment: bool,
+            corrected_judgment: Option<OracleJudgment<T::EntryId, BalanceOf<T>>>,
         ) {
-            let bounty = Self::ensure_bounty_exists(&bounty_id)?;
+            ensure_root(origin)?;
 
-            let bounty_oracle_manager = BountyActorManager::<T>::ensure_bounty_actor_manager(
-                origin,
-                bounty.creation_params.oracle.clone(),
-            )?;
+            let bounty = Self::ensure_bounty_exists(&bounty_id)?;
 
-            let oracle_reward = bounty.creation_params.oracle_reward;
             let current_bounty_stage = Self::get_bounty_stage(&bounty);
 
-            ensure!(
-                matches!(current_bounty_stage,
-                    BountyStage::FailedBountyWithdrawal |
-                    BountyStage::SuccessfulBountyWithdrawal ),
-                Self::unexpected_bounty_stage_error(current_bounty_stage)
-            );
-            ensure!(bounty.has_unpaid_oracle_reward, Error::<T>::OracleRewardAlreadyWithdrawn);
+            Self::ensure_bounty_stage(current_bounty_stage, BountyStage::JudgmentDisputed)?;
 
-            let bounty_creator_manager = BountyActorManager::<T>::get_bounty_actor_manager(
-                bounty.creation_params.creator.clone(),
+            let judgment_dispute = Self::ensure_judgment_dispute_exists(&bounty_id)?;
+
+            if let Some(ref corrected_judgment) = corrected_judgment {
+                ensure!(overturn_judgment, Error::<T>::CorrectedJudgmentRequiresOverturn);
+
+                Self::validate_corrected_judgment(&bounty_id, &bounty, corrected_judgment)?;
+            }
+
+            let bounty_oracle_manager = BountyActorManager::<T>::get_bounty_actor_manager(
+                bounty.creation_params.oracle.clone(),
             )?;
 
+            let bounty_creator_manager = Self::ensure_creator_actor_manager(&bounty)?;
+
             //
             // == MUTATION SAFE ==
             //
 
-            bounty_oracle_manager.transfer_funds_from_bounty_account(bounty_id, oracle_reward);
-
-            <Bounties<T>>::mutate(bounty_id, |bounty| {
-                bounty.has_unpaid_oracle_reward = false;
-            });
+            <JudgmentDisputes<T>>::remove(bounty_id);
 
-            Self::deposit_event(RawEvent::BountyOracleRewardWithdrawal(
-                bounty_id,
-                bounty.creation_params.oracle.clone(),
-                oracle_reward
-            ));
+            if overturn_judgment {
+                let oracle_stake = bounty.creation_params.oracle_stake;
 
-            if Self::has_no_contributions_and_no_work_entries(&bounty_id) {
-                Self::remove_bounty(
-                    &bounty_id,
-                    &bounty,
-                    &bounty_creator_manager
+                Self::transfer_funds_from_bounty_account(
+                    &judgment_dispute.bond_account_id,
+                    bounty_id,
+                    judgment_dispute.bond.saturating_add(oracle_stake),
                 );
-            }
-        }
 
-        /// Bounty Contributor made a remark
-        ///
-        /// # <weight>
+                <Bounties<T>>::mutate(bounty_id, |bounty| {
+                    bounty.milestone = BountyMilestone::JudgmentOverturned;
+
+                    // The oracle reward is split between the funders instead.
+                    if bounty.has_unpaid_oracle_reward {
+                        bounty.has_unpaid_oracle_reward = false;
+                        bounty.forfeited_oracle_reward = bounty.creation_params.oracle_reward;
+                    }
+                });
+
+                Self::deposit_event(RawEvent::OracleJudgmentOverturned(bounty_id, oracle_stake));
+
+                if let Some(corrected_judgment) = corrected_judgment {
+                    Self::apply_oracle_judgment_mutation(
+                        bounty_id,
+                        &bounty,
+                        &bounty_creator_manager,
+                        &corrected_judgment,
+                    )?;
+
+                    Self::deposit_event(RawEvent::OracleJudgmentCorrected(
+                        bounty_id,
+                        corrected_judgment,
+                    ));
+                }
+            } else {
+                let _ = balances::Pallet::<T>::slash(
+                    &Self::bounty_account_id(bounty_id),
+                    judgment_dispute.bond,
+                );
+
+                Self::deposit_event(RawEvent::JudgmentDisputeRejected(
+                    bounty_id,
+                    judgment_dispute.bond,
+                ));
+
+                Self::finalize_oracle_judgment_mutation(
+                    bounty_id,
+                    &bounty,
+                    &bounty_oracle_manager,
+                    &bounty_creator_manager,
+                );
+            }
+        }
+
+        /// Makes the oracle judgment final after the judgment dispute period or after the
+        /// dispute decision period if the council didn't decide the dispute (the dispute bond
+        /// is returned). The oracle stake and the cherry for a successful bounty are returned.
+        /// Can be called by anyone.
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (1)`
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoBounty::<T>::finalize_oracle_judgment()]
+        pub fn finalize_oracle_judgment(
+            origin,
+            bounty_id: T::BountyId,
+        ) {
+            ensure_signed(origin)?;
+
+            let bounty = Self::ensure_bounty_exists(&bounty_id)?;
+
+            let current_bounty_stage = Self::get_bounty_stage(&bounty);
+
+            Self::ensure_bounty_stage(
+                current_bounty_stage,
+                BountyStage::PendingJudgmentFinalization
+            )?;
+
+            let bounty_oracle_manager = BountyActorManager::<T>::get_bounty_actor_manager(
+                bounty.creation_params.oracle.clone(),
+            )?;
+
+            let bounty_creator_manager = Self::ensure_creator_actor_manager(&bounty)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // The dispute wasn't decided in time: return the bond.
+            if let Some(judgment_dispute) = <JudgmentDisputes<T>>::take(bounty_id) {
+                Self::transfer_funds_from_bounty_account(
+                    &judgment_dispute.bond_account_id,
+                    bounty_id,
+                    judgment_dispute.bond,
+                );
+            }
+
+            Self::finalize_oracle_judgment_mutation(
+                bounty_id,
+                &bounty,
+                &bounty_oracle_manager,
+                &bounty_creator_manager,
+            );
+        }
+
+        ///Unlocks the stake related to a work entry
+        ///After the oracle makes the judgment or the council terminates the bounty by calling terminate_bounty(...),
+        ///each worker whose entry has not been judged, can unlock the totality of their stake.
+        ///For the judgment kept for the dispute period the winner receives the reward and
+        ///the rejected entry stake is slashed before unlocking, unless the judgment was overturned.
+        ///The final judgments of the completed work milestones are always applied.
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (1)`
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoBounty::<T>::withdraw_entrant_stake()]
+        pub fn withdraw_entrant_stake(
+            origin,
+            member_id: MemberId<T>,
+            bounty_id: T::BountyId,
+            entry_id: T::EntryId,
+        ) {
+            let worker_account_id =
+                T::Membership::ensure_member_controller_account_origin(origin, member_id)?;
+
+            let bounty = Self::ensure_bounty_exists(&bounty_id)?;
+
+            let current_bounty_stage = Self::get_bounty_stage(&bounty);
+
+            ensure!(
+                matches!(current_bounty_stage,
+                    BountyStage::FailedBountyWithdrawal |
+                    BountyStage::SuccessfulBountyWithdrawal ),
+                Self::unexpected_bounty_stage_error(current_bounty_stage)
+            );
+
+            let entry = Self::ensure_work_entry_exists(&bounty_id, &entry_id)?;
+
+            Self::ensure_work_entry_ownership(&entry, &member_id)?;
+
+            let bounty_creator_manager = BountyActorManager::<T>::get_bounty_actor_manager(
+                bounty.creation_params.creator.clone(),
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Apply the final oracle judgment kept for the judgment dispute period.
+            if let Some(pending_judgment) = entry.pending_judgment.clone() {
+                if Self::is_pending_judgment_final(&bounty, &entry) {
+                    Self::apply_pending_work_entry_judgment(
+                        bounty_id,
+                        entry_id,
+                        &bounty,
+                        &entry,
+                        &worker_account_id,
+                        pending_judgment,
+                    );
+                }
+            }
+
+            T::StakingHandler::unlock(&entry.staking_account_id);
+
+            Self::deposit_event(
+                RawEvent::WorkEntrantStakeUnlocked(
+                    bounty_id,
+                    entry_id,
+                    entry.staking_account_id)
+            );
+
+            Self::remove_work_entry(&bounty_id, &entry_id);
+
+            if Self::can_remove_bounty(&bounty_id, &bounty) {
+                Self::remove_bounty(
+                    &bounty_id,
+                    &bounty,
+                    &bounty_creator_manager
+                );
+            }
+        }
+
+        ///Withraws the oracle reward to oracle
+        ///If bounty is successfully, Failed or Cancelled oracle must call this
+        ///extrinsic to withdraw the oracle reward,
+        /// # <weight>
+        ///
+        /// ## weight
+        /// `O (1)`
+        /// - db:
+        ///    - `O(1)` doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoBounty::<T>::withdraw_oracle_reward_by_oracle_council()
+        .max(WeightInfoBounty::<T>::withdraw_oracle_reward_by_oracle_member())]
+        pub fn withdraw_oracle_reward(
+            origin,
+            bounty_id: T::BountyId,
+        ) {
+            let bounty = Self::ensure_bounty_exists(&bounty_id)?;
+
+            let bounty_oracle_manager = BountyActorManager::<T>::ensure_bounty_actor_manager(
+                origin,
+                bounty.creation_params.oracle.clone(),
+            )?;
+
+            let oracle_reward = bounty.creation_params.oracle_reward;
+            let current_bounty_stage = Self::get_bounty_stage(&bounty);
+
+            ensure!(
+                matches!(current_bounty_stage,
+                    BountyStage::FailedBountyWithdrawal |
+                    BountyStage::SuccessfulBountyWithdrawal ),
+                Self::unexpected_bounty_stage_error(current_bounty_stage)
+            );
+            ensure!(bounty.has_unpaid_oracle_reward, Error::<T>::OracleRewardAlreadyWithdrawn);
+
+            let bounty_creator_manager = BountyActorManager::<T>::get_bounty_actor_manager(
+                bounty.creation_params.creator.clone(),
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            bounty_oracle_manager.transfer_funds_from_bounty_account(bounty_id, oracle_reward);
+
+            <Bounties<T>>::mutate(bounty_id, |bounty| {
+                bounty.has_unpaid_oracle_reward = false;
+            });
+
+            Self::deposit_event(RawEvent::BountyOracleRewardWithdrawal(
+                bounty_id,
+                bounty.creation_params.oracle.clone(),
+                oracle_reward
+            ));
+
+            if Self::has_no_contributions_and_no_work_entries(&bounty_id) {
+                Self::remove_bounty(
+                    &bounty_id,
+                    &bounty,
+                    &bounty_creator_manager
+                );
+            }
+        }
+
+        /// Bounty Contributor made a remark
+        ///
+        /// # <weight>
         ///
         /// ## weight
         /// `O (N)`
@@ -1807,9 +2386,18 @@ impl<T: Config> Module<T> {
     ) {
         let cherry_fraction = Self::get_cherry_fraction_for_member(bounty, funding.amount);
 
+        // Funds paid for the completed work milestones can't be withdrawn.
+        let paid_funding_fraction =
+            Self::get_paid_funding_fraction_for_member(bounty, funding.amount);
+
         let withdrawal_amount = funding
             .total_bloat_bond_and_funding()
-            .saturating_add(cherry_fraction);
+            .saturating_sub(paid_funding_fraction)
+            .saturating_add(cherry_fraction)
+            .saturating_add(Self::get_forfeited_oracle_reward_fraction_for_member(
+                bounty,
+                funding.amount,
+            ));
 
         bounty_funder_manager.transfer_funds_from_bounty_account(*bounty_id, withdrawal_amount);
 
@@ -1826,12 +2414,17 @@ impl<T: Config> Module<T> {
 
     fn withdraw_funding_state_bloat_bond_mutation(
         bounty_id: &T::BountyId,
+        bounty: &Bounty<T>,
         funder: BountyActor<MemberId<T>>,
         bounty_funder_manager: &BountyActorManager<T>,
         funding: Contribution<T>,
     ) {
-        bounty_funder_manager
-            .transfer_funds_from_bounty_account(*bounty_id, funding.funder_state_bloat_bond_amount);
+        // The oracle reward forfeited after the overturned judgment is returned to the funders.
+        let withdrawal_amount = funding.funder_state_bloat_bond_amount.saturating_add(
+            Self::get_forfeited_oracle_reward_fraction_for_member(bounty, funding.amount),
+        );
+
+        bounty_funder_manager.transfer_funds_from_bounty_account(*bounty_id, withdrawal_amount);
 
         //Remove contribution from
         <BountyContributions<T>>::remove(bounty_id, &funder);
@@ -1914,6 +2507,53 @@ impl<T: Config> Module<T> {
             );
         }
 
+        Self::ensure_work_milestones_valid(params)?;
+
+        if params.judgment_dispute_period.is_zero() {
+            ensure!(
+                params.oracle_stake.is_zero(),
+                Error::<T>::OracleStakeRequiresJudgmentDisputePeriod
+            );
+        }
+
+        Ok(())
+    }
+
+    // Validates work milestones for a bounty creation: funding shares should sum up to
+    // the total funding and deadlines should be non-zero and increasing.
+    fn ensure_work_milestones_valid(params: &BountyCreationParameters<T>) -> DispatchResult {
+        if params.work_milestones.is_empty() {
+            return Ok(());
+        }
+
+        ensure!(
+            params.work_milestones.len() <= T::MaxWorkMilestones::get().saturated_into::<usize>(),
+            Error::<T>::WorkMilestoneListIsTooLarge
+        );
+
+        let mut previous_deadline: T::BlockNumber = Zero::zero();
+        let mut total_funding_share = 0u64;
+
+        for work_milestone in params.work_milestones.iter() {
+            ensure!(
+                !work_milestone.funding_share.is_zero(),
+                Error::<T>::InvalidWorkMilestoneFundingShares
+            );
+            ensure!(
+                work_milestone.deadline > previous_deadline,
+                Error::<T>::InvalidWorkMilestoneDeadline
+            );
+
+            previous_deadline = work_milestone.deadline;
+            total_funding_share = total_funding_share
+                .saturating_add(work_milestone.funding_share.deconstruct().into());
+        }
+
+        ensure!(
+            total_funding_share == u64::from(Perbill::one().deconstruct()),
+            Error::<T>::InvalidWorkMilestoneFundingShares
+        );
+
         Ok(())
     }
 
@@ -2044,6 +2684,308 @@ impl<T: Config> Module<T> {
 
         Ok(funding)
     }
+
+    // Verifies judgment dispute existence and retrieves it from the storage.
+    fn ensure_judgment_dispute_exists(
+        bounty_id: &T::BountyId,
+    ) -> Result<JudgmentDisputeOf<T>, DispatchError> {
+        let judgment_dispute =
+            <JudgmentDisputes<T>>::get(bounty_id).ok_or(Error::<T>::JudgmentDisputeDoesntExist)?;
+
+        Ok(judgment_dispute)
+    }
+
+    // Returns the result of the oracle judgment (there is at least one work entrant winner).
+    fn judgment_is_successful(bounty: &Bounty<T>) -> bool {
+        match bounty.milestone {
+            BountyMilestone::JudgmentSubmitted { successful_bounty }
+            | BountyMilestone::JudgmentPending {
+                successful_bounty, ..
+            }
+            | BountyMilestone::JudgmentDisputed {
+                successful_bounty, ..
+            } => successful_bounty,
+            _ => false,
+        }
+    }
+
+    // Applies the oracle judgment (or the corrected judgment of the council): pays the winners,
+    // slashes the rejected work entries and completes the current work milestone.
+    fn apply_oracle_judgment_mutation(
+        bounty_id: T::BountyId,
+        bounty: &Bounty<T>,
+        bounty_creator_manager: &BountyActorManager<T>,
+        judgment: &OracleJudgmentOf<T>,
+    ) -> DispatchResult {
+        let successful_bounty = Self::judgment_has_winners(judgment);
+
+        // The work continues with the next work milestone after a successful judgment.
+        let has_next_work_milestone = successful_bounty && Self::has_next_work_milestone(bounty);
+        let completed_work_milestone =
+            if successful_bounty && !bounty.creation_params.work_milestones.is_empty() {
+                Some(bounty.completed_work_milestones)
+            } else {
+                None
+            };
+
+        // Return a cherry to a creator.
+        if successful_bounty && !has_next_work_milestone {
+            Self::return_bounty_cherry_to_creator(bounty_id, bounty, bounty_creator_manager);
+        }
+
+        let work_period_started_at = Self::get_work_period_start(bounty);
+
+        // Update bounty record.
+        <Bounties<T>>::mutate(bounty_id, |bounty| {
+            bounty.work_period_started_at = work_period_started_at;
+            bounty.pending_judgment_count = 0;
+            bounty.milestone = if has_next_work_milestone {
+                BountyMilestone::WorkMilestoneCompleted
+            } else {
+                BountyMilestone::JudgmentSubmitted { successful_bounty }
+            };
+
+            if completed_work_milestone.is_some() {
+                bounty.completed_work_milestones =
+                    bounty.completed_work_milestones.saturating_add(1);
+            }
+        });
+
+        // Judgments triage.
+        for (entry_id, work_entry_judgment) in judgment.iter() {
+            let entry = Self::ensure_work_entry_exists(&bounty_id, entry_id)?;
+
+            Self::settle_pending_work_entry_judgment(bounty_id, entry_id, bounty, &entry)?;
+
+            // Update work entries for winners.
+            match *work_entry_judgment {
+                OracleWorkEntryJudgment::Winner { reward } => {
+                    let worker_account_id = T::Membership::controller_account_id(entry.member_id)?;
+
+                    // Keep the stake, the next work milestone requires a new submission
+                    // (see `work_milestone` of the entry).
+                    if !has_next_work_milestone {
+                        // Unstake the full work entry state.
+                        T::StakingHandler::unlock(&entry.staking_account_id);
+                    }
+
+                    // Claim the winner reward.
+                    Self::transfer_funds_from_bounty_account(&worker_account_id, bounty_id, reward);
+
+                    if !has_next_work_milestone {
+                        // Delete the work entry record from the storage.
+                        Self::remove_work_entry(&bounty_id, entry_id);
+                    } else if entry.pending_judgment.is_some() {
+                        // Discard the overturned oracle judgment.
+                        <Entries<T>>::mutate(bounty_id, entry_id, |entry| {
+                            if let Some(e) = entry.as_mut() {
+                                e.pending_judgment = None;
+                            };
+                        });
+                    }
+
+                    // Fire an event.
+                    Self::deposit_event(RawEvent::WorkEntrantFundsWithdrawn(
+                        bounty_id,
+                        *entry_id,
+                        entry.member_id,
+                    ));
+                }
+                OracleWorkEntryJudgment::Rejected { slashing_share, .. } => {
+                    let slashing_amount = slashing_share * bounty.creation_params.entrant_stake;
+
+                    if slashing_amount > Zero::zero() {
+                        T::StakingHandler::slash(&entry.staking_account_id, Some(slashing_amount));
+                    }
+
+                    T::StakingHandler::unlock(&entry.staking_account_id);
+
+                    Self::remove_work_entry(&bounty_id, entry_id);
+
+                    // Fire a WorkEntrantStakeSlashed event.
+                    Self::deposit_event(RawEvent::WorkEntrantStakeSlashed(
+                        bounty_id,
+                        *entry_id,
+                        entry.staking_account_id,
+                        slashing_amount,
+                    ));
+                }
+            }
+        }
+        if let Some(work_milestone_index) = completed_work_milestone {
+            Self::deposit_event(RawEvent::WorkMilestoneCompleted(
+                bounty_id,
+                work_milestone_index,
+                Self::work_milestone_payout(bounty),
+            ));
+        }
+
+        Ok(())
+    }
+
+    // Keeps the oracle judgment in the work entries until the judgment dispute period ends
+    // and transfers the oracle stake to the bounty account. The final judgment kept for
+    // the previous work milestone is applied first.
+    fn keep_oracle_judgment_for_dispute_period(
+        bounty_id: T::BountyId,
+        bounty: &Bounty<T>,
+        bounty_oracle_manager: &BountyActorManager<T>,
+        judgment: &OracleJudgmentOf<T>,
+        successful_bounty: bool,
+    ) -> DispatchResult {
+        let oracle_stake = bounty.creation_params.oracle_stake;
+        if !oracle_stake.is_zero() {
+            bounty_oracle_manager.transfer_funds_to_bounty_account(bounty_id, oracle_stake);
+        }
+
+        for (entry_id, work_entry_judgment) in judgment.iter() {
+            let entry = Self::ensure_work_entry_exists(&bounty_id, entry_id)?;
+
+            Self::settle_pending_work_entry_judgment(bounty_id, entry_id, bounty, &entry)?;
+
+            <Entries<T>>::mutate(bounty_id, entry_id, |entry| {
+                if let Some(e) = entry.as_mut() {
+                    e.pending_judgment = Some(work_entry_judgment.to_pending_judgment());
+                    e.judged_work_milestone = bounty.completed_work_milestones;
+                };
+            });
+        }
+
+        let work_period_started_at = Self::get_work_period_start(bounty);
+
+        <Bounties<T>>::mutate(bounty_id, |bounty| {
+            bounty.work_period_started_at = work_period_started_at;
+            bounty.pending_judgment_count = judgment.len().saturated_into();
+            bounty.milestone = BountyMilestone::JudgmentPending {
+                successful_bounty,
+                submitted_at: Self::current_block(),
+            };
+        });
+
+        Ok(())
+    }
+
+    // Makes the oracle judgment final: returns the oracle stake and the cherry for
+    // a successful bounty or completes the current work milestone.
+    fn finalize_oracle_judgment_mutation(
+        bounty_id: T::BountyId,
+        bounty: &Bounty<T>,
+        bounty_oracle_manager: &BountyActorManager<T>,
+        bounty_creator_manager: &BountyActorManager<T>,
+    ) {
+        let successful_bounty = Self::judgment_is_successful(bounty);
+
+        // The work continues with the next work milestone after a successful judgment.
+        let has_next_work_milestone = successful_bounty && Self::has_next_work_milestone(bounty);
+        let completed_work_milestone =
+            if successful_bounty && !bounty.creation_params.work_milestones.is_empty() {
+                Some(bounty.completed_work_milestones)
+            } else {
+                None
+            };
+
+        let oracle_stake = bounty.creation_params.oracle_stake;
+        if !oracle_stake.is_zero() {
+            bounty_oracle_manager.transfer_funds_from_bounty_account(bounty_id, oracle_stake);
+        }
+
+        if successful_bounty && !has_next_work_milestone {
+            Self::return_bounty_cherry_to_creator(bounty_id, bounty, bounty_creator_manager);
+        }
+
+        <Bounties<T>>::mutate(bounty_id, |bounty| {
+            bounty.pending_judgment_count = 0;
+            bounty.milestone = if has_next_work_milestone {
+                BountyMilestone::WorkMilestoneCompleted
+            } else {
+                BountyMilestone::JudgmentSubmitted { successful_bounty }
+            };
+
+            if completed_work_milestone.is_some() {
+                bounty.completed_work_milestones =
+                    bounty.completed_work_milestones.saturating_add(1);
+            }
+        });
+
+        if let Some(work_milestone_index) = completed_work_milestone {
+            Self::deposit_event(RawEvent::WorkMilestoneCompleted(
+                bounty_id,
+                work_milestone_index,
+                Self::work_milestone_payout(bounty),
+            ));
+        }
+
+        Self::deposit_event(RawEvent::OracleJudgmentFinalized(bounty_id));
+    }
+
+    // Applies the final oracle judgment kept for the work entry for the previous work
+    // milestone before the work entry is judged again.
+    fn settle_pending_work_entry_judgment(
+        bounty_id: T::BountyId,
+        entry_id: &T::EntryId,
+        bounty: &Bounty<T>,
+        entry: &Entry<T>,
+    ) -> DispatchResult {
+        if let Some(pending_judgment) = entry.pending_judgment.clone() {
+            if Self::is_pending_judgment_final(bounty, entry) {
+                let worker_account_id = T::Membership::controller_account_id(entry.member_id)?;
+
+                Self::apply_pending_work_entry_judgment(
+                    bounty_id,
+                    *entry_id,
+                    bounty,
+                    entry,
+                    &worker_account_id,
+                    pending_judgment,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    // Checks whether the oracle judgment kept for the work entry is final: it was given for
+    // a completed work milestone or the last oracle judgment became final.
+    fn is_pending_judgment_final(bounty: &Bounty<T>, entry: &Entry<T>) -> bool {
+        entry.judged_work_milestone < bounty.completed_work_milestones
+            || matches!(bounty.milestone, BountyMilestone::JudgmentSubmitted { .. })
+    }
+
+    // Pays the winner reward or slashes the stake of the rejected work entry.
+    fn apply_pending_work_entry_judgment(
+        bounty_id: T::BountyId,
+        entry_id: T::EntryId,
+        bounty: &Bounty<T>,
+        entry: &Entry<T>,
+        worker_account_id: &T::AccountId,
+        pending_judgment: PendingWorkEntryJudgment<BalanceOf<T>>,
+    ) {
+        match pending_judgment {
+            PendingWorkEntryJudgment::Winner { reward } => {
+                Self::transfer_funds_from_bounty_account(worker_account_id, bounty_id, reward);
+
+                Self::deposit_event(RawEvent::WorkEntrantFundsWithdrawn(
+                    bounty_id,
+                    entry_id,
+                    entry.member_id,
+                ));
+            }
+            PendingWorkEntryJudgment::Rejected { slashing_share } => {
+                let slashing_amount = slashing_share * bounty.creation_params.entrant_stake;
+
+                if slashing_amount > Zero::zero() {
+                    T::StakingHandler::slash(&entry.staking_account_id, Some(slashing_amount));
+                }
+
+                Self::deposit_event(RawEvent::WorkEntrantStakeSlashed(
+                    bounty_id,
+                    entry_id,
+                    entry.staking_account_id.clone(),
+                    slashing_amount,
+                ));
+            }
+        }
+    }
     // Calculate cherry fraction to reward member for an unsuccessful bounty.
     // Cherry fraction = cherry * (member funding / total funding).
     fn get_cherry_fraction_for_member(
@@ -2056,6 +2998,81 @@ impl<T: Config> Module<T> {
         funding_share * bounty.creation_params.cherry
     }
 
+    // Calculate the forfeited oracle reward fraction to return to the member.
+    // Oracle reward fraction = forfeited oracle reward * (member funding / total funding).
+    fn get_forfeited_oracle_reward_fraction_for_member(
+        bounty: &Bounty<T>,
+        funding_amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        if bounty.forfeited_oracle_reward.is_zero() {
+            return Zero::zero();
+        }
+
+        let funding_share = Perbill::from_rational(funding_amount, bounty.total_funding);
+
+        funding_share * bounty.forfeited_oracle_reward
+    }
+
+    // Calculate the part of the member funding paid for the completed work milestones.
+    // Paid funding fraction = paid funding * (member funding / total funding).
+    fn get_paid_funding_fraction_for_member(
+        bounty: &Bounty<T>,
+        funding_amount: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        let paid_funding = Self::get_paid_work_milestones_funding(bounty);
+        if paid_funding.is_zero() {
+            return Zero::zero();
+        }
+
+        let funding_share = Perbill::from_rational(funding_amount, bounty.total_funding);
+
+        funding_share * paid_funding
+    }
+
+    // Calculates the funding paid for the completed work milestones.
+    fn get_paid_work_milestones_funding(bounty: &Bounty<T>) -> BalanceOf<T> {
+        bounty
+            .creation_params
+            .work_milestones
+            .iter()
+            .take(bounty.completed_work_milestones.saturated_into())
+            .fold(
+                Zero::zero(),
+                |paid_funding: BalanceOf<T>, work_milestone| {
+                    paid_funding.saturating_add(work_milestone.funding_share * bounty.total_funding)
+                },
+            )
+    }
+
+    // Checks whether there are work milestones left after the current one.
+    fn has_next_work_milestone(bounty: &Bounty<T>) -> bool {
+        bounty.completed_work_milestones.saturating_add(1)
+            < bounty
+                .creation_params
+                .work_milestones
+                .len()
+                .saturated_into::<u32>()
+    }
+
+    // Calculates the reward for the winners of the current work milestone: its share of
+    // the total funding or the rest of the funding for the last one.
+    // It is the total funding for the bounty without work milestones.
+    pub(crate) fn work_milestone_payout(bounty: &Bounty<T>) -> BalanceOf<T> {
+        let current_work_milestone = bounty
+            .creation_params
+            .work_milestones
+            .get(bounty.completed_work_milestones.saturated_into::<usize>());
+
+        match current_work_milestone {
+            Some(work_milestone) if Self::has_next_work_milestone(bounty) => {
+                work_milestone.funding_share * bounty.total_funding
+            }
+            _ => bounty
+                .total_funding
+                .saturating_sub(Self::get_paid_work_milestones_funding(bounty)),
+        }
+    }
+
     /// Remove bounty and all related info from the storage.
     fn remove_bounty(
         bounty_id: &T::BountyId,
@@ -2202,6 +3219,24 @@ impl<T: Config> Module<T> {
         }
     }
 
+    // Verifies work entry existence and retrieves an entry unless it was rejected by the final
+    // oracle judgment kept for the judgment dispute period: such entry can only be withdrawn.
+    fn ensure_active_work_entry_exists(
+        bounty_id: &T::BountyId,
+        bounty: &Bounty<T>,
+        entry_id: &T::EntryId,
+    ) -> Result<Entry<T>, DispatchError> {
+        let entry = Self::ensure_work_entry_exists(bounty_id, entry_id)?;
+
+        let rejected = matches!(
+            entry.pending_judgment,
+            Some(PendingWorkEntryJudgment::Rejected { .. })
+        ) && Self::is_pending_judgment_final(bounty, &entry);
+        ensure!(!rejected, Error::<T>::WorkEntryDoesntExist);
+
+        Ok(entry)
+    }
+
     // Ensures entry record ownership for a member.
     fn ensure_work_entry_ownership(
         entry: &Entry<T>,
@@ -2239,6 +3274,21 @@ impl<T: Config> Module<T> {
         sc.get_bounty_stage()
     }
 
+    // Returns the block when the work period of the bounty started (if it did).
+    fn get_work_period_start(bounty: &Bounty<T>) -> Option<T::BlockNumber> {
+        let sc = BountyStageCalculator::<T> {
+            now: Self::current_block(),
+            bounty,
+        };
+
+        sc.work_period_started_at()
+    }
+
+    // Checks whether the work entry has the work submitted for the current work milestone.
+    fn has_work_submission(bounty: &Bounty<T>, entry: &Entry<T>) -> bool {
+        entry.work_submitted && entry.work_milestone == bounty.completed_work_milestones
+    }
+
     // Validates oracle judgment.
     fn validate_judgment(
         bounty_id: &T::BountyId,
@@ -2250,7 +3300,7 @@ impl<T: Config> Module<T> {
 
         // Validate all work entry Judgments.
         for (entry_id, work_entry_judgment) in judgment.iter() {
-            let entry = Self::ensure_work_entry_exists(bounty_id, entry_id)?;
+            let entry = Self::ensure_active_work_entry_exists(bounty_id, bounty, entry_id)?;
             //checks if member_id exists
             T::Membership::controller_account_id(entry.member_id)?;
             if let OracleWorkEntryJudgment::Winner { reward } = work_entry_judgment {
@@ -2258,7 +3308,7 @@ impl<T: Config> Module<T> {
                 ensure!(*reward != Zero::zero(), Error::<T>::ZeroWinnerReward);
                 // Check winner work submission.
                 ensure!(
-                    entry.work_submitted,
+                    Self::has_work_submission(bounty, &entry),
                     Error::<T>::WinnerShouldHasWorkSubmission
                 );
                 reward_sum_from_judgment = reward_sum_from_judgment.saturating_add(*reward);
@@ -2267,15 +3317,49 @@ impl<T: Config> Module<T> {
 
         // Check for invalid total sum for successful bounty.
         if reward_sum_from_judgment != Zero::zero() {
-            ensure!(
-                reward_sum_from_judgment == bounty.total_funding, // 100% bounty distribution
-                Error::<T>::TotalRewardShouldBeEqualToTotalFunding
-            );
+            if bounty.creation_params.work_milestones.is_empty() {
+                ensure!(
+                    reward_sum_from_judgment == bounty.total_funding, // 100% bounty distribution
+                    Error::<T>::TotalRewardShouldBeEqualToTotalFunding
+                );
+            } else {
+                ensure!(
+                    reward_sum_from_judgment == Self::work_milestone_payout(bounty),
+                    Error::<T>::TotalRewardShouldBeEqualToWorkMilestonePayout
+                );
+            }
         }
 
         Ok(())
     }
 
+    // Validates the corrected judgment of the council: it should judge all the work entries
+    // judged by the overturned oracle judgment.
+    fn validate_corrected_judgment(
+        bounty_id: &T::BountyId,
+        bounty: &Bounty<T>,
+        corrected_judgment: &OracleJudgmentOf<T>,
+    ) -> DispatchResult {
+        Self::validate_judgment(bounty_id, bounty, corrected_judgment)?;
+
+        let mut judged_entry_count = 0u32;
+        for entry_id in corrected_judgment.keys() {
+            let entry = Self::ensure_work_entry_exists(bounty_id, entry_id)?;
+
+            if entry.pending_judgment.is_some() && !Self::is_pending_judgment_final(bounty, &entry)
+            {
+                judged_entry_count = judged_entry_count.saturating_add(1);
+            }
+        }
+
+        ensure!(
+            judged_entry_count == bounty.pending_judgment_count,
+            Error::<T>::CorrectedJudgmentShouldJudgeAllJudgedWorkEntries
+        );
+
+        Ok(())
+    }
+
     // Removes the work entry and decrements active entry count in a bounty.
     fn remove_work_entry(bounty_id: &T::BountyId, entry_id: &T::EntryId) {
         <Entries<T>>::remove(bounty_id, entry_id);
@@ -2308,6 +3392,15 @@ impl<T: Config> Module<T> {
             }
             BountyStage::WorkSubmission => Error::<T>::InvalidStageUnexpectedWorkSubmission.into(),
             BountyStage::Judgment => Error::<T>::InvalidStageUnexpectedJudgment.into(),
+            BountyStage::JudgmentDisputeWindow => {
+                Error::<T>::InvalidStageUnexpectedJudgmentDisputeWindow.into()
+            }
+            BountyStage::JudgmentDisputed => {
+                Error::<T>::InvalidStageUnexpectedJudgmentDisputed.into()
+            }
+            BountyStage::PendingJudgmentFinalization => {
+                Error::<T>::InvalidStageUnexpectedPendingJudgmentFinalization.into()
+            }
             BountyStage::SuccessfulBountyWithdrawal => {
                 Error::<T>::InvalidStageUnexpectedSuccessfulBountyWithdrawal.into()
             }
@@ -2381,6 +3474,21 @@ impl<T: Config> Module<T> {
             WeightInfoBounty::<T>::submit_oracle_judgment_by_member(j, k, w, r),
         )
     }
+
+    // Calculates weight for resolve_oracle_judgment_dispute extrinsic: the corrected judgment
+    // is applied as the oracle judgment without the judgment dispute period.
+    fn resolve_oracle_judgment_dispute_weight(
+        corrected_judgment: &Option<OracleJudgmentOf<T>>,
+    ) -> Weight {
+        let corrected_judgment_weight = corrected_judgment
+            .as_ref()
+            .map_or(Weight::zero(), |judgment| {
+                Self::submit_oracle_judgment_weight(judgment, 0)
+            });
+
+        WeightInfoBounty::<T>::resolve_oracle_judgment_dispute()
+            .saturating_add(corrected_judgment_weight)
+    }
 }
 
 impl<T: Config> frame_support::traits::Hooks<T::BlockNumber> for Pallet<T> {
@@ -0,0 +1,200 @@
+// Migrations for Bounty Pallet
+
+use super::*;
+use frame_support::storage::StoragePrefixedMap;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+use frame_support::weights::Weight;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
+
+pub mod v1 {
+    use super::*;
+    use codec::{Decode, Encode};
+
+    // Bounty creation parameters without the work milestones
+    #[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
+    struct BountyParametersV0<Balance, BlockNumber, MemberId: Ord, ClosedContractWhitelist> {
+        oracle: BountyActor<MemberId>,
+        contract_type: AssuranceContractType<ClosedContractWhitelist>,
+        creator: BountyActor<MemberId>,
+        cherry: Balance,
+        oracle_reward: Balance,
+        entrant_stake: Balance,
+        funding_type: FundingType<BlockNumber, Balance>,
+    }
+
+    // Bounty milestone without the completed work milestone state
+    #[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
+    enum BountyMilestoneV0<BlockNumber> {
+        Created {
+            created_at: BlockNumber,
+            has_contributions: bool,
+        },
+        BountyMaxFundingReached,
+        WorkSubmitted,
+        Terminated,
+        JudgmentSubmitted {
+            successful_bounty: bool,
+        },
+    }
+
+    // Bounty with a single work period
+    #[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
+    struct BountyRecordV0<Balance, BlockNumber, MemberId: Ord, ClosedContractWhitelist> {
+        creation_params:
+            BountyParametersV0<Balance, BlockNumber, MemberId, ClosedContractWhitelist>,
+        total_funding: Balance,
+        milestone: BountyMilestoneV0<BlockNumber>,
+        active_work_entry_count: u32,
+        has_unpaid_oracle_reward: bool,
+    }
+
+    // Work entry submitted for the single work period without the pending judgment
+    #[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
+    struct EntryRecordV0<AccountId, MemberId, BlockNumber> {
+        member_id: MemberId,
+        staking_account_id: AccountId,
+        submitted_at: BlockNumber,
+        work_submitted: bool,
+    }
+
+    type BountyV0<T> = BountyRecordV0<
+        BalanceOf<T>,
+        <T as frame_system::Config>::BlockNumber,
+        <T as MembershipTypes>::MemberId,
+        ClosedContractWhitelist<T>,
+    >;
+
+    type EntryV0<T> = EntryRecordV0<
+        <T as frame_system::Config>::AccountId,
+        <T as MembershipTypes>::MemberId,
+        <T as frame_system::Config>::BlockNumber,
+    >;
+
+    impl<BlockNumber> From<BountyMilestoneV0<BlockNumber>> for BountyMilestone<BlockNumber> {
+        fn from(milestone: BountyMilestoneV0<BlockNumber>) -> Self {
+            match milestone {
+                BountyMilestoneV0::Created {
+                    created_at,
+                    has_contributions,
+                } => BountyMilestone::Created {
+                    created_at,
+                    has_contributions,
+                },
+                BountyMilestoneV0::BountyMaxFundingReached => {
+                    BountyMilestone::BountyMaxFundingReached
+                }
+                BountyMilestoneV0::WorkSubmitted => BountyMilestone::WorkSubmitted,
+                BountyMilestoneV0::Terminated => BountyMilestone::Terminated,
+                BountyMilestoneV0::JudgmentSubmitted { successful_bounty } => {
+                    BountyMilestone::JudgmentSubmitted { successful_bounty }
+                }
+            }
+        }
+    }
+
+    /// Converts the stored bounties and work entries into the work milestone layout:
+    /// - the bounties have no work milestones (a single work period and oracle judgment)
+    /// - no work milestone is completed and the work period start is not set (the work period
+    ///   starts when the funding period ends)
+    /// - the work of the entries is submitted for the first work milestone
+    /// - the bounties have no judgment dispute period and no oracle stake: the oracle
+    ///   judgments are final right after the submission
+    /// - no oracle judgment is pending and no oracle reward is forfeited
+    pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            let onchain = Module::<T>::on_chain_storage_version();
+
+            ensure!(onchain < 1, "this migration can be deleted");
+
+            let bounty_count = Bounties::<T>::iter_keys().count() as u64;
+            let entry_count = Entries::<T>::iter_keys().count() as u64;
+
+            Ok((bounty_count, entry_count).encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let onchain = Module::<T>::on_chain_storage_version();
+            let current = Module::<T>::current_storage_version();
+
+            if onchain > 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut translated = 0u64;
+
+            Bounties::<T>::translate_values(|old: BountyV0<T>| {
+                translated = translated.saturating_add(1);
+
+                let params = old.creation_params;
+
+                Some(Bounty::<T> {
+                    creation_params: BountyStoredCreationParameters::<T> {
+                        oracle: params.oracle,
+                        contract_type: params.contract_type,
+                        creator: params.creator,
+                        cherry: params.cherry,
+                        oracle_reward: params.oracle_reward,
+                        entrant_stake: params.entrant_stake,
+                        funding_type: params.funding_type,
+                        work_milestones: Default::default(),
+                        judgment_dispute_period: Zero::zero(),
+                        oracle_stake: Zero::zero(),
+                    },
+                    total_funding: old.total_funding,
+                    milestone: old.milestone.into(),
+                    active_work_entry_count: old.active_work_entry_count,
+                    has_unpaid_oracle_reward: old.has_unpaid_oracle_reward,
+                    completed_work_milestones: 0,
+                    work_period_started_at: None,
+                    pending_judgment_count: 0,
+                    forfeited_oracle_reward: Zero::zero(),
+                })
+            });
+
+            Entries::<T>::translate_values(|old: EntryV0<T>| {
+                translated = translated.saturating_add(1);
+
+                Some(Entry::<T> {
+                    member_id: old.member_id,
+                    staking_account_id: old.staking_account_id,
+                    submitted_at: old.submitted_at,
+                    work_submitted: old.work_submitted,
+                    work_milestone: 0,
+                    pending_judgment: None,
+                    judged_work_milestone: 0,
+                })
+            });
+
+            current.put::<Module<T>>();
+
+            T::DbWeight::get()
+                .reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let onchain = Module::<T>::on_chain_storage_version();
+            ensure!(onchain == 1, "this migration needs to be run");
+
+            let (pre_upgrade_bounty_count, pre_upgrade_entry_count) =
+                <(u64, u64)>::decode(&mut state.as_slice())
+                    .map_err(|_| "invalid pre_upgrade state")?;
+            let bounty_count = Bounties::<T>::iter_values().count() as u64;
+            ensure!(
+                pre_upgrade_bounty_count == bounty_count,
+                "bounty counts differ post_upgrade"
+            );
+            let entry_count = Entries::<T>::iter_values().count() as u64;
+            ensure!(
+                pre_upgrade_entry_count == entry_count,
+                "entry counts differ post_upgrade"
+            );
+
+            Ok(())
+        }
+    }
+}
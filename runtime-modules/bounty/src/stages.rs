@@ -2,7 +2,9 @@
 //! It allows to get a bounty stage based on the current bounty state and the current system block.
 
 use crate::{Bounty, BountyMilestone, BountyStage, Config, FundingType};
+use frame_support::traits::Get;
 use sp_arithmetic::traits::Saturating;
+use sp_runtime::SaturatedConversion;
 
 // Bounty stage helper.
 pub(crate) struct BountyStageCalculator<'a, T: Config> {
@@ -19,6 +21,7 @@ impl<'a, T: Config> BountyStageCalculator<'a, T> {
             .or_else(|| self.is_funding_expired_stage())
             .or_else(|| self.is_work_submission_stage())
             .or_else(|| self.is_judgment_stage())
+            .or_else(|| self.is_judgment_dispute_stage())
             .or_else(|| self.is_successful_bounty_withdrawal_stage())
             .unwrap_or(BountyStage::FailedBountyWithdrawal)
     }
@@ -58,40 +61,109 @@ impl<'a, T: Config> BountyStageCalculator<'a, T> {
     // Calculates work submission stage of the bounty.
     // Returns None if conditions are not met.
     fn is_work_submission_stage(&self) -> Option<BountyStage> {
+        // Work period is not expired.
+        match self.is_work_period() && !self.work_milestone_deadline_passed() {
+            true => Some(BountyStage::WorkSubmission),
+            false => None,
+        }
+    }
+
+    // Calculates judgment stage of the bounty.
+    // Returns None if conditions are not met.
+    fn is_judgment_stage(&self) -> Option<BountyStage> {
+        // Oracle ended the work period or the current work milestone deadline passed.
+        let work_submitted = BountyMilestone::WorkSubmitted == self.bounty.milestone
+            || (self.is_work_period() && self.work_milestone_deadline_passed());
+
+        // Can be judged only if there are work submissions.
+        if self.bounty.active_work_entry_count > 0 && work_submitted {
+            return Some(BountyStage::Judgment);
+        }
+
+        None
+    }
+
+    // Checks whether the bounty gathered necessary funds and the work wasn't submitted yet.
+    fn is_work_period(&self) -> bool {
         match self.bounty.milestone.clone() {
-            // Funding period is over. Minimum funding reached. Work period is not expired.
+            // Funding period is over. Minimum funding reached.
             BountyMilestone::Created { created_at, .. } => {
                 match self.bounty.creation_params.funding_type {
                     // Perpetual funding is not reached its target yet.
-                    FundingType::Perpetual { .. } => None,
+                    FundingType::Perpetual { .. } => false,
                     FundingType::Limited { .. } => {
-                        let target_reached_and_period_expired = self.target_funding_reached()
-                            && self.funding_period_expired(created_at);
-
-                        match target_reached_and_period_expired {
-                            true => Some(BountyStage::WorkSubmission),
-                            false => None,
-                        }
+                        self.target_funding_reached() && self.funding_period_expired(created_at)
                     }
                 }
             }
-            // Target funding reached. Work period is not expired.
-            BountyMilestone::BountyMaxFundingReached { .. } => Some(BountyStage::WorkSubmission),
+            // Target funding reached.
+            BountyMilestone::BountyMaxFundingReached { .. } => true,
+            // Previous work milestone was completed.
+            BountyMilestone::WorkMilestoneCompleted => true,
+            _ => false,
+        }
+    }
+
+    // Checks whether the deadline of the current work milestone passed.
+    // Always false for the bounty without work milestones.
+    fn work_milestone_deadline_passed(&self) -> bool {
+        let current_work_milestone = self.bounty.creation_params.work_milestones.get(
+            self.bounty
+                .completed_work_milestones
+                .saturated_into::<usize>(),
+        );
+
+        match (current_work_milestone, self.work_period_started_at()) {
+            (Some(work_milestone), Some(work_period_started_at)) => {
+                work_period_started_at.saturating_add(work_milestone.deadline) < self.now
+            }
+            _ => false,
+        }
+    }
+
+    // Returns the block when the work period started: the block of reaching the target funding
+    // or the end of the funding period.
+    pub(crate) fn work_period_started_at(&self) -> Option<T::BlockNumber> {
+        if self.bounty.work_period_started_at.is_some() {
+            return self.bounty.work_period_started_at;
+        }
+
+        match (
+            self.bounty.milestone.clone(),
+            self.bounty.creation_params.funding_type.clone(),
+        ) {
+            (
+                BountyMilestone::Created { created_at, .. },
+                FundingType::Limited { funding_period, .. },
+            ) => Some(created_at.saturating_add(funding_period)),
             _ => None,
         }
     }
 
-    // Calculates judgment stage of the bounty.
+    // Calculates judgment dispute stages of the bounty.
     // Returns None if conditions are not met.
-    fn is_judgment_stage(&self) -> Option<BountyStage> {
-        // Can be judged only if there are work submissions.
-        if self.bounty.active_work_entry_count > 0
-            && BountyMilestone::WorkSubmitted == self.bounty.milestone
-        {
-            return Some(BountyStage::Judgment);
-        }
+    fn is_judgment_dispute_stage(&self) -> Option<BountyStage> {
+        match self.bounty.milestone.clone() {
+            // The judgment can be disputed during the judgment dispute period.
+            BountyMilestone::JudgmentPending { submitted_at, .. } => {
+                let dispute_period = self.bounty.creation_params.judgment_dispute_period;
 
-        None
+                match submitted_at.saturating_add(dispute_period) < self.now {
+                    true => Some(BountyStage::PendingJudgmentFinalization),
+                    false => Some(BountyStage::JudgmentDisputeWindow),
+                }
+            }
+            // The council can decide the dispute during the dispute decision period.
+            BountyMilestone::JudgmentDisputed { disputed_at, .. } => {
+                let decision_period = T::JudgmentDisputeDecisionPeriod::get();
+
+                match disputed_at.saturating_add(decision_period) < self.now {
+                    true => Some(BountyStage::PendingJudgmentFinalization),
+                    false => Some(BountyStage::JudgmentDisputed),
+                }
+            }
+            _ => None,
+        }
     }
 
     // Calculates withdrawal stage for the bounty.
@@ -105,6 +177,8 @@ impl<'a, T: Config> BountyStageCalculator<'a, T> {
                 true => Some(BountyStage::SuccessfulBountyWithdrawal),
                 false => Some(BountyStage::FailedBountyWithdrawal),
             },
+            // The council overturned the judgment.
+            BountyMilestone::JudgmentOverturned => Some(BountyStage::FailedBountyWithdrawal),
             _ => None,
         }
     }
@@ -1,7 +1,8 @@
 use super::mocks::{Balances, Bounty, RuntimeEvent, System, Test};
 use crate::{
     AssuranceContractType, BountyActor, BountyCreationParameters, BountyMilestone, BountyRecord,
-    ClosedContractWhitelist, Config, Entry, FundingType, OracleJudgmentOf, RawEvent,
+    ClosedContractWhitelist, Config, Entry, FundingType, OracleJudgmentOf, RawEvent, WorkMilestone,
+    WorkMilestones,
 };
 use common::council::CouncilBudgetManager;
 use frame_support::dispatch::DispatchResult;
@@ -128,6 +129,9 @@ pub struct CreateBountyFixture {
     entrant_stake: u64,
     contract_type: AssuranceContractType<BTreeSet<u64>>,
     oracle: BountyActor<u64>,
+    work_milestones: Vec<WorkMilestone<u64>>,
+    judgment_dispute_period: u64,
+    oracle_stake: u64,
 }
 
 impl CreateBountyFixture {
@@ -145,6 +149,9 @@ impl CreateBountyFixture {
             entrant_stake: DEFAULT_BOUNTY_ENTRANT_STAKE,
             contract_type: AssuranceContractType::Open,
             oracle: BountyActor::Council,
+            work_milestones: Vec::new(),
+            judgment_dispute_period: 0,
+            oracle_stake: 0,
         }
     }
 
@@ -234,6 +241,27 @@ impl CreateBountyFixture {
         }
     }
 
+    pub fn with_work_milestones(self, work_milestones: Vec<WorkMilestone<u64>>) -> Self {
+        Self {
+            work_milestones,
+            ..self
+        }
+    }
+
+    pub fn with_judgment_dispute_period(self, judgment_dispute_period: u64) -> Self {
+        Self {
+            judgment_dispute_period,
+            ..self
+        }
+    }
+
+    pub fn with_oracle_stake(self, oracle_stake: u64) -> Self {
+        Self {
+            oracle_stake,
+            ..self
+        }
+    }
+
     pub fn get_bounty_creation_parameters(&self) -> BountyCreationParameters<Test> {
         BountyCreationParameters::<Test> {
             creator: self.creator.clone(),
@@ -243,6 +271,9 @@ impl CreateBountyFixture {
             entrant_stake: self.entrant_stake,
             contract_type: self.contract_type.clone(),
             oracle: self.oracle.clone(),
+            work_milestones: self.work_milestones.clone(),
+            judgment_dispute_period: self.judgment_dispute_period,
+            oracle_stake: self.oracle_stake,
             ..Default::default()
         }
     }
@@ -273,12 +304,22 @@ impl CreateBountyFixture {
                 },
             };
 
-            let expected_bounty = BountyRecord::<u64, u64, u64, ClosedContractWhitelist<Test>> {
+            let expected_bounty = BountyRecord::<
+                u64,
+                u64,
+                u64,
+                ClosedContractWhitelist<Test>,
+                WorkMilestones<Test>,
+            > {
                 creation_params: params.clone().try_into().unwrap(),
                 total_funding: 0,
                 milestone: expected_milestone,
                 active_work_entry_count: 0,
                 has_unpaid_oracle_reward: params.oracle_reward > 0,
+                completed_work_milestones: 0,
+                work_period_started_at: None,
+                pending_judgment_count: 0,
+                forfeited_oracle_reward: 0,
             };
 
             assert_eq!(expected_bounty, Bounty::bounties(bounty_id));
@@ -490,6 +531,9 @@ impl AnnounceWorkEntryFixture {
                 staking_account_id: self.staking_account_id,
                 submitted_at: System::block_number(),
                 work_submitted: false,
+                work_milestone: 0,
+                judged_work_milestone: 0,
+                pending_judgment: None,
             };
             assert_eq!(
                 expected_entry,
@@ -620,12 +664,17 @@ impl SubmitJudgmentFixture {
         let new_bounty = Bounty::bounties(self.bounty_id);
 
         if actual_result.is_ok() {
-            assert_eq!(
-                new_bounty.milestone,
-                BountyMilestone::JudgmentSubmitted {
-                    successful_bounty: Bounty::judgment_has_winners(&self.judgment)
+            let successful_bounty = Bounty::judgment_has_winners(&self.judgment);
+            let expected_milestone = if old_bounty.creation_params.judgment_dispute_period > 0 {
+                BountyMilestone::JudgmentPending {
+                    successful_bounty,
+                    submitted_at: System::block_number(),
                 }
-            );
+            } else {
+                BountyMilestone::JudgmentSubmitted { successful_bounty }
+            };
+
+            assert_eq!(new_bounty.milestone, expected_milestone);
         } else {
             assert_eq!(new_bounty, old_bounty);
         }
@@ -726,6 +775,189 @@ impl EndWorkPeriodFixture {
     }
 }
 
+pub struct DisputeOracleJudgmentFixture {
+    origin: RawOrigin<u128>,
+    member_id: u64,
+    bounty_id: u64,
+    entry_id: Option<u64>,
+    rationale: Vec<u8>,
+}
+
+impl DisputeOracleJudgmentFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            member_id: 1,
+            bounty_id: 1,
+            entry_id: Some(1),
+            rationale: b"rationale".to_vec(),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u128>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: u64) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_bounty_id(self, bounty_id: u64) -> Self {
+        Self { bounty_id, ..self }
+    }
+
+    pub fn with_entry_id(self, entry_id: Option<u64>) -> Self {
+        Self { entry_id, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_bounty = Bounty::bounties(self.bounty_id);
+        let actual_result = Bounty::dispute_oracle_judgment(
+            self.origin.clone().into(),
+            self.member_id,
+            self.bounty_id,
+            self.entry_id,
+            self.rationale.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_bounty = Bounty::bounties(self.bounty_id);
+        if actual_result.is_ok() {
+            assert!(<crate::JudgmentDisputes<Test>>::contains_key(
+                self.bounty_id
+            ));
+
+            assert!(matches!(
+                new_bounty.milestone,
+                BountyMilestone::JudgmentDisputed { disputed_at, .. }
+                    if disputed_at == System::block_number()
+            ));
+        } else {
+            assert_eq!(new_bounty, old_bounty);
+        }
+    }
+}
+
+pub struct ResolveJudgmentDisputeFixture {
+    origin: RawOrigin<u128>,
+    bounty_id: u64,
+    overturn_judgment: bool,
+    corrected_judgment: Option<OracleJudgmentOf<Test>>,
+}
+
+impl ResolveJudgmentDisputeFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Root,
+            bounty_id: 1,
+            overturn_judgment: false,
+            corrected_judgment: None,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u128>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_bounty_id(self, bounty_id: u64) -> Self {
+        Self { bounty_id, ..self }
+    }
+
+    pub fn with_overturn_judgment(self, overturn_judgment: bool) -> Self {
+        Self {
+            overturn_judgment,
+            ..self
+        }
+    }
+
+    pub fn with_corrected_judgment(self, corrected_judgment: OracleJudgmentOf<Test>) -> Self {
+        Self {
+            corrected_judgment: Some(corrected_judgment),
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_bounty = Bounty::bounties(self.bounty_id);
+        let actual_result = Bounty::resolve_oracle_judgment_dispute(
+            self.origin.clone().into(),
+            self.bounty_id,
+            self.overturn_judgment,
+            self.corrected_judgment.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_bounty = Bounty::bounties(self.bounty_id);
+        if actual_result.is_ok() {
+            assert!(!<crate::JudgmentDisputes<Test>>::contains_key(
+                self.bounty_id
+            ));
+
+            if self.overturn_judgment {
+                assert!(!new_bounty.has_unpaid_oracle_reward);
+            }
+
+            if self.overturn_judgment && self.corrected_judgment.is_none() {
+                assert_eq!(new_bounty.milestone, BountyMilestone::JudgmentOverturned);
+            } else {
+                assert!(matches!(
+                    new_bounty.milestone,
+                    BountyMilestone::JudgmentSubmitted { .. }
+                        | BountyMilestone::WorkMilestoneCompleted
+                ));
+            }
+        } else {
+            assert_eq!(new_bounty, old_bounty);
+        }
+    }
+}
+
+pub struct FinalizeOracleJudgmentFixture {
+    origin: RawOrigin<u128>,
+    bounty_id: u64,
+}
+
+impl FinalizeOracleJudgmentFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            bounty_id: 1,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u128>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_bounty_id(self, bounty_id: u64) -> Self {
+        Self { bounty_id, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_bounty = Bounty::bounties(self.bounty_id);
+        let actual_result =
+            Bounty::finalize_oracle_judgment(self.origin.clone().into(), self.bounty_id);
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_bounty = Bounty::bounties(self.bounty_id);
+        if actual_result.is_ok() {
+            assert!(!<crate::JudgmentDisputes<Test>>::contains_key(
+                self.bounty_id
+            ));
+
+            assert!(matches!(
+                new_bounty.milestone,
+                BountyMilestone::JudgmentSubmitted { .. } | BountyMilestone::WorkMilestoneCompleted
+            ));
+        } else {
+            assert_eq!(new_bounty, old_bounty);
+        }
+    }
+}
+
 pub struct WithdrawEntrantStakeFixture {
     origin: RawOrigin<u128>,
     entry_id: u64,
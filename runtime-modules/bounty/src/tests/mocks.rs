@@ -45,6 +45,9 @@ parameter_types! {
     pub const MinWorkEntrantStake: u64 = 10;
     pub const CreatorStateBloatBondAmount: u64 = 10;
     pub const FunderStateBloatBondAmount: u64 = 10;
+    pub const MaxWorkMilestones: u32 = 3;
+    pub const JudgmentDisputeBond: u64 = 50;
+    pub const JudgmentDisputeDecisionPeriod: u64 = 10;
 }
 
 impl frame_system::Config for Test {
@@ -87,6 +90,9 @@ impl Config for Test {
     type MinWorkEntrantStake = MinWorkEntrantStake;
     type CreatorStateBloatBondAmount = CreatorStateBloatBondAmount;
     type FunderStateBloatBondAmount = FunderStateBloatBondAmount;
+    type MaxWorkMilestones = MaxWorkMilestones;
+    type JudgmentDisputeBond = JudgmentDisputeBond;
+    type JudgmentDisputeDecisionPeriod = JudgmentDisputeDecisionPeriod;
 }
 
 pub const STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER: u128 = 10000;
@@ -246,6 +252,12 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for Wg {
         unimplemented!()
     }
 
+    fn get_worker_reward_account_id(
+        _: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Test as frame_system::Config>::AccountId> {
+        unimplemented!()
+    }
+
     fn is_leader_account_id(_account_id: &<Test as frame_system::Config>::AccountId) -> bool {
         unimplemented!()
     }
@@ -6,23 +6,25 @@ pub(crate) mod mocks;
 use crate::{
     Bounties, BountyActor, BountyContributions, BountyMilestone, BountyRecord, BountyStage,
     BountyStoredCreationParameters, Entries, Error, FundingType, OracleJudgment,
-    OracleWorkEntryJudgment, RawEvent,
+    OracleWorkEntryJudgment, PendingWorkEntryJudgment, RawEvent, WorkMilestone,
 };
 use fixtures::{
     get_council_budget, get_creator_state_bloat_bond_amount, get_funder_state_bloat_bond_amount,
     increase_account_balance, increase_total_balance_issuance_using_account_id, run_to_block,
-    set_council_budget, AnnounceWorkEntryFixture, CreateBountyFixture, EndWorkPeriodFixture,
-    EventFixture, FundBountyFixture, SubmitJudgmentFixture, SubmitWorkFixture, SwitchOracleFixture,
-    TerminateBountyFixture, WithdrawEntrantStakeFixture, WithdrawFundingFixture,
-    WithdrawOracleRewardFixture, DEFAULT_BOUNTY_CHERRY, DEFAULT_BOUNTY_ORACLE_REWARD,
+    set_council_budget, AnnounceWorkEntryFixture, CreateBountyFixture,
+    DisputeOracleJudgmentFixture, EndWorkPeriodFixture, EventFixture,
+    FinalizeOracleJudgmentFixture, FundBountyFixture, ResolveJudgmentDisputeFixture,
+    SubmitJudgmentFixture, SubmitWorkFixture, SwitchOracleFixture, TerminateBountyFixture,
+    WithdrawEntrantStakeFixture, WithdrawFundingFixture, WithdrawOracleRewardFixture,
+    DEFAULT_BOUNTY_CHERRY, DEFAULT_BOUNTY_ORACLE_REWARD,
 };
 use frame_support::storage::{StorageDoubleMap, StorageMap};
 use frame_support::{assert_err, assert_ok};
 use frame_system::RawOrigin;
 use mocks::{
-    build_test_externalities, Balances, Bounty, ClosedContractSizeLimit, System, Test,
-    COUNCIL_BUDGET_ACCOUNT_ID, INVALID_ACCOUNT_ID, INVALID_MEMBER_ID, MAX_MEMBERS,
-    STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER,
+    build_test_externalities, Balances, Bounty, ClosedContractSizeLimit, JudgmentDisputeBond,
+    JudgmentDisputeDecisionPeriod, System, Test, COUNCIL_BUDGET_ACCOUNT_ID, INVALID_ACCOUNT_ID,
+    INVALID_MEMBER_ID, MAX_MEMBERS, STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER,
 };
 use sp_runtime::DispatchError;
 use sp_runtime::DispatchError::Other;
@@ -4775,6 +4777,1555 @@ fn submit_judgment_fails_with_invalid_judgment() {
     });
 }
 
+#[test]
+fn create_bounty_fails_with_invalid_work_milestones() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let work_milestone = |percent, deadline| WorkMilestone {
+            funding_share: Perbill::from_percent(percent),
+            deadline,
+        };
+
+        set_council_budget(500);
+
+        CreateBountyFixture::default()
+            .with_work_milestones(vec![
+                work_milestone(25, 10),
+                work_milestone(25, 20),
+                work_milestone(25, 30),
+                work_milestone(25, 40),
+            ])
+            .call_and_assert(Err(Error::<Test>::WorkMilestoneListIsTooLarge.into()));
+
+        CreateBountyFixture::default()
+            .with_work_milestones(vec![work_milestone(0, 10), work_milestone(100, 20)])
+            .call_and_assert(Err(Error::<Test>::InvalidWorkMilestoneFundingShares.into()));
+
+        CreateBountyFixture::default()
+            .with_work_milestones(vec![work_milestone(30, 10), work_milestone(60, 20)])
+            .call_and_assert(Err(Error::<Test>::InvalidWorkMilestoneFundingShares.into()));
+
+        CreateBountyFixture::default()
+            .with_work_milestones(vec![work_milestone(30, 20), work_milestone(70, 20)])
+            .call_and_assert(Err(Error::<Test>::InvalidWorkMilestoneDeadline.into()));
+
+        CreateBountyFixture::default()
+            .with_work_milestones(vec![work_milestone(30, 0), work_milestone(70, 20)])
+            .call_and_assert(Err(Error::<Test>::InvalidWorkMilestoneDeadline.into()));
+
+        // Deadlines are counted from the work period start.
+        CreateBountyFixture::default()
+            .with_limited_funding(100, 10)
+            .with_work_milestones(vec![work_milestone(30, 5), work_milestone(70, 20)])
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn submit_judgment_pays_work_milestones_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let entrant_stake = 37;
+        let oracle_member_id = 5;
+        let oracle_account_id = 5;
+        let cherry = 10;
+        let bounty_id = 1;
+        set_council_budget(initial_balance);
+
+        CreateBountyFixture::default()
+            .with_perpetual_period_target_amount(target_funding)
+            .with_entrant_stake(entrant_stake)
+            .with_oracle_member_id(oracle_member_id)
+            .with_cherry(cherry)
+            .with_work_milestones(vec![
+                WorkMilestone {
+                    funding_share: Perbill::from_percent(30),
+                    deadline: 20,
+                },
+                WorkMilestone {
+                    funding_share: Perbill::from_percent(70),
+                    deadline: 40,
+                },
+            ])
+            .call_and_assert(Ok(()));
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let work_member_id = 1;
+        let work_account_id = 1;
+        increase_account_balance(&work_account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_staking_account_id(work_account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let entry_id = 1;
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .call_and_assert(Ok(()));
+
+        // The whole funding can't be paid for the first work milestone.
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: target_funding,
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .with_judgment(judgment)
+            .call_and_assert(Err(
+                Error::<Test>::TotalRewardShouldBeEqualToWorkMilestonePayout.into(),
+            ));
+
+        let first_payout = 30;
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: first_payout,
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::WorkMilestoneCompleted(
+            bounty_id,
+            0,
+            first_payout,
+        ));
+
+        // The winner keeps the stake and should submit the work for the next work milestone.
+        assert_eq!(
+            Balances::usable_balance(&work_account_id),
+            initial_balance - entrant_stake + first_payout
+        );
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(bounty.completed_work_milestones, 1);
+        assert_eq!(bounty.milestone, BountyMilestone::WorkMilestoneCompleted);
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::WorkSubmission
+        );
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .call_and_assert(Ok(()));
+
+        let last_payout = target_funding - first_payout;
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: last_payout,
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::WorkMilestoneCompleted(
+            bounty_id,
+            1,
+            last_payout,
+        ));
+
+        EventFixture::contains_crate_event(RawEvent::BountyCreatorCherryWithdrawal(
+            bounty_id,
+            BountyActor::Council,
+        ));
+
+        assert_eq!(
+            Balances::usable_balance(&work_account_id),
+            initial_balance + target_funding
+        );
+        assert!(!<Entries<Test>>::contains_key(bounty_id, entry_id));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(bounty.completed_work_milestones, 2);
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::SuccessfulBountyWithdrawal
+        );
+    });
+}
+
+#[test]
+fn work_milestone_deadline_counts_from_work_period_start() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let bounty_id = 1;
+        let first_deadline = 10;
+        set_council_budget(initial_balance);
+
+        CreateBountyFixture::default()
+            .with_perpetual_period_target_amount(target_funding)
+            .with_work_milestones(vec![
+                WorkMilestone {
+                    funding_share: Perbill::from_percent(30),
+                    deadline: first_deadline,
+                },
+                WorkMilestone {
+                    funding_share: Perbill::from_percent(70),
+                    deadline: 20,
+                },
+            ])
+            .call_and_assert(Ok(()));
+
+        // The work period starts on reaching the target funding.
+        let work_period_started_at = 20;
+        run_to_block(work_period_started_at);
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(bounty.work_period_started_at, Some(work_period_started_at));
+
+        run_to_block(work_period_started_at + first_deadline);
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::WorkSubmission
+        );
+
+        let work_member_id = 1;
+        let work_account_id = 1;
+        increase_account_balance(&work_account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_staking_account_id(work_account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        run_to_block(work_period_started_at + first_deadline + 1);
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(Bounty::get_bounty_stage(&bounty), BountyStage::Judgment);
+    });
+}
+
+#[test]
+fn submit_judgment_fails_with_work_submitted_for_previous_work_milestone() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let oracle_member_id = 5;
+        let oracle_account_id = 5;
+        let bounty_id = 1;
+        set_council_budget(initial_balance);
+
+        CreateBountyFixture::default()
+            .with_perpetual_period_target_amount(target_funding)
+            .with_oracle_member_id(oracle_member_id)
+            .with_work_milestones(vec![
+                WorkMilestone {
+                    funding_share: Perbill::from_percent(30),
+                    deadline: 20,
+                },
+                WorkMilestone {
+                    funding_share: Perbill::from_percent(70),
+                    deadline: 40,
+                },
+            ])
+            .call_and_assert(Ok(()));
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        // Both entrants submit the work for the first work milestone.
+        for work_member_id in [1, 2] {
+            let work_account_id = work_member_id;
+            increase_account_balance(&work_account_id, initial_balance);
+
+            AnnounceWorkEntryFixture::default()
+                .with_origin(RawOrigin::Signed(work_account_id))
+                .with_member_id(work_member_id)
+                .with_staking_account_id(work_account_id)
+                .with_bounty_id(bounty_id)
+                .call_and_assert(Ok(()));
+
+            SubmitWorkFixture::default()
+                .with_origin(RawOrigin::Signed(work_account_id))
+                .with_member_id(work_member_id)
+                .with_entry_id(work_member_id)
+                .call_and_assert(Ok(()));
+        }
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .call_and_assert(Ok(()));
+
+        let first_entry_id = 1;
+        let second_entry_id = 2;
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            first_entry_id,
+            OracleWorkEntryJudgment::Winner { reward: 30 },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .call_and_assert(Ok(()));
+
+        // Neither entry submitted the work for the last work milestone.
+        for entry_id in [first_entry_id, second_entry_id] {
+            let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+            judgment.insert(entry_id, OracleWorkEntryJudgment::Winner { reward: 70 });
+
+            SubmitJudgmentFixture::default()
+                .with_bounty_id(bounty_id)
+                .with_origin(RawOrigin::Signed(oracle_account_id))
+                .with_judgment(judgment)
+                .call_and_assert(Err(Error::<Test>::WinnerShouldHasWorkSubmission.into()));
+        }
+    });
+}
+
+#[test]
+fn withdraw_funding_after_failed_work_milestone_returns_unpaid_funding() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let entrant_stake = 37;
+        let cherry = 50;
+        let bounty_id = 1;
+
+        let funding_member_id = 2;
+        let funding_account_id = 2;
+
+        let worker_member_id = 3;
+        let worker_account_id = 3;
+
+        let oracle_member_id = 4;
+        let oracle_account_id = 4;
+
+        let first_deadline = 20;
+        let last_deadline = 40;
+
+        set_council_budget(initial_balance);
+        increase_account_balance(&funding_account_id, initial_balance);
+        increase_account_balance(&worker_account_id, entrant_stake);
+
+        CreateBountyFixture::default()
+            .with_limit_period_target_amount(target_funding)
+            .with_entrant_stake(entrant_stake)
+            .with_oracle_member_id(oracle_member_id)
+            .with_cherry(cherry)
+            .with_work_milestones(vec![
+                WorkMilestone {
+                    funding_share: Perbill::from_percent(30),
+                    deadline: first_deadline,
+                },
+                WorkMilestone {
+                    funding_share: Perbill::from_percent(70),
+                    deadline: last_deadline,
+                },
+            ])
+            .call_and_assert(Ok(()));
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_member_id(funding_member_id)
+            .with_origin(RawOrigin::Signed(funding_account_id))
+            .call_and_assert(Ok(()));
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(worker_account_id))
+            .with_member_id(worker_member_id)
+            .with_staking_account_id(worker_account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let entry_id = 1;
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(worker_account_id))
+            .with_member_id(worker_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        // The work milestone deadline ends the work submission.
+        run_to_block(starting_block + first_deadline + 1);
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(Bounty::get_bounty_stage(&bounty), BountyStage::Judgment);
+
+        let first_payout = 30;
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: first_payout,
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        // No work submitted for the last work milestone.
+        run_to_block(starting_block + last_deadline + 1);
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(Bounty::get_bounty_stage(&bounty), BountyStage::Judgment);
+
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Rejected {
+                slashing_share: Perbill::zero(),
+                action_justification: Vec::new(),
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::FailedBountyWithdrawal
+        );
+
+        assert_eq!(
+            Balances::usable_balance(&worker_account_id),
+            entrant_stake + first_payout
+        );
+
+        WithdrawFundingFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_member_id(funding_member_id)
+            .with_origin(RawOrigin::Signed(funding_account_id))
+            .call_and_assert(Ok(()));
+
+        // The funder gets back the unpaid funding and the cherry.
+        assert_eq!(
+            Balances::usable_balance(&funding_account_id),
+            initial_balance - first_payout + cherry
+        );
+    });
+}
+
+#[test]
+fn create_bounty_fails_with_invalid_judgment_dispute_parameters() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_council_budget(500);
+
+        CreateBountyFixture::default()
+            .with_oracle_stake(10)
+            .call_and_assert(Err(
+                Error::<Test>::OracleStakeRequiresJudgmentDisputePeriod.into()
+            ));
+
+        CreateBountyFixture::default()
+            .with_judgment_dispute_period(5)
+            .with_oracle_stake(10)
+            .call_and_assert(Ok(()));
+
+        CreateBountyFixture::default()
+            .with_judgment_dispute_period(5)
+            .with_work_milestones(vec![
+                WorkMilestone {
+                    funding_share: Perbill::from_percent(30),
+                    deadline: 20,
+                },
+                WorkMilestone {
+                    funding_share: Perbill::from_percent(70),
+                    deadline: 40,
+                },
+            ])
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn finalize_oracle_judgment_after_dispute_period_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let entrant_stake = 37;
+        let oracle_member_id = 5;
+        let oracle_account_id = 5;
+        let oracle_stake = 20;
+        let judgment_dispute_period = 5;
+        let bounty_id = 1;
+        set_council_budget(initial_balance);
+        increase_account_balance(&oracle_account_id, initial_balance);
+
+        CreateBountyFixture::default()
+            .with_perpetual_period_target_amount(target_funding)
+            .with_entrant_stake(entrant_stake)
+            .with_oracle_member_id(oracle_member_id)
+            .with_judgment_dispute_period(judgment_dispute_period)
+            .with_oracle_stake(oracle_stake)
+            .call_and_assert(Ok(()));
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let work_member_id = 1;
+        let work_account_id = 1;
+        increase_account_balance(&work_account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_staking_account_id(work_account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let entry_id = 1;
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .call_and_assert(Ok(()));
+
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: target_funding,
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        // The judgment is kept for the dispute period, the oracle stake is transferred.
+        assert_eq!(
+            Balances::usable_balance(&oracle_account_id),
+            initial_balance - oracle_stake
+        );
+        assert_eq!(
+            Bounty::entries(bounty_id, entry_id)
+                .unwrap()
+                .pending_judgment,
+            Some(PendingWorkEntryJudgment::Winner {
+                reward: target_funding
+            })
+        );
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::JudgmentDisputeWindow
+        );
+
+        WithdrawEntrantStakeFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Err(
+                Error::<Test>::InvalidStageUnexpectedJudgmentDisputeWindow.into(),
+            ));
+
+        FinalizeOracleJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Err(
+                Error::<Test>::InvalidStageUnexpectedJudgmentDisputeWindow.into(),
+            ));
+
+        run_to_block(starting_block + judgment_dispute_period + 1);
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::PendingJudgmentFinalization
+        );
+
+        FinalizeOracleJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::BountyCreatorCherryWithdrawal(
+            bounty_id,
+            BountyActor::Council,
+        ));
+        EventFixture::assert_last_crate_event(RawEvent::OracleJudgmentFinalized(bounty_id));
+
+        assert_eq!(
+            Balances::usable_balance(&oracle_account_id),
+            initial_balance
+        );
+
+        WithdrawEntrantStakeFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::WorkEntrantFundsWithdrawn(
+            bounty_id,
+            entry_id,
+            work_member_id,
+        ));
+
+        assert_eq!(
+            Balances::usable_balance(&work_account_id),
+            initial_balance + target_funding
+        );
+    });
+}
+
+#[test]
+fn dispute_oracle_judgment_fails_with_invalid_disputant() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let oracle_member_id = 5;
+        let oracle_account_id = 5;
+        let bounty_id = 1;
+        set_council_budget(initial_balance);
+
+        CreateBountyFixture::default()
+            .with_perpetual_period_target_amount(target_funding)
+            .with_oracle_member_id(oracle_member_id)
+            .with_judgment_dispute_period(5)
+            .call_and_assert(Ok(()));
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let work_member_id = 1;
+        let work_account_id = 1;
+        increase_account_balance(&work_account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_staking_account_id(work_account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let entry_id = 1;
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        DisputeOracleJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Err(
+                Error::<Test>::InvalidStageUnexpectedWorkSubmission.into()
+            ));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .call_and_assert(Ok(()));
+
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Rejected {
+                slashing_share: Perbill::from_percent(50),
+                action_justification: Vec::new(),
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        let other_member_id = 2;
+        let other_account_id = 2;
+        increase_account_balance(&other_account_id, initial_balance);
+
+        // Neither the work entrant nor a contributor.
+        DisputeOracleJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(other_account_id))
+            .with_member_id(other_member_id)
+            .with_bounty_id(bounty_id)
+            .with_entry_id(None)
+            .call_and_assert(Err(Error::<Test>::NoBountyContributionFound.into()));
+
+        DisputeOracleJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(other_account_id))
+            .with_member_id(other_member_id)
+            .with_bounty_id(bounty_id)
+            .with_entry_id(Some(entry_id))
+            .call_and_assert(Err(Error::<Test>::WorkEntryDoesntBelongToWorker.into()));
+
+        ResolveJudgmentDisputeFixture::default()
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Err(
+                Error::<Test>::InvalidStageUnexpectedJudgmentDisputeWindow.into(),
+            ));
+    });
+}
+
+#[test]
+fn overturned_oracle_judgment_fails_bounty_and_slashes_oracle_stake() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let entrant_stake = 40;
+        let oracle_member_id = 5;
+        let oracle_account_id = 5;
+        let oracle_stake = 20;
+        let bounty_id = 1;
+        set_council_budget(initial_balance);
+        increase_account_balance(&oracle_account_id, initial_balance);
+
+        CreateBountyFixture::default()
+            .with_perpetual_period_target_amount(target_funding)
+            .with_entrant_stake(entrant_stake)
+            .with_oracle_member_id(oracle_member_id)
+            .with_judgment_dispute_period(5)
+            .with_oracle_stake(oracle_stake)
+            .call_and_assert(Ok(()));
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let work_member_id = 1;
+        let work_account_id = 1;
+        increase_account_balance(&work_account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_staking_account_id(work_account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let entry_id = 1;
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .call_and_assert(Ok(()));
+
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Rejected {
+                slashing_share: Perbill::from_percent(50),
+                action_justification: Vec::new(),
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        let dispute_bond = JudgmentDisputeBond::get();
+
+        DisputeOracleJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_bounty_id(bounty_id)
+            .with_entry_id(Some(entry_id))
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::OracleJudgmentDisputed(
+            bounty_id,
+            work_member_id,
+            b"rationale".to_vec(),
+        ));
+
+        assert_eq!(
+            Balances::free_balance(&work_account_id),
+            initial_balance - dispute_bond
+        );
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::JudgmentDisputed
+        );
+
+        ResolveJudgmentDisputeFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_bounty_id(bounty_id)
+            .with_overturn_judgment(true)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+
+        ResolveJudgmentDisputeFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_overturn_judgment(true)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::OracleJudgmentOverturned(
+            bounty_id,
+            oracle_stake,
+        ));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::FailedBountyWithdrawal
+        );
+
+        // The disputant gets the bond back and the oracle stake.
+        assert_eq!(
+            Balances::free_balance(&work_account_id),
+            initial_balance + oracle_stake
+        );
+        assert_eq!(
+            Balances::free_balance(&oracle_account_id),
+            initial_balance - oracle_stake
+        );
+
+        // The overturned judgment doesn't slash the work entrant stake.
+        WithdrawEntrantStakeFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Balances::usable_balance(&work_account_id),
+            initial_balance + oracle_stake
+        );
+
+        // The oracle reward is forfeited to the funders.
+        WithdrawOracleRewardFixture::default()
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .call_and_assert(Err(Error::<Test>::OracleRewardAlreadyWithdrawn.into()));
+
+        WithdrawFundingFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        // The council gets back the funding, the cherry, the oracle reward and the bonds.
+        assert_eq!(get_council_budget(), initial_balance);
+        assert!(!<Bounties<Test>>::contains_key(bounty_id));
+    });
+}
+
+#[test]
+fn overturned_oracle_judgment_is_replaced_by_corrected_judgment() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let entrant_stake = 40;
+        let oracle_member_id = 5;
+        let oracle_account_id = 5;
+        let oracle_stake = 20;
+        let bounty_id = 1;
+        set_council_budget(initial_balance);
+        increase_account_balance(&oracle_account_id, initial_balance);
+
+        CreateBountyFixture::default()
+            .with_perpetual_period_target_amount(target_funding)
+            .with_entrant_stake(entrant_stake)
+            .with_oracle_member_id(oracle_member_id)
+            .with_judgment_dispute_period(5)
+            .with_oracle_stake(oracle_stake)
+            .call_and_assert(Ok(()));
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let work_member_id = 1;
+        let work_account_id = 1;
+        increase_account_balance(&work_account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_staking_account_id(work_account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let entry_id = 1;
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        let other_member_id = 2;
+        let other_account_id = 2;
+        increase_account_balance(&other_account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(other_account_id))
+            .with_member_id(other_member_id)
+            .with_staking_account_id(other_account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let other_entry_id = 2;
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .call_and_assert(Ok(()));
+
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Rejected {
+                slashing_share: Perbill::from_percent(50),
+                action_justification: Vec::new(),
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        DisputeOracleJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_bounty_id(bounty_id)
+            .with_entry_id(Some(entry_id))
+            .call_and_assert(Ok(()));
+
+        let mut corrected_judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        corrected_judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: target_funding,
+            },
+        );
+
+        ResolveJudgmentDisputeFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_corrected_judgment(corrected_judgment.clone())
+            .call_and_assert(Err(Error::<Test>::CorrectedJudgmentRequiresOverturn.into()));
+
+        // The work entry judged by the oracle should be judged again.
+        let mut incomplete_judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        incomplete_judgment.insert(
+            other_entry_id,
+            OracleWorkEntryJudgment::Rejected {
+                slashing_share: Perbill::from_percent(50),
+                action_justification: Vec::new(),
+            },
+        );
+
+        ResolveJudgmentDisputeFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_overturn_judgment(true)
+            .with_corrected_judgment(incomplete_judgment)
+            .call_and_assert(Err(
+                Error::<Test>::CorrectedJudgmentShouldJudgeAllJudgedWorkEntries.into(),
+            ));
+
+        ResolveJudgmentDisputeFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_overturn_judgment(true)
+            .with_corrected_judgment(corrected_judgment.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::OracleJudgmentOverturned(
+            bounty_id,
+            oracle_stake,
+        ));
+        EventFixture::assert_last_crate_event(RawEvent::OracleJudgmentCorrected(
+            bounty_id,
+            corrected_judgment,
+        ));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::SuccessfulBountyWithdrawal
+        );
+
+        // The winner of the corrected judgment is paid, the disputant gets the oracle stake.
+        assert_eq!(
+            Balances::usable_balance(&work_account_id),
+            initial_balance + oracle_stake + target_funding
+        );
+        assert!(!<Entries<Test>>::contains_key(bounty_id, entry_id));
+
+        WithdrawOracleRewardFixture::default()
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .call_and_assert(Err(Error::<Test>::OracleRewardAlreadyWithdrawn.into()));
+
+        WithdrawEntrantStakeFixture::default()
+            .with_origin(RawOrigin::Signed(other_account_id))
+            .with_member_id(other_member_id)
+            .with_entry_id(other_entry_id)
+            .call_and_assert(Ok(()));
+
+        WithdrawFundingFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        // The council gets back the cherry, the forfeited oracle reward and the bonds.
+        assert_eq!(get_council_budget(), initial_balance - target_funding);
+        assert!(!<Bounties<Test>>::contains_key(bounty_id));
+    });
+}
+
+#[test]
+fn work_milestone_judgments_can_be_disputed() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let entrant_stake = 40;
+        let oracle_member_id = 5;
+        let oracle_account_id = 5;
+        let judgment_dispute_period = 5;
+        let bounty_id = 1;
+        set_council_budget(initial_balance);
+
+        CreateBountyFixture::default()
+            .with_perpetual_period_target_amount(target_funding)
+            .with_entrant_stake(entrant_stake)
+            .with_oracle_member_id(oracle_member_id)
+            .with_judgment_dispute_period(judgment_dispute_period)
+            .with_work_milestones(vec![
+                WorkMilestone {
+                    funding_share: Perbill::from_percent(30),
+                    deadline: 20,
+                },
+                WorkMilestone {
+                    funding_share: Perbill::from_percent(70),
+                    deadline: 40,
+                },
+            ])
+            .call_and_assert(Ok(()));
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let work_member_id = 1;
+        let work_account_id = 1;
+        increase_account_balance(&work_account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_staking_account_id(work_account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let entry_id = 1;
+
+        let other_member_id = 2;
+        let other_account_id = 2;
+        increase_account_balance(&other_account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(other_account_id))
+            .with_member_id(other_member_id)
+            .with_staking_account_id(other_account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let other_entry_id = 2;
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .call_and_assert(Ok(()));
+
+        let first_payout = 30;
+        let slashing_share = Perbill::from_percent(50);
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: first_payout,
+            },
+        );
+        judgment.insert(
+            other_entry_id,
+            OracleWorkEntryJudgment::Rejected {
+                slashing_share,
+                action_justification: Vec::new(),
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::JudgmentDisputeWindow
+        );
+
+        let finalized_at = starting_block + judgment_dispute_period + 1;
+        run_to_block(finalized_at);
+
+        FinalizeOracleJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::WorkMilestoneCompleted(
+            bounty_id,
+            0,
+            first_payout,
+        ));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(bounty.completed_work_milestones, 1);
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::WorkSubmission
+        );
+
+        // The rejected work entry can't continue with the next work milestone.
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(other_account_id))
+            .with_member_id(other_member_id)
+            .with_entry_id(other_entry_id)
+            .call_and_assert(Err(Error::<Test>::WorkEntryDoesntExist.into()));
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .call_and_assert(Ok(()));
+
+        let last_payout = target_funding - first_payout;
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: last_payout,
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        // The final judgment for the previous work milestone is applied first.
+        assert_eq!(
+            Balances::usable_balance(&work_account_id),
+            initial_balance - entrant_stake + first_payout
+        );
+        assert_eq!(
+            Bounty::entries(bounty_id, entry_id)
+                .unwrap()
+                .pending_judgment,
+            Some(PendingWorkEntryJudgment::Winner {
+                reward: last_payout
+            })
+        );
+
+        run_to_block(finalized_at + judgment_dispute_period + 1);
+
+        FinalizeOracleJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(bounty.completed_work_milestones, 2);
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::SuccessfulBountyWithdrawal
+        );
+
+        WithdrawEntrantStakeFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Balances::usable_balance(&work_account_id),
+            initial_balance + target_funding
+        );
+
+        // The rejected work entry stake is slashed on withdrawal.
+        WithdrawEntrantStakeFixture::default()
+            .with_origin(RawOrigin::Signed(other_account_id))
+            .with_member_id(other_member_id)
+            .with_entry_id(other_entry_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Balances::usable_balance(&other_account_id),
+            initial_balance - slashing_share * entrant_stake
+        );
+    });
+}
+
+#[test]
+fn upheld_oracle_judgment_slashes_dispute_bond() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let entrant_stake = 40;
+        let oracle_member_id = 5;
+        let oracle_account_id = 5;
+        let bounty_id = 1;
+        set_council_budget(initial_balance);
+
+        CreateBountyFixture::default()
+            .with_perpetual_period_target_amount(target_funding)
+            .with_entrant_stake(entrant_stake)
+            .with_oracle_member_id(oracle_member_id)
+            .with_judgment_dispute_period(5)
+            .call_and_assert(Ok(()));
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_council()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let work_member_id = 1;
+        let work_account_id = 1;
+        increase_account_balance(&work_account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_staking_account_id(work_account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let entry_id = 1;
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .call_and_assert(Ok(()));
+
+        let slashing_share = Perbill::from_percent(50);
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Rejected {
+                slashing_share,
+                action_justification: Vec::new(),
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        let dispute_bond = JudgmentDisputeBond::get();
+
+        DisputeOracleJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_bounty_id(bounty_id)
+            .with_entry_id(Some(entry_id))
+            .call_and_assert(Ok(()));
+
+        ResolveJudgmentDisputeFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_overturn_judgment(false)
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::JudgmentDisputeRejected(
+            bounty_id,
+            dispute_bond,
+        ));
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::FailedBountyWithdrawal
+        );
+
+        let slashed_stake = slashing_share * entrant_stake;
+
+        WithdrawEntrantStakeFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::WorkEntrantStakeSlashed(
+            bounty_id,
+            entry_id,
+            work_account_id,
+            slashed_stake,
+        ));
+
+        assert_eq!(
+            Balances::usable_balance(&work_account_id),
+            initial_balance - dispute_bond - slashed_stake
+        );
+    });
+}
+
+#[test]
+fn finalize_oracle_judgment_returns_undecided_dispute_bond() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let initial_balance = 500;
+        let target_funding = 100;
+        let oracle_member_id = 5;
+        let oracle_account_id = 5;
+        let bounty_id = 1;
+        set_council_budget(initial_balance);
+
+        CreateBountyFixture::default()
+            .with_perpetual_period_target_amount(target_funding)
+            .with_oracle_member_id(oracle_member_id)
+            .with_judgment_dispute_period(5)
+            .call_and_assert(Ok(()));
+
+        let funding_member_id = 2;
+        let funding_account_id = 2;
+        increase_account_balance(&funding_account_id, initial_balance);
+
+        FundBountyFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_amount(target_funding)
+            .with_member_id(funding_member_id)
+            .with_origin(RawOrigin::Signed(funding_account_id))
+            .call_and_assert(Ok(()));
+
+        let work_member_id = 1;
+        let work_account_id = 1;
+        increase_account_balance(&work_account_id, initial_balance);
+
+        AnnounceWorkEntryFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_staking_account_id(work_account_id)
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        let entry_id = 1;
+
+        SubmitWorkFixture::default()
+            .with_origin(RawOrigin::Signed(work_account_id))
+            .with_member_id(work_member_id)
+            .with_entry_id(entry_id)
+            .call_and_assert(Ok(()));
+
+        EndWorkPeriodFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .call_and_assert(Ok(()));
+
+        let mut judgment: OracleJudgment<u64, u64> = BTreeMap::new();
+        judgment.insert(
+            entry_id,
+            OracleWorkEntryJudgment::Winner {
+                reward: target_funding,
+            },
+        );
+
+        SubmitJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .with_origin(RawOrigin::Signed(oracle_account_id))
+            .with_judgment(judgment)
+            .call_and_assert(Ok(()));
+
+        let funder_balance = Balances::usable_balance(&funding_account_id);
+
+        // A contributor disputes the judgment.
+        DisputeOracleJudgmentFixture::default()
+            .with_origin(RawOrigin::Signed(funding_account_id))
+            .with_member_id(funding_member_id)
+            .with_bounty_id(bounty_id)
+            .with_entry_id(None)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Balances::usable_balance(&funding_account_id),
+            funder_balance - JudgmentDisputeBond::get()
+        );
+
+        let disputed_at = System::block_number();
+        run_to_block(disputed_at + JudgmentDisputeDecisionPeriod::get());
+
+        FinalizeOracleJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Err(
+                Error::<Test>::InvalidStageUnexpectedJudgmentDisputed.into()
+            ));
+
+        run_to_block(disputed_at + JudgmentDisputeDecisionPeriod::get() + 1);
+
+        // The council didn't decide the dispute: the judgment stands.
+        FinalizeOracleJudgmentFixture::default()
+            .with_bounty_id(bounty_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Balances::usable_balance(&funding_account_id),
+            funder_balance
+        );
+
+        let bounty = Bounty::ensure_bounty_exists(&bounty_id).unwrap();
+        assert_eq!(
+            Bounty::get_bounty_stage(&bounty),
+            BountyStage::SuccessfulBountyWithdrawal
+        );
+    });
+}
+
 #[test]
 fn switch_oracle_to_council_by_council_successful() {
     build_test_externalities().execute_with(|| {
@@ -76,6 +76,9 @@ pub trait WeightInfo {
 	fn contributor_remark(_i: u32, ) -> Weight;
 	fn oracle_remark(_i: u32, ) -> Weight;
 	fn creator_remark(_i: u32, ) -> Weight;
+	fn dispute_oracle_judgment(_i: u32, ) -> Weight;
+	fn resolve_oracle_judgment_dispute() -> Weight;
+	fn finalize_oracle_judgment() -> Weight;
 }
 
 /// Weights for bounty using the Substrate node and recommended hardware.
@@ -90,7 +93,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:0 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 100]`.
 	/// The range of component `j` is `[1, 50]`.
 	fn create_bounty_by_council(i: u32, j: u32, ) -> Weight {
@@ -116,7 +119,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Bounty BountyCount (r:1 w:1)
 	// Proof: Bounty BountyCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:0 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 100]`.
 	/// The range of component `j` is `[1, 50]`.
 	fn create_bounty_by_member(i: u32, j: u32, ) -> Weight {
@@ -136,7 +139,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 2600).saturating_mul(j.into()))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:0)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -154,7 +157,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:0)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -172,7 +175,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:0)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -190,7 +193,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:0)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -208,7 +211,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:2 w:0)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	fn terminate_bounty_w_oracle_reward_w_funds_funding() -> Weight {
@@ -222,7 +225,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:2 w:0)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	fn terminate_bounty_wo_oracle_reward_w_funds_funding() -> Weight {
@@ -236,7 +239,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:2 w:0)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	fn terminate_bounty_work_or_judging_period() -> Weight {
@@ -252,7 +255,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:1)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:2 w:2)
@@ -268,7 +271,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:1)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: Council Budget (r:1 w:1)
@@ -288,7 +291,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:1 w:0)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:1)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:2 w:2)
@@ -304,7 +307,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:0)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:1)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -324,7 +327,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
 	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
@@ -334,7 +337,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Bounty EntryCount (r:1 w:1)
 	// Proof: Bounty EntryCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	// Storage: Bounty Entries (r:0 w:1)
-	// Proof: Bounty Entries (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Proof: Bounty Entries (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 100]`.
 	/// The range of component `j` is `[1, 50]`.
 	fn announce_work_entry(i: u32, j: u32, ) -> Weight {
@@ -354,9 +357,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:1 w:0)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty Entries (r:1 w:1)
-	// Proof: Bounty Entries (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Proof: Bounty Entries (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
 	fn submit_work(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -371,9 +374,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty Entries (r:40 w:40)
-	// Proof: Bounty Entries (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Proof: Bounty Entries (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:40 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: System Account (r:41 w:41)
@@ -407,11 +410,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 11545).saturating_mul(r.into()))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:40 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Entries (r:39 w:39)
-	// Proof: Bounty Entries (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Proof: Bounty Entries (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
 	// Storage: System Account (r:40 w:40)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: Council Budget (r:1 w:1)
@@ -443,7 +446,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 11545).saturating_mul(r.into()))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	fn switch_oracle_to_council_by_council_successful() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `184`
@@ -455,7 +458,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	fn switch_oracle_to_member_by_oracle_council() -> Weight {
@@ -469,7 +472,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	fn switch_oracle_to_member_by_council() -> Weight {
@@ -483,7 +486,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:2 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	fn switch_oracle_to_member_by_oracle_member() -> Weight {
@@ -497,7 +500,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	fn switch_oracle_to_council_by_oracle_member() -> Weight {
@@ -511,7 +514,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	fn end_working_period() -> Weight {
@@ -527,25 +530,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty Entries (r:1 w:1)
-	// Proof: Bounty Entries (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Proof: Bounty Entries (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	fn withdraw_entrant_stake() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `863`
-		//  Estimated: `19510`
-		// Minimum execution time: 51_729 nanoseconds.
-		Weight::from_parts(53_741_000, 0u64)
-			.saturating_add(Weight::from_parts(0, 19510))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		//  Measured:  `881`
+		//  Estimated: `22113`
+		// Minimum execution time: 58_114 nanoseconds.
+		Weight::from_parts(60_302_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 22113))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:0)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:1)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -565,7 +568,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Bounties (r:1 w:0)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Bounty BountyContributions (r:1 w:1)
 	// Proof: Bounty BountyContributions (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
 	// Storage: System Account (r:2 w:2)
@@ -581,7 +584,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: Council Budget (r:1 w:1)
@@ -599,7 +602,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:1)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: System Account (r:2 w:2)
@@ -621,7 +624,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	// Storage: Bounty Entries (r:1 w:0)
-	// Proof: Bounty Entries (max_values: None, max_size: Some(93), added: 2568, mode: MaxEncodedLen)
+	// Proof: Bounty Entries (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
 	fn entrant_remark(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -651,7 +654,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:0)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
 	fn oracle_remark(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -665,7 +668,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
 	// Storage: Bounty Bounties (r:1 w:0)
-	// Proof: Bounty Bounties (max_values: None, max_size: Some(540), added: 3015, mode: MaxEncodedLen)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
 	fn creator_remark(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -678,6 +681,63 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(763_705, 0u64).saturating_mul(i.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Bounty Bounties (r:1 w:1)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
+	// Storage: Bounty Entries (r:1 w:0)
+	// Proof: Bounty Entries (max_values: None, max_size: Some(111), added: 2586, mode: MaxEncodedLen)
+	// Storage: Bounty JudgmentDisputes (r:1 w:1)
+	// Proof: Bounty JudgmentDisputes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `i` is `[0, 100]`.
+	fn dispute_oracle_judgment(i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1012`
+		//  Estimated: `16067`
+		// Minimum execution time: 58_312 nanoseconds.
+		Weight::from_parts(60_417_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 16067))
+			// Standard Error: 23
+			.saturating_add(Weight::from_parts(1_324, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: Bounty Bounties (r:1 w:1)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
+	// Storage: Bounty JudgmentDisputes (r:1 w:1)
+	// Proof: Bounty JudgmentDisputes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	// Storage: System Account (r:3 w:3)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn resolve_oracle_judgment_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1096`
+		//  Estimated: `13484`
+		// Minimum execution time: 61_045 nanoseconds.
+		Weight::from_parts(63_208_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 13484))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: Bounty Bounties (r:1 w:1)
+	// Proof: Bounty Bounties (max_values: None, max_size: Some(645), added: 3120, mode: MaxEncodedLen)
+	// Storage: Bounty JudgmentDisputes (r:1 w:1)
+	// Proof: Bounty JudgmentDisputes (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	// Storage: System Account (r:3 w:3)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Council Budget (r:1 w:1)
+	// Proof: Council Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn finalize_oracle_judgment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1054`
+		//  Estimated: `14484`
+		// Minimum execution time: 64_871 nanoseconds.
+		Weight::from_parts(66_530_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 14484))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // Default implementation for tests
@@ -778,4 +838,13 @@ impl WeightInfo for () {
 	fn creator_remark(i: u32, ) -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn dispute_oracle_judgment(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn resolve_oracle_judgment_dispute() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn finalize_oracle_judgment() -> Weight {
+		Weight::from_parts(0, 0)
+	}
 }
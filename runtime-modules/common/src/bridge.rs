@@ -0,0 +1,50 @@
+use codec::MaxEncodedLen;
+use frame_support::dispatch::DispatchResult;
+use frame_support::Parameter;
+use sp_arithmetic::traits::BaseArithmetic;
+
+/// Creator token operations used to bridge creator tokens to remote chains.
+/// Bridged tokens are escrowed (they remain part of the token supply) and released
+/// back into the member accounts once they are bridged back.
+pub trait CreatorTokenBridge<AccountId> {
+    /// Creator token id
+    type TokenId: Parameter + Copy + Ord + MaxEncodedLen;
+
+    /// Member id of the token account owners
+    type MemberId: Parameter + Copy + Ord + MaxEncodedLen;
+
+    /// Creator token balance
+    type Balance: Parameter + BaseArithmetic + Copy + Default + MaxEncodedLen;
+
+    /// Whether the token issuer enabled bridging of the token
+    fn is_bridging_enabled(token_id: Self::TokenId) -> bool;
+
+    /// Escrow `amount` of tokens from the `member_id` account for an outbound transfer.
+    /// `sender` must be the `member_id` controller account.
+    fn escrow_bridged_tokens(
+        token_id: Self::TokenId,
+        member_id: Self::MemberId,
+        sender: &AccountId,
+        amount: Self::Balance,
+    ) -> DispatchResult;
+
+    /// Release `amount` of escrowed tokens into the `member_id` account
+    /// for an inbound (or reverted outbound) transfer
+    fn release_bridged_tokens(
+        token_id: Self::TokenId,
+        member_id: Self::MemberId,
+        amount: Self::Balance,
+    ) -> DispatchResult;
+
+    /// Issue a token with bridging enabled and `amount` of tokens held by a new member
+    /// controlled by `account_id`. Used to set up the bridge benchmarks.
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_bridgeable_token(
+        _account_id: &AccountId,
+        _amount: Self::Balance,
+    ) -> Result<(Self::TokenId, Self::MemberId), frame_support::dispatch::DispatchError> {
+        Err(frame_support::dispatch::DispatchError::Other(
+            "creator token bridging benchmarks not supported",
+        ))
+    }
+}
@@ -12,6 +12,7 @@
 )]
 
 pub mod bloat_bond;
+pub mod bridge;
 pub mod costs;
 pub mod council;
 pub mod currency;
@@ -119,6 +119,35 @@ pub fn amm_eval_inner<
         .flatten()
 }
 
+/// Computes `base^exp` by squaring, returns `None` on overflow
+///
+/// # Arguments
+///
+/// * `base` - `FixedU128` base
+/// * `exp` - integer exponent
+///
+/// # Returns
+///
+/// `base` to the power of `exp` if it fits into `FixedU128`
+///
+pub fn checked_pow_fixed(base: FixedU128, exp: u128) -> Option<FixedU128> {
+    let mut result = FixedU128::one();
+    let mut base = base;
+    let mut exp = exp;
+
+    while exp > 0 {
+        if exp.checked_rem(2)? == 1 {
+            result = result.checked_mul(&base)?;
+        }
+        exp = exp.checked_div(2)?;
+        if exp > 0 {
+            base = base.checked_mul(&base)?;
+        }
+    }
+
+    Some(result)
+}
+
 #[cfg(test)]
 mod numerical_tests {
     use parameterized::parameterized;
@@ -159,6 +188,26 @@ mod numerical_tests {
         assert_eq!(result, expected);
     }
 
+    #[parameterized(
+        input = {
+            (FixedU128::from_u32(2), 10u128), // integer base
+            (FixedU128::from_float(1.5f64), 2u128), // fractional base
+            (FixedU128::from_u32(7), 0u128), // zero exponent
+            (FixedU128::from_u32(2), 128u128), // overflow
+        },
+        expected = {
+            Some(FixedU128::from_u32(1024)),
+            Some(FixedU128::from_float(2.25f64)),
+            Some(FixedU128::one()),
+            None,
+        }
+    )]
+    fn checked_pow_fixed_base_test(input: (FixedU128, u128), expected: Option<FixedU128>) {
+        let (base, exp) = input;
+
+        assert_eq!(checked_pow_fixed(base, exp), expected);
+    }
+
     #[test]
     fn log_approximation_is_accurate_up_to_14_dec_places() {
         let expected = Perquintill::from_float(0.139761942375158f64); // https://www.wolframalpha.com/input?i=ln%281+%2B+0.15%29
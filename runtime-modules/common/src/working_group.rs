@@ -61,6 +61,9 @@ pub trait WorkingGroupAuthenticator<T: crate::MembershipTypes> {
     /// Get member ID of the specified worker.
     fn get_worker_member_id(worker_id: &T::ActorId) -> Option<T::MemberId>;
 
+    /// Get reward account ID of the specified worker.
+    fn get_worker_reward_account_id(worker_id: &T::ActorId) -> Option<T::AccountId>;
+
     /// Verifies that given account ID belongs to the leader.
     fn is_leader_account_id(account_id: &T::AccountId) -> bool;
 
@@ -6,7 +6,7 @@ use crate::types::*;
 use crate::{
     assert_lt,
     nft::{Nft, NftOwner, OpenAuctionParams, TransactionalStatus},
-    Call, ChannelById, Config, ContentActor, Event, Module as Pallet,
+    Call, ChannelById, Config, ContentActor, Event, Module as Pallet, TakedownRequestById,
 };
 use crate::{ContentTreasury, UpdateChannelPayoutsParameters};
 use balances::Pallet as Balances;
@@ -544,6 +544,221 @@ benchmarks! {
                 ).into());
         }
 
+    /*
+    ===============================================================================================
+    ====================================== CONTENT TAKEDOWNS ======================================
+    ===============================================================================================
+     */
+
+    file_takedown_request {
+
+        let a in 1 .. T::MaxNumberOfAssetsPerVideo::get(); //max objs number
+
+        let b in 1 .. MAX_KILOBYTES_METADATA; //max kilobytes for rationale
+
+        let (video_id, _) = setup_worst_case_scenario_mutable_video::<T>(
+            Some(a),
+            T::MaxStorageBucketsPerBag::get(),
+        )?;
+        let (requester_account_id, requester_id) = member_funded_account::<T>();
+        let takedown_request_id = Pallet::<T>::next_takedown_request_id();
+        let target = TakedownTarget::Video(video_id);
+        let rationale = vec![1u8].repeat((b * 1000) as usize);
+    }: _ (
+        RawOrigin::Signed(requester_account_id),
+        requester_id,
+        target,
+        rationale.clone()
+    )
+        verify {
+
+            assert_last_event::<T>(
+                <T as Config>::RuntimeEvent::from(
+                    Event::<T>::TakedownRequestFiled(
+                        takedown_request_id,
+                        Pallet::<T>::takedown_request_by_id(takedown_request_id),
+                        rationale,
+                    )
+                ).into());
+        }
+
+    accept_takedown_request {
+
+        let a in 1 .. T::MaxNumberOfAssetsPerVideo::get(); //max objs number
+
+        let b in (T::MinStorageBucketsPerBag::get()) .. (T::MaxStorageBucketsPerBag::get());
+
+        let c in 1 .. MAX_KILOBYTES_METADATA; //max kilobytes for rationale
+
+        let (takedown_request_id, (curator_account_id, actor, _, _)) =
+            setup_worst_case_scenario_takedown_request::<T>(a, b)?;
+        let rationale = vec![1u8].repeat((c * 1000) as usize);
+    }: _ (
+        RawOrigin::Signed(curator_account_id),
+        actor,
+        takedown_request_id,
+        b,
+        rationale
+    )
+        verify {
+
+            let takedown_request = Pallet::<T>::takedown_request_by_id(takedown_request_id);
+            assert!(matches!(takedown_request.status, TakedownRequestStatus::Accepted(_)));
+            assert!(takedown_request
+                .content_ids
+                .iter()
+                .all(|cid| storage::Blacklist::contains_key(cid)));
+        }
+
+    reject_takedown_request {
+
+        let a in 1 .. MAX_KILOBYTES_METADATA; //max kilobytes for rationale
+
+        let (takedown_request_id, (curator_account_id, actor, _, _)) =
+            setup_worst_case_scenario_takedown_request::<T>(
+                T::MaxNumberOfAssetsPerVideo::get(),
+                T::MaxStorageBucketsPerBag::get(),
+            )?;
+        let rationale = vec![1u8].repeat((a * 1000) as usize);
+    }: _ (
+        RawOrigin::Signed(curator_account_id),
+        actor,
+        takedown_request_id,
+        rationale.clone()
+    )
+        verify {
+
+            assert_last_event::<T>(
+                <T as Config>::RuntimeEvent::from(
+                    Event::<T>::TakedownRequestRejected(
+                        actor,
+                        takedown_request_id,
+                        rationale,
+                    )
+                ).into());
+        }
+
+    expire_takedown_request {
+
+        let (takedown_request_id, (curator_account_id, _, _, _)) =
+            setup_worst_case_scenario_takedown_request::<T>(
+                T::MaxNumberOfAssetsPerVideo::get(),
+                T::MaxStorageBucketsPerBag::get(),
+            )?;
+
+        fastforward_by_blocks::<T>(T::TakedownRequestDecisionPeriod::get() + One::one());
+    }: _ (
+        RawOrigin::Signed(curator_account_id),
+        takedown_request_id
+    )
+        verify {
+
+            assert_last_event::<T>(
+                <T as Config>::RuntimeEvent::from(
+                    Event::<T>::TakedownRequestExpired(takedown_request_id)
+                ).into());
+        }
+
+    appeal_takedown {
+
+        let a in 1 .. MAX_KILOBYTES_METADATA; //max kilobytes for rationale
+
+        let (takedown_request_id, (curator_account_id, actor, channel_id, _)) =
+            setup_worst_case_scenario_takedown_request::<T>(
+                T::MaxNumberOfAssetsPerVideo::get(),
+                T::MaxStorageBucketsPerBag::get(),
+            )?;
+        Pallet::<T>::accept_takedown_request(
+            RawOrigin::Signed(curator_account_id).into(),
+            actor,
+            takedown_request_id,
+            storage_buckets_num_witness::<T>(channel_id)?,
+            Vec::new(),
+        )?;
+
+        let lead_account_id = T::AccountId::create_account_id(CONTENT_WG_LEADER_ACCOUNT_ID);
+        let rationale = vec![1u8].repeat((a * 1000) as usize);
+    }: _ (
+        RawOrigin::Signed(lead_account_id),
+        takedown_request_id,
+        rationale.clone()
+    )
+        verify {
+
+            assert_last_event::<T>(
+                <T as Config>::RuntimeEvent::from(
+                    Event::<T>::TakedownAppealed(takedown_request_id, rationale)
+                ).into());
+        }
+
+    overturn_takedown {
+
+        let a in 1 .. T::MaxNumberOfAssetsPerVideo::get(); //max objs number
+
+        let (takedown_request_id, (curator_account_id, actor, channel_id, _)) =
+            setup_worst_case_scenario_takedown_request::<T>(a, T::MaxStorageBucketsPerBag::get())?;
+        Pallet::<T>::accept_takedown_request(
+            RawOrigin::Signed(curator_account_id).into(),
+            actor,
+            takedown_request_id,
+            storage_buckets_num_witness::<T>(channel_id)?,
+            Vec::new(),
+        )?;
+
+        let lead_account_id = T::AccountId::create_account_id(CONTENT_WG_LEADER_ACCOUNT_ID);
+        Pallet::<T>::appeal_takedown(
+            RawOrigin::Signed(lead_account_id).into(),
+            takedown_request_id,
+            Vec::new(),
+        )?;
+    }: _ (
+        RawOrigin::Root,
+        takedown_request_id
+    )
+        verify {
+
+            assert_last_event::<T>(
+                <T as Config>::RuntimeEvent::from(
+                    Event::<T>::TakedownOverturned(takedown_request_id)
+                ).into());
+        }
+
+    expire_takedown_appeal {
+
+        let (takedown_request_id, (curator_account_id, actor, channel_id, _)) =
+            setup_worst_case_scenario_takedown_request::<T>(
+                T::MaxNumberOfAssetsPerVideo::get(),
+                T::MaxStorageBucketsPerBag::get(),
+            )?;
+        Pallet::<T>::accept_takedown_request(
+            RawOrigin::Signed(curator_account_id.clone()).into(),
+            actor,
+            takedown_request_id,
+            storage_buckets_num_witness::<T>(channel_id)?,
+            Vec::new(),
+        )?;
+
+        let lead_account_id = T::AccountId::create_account_id(CONTENT_WG_LEADER_ACCOUNT_ID);
+        Pallet::<T>::appeal_takedown(
+            RawOrigin::Signed(lead_account_id).into(),
+            takedown_request_id,
+            Vec::new(),
+        )?;
+
+        fastforward_by_blocks::<T>(T::TakedownAppealDecisionPeriod::get() + One::one());
+    }: _ (
+        RawOrigin::Signed(curator_account_id),
+        takedown_request_id
+    )
+        verify {
+
+            assert!(!TakedownRequestById::<T>::contains_key(takedown_request_id));
+            assert_last_event::<T>(
+                <T as Config>::RuntimeEvent::from(
+                    Event::<T>::TakedownAppealExpired(takedown_request_id)
+                ).into());
+        }
+
     /*
     ===============================================================================================
     ======================================== CURATOR GROUPS =======================================
@@ -1453,6 +1668,99 @@ benchmarks! {
             );
         }
 
+    creator_token_issuer_create_airdrop {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id.clone(),
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        let airdrop_id = project_token::Module::<T>::next_airdrop_id();
+        let params = AirdropParamsOf::<T> {
+            commitment: T::Hashing::hash_of(b"commitment"),
+            amount: 100u32.into(),
+            duration: 100u32.into(),
+        };
+        // No pausable feature prevents this
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _ (
+        origin, actor, channel_id, params.clone()
+    )
+        verify {
+            let airdrop = project_token::Airdrops::<T>::get(token_id, airdrop_id);
+            assert_eq!(airdrop.issuer_member_id, curator_member_id);
+            assert_eq!(airdrop.remaining_amount, params.amount);
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::RuntimeEvent::from(
+                    project_token::Event::<T>::AirdropCreated(
+                        token_id,
+                        airdrop_id,
+                        airdrop
+                    )
+                ).into()
+            );
+        }
+
+    revoke_creator_token_vesting_schedule {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id.clone(),
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        let outputs = worst_case_scenario_issuer_transfer_outputs::<T>(1);
+        let (member_id, payment) = outputs.first().cloned().unwrap();
+        Pallet::<T>::creator_token_issuer_transfer(
+            origin.clone().into(),
+            actor,
+            channel_id,
+            outputs,
+            vec![],
+        )?;
+        let issuer_amount_pre =
+            project_token::Pallet::<T>::account_info_by_token_and_member(token_id, curator_member_id)
+                .amount;
+        // No pausable feature prevents this
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _ (
+        origin, actor, channel_id, member_id, 0
+    )
+        verify {
+            let acc_data =
+                project_token::Pallet::<T>::account_info_by_token_and_member(token_id, member_id);
+            assert!(acc_data.vesting_schedules.is_empty());
+            assert_eq!(
+                project_token::Pallet::<T>::account_info_by_token_and_member(token_id, curator_member_id)
+                    .amount,
+                issuer_amount_pre + payment.amount
+            );
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::RuntimeEvent::from(
+                    project_token::Event::<T>::VestingScheduleRevoked(
+                        token_id,
+                        member_id,
+                        VestingSource::IssuerTransfer(0),
+                        curator_member_id,
+                        payment.amount
+                    )
+                ).into()
+            );
+        }
+
     make_creator_token_permissionless {
         let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
             setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
@@ -1485,6 +1793,37 @@ benchmarks! {
             );
         }
 
+    update_creator_token_bridging {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id,
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        // No pausable feature prevents this
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _ (
+        origin, actor, channel_id, true
+    )
+        verify {
+            assert!(project_token::Pallet::<T>::token_bridging(token_id).enabled);
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::RuntimeEvent::from(
+                    project_token::Event::<T>::TokenBridgingUpdated(
+                        token_id,
+                        true
+                    )
+                ).into()
+            );
+        }
+
     deissue_creator_token {
         let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
             setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
@@ -1568,6 +1907,63 @@ benchmarks! {
             );
         }
 
+    init_creator_token_batch_auction {
+        let a in 1 .. MAX_KILOBYTES_METADATA;
+
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id,
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        let params = worst_case_scenario_token_sale_params::<T>(a, None);
+        // No pausable feature prevents this
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _ (
+        origin, actor, channel_id, params
+    )
+        verify {
+            let start_block = frame_system::Pallet::<T>::block_number();
+            let token = project_token::Module::<T>::token_info_by_id(token_id);
+            // Verify token sale data
+            assert_eq!(token.sale, Some(TokenSale {
+                auto_finalize: false,
+                cap_per_member: Some(DEFAULT_CRT_SALE_CAP_PER_MEMBER.into()),
+                duration: default_crt_sale_duration::<T>(),
+                earnings_destination: None,
+                funds_collected: JoyBalanceOf::<T>::zero(),
+                quantity_left: DEFAULT_CRT_SALE_UPPER_BOUND.into(),
+                start_block,
+                tokens_source: curator_member_id,
+                unit_price: DEFAULT_CRT_SALE_PRICE.into(),
+                vesting_schedule_params: Some(default_vesting_schedule_params::<T>())
+            }));
+            // Verify that owner has max amount of locks possible
+            let owner_acc_data = project_token::Module::<T>::account_info_by_token_and_member(token_id, curator_member_id);
+            assert_eq!(
+                owner_acc_data.vesting_schedules.len(),
+                T::MaxVestingSchedulesPerAccountPerToken::get() as usize
+            );
+            assert!(owner_acc_data.split_staking_status.is_some());
+            // Verify batch auction mode
+            assert!(project_token::BatchAuctions::<T>::contains_key(token_id));
+            // Check event emitted
+            assert_last_event::<T>(
+                <T as project_token::Config>::RuntimeEvent::from(
+                    project_token::Event::<T>::BatchAuctionInitialized(
+                        token_id,
+                        token.next_sale_id - 1
+                    )
+                ).into()
+            );
+        }
+
     update_upcoming_creator_token_sale {
         let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
             setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
@@ -1684,7 +2080,7 @@ benchmarks! {
             )?;
         let slope = 10_000_000u32.into();
         let intercept = 100u32.into();
-        let params = AmmParams{ slope, intercept };
+        let params = AmmParams::Linear { slope, intercept };
         // No pausable feature prevents this
         set_all_channel_paused_features::<T>(channel_id);
     }: _(
@@ -1698,8 +2094,7 @@ benchmarks! {
            assert_eq!(
                 token.amm_curve.unwrap(),
                 AmmCurve {
-                    slope,
-                    intercept,
+                    kind: AmmCurveKind::Linear { slope, intercept },
                     provided_supply: 0u32.into(),
                 }
            )
@@ -1733,6 +2128,128 @@ benchmarks! {
            )
         }
 
+    create_creator_token_poll {
+        let b in 1 .. MAX_KILOBYTES_METADATA;
+
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id,
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        let poll_id = project_token::Module::<T>::next_poll_id();
+        let params = PollParamsOf::<T> {
+            options_number: <T as project_token::Config>::MaxPollOptions::get(),
+            duration: 100u32.into(),
+            weighting: PollVoteWeighting::Balance,
+            metadata: vec![0xff].repeat((b * 1000) as usize),
+        };
+        let linked_action = CreatorTokenPollAction::IssueRevenueSplit(
+            project_token::Pallet::<T>::min_revenue_split_duration()
+        );
+        // No pausable feature prevents this
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _ (
+        origin, actor, channel_id, params, Some((0, linked_action.clone()))
+    )
+        verify {
+            assert!(project_token::Polls::<T>::contains_key(token_id, poll_id));
+            assert_eq!(
+                Pallet::<T>::creator_token_poll_action(channel_id, poll_id),
+                LinkedCreatorTokenPollActionRecord {
+                    member_id: curator_member_id,
+                    option_index: 0,
+                    action: linked_action,
+                }
+            );
+            assert_last_event::<T>(
+                <T as Config>::RuntimeEvent::from(
+                    Event::<T>::CreatorTokenPollCreated(
+                        actor,
+                        channel_id,
+                        token_id,
+                        poll_id
+                    )
+                ).into()
+            );
+        }
+
+    // Worst case scenario:
+    // - linked action executed
+    // - poll removed together with `v` votes
+    execute_creator_token_poll_action {
+        let v in 1 .. (<T as project_token::Config>::MaxPollVotesRemovedPerCall::get());
+
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id.clone(),
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        let poll_id = project_token::Module::<T>::next_poll_id();
+        let duration: T::BlockNumber = 100u32.into();
+        Pallet::<T>::create_creator_token_poll(
+            RawOrigin::Signed(curator_acc_id.clone()).into(),
+            actor,
+            channel_id,
+            PollParamsOf::<T> {
+                options_number: 2,
+                duration,
+                weighting: PollVoteWeighting::Balance,
+                metadata: vec![],
+            },
+            Some((0, CreatorTokenPollAction::IssueRevenueSplit(
+                project_token::Pallet::<T>::min_revenue_split_duration()
+            ))),
+        )?;
+        project_token::Module::<T>::vote_in_poll(
+            RawOrigin::Signed(curator_acc_id.clone()).into(),
+            curator_member_id,
+            token_id,
+            poll_id,
+            0,
+        )?;
+        for i in 1..v {
+            let member_id: T::MemberId = (1_000_000u32 + i).saturated_into();
+            project_token::PollVotes::<T>::insert(
+                poll_id,
+                member_id,
+                PollVote { option_index: 0, weight: One::one() }
+            );
+        }
+        fastforward_by_blocks::<T>(duration);
+        let channel_acc = ContentTreasury::<T>::account_for_channel(channel_id);
+        let _ = balances::Pallet::<T>::deposit_creating(&channel_acc, 1_000_000u32.into());
+        // No pausable feature prevents this
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _ (RawOrigin::Signed(curator_acc_id), channel_id, poll_id)
+        verify {
+            assert!(!crate::CreatorTokenPollActions::<T>::contains_key(channel_id, poll_id));
+            assert!(!project_token::Polls::<T>::contains_key(token_id, poll_id));
+            let token = project_token::Pallet::<T>::token_info_by_id(token_id);
+            assert!(matches!(token.revenue_split, RevenueSplitStateOf::<T>::Active(..)));
+            assert_last_event::<T>(
+                <T as Config>::RuntimeEvent::from(
+                    Event::<T>::CreatorTokenPollActionResolved(
+                        channel_id,
+                        poll_id,
+                        true
+                    )
+                ).into()
+            );
+        }
+
     issue_revenue_split {
         let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
             setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
@@ -1785,6 +2302,56 @@ benchmarks! {
             );
         }
 
+    deposit_creator_token_staking_revenue {
+        let (channel_id, group_id, lead_acc_id, curator_id, curator_acc_id) =
+            setup_worst_case_scenario_curator_channel_all_max::<T>(false)?;
+        let curator_member_id = curator_member_id::<T>(curator_id);
+        let origin = RawOrigin::Signed(curator_acc_id.clone());
+        let actor = ContentActor::Curator(group_id, curator_id);
+        let token_id =
+            issue_creator_token_with_worst_case_scenario_owner::<T>(
+                curator_acc_id.clone(),
+                actor,
+                channel_id,
+                curator_member_id
+            )?;
+        let staked_amount: TokenBalanceOf<T> = DEFAULT_CRT_OWNER_ISSUANCE.into();
+        project_token::Pallet::<T>::stake_for_revenue(
+            RawOrigin::Signed(curator_acc_id).into(),
+            token_id,
+            curator_member_id,
+            staked_amount
+        )?;
+        let channel_acc = ContentTreasury::<T>::account_for_channel(channel_id);
+        let reward_amount = 1_000_000u32.into();
+        let _ = balances::Pallet::<T>::deposit_creating(&channel_acc, reward_amount);
+        let council_budget_pre = T::CouncilBudgetManager::get_budget();
+        // No pausable feature prevents this
+        set_all_channel_paused_features::<T>(channel_id);
+    }: _(origin, actor, channel_id)
+        verify {
+            let allocation = DEFAULT_CRT_REVENUE_SPLIT_RATE * reward_amount;
+            let withdrawn = reward_amount - allocation;
+            let pool = project_token::Pallet::<T>::revenue_staking_pool(token_id);
+            assert!(!pool.reward_per_token.is_zero());
+            // Make sure council budget was increased
+            let council_budget_post = T::CouncilBudgetManager::get_budget();
+            assert_eq!(council_budget_post, council_budget_pre + withdrawn);
+            // Check event emitted
+            assert_past_event::<T>(
+                <T as project_token::Config>::RuntimeEvent::from(
+                    project_token::Event::<T>::StakingRevenueDeposited(
+                        token_id,
+                        allocation,
+                        staked_amount
+                    ),
+                ).into(),
+                1 // expected events:
+                // project_token::StakingRevenueDeposited
+                // balances::Slashed
+            );
+        }
+
     issue_revenue_split_as_collaborator {
         let (channel_id, owner_member_id, owner_acc, lead_account_id) =
             setup_worst_case_scenario_member_channel_all_max::<T>(false)?;
@@ -3407,6 +3974,55 @@ pub mod tests {
         });
     }
 
+    #[test]
+    fn file_takedown_request() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_file_takedown_request());
+        });
+    }
+
+    #[test]
+    fn accept_takedown_request() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_accept_takedown_request());
+        });
+    }
+
+    #[test]
+    fn reject_takedown_request() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_reject_takedown_request());
+        });
+    }
+
+    #[test]
+    fn expire_takedown_request() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_expire_takedown_request());
+        });
+    }
+
+    #[test]
+    fn appeal_takedown() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_appeal_takedown());
+        });
+    }
+
+    #[test]
+    fn overturn_takedown() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_overturn_takedown());
+        });
+    }
+
+    #[test]
+    fn expire_takedown_appeal() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_expire_takedown_appeal());
+        });
+    }
+
     #[test]
     fn create_curator_group() {
         with_default_mock_builder(|| {
@@ -3435,6 +4051,20 @@ pub mod tests {
         })
     }
 
+    #[test]
+    fn creator_token_issuer_create_airdrop() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_creator_token_issuer_create_airdrop());
+        })
+    }
+
+    #[test]
+    fn revoke_creator_token_vesting_schedule() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_revoke_creator_token_vesting_schedule());
+        })
+    }
+
     #[test]
     fn set_curator_group_status() {
         with_default_mock_builder(|| {
@@ -3771,6 +4401,13 @@ pub mod tests {
         });
     }
 
+    #[test]
+    fn update_creator_token_bridging() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_update_creator_token_bridging());
+        });
+    }
+
     #[test]
     fn deissue_creator_token() {
         with_default_mock_builder(|| {
@@ -3785,6 +4422,13 @@ pub mod tests {
         });
     }
 
+    #[test]
+    fn init_creator_token_batch_auction() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_init_creator_token_batch_auction());
+        });
+    }
+
     #[test]
     fn update_upcoming_creator_token_sale() {
         with_default_mock_builder(|| {
@@ -3806,6 +4450,13 @@ pub mod tests {
         });
     }
 
+    #[test]
+    fn deposit_creator_token_staking_revenue() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_deposit_creator_token_staking_revenue());
+        });
+    }
+
     #[test]
     fn issue_revenue_split_as_collaborator() {
         with_default_mock_builder(|| {
@@ -3848,6 +4499,20 @@ pub mod tests {
         });
     }
 
+    #[test]
+    fn create_creator_token_poll() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_create_creator_token_poll());
+        });
+    }
+
+    #[test]
+    fn execute_creator_token_poll_action() {
+        with_default_mock_builder(|| {
+            assert_ok!(Content::test_benchmark_execute_creator_token_poll_action());
+        });
+    }
+
     #[test]
     fn creator_token_issuer_remark() {
         with_default_mock_builder(|| {
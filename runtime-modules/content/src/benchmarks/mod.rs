@@ -86,7 +86,7 @@ const DEFAULT_CRT_SALE_UPPER_BOUND: u32 = DEFAULT_CRT_OWNER_ISSUANCE;
 const DEFAULT_CRT_REVENUE_SPLIT_RATE: Permill = Permill::from_percent(50);
 const DEFAULT_CRT_PATRONAGE_RATE: YearlyRate = YearlyRate(Permill::from_percent(10));
 
-const CHANNEL_AGENT_PERMISSIONS: [ChannelActionPermission; 22] = [
+const CHANNEL_AGENT_PERMISSIONS: [ChannelActionPermission; 24] = [
     ChannelActionPermission::UpdateChannelMetadata,
     ChannelActionPermission::ManageNonVideoChannelAssets,
     ChannelActionPermission::ManageChannelCollaborators,
@@ -109,6 +109,8 @@ const CHANNEL_AGENT_PERMISSIONS: [ChannelActionPermission; 22] = [
     ChannelActionPermission::ManageRevenueSplits,
     ChannelActionPermission::DeissueCreatorToken,
     ChannelActionPermission::AmmControl,
+    ChannelActionPermission::ManageCreatorTokenPolls,
+    ChannelActionPermission::ManageCreatorTokenBridging,
 ];
 
 const CONTENT_MODERATION_ACTIONS: [ContentModerationAction; 13] = [
@@ -1199,6 +1201,29 @@ where
     Ok((video_id, p))
 }
 
+fn setup_worst_case_scenario_takedown_request<T>(
+    assets_num: u32,
+    storage_buckets_num: u32,
+) -> Result<(TakedownRequestId, VideoCreationInputParameters<T>), DispatchError>
+where
+    T: RuntimeConfig,
+    T::AccountId: CreateAccountId,
+{
+    let (video_id, p) =
+        setup_worst_case_scenario_mutable_video::<T>(Some(assets_num), storage_buckets_num)?;
+    let (requester_account_id, requester_id) = member_funded_account::<T>();
+
+    let takedown_request_id = Pallet::<T>::next_takedown_request_id();
+    Pallet::<T>::file_takedown_request(
+        RawOrigin::Signed(requester_account_id).into(),
+        requester_id,
+        TakedownTarget::Video(video_id),
+        Vec::new(),
+    )?;
+
+    Ok((takedown_request_id, p))
+}
+
 fn storage_buckets_num_witness<T: Config>(channel_id: T::ChannelId) -> Result<u32, DispatchError> {
     let bag_id = Pallet::<T>::bag_id_for_channel(&channel_id);
     let channel_bag = <T as Config>::DataObjectStorage::ensure_bag_exists(&bag_id)?;
@@ -1357,6 +1382,7 @@ where
             let payment = PaymentWithVestingOf::<T> {
                 amount: 100u32.into(),
                 vesting_schedule: Some(default_vesting_schedule_params::<T>()),
+                revocable: true,
             };
             (member_id, payment)
         })
@@ -1664,7 +1690,7 @@ fn call_activate_amm<T: Config>(
     actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
     channel_id: T::ChannelId,
 ) {
-    let params = AmmParams {
+    let params = AmmParams::Linear {
         slope: 10_000_000u32.into(),
         intercept: 100u32.into(),
     };
@@ -411,6 +411,48 @@ decl_error! {
         ChannelTransfersBlockedDuringTokenSales,
 
         /// Channel Transfers are blocked during active AMM
-        ChannelTransfersBlockedDuringActiveAmm
+        ChannelTransfersBlockedDuringActiveAmm,
+
+        // Content takedowns
+        // ---------------------
+
+        /// Takedown request doesn't exist
+        TakedownRequestDoesNotExist,
+
+        /// Video targeted by the takedown request has no assets
+        TakedownTargetHasNoAssets,
+
+        /// Insufficient balance to pay the takedown request bond
+        InsufficientBalanceForTakedownRequestBond,
+
+        /// Takedown request is not pending anymore
+        TakedownRequestNotPending,
+
+        /// Takedown request decision period has already expired
+        TakedownRequestDecisionPeriodExpired,
+
+        /// Takedown request decision period has not expired yet
+        TakedownRequestDecisionPeriodNotExpired,
+
+        /// Only accepted takedowns can be appealed
+        TakedownNotAccepted,
+
+        /// Takedown appeal period has already expired
+        TakedownAppealPeriodExpired,
+
+        /// Only appealed takedowns can be overturned or expired
+        TakedownNotAppealed,
+
+        /// Takedown appeal decision period has already expired
+        TakedownAppealDecisionPeriodExpired,
+
+        /// Takedown appeal decision period has not expired yet
+        TakedownAppealDecisionPeriodNotExpired,
+
+        // Creator token polls
+        // ---------------------
+
+        /// Linked action option index is not one of the poll options
+        InvalidCreatorTokenPollActionOption
     }
 }
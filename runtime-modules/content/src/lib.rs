@@ -36,8 +36,8 @@ pub mod weights;
 use core::marker::PhantomData;
 use project_token::traits::PalletToken;
 use project_token::types::{
-    AmmParamsOf, JoyBalanceOf, TokenIssuanceParametersOf, TokenSaleParamsOf,
-    TransferWithVestingOutputsOf, UploadContextOf, YearlyRate,
+    AirdropParamsOf, AmmParamsOf, JoyBalanceOf, PollId, PollParamsOf, TokenIssuanceParametersOf,
+    TokenSaleParamsOf, TransferWithVestingOutputsOf, UploadContextOf, YearlyRate,
 };
 use sp_std::vec;
 pub use weights::WeightInfo;
@@ -195,6 +195,8 @@ pub trait Config:
         UploadContextOf<Self>,
         TransferWithVestingOutputsOf<Self>,
         AmmParamsOf<Self>,
+        AirdropParamsOf<Self>,
+        PollParamsOf<Self>,
     >;
 
     /// Minimum cashout allowed limit
@@ -202,6 +204,18 @@ pub trait Config:
 
     /// Max cashout allowed limit
     type MaximumCashoutAllowedLimit: Get<BalanceOf<Self>>;
+
+    /// Bond paid for filing a content takedown request
+    type TakedownRequestBond: Get<BalanceOf<Self>>;
+
+    /// Number of blocks curators have to decide a content takedown request
+    type TakedownRequestDecisionPeriod: Get<Self::BlockNumber>;
+
+    /// Number of blocks the channel owner has to appeal an accepted content takedown
+    type TakedownAppealPeriod: Get<Self::BlockNumber>;
+
+    /// Number of blocks the council has to overturn an appealed content takedown
+    type TakedownAppealDecisionPeriod: Get<Self::BlockNumber>;
 }
 
 decl_storage! { generate_storage_info
@@ -304,6 +318,18 @@ decl_storage! { generate_storage_info
         /// NFT limits enabled or not
         /// Can be updated in flight by the Council
         pub NftLimitsEnabled get(fn nft_limits_enabled) config(): bool;
+
+        /// Takedown request id counter
+        pub NextTakedownRequestId get(fn next_takedown_request_id): TakedownRequestId;
+
+        /// Content takedown requests (including the decided ones)
+        pub TakedownRequestById get(fn takedown_request_by_id):
+        map hasher(blake2_128_concat) TakedownRequestId => TakedownRequest<T>;
+
+        /// Channel actions linked to the outcome of creator token polls
+        pub CreatorTokenPollActions get(fn creator_token_poll_action):
+        double_map hasher(blake2_128_concat) T::ChannelId,
+        hasher(blake2_128_concat) PollId => LinkedCreatorTokenPollAction<T>;
     }
     add_extra_genesis {
         build(|_| {
@@ -358,6 +384,18 @@ decl_module! {
         /// Export const - max cashout allowed limits
         const MaximumCashoutAllowedLimit: BalanceOf<T> = T::MaximumCashoutAllowedLimit::get();
 
+        /// Export const - content takedown request bond
+        const TakedownRequestBond: BalanceOf<T> = T::TakedownRequestBond::get();
+
+        /// Export const - content takedown request decision period
+        const TakedownRequestDecisionPeriod: T::BlockNumber = T::TakedownRequestDecisionPeriod::get();
+
+        /// Export const - content takedown appeal period
+        const TakedownAppealPeriod: T::BlockNumber = T::TakedownAppealPeriod::get();
+
+        /// Export const - content takedown appeal decision period
+        const TakedownAppealDecisionPeriod: T::BlockNumber = T::TakedownAppealDecisionPeriod::get();
+
         // ======
         // Next set of extrinsics can only be invoked by lead.
         // ======
@@ -1442,6 +1480,347 @@ decl_module! {
             Ok(())
         }
 
+        /// File a bonded takedown request against a channel asset, a video asset or all video assets
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (A)` where:
+        /// - `A` is the size of `rationale` in kilobytes
+        /// - DB:
+        ///    - `O(MaxNumberOfAssetsPerVideo)` - from the the generated weights
+        /// # </weight>
+        #[weight = Module::<T>::file_takedown_request_weight(rationale)]
+        pub fn file_takedown_request(
+            origin,
+            member_id: T::MemberId,
+            target: TakedownTargetOf<T>,
+            rationale: Vec<u8>,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            ensure_member_auth_success::<T>(&sender, &member_id)?;
+
+            let (channel_id, assets) = Self::ensure_valid_takedown_target(&target)?;
+
+            let bag_id = Self::bag_id_for_channel(&channel_id);
+            let content_ids: TakedownContentIds<T> = assets
+                .iter()
+                .map(|id| Storage::<T>::data_object_by_id(&bag_id, id).ipfs_content_id)
+                .collect::<BTreeSet<_>>()
+                .try_into()
+                .map_err(|_| Error::<T>::MaxNumberOfVideoAssetsExceeded)?;
+
+            let bond = T::TakedownRequestBond::get();
+            ensure!(
+                has_sufficient_balance_for_payment::<T>(&sender, bond),
+                Error::<T>::InsufficientBalanceForTakedownRequestBond
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            ContentTreasury::<T>::deposit(&sender, bond)?;
+
+            let deadline = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::TakedownRequestDecisionPeriod::get());
+
+            let takedown_request = TakedownRequest::<T> {
+                requester: member_id,
+                bond_account: sender,
+                bond,
+                channel_id,
+                target,
+                content_ids,
+                blacklisted_content_ids: Default::default(),
+                status: TakedownRequestStatus::Pending(deadline),
+            };
+
+            let takedown_request_id = Self::next_takedown_request_id();
+            TakedownRequestById::<T>::insert(takedown_request_id, takedown_request.clone());
+            NextTakedownRequestId::mutate(|id| *id = id.saturating_add(1));
+
+            Self::deposit_event(
+                RawEvent::TakedownRequestFiled(takedown_request_id, takedown_request, rationale)
+            );
+        }
+
+        /// Accept a pending takedown request: blacklist the targeted content ids, remove the
+        /// targeted assets still present and return the requester bond
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (A + B)` where:
+        /// - `A` is the value of `storage_buckets_num_witness`
+        /// - `B` is the size of `rationale` in kilobytes
+        /// - DB:
+        ///    - `O(A + MaxNumberOfAssetsPerVideo)` - from the the generated weights
+        /// # </weight>
+        #[weight = Module::<T>::accept_takedown_request_weight(storage_buckets_num_witness, rationale)]
+        pub fn accept_takedown_request(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            takedown_request_id: TakedownRequestId,
+            storage_buckets_num_witness: u32,
+            rationale: Vec<u8>,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let takedown_request = Self::ensure_takedown_request_exists(&takedown_request_id)?;
+            takedown_request.ensure_can_be_decided::<T>(frame_system::Pallet::<T>::block_number())?;
+
+            let (assets, action) = Self::get_takedown_target_assets(&takedown_request.target);
+
+            // permissions check
+            let channel = Self::channel_by_id(takedown_request.channel_id);
+            ensure_actor_authorized_to_perform_moderation_actions::<T>(&sender, &actor, &[action], channel.privilege_level)?;
+
+            if !assets.is_empty() {
+                // verify storage buckets witness
+                Self::verify_storage_buckets_num_witness(takedown_request.channel_id, storage_buckets_num_witness)?;
+            }
+
+            // content ids not blacklisted yet
+            let blacklisted_content_ids: TakedownContentIds<T> = takedown_request
+                .content_ids
+                .iter()
+                .filter(|cid| !storage::Blacklist::contains_key(cid))
+                .cloned()
+                .collect::<BTreeSet<_>>()
+                .try_into()
+                .map_err(|_| Error::<T>::MaxNumberOfVideoAssetsExceeded)?;
+
+            let add_hashes = blacklisted_content_ids
+                .iter()
+                .map(|cid| cid.to_vec())
+                .collect::<BTreeSet<_>>();
+
+            Storage::<T>::can_update_content_blacklist(&BTreeSet::new(), &add_hashes)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Storage::<T>::update_content_blacklist(BTreeSet::new(), add_hashes)?;
+
+            if !assets.is_empty() {
+                Storage::<T>::delete_data_objects(
+                    sender,
+                    Self::bag_id_for_channel(&takedown_request.channel_id),
+                    assets.clone(),
+                )?;
+
+                Self::remove_takedown_target_assets(&takedown_request.target, &assets)?;
+            }
+
+            ContentTreasury::<T>::withdraw(&takedown_request.bond_account, takedown_request.bond)?;
+
+            let appeal_deadline = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::TakedownAppealPeriod::get());
+
+            TakedownRequestById::<T>::mutate(takedown_request_id, |request| {
+                request.blacklisted_content_ids = blacklisted_content_ids;
+                request.status = TakedownRequestStatus::Accepted(appeal_deadline);
+            });
+
+            Self::deposit_event(
+                RawEvent::TakedownRequestAccepted(actor, takedown_request_id, assets, rationale)
+            );
+        }
+
+        /// Reject a pending takedown request and slash the requester bond
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (A)` where:
+        /// - `A` is the size of `rationale` in kilobytes
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::reject_takedown_request(to_kb(rationale.len() as u32))]
+        pub fn reject_takedown_request(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            takedown_request_id: TakedownRequestId,
+            rationale: Vec<u8>,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let takedown_request = Self::ensure_takedown_request_exists(&takedown_request_id)?;
+            takedown_request.ensure_can_be_decided::<T>(frame_system::Pallet::<T>::block_number())?;
+
+            let (_, action) = Self::get_takedown_target_assets(&takedown_request.target);
+
+            // permissions check
+            let channel = Self::channel_by_id(takedown_request.channel_id);
+            ensure_actor_authorized_to_perform_moderation_actions::<T>(&sender, &actor, &[action], channel.privilege_level)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            burn_from_usable::<T>(&ContentTreasury::<T>::module_account_id(), takedown_request.bond)?;
+
+            TakedownRequestById::<T>::mutate(takedown_request_id, |request| {
+                request.status = TakedownRequestStatus::Rejected;
+            });
+
+            Self::deposit_event(
+                RawEvent::TakedownRequestRejected(actor, takedown_request_id, rationale)
+            );
+        }
+
+        /// Close a takedown request not decided within the decision period and return the
+        /// requester bond. Can be called by anyone.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::expire_takedown_request()]
+        pub fn expire_takedown_request(
+            origin,
+            takedown_request_id: TakedownRequestId,
+        ) {
+            ensure_signed(origin)?;
+
+            let takedown_request = Self::ensure_takedown_request_exists(&takedown_request_id)?;
+            takedown_request.ensure_can_expire::<T>(frame_system::Pallet::<T>::block_number())?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            ContentTreasury::<T>::withdraw(&takedown_request.bond_account, takedown_request.bond)?;
+
+            TakedownRequestById::<T>::mutate(takedown_request_id, |request| {
+                request.status = TakedownRequestStatus::Expired;
+            });
+
+            Self::deposit_event(RawEvent::TakedownRequestExpired(takedown_request_id));
+        }
+
+        /// Appeal an accepted takedown within the appeal period. The appeal is decided by the
+        /// council through the `Overturn Content Takedown` proposal within the appeal decision
+        /// period.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (A)` where:
+        /// - `A` is the size of `rationale` in kilobytes
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::appeal_takedown(to_kb(rationale.len() as u32))]
+        pub fn appeal_takedown(
+            origin,
+            takedown_request_id: TakedownRequestId,
+            rationale: Vec<u8>,
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let takedown_request = Self::ensure_takedown_request_exists(&takedown_request_id)?;
+            takedown_request.ensure_can_be_appealed::<T>(
+                frame_system::Pallet::<T>::block_number()
+            )?;
+
+            let channel = Self::ensure_channel_exists(&takedown_request.channel_id)?;
+            ensure_is_authorized_to_act_as_channel_owner::<T>(&sender, &channel.owner)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let decision_deadline = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::TakedownAppealDecisionPeriod::get());
+
+            TakedownRequestById::<T>::mutate(takedown_request_id, |request| {
+                request.status = TakedownRequestStatus::Appealed(decision_deadline);
+            });
+
+            Self::deposit_event(RawEvent::TakedownAppealed(takedown_request_id, rationale));
+        }
+
+        /// Overturn an appealed takedown: remove the content ids blacklisted by the takedown
+        /// from the blacklist
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - `O(MaxNumberOfAssetsPerVideo)` - from the the generated weights
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::overturn_takedown(T::MaxNumberOfAssetsPerVideo::get())]
+        pub fn overturn_takedown(
+            origin,
+            takedown_request_id: TakedownRequestId,
+        ) {
+            ensure_root(origin)?;
+
+            let takedown_request = Self::ensure_takedown_request_exists(&takedown_request_id)?;
+            takedown_request.ensure_can_be_overturned::<T>(
+                frame_system::Pallet::<T>::block_number()
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Storage::<T>::update_content_blacklist(
+                takedown_request
+                    .blacklisted_content_ids
+                    .iter()
+                    .map(|cid| cid.to_vec())
+                    .collect(),
+                BTreeSet::new(),
+            )?;
+
+            TakedownRequestById::<T>::mutate(takedown_request_id, |request| {
+                request.status = TakedownRequestStatus::Overturned;
+            });
+
+            Self::deposit_event(RawEvent::TakedownOverturned(takedown_request_id));
+        }
+
+        /// Close a takedown appeal not overturned within the appeal decision period: the takedown
+        /// stands and the takedown request is removed. Can be called by anyone.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::expire_takedown_appeal()]
+        pub fn expire_takedown_appeal(
+            origin,
+            takedown_request_id: TakedownRequestId,
+        ) {
+            ensure_signed(origin)?;
+
+            let takedown_request = Self::ensure_takedown_request_exists(&takedown_request_id)?;
+            takedown_request.ensure_appeal_can_expire::<T>(
+                frame_system::Pallet::<T>::block_number()
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            TakedownRequestById::<T>::remove(takedown_request_id);
+
+            Self::deposit_event(RawEvent::TakedownAppealExpired(takedown_request_id));
+        }
+
         /// Update channel payouts
         ///
         /// <weight>
@@ -3232,6 +3611,57 @@ decl_module! {
             )?;
         }
 
+        /// Initialize creator token sale in batch auction mode
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (A)` where:
+        /// - `A` is the size of `params.metadata` in kilobytes (or 0 if not provided)
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::init_creator_token_batch_auction(
+            to_kb(params.metadata.as_ref().map_or(0u32, |v| v.len() as u32))
+        )]
+        pub fn init_creator_token_batch_auction(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            params: TokenSaleParamsOf<T>,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            let (sender, _) = ensure_actor_authorized_to_init_and_manage_creator_token_sale::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Retrieve member_id based on actor
+            let member_id = get_member_id_of_actor::<T>(&actor)?;
+
+            // Establish earnings destination based on channel owner and sender
+            let earnings_dst = Self::establish_creator_token_sale_earnings_destination(
+                &channel.owner,
+                &sender
+            );
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            T::ProjectToken::init_batch_auction(
+                token_id,
+                member_id,
+                earnings_dst,
+                params
+            )?;
+        }
+
         /// Update upcoming creator token sale
         ///
         /// <weight>
@@ -3332,8 +3762,99 @@ decl_module! {
             )?;
         }
 
-
-        /// Make channel's creator token permissionless
+        /// Create creator token airdrop funded from the issuer account
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::creator_token_issuer_create_airdrop()]
+        pub fn creator_token_issuer_create_airdrop(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            params: AirdropParamsOf<T>
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            ensure_actor_authorized_to_perform_creator_token_issuer_transfer::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // Retrieve member_id based on actor
+            let member_id = get_member_id_of_actor::<T>(&actor)?;
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            T::ProjectToken::create_airdrop(
+                token_id,
+                member_id,
+                params
+            )?;
+        }
+
+
+        /// Revoke a revocable vesting schedule granted through a creator token issuer transfer.
+        /// The vested part stays with the holder, while the unvested part returns to the current
+        /// issuer: the channel owner member (or the actor's member for curator group channels).
+        /// The issuer token account is created if it doesn't exist.
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::revoke_creator_token_vesting_schedule()]
+        pub fn revoke_creator_token_vesting_schedule(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            member_id: T::MemberId,
+            transfer_id: u64,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            let sender = ensure_actor_authorized_to_perform_creator_token_issuer_transfer::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // Resolve the current issuer member
+            let issuer_member_id = match channel.owner {
+                ChannelOwner::Member(owner_member_id) => owner_member_id,
+                ChannelOwner::CuratorGroup(_) => get_member_id_of_actor::<T>(&actor)?,
+            };
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            T::ProjectToken::revoke_vesting_schedule(
+                token_id,
+                member_id,
+                transfer_id,
+                issuer_member_id,
+                sender
+            )?;
+        }
+
+        /// Make channel's creator token permissionless
         ///
         /// <weight>
         ///
@@ -3366,6 +3887,40 @@ decl_module! {
             T::ProjectToken::change_to_permissionless(token_id)?;
         }
 
+        /// Enable / disable bridging of channel's creator token over the JOY bridge
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::update_creator_token_bridging()]
+        pub fn update_creator_token_bridging(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            enabled: bool,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            ensure_actor_authorized_to_manage_creator_token_bridging::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            T::ProjectToken::update_token_bridging(token_id, enabled)?;
+        }
+
         /// Reduce channel's creator token patronage rate to given value
         ///
         /// <weight>
@@ -3497,6 +4052,53 @@ decl_module! {
             Self::execute_channel_balance_withdrawal(&reward_account, &leftover_destination, leftover_amount)?
         }
 
+        /// Deposit channel's revenue into its creator token's revenue staking pool
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::deposit_creator_token_staking_revenue()]
+        pub fn deposit_creator_token_staking_revenue(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            ensure_actor_authorized_to_manage_revenue_splits::<T>(
+                origin,
+                &actor,
+                &channel
+            )?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // Get channel's reward account and its balance
+            let reward_account = ContentTreasury::<T>::account_for_channel(channel_id);
+            let withdrawable_balance =
+                Self::channel_account_withdrawable_balance(&reward_account, &channel);
+
+            // Get leftover funds destination
+            let leftover_destination = Self::channel_funds_destination(&channel)?;
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            let leftover_amount = T::ProjectToken::deposit_staking_revenue(
+                token_id,
+                reward_account.clone(),
+                withdrawable_balance
+            )?;
+
+            Self::execute_channel_balance_withdrawal(&reward_account, &leftover_destination, leftover_amount)?
+        }
+
         /// Finalize an ended revenue split
         ///
         /// <weight>
@@ -3697,6 +4299,150 @@ decl_module! {
 
         }
 
+        /// Create a poll among channel's creator token holders, optionally linking
+        /// a channel action to one of the poll options
+        ///
+        /// Preconditions:
+        /// - channel must exist and have a creator token issued
+        /// - actor must be authorized to manage creator token polls and, if an action is linked,
+        ///   to perform that action directly
+        /// - linked option index must be one of the poll options
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::create_creator_token_poll(to_kb(params.metadata.len() as u32))]
+        pub fn create_creator_token_poll(
+            origin,
+            actor: ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+            channel_id: T::ChannelId,
+            params: PollParamsOf<T>,
+            linked_action: Option<(u32, CreatorTokenPollActionOf<T>)>,
+        ) {
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            channel.ensure_has_no_active_transfer::<T>()?;
+
+            // Permissions check
+            ensure_actor_authorized_to_create_creator_token_poll::<T>(
+                origin,
+                &actor,
+                &channel,
+                linked_action.as_ref().map(|(_, action)| action),
+            )?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            if let Some((option_index, _)) = linked_action {
+                ensure!(
+                    option_index < params.options_number,
+                    Error::<T>::InvalidCreatorTokenPollActionOption
+                );
+            }
+
+            // Retrieve member_id based on actor
+            let member_id = get_member_id_of_actor::<T>(&actor)?;
+
+            // Call to ProjectToken - should be the first call before MUTATION SAFE!
+            let poll_id = T::ProjectToken::create_poll(token_id, member_id, params)?;
+
+            if let Some((option_index, action)) = linked_action {
+                CreatorTokenPollActions::<T>::insert(
+                    channel_id,
+                    poll_id,
+                    LinkedCreatorTokenPollActionRecord {
+                        member_id,
+                        channel_owner: channel.owner.clone(),
+                        option_index,
+                        action,
+                    },
+                );
+            }
+
+            Self::deposit_event(RawEvent::CreatorTokenPollCreated(
+                actor,
+                channel_id,
+                token_id,
+                poll_id,
+            ));
+        }
+
+        /// Finalize an ended creator token poll, resolving the channel action linked to it.
+        /// The action is executed if its option won the poll and the channel owner has not
+        /// changed since the poll creation, otherwise it is discarded.
+        /// The poll is then removed together with its votes (in bounded batches, so the call
+        /// may need to be repeated).
+        /// Can be called by any signed origin.
+        ///
+        /// Preconditions:
+        /// - channel must exist and have a creator token issued
+        /// - poll must exist and must have ended
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (V)` where:
+        /// - `V` is the number of removed poll votes (bounded by `MaxPollVotesRemovedPerCall`)
+        /// - DB:
+        ///    - `O(V)` - from the the generated weights
+        /// # </weight>
+        #[weight = WeightInfoContent::<T>::execute_creator_token_poll_action(
+            <T as project_token::Config>::MaxPollVotesRemovedPerCall::get()
+        )]
+        pub fn execute_creator_token_poll_action(
+            origin,
+            channel_id: T::ChannelId,
+            poll_id: PollId,
+        ) {
+            ensure_signed(origin)?;
+
+            let channel = Self::ensure_channel_exists(&channel_id)?;
+
+            // Ensure token was issued
+            let token_id = channel.ensure_creator_token_issued::<T>()?;
+
+            // Ensure poll has ended
+            let outcome = T::ProjectToken::poll_outcome(token_id, poll_id)?;
+
+            let linked_action = CreatorTokenPollActions::<T>::try_get(channel_id, poll_id).ok();
+
+            let resolved_action = if let Some(linked_action) = linked_action {
+                // The action was authorized for the channel owner that linked it
+                let executed = outcome == Some(linked_action.option_index) &&
+                    linked_action.channel_owner == channel.owner;
+
+                if executed {
+                    Self::execute_linked_poll_action(
+                        channel_id,
+                        &channel,
+                        token_id,
+                        linked_action,
+                    )?;
+                }
+
+                CreatorTokenPollActions::<T>::remove(channel_id, poll_id);
+
+                Some(executed)
+            } else {
+                None
+            };
+
+            T::ProjectToken::finalize_poll(token_id, poll_id)?;
+
+            if let Some(executed) = resolved_action {
+                Self::deposit_event(RawEvent::CreatorTokenPollActionResolved(
+                    channel_id,
+                    poll_id,
+                    executed,
+                ));
+            }
+        }
+
         /// Allow crt issuer to update metadata for an existing token
         #[weight = WeightInfoContent::<T>::creator_token_issuer_remark(to_kb(remark.len() as u32))]
         pub fn creator_token_issuer_remark(
@@ -3731,6 +4477,46 @@ decl_module! {
 }
 
 impl<T: Config> Module<T> {
+    /// Perform the channel action linked to a creator token poll
+    fn execute_linked_poll_action(
+        channel_id: T::ChannelId,
+        channel: &Channel<T>,
+        token_id: T::TokenId,
+        linked_action: LinkedCreatorTokenPollAction<T>,
+    ) -> DispatchResult {
+        match linked_action.action {
+            CreatorTokenPollAction::IssueRevenueSplit(duration) => {
+                // Get channel's reward account and its balance
+                let reward_account = ContentTreasury::<T>::account_for_channel(channel_id);
+                let withdrawable_balance =
+                    Self::channel_account_withdrawable_balance(&reward_account, channel);
+
+                // Get leftover funds destination
+                let leftover_destination = Self::channel_funds_destination(channel)?;
+
+                let leftover_amount = T::ProjectToken::issue_revenue_split(
+                    token_id,
+                    None,
+                    duration,
+                    reward_account.clone(),
+                    withdrawable_balance,
+                )?;
+
+                Self::execute_channel_balance_withdrawal(
+                    &reward_account,
+                    &leftover_destination,
+                    leftover_amount,
+                )
+            }
+            CreatorTokenPollAction::ReducePatronageRate(target_rate) => {
+                T::ProjectToken::reduce_patronage_rate_to(token_id, target_rate)
+            }
+            CreatorTokenPollAction::DeactivateAmm => {
+                T::ProjectToken::deactivate_amm(token_id, linked_action.member_id)
+            }
+        }
+    }
+
     /// Ensure `CuratorGroup` under given id exists
     fn ensure_curator_group_under_given_id_exists(
         curator_group_id: &T::CuratorGroupId,
@@ -4452,6 +5238,114 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    fn ensure_takedown_request_exists(
+        takedown_request_id: &TakedownRequestId,
+    ) -> Result<TakedownRequest<T>, DispatchError> {
+        ensure!(
+            TakedownRequestById::<T>::contains_key(takedown_request_id),
+            Error::<T>::TakedownRequestDoesNotExist
+        );
+        Ok(TakedownRequestById::<T>::get(takedown_request_id))
+    }
+
+    // Ensure the takedown target exists. Returns the target channel and targeted assets.
+    fn ensure_valid_takedown_target(
+        target: &TakedownTargetOf<T>,
+    ) -> Result<(T::ChannelId, BTreeSet<DataObjectId<T>>), DispatchError> {
+        match target {
+            TakedownTarget::ChannelAsset(channel_id, data_object_id) => {
+                let channel = Self::ensure_channel_exists(channel_id)?;
+                let assets = vec![*data_object_id].into_iter().collect();
+                Self::ensure_assets_to_remove_are_part_of_assets_set(
+                    &assets,
+                    &channel.data_objects,
+                )?;
+                Ok((*channel_id, assets))
+            }
+            TakedownTarget::VideoAsset(video_id, data_object_id) => {
+                let video = Self::ensure_video_exists(video_id)?;
+                let assets = vec![*data_object_id].into_iter().collect();
+                Self::ensure_assets_to_remove_are_part_of_assets_set(&assets, &video.data_objects)?;
+                Ok((video.in_channel, assets))
+            }
+            TakedownTarget::Video(video_id) => {
+                let video = Self::ensure_video_exists(video_id)?;
+                ensure!(
+                    !video.data_objects.is_empty(),
+                    Error::<T>::TakedownTargetHasNoAssets
+                );
+                Ok((video.in_channel, video.data_objects.into()))
+            }
+        }
+    }
+
+    // Returns the targeted assets still assigned to the channel or video along with
+    // the moderation action required to take them down.
+    fn get_takedown_target_assets(
+        target: &TakedownTargetOf<T>,
+    ) -> (BTreeSet<DataObjectId<T>>, ContentModerationAction) {
+        match target {
+            TakedownTarget::ChannelAsset(channel_id, data_object_id) => {
+                let assets = Self::channel_by_id(channel_id)
+                    .data_objects
+                    .iter()
+                    .filter(|id| *id == data_object_id)
+                    .cloned()
+                    .collect();
+                (assets, ContentModerationAction::DeleteNonVideoChannelAssets)
+            }
+            TakedownTarget::VideoAsset(video_id, data_object_id) => {
+                let video = Self::video_by_id(video_id);
+                let assets = video
+                    .data_objects
+                    .iter()
+                    .filter(|id| *id == data_object_id)
+                    .cloned()
+                    .collect();
+                let is_nft = video.nft_status.is_some();
+                (assets, ContentModerationAction::DeleteVideoAssets(is_nft))
+            }
+            TakedownTarget::Video(video_id) => {
+                let video = Self::video_by_id(video_id);
+                let is_nft = video.nft_status.is_some();
+                (
+                    video.data_objects.into(),
+                    ContentModerationAction::DeleteVideoAssets(is_nft),
+                )
+            }
+        }
+    }
+
+    // Removes taken down assets from the channel or video assets set.
+    fn remove_takedown_target_assets(
+        target: &TakedownTargetOf<T>,
+        assets: &BTreeSet<DataObjectId<T>>,
+    ) -> DispatchResult {
+        match target {
+            TakedownTarget::ChannelAsset(channel_id, _) => {
+                let updated_assets = Self::create_updated_channel_assets_set(
+                    &Self::channel_by_id(channel_id).data_objects,
+                    &BTreeSet::new(),
+                    assets,
+                )?;
+                ChannelById::<T>::mutate(channel_id, |channel| {
+                    channel.data_objects = updated_assets;
+                });
+            }
+            TakedownTarget::VideoAsset(video_id, _) | TakedownTarget::Video(video_id) => {
+                let updated_assets = Self::create_updated_video_assets_set(
+                    &Self::video_by_id(video_id).data_objects,
+                    &BTreeSet::new(),
+                    assets,
+                )?;
+                VideoById::<T>::mutate(video_id, |video| {
+                    video.data_objects = updated_assets;
+                });
+            }
+        }
+        Ok(())
+    }
+
     //Weight functions
 
     // Calculates weight for create_channel extrinsic.
@@ -4459,8 +5353,19 @@ impl<T: Config> Module<T> {
         //collaborators
         let a = params.collaborators.len() as u32;
 
-        //storage_buckets
-        let b = params.storage_buckets.len() as u32;
+        //storage_buckets (selected by the storage pallet when not provided)
+        let b = if params.storage_buckets.is_empty() {
+            <T as storage::Config>::MaxStorageBucketsPerBag::get()
+        } else {
+            params.storage_buckets.len() as u32
+        };
+
+        // storage buckets (with operator tags) scanned on the automatic selection
+        let scanned_buckets = if params.storage_buckets.is_empty() {
+            <T as storage::Config>::MaxStorageBucketsScannedOnAssignment::get()
+        } else {
+            0
+        };
 
         //distribution_buckets
         let c = params.distribution_buckets.len() as u32;
@@ -4475,6 +5380,7 @@ impl<T: Config> Module<T> {
         let e = to_kb(params.meta.as_ref().map_or(0, |v| v.len()) as u32);
 
         WeightInfoContent::<T>::create_channel(a, b, c, d, e)
+            .saturating_add(T::DbWeight::get().reads((scanned_buckets as u64).saturating_mul(2)))
     }
 
     // Calculates weight for update_channel extrinsic.
@@ -4723,6 +5629,34 @@ impl<T: Config> Module<T> {
         WeightInfoContent::<T>::delete_video_assets_as_moderator(a, b, c)
     }
 
+    // Calculates weight for file_takedown_request extrinsic.
+    fn file_takedown_request_weight(rationale: &Vec<u8>) -> Weight {
+        //max number of targeted assets
+        let a = T::MaxNumberOfAssetsPerVideo::get();
+
+        //rationale
+        let b = to_kb((*rationale).len() as u32);
+
+        WeightInfoContent::<T>::file_takedown_request(a, b)
+    }
+
+    // Calculates weight for accept_takedown_request extrinsic.
+    fn accept_takedown_request_weight(
+        storage_buckets_num_witness: &u32,
+        rationale: &Vec<u8>,
+    ) -> Weight {
+        //max number of targeted assets
+        let a = T::MaxNumberOfAssetsPerVideo::get();
+
+        //storage_buckets_num_witness storage_buckets_num
+        let b = *storage_buckets_num_witness;
+
+        //rationale
+        let c = to_kb((*rationale).len() as u32);
+
+        WeightInfoContent::<T>::accept_takedown_request(a, b, c)
+    }
+
     // Calculates weight for accept_channel_transfer extrinsic.
     fn accept_channel_transfer_weight(collaborators_len: u32) -> Weight {
         WeightInfoContent::<T>::accept_channel_transfer_curator_to_curator(collaborators_len)
@@ -4764,6 +5698,7 @@ decl_event!(
         ChannelPrivilegeLevel = <T as Config>::ChannelPrivilegeLevel,
         ModerationPermissionsByLevel = ModerationPermissionsByLevel<T>,
         TransferCommitmentWitness = TransferCommitmentWitnessOf<T>,
+        TakedownRequest = TakedownRequest<T>,
         PendingTransfer = PendingTransferOf<T>,
         AccountId = <T as frame_system::Config>::AccountId,
         UpdateChannelPayoutsParameters = UpdateChannelPayoutsParameters<T>,
@@ -4881,6 +5816,32 @@ decl_event!(
         // Creator tokens
         CreatorTokenIssued(ContentActor, ChannelId, TokenId),
         CreatorTokenIssuerRemarked(ChannelId, TokenId, Vec<u8>),
+
+        // Content takedowns
+        TakedownRequestFiled(
+            TakedownRequestId,
+            TakedownRequest,
+            Vec<u8>, /* rationale */
+        ),
+        TakedownRequestAccepted(
+            ContentActor,
+            TakedownRequestId,
+            BTreeSet<DataObjectId>, /* removed assets */
+            Vec<u8>,                /* rationale */
+        ),
+        TakedownRequestRejected(
+            ContentActor,
+            TakedownRequestId,
+            Vec<u8>, /* rationale */
+        ),
+        TakedownRequestExpired(TakedownRequestId),
+        TakedownAppealed(TakedownRequestId, Vec<u8> /* rationale */),
+        TakedownOverturned(TakedownRequestId),
+        TakedownAppealExpired(TakedownRequestId),
+
+        // Creator token polls
+        CreatorTokenPollCreated(ContentActor, ChannelId, TokenId, PollId),
+        CreatorTokenPollActionResolved(ChannelId, PollId, bool /* executed */),
     }
 );
 
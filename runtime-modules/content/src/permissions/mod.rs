@@ -681,6 +681,17 @@ pub fn ensure_actor_authorized_to_make_creator_token_permissionless<T: Config>(
     Ok(sender)
 }
 
+pub fn ensure_actor_authorized_to_manage_creator_token_bridging<T: Config>(
+    origin: T::RuntimeOrigin,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    channel: &Channel<T>,
+) -> Result<T::AccountId, DispatchError> {
+    let sender = ensure_signed(origin)?;
+    let required_permissions = vec![ChannelActionPermission::ManageCreatorTokenBridging];
+    ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
+    Ok(sender)
+}
+
 pub fn ensure_actor_authorized_to_reduce_creator_token_patronage_rate<T: Config>(
     origin: T::RuntimeOrigin,
     actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
@@ -735,3 +746,27 @@ pub fn ensure_actor_authorized_to_deactivate_amm<T: Config>(
     ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
     Ok(sender)
 }
+
+pub fn ensure_actor_authorized_to_create_creator_token_poll<T: Config>(
+    origin: T::RuntimeOrigin,
+    actor: &ContentActor<T::CuratorGroupId, T::CuratorId, T::MemberId>,
+    channel: &Channel<T>,
+    linked_action: Option<&CreatorTokenPollActionOf<T>>,
+) -> Result<T::AccountId, DispatchError> {
+    let sender = ensure_signed(origin)?;
+    let mut required_permissions = vec![ChannelActionPermission::ManageCreatorTokenPolls];
+    // Linking an action requires the permissions needed to perform that action directly
+    if let Some(action) = linked_action {
+        required_permissions.push(match action {
+            CreatorTokenPollAction::IssueRevenueSplit(..) => {
+                ChannelActionPermission::ManageRevenueSplits
+            }
+            CreatorTokenPollAction::ReducePatronageRate(..) => {
+                ChannelActionPermission::ReduceCreatorTokenPatronageRate
+            }
+            CreatorTokenPollAction::DeactivateAmm => ChannelActionPermission::AmmControl,
+        });
+    }
+    ensure_actor_has_channel_permissions::<T>(&sender, actor, channel, &required_permissions)?;
+    Ok(sender)
+}
@@ -1,13 +1,19 @@
 mod activate_amm;
 mod claim_patronage_credit;
+mod create_airdrop;
 mod deactivate_amm;
 mod deissue;
+mod deposit_staking_revenue;
 mod finalize_creator_token_sale;
 mod finalize_revenue_split;
+mod init_batch_auction;
 mod init_token_sale;
 mod issue;
 mod issue_revenue_split;
 mod issuer_transfer;
 mod make_permissionless;
+mod poll;
 mod reduce_patronage_rate;
+mod revoke_vesting_schedule;
+mod update_bridging;
 mod update_upcoming_sale;
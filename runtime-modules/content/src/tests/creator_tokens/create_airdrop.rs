@@ -0,0 +1,128 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+
+#[test]
+fn unsuccessful_creator_token_issuer_create_airdrop_non_existing_channel() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        CreatorTokenIssuerCreateAirdropFixture::default()
+            .call_and_assert(Err(Error::<Test>::ChannelDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_creator_token_issuer_create_airdrop_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        CreatorTokenIssuerCreateAirdropFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_creator_token_issuer_create_airdrop_with_zero_amount() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreatorTokenIssuerCreateAirdropFixture::default()
+            .with_amount(0)
+            .call_and_assert(Err(project_token::Error::<Test>::AirdropAmountIsZero.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_creator_token_issuer_create_airdrop_exceeding_issuer_balance() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreatorTokenIssuerCreateAirdropFixture::default()
+            .with_amount(DEFAULT_CREATOR_TOKEN_ISSUANCE + 1)
+            .call_and_assert(Err(
+                project_token::Error::<Test>::InsufficientTransferrableBalance.into(),
+            ));
+    })
+}
+
+// Member channel
+
+#[test]
+fn unsuccessful_member_channel_creator_token_issuer_create_airdrop_by_collaborator_without_permissions(
+) {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[
+                ChannelActionPermission::CreatorTokenIssuerTransfer,
+            ])
+            .setup();
+        CreatorTokenIssuerCreateAirdropFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_member_channel_creator_token_issuer_create_airdrop_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::CreatorTokenIssuerTransfer])
+            .setup();
+        IssueCreatorTokenFixture::default()
+            .with_initial_allocation_to(COLLABORATOR_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        CreatorTokenIssuerCreateAirdropFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_member_channel_creator_token_issuer_create_airdrop_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreatorTokenIssuerCreateAirdropFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+// Curator channel
+
+#[test]
+fn unsuccessful_curator_channel_creator_token_issuer_create_airdrop_by_curator_without_permissions()
+{
+    with_default_mock_builder(|| {
+        ContentTest::with_curator_channel()
+            .with_all_agent_permissions_except(&[
+                ChannelActionPermission::CreatorTokenIssuerTransfer,
+            ])
+            .setup();
+        CreatorTokenIssuerCreateAirdropFixture::default()
+            .with_sender(DEFAULT_CURATOR_ACCOUNT_ID)
+            .with_actor(default_curator_actor())
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_curator_channel_creator_token_issuer_create_airdrop_by_lead() {
+    with_default_mock_builder(|| {
+        ContentTest::with_curator_channel().setup();
+        IssueCreatorTokenFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .with_initial_allocation_to(LEAD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        CreatorTokenIssuerCreateAirdropFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .call_and_assert(Ok(()));
+    })
+}
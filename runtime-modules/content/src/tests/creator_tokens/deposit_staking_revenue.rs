@@ -0,0 +1,113 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::assert_ok;
+
+fn stake_for_revenue(sender: AccountId, member_id: MemberId) {
+    assert_ok!(project_token::Module::<Test>::stake_for_revenue(
+        RuntimeOrigin::signed(sender),
+        project_token::Module::<Test>::next_token_id() - 1,
+        member_id,
+        DEFAULT_CREATOR_TOKEN_ISSUANCE,
+    ));
+}
+
+#[test]
+fn unsuccessful_deposit_staking_revenue_non_existing_channel() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        DepositCreatorTokenStakingRevenueFixture::default()
+            .call_and_assert(Err(Error::<Test>::ChannelDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_deposit_staking_revenue_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        DepositCreatorTokenStakingRevenueFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_deposit_staking_revenue_with_no_tokens_staked() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        increase_account_balance_helper(
+            ContentTreasury::<Test>::account_for_channel(ChannelId::one()),
+            DEFAULT_PAYOUT_EARNED,
+        );
+        DepositCreatorTokenStakingRevenueFixture::default().call_and_assert(Err(
+            project_token::Error::<Test>::NoTokensStakedForRevenue.into(),
+        ));
+    })
+}
+
+#[test]
+fn unsuccessful_deposit_staking_revenue_with_reward_account_empty() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        stake_for_revenue(DEFAULT_MEMBER_ACCOUNT_ID, DEFAULT_MEMBER_ID);
+        DepositCreatorTokenStakingRevenueFixture::default().call_and_assert(Err(
+            project_token::Error::<Test>::CannotIssueSplitWithZeroAllocationAmount.into(),
+        ));
+    })
+}
+
+#[test]
+fn unsuccessful_deposit_member_channel_staking_revenue_by_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[ChannelActionPermission::ManageRevenueSplits])
+            .setup();
+        DepositCreatorTokenStakingRevenueFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_deposit_member_channel_staking_revenue_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ManageRevenueSplits])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        stake_for_revenue(DEFAULT_MEMBER_ACCOUNT_ID, DEFAULT_MEMBER_ID);
+        increase_account_balance_helper(
+            ContentTreasury::<Test>::account_for_channel(ChannelId::one()),
+            DEFAULT_PAYOUT_EARNED,
+        );
+        DepositCreatorTokenStakingRevenueFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_deposit_member_channel_staking_revenue_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        stake_for_revenue(DEFAULT_MEMBER_ACCOUNT_ID, DEFAULT_MEMBER_ID);
+        increase_account_balance_helper(
+            ContentTreasury::<Test>::account_for_channel(ChannelId::one()),
+            DEFAULT_PAYOUT_EARNED,
+        );
+        DepositCreatorTokenStakingRevenueFixture::default().call_and_assert(Ok(()));
+        assert!(!project_token::Module::<Test>::revenue_staking_pool(
+            project_token::Module::<Test>::next_token_id() - 1
+        )
+        .reward_per_token
+        .is_zero());
+    })
+}
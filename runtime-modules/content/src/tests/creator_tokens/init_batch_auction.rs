@@ -0,0 +1,68 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+
+#[test]
+fn unsuccessful_init_creator_token_batch_auction_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        InitCreatorTokenBatchAuctionFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_init_creator_token_batch_auction_by_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[
+                ChannelActionPermission::InitAndManageCreatorTokenSale,
+            ])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        InitCreatorTokenBatchAuctionFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_init_creator_token_batch_auction_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::InitAndManageCreatorTokenSale])
+            .setup();
+        IssueCreatorTokenFixture::default()
+            .with_initial_allocation_to(COLLABORATOR_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        InitCreatorTokenBatchAuctionFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_init_creator_token_batch_auction_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        InitCreatorTokenBatchAuctionFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn unsuccessful_init_creator_token_batch_auction_with_unfinalized_sale() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        InitCreatorTokenBatchAuctionFixture::default().call_and_assert(Ok(()));
+        InitCreatorTokenBatchAuctionFixture::default().call_and_assert(Err(
+            project_token::Error::<Test>::TokenIssuanceNotInIdleState.into(),
+        ));
+    })
+}
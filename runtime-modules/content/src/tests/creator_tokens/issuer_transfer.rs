@@ -135,6 +135,7 @@ fn unsuccessful_curator_channel_creator_token_issuer_transfer_during_transfer()
             PaymentWithVesting {
                 amount: DEFAULT_ISSUER_TRANSFER_AMOUNT,
                 vesting_schedule: None,
+                revocable: false,
             },
         )]
         .try_into()
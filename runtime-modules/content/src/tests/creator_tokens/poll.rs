@@ -0,0 +1,266 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::assert_ok;
+use project_token::types::RevenueSplitState;
+use sp_runtime::Permill;
+
+fn vote_in_default_poll(option_index: u32) {
+    let token_id = Content::channel_by_id(ChannelId::one())
+        .creator_token_id
+        .unwrap();
+    assert_ok!(project_token::Module::<Test>::vote_in_poll(
+        RuntimeOrigin::signed(DEFAULT_MEMBER_ACCOUNT_ID),
+        DEFAULT_MEMBER_ID,
+        token_id,
+        0,
+        option_index,
+    ));
+}
+
+fn default_reduce_patronage_rate_action() -> CreatorTokenPollActionOf<Test> {
+    CreatorTokenPollAction::ReducePatronageRate(YearlyRate(
+        DEFAULT_PATRONAGE_RATE
+            .0
+            .saturating_sub(Permill::from_perthousand(5)),
+    ))
+}
+
+fn default_token_patronage_rate() -> YearlyRate {
+    let token_id = Content::channel_by_id(ChannelId::one())
+        .creator_token_id
+        .unwrap();
+    project_token::Module::<Test>::token_info_by_id(token_id)
+        .patronage_info
+        .rate
+}
+
+#[test]
+fn unsuccessful_create_creator_token_poll_non_existing_channel() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        CreateCreatorTokenPollFixture::default()
+            .call_and_assert(Err(Error::<Test>::ChannelDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_create_creator_token_poll_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        CreateCreatorTokenPollFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_create_creator_token_poll_with_invalid_linked_action_option() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreateCreatorTokenPollFixture::default()
+            .with_linked_action(2, CreatorTokenPollAction::DeactivateAmm)
+            .call_and_assert(Err(
+                Error::<Test>::InvalidCreatorTokenPollActionOption.into()
+            ));
+    })
+}
+
+#[test]
+fn unsuccessful_create_creator_token_poll_by_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[ChannelActionPermission::ManageCreatorTokenPolls])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreateCreatorTokenPollFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn unsuccessful_create_creator_token_poll_by_collaborator_without_linked_action_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ManageCreatorTokenPolls])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreateCreatorTokenPollFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .with_linked_action(0, CreatorTokenPollAction::DeactivateAmm)
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_create_creator_token_poll_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[
+                ChannelActionPermission::ManageCreatorTokenPolls,
+                ChannelActionPermission::AmmControl,
+            ])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreateCreatorTokenPollFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .with_linked_action(0, CreatorTokenPollAction::DeactivateAmm)
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_create_creator_token_poll_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreateCreatorTokenPollFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn unsuccessful_execute_creator_token_poll_action_with_non_existing_poll() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        ExecuteCreatorTokenPollActionFixture::default()
+            .call_and_assert(Err(project_token::Error::<Test>::PollDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn successful_execute_creator_token_poll_action_without_linked_action_removes_poll() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreateCreatorTokenPollFixture::default().call_and_assert(Ok(()));
+        vote_in_default_poll(0);
+        run_to_block(System::block_number() + DEFAULT_POLL_DURATION);
+
+        ExecuteCreatorTokenPollActionFixture::default().call_and_assert(Ok(None));
+    })
+}
+
+#[test]
+fn successful_execute_creator_token_poll_action_discards_action_after_owner_change() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        let rate_pre = default_token_patronage_rate();
+        CreateCreatorTokenPollFixture::default()
+            .with_linked_action(0, default_reduce_patronage_rate_action())
+            .call_and_assert(Ok(()));
+        vote_in_default_poll(0);
+        run_to_block(System::block_number() + DEFAULT_POLL_DURATION);
+        ChannelById::<Test>::mutate(ChannelId::one(), |channel| {
+            channel.owner = ChannelOwner::Member(SECOND_MEMBER_ID)
+        });
+
+        ExecuteCreatorTokenPollActionFixture::default().call_and_assert(Ok(Some(false)));
+
+        assert_eq!(default_token_patronage_rate(), rate_pre);
+    })
+}
+
+#[test]
+fn unsuccessful_execute_creator_token_poll_action_before_poll_end() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreateCreatorTokenPollFixture::default()
+            .with_linked_action(0, default_reduce_patronage_rate_action())
+            .call_and_assert(Ok(()));
+        ExecuteCreatorTokenPollActionFixture::default()
+            .call_and_assert(Err(project_token::Error::<Test>::PollNotEnded.into()));
+    })
+}
+
+#[test]
+fn successful_execute_creator_token_poll_action_with_linked_option_winning() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        let rate_pre = default_token_patronage_rate();
+        CreateCreatorTokenPollFixture::default()
+            .with_linked_action(0, default_reduce_patronage_rate_action())
+            .call_and_assert(Ok(()));
+        vote_in_default_poll(0);
+        run_to_block(System::block_number() + DEFAULT_POLL_DURATION);
+
+        ExecuteCreatorTokenPollActionFixture::default().call_and_assert(Ok(Some(true)));
+
+        assert_ne!(default_token_patronage_rate(), rate_pre);
+    })
+}
+
+#[test]
+fn successful_execute_creator_token_poll_action_with_linked_option_losing() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        let rate_pre = default_token_patronage_rate();
+        CreateCreatorTokenPollFixture::default()
+            .with_linked_action(0, default_reduce_patronage_rate_action())
+            .call_and_assert(Ok(()));
+        vote_in_default_poll(1);
+        run_to_block(System::block_number() + DEFAULT_POLL_DURATION);
+
+        ExecuteCreatorTokenPollActionFixture::default().call_and_assert(Ok(Some(false)));
+
+        assert_eq!(default_token_patronage_rate(), rate_pre);
+    })
+}
+
+#[test]
+fn successful_execute_creator_token_poll_action_without_votes_discards_action() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreateCreatorTokenPollFixture::default()
+            .with_linked_action(0, default_reduce_patronage_rate_action())
+            .call_and_assert(Ok(()));
+        run_to_block(System::block_number() + DEFAULT_POLL_DURATION);
+
+        ExecuteCreatorTokenPollActionFixture::default().call_and_assert(Ok(Some(false)));
+    })
+}
+
+#[test]
+fn successful_execute_creator_token_poll_action_issuing_revenue_split() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreateCreatorTokenPollFixture::default()
+            .with_linked_action(
+                0,
+                CreatorTokenPollAction::IssueRevenueSplit(DEFAULT_REVENUE_SPLIT_DURATION),
+            )
+            .call_and_assert(Ok(()));
+        vote_in_default_poll(0);
+        run_to_block(System::block_number() + DEFAULT_POLL_DURATION);
+        increase_account_balance_helper(
+            ContentTreasury::<Test>::account_for_channel(ChannelId::one()),
+            DEFAULT_PAYOUT_EARNED,
+        );
+
+        ExecuteCreatorTokenPollActionFixture::default().call_and_assert(Ok(Some(true)));
+
+        let token_id = Content::channel_by_id(ChannelId::one())
+            .creator_token_id
+            .unwrap();
+        assert!(matches!(
+            project_token::Module::<Test>::token_info_by_id(token_id).revenue_split,
+            RevenueSplitState::Active(..)
+        ));
+    })
+}
@@ -0,0 +1,156 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+use frame_system::RawOrigin;
+
+fn default_token_account_amount(member_id: MemberId) -> u64 {
+    let token_id = Content::channel_by_id(ChannelId::one())
+        .creator_token_id
+        .unwrap();
+    project_token::Module::<Test>::account_info_by_token_and_member(token_id, member_id).amount
+}
+
+#[test]
+fn unsuccessful_revoke_creator_token_vesting_schedule_non_existing_channel() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        RevokeCreatorTokenVestingScheduleFixture::default()
+            .call_and_assert(Err(Error::<Test>::ChannelDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_revoke_creator_token_vesting_schedule_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        RevokeCreatorTokenVestingScheduleFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_revoke_creator_token_vesting_schedule_not_revocable() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreatorTokenIssuerTransferFixture::default().call_and_assert(Ok(()));
+        RevokeCreatorTokenVestingScheduleFixture::default().call_and_assert(Err(
+            project_token::Error::<Test>::VestingScheduleNotRevocable.into(),
+        ));
+    })
+}
+
+#[test]
+fn unsuccessful_revoke_creator_token_vesting_schedule_by_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[
+                ChannelActionPermission::CreatorTokenIssuerTransfer,
+            ])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreatorTokenIssuerTransferFixture::default()
+            .with_revocable_vesting()
+            .call_and_assert(Ok(()));
+        RevokeCreatorTokenVestingScheduleFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_revoke_creator_token_vesting_schedule_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::CreatorTokenIssuerTransfer])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreatorTokenIssuerTransferFixture::default()
+            .with_revocable_vesting()
+            .call_and_assert(Ok(()));
+        RevokeCreatorTokenVestingScheduleFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_revoke_creator_token_vesting_schedule_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreatorTokenIssuerTransferFixture::default()
+            .with_revocable_vesting()
+            .call_and_assert(Ok(()));
+
+        RevokeCreatorTokenVestingScheduleFixture::default().call_and_assert(Ok(()));
+
+        assert_eq!(default_token_account_amount(SECOND_MEMBER_ID), 0);
+        assert_eq!(
+            default_token_account_amount(DEFAULT_MEMBER_ID),
+            DEFAULT_CREATOR_TOKEN_ISSUANCE
+        );
+    })
+}
+
+#[test]
+fn successful_revoke_creator_token_vesting_schedule_keeps_vested_amount_with_holder() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreatorTokenIssuerTransferFixture::default()
+            .with_revocable_vesting()
+            .call_and_assert(Ok(()));
+        run_to_block(
+            System::block_number()
+                + DEFAULT_VESTING_BLOCKS_BEFORE_CLIFF
+                + DEFAULT_VESTING_DURATION / 2,
+        );
+
+        RevokeCreatorTokenVestingScheduleFixture::default().call_and_assert(Ok(()));
+
+        assert_eq!(
+            default_token_account_amount(SECOND_MEMBER_ID),
+            DEFAULT_ISSUER_TRANSFER_AMOUNT / 2
+        );
+        assert_eq!(
+            default_token_account_amount(DEFAULT_MEMBER_ID),
+            DEFAULT_CREATOR_TOKEN_ISSUANCE - DEFAULT_ISSUER_TRANSFER_AMOUNT / 2
+        );
+    })
+}
+
+#[test]
+fn successful_revoke_creator_token_vesting_schedule_returns_unvested_amount_to_new_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        CreatorTokenIssuerTransferFixture::default()
+            .with_revocable_vesting()
+            .call_and_assert(Ok(()));
+        InitializeChannelTransferFixture::default()
+            .with_new_member_channel_owner(THIRD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        AcceptChannelTransferFixture::default()
+            .with_origin(RawOrigin::Signed(THIRD_MEMBER_ACCOUNT_ID))
+            .with_price(0)
+            .call_and_assert(Ok(()));
+
+        RevokeCreatorTokenVestingScheduleFixture::default()
+            .with_sender(THIRD_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(THIRD_MEMBER_ID))
+            .call_and_assert(Ok(()));
+
+        assert_eq!(default_token_account_amount(SECOND_MEMBER_ID), 0);
+        assert_eq!(
+            default_token_account_amount(THIRD_MEMBER_ID),
+            DEFAULT_ISSUER_TRANSFER_AMOUNT
+        );
+    })
+}
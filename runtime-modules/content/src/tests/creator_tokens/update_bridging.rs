@@ -0,0 +1,140 @@
+#![cfg(test)]
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+
+#[test]
+fn unsuccessful_update_creator_token_bridging_non_existing_channel() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        UpdateCreatorTokenBridgingFixture::default()
+            .call_and_assert(Err(Error::<Test>::ChannelDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_update_creator_token_bridging_token_not_issued() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        UpdateCreatorTokenBridgingFixture::default()
+            .call_and_assert(Err(Error::<Test>::CreatorTokenNotIssued.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_update_creator_token_bridging_during_transfer() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        InitializeChannelTransferFixture::default()
+            .with_new_member_channel_owner(THIRD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+
+        UpdateCreatorTokenBridgingFixture::default()
+            .call_and_assert(Err(Error::<Test>::InvalidChannelTransferStatus.into()));
+    })
+}
+
+// Member channel
+
+#[test]
+fn unsuccessful_update_member_channel_creator_token_bridging_by_collaborator_without_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_all_agent_permissions_except(&[
+                ChannelActionPermission::ManageCreatorTokenBridging,
+            ])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        UpdateCreatorTokenBridgingFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_update_member_channel_creator_token_bridging_by_collaborator() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel()
+            .with_agent_permissions(&[ChannelActionPermission::ManageCreatorTokenBridging])
+            .setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        UpdateCreatorTokenBridgingFixture::default()
+            .with_sender(COLLABORATOR_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(COLLABORATOR_MEMBER_ID))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_update_member_channel_creator_token_bridging_by_owner() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+        UpdateCreatorTokenBridgingFixture::default().call_and_assert(Ok(()));
+        UpdateCreatorTokenBridgingFixture::default()
+            .with_enabled(false)
+            .call_and_assert(Ok(()));
+    })
+}
+
+// Curator channel
+
+#[test]
+fn unsuccessful_update_curator_channel_creator_token_bridging_by_curator_without_permissions() {
+    with_default_mock_builder(|| {
+        ContentTest::with_curator_channel()
+            .with_all_agent_permissions_except(&[
+                ChannelActionPermission::ManageCreatorTokenBridging,
+            ])
+            .setup();
+        IssueCreatorTokenFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .with_initial_allocation_to(LEAD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        UpdateCreatorTokenBridgingFixture::default()
+            .with_sender(DEFAULT_CURATOR_ACCOUNT_ID)
+            .with_actor(default_curator_actor())
+            .call_and_assert(Err(
+                Error::<Test>::ChannelAgentInsufficientPermissions.into()
+            ));
+    })
+}
+
+#[test]
+fn successful_update_curator_channel_creator_token_bridging_by_lead() {
+    with_default_mock_builder(|| {
+        ContentTest::with_curator_channel().setup();
+        IssueCreatorTokenFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .with_initial_allocation_to(LEAD_MEMBER_ID)
+            .call_and_assert(Ok(()));
+        UpdateCreatorTokenBridgingFixture::default()
+            .with_sender(LEAD_ACCOUNT_ID)
+            .with_actor(ContentActor::Lead)
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn update_member_channel_creator_token_bridging_by_owner_fails_on_frozen_pallet() {
+    with_default_mock_builder(|| {
+        ContentTest::with_member_channel().setup();
+        IssueCreatorTokenFixture::default().call_and_assert(Ok(()));
+
+        assert_ok!(Token::set_frozen_status(RawOrigin::Root.into(), true));
+        UpdateCreatorTokenBridgingFixture::default()
+            .call_and_assert(Err(project_token::Error::<Test>::PalletFrozen.into()));
+
+        assert_ok!(Token::set_frozen_status(RawOrigin::Root.into(), false));
+        UpdateCreatorTokenBridgingFixture::default().call_and_assert(Ok(()));
+    })
+}
@@ -19,7 +19,10 @@ use frame_support::{
 };
 use frame_system::RawOrigin;
 use project_token::types::TransferPolicyParamsOf;
-use project_token::types::{TokenAllocationOf, TokenIssuanceParametersOf};
+use project_token::types::{
+    AirdropParamsOf, PaymentWithVesting, PollId, PollParamsOf, PollVoteWeighting,
+    TokenAllocationOf, TokenIssuanceParametersOf, VestingScheduleParamsOf,
+};
 use sp_core::U256;
 use sp_runtime::Permill;
 use sp_std::collections::btree_map::BTreeMap;
@@ -2335,6 +2338,62 @@ impl InitCreatorTokenSaleFixture {
     }
 }
 
+pub struct InitCreatorTokenBatchAuctionFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+    params: TokenSaleParamsOf<Test>,
+}
+
+impl InitCreatorTokenBatchAuctionFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+            params: TokenSaleParamsOf::<Test> {
+                unit_price: DEFAULT_CREATOR_TOKEN_SALE_UNIT_PRICE,
+                upper_bound_quantity: DEFAULT_CREATOR_TOKEN_ISSUANCE,
+                starts_at: None,
+                duration: DEFAULT_CREATOR_TOKEN_SALE_DURATION,
+                vesting_schedule_params: None,
+                cap_per_member: None,
+                metadata: None,
+            },
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = RuntimeOrigin::signed(self.sender);
+        let token_id = Content::channel_by_id(self.channel_id).creator_token_id;
+
+        let actual_result = Content::init_creator_token_batch_auction(
+            origin,
+            self.actor,
+            self.channel_id,
+            self.params.clone(),
+        );
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+            let token_id = token_id.unwrap();
+            let token = project_token::Module::<Test>::token_info_by_id(token_id);
+            assert!(!token.sale.unwrap().auto_finalize);
+            assert!(project_token::BatchAuctions::<Test>::contains_key(token_id));
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
 pub struct UpdateUpcomingCreatorTokenSaleFixture {
     sender: AccountId,
     actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
@@ -2416,6 +2475,31 @@ impl CreatorTokenIssuerTransferFixture {
         Self { actor, ..self }
     }
 
+    pub fn with_revocable_vesting(self) -> Self {
+        let outputs = TransferWithVestingOutputsOf::<Test>::try_from(
+            self.outputs
+                .iter()
+                .map(|(member, payment)| {
+                    (
+                        *member,
+                        PaymentWithVesting {
+                            amount: payment.amount,
+                            vesting_schedule: Some(VestingScheduleParamsOf::<Test> {
+                                linear_vesting_duration: DEFAULT_VESTING_DURATION,
+                                blocks_before_cliff: DEFAULT_VESTING_BLOCKS_BEFORE_CLIFF,
+                                cliff_amount_percentage: Permill::zero(),
+                            }),
+                            revocable: true,
+                        },
+                    )
+                })
+                .collect::<Vec<_>>(),
+        )
+        .ok()
+        .unwrap();
+        Self { outputs, ..self }
+    }
+
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let origin = RuntimeOrigin::signed(self.sender);
 
@@ -2435,24 +2519,24 @@ impl CreatorTokenIssuerTransferFixture {
     }
 }
 
-pub struct ReduceCreatorTokenPatronageRateFixture {
+pub struct CreatorTokenIssuerCreateAirdropFixture {
     sender: AccountId,
     actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
     channel_id: ChannelId,
-    target_rate: YearlyRate,
+    params: AirdropParamsOf<Test>,
 }
 
-impl ReduceCreatorTokenPatronageRateFixture {
+impl CreatorTokenIssuerCreateAirdropFixture {
     pub fn default() -> Self {
         Self {
             sender: DEFAULT_MEMBER_ACCOUNT_ID,
             actor: ContentActor::Member(DEFAULT_MEMBER_ID),
             channel_id: ChannelId::one(),
-            target_rate: YearlyRate(
-                DEFAULT_PATRONAGE_RATE
-                    .0
-                    .saturating_sub(Permill::from_perthousand(5)),
-            ),
+            params: AirdropParamsOf::<Test> {
+                commitment: Hashing::hash_of(b"commitment"),
+                amount: DEFAULT_AIRDROP_AMOUNT,
+                duration: DEFAULT_AIRDROP_DURATION,
+            },
         }
     }
 
@@ -2464,36 +2548,55 @@ impl ReduceCreatorTokenPatronageRateFixture {
         Self { actor, ..self }
     }
 
+    pub fn with_amount(self, amount: u64) -> Self {
+        Self {
+            params: AirdropParamsOf::<Test> {
+                amount,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let origin = RuntimeOrigin::signed(self.sender);
+        let airdrop_id = project_token::Module::<Test>::next_airdrop_id();
 
-        let actual_result = Content::reduce_creator_token_patronage_rate_to(
+        let actual_result = Content::creator_token_issuer_create_airdrop(
             origin,
             self.actor,
             self.channel_id,
-            self.target_rate,
+            self.params.clone(),
         );
 
         if expected_result.is_ok() {
             assert_ok!(actual_result);
+            assert_eq!(
+                project_token::Module::<Test>::next_airdrop_id(),
+                airdrop_id + 1
+            );
         } else {
             assert_noop!(actual_result, expected_result.err().unwrap());
         }
     }
 }
 
-pub struct ClaimCreatorTokenPatronageCreditFixture {
+pub struct RevokeCreatorTokenVestingScheduleFixture {
     sender: AccountId,
     actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
     channel_id: ChannelId,
+    member_id: MemberId,
+    transfer_id: u64,
 }
 
-impl ClaimCreatorTokenPatronageCreditFixture {
+impl RevokeCreatorTokenVestingScheduleFixture {
     pub fn default() -> Self {
         Self {
             sender: DEFAULT_MEMBER_ACCOUNT_ID,
             actor: ContentActor::Member(DEFAULT_MEMBER_ID),
             channel_id: ChannelId::one(),
+            member_id: SECOND_MEMBER_ID,
+            transfer_id: 0,
         }
     }
 
@@ -2508,29 +2611,52 @@ impl ClaimCreatorTokenPatronageCreditFixture {
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let origin = RuntimeOrigin::signed(self.sender);
 
-        let actual_result =
-            Content::claim_creator_token_patronage_credit(origin, self.actor, self.channel_id);
+        let actual_result = Content::revoke_creator_token_vesting_schedule(
+            origin,
+            self.actor,
+            self.channel_id,
+            self.member_id,
+            self.transfer_id,
+        );
 
         if expected_result.is_ok() {
             assert_ok!(actual_result);
+            let token_id = Content::channel_by_id(self.channel_id)
+                .creator_token_id
+                .unwrap();
+            assert!(
+                !project_token::RevocableVestingSchedules::<Test>::contains_key(
+                    (token_id, self.member_id),
+                    self.transfer_id
+                )
+            );
         } else {
             assert_noop!(actual_result, expected_result.err().unwrap());
         }
     }
 }
 
-pub struct MakeCreatorTokenPermissionlessFixture {
+pub struct CreateCreatorTokenPollFixture {
     sender: AccountId,
     actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
     channel_id: ChannelId,
+    params: PollParamsOf<Test>,
+    linked_action: Option<(u32, CreatorTokenPollActionOf<Test>)>,
 }
 
-impl MakeCreatorTokenPermissionlessFixture {
+impl CreateCreatorTokenPollFixture {
     pub fn default() -> Self {
         Self {
             sender: DEFAULT_MEMBER_ACCOUNT_ID,
             actor: ContentActor::Member(DEFAULT_MEMBER_ID),
             channel_id: ChannelId::one(),
+            params: PollParamsOf::<Test> {
+                options_number: 2,
+                duration: DEFAULT_POLL_DURATION,
+                weighting: PollVoteWeighting::Balance,
+                metadata: b"poll".to_vec(),
+            },
+            linked_action: None,
         }
     }
 
@@ -2542,36 +2668,126 @@ impl MakeCreatorTokenPermissionlessFixture {
         Self { actor, ..self }
     }
 
+    pub fn with_linked_action(
+        self,
+        option_index: u32,
+        action: CreatorTokenPollActionOf<Test>,
+    ) -> Self {
+        Self {
+            linked_action: Some((option_index, action)),
+            ..self
+        }
+    }
+
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let origin = RuntimeOrigin::signed(self.sender);
+        let poll_id = project_token::Module::<Test>::next_poll_id();
 
-        let actual_result =
-            Content::make_creator_token_permissionless(origin, self.actor, self.channel_id);
+        let actual_result = Content::create_creator_token_poll(
+            origin,
+            self.actor,
+            self.channel_id,
+            self.params.clone(),
+            self.linked_action.clone(),
+        );
 
         if expected_result.is_ok() {
             assert_ok!(actual_result);
+            assert_eq!(project_token::Module::<Test>::next_poll_id(), poll_id + 1);
+            assert_eq!(
+                CreatorTokenPollActions::<Test>::contains_key(self.channel_id, poll_id),
+                self.linked_action.is_some()
+            );
+            let token_id = Content::channel_by_id(self.channel_id)
+                .creator_token_id
+                .unwrap();
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::Content(RawEvent::CreatorTokenPollCreated(
+                    self.actor,
+                    self.channel_id,
+                    token_id,
+                    poll_id
+                ))
+            );
         } else {
             assert_noop!(actual_result, expected_result.err().unwrap());
         }
     }
 }
 
-pub struct IssueRevenueSplitFixture {
+pub struct ExecuteCreatorTokenPollActionFixture {
+    sender: AccountId,
+    channel_id: ChannelId,
+    poll_id: PollId,
+}
+
+impl ExecuteCreatorTokenPollActionFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: UNAUTHORIZED_MEMBER_ACCOUNT_ID,
+            channel_id: ChannelId::one(),
+            poll_id: PollId::zero(),
+        }
+    }
+
+    pub fn with_poll_id(self, poll_id: PollId) -> Self {
+        Self { poll_id, ..self }
+    }
+
+    // `Ok(None)` is expected when no action is linked to the poll
+    pub fn call_and_assert(&self, expected_result: Result<Option<bool>, DispatchError>) {
+        let origin = RuntimeOrigin::signed(self.sender);
+        let token_id = Content::channel_by_id(self.channel_id).creator_token_id;
+
+        let actual_result =
+            Content::execute_creator_token_poll_action(origin, self.channel_id, self.poll_id);
+
+        match expected_result {
+            Ok(executed) => {
+                assert_ok!(actual_result);
+                assert!(!CreatorTokenPollActions::<Test>::contains_key(
+                    self.channel_id,
+                    self.poll_id
+                ));
+                assert!(!project_token::Polls::<Test>::contains_key(
+                    token_id.unwrap(),
+                    self.poll_id
+                ));
+                if let Some(executed) = executed {
+                    assert_eq!(
+                        System::events().last().unwrap().event,
+                        MetaEvent::Content(RawEvent::CreatorTokenPollActionResolved(
+                            self.channel_id,
+                            self.poll_id,
+                            executed
+                        ))
+                    );
+                }
+            }
+            Err(err) => assert_noop!(actual_result, err),
+        }
+    }
+}
+
+pub struct ReduceCreatorTokenPatronageRateFixture {
     sender: AccountId,
     actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
     channel_id: ChannelId,
-    start: Option<u64>,
-    duration: u64,
+    target_rate: YearlyRate,
 }
 
-impl IssueRevenueSplitFixture {
+impl ReduceCreatorTokenPatronageRateFixture {
     pub fn default() -> Self {
         Self {
             sender: DEFAULT_MEMBER_ACCOUNT_ID,
             actor: ContentActor::Member(DEFAULT_MEMBER_ID),
             channel_id: ChannelId::one(),
-            start: None,
-            duration: DEFAULT_REVENUE_SPLIT_DURATION,
+            target_rate: YearlyRate(
+                DEFAULT_PATRONAGE_RATE
+                    .0
+                    .saturating_sub(Permill::from_perthousand(5)),
+            ),
         }
     }
 
@@ -2583,22 +2799,14 @@ impl IssueRevenueSplitFixture {
         Self { actor, ..self }
     }
 
-    pub fn with_starting_block(self, block: u64) -> Self {
-        Self {
-            start: Some(block),
-            ..self
-        }
-    }
-
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let origin = RuntimeOrigin::signed(self.sender);
 
-        let actual_result = Content::issue_revenue_split(
+        let actual_result = Content::reduce_creator_token_patronage_rate_to(
             origin,
             self.actor,
             self.channel_id,
-            self.start,
-            self.duration,
+            self.target_rate,
         );
 
         if expected_result.is_ok() {
@@ -2609,13 +2817,13 @@ impl IssueRevenueSplitFixture {
     }
 }
 
-pub struct FinalizeRevenueSplitFixture {
+pub struct ClaimCreatorTokenPatronageCreditFixture {
     sender: AccountId,
     actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
     channel_id: ChannelId,
 }
 
-impl FinalizeRevenueSplitFixture {
+impl ClaimCreatorTokenPatronageCreditFixture {
     pub fn default() -> Self {
         Self {
             sender: DEFAULT_MEMBER_ACCOUNT_ID,
@@ -2635,7 +2843,8 @@ impl FinalizeRevenueSplitFixture {
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let origin = RuntimeOrigin::signed(self.sender);
 
-        let actual_result = Content::finalize_revenue_split(origin, self.actor, self.channel_id);
+        let actual_result =
+            Content::claim_creator_token_patronage_credit(origin, self.actor, self.channel_id);
 
         if expected_result.is_ok() {
             assert_ok!(actual_result);
@@ -2645,23 +2854,18 @@ impl FinalizeRevenueSplitFixture {
     }
 }
 
-pub struct ActivateAmmFixture {
+pub struct MakeCreatorTokenPermissionlessFixture {
     sender: AccountId,
     actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
     channel_id: ChannelId,
-    params: AmmParamsOf<Test>,
 }
 
-impl ActivateAmmFixture {
+impl MakeCreatorTokenPermissionlessFixture {
     pub fn default() -> Self {
         Self {
             sender: DEFAULT_MEMBER_ACCOUNT_ID,
             actor: ContentActor::Member(DEFAULT_MEMBER_ID),
             channel_id: ChannelId::one(),
-            params: AmmParamsOf::<Test> {
-                slope: 10_000_000u32.into(),
-                intercept: Zero::zero(),
-            },
         }
     }
 
@@ -2677,7 +2881,7 @@ impl ActivateAmmFixture {
         let origin = RuntimeOrigin::signed(self.sender);
 
         let actual_result =
-            Content::activate_amm(origin, self.actor, self.channel_id, self.params.clone());
+            Content::make_creator_token_permissionless(origin, self.actor, self.channel_id);
 
         if expected_result.is_ok() {
             assert_ok!(actual_result);
@@ -2686,18 +2890,21 @@ impl ActivateAmmFixture {
         }
     }
 }
-pub struct DeactivateAmmFixture {
+
+pub struct UpdateCreatorTokenBridgingFixture {
     sender: AccountId,
     actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
     channel_id: ChannelId,
+    enabled: bool,
 }
 
-impl DeactivateAmmFixture {
+impl UpdateCreatorTokenBridgingFixture {
     pub fn default() -> Self {
         Self {
             sender: DEFAULT_MEMBER_ACCOUNT_ID,
             actor: ContentActor::Member(DEFAULT_MEMBER_ID),
             channel_id: ChannelId::one(),
+            enabled: true,
         }
     }
 
@@ -2709,31 +2916,50 @@ impl DeactivateAmmFixture {
         Self { actor, ..self }
     }
 
+    pub fn with_enabled(self, enabled: bool) -> Self {
+        Self { enabled, ..self }
+    }
+
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let origin = RuntimeOrigin::signed(self.sender);
+        let token_id = Content::channel_by_id(self.channel_id).creator_token_id;
 
-        let actual_result = Content::deactivate_amm(origin, self.actor, self.channel_id);
+        let actual_result = Content::update_creator_token_bridging(
+            origin,
+            self.actor,
+            self.channel_id,
+            self.enabled,
+        );
 
         if expected_result.is_ok() {
             assert_ok!(actual_result);
+            let token_id = token_id.unwrap();
+            assert_eq!(
+                project_token::Pallet::<Test>::token_bridging(token_id).enabled,
+                self.enabled
+            );
         } else {
             assert_noop!(actual_result, expected_result.err().unwrap());
         }
     }
 }
 
-pub struct FinalizeCreatorTokenSaleFixture {
+pub struct IssueRevenueSplitFixture {
     sender: AccountId,
     actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
     channel_id: ChannelId,
+    start: Option<u64>,
+    duration: u64,
 }
 
-impl FinalizeCreatorTokenSaleFixture {
+impl IssueRevenueSplitFixture {
     pub fn default() -> Self {
         Self {
             sender: DEFAULT_MEMBER_ACCOUNT_ID,
             actor: ContentActor::Member(DEFAULT_MEMBER_ID),
             channel_id: ChannelId::one(),
+            start: None,
+            duration: DEFAULT_REVENUE_SPLIT_DURATION,
         }
     }
 
@@ -2745,48 +2971,44 @@ impl FinalizeCreatorTokenSaleFixture {
         Self { actor, ..self }
     }
 
+    pub fn with_starting_block(self, block: u64) -> Self {
+        Self {
+            start: Some(block),
+            ..self
+        }
+    }
+
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let origin = RuntimeOrigin::signed(self.sender);
 
-        let council_budget_pre = <Test as Config>::CouncilBudgetManager::get_budget();
-        let channel = Content::channel_by_id(self.channel_id);
-        let joy_collected = channel.creator_token_id.map_or(0, |t_id| {
-            project_token::Module::<Test>::token_info_by_id(t_id)
-                .sale
-                .map_or(0, |s| s.funds_collected)
-        });
-
-        let actual_result =
-            Content::finalize_creator_token_sale(origin, self.actor, self.channel_id);
-
-        let council_budget_post = <Test as Config>::CouncilBudgetManager::get_budget();
+        let actual_result = Content::issue_revenue_split(
+            origin,
+            self.actor,
+            self.channel_id,
+            self.start,
+            self.duration,
+        );
 
         if expected_result.is_ok() {
             assert_ok!(actual_result);
-            if let ChannelOwner::CuratorGroup(_) = channel.owner {
-                assert_eq!(
-                    council_budget_post,
-                    council_budget_pre.saturating_add(joy_collected)
-                );
-            } else {
-                assert_eq!(council_budget_post, council_budget_pre);
-            }
         } else {
             assert_noop!(actual_result, expected_result.err().unwrap());
         }
     }
 }
 
-pub struct UpdateChannelStateBloatBondFixture {
+pub struct DepositCreatorTokenStakingRevenueFixture {
     sender: AccountId,
-    new_channel_state_bloat_bond: BalanceOf<Test>,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
 }
 
-impl UpdateChannelStateBloatBondFixture {
+impl DepositCreatorTokenStakingRevenueFixture {
     pub fn default() -> Self {
         Self {
-            sender: LEAD_ACCOUNT_ID,
-            new_channel_state_bloat_bond: DEFAULT_CHANNEL_STATE_BLOAT_BOND,
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
         }
     }
 
@@ -2794,13 +3016,216 @@ impl UpdateChannelStateBloatBondFixture {
         Self { sender, ..self }
     }
 
-    pub fn with_channel_state_bloat_bond(
-        self,
-        new_channel_state_bloat_bond: BalanceOf<Test>,
-    ) -> Self {
-        Self {
-            new_channel_state_bloat_bond,
-            ..self
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = RuntimeOrigin::signed(self.sender);
+
+        let actual_result =
+            Content::deposit_creator_token_staking_revenue(origin, self.actor, self.channel_id);
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
+pub struct FinalizeRevenueSplitFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+}
+
+impl FinalizeRevenueSplitFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = RuntimeOrigin::signed(self.sender);
+
+        let actual_result = Content::finalize_revenue_split(origin, self.actor, self.channel_id);
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
+pub struct ActivateAmmFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+    params: AmmParamsOf<Test>,
+}
+
+impl ActivateAmmFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+            params: AmmParamsOf::<Test>::Linear {
+                slope: 10_000_000u32.into(),
+                intercept: Zero::zero(),
+            },
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = RuntimeOrigin::signed(self.sender);
+
+        let actual_result =
+            Content::activate_amm(origin, self.actor, self.channel_id, self.params.clone());
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+pub struct DeactivateAmmFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+}
+
+impl DeactivateAmmFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = RuntimeOrigin::signed(self.sender);
+
+        let actual_result = Content::deactivate_amm(origin, self.actor, self.channel_id);
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
+pub struct FinalizeCreatorTokenSaleFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    channel_id: ChannelId,
+}
+
+impl FinalizeCreatorTokenSaleFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            actor: ContentActor::Member(DEFAULT_MEMBER_ID),
+            channel_id: ChannelId::one(),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let origin = RuntimeOrigin::signed(self.sender);
+
+        let council_budget_pre = <Test as Config>::CouncilBudgetManager::get_budget();
+        let channel = Content::channel_by_id(self.channel_id);
+        let joy_collected = channel.creator_token_id.map_or(0, |t_id| {
+            project_token::Module::<Test>::token_info_by_id(t_id)
+                .sale
+                .map_or(0, |s| s.funds_collected)
+        });
+
+        let actual_result =
+            Content::finalize_creator_token_sale(origin, self.actor, self.channel_id);
+
+        let council_budget_post = <Test as Config>::CouncilBudgetManager::get_budget();
+
+        if expected_result.is_ok() {
+            assert_ok!(actual_result);
+            if let ChannelOwner::CuratorGroup(_) = channel.owner {
+                assert_eq!(
+                    council_budget_post,
+                    council_budget_pre.saturating_add(joy_collected)
+                );
+            } else {
+                assert_eq!(council_budget_post, council_budget_pre);
+            }
+        } else {
+            assert_noop!(actual_result, expected_result.err().unwrap());
+        }
+    }
+}
+
+pub struct UpdateChannelStateBloatBondFixture {
+    sender: AccountId,
+    new_channel_state_bloat_bond: BalanceOf<Test>,
+}
+
+impl UpdateChannelStateBloatBondFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: LEAD_ACCOUNT_ID,
+            new_channel_state_bloat_bond: DEFAULT_CHANNEL_STATE_BLOAT_BOND,
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_channel_state_bloat_bond(
+        self,
+        new_channel_state_bloat_bond: BalanceOf<Test>,
+    ) -> Self {
+        Self {
+            new_channel_state_bloat_bond,
+            ..self
         }
     }
 
@@ -5588,3 +6013,446 @@ pub fn set_staking_candidate_lock(
 ) {
     <Test as membership::Config>::StakingCandidateStakingHandler::lock(who, amount);
 }
+
+pub struct FileTakedownRequestFixture {
+    sender: AccountId,
+    member_id: MemberId,
+    target: TakedownTargetOf<Test>,
+    rationale: Vec<u8>,
+}
+
+impl FileTakedownRequestFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: SECOND_MEMBER_ACCOUNT_ID,
+            member_id: SECOND_MEMBER_ID,
+            target: TakedownTarget::Video(VideoId::one()),
+            rationale: b"rationale".to_vec(),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: MemberId) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_target(self, target: TakedownTargetOf<Test>) -> Self {
+        Self { target, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let balance_pre = Balances::<Test>::usable_balance(self.sender);
+        let treasury_balance_pre = ContentTreasury::<Test>::usable_balance();
+        let takedown_request_id = Content::next_takedown_request_id();
+
+        let actual_result = Content::file_takedown_request(
+            RuntimeOrigin::signed(self.sender),
+            self.member_id,
+            self.target.clone(),
+            self.rationale.clone(),
+        );
+
+        let balance_post = Balances::<Test>::usable_balance(self.sender);
+        let treasury_balance_post = ContentTreasury::<Test>::usable_balance();
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            let bond = <Test as Config>::TakedownRequestBond::get();
+            let takedown_request = Content::takedown_request_by_id(takedown_request_id);
+
+            assert_eq!(balance_pre.saturating_sub(balance_post), bond);
+            assert_eq!(
+                treasury_balance_post.saturating_sub(treasury_balance_pre),
+                bond
+            );
+            assert_eq!(takedown_request.requester, self.member_id);
+            assert_eq!(takedown_request.target, self.target);
+            assert_eq!(
+                takedown_request.status,
+                TakedownRequestStatus::Pending(
+                    System::block_number() + <Test as Config>::TakedownRequestDecisionPeriod::get()
+                )
+            );
+            assert_eq!(Content::next_takedown_request_id(), takedown_request_id + 1);
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::Content(RawEvent::TakedownRequestFiled(
+                    takedown_request_id,
+                    takedown_request,
+                    self.rationale.clone(),
+                ))
+            );
+        } else {
+            assert_eq!(balance_pre, balance_post);
+            assert!(!TakedownRequestById::<Test>::contains_key(
+                takedown_request_id
+            ));
+        }
+    }
+}
+
+pub struct AcceptTakedownRequestFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    takedown_request_id: TakedownRequestId,
+    storage_buckets_num_witness: u32,
+    rationale: Vec<u8>,
+}
+
+impl AcceptTakedownRequestFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: LEAD_ACCOUNT_ID,
+            actor: ContentActor::Lead,
+            takedown_request_id: 0,
+            storage_buckets_num_witness: storage_buckets_num_witness(ChannelId::one()),
+            rationale: b"rationale".to_vec(),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn with_takedown_request_id(self, takedown_request_id: TakedownRequestId) -> Self {
+        Self {
+            takedown_request_id,
+            ..self
+        }
+    }
+
+    pub fn with_storage_buckets_num_witness(self, storage_buckets_num_witness: u32) -> Self {
+        Self {
+            storage_buckets_num_witness,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let request_pre = Content::takedown_request_by_id(self.takedown_request_id);
+        let requester_balance_pre = Balances::<Test>::usable_balance(request_pre.bond_account);
+        let bag_id = Content::bag_id_for_channel(&request_pre.channel_id);
+        let not_blacklisted_content_ids_pre = request_pre
+            .content_ids
+            .iter()
+            .filter(|cid| !storage::Blacklist::contains_key(cid))
+            .cloned()
+            .collect::<BTreeSet<_>>();
+
+        let actual_result = Content::accept_takedown_request(
+            RuntimeOrigin::signed(self.sender),
+            self.actor,
+            self.takedown_request_id,
+            self.storage_buckets_num_witness,
+            self.rationale.clone(),
+        );
+
+        let request_post = Content::takedown_request_by_id(self.takedown_request_id);
+        let requester_balance_post = Balances::<Test>::usable_balance(request_post.bond_account);
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                request_post.status,
+                TakedownRequestStatus::Accepted(
+                    System::block_number() + <Test as Config>::TakedownAppealPeriod::get()
+                )
+            );
+            assert_eq!(
+                requester_balance_post.saturating_sub(requester_balance_pre),
+                request_pre.bond
+            );
+            assert!(request_post
+                .content_ids
+                .iter()
+                .all(|cid| storage::Blacklist::contains_key(cid)));
+            assert_eq!(
+                request_post.blacklisted_content_ids.clone().into_inner(),
+                not_blacklisted_content_ids_pre
+            );
+
+            let removed_assets = match System::events().last().unwrap().event.clone() {
+                MetaEvent::Content(RawEvent::TakedownRequestAccepted(
+                    actor,
+                    takedown_request_id,
+                    removed_assets,
+                    rationale,
+                )) => {
+                    assert_eq!(actor, self.actor);
+                    assert_eq!(takedown_request_id, self.takedown_request_id);
+                    assert_eq!(rationale, self.rationale);
+                    removed_assets
+                }
+                _ => panic!("TakedownRequestAccepted event expected"),
+            };
+            assert!(!removed_assets
+                .iter()
+                .any(|obj_id| storage::DataObjectsById::<Test>::contains_key(&bag_id, obj_id)));
+        } else {
+            assert_eq!(request_pre, request_post);
+            assert_eq!(requester_balance_pre, requester_balance_post);
+        }
+    }
+}
+
+pub struct RejectTakedownRequestFixture {
+    sender: AccountId,
+    actor: ContentActor<CuratorGroupId, CuratorId, MemberId>,
+    takedown_request_id: TakedownRequestId,
+    rationale: Vec<u8>,
+}
+
+impl RejectTakedownRequestFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: LEAD_ACCOUNT_ID,
+            actor: ContentActor::Lead,
+            takedown_request_id: 0,
+            rationale: b"rationale".to_vec(),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_actor(self, actor: ContentActor<CuratorGroupId, CuratorId, MemberId>) -> Self {
+        Self { actor, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let request_pre = Content::takedown_request_by_id(self.takedown_request_id);
+        let treasury_balance_pre = ContentTreasury::<Test>::usable_balance();
+        let requester_balance_pre = Balances::<Test>::usable_balance(request_pre.bond_account);
+
+        let actual_result = Content::reject_takedown_request(
+            RuntimeOrigin::signed(self.sender),
+            self.actor,
+            self.takedown_request_id,
+            self.rationale.clone(),
+        );
+
+        let request_post = Content::takedown_request_by_id(self.takedown_request_id);
+        let treasury_balance_post = ContentTreasury::<Test>::usable_balance();
+        let requester_balance_post = Balances::<Test>::usable_balance(request_post.bond_account);
+
+        assert_eq!(actual_result, expected_result);
+        assert_eq!(requester_balance_pre, requester_balance_post);
+
+        if actual_result.is_ok() {
+            assert_eq!(request_post.status, TakedownRequestStatus::Rejected);
+            assert_eq!(
+                treasury_balance_pre.saturating_sub(treasury_balance_post),
+                request_pre.bond
+            );
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::Content(RawEvent::TakedownRequestRejected(
+                    self.actor,
+                    self.takedown_request_id,
+                    self.rationale.clone(),
+                ))
+            );
+        } else {
+            assert_eq!(request_pre, request_post);
+            assert_eq!(treasury_balance_pre, treasury_balance_post);
+        }
+    }
+}
+
+pub struct ExpireTakedownRequestFixture {
+    sender: AccountId,
+    takedown_request_id: TakedownRequestId,
+}
+
+impl ExpireTakedownRequestFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            takedown_request_id: 0,
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let request_pre = Content::takedown_request_by_id(self.takedown_request_id);
+        let requester_balance_pre = Balances::<Test>::usable_balance(request_pre.bond_account);
+
+        let actual_result = Content::expire_takedown_request(
+            RuntimeOrigin::signed(self.sender),
+            self.takedown_request_id,
+        );
+
+        let request_post = Content::takedown_request_by_id(self.takedown_request_id);
+        let requester_balance_post = Balances::<Test>::usable_balance(request_post.bond_account);
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(request_post.status, TakedownRequestStatus::Expired);
+            assert_eq!(
+                requester_balance_post.saturating_sub(requester_balance_pre),
+                request_pre.bond
+            );
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::Content(RawEvent::TakedownRequestExpired(self.takedown_request_id))
+            );
+        } else {
+            assert_eq!(request_pre, request_post);
+            assert_eq!(requester_balance_pre, requester_balance_post);
+        }
+    }
+}
+
+pub struct AppealTakedownFixture {
+    sender: AccountId,
+    takedown_request_id: TakedownRequestId,
+    rationale: Vec<u8>,
+}
+
+impl AppealTakedownFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            takedown_request_id: 0,
+            rationale: b"rationale".to_vec(),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let request_pre = Content::takedown_request_by_id(self.takedown_request_id);
+
+        let actual_result = Content::appeal_takedown(
+            RuntimeOrigin::signed(self.sender),
+            self.takedown_request_id,
+            self.rationale.clone(),
+        );
+
+        let request_post = Content::takedown_request_by_id(self.takedown_request_id);
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                request_post.status,
+                TakedownRequestStatus::Appealed(
+                    System::block_number() + <Test as Config>::TakedownAppealDecisionPeriod::get()
+                )
+            );
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::Content(RawEvent::TakedownAppealed(
+                    self.takedown_request_id,
+                    self.rationale.clone(),
+                ))
+            );
+        } else {
+            assert_eq!(request_pre, request_post);
+        }
+    }
+}
+
+pub struct OverturnTakedownFixture {
+    origin: RawOrigin<AccountId>,
+    takedown_request_id: TakedownRequestId,
+}
+
+impl OverturnTakedownFixture {
+    pub fn default() -> Self {
+        Self {
+            origin: RawOrigin::Root,
+            takedown_request_id: 0,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<AccountId>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let request_pre = Content::takedown_request_by_id(self.takedown_request_id);
+
+        let actual_result =
+            Content::overturn_takedown(self.origin.clone().into(), self.takedown_request_id);
+
+        let request_post = Content::takedown_request_by_id(self.takedown_request_id);
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(request_post.status, TakedownRequestStatus::Overturned);
+            assert!(!request_post
+                .blacklisted_content_ids
+                .iter()
+                .any(|cid| storage::Blacklist::contains_key(cid)));
+            assert!(request_post
+                .content_ids
+                .iter()
+                .filter(|cid| !request_post.blacklisted_content_ids.contains(cid))
+                .all(|cid| storage::Blacklist::contains_key(cid)));
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::Content(RawEvent::TakedownOverturned(self.takedown_request_id))
+            );
+        } else {
+            assert_eq!(request_pre, request_post);
+        }
+    }
+}
+
+pub struct ExpireTakedownAppealFixture {
+    sender: AccountId,
+    takedown_request_id: TakedownRequestId,
+}
+
+impl ExpireTakedownAppealFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: DEFAULT_MEMBER_ACCOUNT_ID,
+            takedown_request_id: 0,
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let request_pre = Content::takedown_request_by_id(self.takedown_request_id);
+
+        let actual_result = Content::expire_takedown_appeal(
+            RuntimeOrigin::signed(self.sender),
+            self.takedown_request_id,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert!(!TakedownRequestById::<Test>::contains_key(
+                self.takedown_request_id
+            ));
+            assert!(request_pre
+                .blacklisted_content_ids
+                .iter()
+                .all(|cid| storage::Blacklist::contains_key(cid)));
+            assert_eq!(
+                System::events().last().unwrap().event,
+                MetaEvent::Content(RawEvent::TakedownAppealExpired(self.takedown_request_id))
+            );
+        } else {
+            assert_eq!(
+                request_pre,
+                Content::takedown_request_by_id(self.takedown_request_id)
+            );
+        }
+    }
+}
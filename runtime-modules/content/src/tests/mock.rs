@@ -133,6 +133,11 @@ pub const DEFAULT_CREATOR_TOKEN_ISSUANCE: u64 = 1_000_000_000;
 pub const DEFAULT_CREATOR_TOKEN_SALE_UNIT_PRICE: u64 = 10;
 pub const DEFAULT_CREATOR_TOKEN_SALE_DURATION: u64 = 100;
 pub const DEFAULT_ISSUER_TRANSFER_AMOUNT: u64 = 1_000_000;
+pub const DEFAULT_AIRDROP_AMOUNT: u64 = 1_000_000;
+pub const DEFAULT_AIRDROP_DURATION: u64 = 100;
+pub const DEFAULT_POLL_DURATION: u64 = 100;
+pub const DEFAULT_VESTING_DURATION: u64 = 100;
+pub const DEFAULT_VESTING_BLOCKS_BEFORE_CLIFF: u64 = 100;
 pub const DEFAULT_PATRONAGE_RATE: YearlyRate = YearlyRate(Permill::from_percent(10));
 pub const DEFAULT_REVENUE_SPLIT_DURATION: u64 = 1000;
 pub const DEFAULT_SPLIT_RATE: Permill = Permill::from_percent(10);
@@ -292,16 +297,21 @@ parameter_types! {
     pub const MaxNumberOfDataObjectsPerBag: u64 = 4;
     pub const MaxDistributionBucketFamilyNumber: u64 = 20;
     pub const StorageModuleId: PalletId = PalletId(*b"mstorage"); // module storage
-    pub const BlacklistSizeLimit: u64 = 1;
+    pub const BlacklistSizeLimit: u64 = 100;
     pub const MaxNumberOfPendingInvitationsPerDistributionBucket: u32 = 1;
     pub const MinStorageBucketsPerBag: u32 = 1;
     pub const MaxStorageBucketsPerBag: u32 = 20;
+    pub const MaxStorageBucketsScannedOnAssignment: u32 = 100;
     pub const MinDistributionBucketsPerBag: u32 = 1;
     pub const MaxDistributionBucketsPerBag: u32 = 20;
     pub const DefaultMemberDynamicBagNumberOfStorageBuckets: u32 = 3;
     pub const DefaultChannelDynamicBagNumberOfStorageBuckets: u32 = 4;
     pub const MaxDataObjectSize: u64 = VOUCHER_OBJECTS_SIZE_LIMIT;
     pub const MaxNumberOfOperatorsPerDistributionBucket: u32 = 5;
+    pub const BandwidthReportDisputePeriod: u64 = 10;
+    pub const BandwidthReportPeriod: u64 = 5;
+    pub const MaxBytesServedPerBandwidthReport: u64 = 100_000_000_000;
+    pub const BandwidthReportBond: u64 = 100;
 }
 
 pub const STORAGE_WG_LEADER_ACCOUNT_ID: U256 = U256([100001, 0, 0, 0]);
@@ -325,6 +335,7 @@ impl storage::Config for Test {
     type ModuleId = StorageModuleId;
     type MinStorageBucketsPerBag = MinStorageBucketsPerBag;
     type MaxStorageBucketsPerBag = MaxStorageBucketsPerBag;
+    type MaxStorageBucketsScannedOnAssignment = MaxStorageBucketsScannedOnAssignment;
     type MinDistributionBucketsPerBag = MinDistributionBucketsPerBag;
     type MaxDistributionBucketsPerBag = MaxDistributionBucketsPerBag;
     type DefaultMemberDynamicBagNumberOfStorageBuckets =
@@ -341,6 +352,10 @@ impl storage::Config for Test {
     type DistributionWorkingGroup = DistributionWG;
     type WeightInfo = ();
     type ModuleAccountInitialBalance = ExistentialDeposit;
+    type BandwidthReportDisputePeriod = BandwidthReportDisputePeriod;
+    type BandwidthReportPeriod = BandwidthReportPeriod;
+    type MaxBytesServedPerBandwidthReport = MaxBytesServedPerBandwidthReport;
+    type BandwidthReportBond = BandwidthReportBond;
 }
 
 // Anyone can upload and delete without restriction
@@ -372,6 +387,10 @@ parameter_types! {
     pub const MinimumCashoutAllowedLimit: u64 = 1;
     pub const MaximumCashoutAllowedLimit: u64 = 1_000_000;
     pub const MaxNftAuctionWhitelistLength: u32 = 5;
+    pub const TakedownRequestBond: u64 = 100;
+    pub const TakedownRequestDecisionPeriod: u64 = 10;
+    pub const TakedownAppealPeriod: u64 = 10;
+    pub const TakedownAppealDecisionPeriod: u64 = 20;
 }
 
 impl Config for Test {
@@ -445,6 +464,18 @@ impl Config for Test {
 
     /// Max nft auction whitelist length
     type MaxNftAuctionWhitelistLength = MaxNftAuctionWhitelistLength;
+
+    /// Content takedown request bond
+    type TakedownRequestBond = TakedownRequestBond;
+
+    /// Content takedown request decision period
+    type TakedownRequestDecisionPeriod = TakedownRequestDecisionPeriod;
+
+    /// Content takedown appeal period
+    type TakedownAppealPeriod = TakedownAppealPeriod;
+
+    /// Content takedown appeal decision period
+    type TakedownAppealDecisionPeriod = TakedownAppealDecisionPeriod;
 }
 
 pub const COUNCIL_INITIAL_BUDGET: u64 = 0;
@@ -495,7 +526,6 @@ impl working_group::Config<StorageWorkingGroupInstance> for Test {
     type StakingHandler = staking_handler::StakingManager<Self, LockId>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -511,7 +541,6 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
     type StakingHandler = staking_handler::StakingManager<Self, LockId2>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -552,7 +581,6 @@ impl working_group::Config<ContentWorkingGroupInstance> for Test {
     type StakingHandler = staking_handler::StakingManager<Self, LockId3>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -850,6 +878,12 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for Wg {
         unimplemented!()
     }
 
+    fn get_worker_reward_account_id(
+        _: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Test as frame_system::Config>::AccountId> {
+        unimplemented!()
+    }
+
     fn is_leader_account_id(_account_id: &<Test as frame_system::Config>::AccountId) -> bool {
         unimplemented!()
     }
@@ -976,6 +1010,12 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for StorageWG {
         unimplemented!()
     }
 
+    fn get_worker_reward_account_id(
+        _: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Test as frame_system::Config>::AccountId> {
+        unimplemented!()
+    }
+
     fn is_leader_account_id(_account_id: &<Test as frame_system::Config>::AccountId) -> bool {
         unimplemented!()
     }
@@ -1038,6 +1078,12 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for DistributionWG {
         unimplemented!()
     }
 
+    fn get_worker_reward_account_id(
+        _: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Test as frame_system::Config>::AccountId> {
+        unimplemented!()
+    }
+
     fn is_leader_account_id(_account_id: &<Test as frame_system::Config>::AccountId) -> bool {
         unimplemented!()
     }
@@ -1102,6 +1148,14 @@ parameter_types! {
     pub const MaxVestingSchedulesPerAccountPerToken: u32 = 3;
     pub const BlocksPerYear: u32 = 5259487; // blocks every 6s
     pub const MaxOutputs: u32 = 256;
+    pub const MaxPollOptions: u32 = 4;
+    pub const MaxBatchAuctionBids: u32 = 4;
+    pub const RevenueStakingUnstakingPeriod: u64 = 10;
+    pub const MaxOrdersPerBookSide: u32 = 10;
+    pub const MaxOrderMatches: u32 = 3;
+    pub const MaxAllowancesPerAccount: u32 = 3;
+    pub const MaxAirdropClaimsRemovedPerCall: u32 = 2;
+    pub const MaxPollVotesRemovedPerCall: u32 = 2;
 }
 
 impl project_token::Config for Test {
@@ -1116,6 +1170,14 @@ impl project_token::Config for Test {
     type MemberOriginValidator = TestMemberships;
     type MembershipInfoProvider = TestMemberships;
     type MaxOutputs = MaxOutputs;
+    type MaxPollOptions = MaxPollOptions;
+    type MaxBatchAuctionBids = MaxBatchAuctionBids;
+    type RevenueStakingUnstakingPeriod = RevenueStakingUnstakingPeriod;
+    type MaxOrdersPerBookSide = MaxOrdersPerBookSide;
+    type MaxOrderMatches = MaxOrderMatches;
+    type MaxAllowancesPerAccount = MaxAllowancesPerAccount;
+    type MaxAirdropClaimsRemovedPerCall = MaxAirdropClaimsRemovedPerCall;
+    type MaxPollVotesRemovedPerCall = MaxPollVotesRemovedPerCall;
     type WeightInfo = ();
 }
 
@@ -8,5 +8,6 @@ mod merkle;
 mod metaprotocol;
 pub(crate) mod mock;
 mod nft;
+mod takedowns;
 mod transfers;
 mod videos;
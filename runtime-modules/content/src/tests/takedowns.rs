@@ -0,0 +1,416 @@
+#![cfg(test)]
+use super::fixtures::*;
+use super::mock::*;
+use crate::*;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use std::iter::FromIterator;
+
+fn setup_pending_takedown_request() {
+    run_to_block(1);
+
+    create_initial_storage_buckets_helper();
+    increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+    increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+    create_default_member_owned_channel_with_video();
+
+    FileTakedownRequestFixture::default().call_and_assert(Ok(()));
+}
+
+fn setup_accepted_takedown() {
+    setup_pending_takedown_request();
+    AcceptTakedownRequestFixture::default().call_and_assert(Ok(()));
+}
+
+fn setup_appealed_takedown() {
+    setup_accepted_takedown();
+    AppealTakedownFixture::default().call_and_assert(Ok(()));
+}
+
+// File takedown request
+
+#[test]
+fn successful_takedown_request_filing_for_video() {
+    with_default_mock_builder(|| {
+        setup_pending_takedown_request();
+    })
+}
+
+#[test]
+fn successful_takedown_request_filing_for_single_video_asset() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video();
+
+        let asset = *Content::video_by_id(VideoId::one())
+            .data_objects
+            .iter()
+            .next()
+            .unwrap();
+
+        FileTakedownRequestFixture::default()
+            .with_target(TakedownTarget::VideoAsset(VideoId::one(), asset))
+            .call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_request_filing_with_member_auth_failure() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video();
+
+        FileTakedownRequestFixture::default()
+            .with_sender(UNAUTHORIZED_MEMBER_ACCOUNT_ID)
+            .call_and_assert(Err(Error::<Test>::MemberAuthFailed.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_request_filing_for_non_existing_video() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+
+        FileTakedownRequestFixture::default()
+            .call_and_assert(Err(Error::<Test>::VideoDoesNotExist.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_request_filing_for_asset_outside_of_channel_assets() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        increase_account_balance_helper(SECOND_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video();
+
+        FileTakedownRequestFixture::default()
+            .with_target(TakedownTarget::ChannelAsset(
+                ChannelId::one(),
+                TOTAL_OBJECTS_NUMBER,
+            ))
+            .call_and_assert(Err(
+                Error::<Test>::AssetsToRemoveBeyondEntityAssetsSet.into()
+            ));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_request_filing_with_insufficient_balance() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        create_initial_storage_buckets_helper();
+        increase_account_balance_helper(DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
+        create_default_member_owned_channel_with_video();
+
+        FileTakedownRequestFixture::default().call_and_assert(Err(
+            Error::<Test>::InsufficientBalanceForTakedownRequestBond.into(),
+        ));
+    })
+}
+
+// Accept takedown request
+
+#[test]
+fn successful_takedown_request_acceptance() {
+    with_default_mock_builder(|| {
+        setup_accepted_takedown();
+
+        assert!(Content::video_by_id(VideoId::one()).data_objects.is_empty());
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_request_acceptance_with_blacklist_size_limit_exceeded() {
+    with_default_mock_builder(|| {
+        setup_pending_takedown_request();
+
+        let blacklist_size_limit = <Test as storage::Config>::BlacklistSizeLimit::get();
+        let hashes = (0..blacklist_size_limit)
+            .map(|idx| {
+                let mut cid = vec![1u8; storage::Base58MultihashLen::get() as usize];
+                cid[..8].copy_from_slice(&idx.to_be_bytes());
+                cid
+            })
+            .collect::<BTreeSet<_>>();
+        assert_ok!(Storage::<Test>::update_content_blacklist(
+            BTreeSet::new(),
+            hashes
+        ));
+
+        AcceptTakedownRequestFixture::default().call_and_assert(Err(
+            storage::Error::<Test>::BlacklistSizeLimitExceeded.into(),
+        ));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_request_acceptance_by_unauthorized_curator() {
+    with_default_mock_builder(|| {
+        setup_pending_takedown_request();
+
+        AcceptTakedownRequestFixture::default()
+            .with_sender(UNAUTHORIZED_LEAD_ACCOUNT_ID)
+            .call_and_assert(Err(Error::<Test>::LeadAuthFailed.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_request_acceptance_with_invalid_storage_buckets_witness() {
+    with_default_mock_builder(|| {
+        setup_pending_takedown_request();
+
+        AcceptTakedownRequestFixture::default()
+            .with_storage_buckets_num_witness(0)
+            .call_and_assert(Err(
+                Error::<Test>::InvalidStorageBucketsNumWitnessProvided.into()
+            ));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_request_acceptance_after_decision_period() {
+    with_default_mock_builder(|| {
+        setup_pending_takedown_request();
+
+        run_to_block(2 + <Test as Config>::TakedownRequestDecisionPeriod::get());
+
+        AcceptTakedownRequestFixture::default().call_and_assert(Err(
+            Error::<Test>::TakedownRequestDecisionPeriodExpired.into(),
+        ));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_request_acceptance_when_already_decided() {
+    with_default_mock_builder(|| {
+        setup_accepted_takedown();
+
+        AcceptTakedownRequestFixture::default()
+            .call_and_assert(Err(Error::<Test>::TakedownRequestNotPending.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_request_acceptance_for_non_existing_request() {
+    with_default_mock_builder(|| {
+        setup_pending_takedown_request();
+
+        AcceptTakedownRequestFixture::default()
+            .with_takedown_request_id(1)
+            .call_and_assert(Err(Error::<Test>::TakedownRequestDoesNotExist.into()));
+    })
+}
+
+// Reject takedown request
+
+#[test]
+fn successful_takedown_request_rejection() {
+    with_default_mock_builder(|| {
+        setup_pending_takedown_request();
+
+        RejectTakedownRequestFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_request_rejection_by_member() {
+    with_default_mock_builder(|| {
+        setup_pending_takedown_request();
+
+        RejectTakedownRequestFixture::default()
+            .with_sender(UNAUTHORIZED_MEMBER_ACCOUNT_ID)
+            .with_actor(ContentActor::Member(UNAUTHORIZED_MEMBER_ID))
+            .call_and_assert(Err(Error::<Test>::ActorNotAuthorized.into()));
+    })
+}
+
+// Expire takedown request
+
+#[test]
+fn successful_takedown_request_expiration() {
+    with_default_mock_builder(|| {
+        setup_pending_takedown_request();
+
+        run_to_block(2 + <Test as Config>::TakedownRequestDecisionPeriod::get());
+
+        ExpireTakedownRequestFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_request_expiration_during_decision_period() {
+    with_default_mock_builder(|| {
+        setup_pending_takedown_request();
+
+        ExpireTakedownRequestFixture::default().call_and_assert(Err(
+            Error::<Test>::TakedownRequestDecisionPeriodNotExpired.into(),
+        ));
+    })
+}
+
+// Appeal takedown
+
+#[test]
+fn successful_takedown_appeal() {
+    with_default_mock_builder(|| {
+        setup_appealed_takedown();
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_appeal_by_non_channel_owner() {
+    with_default_mock_builder(|| {
+        setup_accepted_takedown();
+
+        AppealTakedownFixture::default()
+            .with_sender(SECOND_MEMBER_ACCOUNT_ID)
+            .call_and_assert(Err(Error::<Test>::MemberAuthFailed.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_appeal_when_not_accepted() {
+    with_default_mock_builder(|| {
+        setup_pending_takedown_request();
+
+        AppealTakedownFixture::default()
+            .call_and_assert(Err(Error::<Test>::TakedownNotAccepted.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_appeal_after_appeal_period() {
+    with_default_mock_builder(|| {
+        setup_accepted_takedown();
+
+        run_to_block(2 + <Test as Config>::TakedownAppealPeriod::get());
+
+        AppealTakedownFixture::default()
+            .call_and_assert(Err(Error::<Test>::TakedownAppealPeriodExpired.into()));
+    })
+}
+
+// Overturn takedown
+
+#[test]
+fn successful_takedown_overturn() {
+    with_default_mock_builder(|| {
+        setup_appealed_takedown();
+
+        OverturnTakedownFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn successful_takedown_overturn_keeps_content_ids_blacklisted_before_acceptance() {
+    with_default_mock_builder(|| {
+        setup_pending_takedown_request();
+
+        let blacklisted_cid = Content::takedown_request_by_id(0)
+            .content_ids
+            .into_iter()
+            .next()
+            .unwrap();
+        assert_ok!(Storage::<Test>::update_content_blacklist(
+            BTreeSet::new(),
+            BTreeSet::from_iter(vec![blacklisted_cid.to_vec()])
+        ));
+
+        AcceptTakedownRequestFixture::default().call_and_assert(Ok(()));
+        AppealTakedownFixture::default().call_and_assert(Ok(()));
+        OverturnTakedownFixture::default().call_and_assert(Ok(()));
+
+        assert!(storage::Blacklist::contains_key(&blacklisted_cid));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_overturn_with_non_root_origin() {
+    with_default_mock_builder(|| {
+        setup_appealed_takedown();
+
+        OverturnTakedownFixture::default()
+            .with_origin(RawOrigin::Signed(LEAD_ACCOUNT_ID))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_overturn_when_not_appealed() {
+    with_default_mock_builder(|| {
+        setup_accepted_takedown();
+
+        OverturnTakedownFixture::default()
+            .call_and_assert(Err(Error::<Test>::TakedownNotAppealed.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_overturn_after_appeal_decision_period() {
+    with_default_mock_builder(|| {
+        setup_appealed_takedown();
+
+        run_to_block(2 + <Test as Config>::TakedownAppealDecisionPeriod::get());
+
+        OverturnTakedownFixture::default().call_and_assert(Err(
+            Error::<Test>::TakedownAppealDecisionPeriodExpired.into(),
+        ));
+    })
+}
+
+// Expire takedown appeal
+
+#[test]
+fn successful_takedown_appeal_expiry() {
+    with_default_mock_builder(|| {
+        setup_appealed_takedown();
+
+        run_to_block(2 + <Test as Config>::TakedownAppealDecisionPeriod::get());
+
+        ExpireTakedownAppealFixture::default().call_and_assert(Ok(()));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_appeal_expiry_within_appeal_decision_period() {
+    with_default_mock_builder(|| {
+        setup_appealed_takedown();
+
+        ExpireTakedownAppealFixture::default().call_and_assert(Err(
+            Error::<Test>::TakedownAppealDecisionPeriodNotExpired.into(),
+        ));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_appeal_expiry_when_not_appealed() {
+    with_default_mock_builder(|| {
+        setup_accepted_takedown();
+
+        run_to_block(2 + <Test as Config>::TakedownAppealDecisionPeriod::get());
+
+        ExpireTakedownAppealFixture::default()
+            .call_and_assert(Err(Error::<Test>::TakedownNotAppealed.into()));
+    })
+}
+
+#[test]
+fn unsuccessful_takedown_appeal_expiry_with_non_existing_request() {
+    with_default_mock_builder(|| {
+        ExpireTakedownAppealFixture::default()
+            .call_and_assert(Err(Error::<Test>::TakedownRequestDoesNotExist.into()));
+    })
+}
@@ -219,11 +219,14 @@ pub mod iterable_enums {
         /// Allows initializing and managing channel's creator token sale.
         /// Included actions:
         /// - `init_creator_token_sale` (CRT source: AGENT'S MEMBERSHIP, JOY dest: AGENT'S ACCOUNT / None)
+        /// - `init_creator_token_batch_auction` (CRT source: AGENT'S MEMBERSHIP, JOY dest: AGENT'S ACCOUNT / None)
         /// - `update_upcoming_creator_token_sale` (only if initialized by AGENT in question)
         /// - `finalize_creator_token_sale` (only if initialized by AGENT in question)
         InitAndManageCreatorTokenSale,
         /// Allows performing creator token issuer transfer (CRT source: AGENT'S MEMBERSHIP) through:
         /// - `creator_token_issuer_transfer`
+        /// - `creator_token_issuer_create_airdrop`
+        /// - `revoke_creator_token_vesting_schedule`
         CreatorTokenIssuerTransfer,
         /// Allows changing creator token transfer policy to permissionless through:
         /// - `make_creator_token_permissionless`
@@ -231,9 +234,11 @@ pub mod iterable_enums {
         /// Allows reducing creator token patronage rate through:
         /// - `reduce_creator_token_patronage_rate_to`
         ReduceCreatorTokenPatronageRate,
-        /// Allows issuing and finalizing revenue splits. Affected extrinsics:
+        /// Allows issuing and finalizing revenue splits, as well as depositing revenue
+        /// to the creator token stakers. Affected extrinsics:
         /// - `issue_revenue_split`
         /// - `finalize_revenue_split`
+        /// - `deposit_creator_token_staking_revenue`
         ManageRevenueSplits,
         /// Allows deissuing a creator token (provided it has 0 supply)
         /// - `deissue_creator_token`
@@ -242,6 +247,12 @@ pub mod iterable_enums {
         /// - activate_amm
         /// - deactivate_amm
         AmmControl,
+        /// Allows creating creator token holder polls through:
+        /// - `create_creator_token_poll`
+        ManageCreatorTokenPolls,
+        /// Allows enabling / disabling creator token bridging through:
+        /// - `update_creator_token_bridging`
+        ManageCreatorTokenBridging,
     }
 }
 
@@ -577,7 +588,8 @@ pub struct ChannelCreationParametersRecord<
     pub meta: Option<Vec<u8>>,
     /// Map from collaborator's MemberId to collaborator's ChannelAgentPermissions
     pub collaborators: BTreeMap<MemberId, ChannelAgentPermissions>,
-    /// Storage buckets to assign to a bag.
+    /// Storage buckets to assign to a bag. Selected automatically by the storage pallet
+    /// when empty.
     pub storage_buckets: BTreeSet<StorageBucketId>,
     /// Distribution buckets to assign to a bag.
     pub distribution_buckets: BTreeSet<DistributionBucketId>,
@@ -807,6 +819,235 @@ pub type UpdateChannelPayoutsParameters<T> = UpdateChannelPayoutsParametersRecor
     <T as frame_system::Config>::Hash,
 >;
 
+/// Takedown request identifier.
+pub type TakedownRequestId = u64;
+
+/// Content targeted by a takedown request.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum TakedownTarget<ChannelId, VideoId, DataObjectId> {
+    /// Single non-video channel asset.
+    ChannelAsset(ChannelId, DataObjectId),
+    /// Single video asset.
+    VideoAsset(VideoId, DataObjectId),
+    /// All assets of the video.
+    Video(VideoId),
+}
+
+// Default trait implemented only because its used in a TakedownRequest which needs to implement
+// a Default trait since it is a StorageValue.
+impl<ChannelId: Default, VideoId, DataObjectId: Default> Default
+    for TakedownTarget<ChannelId, VideoId, DataObjectId>
+{
+    fn default() -> Self {
+        TakedownTarget::ChannelAsset(ChannelId::default(), DataObjectId::default())
+    }
+}
+
+/// Takedown request lifecycle status.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum TakedownRequestStatus<BlockNumber> {
+    /// Waiting for the curators decision until the provided block (inclusive).
+    Pending(BlockNumber),
+    /// Rejected by the curators: the requester bond was slashed.
+    Rejected,
+    /// Accepted by the curators: the content was blacklisted and removed. Can be appealed
+    /// until the provided block (inclusive).
+    Accepted(BlockNumber),
+    /// Accepted takedown appealed by the channel owner, waiting for the council decision until
+    /// the provided block (inclusive).
+    Appealed(BlockNumber),
+    /// Appealed takedown overturned by the council: the content was removed from the blacklist.
+    Overturned,
+    /// Not decided within the decision period: the requester bond was returned.
+    Expired,
+}
+
+impl<BlockNumber: Default> Default for TakedownRequestStatus<BlockNumber> {
+    fn default() -> Self {
+        TakedownRequestStatus::Pending(BlockNumber::default())
+    }
+}
+
+/// Content takedown request. Kept in the state after the decision as an audit trail (unless
+/// its appeal expires).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct TakedownRequestRecord<
+    MemberId,
+    AccountId,
+    Balance,
+    ChannelId,
+    Target,
+    ContentIds,
+    Status,
+> {
+    /// Member who filed the request.
+    pub requester: MemberId,
+    /// Account the bond was paid from (and is returned to).
+    pub bond_account: AccountId,
+    /// Bond paid by the requester.
+    pub bond: Balance,
+    /// Channel the targeted content belongs to.
+    pub channel_id: ChannelId,
+    /// Targeted content.
+    pub target: Target,
+    /// Content ids of the targeted data objects at the moment of filing the request.
+    pub content_ids: ContentIds,
+    /// Content ids added to the blacklist on the request acceptance (the ones that were not
+    /// blacklisted yet). Only these are removed from the blacklist on the takedown overturn.
+    pub blacklisted_content_ids: ContentIds,
+    /// Request status.
+    pub status: Status,
+}
+
+pub type TakedownTargetOf<T> =
+    TakedownTarget<<T as storage::Config>::ChannelId, <T as Config>::VideoId, DataObjectId<T>>;
+
+pub type TakedownContentIds<T> =
+    BoundedBTreeSet<storage::Base58Multihash, <T as Config>::MaxNumberOfAssetsPerVideo>;
+
+pub type TakedownRequest<T> = TakedownRequestRecord<
+    <T as common::MembershipTypes>::MemberId,
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T>,
+    <T as storage::Config>::ChannelId,
+    TakedownTargetOf<T>,
+    TakedownContentIds<T>,
+    TakedownRequestStatus<<T as frame_system::Config>::BlockNumber>,
+>;
+
+impl<
+        MemberId,
+        AccountId,
+        Balance,
+        ChannelId,
+        Target,
+        ContentIds,
+        BlockNumber: PartialOrd + Copy,
+    >
+    TakedownRequestRecord<
+        MemberId,
+        AccountId,
+        Balance,
+        ChannelId,
+        Target,
+        ContentIds,
+        TakedownRequestStatus<BlockNumber>,
+    >
+{
+    /// Ensure the request is pending and its decision period is not over.
+    pub fn ensure_can_be_decided<T: Config>(&self, now: BlockNumber) -> DispatchResult {
+        match self.status {
+            TakedownRequestStatus::Pending(deadline) => {
+                ensure!(
+                    now <= deadline,
+                    Error::<T>::TakedownRequestDecisionPeriodExpired
+                );
+                Ok(())
+            }
+            _ => Err(Error::<T>::TakedownRequestNotPending.into()),
+        }
+    }
+
+    /// Ensure the request is pending and its decision period is over.
+    pub fn ensure_can_expire<T: Config>(&self, now: BlockNumber) -> DispatchResult {
+        match self.status {
+            TakedownRequestStatus::Pending(deadline) => {
+                ensure!(
+                    now > deadline,
+                    Error::<T>::TakedownRequestDecisionPeriodNotExpired
+                );
+                Ok(())
+            }
+            _ => Err(Error::<T>::TakedownRequestNotPending.into()),
+        }
+    }
+
+    /// Ensure the takedown is accepted and its appeal period is not over.
+    pub fn ensure_can_be_appealed<T: Config>(&self, now: BlockNumber) -> DispatchResult {
+        match self.status {
+            TakedownRequestStatus::Accepted(deadline) => {
+                ensure!(now <= deadline, Error::<T>::TakedownAppealPeriodExpired);
+                Ok(())
+            }
+            _ => Err(Error::<T>::TakedownNotAccepted.into()),
+        }
+    }
+
+    /// Ensure the takedown is appealed and its appeal decision period is not over.
+    pub fn ensure_can_be_overturned<T: Config>(&self, now: BlockNumber) -> DispatchResult {
+        match self.status {
+            TakedownRequestStatus::Appealed(deadline) => {
+                ensure!(
+                    now <= deadline,
+                    Error::<T>::TakedownAppealDecisionPeriodExpired
+                );
+                Ok(())
+            }
+            _ => Err(Error::<T>::TakedownNotAppealed.into()),
+        }
+    }
+
+    /// Ensure the takedown is appealed and its appeal decision period is over.
+    pub fn ensure_appeal_can_expire<T: Config>(&self, now: BlockNumber) -> DispatchResult {
+        match self.status {
+            TakedownRequestStatus::Appealed(deadline) => {
+                ensure!(
+                    now > deadline,
+                    Error::<T>::TakedownAppealDecisionPeriodNotExpired
+                );
+                Ok(())
+            }
+            _ => Err(Error::<T>::TakedownNotAppealed.into()),
+        }
+    }
+}
+
+/// Channel action executed once a creator token poll ends with the linked option winning.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum CreatorTokenPollAction<BlockNumber> {
+    /// Issue a revenue split of the given duration, starting immediately.
+    IssueRevenueSplit(BlockNumber),
+    /// Reduce the creator token patronage rate.
+    ReducePatronageRate(YearlyRate),
+    /// Deactivate the creator token AMM.
+    DeactivateAmm,
+}
+
+// Default trait implemented only because its used in a LinkedCreatorTokenPollAction which needs
+// to implement a Default trait since it is a StorageValue.
+impl<BlockNumber> Default for CreatorTokenPollAction<BlockNumber> {
+    fn default() -> Self {
+        CreatorTokenPollAction::DeactivateAmm
+    }
+}
+
+/// Action linked by the channel to one of the options of a creator token poll.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct LinkedCreatorTokenPollActionRecord<MemberId, ChannelOwner, Action> {
+    /// Member who opened the poll on behalf of the channel.
+    pub member_id: MemberId,
+    /// Channel owner at the time the action was linked.
+    pub channel_owner: ChannelOwner,
+    /// Index of the poll option that triggers the action.
+    pub option_index: u32,
+    /// Action to execute.
+    pub action: Action,
+}
+
+pub type CreatorTokenPollActionOf<T> =
+    CreatorTokenPollAction<<T as frame_system::Config>::BlockNumber>;
+
+pub type LinkedCreatorTokenPollAction<T> = LinkedCreatorTokenPollActionRecord<
+    <T as common::MembershipTypes>::MemberId,
+    ChannelOwner<<T as common::MembershipTypes>::MemberId, <T as Config>::CuratorGroupId>,
+    CreatorTokenPollActionOf<T>,
+>;
+
 /// Operations with local pallet account.
 pub trait ModuleAccount<T: Config> {
     /// The module id, used for deriving its sovereign account ID.
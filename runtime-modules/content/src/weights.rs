@@ -118,6 +118,20 @@ pub trait WeightInfo {
 	fn update_channel_state_bloat_bond() -> Weight;
 	fn update_video_state_bloat_bond() -> Weight;
 	fn creator_token_issuer_remark(_b: u32, ) -> Weight;
+	fn file_takedown_request(_a: u32, _b: u32, ) -> Weight;
+	fn accept_takedown_request(_a: u32, _b: u32, _c: u32, ) -> Weight;
+	fn reject_takedown_request(_a: u32, ) -> Weight;
+	fn expire_takedown_request() -> Weight;
+	fn appeal_takedown(_a: u32, ) -> Weight;
+	fn overturn_takedown(_a: u32, ) -> Weight;
+	fn expire_takedown_appeal() -> Weight;
+	fn creator_token_issuer_create_airdrop() -> Weight;
+	fn create_creator_token_poll(_b: u32, ) -> Weight;
+	fn execute_creator_token_poll_action(_v: u32, ) -> Weight;
+	fn revoke_creator_token_vesting_schedule() -> Weight;
+	fn init_creator_token_batch_auction(_a: u32, ) -> Weight;
+	fn deposit_creator_token_staking_revenue() -> Weight;
+	fn update_creator_token_bridging() -> Weight;
 }
 
 /// Weights for content using the Substrate node and recommended hardware.
@@ -2153,6 +2167,364 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(1_125_689, 0u64).saturating_mul(b.into()))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: Content VideoById (r:1 w:0)
+	// Proof: Content VideoById (max_values: None, max_size: Some(513), added: 2988, mode: MaxEncodedLen)
+	// Storage: Storage DataObjectsById (r:20 w:0)
+	// Proof: Storage DataObjectsById (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Content NextTakedownRequestId (r:1 w:1)
+	// Proof: Content NextTakedownRequestId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Content TakedownRequestById (r:0 w:1)
+	// Proof: Content TakedownRequestById (max_values: None, max_size: Some(1952), added: 4427, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 20]`.
+	/// The range of component `b` is `[1, 100]`.
+	fn file_takedown_request(a: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1891 + a * (189 ±0)`
+		//  Estimated: `14682 + a * (2630 ±0)`
+		// Minimum execution time: 117_384 nanoseconds.
+		Weight::from_parts(104_629_733, 0u64)
+			.saturating_add(Weight::from_parts(0, 14682))
+			// Standard Error: 73_565
+			.saturating_add(Weight::from_parts(4_193_221, 0u64).saturating_mul(a.into()))
+			// Standard Error: 19_840
+			.saturating_add(Weight::from_parts(1_130_918, 0u64).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: Content TakedownRequestById (r:1 w:1)
+	// Proof: Content TakedownRequestById (max_values: None, max_size: Some(1952), added: 4427, mode: MaxEncodedLen)
+	// Storage: Content VideoById (r:1 w:1)
+	// Proof: Content VideoById (max_values: None, max_size: Some(513), added: 2988, mode: MaxEncodedLen)
+	// Storage: Content ChannelById (r:1 w:0)
+	// Proof: Content ChannelById (max_values: None, max_size: Some(909), added: 3384, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Proof: Content CuratorGroupById (max_values: None, max_size: Some(1037), added: 3512, mode: MaxEncodedLen)
+	// Storage: Storage Bags (r:1 w:1)
+	// Proof: Storage Bags (max_values: None, max_size: Some(964), added: 3439, mode: MaxEncodedLen)
+	// Storage: Storage Blacklist (r:20 w:20)
+	// Proof: Storage Blacklist (max_values: None, max_size: Some(63), added: 2538, mode: MaxEncodedLen)
+	// Storage: Storage CurrentBlacklistSize (r:1 w:1)
+	// Proof: Storage CurrentBlacklistSize (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Storage DataObjectStateBloatBondValue (r:1 w:0)
+	// Proof: Storage DataObjectStateBloatBondValue (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Storage DataObjectsById (r:20 w:20)
+	// Proof: Storage DataObjectsById (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
+	// Storage: Storage DataObjectPerMegabyteFee (r:1 w:0)
+	// Proof: Storage DataObjectPerMegabyteFee (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Storage StorageBucketById (r:13 w:13)
+	// Proof: Storage StorageBucketById (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	// Storage: System Account (r:23 w:23)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 20]`.
+	/// The range of component `b` is `[1, 13]`.
+	/// The range of component `c` is `[1, 100]`.
+	fn accept_takedown_request(a: u32, b: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5772 + a * (361 ±0) + b * (80 ±0)`
+		//  Estimated: `36518 + a * (10304 ±0) + b * (2581 ±0)`
+		// Minimum execution time: 561_208 nanoseconds.
+		Weight::from_parts(231_512_449, 0u64)
+			.saturating_add(Weight::from_parts(0, 36518))
+			// Standard Error: 590_728
+			.saturating_add(Weight::from_parts(33_671_508, 0u64).saturating_mul(a.into()))
+			// Standard Error: 182_672
+			.saturating_add(Weight::from_parts(10_412_337, 0u64).saturating_mul(b.into()))
+			// Standard Error: 21_827
+			.saturating_add(Weight::from_parts(1_244_172, 0u64).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2581).saturating_mul(b.into()))
+	}
+	// Storage: Content TakedownRequestById (r:1 w:1)
+	// Proof: Content TakedownRequestById (max_values: None, max_size: Some(1952), added: 4427, mode: MaxEncodedLen)
+	// Storage: Content ChannelById (r:1 w:0)
+	// Proof: Content ChannelById (max_values: None, max_size: Some(909), added: 3384, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Proof: Content CuratorGroupById (max_values: None, max_size: Some(1037), added: 3512, mode: MaxEncodedLen)
+	// Storage: Content VideoById (r:1 w:0)
+	// Proof: Content VideoById (max_values: None, max_size: Some(513), added: 2988, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 100]`.
+	fn reject_takedown_request(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3874`
+		//  Estimated: `22633`
+		// Minimum execution time: 158_924 nanoseconds.
+		Weight::from_parts(147_312_605, 0u64)
+			.saturating_add(Weight::from_parts(0, 22633))
+			// Standard Error: 20_005
+			.saturating_add(Weight::from_parts(1_140_286, 0u64).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Content TakedownRequestById (r:1 w:1)
+	// Proof: Content TakedownRequestById (max_values: None, max_size: Some(1952), added: 4427, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn expire_takedown_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1568`
+		//  Estimated: `8712`
+		// Minimum execution time: 71_033 nanoseconds.
+		Weight::from_parts(72_611_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 8712))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Content TakedownRequestById (r:1 w:1)
+	// Proof: Content TakedownRequestById (max_values: None, max_size: Some(1952), added: 4427, mode: MaxEncodedLen)
+	// Storage: Content ChannelById (r:1 w:0)
+	// Proof: Content ChannelById (max_values: None, max_size: Some(909), added: 3384, mode: MaxEncodedLen)
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 100]`.
+	fn appeal_takedown(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2687`
+		//  Estimated: `9490`
+		// Minimum execution time: 88_163 nanoseconds.
+		Weight::from_parts(80_904_316, 0u64)
+			.saturating_add(Weight::from_parts(0, 9490))
+			// Standard Error: 19_779
+			.saturating_add(Weight::from_parts(1_127_458, 0u64).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Content TakedownRequestById (r:1 w:1)
+	// Proof: Content TakedownRequestById (max_values: None, max_size: Some(1952), added: 4427, mode: MaxEncodedLen)
+	// Storage: Storage Blacklist (r:20 w:20)
+	// Proof: Storage Blacklist (max_values: None, max_size: Some(63), added: 2538, mode: MaxEncodedLen)
+	// Storage: Storage CurrentBlacklistSize (r:1 w:1)
+	// Proof: Storage CurrentBlacklistSize (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 20]`.
+	fn overturn_takedown(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1503 + a * (66 ±0)`
+		//  Estimated: `4009 + a * (2538 ±0)`
+		// Minimum execution time: 52_337 nanoseconds.
+		Weight::from_parts(47_880_145, 0u64)
+			.saturating_add(Weight::from_parts(0, 4009))
+			// Standard Error: 63_195
+			.saturating_add(Weight::from_parts(3_602_118, 0u64).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 2538).saturating_mul(a.into()))
+	}
+	// TODO: adjust weight, derived from `expire_takedown_request` without rerunning the benchmark.
+	// Storage: Content TakedownRequestById (r:1 w:1)
+	// Proof: Content TakedownRequestById (max_values: None, max_size: Some(1952), added: 4427, mode: MaxEncodedLen)
+	fn expire_takedown_appeal() -> Weight {
+		Weight::from_parts(30_000_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 4427))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Proof: Content ChannelById (max_values: None, max_size: Some(909), added: 3384, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Proof: Content CuratorGroupById (max_values: None, max_size: Some(1037), added: 3512, mode: MaxEncodedLen)
+	// Storage: Token PalletFrozen (r:1 w:0)
+	// Proof: Token PalletFrozen (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Proof: Token TokenInfoById (max_values: None, max_size: Some(352), added: 2827, mode: MaxEncodedLen)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	// Proof: Token AccountInfoByTokenAndMember (max_values: None, max_size: Some(489), added: 2964, mode: MaxEncodedLen)
+	// Storage: Token NextAirdropId (r:1 w:1)
+	// Proof: Token NextAirdropId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Token Airdrops (r:0 w:1)
+	// Proof: Token Airdrops (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	fn creator_token_issuer_create_airdrop() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2271`
+		//  Estimated: `19336`
+		// Minimum execution time: 71_482 nanoseconds.
+		Weight::from_parts(73_115_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 19336))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Proof: Content ChannelById (max_values: None, max_size: Some(909), added: 3384, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Proof: Content CuratorGroupById (max_values: None, max_size: Some(1037), added: 3512, mode: MaxEncodedLen)
+	// Storage: Token PalletFrozen (r:1 w:0)
+	// Proof: Token PalletFrozen (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Proof: Token TokenInfoById (max_values: None, max_size: Some(352), added: 2827, mode: MaxEncodedLen)
+	// Storage: Token NextPollId (r:1 w:1)
+	// Proof: Token NextPollId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Token Polls (r:0 w:1)
+	// Proof: Token Polls (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
+	// Storage: Content CreatorTokenPollActions (r:0 w:1)
+	// Proof: Content CreatorTokenPollActions (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// The range of component `b` is `[1, 100]`.
+	fn create_creator_token_poll(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3120`
+		//  Estimated: `18709`
+		// Minimum execution time: 141_862 nanoseconds.
+		Weight::from_parts(143_517_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 18709))
+			// Standard Error: 19_748
+			.saturating_add(Weight::from_parts(1_125_689, 0u64).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Proof: Content ChannelById (max_values: None, max_size: Some(909), added: 3384, mode: MaxEncodedLen)
+	// Storage: Content CreatorTokenPollActions (r:1 w:1)
+	// Proof: Content CreatorTokenPollActions (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	// Storage: Token Polls (r:1 w:1)
+	// Proof: Token Polls (max_values: None, max_size: Some(322), added: 2797, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Token PalletFrozen (r:1 w:0)
+	// Proof: Token PalletFrozen (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Token TokenInfoById (r:1 w:1)
+	// Proof: Token TokenInfoById (max_values: None, max_size: Some(352), added: 2827, mode: MaxEncodedLen)
+	// Storage: Token MinRevenueSplitDuration (r:1 w:0)
+	// Proof: Token MinRevenueSplitDuration (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Token MinRevenueSplitTimeToStart (r:1 w:0)
+	// Proof: Token MinRevenueSplitTimeToStart (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Council Budget (r:1 w:1)
+	// Proof: Council Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Token PollVotes (r:0 w:100)
+	// Proof: Token PollVotes (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// The range of component `v` is `[1, 100]`.
+	fn execute_creator_token_poll_action(v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3902 + v * (72 ±0)`
+		//  Estimated: `18759`
+		// Minimum execution time: 226_940 nanoseconds.
+		Weight::from_parts(229_371_618, 0u64)
+			.saturating_add(Weight::from_parts(0, 18759))
+			// Standard Error: 2_416
+			.saturating_add(Weight::from_parts(1_263_472, 0u64).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Proof: Content ChannelById (max_values: None, max_size: Some(909), added: 3384, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Proof: Content CuratorGroupById (max_values: None, max_size: Some(1037), added: 3512, mode: MaxEncodedLen)
+	// Storage: Token PalletFrozen (r:1 w:0)
+	// Proof: Token PalletFrozen (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Proof: Token TokenInfoById (max_values: None, max_size: Some(352), added: 2827, mode: MaxEncodedLen)
+	// Storage: Token RevocableVestingSchedules (r:1 w:1)
+	// Proof: Token RevocableVestingSchedules (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	// Storage: Token AccountInfoByTokenAndMember (r:2 w:2)
+	// Proof: Token AccountInfoByTokenAndMember (max_values: None, max_size: Some(489), added: 2964, mode: MaxEncodedLen)
+	fn revoke_creator_token_vesting_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3684`
+		//  Estimated: `21336`
+		// Minimum execution time: 137_915 nanoseconds.
+		Weight::from_parts(140_262_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 21336))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Proof: Content ChannelById (max_values: None, max_size: Some(909), added: 3384, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Proof: Content CuratorGroupById (max_values: None, max_size: Some(1037), added: 3512, mode: MaxEncodedLen)
+	// Storage: Token PalletFrozen (r:1 w:0)
+	// Proof: Token PalletFrozen (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Token TokenInfoById (r:1 w:1)
+	// Proof: Token TokenInfoById (max_values: None, max_size: Some(352), added: 2827, mode: MaxEncodedLen)
+	// Storage: Token MinSaleDuration (r:1 w:0)
+	// Proof: Token MinSaleDuration (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Token AccountInfoByTokenAndMember (r:1 w:1)
+	// Proof: Token AccountInfoByTokenAndMember (max_values: None, max_size: Some(489), added: 2964, mode: MaxEncodedLen)
+	// Storage: Token BatchAuctions (r:0 w:1)
+	// Proof: Token BatchAuctions (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	/// The range of component `a` is `[1, 100]`.
+	fn init_creator_token_batch_auction(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3539`
+		//  Estimated: `23262`
+		// Minimum execution time: 186_310 nanoseconds.
+		Weight::from_parts(189_874_217, 0u64)
+			.saturating_add(Weight::from_parts(0, 23262))
+			// Standard Error: 17_284
+			.saturating_add(Weight::from_parts(985_214, 0u64).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Proof: Content ChannelById (max_values: None, max_size: Some(909), added: 3384, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Proof: Content CuratorGroupById (max_values: None, max_size: Some(1037), added: 3512, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Token PalletFrozen (r:1 w:0)
+	// Proof: Token PalletFrozen (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Proof: Token TokenInfoById (max_values: None, max_size: Some(352), added: 2827, mode: MaxEncodedLen)
+	// Storage: Token RevenueStakingPools (r:1 w:1)
+	// Proof: Token RevenueStakingPools (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: Council Budget (r:1 w:1)
+	// Proof: Council Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn deposit_creator_token_staking_revenue() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3812`
+		//  Estimated: `28026`
+		// Minimum execution time: 203_517 nanoseconds.
+		Weight::from_parts(208_163_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 28026))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: Content ChannelById (r:1 w:0)
+	// Proof: Content ChannelById (max_values: None, max_size: Some(909), added: 3384, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Content CuratorGroupById (r:1 w:0)
+	// Proof: Content CuratorGroupById (max_values: None, max_size: Some(1037), added: 3512, mode: MaxEncodedLen)
+	// Storage: Token PalletFrozen (r:1 w:0)
+	// Proof: Token PalletFrozen (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+	// Storage: Token TokenInfoById (r:1 w:0)
+	// Proof: Token TokenInfoById (max_values: None, max_size: Some(352), added: 2827, mode: MaxEncodedLen)
+	// Storage: Token TokenBridgingById (r:1 w:1)
+	// Proof: Token TokenBridgingById (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	fn update_creator_token_bridging() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3118`
+		//  Estimated: `15385`
+		// Minimum execution time: 148_213 nanoseconds.
+		Weight::from_parts(151_877_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 15385))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // Default implementation for tests
@@ -2379,4 +2751,46 @@ impl WeightInfo for () {
 	fn creator_token_issuer_remark(b: u32, ) -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn file_takedown_request(a: u32, b: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn accept_takedown_request(a: u32, b: u32, c: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn reject_takedown_request(a: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn expire_takedown_request() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn appeal_takedown(a: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn overturn_takedown(a: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn expire_takedown_appeal() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn creator_token_issuer_create_airdrop() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn create_creator_token_poll(b: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn execute_creator_token_poll_action(v: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn revoke_creator_token_vesting_schedule() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn init_creator_token_batch_auction(a: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn deposit_creator_token_staking_revenue() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn update_creator_token_bridging() -> Weight {
+		Weight::from_parts(0, 0)
+	}
 }
@@ -341,6 +341,12 @@ impl common::working_group::WorkingGroupAuthenticator<Runtime> for Wg {
         unimplemented!()
     }
 
+    fn get_worker_reward_account_id(
+        _: &<Runtime as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Runtime as frame_system::Config>::AccountId> {
+        unimplemented!()
+    }
+
     fn ensure_leader_origin(
         _origin: <Runtime as frame_system::Config>::RuntimeOrigin,
     ) -> DispatchResult {
@@ -150,7 +150,6 @@ impl working_group::Config<ForumWorkingGroupInstance> for Runtime {
     type StakingAccountValidator = membership::Module<Runtime>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -306,6 +305,12 @@ impl common::working_group::WorkingGroupAuthenticator<Runtime> for Wg {
         unimplemented!()
     }
 
+    fn get_worker_reward_account_id(
+        _: &<Runtime as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Runtime as frame_system::Config>::AccountId> {
+        unimplemented!()
+    }
+
     fn is_leader_account_id(account_id: &<Runtime as frame_system::Config>::AccountId) -> bool {
         *account_id == FORUM_LEAD_ORIGIN_ID
     }
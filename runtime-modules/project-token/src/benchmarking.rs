@@ -39,6 +39,10 @@ const DEFAULT_SPLIT_PARTICIPATION: u32 =
 const DEFAULT_AMM_AMOUNT: u32 = 1;
 const DEFAULT_AMM_JOY_AMOUNT: u32 = 5_000_100; // (a = 10_000_000) * amount^2 /2 + (b = 100) * amount, amount = 1
 
+// Order book
+const DEFAULT_ORDER_QUANTITY: u32 = 100;
+const DEFAULT_ORDER_PRICE: u32 = 2_000_000;
+
 // Patronage
 const DEFAULT_PATRONAGE: YearlyRate = YearlyRate(Permill::from_percent(15));
 // Metadata
@@ -159,8 +163,52 @@ fn init_token_sale<T: Config>(token_id: T::TokenId) -> Result<TokenSaleId, Dispa
     Ok(sale_id)
 }
 
+fn init_batch_auction<T: Config>(token_id: T::TokenId) -> Result<TokenSaleId, DispatchError> {
+    let sale_id = Token::<T>::token_info_by_id(token_id).next_sale_id;
+    Token::<T>::init_batch_auction(
+        token_id,
+        T::MemberId::zero(),
+        Some(token_owner_account::<T>()),
+        TokenSaleParamsOf::<T> {
+            unit_price: DEFAULT_SALE_UNIT_PRICE.into(),
+            upper_bound_quantity: DEFAULT_TOKENS_ON_SALE.into(),
+            starts_at: None,
+            duration: default_sale_duration::<T>(),
+            cap_per_member: Some(DEFAULT_SALE_PURCHASE.into()),
+            vesting_schedule_params: Some(VestingScheduleParams {
+                blocks_before_cliff: 100u32.into(),
+                cliff_amount_percentage: Permill::from_percent(100),
+                linear_vesting_duration: 0u32.into(),
+            }),
+            metadata: None,
+        },
+    )?;
+    Ok(sale_id)
+}
+
+fn place_batch_auction_bid<T: Config>(
+    token_id: T::TokenId,
+    index: u32,
+    max_price: u32,
+) -> Result<<T as MembershipTypes>::MemberId, DispatchError> {
+    let bidder = account::<T::AccountId>("bidder", index, SEED);
+    let member_id = create_member::<T>(&bidder, &uniq_handle("bidder", index));
+    let _ = Joy::<T>::deposit_creating(
+        &bidder,
+        Token::<T>::bloat_bond() + (DEFAULT_SALE_PURCHASE * max_price).into(),
+    );
+    Token::<T>::place_batch_auction_bid(
+        RawOrigin::Signed(bidder).into(),
+        token_id,
+        member_id,
+        DEFAULT_SALE_PURCHASE.into(),
+        max_price.into(),
+    )?;
+    Ok(member_id)
+}
+
 fn activate_amm<T: Config>(token_id: T::TokenId, member_id: T::MemberId) -> DispatchResult {
-    let params = AmmParams {
+    let params = AmmParams::Linear {
         slope: 10_000_000u32.into(),
         intercept: 100u32.into(),
     };
@@ -212,6 +260,66 @@ fn issue_revenue_split<T: Config>(token_id: T::TokenId, forced_id: Option<u32>)
     Ok(())
 }
 
+fn deposit_staking_revenue<T: Config>(token_id: T::TokenId) -> DispatchResult {
+    // top up owner JOY balance
+    let _ = Joy::<T>::deposit_creating(
+        &token_owner_account::<T>(),
+        DEFAULT_SPLIT_REVENUE.saturated_into(),
+    );
+
+    Token::<T>::deposit_staking_revenue(
+        token_id,
+        token_owner_account::<T>(),
+        DEFAULT_SPLIT_REVENUE.saturated_into(),
+    )?;
+
+    // Slash the remaining balance
+    let _ = Joy::<T>::slash(
+        &token_owner_account::<T>(),
+        (DEFAULT_SPLIT_REVENUE - DEFAULT_SPLIT_ALLOCATION).saturated_into(),
+    );
+
+    Ok(())
+}
+
+fn place_sell_limit_order<T: Config>(
+    token_id: T::TokenId,
+    index: u32,
+) -> Result<<T as MembershipTypes>::MemberId, DispatchError> {
+    let seller = account::<T::AccountId>("seller", index, SEED);
+    let member_id = create_member::<T>(&seller, &uniq_handle("seller", index));
+    Token::<T>::do_insert_new_account_for_token(
+        token_id,
+        &member_id,
+        AccountDataOf::<T>::new_with_amount_and_bond(
+            DEFAULT_ORDER_QUANTITY.into(),
+            RepayableBloatBond::new(JoyBalanceOf::<T>::zero(), None),
+        ),
+    );
+    Token::<T>::place_limit_order(
+        RawOrigin::Signed(seller).into(),
+        token_id,
+        member_id,
+        OrderSide::Sell,
+        DEFAULT_ORDER_QUANTITY.into(),
+        DEFAULT_ORDER_PRICE.into(),
+    )?;
+    Ok(member_id)
+}
+
+fn create_limit_order_buyer<T: Config>(
+    quantity: u32,
+) -> (T::AccountId, <T as MembershipTypes>::MemberId) {
+    let buyer = account::<T::AccountId>("buyer", 0, SEED);
+    let member_id = create_member::<T>(&buyer, b"buyer");
+    let _ = Joy::<T>::deposit_creating(
+        &buyer,
+        Token::<T>::bloat_bond()
+            + JoyBalanceOf::<T>::from(quantity) * (DEFAULT_ORDER_PRICE + 1).into(),
+    );
+    (buyer, member_id)
+}
+
 fn setup_account_with_max_number_of_locks<T: Config>(
     token_id: T::TokenId,
     member_id: &T::MemberId,
@@ -270,6 +378,8 @@ benchmarks! {
             amm_buy_tx_fees: Some(Permill::from_percent(1)),
             amm_sell_tx_fees: Some(Permill::from_percent(1)),
             bloat_bond: Some(1000u32.into()),
+            order_book_tx_fees: Some(Permill::from_percent(1)),
+            min_order_value: Some(1000u32.into()),
         };
         let origin = RawOrigin::Root;
     }: _(origin, parameters.clone())
@@ -349,10 +459,153 @@ benchmarks! {
         )
     }
 
+    // Worst case scenario:
+    // - new allowance
+    // - bloat_bond is non-zero
+    approve {
+        let (owner_member_id, owner_account) = create_owner::<T>();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let bloat_bond: JoyBalanceOf<T> = T::JoyExistentialDeposit::get();
+        BloatBond::<T>::set(bloat_bond);
+        let _ = Joy::<T>::deposit_creating(&owner_account, bloat_bond);
+        let spender_acc = account::<T::AccountId>("spender", 0, SEED);
+        let spender = AllowanceSpender::Member(create_member::<T>(&spender_acc, b"spender"));
+        let amount: TokenBalanceOf<T> = DEFAULT_TX_AMOUNT.into();
+        let expires_at = Some(System::<T>::block_number() + T::BlockNumber::one());
+    }: _(
+        RawOrigin::Signed(owner_account),
+        owner_member_id,
+        token_id,
+        spender.clone(),
+        amount,
+        expires_at
+    )
+    verify {
+        assert_eq!(
+            Token::<T>::allowance((token_id, owner_member_id), &spender),
+            TokenAllowanceOf::<T> {
+                amount,
+                expires_at,
+                bloat_bond: RepayableBloatBond::new(bloat_bond, None),
+            }
+        );
+        assert_eq!(Token::<T>::allowances_number(token_id, owner_member_id), 1);
+        assert_last_event::<T>(
+            RawEvent::AllowanceUpdated(token_id, owner_member_id, spender, amount, expires_at).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - bloat_bond is non-zero
+    revoke_allowance {
+        let (owner_member_id, owner_account) = create_owner::<T>();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let bloat_bond: JoyBalanceOf<T> = T::JoyExistentialDeposit::get();
+        BloatBond::<T>::set(bloat_bond);
+        let _ = Joy::<T>::deposit_creating(&owner_account, bloat_bond);
+        let spender_acc = account::<T::AccountId>("spender", 0, SEED);
+        let spender = AllowanceSpender::Member(create_member::<T>(&spender_acc, b"spender"));
+        Token::<T>::approve(
+            RawOrigin::Signed(owner_account.clone()).into(),
+            owner_member_id,
+            token_id,
+            spender.clone(),
+            DEFAULT_TX_AMOUNT.into(),
+            None
+        )?;
+    }: _(
+        RawOrigin::Signed(owner_account),
+        owner_member_id,
+        token_id,
+        spender.clone()
+    )
+    verify {
+        assert!(!Allowances::<T>::contains_key((token_id, owner_member_id), &spender));
+        assert!(!AllowancesNumber::<T>::contains_key(token_id, owner_member_id));
+        assert_last_event::<T>(
+            RawEvent::AllowanceRevoked(token_id, owner_member_id, spender).into()
+        );
+    }
+
+    transfer_from {
+        let o in 1 .. (<T as Config>::MaxOutputs::get());
+        let m in 1 .. MAX_KILOBYTES_METADATA;
+
+        let (owner_member_id, owner_account) = create_owner::<T>();
+        let _outputs =
+            (0..(o as u16))
+            .map(|i| {
+                let member_id = create_member::<T>(
+                    &account::<T::AccountId>("dst", i as u32, SEED),
+                    &uniq_handle("dst_member", i as u32)
+                );
+                (
+                    member_id,
+                    TokenBalanceOf::<T>::from(DEFAULT_TX_AMOUNT)
+                )
+            })
+            .collect::<Vec<_>>();
+        let outputs: TransferOutputsOf<T> = _outputs.try_into().unwrap();
+        let bloat_bond: JoyBalanceOf<T> = T::JoyExistentialDeposit::get();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        setup_account_with_max_number_of_locks::<T>(token_id, &owner_member_id, None);
+        BloatBond::<T>::set(bloat_bond);
+        let spender_acc = account::<T::AccountId>("spender", 0, SEED);
+        let spender = AllowanceSpender::Member(create_member::<T>(&spender_acc, b"spender"));
+        let _ = Joy::<T>::deposit_creating(
+            &spender_acc,
+            bloat_bond * (o + 1).into()
+        );
+        // allowance is not exhausted by the transfer
+        let allowance_amount = TokenBalanceOf::<T>::from(DEFAULT_TX_AMOUNT) * (o + 1).into();
+        Token::<T>::approve(
+            RawOrigin::Signed(owner_account).into(),
+            owner_member_id,
+            token_id,
+            spender.clone(),
+            allowance_amount,
+            None
+        )?;
+        let metadata = vec![0xf].repeat((m * 1000) as usize);
+    }: _(
+        RawOrigin::Signed(spender_acc.clone()),
+        spender.clone(),
+        owner_member_id,
+        token_id,
+        outputs.clone(),
+        metadata.clone()
+    )
+    verify {
+        outputs.iter().for_each(|(member_id, _)| {
+            assert_eq!(
+                AccountInfoByTokenAndMember::<T>::get(token_id, member_id).amount,
+                DEFAULT_TX_AMOUNT.into()
+            );
+        });
+        assert_last_event::<T>(
+            RawEvent::TokenAmountTransferredFrom(
+                token_id,
+                owner_member_id,
+                spender,
+                Transfers(
+                    outputs
+                        .iter()
+                        .map(|(m, p)| (Validated::NonExisting(*m), ValidatedPayment::from(PaymentWithVesting::from(*p))))
+                        .collect()
+                ),
+                DEFAULT_TX_AMOUNT.into(),
+                metadata
+            ).into()
+        );
+    }
+
     // Worst case scenario:
     // - account's bloat_bond is non-zero
     // - token policy is Permissioned
+    // - account granted non-zero bond allowances
     dust_account {
+        let a in 0 .. (<T as Config>::MaxAllowancesPerAccount::get());
+
         let (owner_member_id, owner_account) = create_owner::<T>();
         let bloat_bond: JoyBalanceOf<T> = T::JoyExistentialDeposit::get();
 
@@ -371,6 +624,19 @@ benchmarks! {
             T::JoyExistentialDeposit::get()
         );
 
+        // Grant `a` allowances
+        let _ = Joy::<T>::deposit_creating(&owner_account, bloat_bond * a.into());
+        for i in 0..a {
+            Token::<T>::approve(
+                RawOrigin::Signed(owner_account.clone()).into(),
+                owner_member_id,
+                token_id,
+                AllowanceSpender::Account(account::<T::AccountId>("spender", i, SEED)),
+                DEFAULT_TX_AMOUNT.into(),
+                None
+            )?;
+        }
+
         // Burn all owner tokens
         Token::<T>::burn(
             RawOrigin::Signed(owner_account.clone()).into(),
@@ -385,6 +651,8 @@ benchmarks! {
     )
     verify {
         assert!(!AccountInfoByTokenAndMember::<T>::contains_key(token_id, owner_member_id));
+        assert!(Allowances::<T>::iter_prefix((token_id, owner_member_id)).next().is_none());
+        assert!(!AllowancesNumber::<T>::contains_key(token_id, owner_member_id));
         assert_last_event::<T>(
             RawEvent::AccountDustedBy(
                 token_id,
@@ -445,6 +713,166 @@ benchmarks! {
         );
     }
 
+    // Worst case scenario:
+    // - new account needs to be created
+    // - claim has a vesting schedule
+    // - bloat_bond is non-zero
+    claim_airdrop {
+        let h in 1 .. MAX_MERKLE_PROOF_HASHES;
+
+        let (owner_member_id, _) = create_owner::<T>();
+        let acc = account::<T::AccountId>("claimer", 0, SEED);
+        let member_id = create_member::<T>(&acc, b"claimer");
+        let amount: TokenBalanceOf<T> = DEFAULT_TX_AMOUNT.into();
+        let vesting_schedule = Some(VestingScheduleParams {
+            blocks_before_cliff: 100u32.into(),
+            cliff_amount_percentage: Permill::from_percent(10),
+            linear_vesting_duration: 100u32.into(),
+        });
+        let claims: Vec<(T::MemberId, TokenBalanceOf<T>, Option<VestingScheduleParamsOf<T>>)> =
+            (0..2u32.pow(h)).map(|i| {
+                let claimer_id = if i == 0 { member_id } else { (i + 1000).saturated_into() };
+                (claimer_id, amount, vesting_schedule.clone())
+            }).collect();
+        let commitment = generate_merkle_root_helper::<T, _>(&claims).pop().unwrap();
+        let proof = MerkleProof::<<T as frame_system::Config>::Hashing>(
+            build_merkle_path_helper::<T, _>(&claims, 0)
+        );
+        let bloat_bond: JoyBalanceOf<T> = T::JoyExistentialDeposit::get();
+
+        // Make sure that proof.0.len() is h
+        assert_eq!(proof.0.len() as u32, h);
+
+        let _ = Joy::<T>::deposit_creating(&acc, bloat_bond + T::JoyExistentialDeposit::get());
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        BloatBond::<T>::set(bloat_bond);
+        let airdrop_id = Token::<T>::next_airdrop_id();
+        Token::<T>::create_airdrop(
+            token_id,
+            owner_member_id,
+            AirdropParamsOf::<T> {
+                commitment,
+                amount: amount * 2u32.pow(h).into(),
+                duration: 100u32.into(),
+            },
+        )?;
+    }: _(
+        RawOrigin::Signed(acc.clone()),
+        member_id,
+        token_id,
+        airdrop_id,
+        amount,
+        vesting_schedule,
+        proof
+    )
+    verify {
+        assert!(AccountInfoByTokenAndMember::<T>::contains_key(token_id, member_id));
+        assert!(Token::<T>::airdrop_claimed(airdrop_id, member_id));
+        assert_last_event::<T>(
+            RawEvent::AirdropClaimed(
+                token_id,
+                airdrop_id,
+                member_id,
+                amount
+            ).into()
+        );
+        // Ensure bloat_bond was transferred
+        assert_eq!(
+            Joy::<T>::usable_balance(&acc),
+            T::JoyExistentialDeposit::get()
+        );
+    }
+
+    // Worst case scenario:
+    // - all claim records removed
+    finalize_airdrop {
+        let c in 0 .. (<T as Config>::MaxAirdropClaimsRemovedPerCall::get());
+
+        let (owner_member_id, owner_account) = create_owner::<T>();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let amount: TokenBalanceOf<T> = DEFAULT_TX_AMOUNT.into();
+        let airdrop_id = Token::<T>::next_airdrop_id();
+        Token::<T>::create_airdrop(
+            token_id,
+            owner_member_id,
+            AirdropParamsOf::<T> {
+                commitment: T::Hashing::hash_of(b"commitment"),
+                amount,
+                duration: 1u32.into(),
+            },
+        )?;
+        for i in 0..c {
+            let member_id: T::MemberId = i.saturated_into();
+            AirdropClaims::<T>::insert(airdrop_id, member_id, true);
+        }
+        System::<T>::set_block_number(System::<T>::block_number() + 1u32.into());
+    }: _(
+        RawOrigin::Signed(owner_account.clone()),
+        token_id,
+        airdrop_id
+    )
+    verify {
+        assert!(!Airdrops::<T>::contains_key(token_id, airdrop_id));
+        assert!(AirdropClaims::<T>::iter_prefix(airdrop_id).next().is_none());
+        assert_eq!(
+            Token::<T>::ensure_account_data_exists(token_id, &owner_member_id).unwrap().amount,
+            DEFAULT_TOKEN_ISSUANCE.into()
+        );
+        assert_last_event::<T>(
+            RawEvent::AirdropFinalized(
+                token_id,
+                airdrop_id,
+                amount
+            ).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - voter has an active poll voting lock
+    vote_in_poll {
+        let (owner_member_id, owner_account) = create_owner::<T>();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let params = PollParamsOf::<T> {
+            options_number: T::MaxPollOptions::get(),
+            duration: 100u32.into(),
+            weighting: PollVoteWeighting::Balance,
+            metadata: vec![],
+        };
+        let previous_poll_id = Token::<T>::create_poll(token_id, owner_member_id, params.clone())?;
+        let poll_id = Token::<T>::create_poll(token_id, owner_member_id, params)?;
+        Token::<T>::vote_in_poll(
+            RawOrigin::Signed(owner_account.clone()).into(),
+            owner_member_id,
+            token_id,
+            previous_poll_id,
+            0
+        )?;
+        let option_index = T::MaxPollOptions::get() - 1;
+    }: _(
+        RawOrigin::Signed(owner_account.clone()),
+        owner_member_id,
+        token_id,
+        poll_id,
+        option_index
+    )
+    verify {
+        let weight: TokenBalanceOf<T> = DEFAULT_TOKEN_ISSUANCE.into();
+        assert_eq!(
+            Token::<T>::poll(token_id, poll_id).tallies.get(option_index as usize).copied(),
+            Some(weight)
+        );
+        assert_eq!(Token::<T>::poll_voting_lock(token_id, owner_member_id).amount, weight);
+        assert_last_event::<T>(
+            RawEvent::VoteCastInPoll(
+                token_id,
+                poll_id,
+                owner_member_id,
+                option_index,
+                weight
+            ).into()
+        );
+    }
+
     // Worst case scenario:
     // - new account needs to be created
     // - sale has a vesting schedule
@@ -512,11 +940,125 @@ benchmarks! {
     }
 
     // Worst case scenario:
-    // - participant.vesting_schedules.len() is T::MaxVestingSchedulesPerAccountPerToken
-    // - participant.split_staking_status is Some(_)
-    // - dividend_amount/payout is non-zero
-    participate_in_split {
-        let (owner_member_id, owner_account) = create_owner::<T>();
+    // - new token account is created for the bidder
+    // Worst case scenario:
+    // - MaxBatchAuctionBids bids placed, so the lowest one is evicted
+    // - new token account created
+    place_batch_auction_bid {
+        create_owner::<T>();
+        let participant = account::<T::AccountId>("participant", 0, SEED);
+        let member_id = create_member::<T>(&participant, b"participant");
+        let bloat_bond: JoyBalanceOf<T> = T::JoyExistentialDeposit::get();
+        let max_bids = T::MaxBatchAuctionBids::get();
+        let max_price = DEFAULT_SALE_UNIT_PRICE + max_bids;
+
+        let _ = Joy::<T>::deposit_creating(
+            &participant,
+            bloat_bond +
+            (DEFAULT_SALE_PURCHASE * max_price).into()
+        );
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let sale_id = init_batch_auction::<T>(token_id)?;
+        BloatBond::<T>::set(bloat_bond);
+        let mut lowest_bidder = None;
+        for i in 0..max_bids {
+            let bidder = place_batch_auction_bid::<T>(token_id, i, DEFAULT_SALE_UNIT_PRICE + i)?;
+            lowest_bidder = lowest_bidder.or(Some(bidder));
+        }
+    }: _(
+        RawOrigin::Signed(participant.clone()),
+        token_id,
+        member_id,
+        DEFAULT_SALE_PURCHASE.into(),
+        max_price.into()
+    )
+    verify {
+        assert_eq!(
+            Token::<T>::batch_auction_bid(token_id, member_id),
+            Some(BatchAuctionBid {
+                bidder_account: participant.clone(),
+                quantity: DEFAULT_SALE_PURCHASE.into(),
+                max_price: max_price.into(),
+            })
+        );
+        assert_eq!(Token::<T>::batch_auction(token_id).bids_number, max_bids);
+        if let Some(bidder) = lowest_bidder {
+            assert!(Token::<T>::batch_auction_bid(token_id, bidder).is_none());
+        }
+        assert_last_event::<T>(
+            RawEvent::BatchAuctionBidPlaced(
+                token_id,
+                sale_id,
+                member_id,
+                DEFAULT_SALE_PURCHASE.into(),
+                max_price.into()
+            ).into()
+        );
+        // Ensure JOY was locked and bloat_bond was transferred
+        assert_eq!(
+            Joy::<T>::usable_balance(&participant),
+            T::JoyExistentialDeposit::get()
+        );
+    }
+
+    // Worst case scenario:
+    // - MaxBatchAuctionBids bids, each at a different price
+    settle_batch_auction {
+        create_owner::<T>();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let sale_id = init_batch_auction::<T>(token_id)?;
+        for i in 0..T::MaxBatchAuctionBids::get() {
+            place_batch_auction_bid::<T>(token_id, i, DEFAULT_SALE_UNIT_PRICE + i)?;
+        }
+        System::<T>::set_block_number(System::<T>::block_number() + default_sale_duration::<T>());
+    }: _(RawOrigin::Signed(token_owner_account::<T>()), token_id)
+    verify {
+        assert!(Token::<T>::batch_auction(token_id).clearing.is_some());
+        assert_last_event::<T>(
+            RawEvent::BatchAuctionCleared(
+                token_id,
+                sale_id,
+                DEFAULT_SALE_UNIT_PRICE.into()
+            ).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - sale has a vesting schedule
+    // - platform_fee is set
+    // - sale.earnings_destination.is_some()
+    settle_batch_auction_bid {
+        create_owner::<T>();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let sale_id = init_batch_auction::<T>(token_id)?;
+        let member_id = place_batch_auction_bid::<T>(token_id, 0, DEFAULT_SALE_UNIT_PRICE)?;
+        SalePlatformFee::set(Permill::from_percent(10));
+        System::<T>::set_block_number(System::<T>::block_number() + default_sale_duration::<T>());
+        Token::<T>::settle_batch_auction(
+            RawOrigin::Signed(token_owner_account::<T>()).into(),
+            token_id
+        )?;
+    }: _(RawOrigin::Signed(token_owner_account::<T>()), token_id, member_id)
+    verify {
+        assert!(Token::<T>::batch_auction_bid(token_id, member_id).is_none());
+        assert_eq!(Token::<T>::batch_auction(token_id).bids_number, 0);
+        assert_last_event::<T>(
+            RawEvent::BatchAuctionBidSettled(
+                token_id,
+                sale_id,
+                member_id,
+                DEFAULT_SALE_PURCHASE.into(),
+                (DEFAULT_SALE_PURCHASE * DEFAULT_SALE_UNIT_PRICE).into()
+            ).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - participant.vesting_schedules.len() is T::MaxVestingSchedulesPerAccountPerToken
+    // - participant.split_staking_status is Some(_)
+    // - dividend_amount/payout is non-zero
+    participate_in_split {
+        let (owner_member_id, owner_account) = create_owner::<T>();
 
         let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
 
@@ -603,6 +1145,250 @@ benchmarks! {
             ).into()
         );
     }
+    // Worst case scenario:
+    // - participant already has a revenue stake with some rewards accrued
+    stake_for_revenue {
+        let (owner_member_id, owner_account) = create_owner::<T>();
+
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+
+        let participant_acc = account::<T::AccountId>("participant", 0, SEED);
+        let participant_id = create_member::<T>(&participant_acc, b"participant");
+        setup_account_with_max_number_of_locks::<T>(token_id, &participant_id, Some(DEFAULT_SPLIT_PARTICIPATION.into()));
+
+        Token::<T>::stake_for_revenue(
+            RawOrigin::Signed(participant_acc.clone()).into(),
+            token_id,
+            participant_id,
+            TokenBalanceOf::<T>::one(),
+        )?;
+        deposit_staking_revenue::<T>(token_id)?;
+    }: _(
+        RawOrigin::Signed(participant_acc.clone()),
+        token_id,
+        participant_id,
+        DEFAULT_SPLIT_PARTICIPATION.into()
+    )
+    verify {
+        assert_eq!(
+            Token::<T>::revenue_stake(token_id, participant_id).amount,
+            TokenBalanceOf::<T>::from(DEFAULT_SPLIT_PARTICIPATION) + TokenBalanceOf::<T>::one()
+        );
+        assert!(!Token::<T>::revenue_stake(token_id, participant_id).pending_rewards.is_zero());
+        assert_last_event::<T>(
+            RawEvent::TokensStakedForRevenue(
+                token_id,
+                participant_id,
+                DEFAULT_SPLIT_PARTICIPATION.into(),
+            ).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - participant's stake has some rewards accrued
+    // - some tokens are still in the unstaking cooldown
+    unstake_from_revenue {
+        let (owner_member_id, owner_account) = create_owner::<T>();
+
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+
+        let participant_acc = account::<T::AccountId>("participant", 0, SEED);
+        let participant_id = create_member::<T>(&participant_acc, b"participant");
+        setup_account_with_max_number_of_locks::<T>(token_id, &participant_id, Some(DEFAULT_SPLIT_PARTICIPATION.into()));
+
+        Token::<T>::stake_for_revenue(
+            RawOrigin::Signed(participant_acc.clone()).into(),
+            token_id,
+            participant_id,
+            TokenBalanceOf::<T>::from(DEFAULT_SPLIT_PARTICIPATION) + TokenBalanceOf::<T>::one(),
+        )?;
+        Token::<T>::unstake_from_revenue(
+            RawOrigin::Signed(participant_acc.clone()).into(),
+            token_id,
+            participant_id,
+            TokenBalanceOf::<T>::one(),
+        )?;
+        deposit_staking_revenue::<T>(token_id)?;
+    }: _(
+        RawOrigin::Signed(participant_acc.clone()),
+        token_id,
+        participant_id,
+        DEFAULT_SPLIT_PARTICIPATION.into()
+    )
+    verify {
+        let stake = Token::<T>::revenue_stake(token_id, participant_id);
+        let unstaking_until = System::<T>::block_number() + T::RevenueStakingUnstakingPeriod::get();
+        assert!(stake.amount.is_zero());
+        assert_eq!(
+            stake.unstaking_amount,
+            TokenBalanceOf::<T>::from(DEFAULT_SPLIT_PARTICIPATION) + TokenBalanceOf::<T>::one()
+        );
+        assert_eq!(stake.unstaking_until, unstaking_until);
+        assert_last_event::<T>(
+            RawEvent::TokensUnstakedFromRevenue(
+                token_id,
+                participant_id,
+                DEFAULT_SPLIT_PARTICIPATION.into(),
+                unstaking_until,
+            ).into()
+        );
+    }
+
+    claim_staking_rewards {
+        let (owner_member_id, owner_account) = create_owner::<T>();
+
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+
+        let participant_acc = account::<T::AccountId>("participant", 0, SEED);
+        let participant_id = create_member::<T>(&participant_acc, b"participant");
+        setup_account_with_max_number_of_locks::<T>(token_id, &participant_id, Some(DEFAULT_SPLIT_PARTICIPATION.into()));
+
+        Token::<T>::stake_for_revenue(
+            RawOrigin::Signed(participant_acc.clone()).into(),
+            token_id,
+            participant_id,
+            DEFAULT_SPLIT_PARTICIPATION.into(),
+        )?;
+        deposit_staking_revenue::<T>(token_id)?;
+        let rewards = Token::<T>::revenue_stake(token_id, participant_id)
+            .claimable_rewards(&Token::<T>::revenue_staking_pool(token_id));
+        let balance_pre = Joy::<T>::usable_balance(&participant_acc);
+    }: _(
+        RawOrigin::Signed(participant_acc.clone()),
+        token_id,
+        participant_id
+    )
+    verify {
+        assert_eq!(Joy::<T>::usable_balance(&participant_acc), balance_pre + rewards);
+        assert!(Token::<T>::revenue_stake(token_id, participant_id).pending_rewards.is_zero());
+        assert_last_event::<T>(
+            RawEvent::StakingRewardsClaimed(
+                token_id,
+                participant_id,
+                rewards,
+            ).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - new token account is created for the buyer
+    // - buy order crosses MaxOrderMatches sell orders at a lower price
+    //   (each trade is refunded the price difference and fully fills the sell order)
+    place_limit_order {
+        create_owner::<T>();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let max_matches = T::MaxOrderMatches::get();
+        for i in 0..max_matches {
+            place_sell_limit_order::<T>(token_id, i)?;
+        }
+        let quantity = max_matches * DEFAULT_ORDER_QUANTITY;
+        let (buyer, member_id) = create_limit_order_buyer::<T>(quantity);
+        BloatBond::<T>::set(T::JoyExistentialDeposit::get());
+        let order_id = Token::<T>::next_order_id();
+    }: _(
+        RawOrigin::Signed(buyer.clone()),
+        token_id,
+        member_id,
+        OrderSide::Buy,
+        quantity.into(),
+        (DEFAULT_ORDER_PRICE + 1).into()
+    )
+    verify {
+        let payment: JoyBalanceOf<T> = (DEFAULT_ORDER_QUANTITY * DEFAULT_ORDER_PRICE).into();
+        assert_eq!(
+            Token::<T>::account_info_by_token_and_member(token_id, member_id).amount,
+            quantity.into()
+        );
+        assert!(Token::<T>::limit_order(token_id, order_id).is_none());
+        assert_eq!(Token::<T>::order_book(token_id), OrderBookOf::<T>::default());
+        assert_last_event::<T>(
+            RawEvent::LimitOrdersMatched(
+                token_id,
+                order_id,
+                order_id - 1,
+                DEFAULT_ORDER_QUANTITY.into(),
+                DEFAULT_ORDER_PRICE.into(),
+                Token::<T>::order_book_tx_fees().mul_floor(payment),
+            ).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - buy order (JOY refunded)
+    cancel_limit_order {
+        create_owner::<T>();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let (buyer, member_id) = create_limit_order_buyer::<T>(DEFAULT_ORDER_QUANTITY);
+        let order_id = Token::<T>::next_order_id();
+        Token::<T>::place_limit_order(
+            RawOrigin::Signed(buyer.clone()).into(),
+            token_id,
+            member_id,
+            OrderSide::Buy,
+            DEFAULT_ORDER_QUANTITY.into(),
+            DEFAULT_ORDER_PRICE.into(),
+        )?;
+        let balance_pre = Joy::<T>::usable_balance(&buyer);
+    }: _(RawOrigin::Signed(buyer.clone()), token_id, member_id, order_id)
+    verify {
+        assert!(Token::<T>::limit_order(token_id, order_id).is_none());
+        assert_eq!(Token::<T>::open_orders_number(token_id, member_id), 0);
+        assert_eq!(
+            Joy::<T>::usable_balance(&buyer),
+            balance_pre + (DEFAULT_ORDER_QUANTITY * DEFAULT_ORDER_PRICE).into()
+        );
+        assert_last_event::<T>(
+            RawEvent::LimitOrderCancelled(
+                token_id,
+                order_id,
+                member_id,
+                DEFAULT_ORDER_QUANTITY.into(),
+            ).into()
+        );
+    }
+
+    // Worst case scenario:
+    // - MaxOrderMatches sell orders cross the best bid at a lower price,
+    //   left over after the buy order placement reached MaxOrderMatches matches
+    match_limit_orders {
+        create_owner::<T>();
+        let token_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let max_matches = T::MaxOrderMatches::get();
+        for i in 0..(2 * max_matches) {
+            place_sell_limit_order::<T>(token_id, i)?;
+        }
+        let quantity = 2 * max_matches * DEFAULT_ORDER_QUANTITY;
+        let (buyer, member_id) = create_limit_order_buyer::<T>(quantity);
+        let order_id = Token::<T>::next_order_id();
+        Token::<T>::place_limit_order(
+            RawOrigin::Signed(buyer).into(),
+            token_id,
+            member_id,
+            OrderSide::Buy,
+            quantity.into(),
+            (DEFAULT_ORDER_PRICE + 1).into(),
+        )?;
+    }: _(RawOrigin::Signed(token_owner_account::<T>()), token_id)
+    verify {
+        let payment: JoyBalanceOf<T> = (DEFAULT_ORDER_QUANTITY * DEFAULT_ORDER_PRICE).into();
+        assert_eq!(
+            Token::<T>::account_info_by_token_and_member(token_id, member_id).amount,
+            quantity.into()
+        );
+        assert!(Token::<T>::limit_order(token_id, order_id).is_none());
+        assert_eq!(Token::<T>::order_book(token_id), OrderBookOf::<T>::default());
+        assert_last_event::<T>(
+            RawEvent::LimitOrdersMatched(
+                token_id,
+                order_id,
+                order_id - 1,
+                DEFAULT_ORDER_QUANTITY.into(),
+                DEFAULT_ORDER_PRICE.into(),
+                Token::<T>::order_book_tx_fees().mul_floor(payment),
+            ).into()
+        );
+    }
+
     set_frozen_status {
         let pallet_frozen = true;
     }: _(RawOrigin::Root, pallet_frozen)
@@ -738,6 +1524,42 @@ benchmarks! {
         //     desired_price + T::JoyExistentialDeposit::get() - sell_tx_fee_amount,
         // );
     }
+
+    swap_on_amm {
+        let (owner_member_id, owner_account) = create_owner::<T>();
+        let token_in_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let token_out_id = issue_token::<T>(TransferPolicyParams::Permissionless)?;
+        let amount = DEFAULT_AMM_AMOUNT.into();
+        let desired_price = DEFAULT_AMM_JOY_AMOUNT.into(); // computed using supply = 0  a = 10% and b = 10%
+        let bloat_bond = BloatBond::<T>::get();
+        let participant_acc = account::<T::AccountId>("participant", 0, SEED);
+        let participant_id = create_member::<T>(&participant_acc, b"participant");
+        // enough to buy `token_in_id` and cover fees and bloat bond of the swap
+        let _ = Joy::<T>::deposit_creating(&participant_acc, desired_price + desired_price + bloat_bond + bloat_bond);
+        activate_amm::<T>(token_in_id, owner_member_id)?;
+        activate_amm::<T>(token_out_id, owner_member_id)?;
+        call_buy_on_amm::<T>(token_in_id, participant_acc.clone(), participant_id, amount)?;
+    }: _(
+        RawOrigin::Signed(participant_acc.clone()),
+        participant_id,
+        token_in_id,
+        amount,
+        token_out_id,
+        amount,
+        desired_price + desired_price,
+        Zero::zero()
+    )
+    verify {
+        assert!(
+            Token::<T>::ensure_account_data_exists(token_in_id, &participant_id).unwrap().amount.is_zero(),
+        );
+        assert_eq!(
+            Token::<T>::ensure_account_data_exists(token_out_id, &participant_id).unwrap().amount,
+            amount,
+        );
+        let provided_supply = Token::<T>::ensure_token_exists(token_out_id).unwrap().amm_curve.unwrap().provided_supply;
+        assert_eq!(provided_supply, amount);
+    }
 }
 
 #[cfg(test)]
@@ -753,6 +1575,48 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_claim_airdrop() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_claim_airdrop());
+        });
+    }
+
+    #[test]
+    fn test_finalize_airdrop() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_finalize_airdrop());
+        });
+    }
+
+    #[test]
+    fn test_vote_in_poll() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_vote_in_poll());
+        });
+    }
+
+    #[test]
+    fn test_approve() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_approve());
+        });
+    }
+
+    #[test]
+    fn test_revoke_allowance() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_revoke_allowance());
+        });
+    }
+
+    #[test]
+    fn test_transfer_from() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_transfer_from());
+        });
+    }
+
     #[test]
     fn test_dust_account() {
         build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
@@ -774,6 +1638,27 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_place_batch_auction_bid() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_place_batch_auction_bid());
+        });
+    }
+
+    #[test]
+    fn test_settle_batch_auction() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_settle_batch_auction());
+        });
+    }
+
+    #[test]
+    fn test_settle_batch_auction_bid() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_settle_batch_auction_bid());
+        });
+    }
+
     #[test]
     fn test_participate_in_split() {
         build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
@@ -788,6 +1673,48 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_stake_for_revenue() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_stake_for_revenue());
+        });
+    }
+
+    #[test]
+    fn test_unstake_from_revenue() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_unstake_from_revenue());
+        });
+    }
+
+    #[test]
+    fn test_claim_staking_rewards() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_claim_staking_rewards());
+        });
+    }
+
+    #[test]
+    fn test_place_limit_order() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_place_limit_order());
+        });
+    }
+
+    #[test]
+    fn test_cancel_limit_order() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_cancel_limit_order());
+        });
+    }
+
+    #[test]
+    fn test_match_limit_orders() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_match_limit_orders());
+        });
+    }
+
     #[test]
     fn test_burn() {
         build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
@@ -816,6 +1743,13 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_swap_on_amm() {
+        build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+            assert_ok!(Token::test_benchmark_swap_on_amm());
+        });
+    }
+
     #[test]
     fn test_update_token_constraints() {
         build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
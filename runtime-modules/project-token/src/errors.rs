@@ -55,6 +55,84 @@ decl_error! {
         /// Cannot Deissue Token with outstanding accounts
         CannotDeissueTokenWithOutstandingAccounts,
 
+        // ------ Airdrops --------------------------------------------------------------
+
+        /// Airdrop amount must be greater than zero
+        AirdropAmountIsZero,
+
+        /// Airdrop duration must be greater than zero
+        AirdropDurationIsZero,
+
+        /// Requested airdrop does not exist
+        AirdropDoesNotExist,
+
+        /// Airdrop claim period has ended
+        AirdropExpired,
+
+        /// Airdrop claim period has not ended yet
+        AirdropNotExpired,
+
+        /// Member has already claimed the airdrop
+        AirdropAlreadyClaimed,
+
+        /// Claimed amount exceeds the remaining airdrop amount
+        InsufficientAirdropRemainingAmount,
+
+        /// Cannot deissue token with pending airdrops
+        CannotDeissueTokenWithPendingAirdrops,
+
+        /// Cannot deissue token with tokens escrowed by bridge transfers
+        CannotDeissueTokenWithBridgedTokens,
+
+        // ------ Polls -----------------------------------------------------------------
+
+        /// Poll must have at least 2 and at most `MaxPollOptions` options
+        InvalidPollOptionsNumber,
+
+        /// Poll duration must be greater than zero
+        PollDurationIsZero,
+
+        /// Requested poll does not exist
+        PollDoesNotExist,
+
+        /// Poll voting period has ended
+        PollEnded,
+
+        /// Poll voting period has not ended yet
+        PollNotEnded,
+
+        /// Member has already voted in the poll
+        PollAlreadyVoted,
+
+        /// Chosen option is not one of the poll options
+        InvalidPollOption,
+
+        /// Member account has no voting power under the poll weighting
+        NoPollVotingPower,
+
+        // ------ Allowances ------------------------------------------------------------
+
+        /// Allowance amount must be greater than zero
+        AllowanceAmountIsZero,
+
+        /// Allowance expiry must be in the future
+        AllowanceExpiryInThePast,
+
+        /// Allowance for the given spender does not exist
+        AllowanceDoesNotExist,
+
+        /// Allowance has expired
+        AllowanceExpired,
+
+        /// Total amount transferred exceeds the remaining allowance
+        AllowanceExceeded,
+
+        /// Origin does not match the allowance spender
+        AllowanceSpenderOriginMismatch,
+
+        /// Account already granted `MaxAllowancesPerAccount` allowances
+        MaxAllowancesPerAccountReached,
+
         // ------Token Sale Errors ------------------------------------------------------
 
         /// The token has no upcoming sale
@@ -87,6 +165,9 @@ decl_error! {
         /// Maximum number of vesting schedules for this account-token pair was reached.
         MaxVestingSchedulesPerAccountPerTokenReached,
 
+        /// Vesting schedule by given source is not revocable
+        VestingScheduleNotRevocable,
+
         /// Previous sale was still not finalized, finalize it first.
         PreviousSaleNotFinalized,
 
@@ -114,6 +195,36 @@ decl_error! {
         /// No Sale if Amm is active
         CannotInitSaleIfAmmIsActive,
 
+        /// Tokens on a batch auction sale can only be bid for
+        CannotPurchaseOnBatchAuction,
+
+        /// The token's current sale is not a batch auction
+        NoBatchAuction,
+
+        /// Bid max price is lower than the batch auction reserve price
+        BatchAuctionBidPriceTooLow,
+
+        /// Maximum number of bids per batch auction (`MaxBatchAuctionBids`) reached
+        MaxBatchAuctionBidsReached,
+
+        /// Auction is full and the bid max price is not higher than the lowest bid's max price
+        BatchAuctionBidPriceNotAboveLowestBid,
+
+        /// No bid placed by the member in the batch auction
+        BatchAuctionBidDoesNotExist,
+
+        /// Batch auction bidding has not ended yet
+        BatchAuctionNotEnded,
+
+        /// Batch auction clearing price was already determined
+        BatchAuctionAlreadyCleared,
+
+        /// Batch auction clearing price was not determined yet
+        BatchAuctionNotCleared,
+
+        /// Batch auction still has bids to settle
+        BatchAuctionHasUnsettledBids,
+
         // ------ Revenue Split Errors ------------------------------------------------------
 
         /// Specified revenue split starting block is in the past
@@ -155,6 +266,63 @@ decl_error! {
         /// Revenue split rate cannot be 0
         RevenueSplitRateIsZero,
 
+        // ------ Order Book Errors ---------------------------------------------------------
+
+        /// Limit order quantity must be greater than zero
+        OrderQuantityIsZero,
+
+        /// Limit order price must be greater than zero
+        OrderPriceIsZero,
+
+        /// Limit order value (`quantity * price`) is lower than `MinOrderValue`
+        OrderValueTooLow,
+
+        /// Order book side already holds `MaxOrdersPerBookSide` orders
+        MaxOrdersPerBookSideReached,
+
+        /// Requested limit order does not exist
+        OrderDoesNotExist,
+
+        /// Limit order was not placed by the member
+        OrderNotOwnedByMember,
+
+        /// Best bid and best ask of the order book do not cross
+        OrderBookNotCrossed,
+
+        /// Attempt to remove an account with open limit orders
+        AttemptToRemoveAccountWithOpenOrders,
+
+        // ------ Revenue Staking Errors ----------------------------------------------------
+
+        /// Attempt to stake / unstake zero tokens in the revenue staking pool
+        RevenueStakingAmountIsZero,
+
+        /// Account's unstaked tokens amount is insufficient for revenue staking
+        InsufficientBalanceForRevenueStaking,
+
+        /// Amount to unstake exceeds the account's stake in the revenue staking pool
+        InsufficientRevenueStake,
+
+        /// Account has no staking rewards to claim
+        NoStakingRewardsToClaim,
+
+        /// Revenue cannot be deposited when no tokens are staked in the revenue staking pool
+        NoTokensStakedForRevenue,
+
+        /// Attempt to remove an account with unclaimed revenue staking rewards
+        AttemptToRemoveAccountWithUnclaimedStakingRewards,
+
+        // ------ Bridging Errors ---------------------------------------------------------
+
+        /// Token issuer did not enable bridging of the token
+        TokenBridgingNotEnabled,
+
+        /// Bridge transfer sender is not the member controller account
+        BridgeSenderNotMemberController,
+
+        /// Amount of tokens to release exceeds the amount escrowed by bridge transfers
+        InsufficientBridgedTokens,
+
         // ------ Burning ------------------------------------------------------
 
         /// Provided amount to burn is == 0
@@ -163,6 +331,9 @@ decl_error! {
         /// Amount of tokens to burn exceeds total amount of tokens owned by the account
         BurnAmountGreaterThanAccountTokensAmount,
 
+        /// Tokens staked in the revenue staking pool (or in unstaking cooldown) cannot be burned
+        CannotBurnRevenueStakedTokens,
+
         /// ------ AMM ---------------------------------------------------------
 
         /// not in AMM state
@@ -189,6 +360,30 @@ decl_error! {
         /// Attempting to sell more than amm provided supply
         NotEnoughTokenMintedByAmmForThisSale,
 
+        /// Piecewise linear curve breakpoints must start at zero supply and be strictly increasing
+        InvalidAmmCurveBreakpoints,
+
+        /// Number of piecewise linear curve breakpoints exceeds the limit
+        MaxAmmCurveBreakpointsExceeded,
+
+        /// Attempting to buy the whole (or more than) constant product curve token reserve
+        InsufficientAmmTokenReserve,
+
+        /// Attempting to swap a token for itself
+        SwapTokensMustDiffer,
+
+        /// Swap input and output amounts must be non-zero
+        SwapAmountIsZero,
+
+        /// Only tokens with permissionless transfer policy can be swapped on AMM
+        CannotSwapPermissionedToken,
+
+        /// JOY amount required to buy the output token exceeds the provided limit
+        MaxJoyIntermediateExceeded,
+
+        /// JOY amount received from selling the input token is below the provided limit
+        MinJoyIntermediateNotReached,
+
         /// -------- Patronage --------------------------------------------------
 
         /// Target Rate is higher than current patronage rate
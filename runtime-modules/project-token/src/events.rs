@@ -1,8 +1,9 @@
 #![allow(clippy::unused_unit)]
 
 use crate::types::{
-    AmmCurveOf, JoyBalanceOf, RevenueSplitId, TokenConstraintsOf, TokenIssuanceParametersOf,
-    TokenSaleId, TokenSaleOf, TransferPolicyOf, ValidatedTransfersOf, YearlyRate,
+    AirdropId, AirdropOf, AllowanceSpenderOf, AmmCurveOf, JoyBalanceOf, OrderId, OrderSide, PollId,
+    PollOf, RevenueSplitId, TokenConstraintsOf, TokenIssuanceParametersOf, TokenSaleId,
+    TokenSaleOf, TransferPolicyOf, ValidatedTransfersOf, VestingSource, YearlyRate,
 };
 use common::MembershipTypes;
 use frame_support::decl_event;
@@ -22,7 +23,10 @@ decl_event! {
         ValidatedTransfers = ValidatedTransfersOf<T>,
         TokenSale = TokenSaleOf<T>,
         AmmCurve = AmmCurveOf<T>,
-        TokenConstraints = TokenConstraintsOf<T>
+        TokenConstraints = TokenConstraintsOf<T>,
+        AllowanceSpender = AllowanceSpenderOf<T>,
+        Airdrop = AirdropOf<T>,
+        Poll = PollOf<T>
 
     {
         /// Token amount is transferred from src to dst
@@ -43,6 +47,87 @@ decl_event! {
         /// - transfer's metadata
         TokenAmountTransferredByIssuer(TokenId, MemberId, ValidatedTransfers, Vec<u8>),
 
+        /// Airdrop created by the token issuer
+        /// Params:
+        /// - token identifier
+        /// - airdrop identifier
+        /// - airdrop data
+        AirdropCreated(TokenId, AirdropId, Airdrop),
+
+        /// Airdrop claimed by a recipient
+        /// Params:
+        /// - token identifier
+        /// - airdrop identifier
+        /// - recipient member id
+        /// - claimed amount
+        AirdropClaimed(TokenId, AirdropId, MemberId, Balance),
+
+        /// Expired airdrop finalized
+        /// Params:
+        /// - token identifier
+        /// - airdrop identifier
+        /// - unclaimed amount returned to the issuer account (burned if the account was removed),
+        ///   zero when the call only removes the remaining claim records
+        AirdropFinalized(TokenId, AirdropId, Balance),
+
+        /// Token holder poll opened by the token issuer
+        /// Params:
+        /// - token identifier
+        /// - poll identifier
+        /// - poll data
+        /// - poll metadata
+        PollCreated(TokenId, PollId, Poll, Vec<u8>),
+
+        /// Vote cast in a token holder poll
+        /// Params:
+        /// - token identifier
+        /// - poll identifier
+        /// - voter member id
+        /// - chosen option index
+        /// - vote weight
+        VoteCastInPoll(TokenId, PollId, MemberId, u32, Balance),
+
+        /// Ended token holder poll removed together with its votes
+        /// Params:
+        /// - token identifier
+        /// - poll identifier
+        PollRemoved(TokenId, PollId),
+
+        /// Revocable vesting schedule terminated by the token issuer
+        /// Params:
+        /// - token identifier
+        /// - holder member id
+        /// - vesting schedule source
+        /// - issuer member id the unvested amount was returned to
+        /// - unvested amount returned to the issuer
+        VestingScheduleRevoked(TokenId, MemberId, VestingSource, MemberId, Balance),
+
+        /// Allowance set by token holder
+        /// Params:
+        /// - token identifier
+        /// - owner member id
+        /// - spender
+        /// - allowed amount
+        /// - optional expiry block
+        AllowanceUpdated(TokenId, MemberId, AllowanceSpender, Balance, Option<BlockNumber>),
+
+        /// Allowance revoked by token holder
+        /// Params:
+        /// - token identifier
+        /// - owner member id
+        /// - spender
+        AllowanceRevoked(TokenId, MemberId, AllowanceSpender),
+
+        /// Token amount transferred by spender on behalf of the holder
+        /// Params:
+        /// - token identifier
+        /// - source (holder) member id
+        /// - spender
+        /// - map containing validated outputs
+        /// - remaining allowance amount
+        /// - transfer's metadata
+        TokenAmountTransferredFrom(TokenId, MemberId, AllowanceSpender, ValidatedTransfers, Balance, Vec<u8>),
+
         /// Patronage rate decreased
         /// Params:
         /// - token identifier
@@ -87,6 +172,55 @@ decl_event! {
         /// - amount unstaked
         RevenueSplitLeft(TokenId, MemberId, Balance),
 
+        /// Revenue deposited into the token's revenue staking pool
+        /// Params:
+        /// - token identifier
+        /// - revenue allocated to the stakers (JOY)
+        /// - total amount of tokens staked in the pool
+        StakingRevenueDeposited(TokenId, JoyBalance, Balance),
+
+        /// Member staked tokens in the revenue staking pool
+        /// Params:
+        /// - token identifier
+        /// - staker's member id
+        /// - amount staked
+        TokensStakedForRevenue(TokenId, MemberId, Balance),
+
+        /// Member unstaked tokens from the revenue staking pool
+        /// Params:
+        /// - token identifier
+        /// - staker's member id
+        /// - amount unstaked
+        /// - block at which the unstaked tokens get unlocked
+        TokensUnstakedFromRevenue(TokenId, MemberId, Balance, BlockNumber),
+
+        /// Member claimed revenue staking rewards
+        /// Params:
+        /// - token identifier
+        /// - staker's member id
+        /// - rewards amount (JOY)
+        StakingRewardsClaimed(TokenId, MemberId, JoyBalance),
+
+        /// Token bridging enabled / disabled by the issuer
+        /// Params:
+        /// - token identifier
+        /// - whether bridging is enabled
+        TokenBridgingUpdated(TokenId, bool),
+
+        /// Tokens escrowed by an outbound bridge transfer
+        /// Params:
+        /// - token identifier
+        /// - member id of the account the tokens were escrowed from
+        /// - amount escrowed
+        TokensEscrowedForBridge(TokenId, MemberId, Balance),
+
+        /// Escrowed tokens released by an inbound (or reverted) bridge transfer
+        /// Params:
+        /// - token identifier
+        /// - member id of the account the tokens were released into
+        /// - amount released
+        BridgedTokensReleased(TokenId, MemberId, Balance),
+
         /// Member joined whitelist
         /// Params:
         /// - token identifier
@@ -137,6 +271,45 @@ decl_event! {
         /// - buyer's member id
         TokensPurchasedOnSale(TokenId, TokenSaleId, Balance, MemberId),
 
+        /// Token Sale initialized as a batch auction
+        /// Params:
+        /// - token id
+        /// - token sale id
+        BatchAuctionInitialized(TokenId, TokenSaleId),
+
+        /// Bid placed (or replaced) in a batch auction
+        /// Params:
+        /// - token id
+        /// - token sale id
+        /// - bidder's member id
+        /// - quantity of tokens requested
+        /// - max price per token (in JOY)
+        BatchAuctionBidPlaced(TokenId, TokenSaleId, MemberId, Balance, JoyBalance),
+
+        /// Lowest batch auction bid evicted by a higher bid placed in a full auction,
+        /// with the locked JOY refunded
+        /// Params:
+        /// - token id
+        /// - token sale id
+        /// - evicted bidder's member id
+        BatchAuctionBidEvicted(TokenId, TokenSaleId, MemberId),
+
+        /// Batch auction clearing price determined
+        /// Params:
+        /// - token id
+        /// - token sale id
+        /// - clearing price per token (in JOY)
+        BatchAuctionCleared(TokenId, TokenSaleId, JoyBalance),
+
+        /// Batch auction bid settled at the clearing price, with the remaining locked JOY refunded
+        /// Params:
+        /// - token id
+        /// - token sale id
+        /// - bidder's member id
+        /// - amount of tokens purchased
+        /// - amount of JOY paid
+        BatchAuctionBidSettled(TokenId, TokenSaleId, MemberId, Balance, JoyBalance),
+
         /// Token Sale Finalized
         /// Params:
         /// - token id
@@ -180,6 +353,45 @@ decl_event! {
         /// - amount of JOY withdrawn from curve treasury
         TokensSoldOnAmm(TokenId, MemberId, Balance, JoyBalance),
 
+        /// Tokens Swapped on AMM
+        /// Params:
+        /// - member id
+        /// - id of the token sold
+        /// - amount of sold CRT burned
+        /// - id of the token bought
+        /// - amount of bought CRT minted
+        /// - amount of JOY withdrawn from the sold token curve treasury
+        /// - amount of JOY deposited into the bought token curve treasury
+        TokensSwappedOnAmm(MemberId, TokenId, Balance, TokenId, Balance, JoyBalance, JoyBalance),
+
+        /// Limit order placed in the token order book
+        /// Params:
+        /// - token id
+        /// - order id
+        /// - member id
+        /// - order side
+        /// - quantity of tokens
+        /// - price (in JOY) per token
+        LimitOrderPlaced(TokenId, OrderId, MemberId, OrderSide, Balance, JoyBalance),
+
+        /// Limit order cancelled
+        /// Params:
+        /// - token id
+        /// - order id
+        /// - member id
+        /// - unfilled quantity of tokens
+        LimitOrderCancelled(TokenId, OrderId, MemberId, Balance),
+
+        /// Crossing limit orders matched
+        /// Params:
+        /// - token id
+        /// - buy order id
+        /// - sell order id
+        /// - quantity of tokens traded
+        /// - price (in JOY) per token
+        /// - platform fee (in JOY) burned
+        LimitOrdersMatched(TokenId, OrderId, OrderId, Balance, JoyBalance, JoyBalance),
+
         /// AMM deactivated
         /// Params:
         /// - token id
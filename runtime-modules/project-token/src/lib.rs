@@ -29,16 +29,18 @@ use frame_support::{
     decl_module, decl_storage,
     dispatch::{fmt::Debug, marker::Copy, DispatchError, DispatchResult},
     ensure,
-    traits::{Currency, ExistenceRequirement, Get},
-    PalletId,
+    traits::{Currency, ExistenceRequirement, Get, StorageVersion},
+    IterableStorageDoubleMap, PalletId,
 };
 use frame_system::{ensure_root, ensure_signed};
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::{AtLeast32BitUnsigned, One, Saturating, Zero};
+use sp_io::KillStorageResult;
 use sp_runtime::{
-    traits::{AccountIdConversion, CheckedAdd},
+    traits::{AccountIdConversion, CheckedAdd, CheckedMul},
     Permill,
 };
+use sp_std::cmp::{max, min};
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::convert::TryInto;
 use sp_std::vec;
@@ -49,6 +51,7 @@ use storage::UploadParameters;
 mod benchmarking;
 mod errors;
 mod events;
+pub mod migrations;
 mod tests;
 pub mod traits;
 pub mod types;
@@ -56,6 +59,7 @@ mod utils;
 
 // crate imports
 use common::bloat_bond::{RepayableBloatBond, RepayableBloatBondOf};
+use common::bridge::CreatorTokenBridge;
 use common::costs::{
     burn_from_usable, has_sufficient_balance_for_fees, has_sufficient_balance_for_payment, pay_fee,
 };
@@ -70,6 +74,9 @@ pub use weights::WeightInfo;
 
 type WeightInfoToken<T> = <T as Config>::WeightInfo;
 
+// Amm curve kinds: the stored linear amm curves are converted into `AmmCurveKind::Linear`.
+const CURRENT_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 /// Pallet Configuration
 pub trait Config:
     frame_system::Config + balances::Config + storage::Config + membership::Config
@@ -121,6 +128,30 @@ pub trait Config:
 
     /// Max outputs number for a transfer
     type MaxOutputs: Get<u32>;
+
+    /// Max number of options in a token holder poll
+    type MaxPollOptions: Get<u32>;
+
+    /// Max number of bids in a batch auction sale
+    type MaxBatchAuctionBids: Get<u32>;
+
+    /// Number of blocks the tokens unstaked from a revenue staking pool remain locked for
+    type RevenueStakingUnstakingPeriod: Get<Self::BlockNumber>;
+
+    /// Max number of open orders on each side of a token order book
+    type MaxOrdersPerBookSide: Get<u32>;
+
+    /// Max number of crossing order matches executed in a single call
+    type MaxOrderMatches: Get<u32>;
+
+    /// Max number of allowances granted by a single token account
+    type MaxAllowancesPerAccount: Get<u32>;
+
+    /// Max number of airdrop claim records removed by a single `finalize_airdrop` call
+    type MaxAirdropClaimsRemovedPerCall: Get<u32>;
+
+    /// Max number of poll votes removed by a single poll finalization
+    type MaxPollVotesRemovedPerCall: Get<u32>;
 }
 
 decl_storage! { generate_storage_info
@@ -169,6 +200,114 @@ decl_storage! { generate_storage_info
         /// Minimum slope parameters allowed for AMM curve
         pub MinAmmSlopeParameter get(fn min_amm_slope_parameter) config(): TokenBalanceOf<T> = TokenBalanceOf::<T>::from(1_000_000_u32);
 
+        /// map TokenId => batch auction mode of the token's current sale (if any)
+        pub BatchAuctions get(fn batch_auction):
+        map
+            hasher(blake2_128_concat) T::TokenId => BatchAuctionOf<T>;
+
+        /// Double map TokenId x MemberId => bid placed in the token's batch auction
+        pub BatchAuctionBids get(fn batch_auction_bid):
+        double_map
+            hasher(blake2_128_concat) T::TokenId,
+            hasher(blake2_128_concat) T::MemberId => Option<BatchAuctionBidOf<T>>;
+
+        /// map TokenId => continuous revenue staking pool of the token
+        pub RevenueStakingPools get(fn revenue_staking_pool):
+        map
+            hasher(blake2_128_concat) T::TokenId => RevenueStakingPoolOf<T>;
+
+        /// Double map TokenId x MemberId => member's stake in the token's revenue staking pool
+        pub RevenueStakes get(fn revenue_stake):
+        double_map
+            hasher(blake2_128_concat) T::TokenId,
+            hasher(blake2_128_concat) T::MemberId => RevenueStakeOf<T>;
+
+        /// Order book limit order transaction fee percentage
+        pub OrderBookTxFees get(fn order_book_tx_fees) config(): Permill = Permill::from_perthousand(3_u32);
+
+        /// Minimum value (`quantity * price`, in JOY) of a limit order
+        pub MinOrderValue get(fn min_order_value) config(): JoyBalanceOf<T>;
+
+        /// Limit order Id nonce
+        pub NextOrderId get(fn next_order_id): OrderId;
+
+        /// Double map TokenId x OrderId => open limit order
+        pub LimitOrders get(fn limit_order):
+        double_map
+            hasher(blake2_128_concat) T::TokenId,
+            hasher(blake2_128_concat) OrderId => Option<LimitOrderOf<T>>;
+
+        /// map TokenId => order book of the token's open limit orders
+        pub OrderBooks get(fn order_book):
+        map
+            hasher(blake2_128_concat) T::TokenId => OrderBookOf<T>;
+
+        /// Double map TokenId x MemberId => number of the member's open limit orders
+        pub OpenOrdersNumber get(fn open_orders_number):
+        double_map
+            hasher(blake2_128_concat) T::TokenId,
+            hasher(blake2_128_concat) T::MemberId => u32;
+
+        /// map TokenId => bridging status of the token
+        pub TokenBridgingById get(fn token_bridging):
+        map
+            hasher(blake2_128_concat) T::TokenId => TokenBridgingOf<T>;
+
+        /// Airdrop Id nonce
+        pub NextAirdropId get(fn next_airdrop_id): AirdropId;
+
+        /// Double map TokenId x AirdropId => pending airdrop
+        pub Airdrops get(fn airdrop):
+        double_map
+            hasher(blake2_128_concat) T::TokenId,
+            hasher(blake2_128_concat) AirdropId => AirdropOf<T>;
+
+        /// Double map AirdropId x MemberId => whether the member already claimed the airdrop
+        pub AirdropClaims get(fn airdrop_claimed):
+        double_map
+            hasher(blake2_128_concat) AirdropId,
+            hasher(blake2_128_concat) T::MemberId => bool;
+
+        /// Poll Id nonce
+        pub NextPollId get(fn next_poll_id): PollId;
+
+        /// Double map (TokenId, holder MemberId) x vesting transfer id => issuer MemberId
+        /// of the revocable vesting schedules created through issuer transfers
+        pub RevocableVestingSchedules get(fn revocable_vesting_schedule_issuer):
+        double_map
+            hasher(blake2_128_concat) (T::TokenId, T::MemberId),
+            hasher(blake2_128_concat) u64 => T::MemberId;
+
+        /// Double map TokenId x PollId => token holder poll
+        pub Polls get(fn poll):
+        double_map
+            hasher(blake2_128_concat) T::TokenId,
+            hasher(blake2_128_concat) PollId => PollOf<T>;
+
+        /// Double map PollId x MemberId => vote cast by the member
+        pub PollVotes get(fn poll_vote):
+        double_map
+            hasher(blake2_128_concat) PollId,
+            hasher(blake2_128_concat) T::MemberId => PollVoteOf<T>;
+
+        /// Double map TokenId x MemberId => tokens locked by the member's poll votes
+        pub PollVotingLocks get(fn poll_voting_lock):
+        double_map
+            hasher(blake2_128_concat) T::TokenId,
+            hasher(blake2_128_concat) T::MemberId => PollVotingLockOf<T>;
+
+        /// Double map (TokenId, owner MemberId) x spender => allowance granted by the owner
+        pub Allowances get(fn allowance):
+        double_map
+            hasher(blake2_128_concat) (T::TokenId, T::MemberId),
+            hasher(blake2_128_concat) AllowanceSpenderOf<T> => TokenAllowanceOf<T>;
+
+        /// Double map TokenId x MemberId => number of allowances granted by the member
+        pub AllowancesNumber get(fn allowances_number):
+        double_map
+            hasher(blake2_128_concat) T::TokenId,
+            hasher(blake2_128_concat) T::MemberId => u32;
+
         /// Current frozen state.
         pub PalletFrozen get(fn pallet_frozen) : bool;
     }
@@ -273,6 +412,228 @@ decl_module! {
             Ok(())
         }
 
+        /// Allow `spender` to transfer up to `amount` of `token_id` tokens from `owner_member_id`
+        /// account, replacing any previous allowance for the same spender.
+        ///
+        /// Preconditions:
+        /// - origin signer must be `owner_member_id` controller account
+        /// - token by `token_id` must exists
+        /// - account of `owner_member_id` must exist for `token_id`
+        /// - `amount` must be > 0
+        /// - `expires_at`, if provided, must not be in the past
+        /// - if no allowance for `spender` exists yet:
+        ///   - `owner_member_id` must have granted less than `MaxAllowancesPerAccount` allowances
+        ///   - sender must have enough JOYs to cover the bloat bond
+        ///
+        /// Postconditions:
+        /// - allowance for `spender` set to `amount` with `expires_at` expiry
+        /// - if no allowance for `spender` existed: bloat bond transferred from sender's JOY
+        ///   balance into the treasury account
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::approve()]
+        pub fn approve(
+            origin,
+            owner_member_id: T::MemberId,
+            token_id: T::TokenId,
+            spender: AllowanceSpenderOf<T>,
+            amount: TokenBalanceOf<T>,
+            expires_at: Option<T::BlockNumber>
+        ) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            let sender = T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                owner_member_id
+            )?;
+
+            Self::ensure_token_exists(token_id)?;
+            Self::ensure_account_data_exists(token_id, &owner_member_id)?;
+
+            ensure!(!amount.is_zero(), Error::<T>::AllowanceAmountIsZero);
+
+            if let Some(expires_at) = expires_at {
+                ensure!(expires_at >= Self::current_block(), Error::<T>::AllowanceExpiryInThePast);
+            }
+
+            let existing_allowance =
+                Allowances::<T>::try_get((token_id, owner_member_id), &spender).ok();
+
+            if existing_allowance.is_none() {
+                ensure!(
+                    Self::allowances_number(token_id, owner_member_id) <
+                        T::MaxAllowancesPerAccount::get(),
+                    Error::<T>::MaxAllowancesPerAccountReached
+                );
+
+                ensure!(
+                    has_sufficient_balance_for_fees::<T>(&sender, Self::bloat_bond()),
+                    Error::<T>::InsufficientJoyBalance
+                );
+            }
+
+            // == MUTATION SAFE ==
+
+            // Replacing an existing allowance keeps its bloat bond
+            let bloat_bond = match existing_allowance {
+                Some(allowance) => allowance.bloat_bond,
+                None => {
+                    let bloat_bond = Self::pay_bloat_bond(&sender)?;
+                    AllowancesNumber::<T>::mutate(token_id, owner_member_id, |allowances_number| {
+                        *allowances_number = allowances_number.saturating_add(1);
+                    });
+                    bloat_bond
+                }
+            };
+
+            Allowances::<T>::insert(
+                (token_id, owner_member_id),
+                spender.clone(),
+                TokenAllowanceOf::<T> { amount, expires_at, bloat_bond }
+            );
+
+            Self::deposit_event(RawEvent::AllowanceUpdated(
+                token_id,
+                owner_member_id,
+                spender,
+                amount,
+                expires_at
+            ));
+            Ok(())
+        }
+
+        /// Revoke allowance previously granted to `spender` by `owner_member_id`
+        ///
+        /// Preconditions:
+        /// - origin signer must be `owner_member_id` controller account
+        /// - allowance for `spender` must exist
+        ///
+        /// Postconditions:
+        /// - allowance for `spender` removed
+        /// - allowance bloat bond refunded to `owner_member_id` controller account
+        ///   (or `bloat_bond.repayment_restricted_to` account)
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::revoke_allowance()]
+        pub fn revoke_allowance(
+            origin,
+            owner_member_id: T::MemberId,
+            token_id: T::TokenId,
+            spender: AllowanceSpenderOf<T>
+        ) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            let sender = T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                owner_member_id
+            )?;
+
+            let allowance = Self::ensure_allowance_exists(token_id, &owner_member_id, &spender)?;
+
+            // == MUTATION SAFE ==
+
+            Self::remove_allowance(token_id, owner_member_id, &spender, &allowance, &sender)?;
+
+            Self::deposit_event(RawEvent::AllowanceRevoked(token_id, owner_member_id, spender));
+            Ok(())
+        }
+
+        /// Allow `spender` to transfer from `src_member_id` account to the various `outputs`
+        /// beneficiaries, within the allowance granted by `src_member_id`.
+        ///
+        /// Preconditions:
+        /// - origin signer must be the `spender` account or the `spender` member controller account
+        /// - allowance for `spender` must exist, must not be expired and must cover the total
+        ///   transferred amount
+        /// - token by `token_id` must exists
+        /// - account of `src_member_id` must exist for `token_id`
+        /// - sender must have enough JOYs to cover the total bloat bond required in case of
+        ///   destination(s) not existing.
+        /// - source account must have enough transferrable token funds to cover all the transfer(s)
+        /// - `outputs` must designate existing destination(s) for "Permissioned" transfers.
+        //
+        /// Postconditions:
+        /// - source account's tokens amount is decreased by the total transferred amount
+        /// - allowance decreased by the total transferred amount (removed if exhausted,
+        ///   refunding its bloat bond to `src_member_id` controller account)
+        /// - total bloat bond transferred from sender's JOY balance into the treasury account
+        ///   in case destination(s) have been added to storage
+        /// - `outputs.beneficiary` tokens amount increased by `amount`
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (T + M)` where:
+        /// - `T` is the length of `outputs`
+        /// - `M` is the size of `metadata` in kilobytes
+        /// - DB:
+        ///   - `O(T)` - from the the generated weights
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::transfer_from(outputs.len() as u32, to_kb(metadata.len() as u32))]
+        pub fn transfer_from(
+            origin,
+            spender: AllowanceSpenderOf<T>,
+            src_member_id: T::MemberId,
+            token_id: T::TokenId,
+            outputs: TransferOutputsOf<T>,
+            metadata: Vec<u8>
+        ) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            let sender = Self::ensure_allowance_spender_origin(origin, &spender)?;
+
+            let allowance = Self::ensure_allowance_exists(token_id, &src_member_id, &spender)?;
+            ensure!(!allowance.is_expired(Self::current_block()), Error::<T>::AllowanceExpired);
+
+            let transfers: TransfersOf<T> = outputs.into();
+            ensure!(transfers.total_amount() <= allowance.amount, Error::<T>::AllowanceExceeded);
+
+            // Currency transfer preconditions
+            let validated_transfers = Self::ensure_can_transfer(token_id, &sender, &src_member_id, transfers, false)?;
+
+            let src_controller = T::MembershipInfoProvider::controller_account_id(src_member_id)?;
+
+            // == MUTATION SAFE ==
+
+            Self::do_transfer(token_id, &sender, &src_member_id, &validated_transfers)?;
+
+            let mut remaining_allowance = allowance;
+            remaining_allowance.decrease_amount_by(validated_transfers.total_amount());
+            if remaining_allowance.amount.is_zero() {
+                Self::remove_allowance(
+                    token_id,
+                    src_member_id,
+                    &spender,
+                    &remaining_allowance,
+                    &src_controller
+                )?;
+            } else {
+                Allowances::<T>::insert((token_id, src_member_id), &spender, &remaining_allowance);
+            }
+
+            Self::deposit_event(RawEvent::TokenAmountTransferredFrom(
+                token_id,
+                src_member_id,
+                spender,
+                validated_transfers,
+                remaining_allowance.amount,
+                metadata
+            ));
+            Ok(())
+        }
+
         /// Burn tokens from specified account
         ///
         /// Preconditions:
@@ -331,11 +692,17 @@ decl_module! {
                 Error::<T>::BurnAmountGreaterThanAccountTokensAmount
             );
 
+            // Ensure burn amount doesn't include tokens locked by revenue staking
+            let now = Self::current_block();
+            ensure!(
+                account_info.amount.saturating_sub(Self::revenue_stake(token_id, member_id).locks(now)) >= amount,
+                Error::<T>::CannotBurnRevenueStakedTokens
+            );
+
             // Ensure token supply can be modified
             token_info.ensure_can_modify_supply::<T>()?;
 
             // == MUTATION SAFE ==
-            let now = Self::current_block();
 
             // Burn tokens from the account
             AccountInfoByTokenAndMember::<T>::try_mutate(token_id, member_id, |account| {
@@ -362,18 +729,27 @@ decl_module! {
         ///   controller account
         /// - `token_id` x `member_id` account must be an empty account
         ///   (`account_data.amount` == 0)
+        /// - `token_id` x `member_id` account must have no unclaimed revenue staking rewards
+        /// - `token_id` x `member_id` account must have no open limit orders
         /// Postconditions:
         /// - Account information for `token_id` x `member_id` removed from storage
+        /// - Poll voting lock for `token_id` x `member_id` removed from storage
+        /// - Revenue stake for `token_id` x `member_id` removed from storage
+        /// - Allowances granted by `member_id` for `token_id` removed from storage
+        ///   and their bloat bonds refunded to `member_id` controller account
+        ///   (or `bloat_bond.repayment_restricted_to` accounts)
         /// - bloat bond refunded to `member_id` controller account
         ///   (or `bloat_bond.repayment_restricted_to` account)
         ///
         /// <weight>
         ///
-        /// `O (1)`
+        /// `O (A)` where:
+        /// - `A` is the number of allowances granted by the account
+        ///   (bounded by `MaxAllowancesPerAccount`)
         /// - DB:
-        ///   - `O(1)` - doesn't depend on the state or parameters
+        ///   - `O(A)` - from the the generated weights
         /// # </weight>
-        #[weight = WeightInfoToken::<T>::dust_account()]
+        #[weight = WeightInfoToken::<T>::dust_account(T::MaxAllowancesPerAccount::get())]
         pub fn dust_account(origin, token_id: T::TokenId, member_id: T::MemberId) -> DispatchResult {
             Self::ensure_unfrozen_state()?;
 
@@ -390,8 +766,29 @@ decl_module! {
                 &account_to_remove_info,
             )?;
 
+            // An empty account has no revenue stake left, but may still hold its rewards
+            ensure!(
+                Self::revenue_stake(token_id, member_id).pending_rewards.is_zero(),
+                Error::<T>::AttemptToRemoveAccountWithUnclaimedStakingRewards
+            );
+
+            // Open orders pay out to / refund into the account
+            ensure!(
+                Self::open_orders_number(token_id, member_id).is_zero(),
+                Error::<T>::AttemptToRemoveAccountWithOpenOrders
+            );
+
             // == MUTATION SAFE ==
             AccountInfoByTokenAndMember::<T>::remove(token_id, member_id);
+            PollVotingLocks::<T>::remove(token_id, member_id);
+            RevenueStakes::<T>::remove(token_id, member_id);
+            #[allow(deprecated)]
+            RevocableVestingSchedules::<T>::remove_prefix((token_id, member_id), None);
+
+            for (_, allowance) in Allowances::<T>::drain_prefix((token_id, member_id)) {
+                allowance.bloat_bond.repay::<T>(&treasury, &member_controller, false)?;
+            }
+            AllowancesNumber::<T>::remove(token_id, member_id);
 
             TokenInfoById::<T>::mutate(token_id, |token_info| {
                 token_info.decrement_accounts_number();
@@ -472,69 +869,282 @@ decl_module! {
             Ok(())
         }
 
-        /// Purchase tokens on active token sale.
-        ///
+        /// Claim tokens allocated to `member_id` by an issuer airdrop
         /// Preconditions:
-        /// - token by `token_id` must exist
-        /// - token by `token_id` must be in OfferingState::Sale
-        /// - `amount` cannot exceed number of tokens remaining on sale
-        /// - `origin` signer must be controller account of `member_id` member
-        /// - sender's available JOY balance must be:
-        ///   - >= `joy_existential_deposit + amount * sale.unit_price`
-        ///     if AccountData already exist
-        ///   - >= `joy_existential_deposit + amount * sale.unit_price + bloat_bond`
-        ///     if AccountData does not exist
-        /// - let `fee_amount` be `sale_platform_fee.mul_floor(amount * sale.unit_price)`
-        /// - if `sale.earnings_destination.is_some()` and `sale.earnings_destination` account has
-        ///   zero balance:
-        ///   - the amount to be transferred from `sender` to `sale.earnings_destination`,
-        ///     which is equal to `amount * sale.unit_price - fee_amount`, must be greater than
-        ///     `joy_existential_deposit`
-        /// - total number of tokens already purchased by the member on the current sale
-        ///   PLUS `amount` must not exceed sale's purchase cap per member
-        /// - if Permissioned token:
-        ///   - AccountInfoByTokenAndMember(token_id, &member_id) must exist
-        /// - if `sale.vesting_schedule.is_some()`:
-        ///   - number of sender account's ongoing vesting schedules
-        ///     must be < MaxVestingSchedulesPerAccountPerToken
+        /// - `origin` signer must be a controller account of `member_id`
+        /// - airdrop by `token_id` x `airdrop_id` must exist and must not be expired
+        /// - `member_id` must not have claimed the airdrop already
+        /// - merkle proof of `(member_id, amount, vesting_schedule)` must be valid
+        /// - `amount` must not exceed airdrop remaining amount
+        /// - sender must be able to cover the bloat bond if the account does not exist
         ///
         /// Postconditions:
-        /// - if `sale.earnings_destination.is_some()`:
-        ///   - `amount * sale.unit_price - fee_amount` JOY tokens are transfered from `sender`
-        ///     to `sale.earnings_destination`
-        ///   - `fee_amount` JOY is slashed from `sender` balance
-        /// - if `sale.earnings_destination.is_none()`:
-        ///   - `amount * sale.unit_price` JOY is slashed from `sender` balance
-        /// - if new token account created: `bloat_bond` transferred from `sender` to treasury
-        /// - if `sale.vesting_schedule.is_some()`:
-        ///   - if buyer has no `vesting_schedule` related to the current sale:
-        ///     - a new vesting schedule (`sale.get_vesting_schedule(purchase_amount)`) is added to
-        ///       buyer's `vesing_schedules`
-        ///     - some finished vesting schedule is removed from buyer's account_data in case the
-        ///       number of buyer's vesting_schedules was == MaxVestingSchedulesPerAccountPerToken
-        ///   - if buyer already has a `vesting_schedule` related to the current sale:
-        ///     - current vesting schedule's `cliff_amount` is increased by
-        ///       `sale.get_vesting_schedule(purchase_amount).cliff_amount`
-        ///     - current vesting schedule's `post_cliff_total_amount` is increased by
-        ///       `sale.get_vesting_schedule(purchase_amount).post_cliff_total_amount`
-        /// - if `sale.vesting_schedule.is_none()`:
-        ///   - buyer's account token amount increased by `amount`
-        /// - if `token_data.sale.quantity_left - amount == 0` and `sale.auto_finalize` is `true`
-        ///   `token_data.sale` is set to None, otherwise `token_data.sale.quantity_left` is
-        ///   decreased by `amount` and `token_data.sale.funds_collected` in increased by
-        ///   `amount * sale.unit_price`
+        /// - account for `member_id` created if necessary, with bloat bond transferred from
+        ///   sender to treasury account
+        /// - `amount` credited to the account, subject to `vesting_schedule` if provided
+        /// - airdrop remaining amount decreased by `amount`
+        /// - claim recorded for `member_id`
         ///
         /// <weight>
         ///
         /// ## Weight
-        /// `O (1)`
+        /// `O (H)` where:
+        /// - `H` is the length of `proof.0`
         /// - DB:
         ///   - `O(1)` - doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoToken::<T>::purchase_tokens_on_sale()]
-        pub fn purchase_tokens_on_sale(
+        #[weight = WeightInfoToken::<T>::claim_airdrop(
+            proof.0.len() as u32
+        )]
+        pub fn claim_airdrop(
             origin,
-            token_id: T::TokenId,
+            member_id: T::MemberId,
+            token_id: T::TokenId,
+            airdrop_id: AirdropId,
+            amount: TokenBalanceOf<T>,
+            vesting_schedule: Option<VestingScheduleParamsOf<T>>,
+            proof: MerkleProofOf<T>
+        ) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            let sender = T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+
+            let airdrop = Self::ensure_airdrop_exists(token_id, airdrop_id)?;
+            ensure!(!airdrop.is_expired(Self::current_block()), Error::<T>::AirdropExpired);
+            ensure!(!Self::airdrop_claimed(airdrop_id, member_id), Error::<T>::AirdropAlreadyClaimed);
+
+            proof.verify::<T, _>(&(member_id, amount, vesting_schedule.clone()), airdrop.commitment)?;
+
+            ensure!(amount <= airdrop.remaining_amount, Error::<T>::InsufficientAirdropRemainingAmount);
+
+            // airdrops are issuer transfers: destination accounts may be created under any policy
+            let token_info = Self::ensure_token_exists(token_id)?;
+            let transfers: TransfersOf<T> = Transfers::<_, _>(
+                [(member_id, PaymentWithVestingOf::<T> { amount, vesting_schedule, revocable: false })]
+                    .iter()
+                    .cloned()
+                    .collect()
+            );
+            let validated_transfers = Self::validate_transfers(token_id, transfers, &token_info.transfer_policy, true)?;
+
+            ensure!(
+                has_sufficient_balance_for_fees::<T>(&sender, Self::compute_bloat_bond(&validated_transfers)),
+                Error::<T>::InsufficientJoyBalance
+            );
+
+            // == MUTATION SAFE ==
+
+            Self::do_credit_transfer_destinations(token_id, &sender, &validated_transfers)?;
+
+            Airdrops::<T>::mutate(token_id, airdrop_id, |airdrop| {
+                airdrop.decrease_remaining_amount_by(amount);
+            });
+
+            AirdropClaims::<T>::insert(airdrop_id, member_id, true);
+
+            Self::deposit_event(RawEvent::AirdropClaimed(token_id, airdrop_id, member_id, amount));
+
+            Ok(())
+        }
+
+        /// Finalize an expired airdrop, returning the unclaimed tokens to the issuer
+        /// Preconditions:
+        /// - `origin` must be signed
+        /// - airdrop by `token_id` x `airdrop_id` must exist and must be expired
+        ///
+        /// Postconditions:
+        /// - airdrop remaining amount credited to the issuer account, or burned if the
+        ///   issuer account no longer exists
+        /// - airdrop remaining amount set to zero
+        /// - at most `MaxAirdropClaimsRemovedPerCall` airdrop claim records removed
+        /// - airdrop removed from storage once all of its claim records are removed,
+        ///   otherwise the call needs to be repeated
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (C)` where:
+        /// - `C` is the number of removed claim records
+        ///   (bounded by `MaxAirdropClaimsRemovedPerCall`)
+        /// - DB:
+        ///   - `O(C)` - from the the generated weights
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::finalize_airdrop(T::MaxAirdropClaimsRemovedPerCall::get())]
+        pub fn finalize_airdrop(origin, token_id: T::TokenId, airdrop_id: AirdropId) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            ensure_signed(origin)?;
+
+            let airdrop = Self::ensure_airdrop_exists(token_id, airdrop_id)?;
+            ensure!(airdrop.is_expired(Self::current_block()), Error::<T>::AirdropNotExpired);
+
+            // == MUTATION SAFE ==
+
+            let remaining_amount = airdrop.remaining_amount;
+            if AccountInfoByTokenAndMember::<T>::contains_key(token_id, airdrop.issuer_member_id) {
+                AccountInfoByTokenAndMember::<T>::mutate(token_id, airdrop.issuer_member_id, |account_data| {
+                    account_data.increase_amount_by(remaining_amount);
+                });
+            } else {
+                TokenInfoById::<T>::mutate(token_id, |token_data| {
+                    token_data.decrease_supply_by(remaining_amount);
+                });
+            }
+
+            // Claims can no longer be submitted for an expired airdrop
+            #[allow(deprecated)]
+            let claims_removal = AirdropClaims::<T>::remove_prefix(
+                airdrop_id,
+                Some(T::MaxAirdropClaimsRemovedPerCall::get())
+            );
+
+            match claims_removal {
+                KillStorageResult::AllRemoved(_) => Airdrops::<T>::remove(token_id, airdrop_id),
+                KillStorageResult::SomeRemaining(_) => {
+                    Airdrops::<T>::mutate(token_id, airdrop_id, |airdrop| {
+                        airdrop.decrease_remaining_amount_by(remaining_amount);
+                    })
+                }
+            }
+
+            Self::deposit_event(RawEvent::AirdropFinalized(token_id, airdrop_id, remaining_amount));
+
+            Ok(())
+        }
+
+        /// Vote in a token holder poll
+        /// Preconditions:
+        /// - `origin` signer must be a controller account of `member_id`
+        /// - poll by `token_id` x `poll_id` must exist and must not have ended
+        /// - `member_id` must not have voted in the poll already
+        /// - `option_index` must be one of the poll options
+        /// - `token_id` x `member_id` account must exist and have non-zero voting power
+        ///   (total balance or staked balance, depending on the poll weighting)
+        ///
+        /// Postconditions:
+        /// - voting power added to the `option_index` tally
+        /// - vote recorded for `member_id`
+        /// - voting power locked in the account until the end of the poll
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::vote_in_poll()]
+        pub fn vote_in_poll(
+            origin,
+            member_id: T::MemberId,
+            token_id: T::TokenId,
+            poll_id: PollId,
+            option_index: u32
+        ) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+
+            let current_block = Self::current_block();
+            let poll = Self::ensure_poll_exists(token_id, poll_id)?;
+            ensure!(!poll.is_ended(current_block), Error::<T>::PollEnded);
+            ensure!(!PollVotes::<T>::contains_key(poll_id, member_id), Error::<T>::PollAlreadyVoted);
+            ensure!((option_index as usize) < poll.tallies.len(), Error::<T>::InvalidPollOption);
+
+            let account_data = Self::ensure_account_data_exists(token_id, &member_id)?;
+            let weight = match poll.weighting {
+                PollVoteWeighting::Balance => account_data.amount,
+                PollVoteWeighting::StakedBalance => account_data.staked(),
+            };
+            ensure!(!weight.is_zero(), Error::<T>::NoPollVotingPower);
+
+            // == MUTATION SAFE ==
+
+            Polls::<T>::mutate(token_id, poll_id, |poll| {
+                poll.add_vote(option_index, weight);
+            });
+
+            PollVotes::<T>::insert(poll_id, member_id, PollVoteOf::<T> { option_index, weight });
+
+            PollVotingLocks::<T>::mutate(token_id, member_id, |lock| {
+                lock.extend(weight, poll.ends_at, current_block);
+            });
+
+            Self::deposit_event(RawEvent::VoteCastInPoll(token_id, poll_id, member_id, option_index, weight));
+
+            Ok(())
+        }
+
+        /// Purchase tokens on active token sale.
+        ///
+        /// Preconditions:
+        /// - token by `token_id` must exist
+        /// - token by `token_id` must be in OfferingState::Sale
+        /// - token's sale must not be a batch auction
+        /// - `amount` cannot exceed number of tokens remaining on sale
+        /// - `origin` signer must be controller account of `member_id` member
+        /// - sender's available JOY balance must be:
+        ///   - >= `joy_existential_deposit + amount * sale.unit_price`
+        ///     if AccountData already exist
+        ///   - >= `joy_existential_deposit + amount * sale.unit_price + bloat_bond`
+        ///     if AccountData does not exist
+        /// - let `fee_amount` be `sale_platform_fee.mul_floor(amount * sale.unit_price)`
+        /// - if `sale.earnings_destination.is_some()` and `sale.earnings_destination` account has
+        ///   zero balance:
+        ///   - the amount to be transferred from `sender` to `sale.earnings_destination`,
+        ///     which is equal to `amount * sale.unit_price - fee_amount`, must be greater than
+        ///     `joy_existential_deposit`
+        /// - total number of tokens already purchased by the member on the current sale
+        ///   PLUS `amount` must not exceed sale's purchase cap per member
+        /// - if Permissioned token:
+        ///   - AccountInfoByTokenAndMember(token_id, &member_id) must exist
+        /// - if `sale.vesting_schedule.is_some()`:
+        ///   - number of sender account's ongoing vesting schedules
+        ///     must be < MaxVestingSchedulesPerAccountPerToken
+        ///
+        /// Postconditions:
+        /// - if `sale.earnings_destination.is_some()`:
+        ///   - `amount * sale.unit_price - fee_amount` JOY tokens are transfered from `sender`
+        ///     to `sale.earnings_destination`
+        ///   - `fee_amount` JOY is slashed from `sender` balance
+        /// - if `sale.earnings_destination.is_none()`:
+        ///   - `amount * sale.unit_price` JOY is slashed from `sender` balance
+        /// - if new token account created: `bloat_bond` transferred from `sender` to treasury
+        /// - if `sale.vesting_schedule.is_some()`:
+        ///   - if buyer has no `vesting_schedule` related to the current sale:
+        ///     - a new vesting schedule (`sale.get_vesting_schedule(purchase_amount)`) is added to
+        ///       buyer's `vesing_schedules`
+        ///     - some finished vesting schedule is removed from buyer's account_data in case the
+        ///       number of buyer's vesting_schedules was == MaxVestingSchedulesPerAccountPerToken
+        ///   - if buyer already has a `vesting_schedule` related to the current sale:
+        ///     - current vesting schedule's `cliff_amount` is increased by
+        ///       `sale.get_vesting_schedule(purchase_amount).cliff_amount`
+        ///     - current vesting schedule's `post_cliff_total_amount` is increased by
+        ///       `sale.get_vesting_schedule(purchase_amount).post_cliff_total_amount`
+        /// - if `sale.vesting_schedule.is_none()`:
+        ///   - buyer's account token amount increased by `amount`
+        /// - if `token_data.sale.quantity_left - amount == 0` and `sale.auto_finalize` is `true`
+        ///   `token_data.sale` is set to None, otherwise `token_data.sale.quantity_left` is
+        ///   decreased by `amount` and `token_data.sale.funds_collected` in increased by
+        ///   `amount * sale.unit_price`
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::purchase_tokens_on_sale()]
+        pub fn purchase_tokens_on_sale(
+            origin,
+            token_id: T::TokenId,
             member_id: T::MemberId,
             amount: TokenBalanceOf<T>,
         ) -> DispatchResult {
@@ -550,6 +1160,10 @@ decl_module! {
             let current_block = Self::current_block();
             let token_data = Self::ensure_token_exists(token_id)?;
             let sale = OfferingStateOf::<T>::ensure_sale_of::<T>(&token_data)?;
+            ensure!(
+                !BatchAuctions::<T>::contains_key(token_id),
+                Error::<T>::CannotPurchaseOnBatchAuction
+            );
             let sale_id = token_data.next_sale_id
                 .checked_sub(1)
                 .ok_or(Error::<T>::ArithmeticError)?;
@@ -666,24 +1280,591 @@ decl_module! {
             Ok(())
         }
 
-        /// Participate in the *latest* token revenue split (if ongoing)
+        /// Place a bid in the token's batch auction sale, replacing the member's previous bid (if any).
+        ///
         /// Preconditions:
-        /// - `token` must exist for `token_id`
-        /// - `origin` signer must be `member_id` member controller account
-        /// - `amount` must be > 0
-        /// - `account` must exist  for `(token_id, member_id)`
-        /// - `token.split_status` must be active AND THEN current_block in
-        ///    [split.start, split.start + split_duration)
-        /// - `account.staking_status.is_none()` OR `account.staking_status.split_id` refers to a past split
-        /// - `account.amount` >= `amount`
-        /// - let `dividend = split_allocation * account.staked_amount / token.supply``
-        ///    then `treasury` must be able to transfer `dividend` amount of JOY.
-        ///    (This condition technically, should always be satisfied)
+        /// - token by `token_id` must exist
+        /// - token by `token_id` must be in OfferingState::Sale and the sale must be a batch auction
+        /// - `origin` signer must be controller account of `member_id` member
+        /// - `quantity` must be > 0 and cannot exceed the number of tokens on sale
+        ///   nor the sale's purchase cap per member
+        /// - `max_price` must be >= `sale.unit_price` (reserve price)
+        /// - if the member has no bid yet and the auction already holds MaxBatchAuctionBids bids:
+        ///   `max_price` must be higher than the lowest bid's max price
+        /// - sender's available JOY balance must be:
+        ///   - >= `joy_existential_deposit + quantity * max_price`
+        ///     if AccountData already exist
+        ///   - >= `joy_existential_deposit + quantity * max_price + bloat_bond`
+        ///     if AccountData does not exist
+        /// - if Permissioned token:
+        ///   - AccountInfoByTokenAndMember(token_id, &member_id) must exist
+        /// - if `sale.vesting_schedule.is_some()`:
+        ///   - number of sender account's ongoing vesting schedules
+        ///     must be < MaxVestingSchedulesPerAccountPerToken
         ///
-        /// Postconditions
-        /// - `dividend` amount of JOYs transferred from `treasury_account` to `sender`
-        /// - `token` revenue split dividends payed tracking variable increased by `dividend`
-        /// - `account.staking_status` set to Some(..) with `amount` and `token.latest_split`
+        /// Postconditions:
+        /// - JOY locked by the previous bid (if any) is refunded to its bidder account
+        /// - if the auction was full: the lowest bid is evicted and its locked JOY refunded
+        ///   to its bidder account
+        /// - `quantity * max_price` JOY transferred from `sender` to the treasury account
+        /// - if new token account created: `bloat_bond` transferred from `sender` to treasury
+        /// - bid stored for `token_id` x `member_id`
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - bounded by MaxBatchAuctionBids
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::place_batch_auction_bid()]
+        pub fn place_batch_auction_bid(
+            origin,
+            token_id: T::TokenId,
+            member_id: T::MemberId,
+            quantity: TokenBalanceOf<T>,
+            max_price: JoyBalanceOf<T>,
+        ) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            ensure!(!quantity.is_zero(), Error::<T>::SalePurchaseAmountIsZero);
+
+            let sender = T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+            let current_block = Self::current_block();
+            let token_data = Self::ensure_token_exists(token_id)?;
+            let sale = OfferingStateOf::<T>::ensure_sale_of::<T>(&token_data)?;
+            let auction = Self::ensure_batch_auction_exists(token_id)?;
+            let sale_id = token_data.next_sale_id
+                .checked_sub(1)
+                .ok_or(Error::<T>::ArithmeticError)?;
+
+            ensure!(max_price >= sale.unit_price, Error::<T>::BatchAuctionBidPriceTooLow);
+            ensure!(quantity <= sale.quantity_left, Error::<T>::NotEnoughTokensOnSale);
+            if let Some(cap) = sale.cap_per_member {
+                ensure!(quantity <= cap, Error::<T>::SalePurchaseCapExceeded);
+            }
+
+            let account_data = Self::ensure_account_data_exists(token_id, &member_id).ok();
+
+            // Ensure account exists if Permissioned token
+            if let TransferPolicy::Permissioned(_) = token_data.transfer_policy {
+                ensure!(account_data.is_some(), Error::<T>::AccountInformationDoesNotExist);
+            }
+
+            // Ensure vesting schedule can be added once the bid is settled
+            if sale.vesting_schedule_params.is_some() {
+                if let Some(acc_data) = account_data.as_ref() {
+                    acc_data.ensure_can_add_or_update_vesting_schedule::<T>(
+                        current_block,
+                        VestingSource::Sale(sale_id)
+                    )?;
+                }
+            }
+
+            let previous_bid = Self::batch_auction_bid(token_id, member_id);
+            let evicted_bid = if previous_bid.is_none() &&
+                auction.bids_number >= T::MaxBatchAuctionBids::get() {
+                let (lowest_bidder, lowest_bid) = Self::lowest_batch_auction_bid(token_id)
+                    .ok_or(Error::<T>::MaxBatchAuctionBidsReached)?;
+                ensure!(
+                    max_price > lowest_bid.max_price,
+                    Error::<T>::BatchAuctionBidPriceNotAboveLowestBid
+                );
+                Some((lowest_bidder, lowest_bid))
+            } else {
+                None
+            };
+
+            let bloat_bond = Self::bloat_bond();
+            let treasury = Self::module_treasury_account();
+            let locked_amount = max_price.saturating_mul(quantity.into());
+            let total_cost = match account_data.as_ref() {
+                Some(_) => locked_amount,
+                None => locked_amount.saturating_add(bloat_bond)
+            };
+
+            ensure!(
+                has_sufficient_balance_for_payment::<T>(&sender, total_cost),
+                Error::<T>::InsufficientJoyBalance
+            );
+
+            // == MUTATION SAFE ==
+
+            if let Some(bid) = previous_bid.as_ref() {
+                Self::transfer_joy(&treasury, &bid.bidder_account, bid.locked_amount())?;
+            }
+
+            if let Some((evicted_member_id, bid)) = evicted_bid.as_ref() {
+                Self::transfer_joy(&treasury, &bid.bidder_account, bid.locked_amount())?;
+                BatchAuctionBids::<T>::remove(token_id, evicted_member_id);
+                Self::deposit_event(RawEvent::BatchAuctionBidEvicted(
+                    token_id,
+                    sale_id,
+                    *evicted_member_id
+                ));
+            }
+
+            Self::transfer_joy(&sender, &treasury, locked_amount)?;
+
+            if account_data.is_none() {
+                Self::transfer_joy(&sender, &treasury, bloat_bond)?;
+                Self::do_insert_new_account_for_token(
+                    token_id,
+                    &member_id,
+                    AccountDataOf::<T>::new_with_amount_and_bond(
+                        TokenBalanceOf::<T>::zero(),
+                        // No restrictions on repayable bloat bond,
+                        // since only usable balance is allowed
+                        RepayableBloatBond::new(bloat_bond, None)
+                    )
+                );
+            }
+
+            BatchAuctionBids::<T>::insert(token_id, member_id, BatchAuctionBidOf::<T> {
+                bidder_account: sender,
+                quantity,
+                max_price,
+            });
+
+            // An evicted bid is replaced by the new one
+            if previous_bid.is_none() && evicted_bid.is_none() {
+                BatchAuctions::<T>::mutate(token_id, |auction| {
+                    auction.bids_number = auction.bids_number.saturating_add(1);
+                });
+            }
+
+            Self::deposit_event(RawEvent::BatchAuctionBidPlaced(
+                token_id,
+                sale_id,
+                member_id,
+                quantity,
+                max_price
+            ));
+
+            Ok(())
+        }
+
+        /// Determine the clearing price of an ended batch auction.
+        ///
+        /// Preconditions:
+        /// - `origin` must be signed
+        /// - token by `token_id` must exist
+        /// - token's current sale must be a batch auction which has ended
+        /// - auction clearing price must not be determined yet
+        ///
+        /// Postconditions:
+        /// - auction clearing is set: the clearing price is the lowest bid price at which the
+        ///   demand covers the tokens on sale (or the lowest bid price if it doesn't),
+        ///   or `sale.unit_price` if there are no bids
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - bounded by MaxBatchAuctionBids
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::settle_batch_auction()]
+        pub fn settle_batch_auction(origin, token_id: T::TokenId) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            ensure_signed(origin)?;
+
+            let token_data = Self::ensure_token_exists(token_id)?;
+            let auction = Self::ensure_batch_auction_exists(token_id)?;
+            let sale = token_data.sale.ok_or(Error::<T>::NoBatchAuction)?;
+            let sale_id = token_data.next_sale_id
+                .checked_sub(1)
+                .ok_or(Error::<T>::ArithmeticError)?;
+
+            ensure!(sale.end_block() <= Self::current_block(), Error::<T>::BatchAuctionNotEnded);
+            ensure!(auction.clearing.is_none(), Error::<T>::BatchAuctionAlreadyCleared);
+
+            let clearing = BatchAuctionClearing::compute(
+                BatchAuctionBids::<T>::iter_prefix_values(token_id)
+                    .map(|bid| (bid.max_price, bid.quantity)),
+                sale.quantity_left,
+                sale.unit_price,
+            );
+
+            // == MUTATION SAFE ==
+
+            let clearing_price = clearing.price;
+            BatchAuctions::<T>::mutate(token_id, |auction| {
+                auction.clearing = Some(clearing);
+            });
+
+            Self::deposit_event(RawEvent::BatchAuctionCleared(token_id, sale_id, clearing_price));
+
+            Ok(())
+        }
+
+        /// Settle a batch auction bid at the auction clearing price.
+        ///
+        /// Preconditions:
+        /// - `origin` must be signed
+        /// - token by `token_id` must exist
+        /// - token's current sale must be a batch auction with clearing price determined
+        /// - bid by `token_id` x `member_id` must exist
+        ///
+        /// Postconditions:
+        /// - let `filled` be the quantity of tokens won by the bid (the whole bid quantity if its
+        ///   max price is above the clearing price, a pro-rata share if it is equal, zero otherwise),
+        ///   or zero if the bidder's account no longer exists or cannot receive the sale vesting schedule
+        /// - let `payment` be `filled * clearing_price` and `fee_amount` be
+        ///   `sale_platform_fee.mul_floor(payment)`
+        /// - if `sale.earnings_destination.is_some()`:
+        ///   - `payment - fee_amount` JOY transferred from the treasury account to
+        ///     `sale.earnings_destination` and `fee_amount` JOY is burned
+        /// - if `sale.earnings_destination.is_none()`:
+        ///   - `payment` JOY is burned
+        /// - remaining locked JOY refunded to the bidder account
+        /// - `filled` tokens credited to the bidder (subject to `sale.vesting_schedule`)
+        /// - `token_data.sale.quantity_left` decreased by `filled`
+        ///   and `token_data.sale.funds_collected` increased by `payment`
+        /// - bid removed from storage
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::settle_batch_auction_bid()]
+        pub fn settle_batch_auction_bid(
+            origin,
+            token_id: T::TokenId,
+            member_id: T::MemberId,
+        ) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            ensure_signed(origin)?;
+
+            let token_data = Self::ensure_token_exists(token_id)?;
+            let auction = Self::ensure_batch_auction_exists(token_id)?;
+            let clearing = auction.clearing.ok_or(Error::<T>::BatchAuctionNotCleared)?;
+            let bid = Self::ensure_batch_auction_bid_exists(token_id, member_id)?;
+            let sale = token_data.sale.ok_or(Error::<T>::NoBatchAuction)?;
+            let sale_id = token_data.next_sale_id
+                .checked_sub(1)
+                .ok_or(Error::<T>::ArithmeticError)?;
+
+            // Tokens won by the bid are not filled if they cannot be credited to the bidder,
+            // in which case the locked JOY is refunded in full
+            let won_quantity = clearing.filled_quantity(bid.quantity, bid.max_price);
+            let vesting_schedule = sale.get_vesting_schedule(won_quantity);
+            let (filled, vesting_cleanup_key) = match Self::ensure_account_data_exists(token_id, &member_id) {
+                Ok(acc_data) if !won_quantity.is_zero() => match &vesting_schedule {
+                    Some(_) => acc_data
+                        .ensure_can_add_or_update_vesting_schedule::<T>(
+                            Self::current_block(),
+                            VestingSource::Sale(sale_id)
+                        )
+                        .map_or((TokenBalanceOf::<T>::zero(), None), |key| (won_quantity, key)),
+                    None => (won_quantity, None),
+                },
+                _ => (TokenBalanceOf::<T>::zero(), None),
+            };
+
+            let payment = clearing.price.saturating_mul(filled.into());
+            let burn_amount = if sale.earnings_destination.is_some() {
+                Self::sale_platform_fee().mul_floor(payment)
+            } else {
+                payment
+            };
+            let transfer_amount = payment.saturating_sub(burn_amount);
+            let refund_amount = bid.locked_amount().saturating_sub(payment);
+            let treasury = Self::module_treasury_account();
+
+            // == MUTATION SAFE ==
+
+            if let Some(dst) = sale.earnings_destination.as_ref() {
+                Self::transfer_joy(&treasury, dst, transfer_amount)?;
+            }
+
+            if !burn_amount.is_zero() {
+                burn_from_usable::<T>(&treasury, burn_amount)?;
+            }
+
+            Self::transfer_joy(&treasury, &bid.bidder_account, refund_amount)?;
+
+            if !filled.is_zero() {
+                AccountInfoByTokenAndMember::<T>::try_mutate(token_id, member_id, |acc_data| {
+                    acc_data.process_sale_purchase::<T>(
+                        sale_id,
+                        filled,
+                        vesting_schedule,
+                        vesting_cleanup_key
+                    )?;
+                    DispatchResult::Ok(())
+                })?;
+            }
+
+            TokenInfoById::<T>::mutate(token_id, |t| {
+                if let Some(s) = t.sale.as_mut() {
+                    s.quantity_left = s.quantity_left.saturating_sub(filled);
+                    s.funds_collected = s.funds_collected.saturating_add(payment);
+                }
+            });
+
+            BatchAuctionBids::<T>::remove(token_id, member_id);
+            BatchAuctions::<T>::mutate(token_id, |auction| {
+                auction.bids_number = auction.bids_number.saturating_sub(1);
+            });
+
+            Self::deposit_event(RawEvent::BatchAuctionBidSettled(
+                token_id,
+                sale_id,
+                member_id,
+                filled,
+                payment
+            ));
+
+            Ok(())
+        }
+
+        /// Participate in the *latest* token revenue split (if ongoing)
+        /// Preconditions:
+        /// - `token` must exist for `token_id`
+        /// - `origin` signer must be `member_id` member controller account
+        /// - `amount` must be > 0
+        /// - `account` must exist  for `(token_id, member_id)`
+        /// - `token.split_status` must be active AND THEN current_block in
+        ///    [split.start, split.start + split_duration)
+        /// - `account.staking_status.is_none()` OR `account.staking_status.split_id` refers to a past split
+        /// - `account.amount` >= `amount`
+        /// - let `dividend = split_allocation * account.staked_amount / token.supply``
+        ///    then `treasury` must be able to transfer `dividend` amount of JOY.
+        ///    (This condition technically, should always be satisfied)
+        ///
+        /// Postconditions
+        /// - `dividend` amount of JOYs transferred from `treasury_account` to `sender`
+        /// - `token` revenue split dividends payed tracking variable increased by `dividend`
+        /// - `account.staking_status` set to Some(..) with `amount` and `token.latest_split`
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::participate_in_split()]
+        pub fn participate_in_split(
+            origin,
+            token_id: T::TokenId,
+            member_id: T::MemberId,
+            amount: TokenBalanceOf<T>,
+        ) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            let sender = T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+
+            ensure!(
+                !amount.is_zero(),
+                Error::<T>::CannotParticipateInSplitWithZeroAmount,
+            );
+
+            let token_info = Self::ensure_token_exists(token_id)?;
+            let split_info = token_info.revenue_split.ensure_active::<T>()?;
+            let split_id = token_info.next_revenue_split_id
+                .checked_sub(1)
+                .ok_or(Error::<T>::ArithmeticError)?;
+
+            let current_block = Self::current_block();
+            ensure!(
+                split_info.timeline.is_ongoing(current_block),
+                Error::<T>::RevenueSplitNotOngoing
+            );
+
+            let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
+
+            account_info.ensure_can_stake::<T>(amount, token_info.next_revenue_split_id)?;
+
+            // it should not really be possible to have supply == 0 with staked amount > 0
+            debug_assert!(!token_info.total_supply.is_zero());
+            let dividend_amount = Self::compute_revenue_split_dividend(
+                amount,
+                token_info.total_supply,
+                split_info.allocation,
+            );
+
+            // ensure JOY can be transferred from `treasury_account` and it doesn't cause
+            // KeepAlive error
+            let treasury_account: T::AccountId = Self::module_treasury_account();
+            Self::ensure_can_transfer_joy(&treasury_account, dividend_amount)?;
+
+            // == MUTATION SAFE ==
+
+            Self::transfer_joy(
+                &treasury_account,
+                &sender,
+                dividend_amount
+            )?;
+
+            TokenInfoById::<T>::mutate(token_id, |token_info| {
+                token_info.revenue_split.account_for_dividend(dividend_amount);
+            });
+
+            AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account_info| {
+                account_info.stake(split_id, amount);
+            });
+
+            Self::deposit_event(RawEvent::UserParticipatedInSplit(
+                token_id,
+                member_id,
+                amount,
+                dividend_amount,
+                split_id,
+            ));
+
+            Ok(())
+        }
+
+        /// Split-participating user leaves revenue split
+        /// Preconditions
+        /// - `token` must exist for `token_id`
+        /// - `origin` signer must be `member_id` member controller account
+        /// - `account` must exist for `(token_id, member_id)`
+        /// - `account.staking status.is_some()'
+        /// - if `(account.staking_status.split_id == token.next_revenue_split_id - 1`
+        ///    AND `token.revenue_split` is active) THEN split staking period  must be ended
+        ///
+        /// Postconditions
+        /// - `account.staking_status` set to None
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::exit_revenue_split()]
+        pub fn exit_revenue_split(origin, token_id: T::TokenId, member_id: T::MemberId) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+
+            let token_info = Self::ensure_token_exists(token_id)?;
+
+            let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
+            let staking_info = account_info.ensure_account_is_valid_split_participant::<T>()?;
+            let current_split_id = token_info.next_revenue_split_id
+                .checked_sub(1)
+                .ok_or(Error::<T>::ArithmeticError)?;
+
+            // staking_info.split_id in [0,token_info.next_revenue_split_id) is a runtime invariant
+            if staking_info.split_id == current_split_id {
+                if let Ok(split_info) = token_info.revenue_split.ensure_active::<T>() {
+                    ensure!(
+                        split_info.timeline.is_ended(Self::current_block()),
+                        Error::<T>::RevenueSplitDidNotEnd,
+                    );
+                }
+            }
+
+            // == MUTATION SAFE ==
+
+            AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account_info| {
+                account_info.unstake();
+            });
+
+            Self::deposit_event(RawEvent::RevenueSplitLeft(token_id, member_id, staking_info.amount));
+            Ok(())
+        }
+
+        /// Stake tokens in the token's continuous revenue staking pool
+        ///
+        /// Preconditions:
+        /// - `origin` signer must be `member_id` member controller account
+        /// - `amount` must be > 0
+        /// - token by `token_id` must exist
+        /// - account must exist for `(token_id, member_id)`
+        /// - `account.amount` minus the tokens already locked by revenue staking must be >= `amount`
+        ///
+        /// Postconditions:
+        /// - rewards accrued by the account's current stake settled
+        /// - account's revenue stake increased by `amount`
+        /// - revenue staking pool total stake increased by `amount`
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::stake_for_revenue()]
+        pub fn stake_for_revenue(
+            origin,
+            token_id: T::TokenId,
+            member_id: T::MemberId,
+            amount: TokenBalanceOf<T>,
+        ) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+
+            ensure!(
+                !amount.is_zero(),
+                Error::<T>::RevenueStakingAmountIsZero,
+            );
+
+            Self::ensure_token_exists(token_id)?;
+
+            let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
+
+            let mut stake = Self::revenue_stake(token_id, member_id);
+            ensure!(
+                account_info.amount.saturating_sub(stake.locks(Self::current_block())) >= amount,
+                Error::<T>::InsufficientBalanceForRevenueStaking,
+            );
+
+            // == MUTATION SAFE ==
+
+            RevenueStakingPools::<T>::mutate(token_id, |pool| {
+                stake.settle(pool);
+                stake.stake(amount);
+                pool.increase_stake_by(amount);
+            });
+
+            Self::store_revenue_stake(token_id, member_id, stake);
+
+            Self::deposit_event(RawEvent::TokensStakedForRevenue(token_id, member_id, amount));
+
+            Ok(())
+        }
+
+        /// Unstake tokens from the token's continuous revenue staking pool.
+        /// Unstaked tokens stop accruing rewards immediately, but remain locked
+        /// for `RevenueStakingUnstakingPeriod` blocks.
+        ///
+        /// Preconditions:
+        /// - `origin` signer must be `member_id` member controller account
+        /// - `amount` must be > 0
+        /// - token by `token_id` must exist
+        /// - account's revenue stake must be >= `amount`
+        ///
+        /// Postconditions:
+        /// - rewards accrued by the account's current stake settled
+        /// - account's revenue stake decreased by `amount`
+        /// - revenue staking pool total stake decreased by `amount`
+        /// - `amount` (together with the tokens still in cooldown) locked until
+        ///   `current_block + RevenueStakingUnstakingPeriod`
         ///
         /// <weight>
         ///
@@ -692,8 +1873,8 @@ decl_module! {
         /// - DB:
         ///   - `O(1)` - doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoToken::<T>::participate_in_split()]
-        pub fn participate_in_split(
+        #[weight = WeightInfoToken::<T>::unstake_from_revenue()]
+        pub fn unstake_from_revenue(
             origin,
             token_id: T::TokenId,
             member_id: T::MemberId,
@@ -701,83 +1882,282 @@ decl_module! {
         ) -> DispatchResult {
             Self::ensure_unfrozen_state()?;
 
-            let sender = T::MemberOriginValidator::ensure_member_controller_account_origin(
+            T::MemberOriginValidator::ensure_member_controller_account_origin(
                 origin,
                 member_id
             )?;
 
             ensure!(
                 !amount.is_zero(),
-                Error::<T>::CannotParticipateInSplitWithZeroAmount,
+                Error::<T>::RevenueStakingAmountIsZero,
             );
 
-            let token_info = Self::ensure_token_exists(token_id)?;
-            let split_info = token_info.revenue_split.ensure_active::<T>()?;
-            let split_id = token_info.next_revenue_split_id
-                .checked_sub(1)
-                .ok_or(Error::<T>::ArithmeticError)?;
+            Self::ensure_token_exists(token_id)?;
 
-            let current_block = Self::current_block();
+            let mut stake = Self::revenue_stake(token_id, member_id);
             ensure!(
-                split_info.timeline.is_ongoing(current_block),
-                Error::<T>::RevenueSplitNotOngoing
+                stake.amount >= amount,
+                Error::<T>::InsufficientRevenueStake,
             );
 
-            let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
+            let current_block = Self::current_block();
+            let unstaking_until = current_block.saturating_add(T::RevenueStakingUnstakingPeriod::get());
 
-            account_info.ensure_can_stake::<T>(amount, token_info.next_revenue_split_id)?;
+            // == MUTATION SAFE ==
 
-            // it should not really be possible to have supply == 0 with staked amount > 0
-            debug_assert!(!token_info.total_supply.is_zero());
-            let dividend_amount = Self::compute_revenue_split_dividend(
+            RevenueStakingPools::<T>::mutate(token_id, |pool| {
+                stake.settle(pool);
+                stake.unstake(amount, current_block, unstaking_until);
+                pool.decrease_stake_by(amount);
+            });
+
+            Self::store_revenue_stake(token_id, member_id, stake);
+
+            Self::deposit_event(RawEvent::TokensUnstakedFromRevenue(
+                token_id,
+                member_id,
                 amount,
-                token_info.total_supply,
-                split_info.allocation,
+                unstaking_until,
+            ));
+
+            Ok(())
+        }
+
+        /// Claim JOY rewards accrued by the account's stake in the token's continuous
+        /// revenue staking pool
+        ///
+        /// Preconditions:
+        /// - `origin` signer must be `member_id` member controller account
+        /// - token by `token_id` must exist
+        /// - account's claimable rewards must be > 0
+        /// - `treasury` must be able to transfer the rewards amount of JOY
+        ///   (This condition technically, should always be satisfied)
+        ///
+        /// Postconditions:
+        /// - claimable rewards transferred from `treasury_account` to `sender`
+        /// - account's revenue stake rewards settled and reset to 0
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::claim_staking_rewards()]
+        pub fn claim_staking_rewards(origin, token_id: T::TokenId, member_id: T::MemberId) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            let sender = T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+
+            Self::ensure_token_exists(token_id)?;
+
+            let pool = Self::revenue_staking_pool(token_id);
+            let mut stake = Self::revenue_stake(token_id, member_id);
+            let rewards = stake.claimable_rewards(&pool);
+            ensure!(
+                !rewards.is_zero(),
+                Error::<T>::NoStakingRewardsToClaim,
             );
 
             // ensure JOY can be transferred from `treasury_account` and it doesn't cause
             // KeepAlive error
             let treasury_account: T::AccountId = Self::module_treasury_account();
-            Self::ensure_can_transfer_joy(&treasury_account, dividend_amount)?;
+            Self::ensure_can_transfer_joy(&treasury_account, rewards)?;
 
             // == MUTATION SAFE ==
 
             Self::transfer_joy(
                 &treasury_account,
                 &sender,
-                dividend_amount
+                rewards
             )?;
 
-            TokenInfoById::<T>::mutate(token_id, |token_info| {
-                token_info.revenue_split.account_for_dividend(dividend_amount);
-            });
+            stake.settle(&pool);
+            stake.pending_rewards = JoyBalanceOf::<T>::zero();
+            Self::store_revenue_stake(token_id, member_id, stake);
 
-            AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account_info| {
-                account_info.stake(split_id, amount);
+            Self::deposit_event(RawEvent::StakingRewardsClaimed(token_id, member_id, rewards));
+
+            Ok(())
+        }
+
+        /// Place a limit order in the token order book.
+        /// Crossing orders are matched right away (at most `MaxOrderMatches` times),
+        /// at the price of the earlier order.
+        ///
+        /// Preconditions:
+        /// - `origin` signer must be `member_id` member controller account
+        /// - `quantity` must be > 0
+        /// - `price` must be > 0
+        /// - `quantity * price` must be >= `MinOrderValue`
+        /// - token by `token_id` must exist
+        /// - order book `side` must hold less than `MaxOrdersPerBookSide` orders
+        /// - if `side` is `Buy`:
+        ///   - sender's available JOY balance must be:
+        ///     - >= `joy_existential_deposit + quantity * price`
+        ///       if AccountData already exist
+        ///     - >= `joy_existential_deposit + quantity * price + bloat_bond`
+        ///       if AccountData does not exist
+        ///   - if Permissioned token:
+        ///     - AccountInfoByTokenAndMember(token_id, &member_id) must exist
+        /// - if `side` is `Sell`:
+        ///   - AccountInfoByTokenAndMember(token_id, &member_id) must exist
+        ///   - account's transferrable balance must be >= `quantity`
+        ///     (vesting schedules and locks taken into account)
+        ///
+        /// Postconditions:
+        /// - if `side` is `Buy`:
+        ///   - `quantity * price` JOY transferred from `sender` to the treasury account
+        ///   - if new token account created: `bloat_bond` transferred from `sender` to treasury
+        /// - if `side` is `Sell`:
+        ///   - `quantity` tokens moved out of the `member_id` account
+        /// - order stored and added to the order book
+        /// - member's open orders number incremented
+        /// - crossing orders matched:
+        ///   - buyer account credited with the traded tokens and refunded the price difference
+        ///   - seller account paid the trade amount reduced by the `OrderBookTxFees` fee,
+        ///     which is burned
+        ///   - filled orders removed
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        ///     (bounded by `MaxOrderMatches`)
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::place_limit_order()]
+        pub fn place_limit_order(
+            origin,
+            token_id: T::TokenId,
+            member_id: T::MemberId,
+            side: OrderSide,
+            quantity: TokenBalanceOf<T>,
+            price: JoyBalanceOf<T>,
+        ) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            ensure!(!quantity.is_zero(), Error::<T>::OrderQuantityIsZero);
+            ensure!(!price.is_zero(), Error::<T>::OrderPriceIsZero);
+
+            let sender = T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+            let token_data = Self::ensure_token_exists(token_id)?;
+
+            let mut order_book = Self::order_book(token_id);
+            ensure!(!order_book.is_full(side), Error::<T>::MaxOrdersPerBookSideReached);
+
+            let account_data = Self::ensure_account_data_exists(token_id, &member_id).ok();
+            let bloat_bond = Self::bloat_bond();
+            let treasury = Self::module_treasury_account();
+            let locked_amount = price
+                .checked_mul(&quantity.into())
+                .ok_or(Error::<T>::ArithmeticError)?;
+            ensure!(locked_amount >= Self::min_order_value(), Error::<T>::OrderValueTooLow);
+
+            match side {
+                OrderSide::Buy => {
+                    // Ensure account exists if Permissioned token
+                    if let TransferPolicy::Permissioned(_) = token_data.transfer_policy {
+                        ensure!(account_data.is_some(), Error::<T>::AccountInformationDoesNotExist);
+                    }
+
+                    let total_cost = match account_data.as_ref() {
+                        Some(_) => locked_amount,
+                        None => locked_amount.saturating_add(bloat_bond)
+                    };
+                    ensure!(
+                        has_sufficient_balance_for_payment::<T>(&sender, total_cost),
+                        Error::<T>::InsufficientJoyBalance
+                    );
+                }
+                OrderSide::Sell => {
+                    let account_data = account_data
+                        .as_ref()
+                        .ok_or(Error::<T>::AccountInformationDoesNotExist)?;
+                    Self::ensure_can_transfer_from_account(token_id, &member_id, account_data, quantity)?;
+                }
+            }
+
+            let order_id = Self::next_order_id();
+
+            // == MUTATION SAFE ==
+
+            match side {
+                OrderSide::Buy => {
+                    Self::transfer_joy(&sender, &treasury, locked_amount)?;
+
+                    if account_data.is_none() {
+                        Self::transfer_joy(&sender, &treasury, bloat_bond)?;
+                        Self::do_insert_new_account_for_token(
+                            token_id,
+                            &member_id,
+                            AccountDataOf::<T>::new_with_amount_and_bond(
+                                TokenBalanceOf::<T>::zero(),
+                                // No restrictions on repayable bloat bond,
+                                // since only usable balance is allowed
+                                RepayableBloatBond::new(bloat_bond, None)
+                            )
+                        );
+                    }
+                }
+                OrderSide::Sell => {
+                    AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account_data| {
+                        account_data.decrease_amount_by(quantity);
+                    });
+                }
+            }
+
+            order_book.insert::<T>(side, price, order_id)?;
+            OrderBooks::<T>::insert(token_id, order_book);
+
+            LimitOrders::<T>::insert(token_id, order_id, LimitOrderOf::<T> {
+                member_id,
+                account: sender,
+                side,
+                price,
+                quantity,
+                filled_quantity: TokenBalanceOf::<T>::zero(),
+            });
+            NextOrderId::put(order_id.saturating_add(1));
+            OpenOrdersNumber::<T>::mutate(token_id, member_id, |orders_number| {
+                *orders_number = orders_number.saturating_add(1);
             });
 
-            Self::deposit_event(RawEvent::UserParticipatedInSplit(
+            Self::deposit_event(RawEvent::LimitOrderPlaced(
                 token_id,
+                order_id,
                 member_id,
-                amount,
-                dividend_amount,
-                split_id,
+                side,
+                quantity,
+                price,
             ));
 
+            Self::match_crossing_orders(token_id, T::MaxOrderMatches::get())?;
+
             Ok(())
         }
 
-        /// Split-participating user leaves revenue split
-        /// Preconditions
-        /// - `token` must exist for `token_id`
+        /// Cancel an open limit order
+        ///
+        /// Preconditions:
         /// - `origin` signer must be `member_id` member controller account
-        /// - `account` must exist for `(token_id, member_id)`
-        /// - `account.staking status.is_some()'
-        /// - if `(account.staking_status.split_id == token.next_revenue_split_id - 1`
-        ///    AND `token.revenue_split` is active) THEN split staking period  must be ended
+        /// - order by `token_id` x `order_id` must exist
+        /// - order must have been placed by `member_id`
         ///
-        /// Postconditions
-        /// - `account.staking_status` set to None
+        /// Postconditions:
+        /// - if buy order: JOY locked by the unfilled part of the order refunded
+        ///   from the treasury account to the order account
+        /// - if sell order: unfilled quantity of tokens moved back to the `member_id` account
+        /// - order removed from storage and from the order book
+        /// - member's open orders number decremented
         ///
         /// <weight>
         ///
@@ -786,8 +2166,13 @@ decl_module! {
         /// - DB:
         ///   - `O(1)` - doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoToken::<T>::exit_revenue_split()]
-        pub fn exit_revenue_split(origin, token_id: T::TokenId, member_id: T::MemberId) -> DispatchResult {
+        #[weight = WeightInfoToken::<T>::cancel_limit_order()]
+        pub fn cancel_limit_order(
+            origin,
+            token_id: T::TokenId,
+            member_id: T::MemberId,
+            order_id: OrderId,
+        ) -> DispatchResult {
             Self::ensure_unfrozen_state()?;
 
             T::MemberOriginValidator::ensure_member_controller_account_origin(
@@ -795,31 +2180,71 @@ decl_module! {
                 member_id
             )?;
 
-            let token_info = Self::ensure_token_exists(token_id)?;
+            let order = Self::ensure_limit_order_exists(token_id, order_id)?;
+            ensure!(order.member_id == member_id, Error::<T>::OrderNotOwnedByMember);
 
-            let account_info = Self::ensure_account_data_exists(token_id, &member_id)?;
-            let staking_info = account_info.ensure_account_is_valid_split_participant::<T>()?;
-            let current_split_id = token_info.next_revenue_split_id
-                .checked_sub(1)
-                .ok_or(Error::<T>::ArithmeticError)?;
+            let treasury = Self::module_treasury_account();
+            let remaining_quantity = order.remaining_quantity();
 
-            // staking_info.split_id in [0,token_info.next_revenue_split_id) is a runtime invariant
-            if staking_info.split_id == current_split_id {
-                if let Ok(split_info) = token_info.revenue_split.ensure_active::<T>() {
-                    ensure!(
-                        split_info.timeline.is_ended(Self::current_block()),
-                        Error::<T>::RevenueSplitDidNotEnd,
-                    );
+            // == MUTATION SAFE ==
+
+            match order.side {
+                OrderSide::Buy => {
+                    Self::transfer_joy(&treasury, &order.account, order.locked_amount())?;
+                }
+                OrderSide::Sell => {
+                    AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account_data| {
+                        account_data.increase_amount_by(remaining_quantity);
+                    });
                 }
             }
 
+            OrderBooks::<T>::mutate(token_id, |order_book| order_book.remove(order_id));
+            Self::remove_limit_order(token_id, order_id, member_id);
+
+            Self::deposit_event(RawEvent::LimitOrderCancelled(
+                token_id,
+                order_id,
+                member_id,
+                remaining_quantity,
+            ));
+
+            Ok(())
+        }
+
+        /// Match the crossing orders of the token order book
+        /// (at most `MaxOrderMatches` times)
+        ///
+        /// Preconditions:
+        /// - `origin` must be signed
+        /// - best bid and best ask of the `token_id` order book must cross
+        ///
+        /// Postconditions:
+        /// - crossing orders matched as in `place_limit_order`
+        ///
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///   - `O(1)` - doesn't depend on the state or parameters
+        ///     (bounded by `MaxOrderMatches`)
+        /// # </weight>
+        #[weight = WeightInfoToken::<T>::match_limit_orders()]
+        pub fn match_limit_orders(origin, token_id: T::TokenId) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            ensure_signed(origin)?;
+
+            ensure!(
+                Self::order_book(token_id).crossing_orders().is_some(),
+                Error::<T>::OrderBookNotCrossed
+            );
+
             // == MUTATION SAFE ==
 
-            AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account_info| {
-                account_info.unstake();
-            });
+            Self::match_crossing_orders(token_id, T::MaxOrderMatches::get())?;
 
-            Self::deposit_event(RawEvent::RevenueSplitLeft(token_id, member_id, staking_info.amount));
             Ok(())
         }
 
@@ -940,49 +2365,192 @@ decl_module! {
                 member_id
             )?;
 
-            let token_data = Self::ensure_token_exists(token_id)?;
-            token_data.ensure_can_modify_supply::<T>()?;
+            let token_data = Self::ensure_token_exists(token_id)?;
+            token_data.ensure_can_modify_supply::<T>()?;
+
+            let curve = token_data.amm_curve.ok_or(Error::<T>::NotInAmmState)?;
+            let user_acc_data = Self::ensure_account_data_exists(token_id, &member_id)?;
+
+            ensure!(
+                Self::transferrable_balance(token_id, &member_id, &user_acc_data) >= amount,
+                Error::<T>::InsufficientTokenBalance,
+            );
+
+            let amm_treasury_account = Self::amm_treasury_account(token_id);
+
+            let price = curve.eval::<T>(amount, AmmOperation::Sell)?.into();
+
+            // slippage tolerance ccurve.eval::<T>heck
+            if let Some((slippage_tolerance, desired_price)) = slippage_tolerance {
+                ensure!(desired_price.saturating_sub(price) <= slippage_tolerance.mul_floor(desired_price), Error::<T>::SlippageToleranceExceeded);
+            }
+
+            let sell_tx_fee = Self::amm_sell_tx_fees().mul_floor(price);
+            let sell_price = price.saturating_sub(sell_tx_fee);
+
+            Self::ensure_can_transfer_joy(&amm_treasury_account, sell_price)?;
+
+            // == MUTATION SAFE ==
+
+            AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account_data| {
+                account_data.decrease_amount_by(amount);
+            });
+
+            TokenInfoById::<T>::mutate(token_id, |token_data| {
+                token_data.decrease_supply_by(amount);
+                token_data.decrease_amm_bought_amount_by(amount);
+            });
+
+            // transfer the price - tx_fee * price
+            Self::transfer_joy(&amm_treasury_account, &sender, sell_price)?;
+
+            // burn tx_fee * price
+            let _ = burn_from_usable::<T>(&amm_treasury_account, sell_tx_fee);
+
+            Self::deposit_event(RawEvent::TokensSoldOnAmm(token_id, member_id, amount, sell_price));
+
+            Ok(())
+        }
+
+        /// Atomically sell `amount_in` of `token_in_id` and buy `amount_out` of `token_out_id`
+        /// through their respective AMMs, routing the JOY proceeds of the sale into the purchase
+        /// Preconditions
+        /// - origin, member_id pair must be a valid authentication pair
+        /// - `token_in_id` and `token_out_id` must be distinct, existing tokens in AMM state
+        /// - both tokens must have permissionless transfer policy
+        /// - both amounts must be non-zero
+        /// - both token supplies can be modified (there is no active revenue split)
+        /// - user usable CRT balance of `token_in_id` must be at least `amount_in`
+        /// - JOY deposited into `token_out_id` amm treasury must not exceed `max_joy_intermediate`
+        /// - JOY received from the sale (net of the tx fee) must be at least `min_joy_intermediate`
+        /// - `token_in_id` amm treasury account must have sufficient JOYs for the sale
+        /// - sale proceeds together with user usable JOY balance must be enough for the
+        ///   purchase (+ existential deposit)
+        ///
+        /// Postconditions
+        /// - `amount_in` of `token_in_id` burned from user account and total supply
+        /// - `amount_out` of `token_out_id` minted into user account (which is created if necessary
+        ///   with bloat bond transferred to it)
+        /// - sale proceeds transferred from `token_in_id` amm treasury to user and purchase price
+        ///   transferred from user to `token_out_id` amm treasury account
+        /// - tx fees of both operations burned
+        /// - event deposited
+        #[weight = WeightInfoToken::<T>::swap_on_amm()]
+        fn swap_on_amm(
+            origin,
+            member_id: T::MemberId,
+            token_in_id: T::TokenId,
+            amount_in: <T as Config>::Balance,
+            token_out_id: T::TokenId,
+            amount_out: <T as Config>::Balance,
+            max_joy_intermediate: JoyBalanceOf<T>,
+            min_joy_intermediate: JoyBalanceOf<T>,
+        ) -> DispatchResult {
+            Self::ensure_unfrozen_state()?;
+
+            ensure!(token_in_id != token_out_id, Error::<T>::SwapTokensMustDiffer);
+            ensure!(!amount_in.is_zero() && !amount_out.is_zero(), Error::<T>::SwapAmountIsZero);
+
+            let sender = ensure_signed(origin.clone())?;
+
+            T::MemberOriginValidator::ensure_member_controller_account_origin(
+                origin,
+                member_id
+            )?;
+
+            let token_in_data = Self::ensure_token_exists(token_in_id)?;
+            token_in_data.ensure_can_modify_supply::<T>()?;
+            ensure!(
+                token_in_data.transfer_policy == TransferPolicyOf::<T>::Permissionless,
+                Error::<T>::CannotSwapPermissionedToken
+            );
+            let curve_in = token_in_data.amm_curve.ok_or(Error::<T>::NotInAmmState)?;
 
-            let curve = token_data.amm_curve.ok_or(Error::<T>::NotInAmmState)?;
-            let user_acc_data = Self::ensure_account_data_exists(token_id, &member_id)?;
+            let token_out_data = Self::ensure_token_exists(token_out_id)?;
+            token_out_data.ensure_can_modify_supply::<T>()?;
+            ensure!(
+                token_out_data.transfer_policy == TransferPolicyOf::<T>::Permissionless,
+                Error::<T>::CannotSwapPermissionedToken
+            );
+            let curve_out = token_out_data.amm_curve.ok_or(Error::<T>::NotInAmmState)?;
 
+            let user_acc_in_data = Self::ensure_account_data_exists(token_in_id, &member_id)?;
             ensure!(
-                user_acc_data.transferrable::<T>(Self::current_block()) >= amount,
+                Self::transferrable_balance(token_in_id, &member_id, &user_acc_in_data) >= amount_in,
                 Error::<T>::InsufficientTokenBalance,
             );
 
-            let amm_treasury_account = Self::amm_treasury_account(token_id);
+            // sell leg
+            let amm_in_treasury_account = Self::amm_treasury_account(token_in_id);
+            let sell_amount = curve_in.eval::<T>(amount_in, AmmOperation::Sell)?.into();
+            let sell_tx_fee = Self::amm_sell_tx_fees().mul_floor(sell_amount);
+            let sell_price = sell_amount.saturating_sub(sell_tx_fee);
 
-            let price = curve.eval::<T>(amount, AmmOperation::Sell)?.into();
+            // buy leg
+            let user_acc_out_data_exists = AccountInfoByTokenAndMember::<T>::contains_key(token_out_id, member_id);
+            let amm_out_treasury_account = Self::amm_treasury_account(token_out_id);
+            let buy_amount = curve_out.eval::<T>(amount_out, AmmOperation::Buy)?.into();
+            let bloat_bond = Self::bloat_bond();
+            let buy_tx_fee = Self::amm_buy_tx_fees().mul_floor(buy_amount);
+            let buy_price = buy_tx_fee.checked_add(&buy_amount).ok_or(Error::<T>::ArithmeticError)?;
 
-            // slippage tolerance ccurve.eval::<T>heck
-            if let Some((slippage_tolerance, desired_price)) = slippage_tolerance {
-                ensure!(desired_price.saturating_sub(price) <= slippage_tolerance.mul_floor(desired_price), Error::<T>::SlippageToleranceExceeded);
-            }
+            ensure!(sell_price >= min_joy_intermediate, Error::<T>::MinJoyIntermediateNotReached);
+            ensure!(buy_price <= max_joy_intermediate, Error::<T>::MaxJoyIntermediateExceeded);
 
-            let sell_tx_fee = Self::amm_sell_tx_fees().mul_floor(price);
-            let sell_price = price.saturating_sub(sell_tx_fee);
+            let joys_required = if !user_acc_out_data_exists {
+                buy_price.saturating_add(bloat_bond)
+            } else {
+                buy_price
+            };
 
-            Self::ensure_can_transfer_joy(&amm_treasury_account, sell_price)?;
+            Self::ensure_can_transfer_joy(&amm_in_treasury_account, sell_price)?;
+            // sale proceeds cover the purchase first, any shortfall is paid from user balance
+            Self::ensure_can_transfer_joy(&sender, joys_required.saturating_sub(sell_price))?;
 
             // == MUTATION SAFE ==
 
-            AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account_data| {
-                account_data.decrease_amount_by(amount);
+            AccountInfoByTokenAndMember::<T>::mutate(token_in_id, member_id, |account_data| {
+                account_data.decrease_amount_by(amount_in);
             });
 
-            TokenInfoById::<T>::mutate(token_id, |token_data| {
-                token_data.decrease_supply_by(amount);
-                token_data.decrease_amm_bought_amount_by(amount);
+            TokenInfoById::<T>::mutate(token_in_id, |token_data| {
+                token_data.decrease_supply_by(amount_in);
+                token_data.decrease_amm_bought_amount_by(amount_in);
             });
 
-            // transfer the price - tx_fee * price
-            Self::transfer_joy(&amm_treasury_account, &sender, sell_price)?;
+            Self::transfer_joy(&amm_in_treasury_account, &sender, sell_price)?;
+            let _ = burn_from_usable::<T>(&amm_in_treasury_account, sell_tx_fee);
 
-            // burn tx_fee * price
-            let _ = burn_from_usable::<T>(&amm_treasury_account, sell_tx_fee);
+            if !user_acc_out_data_exists {
+                let new_account_info = AccountDataOf::<T>::new_with_amount_and_bond(
+                    amount_out,
+                    RepayableBloatBond::new(bloat_bond, None)
+                );
+                Self::do_insert_new_account_for_token(token_out_id, &member_id, new_account_info);
+                Self::transfer_joy(&sender, &amm_out_treasury_account, bloat_bond)?;
+            } else {
+                AccountInfoByTokenAndMember::<T>::mutate(token_out_id, member_id, |account_data| {
+                    account_data.increase_amount_by(amount_out);
+                });
+            }
 
-            Self::deposit_event(RawEvent::TokensSoldOnAmm(token_id, member_id, amount, sell_price));
+            TokenInfoById::<T>::mutate(token_out_id, |token_data| {
+                token_data.increase_supply_by(amount_out);
+                token_data.increase_amm_bought_amount_by(amount_out);
+            });
+
+            Self::transfer_joy(&sender, &amm_out_treasury_account, buy_price)?;
+            let _ = burn_from_usable::<T>(&amm_out_treasury_account, buy_tx_fee);
+
+            Self::deposit_event(RawEvent::TokensSwappedOnAmm(
+                member_id,
+                token_in_id,
+                amount_in,
+                token_out_id,
+                amount_out,
+                sell_price,
+                buy_price,
+            ));
 
             Ok(())
         }
@@ -1064,9 +2632,19 @@ decl_module! {
                 BloatBond::<T>::put(new_bloat_bond);
             }
 
+            if let Some(new_order_book_tx_fee) = parameters.order_book_tx_fees {
+                OrderBookTxFees::put(new_order_book_tx_fee);
+            }
+
+            if let Some(new_min_order_value) = parameters.min_order_value {
+                MinOrderValue::<T>::put(new_min_order_value);
+            }
+
             Self::deposit_event(RawEvent::TokenConstraintsUpdated(parameters));
 
         }
+
+        type StorageVersion = CURRENT_STORAGE_VERSION;
     }
 }
 
@@ -1082,6 +2660,8 @@ impl<T: Config>
         UploadContextOf<T>,
         TransferWithVestingOutputsOf<T>,
         AmmParamsOf<T>,
+        AirdropParamsOf<T>,
+        PollParamsOf<T>,
     > for Module<T>
 {
     /// Establish whether there's an unfinalized revenue split
@@ -1320,6 +2900,8 @@ impl<T: Config>
             &validated_transfers,
         )?;
 
+        Self::register_revocable_vesting_schedules(token_id, src_member_id, &validated_transfers);
+
         Self::deposit_event(RawEvent::TokenAmountTransferredByIssuer(
             token_id,
             src_member_id,
@@ -1329,6 +2911,259 @@ impl<T: Config>
         Ok(())
     }
 
+    /// Revoke a revocable vesting schedule created through an issuer transfer
+    ///
+    /// Preconditions:
+    /// - token by `token_id` exists
+    /// - vesting schedule `VestingSource::IssuerTransfer(transfer_id)` of `token_id` x `member_id`
+    ///   account was created as revocable
+    /// - if `token_id` x `issuer_member_id` account doesn't exist: `bloat_bond_payer` usable
+    ///   JOY balance must be >= `bloat_bond`
+    ///
+    /// Postconditions:
+    /// - vesting schedule removed from `token_id` x `member_id` account (if still present)
+    /// - `token_id` x `member_id` account amount decreased by the schedule's unvested amount,
+    ///   amount staked in a revenue split or in the revenue staking pool capped to the
+    ///   remaining amount
+    /// - `token_id` x `issuer_member_id` account created (if it doesn't exist) with
+    ///   `bloat_bond` transferred from `bloat_bond_payer` to treasury
+    /// - `token_id` x `issuer_member_id` account amount increased by the schedule's unvested
+    ///   amount
+    /// - revocable vesting schedule record removed
+    fn revoke_vesting_schedule(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        transfer_id: u64,
+        issuer_member_id: T::MemberId,
+        bloat_bond_payer: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_unfrozen_state()?;
+
+        Self::ensure_token_exists(token_id)?;
+
+        ensure!(
+            RevocableVestingSchedules::<T>::contains_key((token_id, member_id), transfer_id),
+            Error::<T>::VestingScheduleNotRevocable
+        );
+
+        // The issuer account may have been removed since the schedule was granted
+        let issuer_account_exists =
+            Self::ensure_account_data_exists(token_id, &issuer_member_id).is_ok();
+        if !issuer_account_exists {
+            ensure!(
+                has_sufficient_balance_for_fees::<T>(&bloat_bond_payer, Self::bloat_bond()),
+                Error::<T>::InsufficientJoyBalance
+            );
+        }
+
+        let account_data = Self::ensure_account_data_exists(token_id, &member_id)?;
+        let source = VestingSource::IssuerTransfer(transfer_id);
+        // The schedule may already be gone (cleaned up after vesting or burned)
+        let unvested_amount = account_data
+            .vesting_schedules
+            .get(&source)
+            .map_or(TokenBalanceOf::<T>::zero(), |vs| {
+                vs.locks::<T>(Self::current_block())
+            });
+
+        // == MUTATION SAFE ==
+
+        AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account_data| {
+            account_data.revoke_vesting_schedule(&source, unvested_amount);
+        });
+
+        // Revenue stake cannot exceed the remaining amount
+        let remaining_amount = account_data.amount.saturating_sub(unvested_amount);
+        let mut stake = Self::revenue_stake(token_id, member_id);
+        if stake.locks(Self::current_block()) > remaining_amount {
+            RevenueStakingPools::<T>::mutate(token_id, |pool| {
+                stake.settle(pool);
+                let removed = stake.cap_amount_to(
+                    remaining_amount.saturating_sub(stake.unstaking(Self::current_block())),
+                );
+                pool.decrease_stake_by(removed);
+            });
+            Self::store_revenue_stake(token_id, member_id, stake);
+        }
+
+        if issuer_account_exists {
+            AccountInfoByTokenAndMember::<T>::mutate(token_id, issuer_member_id, |account_data| {
+                account_data.increase_amount_by(unvested_amount);
+            });
+        } else {
+            let repayable_bloat_bond = Self::pay_bloat_bond(&bloat_bond_payer)?;
+            Self::do_insert_new_account_for_token(
+                token_id,
+                &issuer_member_id,
+                AccountDataOf::<T>::new_with_amount_and_bond(unvested_amount, repayable_bloat_bond),
+            );
+        }
+
+        RevocableVestingSchedules::<T>::remove((token_id, member_id), transfer_id);
+
+        Self::deposit_event(RawEvent::VestingScheduleRevoked(
+            token_id,
+            member_id,
+            source,
+            issuer_member_id,
+            unvested_amount,
+        ));
+        Ok(())
+    }
+
+    /// Create an airdrop funded from the issuer account
+    ///
+    /// Preconditions:
+    /// - token by `token_id` exists
+    /// - `params.amount` and `params.duration` are > 0
+    /// - `token_id` x `member_id` account exists
+    /// - `token_id` x `member_id` account has transferrable CRT balance >= `params.amount`
+    ///
+    /// Postconditions:
+    /// - `token_id` x `member_id` account balance is decreased by `params.amount`
+    /// - airdrop expiring at `current_block + params.duration` added to storage
+    /// - `NextAirdropId` increased by 1
+    fn create_airdrop(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        params: AirdropParamsOf<T>,
+    ) -> DispatchResult {
+        Self::ensure_unfrozen_state()?;
+
+        Self::ensure_token_exists(token_id)?;
+
+        ensure!(!params.amount.is_zero(), Error::<T>::AirdropAmountIsZero);
+        ensure!(
+            !params.duration.is_zero(),
+            Error::<T>::AirdropDurationIsZero
+        );
+
+        let current_block = Self::current_block();
+        let account_data = Self::ensure_account_data_exists(token_id, &member_id)?;
+        Self::ensure_can_transfer_from_account(token_id, &member_id, &account_data, params.amount)?;
+
+        // == MUTATION SAFE ==
+
+        AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account_data| {
+            account_data.decrease_amount_by(params.amount);
+        });
+
+        let airdrop_id = Self::next_airdrop_id();
+        let airdrop = AirdropOf::<T> {
+            issuer_member_id: member_id,
+            commitment: params.commitment,
+            remaining_amount: params.amount,
+            expires_at: current_block.saturating_add(params.duration),
+        };
+        Airdrops::<T>::insert(token_id, airdrop_id, airdrop.clone());
+        NextAirdropId::put(airdrop_id.saturating_add(One::one()));
+
+        Self::deposit_event(RawEvent::AirdropCreated(token_id, airdrop_id, airdrop));
+        Ok(())
+    }
+
+    /// Open a token holder poll
+    ///
+    /// Preconditions:
+    /// - token by `token_id` exists
+    /// - `params.options_number` is in [2, `MaxPollOptions`]
+    /// - `params.duration` is non-zero
+    ///
+    /// Postconditions:
+    /// - poll with zero tallies added under `NextPollId`, ending at
+    ///   `current_block + params.duration`
+    /// - `NextPollId` increased by 1
+    fn create_poll(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        params: PollParamsOf<T>,
+    ) -> Result<PollId, DispatchError> {
+        Self::ensure_unfrozen_state()?;
+
+        Self::ensure_token_exists(token_id)?;
+
+        ensure!(
+            params.options_number >= 2 && params.options_number <= T::MaxPollOptions::get(),
+            Error::<T>::InvalidPollOptionsNumber
+        );
+        ensure!(!params.duration.is_zero(), Error::<T>::PollDurationIsZero);
+
+        let tallies = vec![TokenBalanceOf::<T>::zero(); params.options_number as usize]
+            .try_into()
+            .map_err(|_| Error::<T>::InvalidPollOptionsNumber)?;
+
+        // == MUTATION SAFE ==
+
+        let current_block = Self::current_block();
+        let poll_id = Self::next_poll_id();
+        let poll = PollOf::<T> {
+            creator_member_id: member_id,
+            created_at: current_block,
+            ends_at: current_block.saturating_add(params.duration),
+            weighting: params.weighting,
+            tallies,
+        };
+        Polls::<T>::insert(token_id, poll_id, poll.clone());
+        NextPollId::put(poll_id.saturating_add(One::one()));
+
+        Self::deposit_event(RawEvent::PollCreated(
+            token_id,
+            poll_id,
+            poll,
+            params.metadata,
+        ));
+        Ok(poll_id)
+    }
+
+    /// Winning option of an ended poll
+    ///
+    /// Preconditions:
+    /// - poll by `token_id` x `poll_id` exists and has ended
+    ///
+    /// Postconditions:
+    /// - option with the strictly highest non-zero tally returned, None otherwise
+    fn poll_outcome(token_id: T::TokenId, poll_id: PollId) -> Result<Option<u32>, DispatchError> {
+        let poll = Self::ensure_poll_exists(token_id, poll_id)?;
+        ensure!(
+            poll.is_ended(Self::current_block()),
+            Error::<T>::PollNotEnded
+        );
+        Ok(poll.winning_option())
+    }
+
+    /// Remove an ended poll together with its votes
+    ///
+    /// Preconditions:
+    /// - poll by `token_id` x `poll_id` exists and has ended
+    ///
+    /// Postconditions:
+    /// - at most `MaxPollVotesRemovedPerCall` poll votes removed
+    /// - poll removed from storage once all of its votes are removed, in which case
+    ///   `true` is returned
+    fn finalize_poll(token_id: T::TokenId, poll_id: PollId) -> Result<bool, DispatchError> {
+        let poll = Self::ensure_poll_exists(token_id, poll_id)?;
+        ensure!(
+            poll.is_ended(Self::current_block()),
+            Error::<T>::PollNotEnded
+        );
+
+        // == MUTATION SAFE ==
+
+        // Votes are only used to reject double voting while the poll is open
+        #[allow(deprecated)]
+        let votes_removal =
+            PollVotes::<T>::remove_prefix(poll_id, Some(T::MaxPollVotesRemovedPerCall::get()));
+
+        match votes_removal {
+            KillStorageResult::AllRemoved(_) => {
+                Polls::<T>::remove(token_id, poll_id);
+                Self::deposit_event(RawEvent::PollRemoved(token_id, poll_id));
+                Ok(true)
+            }
+            KillStorageResult::SomeRemaining(_) => Ok(false),
+        }
+    }
+
     /// Initialize token sale
     ///
     /// Preconditions:
@@ -1364,13 +3199,7 @@ impl<T: Config>
             auto_finalize,
             current_block,
         )?;
-        Self::ensure_can_init_sale(
-            token_id,
-            member_id,
-            &token_data,
-            &sale_params,
-            current_block,
-        )?;
+        Self::ensure_can_init_sale(token_id, member_id, &token_data, &sale_params)?;
 
         // == MUTATION SAFE ==
 
@@ -1394,6 +3223,38 @@ impl<T: Config>
         Ok(())
     }
 
+    /// Initialize new token sale in batch auction mode
+    ///
+    /// Preconditions:
+    /// - same as `init_token_sale`, with `sale_params.unit_price` used as the reserve price
+    ///
+    /// Postconditions:
+    /// - same as `init_token_sale`, with `auto_finalize` set to `false`
+    /// - token's batch auction is set
+    fn init_batch_auction(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        earnings_destination: Option<T::AccountId>,
+        sale_params: TokenSaleParamsOf<T>,
+    ) -> DispatchResult {
+        let sale_id = Self::ensure_token_exists(token_id)?.next_sale_id;
+
+        Self::init_token_sale(
+            token_id,
+            member_id,
+            earnings_destination,
+            false,
+            sale_params,
+        )?;
+
+        // == MUTATION SAFE ==
+
+        BatchAuctions::<T>::insert(token_id, BatchAuctionOf::<T>::default());
+
+        Self::deposit_event(RawEvent::BatchAuctionInitialized(token_id, sale_id));
+        Ok(())
+    }
+
     /// Update upcoming token sale
     ///
     /// Preconditions:
@@ -1496,15 +3357,95 @@ impl<T: Config>
     /// - `token.latest_split` incremented by 1
     fn issue_revenue_split(
         token_id: T::TokenId,
-        start: Option<T::BlockNumber>,
-        duration: T::BlockNumber,
+        start: Option<T::BlockNumber>,
+        duration: T::BlockNumber,
+        revenue_source_account: T::AccountId,
+        revenue_amount: JoyBalanceOf<T>,
+    ) -> Result<JoyBalanceOf<T>, DispatchError> {
+        Self::ensure_unfrozen_state()?;
+
+        let token_info = Self::ensure_token_exists(token_id)?;
+        token_info.revenue_split.ensure_inactive::<T>()?;
+
+        let allocation_amount = token_info.revenue_split_rate.mul_floor(revenue_amount);
+
+        ensure!(
+            !allocation_amount.is_zero(),
+            Error::<T>::CannotIssueSplitWithZeroAllocationAmount,
+        );
+
+        ensure!(
+            duration >= Self::min_revenue_split_duration(),
+            Error::<T>::RevenueSplitDurationTooShort
+        );
+
+        let current_block = Self::current_block();
+        if let Some(starting_block) = start {
+            ensure!(
+                starting_block.saturating_sub(current_block)
+                    >= Self::min_revenue_split_time_to_start(),
+                Error::<T>::RevenueSplitTimeToStartTooShort,
+            );
+        }
+
+        let revenue_split_start = start.unwrap_or_else(|| {
+            current_block.saturating_add(Self::min_revenue_split_time_to_start())
+        });
+        let timeline = TimelineOf::<T>::from_params(revenue_split_start, duration);
+
+        let treasury_account = Self::module_treasury_account();
+
+        Self::ensure_can_transfer_joy(&revenue_source_account, allocation_amount)?;
+
+        // == MUTATION SAFE ==
+
+        // tranfer allocation keeping the source account alive
+        Self::transfer_joy(
+            &revenue_source_account,
+            &treasury_account,
+            allocation_amount,
+        )?;
+
+        TokenInfoById::<T>::mutate(token_id, |token_info| {
+            token_info.activate_new_revenue_split(allocation_amount, timeline);
+        });
+
+        Self::deposit_event(RawEvent::RevenueSplitIssued(
+            token_id,
+            revenue_split_start,
+            duration,
+            allocation_amount,
+        ));
+
+        Ok(revenue_amount.saturating_sub(allocation_amount))
+    }
+
+    /// Deposit revenue into the token's continuous revenue staking pool
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    /// - some tokens must be staked in the token's revenue staking pool
+    /// - `token.revenue_split_rate * revenue_amount` must be > 0
+    /// - `revenue_source_account` must be able to transfer the allocation amount
+    ///
+    /// Postconditions:
+    /// - `token.revenue_split_rate * revenue_amount` of JOY transferred from
+    ///   `revenue_source_account` to the treasury account
+    /// - pool's reward per staked token increased by `allocation / pool.total_staked`
+    /// - returns the part of `revenue_amount` not allocated to the stakers
+    fn deposit_staking_revenue(
+        token_id: T::TokenId,
         revenue_source_account: T::AccountId,
         revenue_amount: JoyBalanceOf<T>,
     ) -> Result<JoyBalanceOf<T>, DispatchError> {
         Self::ensure_unfrozen_state()?;
 
         let token_info = Self::ensure_token_exists(token_id)?;
-        token_info.revenue_split.ensure_inactive::<T>()?;
+
+        let pool = Self::revenue_staking_pool(token_id);
+        ensure!(
+            !pool.total_staked.is_zero(),
+            Error::<T>::NoTokensStakedForRevenue
+        );
 
         let allocation_amount = token_info.revenue_split_rate.mul_floor(revenue_amount);
 
@@ -1513,27 +3454,6 @@ impl<T: Config>
             Error::<T>::CannotIssueSplitWithZeroAllocationAmount,
         );
 
-        ensure!(
-            duration >= Self::min_revenue_split_duration(),
-            Error::<T>::RevenueSplitDurationTooShort
-        );
-
-        let current_block = Self::current_block();
-        if let Some(starting_block) = start {
-            ensure!(
-                starting_block.saturating_sub(current_block)
-                    >= Self::min_revenue_split_time_to_start(),
-                Error::<T>::RevenueSplitTimeToStartTooShort,
-            );
-        }
-
-        let revenue_split_start = start.unwrap_or_else(|| {
-            current_block.saturating_add(Self::min_revenue_split_time_to_start())
-        });
-        let timeline = TimelineOf::<T>::from_params(revenue_split_start, duration);
-
-        let treasury_account = Self::module_treasury_account();
-
         Self::ensure_can_transfer_joy(&revenue_source_account, allocation_amount)?;
 
         // == MUTATION SAFE ==
@@ -1541,19 +3461,16 @@ impl<T: Config>
         // tranfer allocation keeping the source account alive
         Self::transfer_joy(
             &revenue_source_account,
-            &treasury_account,
+            &Self::module_treasury_account(),
             allocation_amount,
         )?;
 
-        TokenInfoById::<T>::mutate(token_id, |token_info| {
-            token_info.activate_new_revenue_split(allocation_amount, timeline);
-        });
+        RevenueStakingPools::<T>::mutate(token_id, |pool| pool.accrue(allocation_amount));
 
-        Self::deposit_event(RawEvent::RevenueSplitIssued(
+        Self::deposit_event(RawEvent::StakingRevenueDeposited(
             token_id,
-            revenue_split_start,
-            duration,
             allocation_amount,
+            pool.total_staked,
         ));
 
         Ok(revenue_amount.saturating_sub(allocation_amount))
@@ -1608,11 +3525,14 @@ impl<T: Config>
     /// - token by `token_id` must exists
     /// - token must be in Idle offering state
     /// - token must have `sale` set
+    /// - if the sale is a batch auction: its clearing price must be determined
+    ///   and all of its bids settled
     ///
     /// Postconditions:
     /// - `token_data.sale.tokens_source` account balance is increased by
     ///   `token_data.last_sale.quantity_left`
     /// - `token_data.sale` is set to None
+    /// - batch auction (if any) removed from storage
     fn finalize_token_sale(token_id: T::TokenId) -> Result<JoyBalanceOf<T>, DispatchError> {
         Self::ensure_unfrozen_state()?;
 
@@ -1624,7 +3544,20 @@ impl<T: Config>
             .checked_sub(1)
             .ok_or(Error::<T>::ArithmeticError)?;
 
+        if let Ok(auction) = Self::ensure_batch_auction_exists(token_id) {
+            ensure!(
+                auction.clearing.is_some(),
+                Error::<T>::BatchAuctionNotCleared
+            );
+            ensure!(
+                auction.bids_number.is_zero(),
+                Error::<T>::BatchAuctionHasUnsettledBids
+            );
+        }
+
         // == MUTATION SAFE ==
+
+        BatchAuctions::<T>::remove(token_id);
         AccountInfoByTokenAndMember::<T>::mutate(token_id, sale.tokens_source, |ad| {
             ad.increase_amount_by(sale.quantity_left);
         });
@@ -1646,6 +3579,7 @@ impl<T: Config>
     /// Preconditions
     /// - token_id must exist
     /// - offering state for `token_id` must be `Idle`
+    /// - curve parameters must be valid for the curve kind
     ///
     /// Postconditions
     /// - token `amm_curve` activated with specified parameters
@@ -1665,11 +3599,7 @@ impl<T: Config>
             Error::<T>::TokenIssuanceNotInIdleState
         );
 
-        ensure!(
-            params.slope >= Self::min_amm_slope_parameter(),
-            Error::<T>::CurveSlopeParametersTooLow
-        );
-        let curve = AmmCurveOf::<T>::from_params::<T>(params);
+        let curve = AmmCurveOf::<T>::from_params::<T>(params, Self::min_amm_slope_parameter())?;
 
         // == MUTATION SAFE ==
 
@@ -1690,6 +3620,30 @@ impl<T: Config>
         Ok(())
     }
 
+    /// Enable / disable bridging the token to remote chains.
+    /// Disabling bridging only blocks new outbound transfers, escrowed tokens can still
+    /// be bridged back.
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    ///
+    /// Postconditions:
+    /// - token bridging `enabled` flag set to `enabled`
+    fn update_token_bridging(token_id: T::TokenId, enabled: bool) -> DispatchResult {
+        Self::ensure_unfrozen_state()?;
+
+        Self::ensure_token_exists(token_id)?;
+
+        // == MUTATION SAFE ==
+
+        TokenBridgingById::<T>::mutate(token_id, |bridging| {
+            bridging.enabled = enabled;
+        });
+
+        Self::deposit_event(RawEvent::TokenBridgingUpdated(token_id, enabled));
+
+        Ok(())
+    }
+
     /// Deactivate the amm functionality
     /// Preconditions
     /// - (origin, member_id) must be a valid authentication pair
@@ -1728,6 +3682,154 @@ impl<T: Config>
     }
 }
 
+impl<T: Config> CreatorTokenBridge<T::AccountId> for Module<T> {
+    type TokenId = T::TokenId;
+    type MemberId = T::MemberId;
+    type Balance = TokenBalanceOf<T>;
+
+    fn is_bridging_enabled(token_id: T::TokenId) -> bool {
+        Self::token_bridging(token_id).enabled
+    }
+
+    /// Escrow tokens for an outbound bridge transfer
+    /// Preconditions:
+    /// - `sender` must be the `member_id` controller account
+    /// - token by `token_id` must exist and have bridging enabled
+    /// - `member_id` account must exist
+    /// - `member_id` account transferrable balance must be >= `amount`
+    ///
+    /// Postconditions:
+    /// - `amount` tokens moved out of the `member_id` account
+    /// - token escrowed amount increased by `amount`
+    fn escrow_bridged_tokens(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        sender: &T::AccountId,
+        amount: TokenBalanceOf<T>,
+    ) -> DispatchResult {
+        Self::ensure_unfrozen_state()?;
+
+        ensure!(
+            T::MemberOriginValidator::is_member_controller_account(&member_id, sender),
+            Error::<T>::BridgeSenderNotMemberController,
+        );
+
+        Self::ensure_token_exists(token_id)?;
+        ensure!(
+            Self::is_bridging_enabled(token_id),
+            Error::<T>::TokenBridgingNotEnabled,
+        );
+
+        let account_data = Self::ensure_account_data_exists(token_id, &member_id)?;
+        Self::ensure_can_transfer_from_account(token_id, &member_id, &account_data, amount)?;
+
+        // == MUTATION SAFE ==
+
+        AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account_data| {
+            account_data.decrease_amount_by(amount);
+        });
+        TokenBridgingById::<T>::mutate(token_id, |bridging| {
+            bridging.escrowed_amount = bridging.escrowed_amount.saturating_add(amount);
+        });
+
+        Self::deposit_event(RawEvent::TokensEscrowedForBridge(
+            token_id, member_id, amount,
+        ));
+
+        Ok(())
+    }
+
+    /// Release escrowed tokens for an inbound (or reverted outbound) bridge transfer
+    /// Preconditions:
+    /// - token by `token_id` must exist
+    /// - token escrowed amount must be >= `amount`
+    /// - `member_id` account must exist
+    ///
+    /// Postconditions:
+    /// - `amount` tokens added to the `member_id` account
+    /// - token escrowed amount decreased by `amount`
+    fn release_bridged_tokens(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        amount: TokenBalanceOf<T>,
+    ) -> DispatchResult {
+        Self::ensure_unfrozen_state()?;
+
+        Self::ensure_token_exists(token_id)?;
+        ensure!(
+            Self::token_bridging(token_id).escrowed_amount >= amount,
+            Error::<T>::InsufficientBridgedTokens,
+        );
+
+        Self::ensure_account_data_exists(token_id, &member_id)?;
+
+        // == MUTATION SAFE ==
+
+        AccountInfoByTokenAndMember::<T>::mutate(token_id, member_id, |account_data| {
+            account_data.increase_amount_by(amount);
+        });
+        TokenBridgingById::<T>::mutate(token_id, |bridging| {
+            bridging.escrowed_amount = bridging.escrowed_amount.saturating_sub(amount);
+        });
+
+        Self::deposit_event(RawEvent::BridgedTokensReleased(token_id, member_id, amount));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn create_bridgeable_token(
+        account_id: &T::AccountId,
+        amount: TokenBalanceOf<T>,
+    ) -> Result<(T::TokenId, T::MemberId), DispatchError> {
+        let member_id = membership::Module::<T>::members_created();
+        let _ = Joy::<T>::deposit_creating(
+            account_id,
+            T::JoyExistentialDeposit::get()
+                .saturating_add(membership::Module::<T>::membership_price())
+                .saturating_add(Self::bloat_bond()),
+        );
+        membership::Module::<T>::buy_membership(
+            frame_system::RawOrigin::Signed(account_id.clone()).into(),
+            membership::BuyMembershipParameters {
+                root_account: account_id.clone(),
+                controller_account: account_id.clone(),
+                handle: Some(b"bridge".to_vec()),
+                metadata: vec![],
+                referrer_id: None,
+            },
+        )?;
+
+        let token_id = Self::next_token_id();
+        Self::issue_token(
+            account_id.clone(),
+            TokenIssuanceParametersOf::<T> {
+                initial_allocation: [(
+                    member_id,
+                    TokenAllocation {
+                        amount,
+                        vesting_schedule_params: None,
+                    },
+                )]
+                .iter()
+                .cloned()
+                .collect(),
+                transfer_policy: TransferPolicyParams::Permissionless,
+                patronage_rate: YearlyRate(Permill::zero()),
+                revenue_split_rate: Permill::zero(),
+                metadata: vec![],
+            },
+            UploadContext {
+                bag_id: Default::default(),
+                uploader_account: account_id.clone(),
+            },
+        )?;
+        Self::update_token_bridging(token_id, true)?;
+
+        Ok((token_id, member_id))
+    }
+}
+
 /// Module implementation
 impl<T: Config> Module<T> {
     pub(crate) fn ensure_account_data_exists(
@@ -1741,6 +3843,246 @@ impl<T: Config> Module<T> {
         Ok(Self::account_info_by_token_and_member(token_id, member_id))
     }
 
+    pub(crate) fn ensure_airdrop_exists(
+        token_id: T::TokenId,
+        airdrop_id: AirdropId,
+    ) -> Result<AirdropOf<T>, DispatchError> {
+        ensure!(
+            Airdrops::<T>::contains_key(token_id, airdrop_id),
+            Error::<T>::AirdropDoesNotExist,
+        );
+        Ok(Self::airdrop(token_id, airdrop_id))
+    }
+
+    /// Bid with the lowest max price placed in the token's batch auction
+    pub(crate) fn lowest_batch_auction_bid(
+        token_id: T::TokenId,
+    ) -> Option<(T::MemberId, BatchAuctionBidOf<T>)> {
+        BatchAuctionBids::<T>::iter_prefix(token_id).min_by_key(|(_, bid)| bid.max_price)
+    }
+
+    pub(crate) fn ensure_batch_auction_exists(
+        token_id: T::TokenId,
+    ) -> Result<BatchAuctionOf<T>, DispatchError> {
+        ensure!(
+            BatchAuctions::<T>::contains_key(token_id),
+            Error::<T>::NoBatchAuction,
+        );
+        Ok(Self::batch_auction(token_id))
+    }
+
+    pub(crate) fn ensure_limit_order_exists(
+        token_id: T::TokenId,
+        order_id: OrderId,
+    ) -> Result<LimitOrderOf<T>, DispatchError> {
+        Self::limit_order(token_id, order_id).ok_or_else(|| Error::<T>::OrderDoesNotExist.into())
+    }
+
+    pub(crate) fn remove_limit_order(
+        token_id: T::TokenId,
+        order_id: OrderId,
+        member_id: T::MemberId,
+    ) {
+        LimitOrders::<T>::remove(token_id, order_id);
+        OpenOrdersNumber::<T>::mutate_exists(token_id, member_id, |orders_number| {
+            *orders_number = orders_number
+                .map(|number| number.saturating_sub(1))
+                .filter(|number| !number.is_zero());
+        });
+    }
+
+    /// Match the crossing orders of the token order book, at most `max_matches` times.
+    /// Each match trades the smaller remaining quantity at the price of the earlier order,
+    /// the `OrderBookTxFees` fee is charged on the seller proceeds and burned
+    /// (same as the AMM transaction fees).
+    /// Returns the number of executed matches.
+    pub(crate) fn match_crossing_orders(
+        token_id: T::TokenId,
+        max_matches: u32,
+    ) -> Result<u32, DispatchError> {
+        let treasury = Self::module_treasury_account();
+        let mut order_book = Self::order_book(token_id);
+        let mut matches = 0u32;
+
+        while matches < max_matches {
+            let ((_, bid_id), (_, ask_id)) = match order_book.crossing_orders() {
+                Some(crossing_orders) => crossing_orders,
+                None => break,
+            };
+            let mut bid = Self::ensure_limit_order_exists(token_id, bid_id)?;
+            let mut ask = Self::ensure_limit_order_exists(token_id, ask_id)?;
+
+            let quantity = min(bid.remaining_quantity(), ask.remaining_quantity());
+            let price = if bid_id < ask_id {
+                bid.price
+            } else {
+                ask.price
+            };
+            let payment = price.saturating_mul(quantity.into());
+            let fee = Self::order_book_tx_fees().mul_floor(payment);
+            let refund = bid
+                .price
+                .saturating_sub(price)
+                .saturating_mul(quantity.into());
+
+            Self::transfer_joy(&treasury, &ask.account, payment.saturating_sub(fee))?;
+            if !refund.is_zero() {
+                Self::transfer_joy(&treasury, &bid.account, refund)?;
+            }
+            let _ = burn_from_usable::<T>(&treasury, fee);
+
+            AccountInfoByTokenAndMember::<T>::mutate(token_id, bid.member_id, |account_data| {
+                account_data.increase_amount_by(quantity);
+            });
+
+            bid.fill(quantity);
+            ask.fill(quantity);
+            Self::store_limit_order(token_id, bid_id, bid, &mut order_book);
+            Self::store_limit_order(token_id, ask_id, ask, &mut order_book);
+
+            Self::deposit_event(RawEvent::LimitOrdersMatched(
+                token_id, bid_id, ask_id, quantity, price, fee,
+            ));
+
+            matches = matches.saturating_add(1);
+        }
+
+        OrderBooks::<T>::insert(token_id, order_book);
+
+        Ok(matches)
+    }
+
+    /// Store the limit order, removing it (also from the order book) once filled
+    fn store_limit_order(
+        token_id: T::TokenId,
+        order_id: OrderId,
+        order: LimitOrderOf<T>,
+        order_book: &mut OrderBookOf<T>,
+    ) {
+        if order.remaining_quantity().is_zero() {
+            order_book.remove(order_id);
+            Self::remove_limit_order(token_id, order_id, order.member_id);
+        } else {
+            LimitOrders::<T>::insert(token_id, order_id, order);
+        }
+    }
+
+    pub(crate) fn ensure_batch_auction_bid_exists(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+    ) -> Result<BatchAuctionBidOf<T>, DispatchError> {
+        Self::batch_auction_bid(token_id, member_id)
+            .ok_or_else(|| Error::<T>::BatchAuctionBidDoesNotExist.into())
+    }
+
+    pub(crate) fn ensure_poll_exists(
+        token_id: T::TokenId,
+        poll_id: PollId,
+    ) -> Result<PollOf<T>, DispatchError> {
+        ensure!(
+            Polls::<T>::contains_key(token_id, poll_id),
+            Error::<T>::PollDoesNotExist,
+        );
+        Ok(Self::poll(token_id, poll_id))
+    }
+
+    /// Transferrable balance of `token_id` x `member_id` account, taking poll voting locks
+    /// and revenue staking into account
+    pub(crate) fn transferrable_balance(
+        token_id: T::TokenId,
+        member_id: &T::MemberId,
+        account_data: &AccountDataOf<T>,
+    ) -> TokenBalanceOf<T> {
+        let current_block = Self::current_block();
+        let poll_locked = Self::poll_voting_lock(token_id, member_id).locks(current_block);
+        let revenue_staked = Self::revenue_stake(token_id, member_id).locks(current_block);
+        account_data.transferrable_with_locks::<T>(current_block, max(poll_locked, revenue_staked))
+    }
+
+    /// Store the revenue stake of `token_id` x `member_id` account, removing it
+    /// once it no longer locks any tokens nor holds any rewards
+    pub(crate) fn store_revenue_stake(
+        token_id: T::TokenId,
+        member_id: T::MemberId,
+        stake: RevenueStakeOf<T>,
+    ) {
+        if stake.locks(Self::current_block()).is_zero() && stake.pending_rewards.is_zero() {
+            RevenueStakes::<T>::remove(token_id, member_id);
+        } else {
+            RevenueStakes::<T>::insert(token_id, member_id, stake);
+        }
+    }
+
+    /// Ensure that `amount` tokens can be transferred from `token_id` x `member_id` account
+    pub(crate) fn ensure_can_transfer_from_account(
+        token_id: T::TokenId,
+        member_id: &T::MemberId,
+        account_data: &AccountDataOf<T>,
+        amount: TokenBalanceOf<T>,
+    ) -> DispatchResult {
+        ensure!(
+            Self::transferrable_balance(token_id, member_id, account_data) >= amount,
+            Error::<T>::InsufficientTransferrableBalance,
+        );
+        Ok(())
+    }
+
+    pub(crate) fn ensure_allowance_exists(
+        token_id: T::TokenId,
+        owner_member_id: &T::MemberId,
+        spender: &AllowanceSpenderOf<T>,
+    ) -> Result<TokenAllowanceOf<T>, DispatchError> {
+        ensure!(
+            Allowances::<T>::contains_key((token_id, *owner_member_id), spender),
+            Error::<T>::AllowanceDoesNotExist,
+        );
+        Ok(Self::allowance((token_id, *owner_member_id), spender))
+    }
+
+    /// Remove the allowance granted to `spender` and refund its bloat bond
+    /// (to `bloat_bond.repayment_restricted_to` account or `fallback_to` account)
+    pub(crate) fn remove_allowance(
+        token_id: T::TokenId,
+        owner_member_id: T::MemberId,
+        spender: &AllowanceSpenderOf<T>,
+        allowance: &TokenAllowanceOf<T>,
+        fallback_to: &T::AccountId,
+    ) -> DispatchResult {
+        Allowances::<T>::remove((token_id, owner_member_id), spender);
+
+        AllowancesNumber::<T>::mutate_exists(token_id, owner_member_id, |allowances_number| {
+            *allowances_number = allowances_number
+                .map(|number| number.saturating_sub(1))
+                .filter(|number| !number.is_zero());
+        });
+
+        allowance
+            .bloat_bond
+            .repay::<T>(&Self::module_treasury_account(), fallback_to, false)
+    }
+
+    /// Ensure origin is signed by the allowance `spender` and return the signer account
+    pub(crate) fn ensure_allowance_spender_origin(
+        origin: T::RuntimeOrigin,
+        spender: &AllowanceSpenderOf<T>,
+    ) -> Result<T::AccountId, DispatchError> {
+        match spender {
+            AllowanceSpender::Member(member_id) => {
+                T::MemberOriginValidator::ensure_member_controller_account_origin(
+                    origin, *member_id,
+                )
+            }
+            AllowanceSpender::Account(account_id) => {
+                let sender = ensure_signed(origin)?;
+                ensure!(
+                    sender == *account_id,
+                    Error::<T>::AllowanceSpenderOriginMismatch
+                );
+                Ok(sender)
+            }
+        }
+    }
+
     pub(crate) fn ensure_token_exists(
         token_id: T::TokenId,
     ) -> Result<TokenDataOf<T>, DispatchError> {
@@ -1754,6 +4096,8 @@ impl<T: Config> Module<T> {
     /// Perform token de-issuing: unfallible
     pub(crate) fn do_deissue_token(token_id: T::TokenId) {
         TokenInfoById::<T>::remove(token_id);
+        RevenueStakingPools::<T>::remove(token_id);
+        TokenBridgingById::<T>::remove(token_id);
         // TODO: add extra state removal as implementation progresses
     }
 
@@ -1772,8 +4116,12 @@ impl<T: Config> Module<T> {
         let src_account_info = Self::ensure_account_data_exists(token_id, src_member_id)?;
 
         // ensure src account can cover total transfers amount
-        src_account_info
-            .ensure_can_transfer::<T>(Self::current_block(), transfers.total_amount())?;
+        Self::ensure_can_transfer_from_account(
+            token_id,
+            src_member_id,
+            &src_account_info,
+            transfers.total_amount(),
+        )?;
 
         // validate destinations
         let validated_transfers =
@@ -1798,6 +4146,21 @@ impl<T: Config> Module<T> {
     ) -> DispatchResult {
         Self::ensure_unfrozen_state()?;
 
+        Self::do_credit_transfer_destinations(token_id, bloat_bond_payer, validated_transfers)?;
+
+        AccountInfoByTokenAndMember::<T>::mutate(token_id, src_member_id, |account_data| {
+            account_data.decrease_amount_by(validated_transfers.total_amount());
+        });
+
+        Ok(())
+    }
+
+    /// Pay destination accounts bloat bonds and credit the transferred amounts to them
+    pub(crate) fn do_credit_transfer_destinations(
+        token_id: T::TokenId,
+        bloat_bond_payer: &T::AccountId,
+        validated_transfers: &ValidatedTransfersOf<T>,
+    ) -> DispatchResult {
         let current_block = Self::current_block();
 
         let validated_transfers_with_bloat_bonds =
@@ -1858,13 +4221,35 @@ impl<T: Config> Module<T> {
             }
         }
 
-        AccountInfoByTokenAndMember::<T>::mutate(token_id, src_member_id, |account_data| {
-            account_data.decrease_amount_by(validated_transfers.total_amount());
-        });
-
         Ok(())
     }
 
+    /// Record the revocable vesting schedules created by an issuer transfer
+    pub(crate) fn register_revocable_vesting_schedules(
+        token_id: T::TokenId,
+        issuer_member_id: T::MemberId,
+        validated_transfers: &ValidatedTransfersOf<T>,
+    ) {
+        for (validated_account, validated_payment) in validated_transfers.0.iter() {
+            let payment = &validated_payment.payment;
+            if !payment.revocable || payment.vesting_schedule.is_none() {
+                continue;
+            }
+            let dst_member_id = match validated_account {
+                Validated::Existing(member_id) | Validated::NonExisting(member_id) => *member_id,
+            };
+            // The schedule was added under the account's latest vesting transfer id
+            let transfer_id = Self::account_info_by_token_and_member(token_id, dst_member_id)
+                .next_vesting_transfer_id
+                .saturating_sub(1);
+            RevocableVestingSchedules::<T>::insert(
+                (token_id, dst_member_id),
+                transfer_id,
+                issuer_member_id,
+            );
+        }
+    }
+
     pub(crate) fn current_block() -> T::BlockNumber {
         <frame_system::Pallet<T>>::block_number()
     }
@@ -1887,7 +4272,6 @@ impl<T: Config> Module<T> {
         member_id: T::MemberId,
         token_data: &TokenDataOf<T>,
         sale_params: &TokenSaleParamsOf<T>,
-        current_block: T::BlockNumber,
     ) -> DispatchResult {
         // Ensure sale duration is >= MinSaleDuration
         ensure!(
@@ -1908,7 +4292,12 @@ impl<T: Config> Module<T> {
         let account_data = Self::ensure_account_data_exists(token_id, &member_id)?;
 
         // Ensure source account has enough transferrable tokens
-        account_data.ensure_can_transfer::<T>(current_block, sale_params.upper_bound_quantity)?;
+        Self::ensure_can_transfer_from_account(
+            token_id,
+            &member_id,
+            &account_data,
+            sale_params.upper_bound_quantity,
+        )?;
 
         Ok(())
     }
@@ -1959,6 +4348,16 @@ impl<T: Config> Module<T> {
             Error::<T>::CannotDeissueTokenWithOutstandingAccounts,
         );
 
+        ensure!(
+            Airdrops::<T>::iter_prefix(token_id).next().is_none(),
+            Error::<T>::CannotDeissueTokenWithPendingAirdrops,
+        );
+
+        ensure!(
+            Self::token_bridging(token_id).escrowed_amount.is_zero(),
+            Error::<T>::CannotDeissueTokenWithBridgedTokens,
+        );
+
         // This is a extra, since when no account exists -> total_supply == 0
         debug_assert!(token_info.total_supply.is_zero());
 
@@ -0,0 +1,118 @@
+// Migrations for Project Token Pallet
+
+use super::*;
+use frame_support::storage::StoragePrefixedMap;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+use frame_support::weights::Weight;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::storage::IterableStorageMap;
+
+pub mod v1 {
+    use super::*;
+    use codec::{Decode, Encode};
+
+    // Amm curve with the linear pricing function only: y = ax + b
+    #[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
+    struct AmmCurveV0<Balance> {
+        slope: Balance,
+        intercept: Balance,
+        provided_supply: Balance,
+    }
+
+    #[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
+    struct TokenDataV0<Balance, Hash, BlockNumber, TokenSale, RevenueSplitState> {
+        total_supply: Balance,
+        tokens_issued: Balance,
+        next_sale_id: TokenSaleId,
+        sale: Option<TokenSale>,
+        transfer_policy: TransferPolicy<Hash>,
+        patronage_info: PatronageData<Balance, BlockNumber>,
+        accounts_number: u64,
+        revenue_split_rate: Permill,
+        revenue_split: RevenueSplitState,
+        next_revenue_split_id: RevenueSplitId,
+        amm_curve: Option<AmmCurveV0<Balance>>,
+    }
+
+    type TokenDataV0Of<T> = TokenDataV0<
+        TokenBalanceOf<T>,
+        <T as frame_system::Config>::Hash,
+        <T as frame_system::Config>::BlockNumber,
+        TokenSaleOf<T>,
+        RevenueSplitStateOf<T>,
+    >;
+
+    /// Converts the stored linear amm curves into the `AmmCurveKind::Linear` curves.
+    pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            let onchain = Module::<T>::on_chain_storage_version();
+
+            ensure!(onchain < 1, "this migration can be deleted");
+
+            let token_count = TokenInfoById::<T>::iter_keys().count() as u64;
+
+            Ok(token_count.encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let onchain = Module::<T>::on_chain_storage_version();
+            let current = Module::<T>::current_storage_version();
+
+            if onchain > 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut translated = 0u64;
+
+            TokenInfoById::<T>::translate_values(|old: TokenDataV0Of<T>| {
+                translated = translated.saturating_add(1);
+
+                let amm_curve = old.amm_curve.map(|curve| AmmCurve {
+                    kind: AmmCurveKind::Linear {
+                        slope: curve.slope,
+                        intercept: curve.intercept,
+                    },
+                    provided_supply: curve.provided_supply,
+                });
+
+                Some(TokenDataOf::<T> {
+                    total_supply: old.total_supply,
+                    tokens_issued: old.tokens_issued,
+                    next_sale_id: old.next_sale_id,
+                    sale: old.sale,
+                    transfer_policy: old.transfer_policy,
+                    patronage_info: old.patronage_info,
+                    accounts_number: old.accounts_number,
+                    revenue_split_rate: old.revenue_split_rate,
+                    revenue_split: old.revenue_split,
+                    next_revenue_split_id: old.next_revenue_split_id,
+                    amm_curve,
+                })
+            });
+
+            current.put::<Module<T>>();
+
+            T::DbWeight::get()
+                .reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let onchain = Module::<T>::on_chain_storage_version();
+            ensure!(onchain == 1, "this migration needs to be run");
+
+            let pre_upgrade_token_count =
+                u64::decode(&mut state.as_slice()).map_err(|_| "invalid pre_upgrade state")?;
+            let token_count = TokenInfoById::<T>::iter_values().count() as u64;
+            ensure!(
+                pre_upgrade_token_count == token_count,
+                "token counts differ post_upgrade"
+            );
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,399 @@
+#![cfg(test)]
+
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::traits::PalletToken;
+use crate::types::VestingSource;
+use crate::{joy, last_event_eq, member, origin, token, AirdropClaims, Airdrops, Error, RawEvent};
+use frame_support::{assert_err, assert_ok, IterableStorageDoubleMap, StorageDoubleMap};
+use sp_runtime::DispatchError;
+
+// --------------------- create_airdrop -------------------------------
+
+#[test]
+fn create_airdrop_fails_with_non_existing_token() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        let result = CreateAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn create_airdrop_fails_with_zero_amount() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = CreateAirdropFixture::default()
+            .with_amount(0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropAmountIsZero);
+    })
+}
+
+#[test]
+fn create_airdrop_fails_with_zero_duration() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = CreateAirdropFixture::default()
+            .with_duration(0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropDurationIsZero);
+    })
+}
+
+#[test]
+fn create_airdrop_fails_with_non_existing_issuer_account() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = CreateAirdropFixture::default()
+            .with_member_id(member!(2).0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn create_airdrop_fails_with_insufficient_issuer_balance() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default()
+            .with_supply(DEFAULT_AIRDROP_AMOUNT - 1)
+            .execute_call()
+            .unwrap();
+
+        let result = CreateAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientTransferrableBalance);
+    })
+}
+
+#[test]
+fn create_airdrop_ok() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        let supply_pre = Token::token_info_by_id(token!(1)).total_supply;
+
+        let result = CreateAirdropFixture::default().execute_call();
+
+        assert_ok!(result);
+        let airdrop = Token::airdrop(token!(1), 0);
+        assert_eq!(
+            airdrop,
+            Airdrop {
+                issuer_member_id: member!(1).0,
+                commitment: airdrop.commitment,
+                remaining_amount: DEFAULT_AIRDROP_AMOUNT,
+                expires_at: System::block_number() + DEFAULT_AIRDROP_DURATION,
+            }
+        );
+        assert_eq!(Token::next_airdrop_id(), 1);
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_AIRDROP_AMOUNT
+        );
+        // escrowed tokens are still part of the supply
+        assert_eq!(Token::token_info_by_id(token!(1)).total_supply, supply_pre);
+        last_event_eq!(RawEvent::AirdropCreated(token!(1), 0, airdrop));
+    })
+}
+
+#[test]
+fn deissue_token_fails_with_pending_airdrop() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default()
+            .with_supply(DEFAULT_AIRDROP_AMOUNT)
+            .execute_call()
+            .unwrap();
+        CreateAirdropFixture::default().execute_call().unwrap();
+        Token::dust_account(origin!(member!(1).1), token!(1), member!(1).0).unwrap();
+
+        let result = Token::deissue_token(token!(1));
+
+        assert_err!(result, Error::<Test>::CannotDeissueTokenWithPendingAirdrops);
+    })
+}
+
+// --------------------- claim_airdrop -------------------------------
+
+#[test]
+fn claim_airdrop_fails_with_invalid_origin() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreateAirdropFixture::default().execute_call().unwrap();
+
+        let result = ClaimAirdropFixture::default()
+            .with_sender(member!(3).1)
+            .execute_call();
+
+        assert_err!(
+            result,
+            DispatchError::Other("origin signer not a member controller account")
+        );
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_non_existing_airdrop() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreateAirdropFixture::default().execute_call().unwrap();
+
+        let result = ClaimAirdropFixture::default()
+            .with_airdrop_id(1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropDoesNotExist);
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_expired_airdrop() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreateAirdropFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_AIRDROP_DURATION);
+
+        let result = ClaimAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropExpired);
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_invalid_proof() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreateAirdropFixture::default().execute_call().unwrap();
+
+        let result = ClaimAirdropFixture::default()
+            .with_amount(DEFAULT_AIRDROP_CLAIM_AMOUNT + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::MerkleProofVerificationFailure);
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_when_already_claimed() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreateAirdropFixture::default().execute_call().unwrap();
+        ClaimAirdropFixture::default().execute_call().unwrap();
+
+        let result = ClaimAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropAlreadyClaimed);
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_insufficient_remaining_amount() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreateAirdropFixture::default()
+            .with_amount(DEFAULT_AIRDROP_CLAIM_AMOUNT - 1)
+            .execute_call()
+            .unwrap();
+
+        let result = ClaimAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientAirdropRemainingAmount);
+    })
+}
+
+#[test]
+fn claim_airdrop_fails_with_insufficient_joy_for_bloat_bond() {
+    let config = GenesisConfigBuilder::new_empty()
+        .with_bloat_bond(joy!(100))
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        increase_account_balance(&member!(1).1, ExistentialDeposit::get() + joy!(100));
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreateAirdropFixture::default().execute_call().unwrap();
+
+        let result = ClaimAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientJoyBalance);
+    })
+}
+
+#[test]
+fn claim_airdrop_ok() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreateAirdropFixture::default().execute_call().unwrap();
+
+        let result = ClaimAirdropFixture::default().execute_call();
+
+        assert_ok!(result);
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(2).0).amount,
+            DEFAULT_AIRDROP_CLAIM_AMOUNT
+        );
+        assert_eq!(
+            Token::airdrop(token!(1), 0).remaining_amount,
+            DEFAULT_AIRDROP_AMOUNT - DEFAULT_AIRDROP_CLAIM_AMOUNT
+        );
+        assert!(Token::airdrop_claimed(0, member!(2).0));
+        last_event_eq!(RawEvent::AirdropClaimed(
+            token!(1),
+            0,
+            member!(2).0,
+            DEFAULT_AIRDROP_CLAIM_AMOUNT
+        ));
+    })
+}
+
+#[test]
+fn claim_airdrop_ok_with_vesting_schedule() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreateAirdropFixture::default().execute_call().unwrap();
+
+        let result = ClaimAirdropFixture::default().with_claim(1).execute_call();
+
+        assert_ok!(result);
+        let account_data = Token::account_info_by_token_and_member(token!(1), member!(3).0);
+        assert_eq!(account_data.amount, DEFAULT_AIRDROP_CLAIM_AMOUNT);
+        assert!(account_data
+            .vesting_schedules
+            .contains_key(&VestingSource::IssuerTransfer(0)));
+    })
+}
+
+#[test]
+fn claim_airdrop_ok_with_bloat_bond_transferred_to_treasury() {
+    let bloat_bond = joy!(100);
+    let config = GenesisConfigBuilder::new_empty()
+        .with_bloat_bond(bloat_bond)
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        increase_account_balance(&member!(1).1, ExistentialDeposit::get() + bloat_bond);
+        increase_account_balance(&member!(2).1, ExistentialDeposit::get() + bloat_bond);
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreateAirdropFixture::default().execute_call().unwrap();
+        let treasury_balance_pre = Balances::usable_balance(Token::module_treasury_account());
+
+        let result = ClaimAirdropFixture::default().execute_call();
+
+        assert_ok!(result);
+        assert_eq!(
+            Balances::usable_balance(Token::module_treasury_account()),
+            treasury_balance_pre + bloat_bond
+        );
+        assert_eq!(
+            Balances::usable_balance(member!(2).1),
+            ExistentialDeposit::get()
+        );
+    })
+}
+
+// --------------------- finalize_airdrop -------------------------------
+
+#[test]
+fn finalize_airdrop_fails_with_non_existing_airdrop() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = FinalizeAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropDoesNotExist);
+    })
+}
+
+#[test]
+fn finalize_airdrop_fails_before_expiry() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreateAirdropFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_AIRDROP_DURATION - 1);
+
+        let result = FinalizeAirdropFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AirdropNotExpired);
+    })
+}
+
+#[test]
+fn finalize_airdrop_ok_with_remaining_amount_returned_to_issuer() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreateAirdropFixture::default().execute_call().unwrap();
+        ClaimAirdropFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_AIRDROP_DURATION);
+
+        let result = FinalizeAirdropFixture::default().execute_call();
+
+        assert_ok!(result);
+        assert!(!Airdrops::<Test>::contains_key(token!(1), 0));
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_AIRDROP_CLAIM_AMOUNT
+        );
+        assert!(!AirdropClaims::<Test>::contains_key(0, member!(2).0));
+        last_event_eq!(RawEvent::AirdropFinalized(
+            token!(1),
+            0,
+            DEFAULT_AIRDROP_AMOUNT - DEFAULT_AIRDROP_CLAIM_AMOUNT
+        ));
+    })
+}
+
+#[test]
+fn finalize_airdrop_ok_with_claim_records_removed_in_bounded_batches() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreateAirdropFixture::default().execute_call().unwrap();
+        ClaimAirdropFixture::default().execute_call().unwrap();
+        for i in 0..MaxAirdropClaimsRemovedPerCall::get() {
+            AirdropClaims::<Test>::insert(0, member!(10 + i).0, true);
+        }
+        increase_block_number_by(DEFAULT_AIRDROP_DURATION);
+
+        FinalizeAirdropFixture::default().execute_call().unwrap();
+
+        // remaining amount returned, claim records left to be removed
+        assert_eq!(Token::airdrop(token!(1), 0).remaining_amount, 0);
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_AIRDROP_CLAIM_AMOUNT
+        );
+        assert!(AirdropClaims::<Test>::iter_prefix(0).next().is_some());
+
+        FinalizeAirdropFixture::default().execute_call().unwrap();
+
+        assert!(!Airdrops::<Test>::contains_key(token!(1), 0));
+        assert!(AirdropClaims::<Test>::iter_prefix(0).next().is_none());
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_AIRDROP_CLAIM_AMOUNT
+        );
+        last_event_eq!(RawEvent::AirdropFinalized(token!(1), 0, 0));
+    })
+}
+
+#[test]
+fn finalize_airdrop_ok_with_remaining_amount_burned_when_issuer_account_removed() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default()
+            .with_supply(DEFAULT_AIRDROP_AMOUNT)
+            .execute_call()
+            .unwrap();
+        CreateAirdropFixture::default().execute_call().unwrap();
+        Token::dust_account(origin!(member!(1).1), token!(1), member!(1).0).unwrap();
+        increase_block_number_by(DEFAULT_AIRDROP_DURATION);
+
+        let result = FinalizeAirdropFixture::default().execute_call();
+
+        assert_ok!(result);
+        assert!(!Airdrops::<Test>::contains_key(token!(1), 0));
+        assert_eq!(Token::token_info_by_id(token!(1)).total_supply, 0);
+    })
+}
@@ -0,0 +1,586 @@
+#![cfg(test)]
+
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::tests::test_utils::new_issuer_transfers;
+use crate::traits::PalletToken;
+use crate::types::{Transfers, Validated};
+use crate::{
+    joy, last_event_eq, member, merkle_root, origin, token, Allowances, AllowancesNumber, Error,
+    RawEvent, RepayableBloatBond,
+};
+use frame_support::{assert_err, assert_ok, IterableStorageDoubleMap, StorageDoubleMap};
+use frame_system::RawOrigin;
+use sp_runtime::{DispatchError, Permill};
+
+// --------------------- approve -------------------------------
+
+#[test]
+fn approve_fails_with_invalid_owner_origin() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = ApproveFixture::default()
+            .with_sender(member!(2).1)
+            .execute_call();
+
+        assert_err!(
+            result,
+            DispatchError::Other("origin signer not a member controller account")
+        );
+    })
+}
+
+#[test]
+fn approve_fails_with_non_existing_token() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        let result = ApproveFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn approve_fails_with_zero_amount() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = ApproveFixture::default().with_amount(0).execute_call();
+
+        assert_err!(result, Error::<Test>::AllowanceAmountIsZero);
+    })
+}
+
+#[test]
+fn approve_fails_with_expiry_in_the_past() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        increase_block_number_by(10);
+
+        let result = ApproveFixture::default()
+            .with_expires_at(System::block_number() - 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AllowanceExpiryInThePast);
+    })
+}
+
+#[test]
+fn approve_ok_with_allowance_set() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        let spender = AllowanceSpender::Account(member!(3).1);
+
+        ApproveFixture::default()
+            .with_spender(spender.clone())
+            .with_expires_at(100)
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(
+            Token::allowance((token!(1), member!(1).0), &spender),
+            TokenAllowance {
+                amount: DEFAULT_ALLOWANCE_AMOUNT,
+                expires_at: Some(100),
+                bloat_bond: RepayableBloatBond::new(0, None),
+            }
+        );
+        last_event_eq!(RawEvent::AllowanceUpdated(
+            token!(1),
+            member!(1).0,
+            spender,
+            DEFAULT_ALLOWANCE_AMOUNT,
+            Some(100)
+        ));
+    })
+}
+
+#[test]
+fn approve_ok_with_previous_allowance_replaced() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default()
+            .with_expires_at(100)
+            .execute_call()
+            .unwrap();
+
+        ApproveFixture::default()
+            .with_amount(1)
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(
+            Token::allowance(
+                (token!(1), member!(1).0),
+                AllowanceSpender::Member(member!(2).0)
+            ),
+            TokenAllowance {
+                amount: 1,
+                expires_at: None,
+                bloat_bond: RepayableBloatBond::new(0, None),
+            }
+        );
+        assert_eq!(Token::allowances_number(token!(1), member!(1).0), 1);
+    })
+}
+
+#[test]
+fn approve_fails_with_max_allowances_per_account_reached() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        for i in 0..MaxAllowancesPerAccount::get() {
+            ApproveFixture::default()
+                .with_spender(AllowanceSpender::Account(member!(10 + i).1))
+                .execute_call()
+                .unwrap();
+        }
+
+        let result = ApproveFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::MaxAllowancesPerAccountReached);
+    })
+}
+
+#[test]
+fn approve_fails_with_insufficient_joy_balance_for_bloat_bond() {
+    let config = GenesisConfigBuilder::new_empty()
+        .with_bloat_bond(joy!(100))
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = ApproveFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientJoyBalance);
+    })
+}
+
+#[test]
+fn approve_ok_with_bloat_bond_transferred_to_treasury() {
+    let bloat_bond = joy!(100);
+    let config = GenesisConfigBuilder::new_empty()
+        .with_bloat_bond(bloat_bond)
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        increase_account_balance(&member!(1).1, ExistentialDeposit::get() + bloat_bond);
+        IssueTokenFixture::default().execute_call().unwrap();
+        increase_account_balance(&member!(1).1, bloat_bond);
+        let treasury_balance_pre = Balances::usable_balance(Token::module_treasury_account());
+
+        ApproveFixture::default().execute_call().unwrap();
+        // replacing the allowance requires no further bond
+        ApproveFixture::default()
+            .with_amount(1)
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(
+            Balances::usable_balance(Token::module_treasury_account()),
+            treasury_balance_pre + bloat_bond
+        );
+        assert_eq!(
+            Token::allowance(
+                (token!(1), member!(1).0),
+                AllowanceSpender::Member(member!(2).0)
+            )
+            .bloat_bond,
+            RepayableBloatBond::new(bloat_bond, None)
+        );
+    })
+}
+
+// --------------------- revoke_allowance -------------------------------
+
+#[test]
+fn revoke_allowance_fails_with_non_existing_allowance() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = RevokeAllowanceFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AllowanceDoesNotExist);
+    })
+}
+
+#[test]
+fn revoke_allowance_fails_with_invalid_owner_origin() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        let result = RevokeAllowanceFixture::default()
+            .with_sender(member!(2).1)
+            .execute_call();
+
+        assert_err!(
+            result,
+            DispatchError::Other("origin signer not a member controller account")
+        );
+    })
+}
+
+#[test]
+fn revoke_allowance_ok() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        RevokeAllowanceFixture::default().execute_call().unwrap();
+
+        let spender = AllowanceSpender::Member(member!(2).0);
+        assert!(!Allowances::<Test>::contains_key(
+            (token!(1), member!(1).0),
+            &spender
+        ));
+        assert!(!AllowancesNumber::<Test>::contains_key(
+            token!(1),
+            member!(1).0
+        ));
+        last_event_eq!(RawEvent::AllowanceRevoked(token!(1), member!(1).0, spender));
+    })
+}
+
+#[test]
+fn revoke_allowance_ok_with_bloat_bond_refunded() {
+    let bloat_bond = joy!(100);
+    let config = GenesisConfigBuilder::new_empty()
+        .with_bloat_bond(bloat_bond)
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        increase_account_balance(&member!(1).1, ExistentialDeposit::get() + bloat_bond);
+        IssueTokenFixture::default().execute_call().unwrap();
+        increase_account_balance(&Token::module_treasury_account(), ExistentialDeposit::get());
+        increase_account_balance(&member!(1).1, bloat_bond);
+        ApproveFixture::default().execute_call().unwrap();
+        let owner_balance_pre = Balances::usable_balance(member!(1).1);
+
+        RevokeAllowanceFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Balances::usable_balance(member!(1).1),
+            owner_balance_pre + bloat_bond
+        );
+    })
+}
+
+// --------------------- transfer_from -------------------------------
+
+#[test]
+fn transfer_from_fails_with_non_existing_allowance() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = TransferFromFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AllowanceDoesNotExist);
+    })
+}
+
+#[test]
+fn transfer_from_fails_with_member_spender_origin_mismatch() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        let result = TransferFromFixture::default()
+            .with_sender(member!(3).1)
+            .execute_call();
+
+        assert_err!(
+            result,
+            DispatchError::Other("origin signer not a member controller account")
+        );
+    })
+}
+
+#[test]
+fn transfer_from_fails_with_account_spender_origin_mismatch() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        let spender = AllowanceSpender::Account(member!(2).1);
+        ApproveFixture::default()
+            .with_spender(spender.clone())
+            .execute_call()
+            .unwrap();
+
+        let result = TransferFromFixture::default()
+            .with_spender(spender)
+            .with_sender(member!(3).1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AllowanceSpenderOriginMismatch);
+    })
+}
+
+#[test]
+fn transfer_from_fails_with_expired_allowance() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default()
+            .with_expires_at(System::block_number() + 5)
+            .execute_call()
+            .unwrap();
+        increase_block_number_by(6);
+
+        let result = TransferFromFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AllowanceExpired);
+    })
+}
+
+#[test]
+fn transfer_from_fails_with_allowance_exceeded() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        let result = TransferFromFixture::default()
+            .with_outputs(vec![
+                (member!(3).0, DEFAULT_ALLOWANCE_AMOUNT),
+                (member!(4).0, 1),
+            ])
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AllowanceExceeded);
+    })
+}
+
+#[test]
+fn transfer_from_fails_with_vested_tokens_locked() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        // only vested (locked) tokens in the source account
+        Token::issuer_transfer(
+            token!(1),
+            member!(1).0,
+            member!(1).1,
+            new_issuer_transfers(vec![(
+                member!(5).0,
+                DEFAULT_ALLOWANCE_AMOUNT,
+                Some(VestingScheduleParams {
+                    blocks_before_cliff: 100,
+                    cliff_amount_percentage: Permill::from_percent(10),
+                    linear_vesting_duration: 100,
+                }),
+            )]),
+            vec![],
+        )
+        .unwrap();
+        ApproveFixture::default()
+            .with_owner(member!(5))
+            .execute_call()
+            .unwrap();
+
+        let result = TransferFromFixture::default()
+            .with_src_member_id(member!(5).0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientTransferrableBalance);
+    })
+}
+
+#[test]
+fn transfer_from_fails_with_non_whitelisted_destination_under_permissioned_policy() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default()
+            .with_transfer_policy(TransferPolicyParams::Permissioned(WhitelistParams {
+                commitment: merkle_root![member!(1).0, member!(2).0],
+                payload: None,
+            }))
+            .execute_call()
+            .unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        let result = TransferFromFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn transfer_from_ok_with_balances_and_allowance_updated() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        TransferFromFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_ALLOWANCE_TRANSFER_AMOUNT
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(3).0).amount,
+            DEFAULT_ALLOWANCE_TRANSFER_AMOUNT
+        );
+        assert_eq!(
+            Token::allowance(
+                (token!(1), member!(1).0),
+                AllowanceSpender::Member(member!(2).0)
+            )
+            .amount,
+            DEFAULT_ALLOWANCE_AMOUNT - DEFAULT_ALLOWANCE_TRANSFER_AMOUNT
+        );
+    })
+}
+
+#[test]
+fn transfer_from_ok_with_account_spender() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        let spender = AllowanceSpender::Account(member!(3).1);
+        ApproveFixture::default()
+            .with_spender(spender.clone())
+            .execute_call()
+            .unwrap();
+
+        let result = TransferFromFixture::default()
+            .with_spender(spender)
+            .with_sender(member!(3).1)
+            .execute_call();
+
+        assert_ok!(result);
+    })
+}
+
+#[test]
+fn transfer_from_ok_with_exhausted_allowance_removed() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        TransferFromFixture::default()
+            .with_outputs(vec![(member!(3).0, DEFAULT_ALLOWANCE_AMOUNT)])
+            .execute_call()
+            .unwrap();
+
+        assert!(!Allowances::<Test>::contains_key(
+            (token!(1), member!(1).0),
+            AllowanceSpender::Member(member!(2).0)
+        ));
+        assert_err!(
+            TransferFromFixture::default().execute_call(),
+            Error::<Test>::AllowanceDoesNotExist
+        );
+    })
+}
+
+#[test]
+fn transfer_from_ok_with_exhausted_allowance_bloat_bond_refunded_to_owner() {
+    let bloat_bond = joy!(100);
+    let config = GenesisConfigBuilder::new_empty()
+        .with_bloat_bond(bloat_bond)
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        increase_account_balance(&member!(1).1, ExistentialDeposit::get() + bloat_bond);
+        IssueTokenFixture::default().execute_call().unwrap();
+        increase_account_balance(&Token::module_treasury_account(), ExistentialDeposit::get());
+        increase_account_balance(&member!(1).1, bloat_bond);
+        ApproveFixture::default().execute_call().unwrap();
+        increase_account_balance(&member!(2).1, ExistentialDeposit::get() + bloat_bond);
+        let owner_balance_pre = Balances::usable_balance(member!(1).1);
+
+        TransferFromFixture::default()
+            .with_outputs(vec![(member!(3).0, DEFAULT_ALLOWANCE_AMOUNT)])
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(
+            Balances::usable_balance(member!(1).1),
+            owner_balance_pre + bloat_bond
+        );
+        assert!(!AllowancesNumber::<Test>::contains_key(
+            token!(1),
+            member!(1).0
+        ));
+    })
+}
+
+#[test]
+fn transfer_from_ok_with_event_deposited() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+
+        TransferFromFixture::default().execute_call().unwrap();
+
+        last_event_eq!(RawEvent::TokenAmountTransferredFrom(
+            token!(1),
+            member!(1).0,
+            AllowanceSpender::Member(member!(2).0),
+            Transfers::<_, _>::new_validated(vec![(
+                Validated::NonExisting(member!(3).0),
+                DEFAULT_ALLOWANCE_TRANSFER_AMOUNT,
+                None,
+                None
+            )]),
+            DEFAULT_ALLOWANCE_AMOUNT - DEFAULT_ALLOWANCE_TRANSFER_AMOUNT,
+            "metadata".as_bytes().to_vec()
+        ));
+    })
+}
+
+#[test]
+fn transfer_from_fails_when_pallet_frozen() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        ApproveFixture::default().execute_call().unwrap();
+        assert_ok!(Token::set_frozen_status(RawOrigin::Root.into(), true));
+
+        let result = TransferFromFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::PalletFrozen);
+    })
+}
+
+// --------------------- dust_account -------------------------------
+
+#[test]
+fn dust_account_ok_with_allowances_removed_and_bloat_bonds_refunded() {
+    let bloat_bond = joy!(100);
+    let config = GenesisConfigBuilder::new_empty()
+        .with_bloat_bond(bloat_bond)
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        increase_account_balance(&member!(1).1, ExistentialDeposit::get() + bloat_bond);
+        IssueTokenFixture::default().execute_call().unwrap();
+        increase_account_balance(&Token::module_treasury_account(), ExistentialDeposit::get());
+        increase_account_balance(&member!(1).1, 2 * bloat_bond);
+        ApproveFixture::default().execute_call().unwrap();
+        ApproveFixture::default()
+            .with_spender(AllowanceSpender::Account(member!(3).1))
+            .execute_call()
+            .unwrap();
+        assert_ok!(Token::burn(
+            origin!(member!(1).1),
+            token!(1),
+            member!(1).0,
+            DEFAULT_INITIAL_ISSUANCE
+        ));
+        let owner_balance_pre = Balances::usable_balance(member!(1).1);
+
+        assert_ok!(Token::dust_account(
+            origin!(member!(1).1),
+            token!(1),
+            member!(1).0
+        ));
+
+        assert!(Allowances::<Test>::iter_prefix((token!(1), member!(1).0))
+            .next()
+            .is_none());
+        assert!(!AllowancesNumber::<Test>::contains_key(
+            token!(1),
+            member!(1).0
+        ));
+        // both allowances bloat bonds and the account bloat bond refunded
+        assert_eq!(
+            Balances::usable_balance(member!(1).1),
+            owner_balance_pre + 3 * bloat_bond
+        );
+    })
+}
@@ -2,8 +2,14 @@
 
 use crate::tests::fixtures::*;
 use crate::tests::mock::*;
-use crate::types::{AmmCurve, AmmOperation, VestingScheduleParamsOf};
-use crate::{joy, last_event_eq, member, token, Error, RawEvent, RepayableBloatBondOf};
+use crate::types::{
+    AmmCurve, AmmCurveBreakpoint, AmmCurveKind, AmmCurveOf, AmmOperation, MaxAmmCurveBreakpoints,
+    VestingScheduleParamsOf,
+};
+use crate::{
+    joy, last_event_eq, member, merkle_root, token, Error, RawEvent, RepayableBloatBondOf,
+};
+use frame_support::traits::Get;
 use frame_support::{assert_err, assert_ok};
 use frame_system::RawOrigin;
 use sp_arithmetic::traits::One;
@@ -404,8 +410,7 @@ fn amm_activation_successful() {
         assert_eq!(
             IssuanceState::of::<Test>(&token),
             IssuanceState::Amm(AmmCurve {
-                slope,
-                intercept,
+                kind: AmmCurveKind::Linear { slope, intercept },
                 provided_supply: 0u32.into(),
             })
         );
@@ -446,14 +451,391 @@ fn amm_activation_ok_with_event_deposit() {
             token_id,
             creator_id,
             AmmCurve {
-                slope: AMM_CURVE_SLOPE,
-                intercept: AMM_CURVE_INTERCEPT,
+                kind: AmmCurveKind::Linear {
+                    slope: AMM_CURVE_SLOPE,
+                    intercept: AMM_CURVE_INTERCEPT,
+                },
                 provided_supply: 0u32.into(),
             }
         ));
     })
 }
 
+fn exponential_amm_params() -> AmmParams {
+    AmmParams::Exponential {
+        initial_price: 100u32.into(),
+        growth_rate: Permill::from_percent(10),
+        supply_step: 10u32.into(),
+    }
+}
+
+fn constant_product_amm_params() -> AmmParams {
+    AmmParams::ConstantProduct {
+        joy_reserve: 1_000_000u32.into(),
+        token_reserve: 1_000_000u32.into(),
+    }
+}
+
+fn piecewise_linear_amm_params() -> AmmParams {
+    AmmParams::PiecewiseLinear {
+        intercept: AMM_CURVE_INTERCEPT,
+        breakpoints: vec![
+            AmmCurveBreakpoint {
+                supply: 0u32.into(),
+                slope: AMM_CURVE_SLOPE,
+            },
+            AmmCurveBreakpoint {
+                supply: 500u32.into(),
+                slope: 2 * AMM_CURVE_SLOPE,
+            },
+        ],
+    }
+}
+
+fn amm_curve_with_supply(params: AmmParams, provided_supply: Balance) -> AmmCurveOf<Test> {
+    AmmCurve {
+        provided_supply,
+        ..AmmCurveOf::<Test>::from_params::<Test>(params, Zero::zero()).unwrap()
+    }
+}
+
+#[test]
+fn amm_activation_successful_with_non_linear_curves() {
+    let token_id = token!(1);
+
+    for params in [
+        exponential_amm_params(),
+        constant_product_amm_params(),
+        piecewise_linear_amm_params(),
+    ] {
+        let config = GenesisConfigBuilder::new_empty().build();
+        build_test_externalities(config).execute_with(|| {
+            IssueTokenFixture::default().execute_call().unwrap();
+
+            ActivateAmmFixture::default()
+                .with_params(params.clone())
+                .execute_call()
+                .unwrap();
+
+            assert_eq!(
+                Token::token_info_by_id(token_id).amm_curve,
+                Some(amm_curve_with_supply(params, Zero::zero()))
+            );
+        })
+    }
+}
+
+#[test]
+fn amm_activation_fails_with_invalid_exponential_curve_parameters() {
+    let config = GenesisConfigBuilder::new_empty().build();
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = ActivateAmmFixture::default()
+            .with_params(AmmParams::Exponential {
+                initial_price: 100u32.into(),
+                growth_rate: Permill::from_percent(10),
+                supply_step: Zero::zero(),
+            })
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InvalidCurveParameters);
+    })
+}
+
+#[test]
+fn amm_activation_fails_with_invalid_constant_product_curve_parameters() {
+    let config = GenesisConfigBuilder::new_empty().build();
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = ActivateAmmFixture::default()
+            .with_params(AmmParams::ConstantProduct {
+                joy_reserve: u128::MAX,
+                token_reserve: 2u32.into(),
+            })
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InvalidCurveParameters);
+    })
+}
+
+#[test]
+fn amm_activation_fails_with_piecewise_linear_breakpoints_not_starting_at_zero_supply() {
+    let config = GenesisConfigBuilder::new_empty().build();
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = ActivateAmmFixture::default()
+            .with_params(AmmParams::PiecewiseLinear {
+                intercept: AMM_CURVE_INTERCEPT,
+                breakpoints: vec![AmmCurveBreakpoint {
+                    supply: 1u32.into(),
+                    slope: AMM_CURVE_SLOPE,
+                }],
+            })
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InvalidAmmCurveBreakpoints);
+    })
+}
+
+#[test]
+fn amm_activation_fails_with_piecewise_linear_breakpoints_not_increasing() {
+    let config = GenesisConfigBuilder::new_empty().build();
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let breakpoint = AmmCurveBreakpoint {
+            supply: 0u32.into(),
+            slope: AMM_CURVE_SLOPE,
+        };
+        let result = ActivateAmmFixture::default()
+            .with_params(AmmParams::PiecewiseLinear {
+                intercept: AMM_CURVE_INTERCEPT,
+                breakpoints: vec![breakpoint, breakpoint],
+            })
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InvalidAmmCurveBreakpoints);
+    })
+}
+
+#[test]
+fn amm_activation_fails_with_too_many_piecewise_linear_breakpoints() {
+    let config = GenesisConfigBuilder::new_empty().build();
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let breakpoints = (0..=MaxAmmCurveBreakpoints::get())
+            .map(|index| AmmCurveBreakpoint {
+                supply: (index * 100).into(),
+                slope: AMM_CURVE_SLOPE,
+            })
+            .collect();
+        let result = ActivateAmmFixture::default()
+            .with_params(AmmParams::PiecewiseLinear {
+                intercept: AMM_CURVE_INTERCEPT,
+                breakpoints,
+            })
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::MaxAmmCurveBreakpointsExceeded);
+    })
+}
+
+#[test]
+fn amm_activation_fails_with_piecewise_linear_slope_too_low() {
+    let config = GenesisConfigBuilder::new_empty().build();
+    build_test_externalities(config).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = ActivateAmmFixture::default()
+            .with_params(AmmParams::PiecewiseLinear {
+                intercept: AMM_CURVE_INTERCEPT,
+                breakpoints: vec![AmmCurveBreakpoint {
+                    supply: 0u32.into(),
+                    slope: Zero::zero(),
+                }],
+            })
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::CurveSlopeParametersTooLow);
+    })
+}
+
+#[test]
+fn amm_eval_ok_with_exponential_curve() {
+    build_default_test_externalities().execute_with(|| {
+        // 10 * 100 + 10 * 110 + 5 * 121
+        let curve = amm_curve_with_supply(exponential_amm_params(), Zero::zero());
+        assert_eq!(
+            curve.eval::<Test>(25u32.into(), AmmOperation::Buy),
+            Ok(2705u32.into())
+        );
+
+        let curve = amm_curve_with_supply(exponential_amm_params(), 25u32.into());
+        assert_eq!(
+            curve.eval::<Test>(25u32.into(), AmmOperation::Sell),
+            Ok(2705u32.into())
+        );
+    })
+}
+
+#[test]
+fn amm_eval_with_exponential_curve_rounds_buys_up_and_sells_down() {
+    build_default_test_externalities().execute_with(|| {
+        let params = AmmParams::Exponential {
+            initial_price: 7u32.into(),
+            growth_rate: Permill::from_percent(3),
+            supply_step: 3u32.into(),
+        };
+
+        for amount in 1u32..30 {
+            let buy_price = amm_curve_with_supply(params.clone(), Zero::zero())
+                .eval::<Test>(amount.into(), AmmOperation::Buy)
+                .unwrap();
+            let sell_price = amm_curve_with_supply(params.clone(), amount.into())
+                .eval::<Test>(amount.into(), AmmOperation::Sell)
+                .unwrap();
+
+            assert!(sell_price <= buy_price);
+        }
+    })
+}
+
+#[test]
+fn amm_eval_ok_with_constant_product_curve() {
+    build_default_test_externalities().execute_with(|| {
+        // 1_000_000 * 1_000_000 / 500_000 - 1_000_000
+        let curve = amm_curve_with_supply(constant_product_amm_params(), Zero::zero());
+        assert_eq!(
+            curve.eval::<Test>(500_000u32.into(), AmmOperation::Buy),
+            Ok(1_000_000u32.into())
+        );
+    })
+}
+
+#[test]
+fn amm_eval_fails_with_constant_product_token_reserve_exhausted() {
+    build_default_test_externalities().execute_with(|| {
+        let curve = amm_curve_with_supply(constant_product_amm_params(), Zero::zero());
+        assert_eq!(
+            curve.eval::<Test>(1_000_000u32.into(), AmmOperation::Buy),
+            Err(Error::<Test>::InsufficientAmmTokenReserve.into())
+        );
+    })
+}
+
+#[test]
+fn amm_eval_ok_with_piecewise_linear_curve() {
+    build_default_test_externalities().execute_with(|| {
+        let params = AmmParams::PiecewiseLinear {
+            intercept: 100u32.into(),
+            breakpoints: vec![
+                AmmCurveBreakpoint {
+                    supply: 0u32.into(),
+                    slope: 2u32.into(),
+                },
+                AmmCurveBreakpoint {
+                    supply: 10u32.into(),
+                    slope: 4u32.into(),
+                },
+            ],
+        };
+
+        // (100 * 10 + 2 * 10^2 / 2) + (120 * 10 + 4 * 10^2 / 2)
+        let curve = amm_curve_with_supply(params.clone(), Zero::zero());
+        assert_eq!(
+            curve.eval::<Test>(20u32.into(), AmmOperation::Buy),
+            Ok(2500u32.into())
+        );
+
+        // (120 * 5 + 4 * 5^2 / 2)
+        let curve = amm_curve_with_supply(params, 15u32.into());
+        assert_eq!(
+            curve.eval::<Test>(5u32.into(), AmmOperation::Sell),
+            Ok(1100u32.into())
+        );
+    })
+}
+
+#[test]
+fn amm_buy_fails_with_constant_product_token_reserve_exhausted() {
+    let (_, user_account_id) = member!(2);
+    build_default_test_externalities_with_balances(vec![(user_account_id, Balance::from(u64::MAX))])
+        .execute_with(|| {
+            IssueTokenFixture::default().execute_call().unwrap();
+            ActivateAmmFixture::default()
+                .with_params(constant_product_amm_params())
+                .execute_call()
+                .unwrap();
+
+            let result = AmmBuyFixture::default()
+                .with_amount(1_000_000u32.into())
+                .execute_call();
+
+            assert_err!(result, Error::<Test>::InsufficientAmmTokenReserve);
+        })
+}
+
+#[test]
+fn amm_buy_fails_with_slippage_on_non_linear_curve() {
+    let (_, user_account_id) = member!(2);
+    build_default_test_externalities_with_balances(vec![(user_account_id, Balance::from(u64::MAX))])
+        .execute_with(|| {
+            IssueTokenFixture::default().execute_call().unwrap();
+            ActivateAmmFixture::default()
+                .with_params(constant_product_amm_params())
+                .execute_call()
+                .unwrap();
+
+            // price of the first half of the reserve is 1_000_000
+            let result = AmmBuyFixture::default()
+                .with_amount(500_000u32.into())
+                .with_slippage_tolerance((Permill::from_percent(10), 500_000u32.into()))
+                .execute_call();
+
+            assert_err!(result, Error::<Test>::SlippageToleranceExceeded);
+        })
+}
+
+#[test]
+fn amm_buy_and_sell_round_trips_cannot_drain_amm_treasury() {
+    let token_id = token!(1);
+    let (user_member_id, user_account_id) = member!(2);
+    let user_balance = Balance::from(u64::MAX);
+
+    for params in [
+        AmmParams::Linear {
+            slope: AMM_CURVE_SLOPE,
+            intercept: AMM_CURVE_INTERCEPT,
+        },
+        exponential_amm_params(),
+        constant_product_amm_params(),
+        piecewise_linear_amm_params(),
+    ] {
+        build_default_test_externalities_with_balances(vec![(user_account_id, user_balance)])
+            .execute_with(|| {
+                IssueTokenFixture::default().execute_call().unwrap();
+                ActivateAmmFixture::default()
+                    .with_params(params)
+                    .execute_call()
+                    .unwrap();
+
+                let amm_treasury_account = Token::amm_treasury_account(token_id);
+                let amm_treasury_pre =
+                    Balances::usable_balance(amm_treasury_account) + Token::bloat_bond();
+
+                // buy and sell the same amount using different chunks
+                for amount in [7u32, 993, 41, 459] {
+                    AmmBuyFixture::default()
+                        .with_sender(user_account_id)
+                        .with_member_id(user_member_id)
+                        .with_amount(amount.into())
+                        .execute_call()
+                        .unwrap();
+                }
+                for amount in [500u32, 1, 499, 300, 200] {
+                    AmmSellFixture::default()
+                        .with_sender(user_account_id)
+                        .with_member_id(user_member_id)
+                        .with_amount(amount.into())
+                        .execute_call()
+                        .unwrap();
+                }
+
+                let token = Token::token_info_by_id(token_id);
+                assert!(token.amm_curve.unwrap().provided_supply.is_zero());
+                assert_eq!(
+                    Balances::usable_balance(amm_treasury_account),
+                    amm_treasury_pre
+                );
+                assert!(Balances::usable_balance(user_account_id) < user_balance);
+            })
+    }
+}
+
 // --------------------- amm_sell -------------------------------
 // price = eval_function(amount, amm_sprovided_supply, slope, intercept)
 // user_balance += price - sell_fees
@@ -1134,3 +1516,293 @@ fn amm_sell_fails_when_pallet_frozen() {
         );
     })
 }
+
+// --------------------- amm_swap -------------------------------
+// user_balance += sell_price(token_in) - buy_price(token_out) - bloat_bond
+// token_in supply -= amount_in, token_out supply += amount_out
+
+fn swap_joy_shortfall() -> JoyBalance {
+    let buy_in = amm_function_buy_values_with_tx_fees(DEFAULT_AMM_BUY_AMOUNT, Zero::zero());
+    let sell_in =
+        amm_function_sell_values_with_tx_fees(DEFAULT_AMM_SELL_AMOUNT, DEFAULT_AMM_BUY_AMOUNT);
+    let buy_out = amm_function_buy_values_with_tx_fees(DEFAULT_AMM_BUY_AMOUNT, Zero::zero());
+    buy_in + buy_out - sell_in + DEFAULT_BLOAT_BOND
+}
+
+fn setup_swap() {
+    IssueTokenFixture::default().execute_call().unwrap();
+    IssueTokenFixture::default().execute_call().unwrap();
+    ActivateAmmFixture::default().execute_call().unwrap();
+    ActivateAmmFixture::default()
+        .with_token_id(token!(2))
+        .execute_call()
+        .unwrap();
+    AmmBuyFixture::default().execute_call().unwrap();
+}
+
+#[test]
+fn amm_swap_fails_with_same_token_specified() {
+    let (_, user_account_id) = member!(2);
+    build_default_test_externalities_with_balances(vec![(
+        user_account_id,
+        swap_joy_shortfall() + ed(),
+    )])
+    .execute_with(|| {
+        setup_swap();
+
+        let result = AmmSwapFixture::default()
+            .with_token_out_id(token!(1))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::SwapTokensMustDiffer);
+    })
+}
+
+#[test]
+fn amm_swap_fails_with_zero_amount() {
+    let (_, user_account_id) = member!(2);
+    build_default_test_externalities_with_balances(vec![(
+        user_account_id,
+        swap_joy_shortfall() + ed(),
+    )])
+    .execute_with(|| {
+        setup_swap();
+
+        let result = AmmSwapFixture::default()
+            .with_amount_out(0u32.into())
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::SwapAmountIsZero);
+    })
+}
+
+#[test]
+fn amm_swap_fails_with_token_out_not_in_amm_state() {
+    let (_, user_account_id) = member!(2);
+    build_default_test_externalities_with_balances(vec![(
+        user_account_id,
+        swap_joy_shortfall() + ed(),
+    )])
+    .execute_with(|| {
+        setup_swap();
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = AmmSwapFixture::default()
+            .with_token_out_id(token!(3))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::NotInAmmState);
+    })
+}
+
+#[test]
+fn amm_swap_fails_with_permissioned_token() {
+    let (_, user_account_id) = member!(2);
+    build_default_test_externalities_with_balances(vec![(
+        user_account_id,
+        swap_joy_shortfall() + ed(),
+    )])
+    .execute_with(|| {
+        setup_swap();
+        IssueTokenFixture::default()
+            .with_transfer_policy(TransferPolicyParams::Permissioned(WhitelistParams {
+                commitment: merkle_root![member!(1).0, member!(2).0],
+                payload: None,
+            }))
+            .execute_call()
+            .unwrap();
+        ActivateAmmFixture::default()
+            .with_token_id(token!(3))
+            .execute_call()
+            .unwrap();
+
+        let result = AmmSwapFixture::default()
+            .with_token_out_id(token!(3))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::CannotSwapPermissionedToken);
+    })
+}
+
+#[test]
+fn amm_swap_fails_with_insufficient_token_in_balance() {
+    let (_, user_account_id) = member!(2);
+    build_default_test_externalities_with_balances(vec![(
+        user_account_id,
+        swap_joy_shortfall() + ed(),
+    )])
+    .execute_with(|| {
+        setup_swap();
+
+        let result = AmmSwapFixture::default()
+            .with_amount_in(DEFAULT_AMM_BUY_AMOUNT + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientTokenBalance);
+    })
+}
+
+#[test]
+fn amm_swap_fails_with_max_joy_intermediate_exceeded() {
+    let (_, user_account_id) = member!(2);
+    build_default_test_externalities_with_balances(vec![(
+        user_account_id,
+        swap_joy_shortfall() + ed(),
+    )])
+    .execute_with(|| {
+        setup_swap();
+        let buy_price = amm_function_buy_values_with_tx_fees(DEFAULT_AMM_BUY_AMOUNT, Zero::zero());
+
+        let result = AmmSwapFixture::default()
+            .with_max_joy_intermediate(buy_price - 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::MaxJoyIntermediateExceeded);
+    })
+}
+
+#[test]
+fn amm_swap_fails_with_min_joy_intermediate_not_reached() {
+    let (_, user_account_id) = member!(2);
+    build_default_test_externalities_with_balances(vec![(
+        user_account_id,
+        swap_joy_shortfall() + ed(),
+    )])
+    .execute_with(|| {
+        setup_swap();
+        let sell_price =
+            amm_function_sell_values_with_tx_fees(DEFAULT_AMM_SELL_AMOUNT, DEFAULT_AMM_BUY_AMOUNT);
+
+        let result = AmmSwapFixture::default()
+            .with_min_joy_intermediate(sell_price + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::MinJoyIntermediateNotReached);
+    })
+}
+
+#[test]
+fn amm_swap_ok_with_min_joy_intermediate_reached() {
+    let (_, user_account_id) = member!(2);
+    build_default_test_externalities_with_balances(vec![(
+        user_account_id,
+        swap_joy_shortfall() + ed(),
+    )])
+    .execute_with(|| {
+        setup_swap();
+        let sell_price =
+            amm_function_sell_values_with_tx_fees(DEFAULT_AMM_SELL_AMOUNT, DEFAULT_AMM_BUY_AMOUNT);
+
+        let result = AmmSwapFixture::default()
+            .with_min_joy_intermediate(sell_price)
+            .execute_call();
+
+        assert_ok!(result);
+    })
+}
+
+#[test]
+fn amm_swap_fails_with_insufficient_joy_balance_for_shortfall() {
+    let (_, user_account_id) = member!(2);
+    build_default_test_externalities_with_balances(vec![(
+        user_account_id,
+        swap_joy_shortfall() + ed() - 1,
+    )])
+    .execute_with(|| {
+        setup_swap();
+
+        let result = AmmSwapFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientJoyBalance);
+    })
+}
+
+#[test]
+fn amm_swap_ok_with_crt_amounts_and_supply_correctly_updated() {
+    let (user_member_id, user_account_id) = member!(2);
+    build_default_test_externalities_with_balances(vec![(
+        user_account_id,
+        swap_joy_shortfall() + ed(),
+    )])
+    .execute_with(|| {
+        setup_swap();
+        let supply_in_pre = Token::token_info_by_id(token!(1)).total_supply;
+
+        AmmSwapFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), user_member_id).amount,
+            DEFAULT_AMM_BUY_AMOUNT - DEFAULT_AMM_SELL_AMOUNT
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(2), user_member_id).amount,
+            DEFAULT_AMM_BUY_AMOUNT
+        );
+        assert_eq!(
+            Token::token_info_by_id(token!(1)).total_supply,
+            supply_in_pre - DEFAULT_AMM_SELL_AMOUNT
+        );
+        assert_eq!(
+            Token::token_info_by_id(token!(2))
+                .amm_curve
+                .unwrap()
+                .provided_supply,
+            DEFAULT_AMM_BUY_AMOUNT
+        );
+    })
+}
+
+#[test]
+fn amm_swap_ok_with_joy_balances_correctly_updated() {
+    let (_, user_account_id) = member!(2);
+    build_default_test_externalities_with_balances(vec![(
+        user_account_id,
+        swap_joy_shortfall() + ed(),
+    )])
+    .execute_with(|| {
+        setup_swap();
+        let treasury_in_pre = Balances::usable_balance(Token::amm_treasury_account(token!(1)));
+        let treasury_out_pre = Balances::usable_balance(Token::amm_treasury_account(token!(2)));
+
+        AmmSwapFixture::default().execute_call().unwrap();
+
+        assert_eq!(Balances::usable_balance(user_account_id), ed());
+        assert_eq!(
+            treasury_in_pre - Balances::usable_balance(Token::amm_treasury_account(token!(1))),
+            amm_function_values(
+                DEFAULT_AMM_SELL_AMOUNT,
+                DEFAULT_AMM_BUY_AMOUNT,
+                AmmOperation::Sell
+            )
+        );
+        assert_eq!(
+            Balances::usable_balance(Token::amm_treasury_account(token!(2))) - treasury_out_pre,
+            amm_function_values(DEFAULT_AMM_BUY_AMOUNT, Zero::zero(), AmmOperation::Buy)
+                + DEFAULT_BLOAT_BOND
+        );
+    })
+}
+
+#[test]
+fn amm_swap_ok_with_event_deposited() {
+    let (user_member_id, user_account_id) = member!(2);
+    build_default_test_externalities_with_balances(vec![(
+        user_account_id,
+        swap_joy_shortfall() + ed(),
+    )])
+    .execute_with(|| {
+        setup_swap();
+
+        AmmSwapFixture::default().execute_call().unwrap();
+
+        last_event_eq!(RawEvent::TokensSwappedOnAmm(
+            user_member_id,
+            token!(1),
+            DEFAULT_AMM_SELL_AMOUNT,
+            token!(2),
+            DEFAULT_AMM_BUY_AMOUNT,
+            amm_function_sell_values_with_tx_fees(DEFAULT_AMM_SELL_AMOUNT, DEFAULT_AMM_BUY_AMOUNT),
+            amm_function_buy_values_with_tx_fees(DEFAULT_AMM_BUY_AMOUNT, Zero::zero()),
+        ));
+    })
+}
@@ -0,0 +1,445 @@
+#![cfg(test)]
+
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::traits::PalletToken;
+use crate::types::{BatchAuctionClearing, Joy};
+use crate::{last_event_eq, member, merkle_root, token, Error, RawEvent};
+use frame_support::assert_err;
+use frame_support::traits::Get;
+
+fn setup_batch_auction(bids: &[(u64, Balance, JoyBalance)]) {
+    IssueTokenFixture::default().call_and_assert(Ok(()));
+    InitBatchAuctionFixture::default()
+        .with_upper_bound_quantity(1000)
+        .execute_call()
+        .unwrap();
+    for (member, quantity, max_price) in bids.iter() {
+        increase_account_balance(&member!(*member).1, ed() + quantity * max_price);
+        PlaceBatchAuctionBidFixture::default()
+            .with_member(member!(*member))
+            .with_quantity(*quantity)
+            .with_max_price(*max_price)
+            .execute_call()
+            .unwrap();
+    }
+}
+
+// --------------------- init_batch_auction -------------------------------
+
+#[test]
+fn init_batch_auction_ok() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+
+        InitBatchAuctionFixture::default().execute_call().unwrap();
+
+        let token = Token::token_info_by_id(token!(1));
+        assert!(!token.sale.unwrap().auto_finalize);
+        assert_eq!(Token::batch_auction(token!(1)).bids_number, 0);
+        last_event_eq!(RawEvent::BatchAuctionInitialized(token!(1), 0));
+    })
+}
+
+#[test]
+fn purchase_tokens_on_sale_fails_on_batch_auction() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        setup_batch_auction(&[]);
+        increase_account_balance(
+            &member!(2).1,
+            ed() + DEFAULT_SALE_UNIT_PRICE * DEFAULT_SALE_PURCHASE_AMOUNT,
+        );
+
+        let result = Token::purchase_tokens_on_sale(
+            RuntimeOrigin::signed(member!(2).1),
+            token!(1),
+            member!(2).0,
+            DEFAULT_SALE_PURCHASE_AMOUNT,
+        );
+
+        assert_err!(result, Error::<Test>::CannotPurchaseOnBatchAuction);
+    })
+}
+
+// --------------------- place_batch_auction_bid -------------------------------
+
+#[test]
+fn place_batch_auction_bid_fails_without_batch_auction() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        InitTokenSaleFixture::default().call_and_assert(Ok(()));
+        increase_account_balance(
+            &member!(2).1,
+            ed() + DEFAULT_SALE_UNIT_PRICE * DEFAULT_SALE_PURCHASE_AMOUNT,
+        );
+
+        let result = PlaceBatchAuctionBidFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::NoBatchAuction);
+    })
+}
+
+#[test]
+fn place_batch_auction_bid_fails_with_price_below_reserve_price() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        setup_batch_auction(&[]);
+        increase_account_balance(
+            &member!(2).1,
+            ed() + DEFAULT_SALE_UNIT_PRICE * DEFAULT_SALE_PURCHASE_AMOUNT,
+        );
+
+        let result = PlaceBatchAuctionBidFixture::default()
+            .with_max_price(DEFAULT_SALE_UNIT_PRICE - 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::BatchAuctionBidPriceTooLow);
+    })
+}
+
+#[test]
+fn place_batch_auction_bid_fails_with_quantity_above_cap_per_member() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().call_and_assert(Ok(()));
+        InitBatchAuctionFixture::default()
+            .with_cap_per_member(DEFAULT_SALE_PURCHASE_AMOUNT - 1)
+            .execute_call()
+            .unwrap();
+        increase_account_balance(
+            &member!(2).1,
+            ed() + DEFAULT_SALE_UNIT_PRICE * DEFAULT_SALE_PURCHASE_AMOUNT,
+        );
+
+        let result = PlaceBatchAuctionBidFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::SalePurchaseCapExceeded);
+    })
+}
+
+#[test]
+fn place_batch_auction_bid_fails_with_permissioned_token_and_non_existing_account() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default()
+            .with_transfer_policy(TransferPolicyParams::Permissioned(WhitelistParams {
+                commitment: merkle_root![member!(1).0, member!(2).0],
+                payload: None,
+            }))
+            .call_and_assert(Ok(()));
+        InitBatchAuctionFixture::default().execute_call().unwrap();
+        increase_account_balance(
+            &member!(2).1,
+            ed() + DEFAULT_SALE_UNIT_PRICE * DEFAULT_SALE_PURCHASE_AMOUNT,
+        );
+
+        let result = PlaceBatchAuctionBidFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn place_batch_auction_bid_fails_when_max_bids_reached_and_price_not_above_lowest_bid() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        let max_bids = MaxBatchAuctionBids::get() as u64;
+        let bids: Vec<_> = (2..2 + max_bids)
+            .map(|member| (member, 10, DEFAULT_SALE_UNIT_PRICE))
+            .collect();
+        setup_batch_auction(&bids);
+        let bidder = member!(2 + max_bids);
+        increase_account_balance(&bidder.1, ed() + DEFAULT_SALE_UNIT_PRICE * 10);
+
+        let result = PlaceBatchAuctionBidFixture::default()
+            .with_member(bidder)
+            .with_quantity(10)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::BatchAuctionBidPriceNotAboveLowestBid);
+    })
+}
+
+#[test]
+fn place_batch_auction_bid_evicts_lowest_bid_when_max_bids_reached() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        let max_bids = MaxBatchAuctionBids::get() as u64;
+        // member 2 places the lowest bid
+        let bids: Vec<_> = (2..2 + max_bids)
+            .map(|member| (member, 10, DEFAULT_SALE_UNIT_PRICE + member - 2))
+            .collect();
+        setup_batch_auction(&bids);
+        let bidder = member!(2 + max_bids);
+        let max_price = DEFAULT_SALE_UNIT_PRICE + 1;
+        increase_account_balance(&bidder.1, ed() + max_price * 10);
+
+        PlaceBatchAuctionBidFixture::default()
+            .with_member(bidder)
+            .with_quantity(10)
+            .with_max_price(max_price)
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(Token::batch_auction(token!(1)).bids_number, max_bids as u32);
+        assert!(Token::batch_auction_bid(token!(1), member!(2).0).is_none());
+        assert!(Token::batch_auction_bid(token!(1), bidder.0).is_some());
+        // evicted lock refunded
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(2).1),
+            ed() + DEFAULT_SALE_UNIT_PRICE * 10
+        );
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::Token(RawEvent::BatchAuctionBidEvicted(token!(1), 0, member!(2).0))));
+    })
+}
+
+#[test]
+fn place_batch_auction_bid_locks_joy() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        setup_batch_auction(&[]);
+        let locked_amount = DEFAULT_SALE_UNIT_PRICE * DEFAULT_SALE_PURCHASE_AMOUNT;
+        increase_account_balance(&member!(2).1, ed() + locked_amount);
+        let treasury_balance = Joy::<Test>::usable_balance(Token::module_treasury_account());
+
+        PlaceBatchAuctionBidFixture::default()
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(
+            Token::batch_auction_bid(token!(1), member!(2).0),
+            Some(BatchAuctionBid {
+                bidder_account: member!(2).1,
+                quantity: DEFAULT_SALE_PURCHASE_AMOUNT,
+                max_price: DEFAULT_SALE_UNIT_PRICE,
+            })
+        );
+        assert_eq!(Token::batch_auction(token!(1)).bids_number, 1);
+        assert_eq!(Joy::<Test>::usable_balance(member!(2).1), ed());
+        assert_eq!(
+            Joy::<Test>::usable_balance(Token::module_treasury_account()),
+            treasury_balance + locked_amount
+        );
+        last_event_eq!(RawEvent::BatchAuctionBidPlaced(
+            token!(1),
+            0,
+            member!(2).0,
+            DEFAULT_SALE_PURCHASE_AMOUNT,
+            DEFAULT_SALE_UNIT_PRICE
+        ));
+    })
+}
+
+#[test]
+fn place_batch_auction_bid_replaces_previous_bid() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        setup_batch_auction(&[(2, 100, DEFAULT_SALE_UNIT_PRICE)]);
+        increase_account_balance(&member!(2).1, DEFAULT_SALE_UNIT_PRICE * 100);
+
+        PlaceBatchAuctionBidFixture::default()
+            .with_quantity(100)
+            .with_max_price(DEFAULT_SALE_UNIT_PRICE * 2)
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(Token::batch_auction(token!(1)).bids_number, 1);
+        assert_eq!(
+            Token::batch_auction_bid(token!(1), member!(2).0).map(|bid| bid.max_price),
+            Some(DEFAULT_SALE_UNIT_PRICE * 2)
+        );
+        // previous lock refunded
+        assert_eq!(Joy::<Test>::usable_balance(member!(2).1), ed());
+    })
+}
+
+// --------------------- settle_batch_auction -------------------------------
+
+#[test]
+fn settle_batch_auction_fails_before_sale_end() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        setup_batch_auction(&[(2, 100, DEFAULT_SALE_UNIT_PRICE)]);
+
+        let result = SettleBatchAuctionFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::BatchAuctionNotEnded);
+    })
+}
+
+#[test]
+fn settle_batch_auction_fails_when_already_cleared() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        setup_batch_auction(&[(2, 100, DEFAULT_SALE_UNIT_PRICE)]);
+        increase_block_number_by(DEFAULT_SALE_DURATION);
+        SettleBatchAuctionFixture::default().execute_call().unwrap();
+
+        let result = SettleBatchAuctionFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::BatchAuctionAlreadyCleared);
+    })
+}
+
+#[test]
+fn settle_batch_auction_determines_uniform_clearing_price() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        setup_batch_auction(&[(2, 600, 30), (3, 400, 20), (4, 400, 20), (5, 300, 10)]);
+        increase_block_number_by(DEFAULT_SALE_DURATION);
+
+        SettleBatchAuctionFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Token::batch_auction(token!(1)).clearing,
+            Some(BatchAuctionClearing {
+                price: 20,
+                marginal_supply: 400,
+                marginal_demand: 800,
+            })
+        );
+        last_event_eq!(RawEvent::BatchAuctionCleared(token!(1), 0, 20));
+    })
+}
+
+// --------------------- settle_batch_auction_bid -------------------------------
+
+#[test]
+fn settle_batch_auction_bid_fails_before_clearing() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        setup_batch_auction(&[(2, 100, DEFAULT_SALE_UNIT_PRICE)]);
+        increase_block_number_by(DEFAULT_SALE_DURATION);
+
+        let result = SettleBatchAuctionBidFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::BatchAuctionNotCleared);
+    })
+}
+
+#[test]
+fn settle_batch_auction_bid_fails_with_non_existing_bid() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        setup_batch_auction(&[(2, 100, DEFAULT_SALE_UNIT_PRICE)]);
+        increase_block_number_by(DEFAULT_SALE_DURATION);
+        SettleBatchAuctionFixture::default().execute_call().unwrap();
+
+        let result = SettleBatchAuctionBidFixture::default()
+            .with_member_id(member!(3).0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::BatchAuctionBidDoesNotExist);
+    })
+}
+
+#[test]
+fn settle_batch_auction_bid_fills_bids_at_clearing_price() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        let bids = [(2, 600, 30), (3, 400, 20), (4, 400, 20), (5, 300, 10)];
+        setup_batch_auction(&bids);
+        increase_block_number_by(DEFAULT_SALE_DURATION);
+        SettleBatchAuctionFixture::default().execute_call().unwrap();
+
+        // bids above the clearing price are filled in full, bids at the clearing price
+        // share the remaining supply pro-rata and bids below it are refunded
+        for ((member, quantity, max_price), filled) in
+            bids.iter().zip([600, 200, 200, 0].iter().copied())
+        {
+            SettleBatchAuctionBidFixture::default()
+                .with_member_id(member!(*member).0)
+                .execute_call()
+                .unwrap();
+
+            let payment = filled * 20;
+            last_event_eq!(RawEvent::BatchAuctionBidSettled(
+                token!(1),
+                0,
+                member!(*member).0,
+                filled,
+                payment
+            ));
+            assert_eq!(
+                Token::account_info_by_token_and_member(token!(1), member!(*member).0).amount,
+                filled
+            );
+            assert_eq!(
+                Joy::<Test>::usable_balance(member!(*member).1),
+                ed() + quantity * max_price - payment
+            );
+        }
+
+        let sale = Token::token_info_by_id(token!(1)).sale.unwrap();
+        assert_eq!(sale.quantity_left, 0);
+        assert_eq!(sale.funds_collected, 20_000);
+        assert_eq!(Token::batch_auction(token!(1)).bids_number, 0);
+    })
+}
+
+#[test]
+fn settle_batch_auction_bid_fills_all_bids_at_lowest_price_when_undersubscribed() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        setup_batch_auction(&[(2, 300, 30), (3, 200, 15)]);
+        increase_block_number_by(DEFAULT_SALE_DURATION);
+        SettleBatchAuctionFixture::default().execute_call().unwrap();
+
+        SettleBatchAuctionBidFixture::default()
+            .execute_call()
+            .unwrap();
+        SettleBatchAuctionBidFixture::default()
+            .with_member_id(member!(3).0)
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(2).0).amount,
+            300
+        );
+        assert_eq!(Joy::<Test>::usable_balance(member!(2).1), ed() + 300 * 15);
+        assert_eq!(
+            Token::token_info_by_id(token!(1))
+                .sale
+                .unwrap()
+                .quantity_left,
+            500
+        );
+    })
+}
+
+// --------------------- finalize_token_sale -------------------------------
+
+#[test]
+fn finalize_batch_auction_fails_with_unsettled_bids() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        setup_batch_auction(&[(2, 300, 30), (3, 200, 15)]);
+        increase_block_number_by(DEFAULT_SALE_DURATION);
+
+        assert_err!(
+            Token::finalize_token_sale(token!(1)),
+            Error::<Test>::BatchAuctionNotCleared
+        );
+
+        SettleBatchAuctionFixture::default().execute_call().unwrap();
+        SettleBatchAuctionBidFixture::default()
+            .execute_call()
+            .unwrap();
+
+        assert_err!(
+            Token::finalize_token_sale(token!(1)),
+            Error::<Test>::BatchAuctionHasUnsettledBids
+        );
+    })
+}
+
+#[test]
+fn finalize_batch_auction_ok() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        setup_batch_auction(&[(2, 300, 30)]);
+        increase_block_number_by(DEFAULT_SALE_DURATION);
+        SettleBatchAuctionFixture::default().execute_call().unwrap();
+        SettleBatchAuctionBidFixture::default()
+            .execute_call()
+            .unwrap();
+        let source_amount = Token::account_info_by_token_and_member(token!(1), member!(1).0).amount;
+
+        let funds_collected = Token::finalize_token_sale(token!(1)).unwrap();
+
+        assert_eq!(funds_collected, 300 * 30);
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(1).0).amount,
+            source_amount + 700
+        );
+        assert!(Token::token_info_by_id(token!(1)).sale.is_none());
+        assert_eq!(Token::batch_auction(token!(1)), Default::default());
+        last_event_eq!(RawEvent::TokenSaleFinalized(token!(1), 0, 700, 300 * 30));
+    })
+}
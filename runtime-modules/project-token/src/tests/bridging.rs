@@ -0,0 +1,250 @@
+#![cfg(test)]
+
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::{last_event_eq, member, origin, token, Error, RawEvent};
+use frame_support::{assert_err, assert_ok};
+
+// --------------------- update_token_bridging -------------------------------
+
+#[test]
+fn update_token_bridging_fails_with_non_existing_token() {
+    build_default_test_externalities().execute_with(|| {
+        let result = UpdateTokenBridgingFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn update_token_bridging_ok() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = UpdateTokenBridgingFixture::default().execute_call();
+
+        assert_ok!(result);
+        assert!(Token::token_bridging(token!(1)).enabled);
+        last_event_eq!(RawEvent::TokenBridgingUpdated(token!(1), true));
+    })
+}
+
+#[test]
+fn update_token_bridging_ok_with_bridging_disabled() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        UpdateTokenBridgingFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = UpdateTokenBridgingFixture::default()
+            .with_enabled(false)
+            .execute_call();
+
+        assert_ok!(result);
+        assert!(!Token::token_bridging(token!(1)).enabled);
+        last_event_eq!(RawEvent::TokenBridgingUpdated(token!(1), false));
+    })
+}
+
+// --------------------- escrow_bridged_tokens -------------------------------
+
+#[test]
+fn escrow_bridged_tokens_fails_with_invalid_sender() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        UpdateTokenBridgingFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = EscrowBridgedTokensFixture::default()
+            .with_sender(member!(2).1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::BridgeSenderNotMemberController);
+    })
+}
+
+#[test]
+fn escrow_bridged_tokens_fails_with_non_existing_token() {
+    build_default_test_externalities().execute_with(|| {
+        let result = EscrowBridgedTokensFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn escrow_bridged_tokens_fails_with_bridging_not_enabled() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = EscrowBridgedTokensFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenBridgingNotEnabled);
+    })
+}
+
+#[test]
+fn escrow_bridged_tokens_fails_with_non_existing_account() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        UpdateTokenBridgingFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = EscrowBridgedTokensFixture::default()
+            .with_member(member!(2))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn escrow_bridged_tokens_fails_with_insufficient_transferrable_balance() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        UpdateTokenBridgingFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = EscrowBridgedTokensFixture::default()
+            .with_amount(DEFAULT_INITIAL_ISSUANCE + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientTransferrableBalance);
+    })
+}
+
+#[test]
+fn escrow_bridged_tokens_ok() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        UpdateTokenBridgingFixture::default()
+            .execute_call()
+            .unwrap();
+        let supply_pre = Token::token_info_by_id(token!(1)).total_supply;
+
+        let result = EscrowBridgedTokensFixture::default().execute_call();
+
+        assert_ok!(result);
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_BRIDGED_AMOUNT
+        );
+        assert_eq!(
+            Token::token_bridging(token!(1)).escrowed_amount,
+            DEFAULT_BRIDGED_AMOUNT
+        );
+        assert_eq!(Token::token_info_by_id(token!(1)).total_supply, supply_pre);
+        last_event_eq!(RawEvent::TokensEscrowedForBridge(
+            token!(1),
+            member!(1).0,
+            DEFAULT_BRIDGED_AMOUNT
+        ));
+    })
+}
+
+// --------------------- release_bridged_tokens -------------------------------
+
+#[test]
+fn release_bridged_tokens_fails_with_non_existing_token() {
+    build_default_test_externalities().execute_with(|| {
+        let result = ReleaseBridgedTokensFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn release_bridged_tokens_fails_with_insufficient_bridged_tokens() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        UpdateTokenBridgingFixture::default()
+            .execute_call()
+            .unwrap();
+        EscrowBridgedTokensFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = ReleaseBridgedTokensFixture::default()
+            .with_amount(DEFAULT_BRIDGED_AMOUNT + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientBridgedTokens);
+    })
+}
+
+#[test]
+fn release_bridged_tokens_fails_with_non_existing_account() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        UpdateTokenBridgingFixture::default()
+            .execute_call()
+            .unwrap();
+        EscrowBridgedTokensFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let result = ReleaseBridgedTokensFixture::default()
+            .with_member_id(member!(2).0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn release_bridged_tokens_ok_with_bridging_disabled() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        UpdateTokenBridgingFixture::default()
+            .execute_call()
+            .unwrap();
+        EscrowBridgedTokensFixture::default()
+            .execute_call()
+            .unwrap();
+        UpdateTokenBridgingFixture::default()
+            .with_enabled(false)
+            .execute_call()
+            .unwrap();
+
+        let result = ReleaseBridgedTokensFixture::default().execute_call();
+
+        assert_ok!(result);
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE
+        );
+        assert_eq!(Token::token_bridging(token!(1)).escrowed_amount, 0);
+        last_event_eq!(RawEvent::BridgedTokensReleased(
+            token!(1),
+            member!(1).0,
+            DEFAULT_BRIDGED_AMOUNT
+        ));
+    })
+}
+
+// --------------------- deissue_token -------------------------------
+
+#[test]
+fn deissue_token_fails_with_bridged_tokens() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default()
+            .with_supply(DEFAULT_BRIDGED_AMOUNT)
+            .execute_call()
+            .unwrap();
+        UpdateTokenBridgingFixture::default()
+            .execute_call()
+            .unwrap();
+        EscrowBridgedTokensFixture::default()
+            .execute_call()
+            .unwrap();
+        Token::dust_account(origin!(member!(1).1), token!(1), member!(1).0).unwrap();
+
+        let result = Token::deissue_token(token!(1));
+
+        assert_err!(result, Error::<Test>::CannotDeissueTokenWithBridgedTokens);
+    })
+}
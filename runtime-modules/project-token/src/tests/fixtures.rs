@@ -2,14 +2,17 @@
 
 use crate::tests::mock::*;
 use crate::tests::test_utils::new_transfers;
-use crate::types::{Joy, TransferOutputsOf};
+use crate::types::{
+    AirdropId, Joy, MerkleProofOf, OrderId, OrderSide, PollId, PollVoteWeighting, TransferOutputsOf,
+};
 use crate::{
-    last_event_eq, member, yearly_rate, AccountInfoByTokenAndMember, RawEvent, YearlyRate,
+    last_event_eq, member, token, yearly_rate, AccountInfoByTokenAndMember, RawEvent, YearlyRate,
 };
 use crate::{traits::PalletToken, types::VestingSource};
+use common::bridge::CreatorTokenBridge;
 use frame_support::dispatch::DispatchResult;
 use frame_support::storage::StorageDoubleMap;
-use sp_arithmetic::traits::One;
+use sp_arithmetic::traits::{One, Zero};
 use sp_runtime::{DispatchError, Permill};
 
 use sp_std::collections::btree_map::BTreeMap;
@@ -851,30 +854,35 @@ impl IssueRevenueSplitFixture {
     }
 }
 
-/// Finalize Revenue Split
-pub struct FinalizeRevenueSplitFixture {
+pub struct DepositStakingRevenueFixture {
     token_id: TokenId,
-    account_id: AccountId,
+    revenue_source_account: AccountId,
+    revenue_amount: JoyBalance,
 }
 
-impl FinalizeRevenueSplitFixture {
+impl DepositStakingRevenueFixture {
     pub fn default() -> Self {
         Self {
             token_id: TokenId::one(),
-            account_id: member!(1).1,
+            revenue_source_account: member!(1).1,
+            revenue_amount: Balance::from(DEFAULT_SPLIT_REVENUE),
         }
     }
 
-    pub fn with_token_id(self, id: u64) -> Self {
+    pub fn with_revenue_amount(self, amount: u128) -> Self {
         Self {
-            token_id: id.into(),
+            revenue_amount: amount.into(),
             ..self
         }
     }
 
-    pub fn execute_call(&self) -> DispatchResult {
+    pub fn execute_call(&self) -> Result<JoyBalance, DispatchError> {
         let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
-        let result = Token::finalize_revenue_split(self.token_id, self.account_id);
+        let result = Token::deposit_staking_revenue(
+            self.token_id,
+            self.revenue_source_account,
+            self.revenue_amount,
+        );
         let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
 
         // no-op in case of error
@@ -886,56 +894,74 @@ impl FinalizeRevenueSplitFixture {
     }
 }
 
-pub struct ParticipateInSplitFixture {
-    sender: AccountId,
+pub struct UpdateTokenBridgingFixture {
     token_id: TokenId,
-    member_id: MemberId,
-    amount: Balance,
+    enabled: bool,
 }
 
-impl ParticipateInSplitFixture {
+impl UpdateTokenBridgingFixture {
     pub fn default() -> Self {
         Self {
-            sender: member!(2).1,
-            token_id: TokenId::one(),
-            member_id: member!(2).0,
-            amount: DEFAULT_SPLIT_PARTICIPATION.into(),
+            token_id: token!(1),
+            enabled: true,
         }
     }
 
-    pub fn with_amount(self, amount: u128) -> Self {
-        Self {
-            amount: amount.into(),
-            ..self
+    pub fn with_enabled(self, enabled: bool) -> Self {
+        Self { enabled, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::update_token_bridging(self.token_id, self.enabled);
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
         }
+
+        result
     }
+}
 
-    pub fn with_token_id(self, token_id: u64) -> Self {
+pub struct EscrowBridgedTokensFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    member_id: MemberId,
+    amount: Balance,
+}
+
+impl EscrowBridgedTokensFixture {
+    pub fn default() -> Self {
         Self {
-            token_id: token_id.into(),
-            ..self
+            sender: member!(1).1,
+            token_id: token!(1),
+            member_id: member!(1).0,
+            amount: DEFAULT_BRIDGED_AMOUNT,
         }
     }
 
-    pub fn with_sender(self, account_id: u64) -> Self {
+    pub fn with_member(self, (member_id, sender): (MemberId, AccountId)) -> Self {
         Self {
-            sender: account_id.into(),
+            member_id,
+            sender,
             ..self
         }
     }
 
-    pub fn with_member_id(self, member_id: u64) -> Self {
-        Self { member_id, ..self }
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_amount(self, amount: Balance) -> Self {
+        Self { amount, ..self }
     }
 
     pub fn execute_call(&self) -> DispatchResult {
         let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
-        let result = Token::participate_in_split(
-            RuntimeOrigin::signed(self.sender),
-            self.token_id,
-            self.member_id,
-            self.amount,
-        );
+        let result =
+            Token::escrow_bridged_tokens(self.token_id, self.member_id, &self.sender, self.amount);
         let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
 
         // no-op in case of error
@@ -947,35 +973,32 @@ impl ParticipateInSplitFixture {
     }
 }
 
-pub struct TransferFixture {
-    sender: AccountId,
+pub struct ReleaseBridgedTokensFixture {
     token_id: TokenId,
-    src_member_id: MemberId,
-    outputs: TransferOutputsOf<Test>,
-    metadata: Vec<u8>,
+    member_id: MemberId,
+    amount: Balance,
 }
 
-impl TransferFixture {
+impl ReleaseBridgedTokensFixture {
     pub fn default() -> Self {
-        let outputs = new_transfers(vec![(member!(2).0, DEFAULT_SPLIT_PARTICIPATION)]);
         Self {
-            sender: member!(1).1,
-            token_id: 1u64.into(),
-            src_member_id: member!(1).0,
-            outputs,
-            metadata: "metadata".as_bytes().to_vec(),
+            token_id: token!(1),
+            member_id: member!(1).0,
+            amount: DEFAULT_BRIDGED_AMOUNT,
         }
     }
 
+    pub fn with_member_id(self, member_id: MemberId) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_amount(self, amount: Balance) -> Self {
+        Self { amount, ..self }
+    }
+
     pub fn execute_call(&self) -> DispatchResult {
         let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
-        let result = Token::transfer(
-            RuntimeOrigin::signed(self.sender),
-            self.src_member_id,
-            self.token_id,
-            self.outputs.clone(),
-            self.metadata.clone(),
-        );
+        let result = Token::release_bridged_tokens(self.token_id, self.member_id, self.amount);
         let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
 
         // no-op in case of error
@@ -987,45 +1010,46 @@ impl TransferFixture {
     }
 }
 
-pub struct ExitRevenueSplitFixture {
+pub struct StakeForRevenueFixture {
     sender: AccountId,
     token_id: TokenId,
     member_id: MemberId,
+    amount: Balance,
 }
 
-impl ExitRevenueSplitFixture {
+impl StakeForRevenueFixture {
     pub fn default() -> Self {
         Self {
-            sender: member!(2).1,
-            token_id: TokenId::one(),
-            member_id: member!(2).0,
+            sender: member!(1).1,
+            token_id: token!(1),
+            member_id: member!(1).0,
+            amount: DEFAULT_SPLIT_PARTICIPATION,
         }
     }
 
-    pub fn with_account(self, account_id: u64) -> Self {
+    pub fn with_member(self, (member_id, sender): (MemberId, AccountId)) -> Self {
         Self {
-            sender: account_id.into(),
+            member_id,
+            sender,
             ..self
         }
     }
 
-    pub fn with_token_id(self, token_id: u64) -> Self {
-        Self {
-            token_id: token_id.into(),
-            ..self
-        }
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
     }
 
-    pub fn with_member_id(self, member_id: u64) -> Self {
-        Self { member_id, ..self }
+    pub fn with_amount(self, amount: Balance) -> Self {
+        Self { amount, ..self }
     }
 
     pub fn execute_call(&self) -> DispatchResult {
         let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
-        let result = Token::exit_revenue_split(
+        let result = Token::stake_for_revenue(
             RuntimeOrigin::signed(self.sender),
             self.token_id,
             self.member_id,
+            self.amount,
         );
         let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
 
@@ -1038,45 +1062,43 @@ impl ExitRevenueSplitFixture {
     }
 }
 
-pub struct ActivateAmmFixture {
+pub struct UnstakeFromRevenueFixture {
+    sender: AccountId,
     token_id: TokenId,
     member_id: MemberId,
-    params: AmmParams,
+    amount: Balance,
 }
 
-impl ActivateAmmFixture {
+impl UnstakeFromRevenueFixture {
     pub fn default() -> Self {
-        let (creator_member_id, _) = member!(1);
-        ActivateAmmFixture {
-            token_id: TokenId::one(),
-            member_id: creator_member_id,
-            params: AmmParams {
-                // like Deso: https://docs.deso.org/about-deso-chain/readme#the-creator-coin-supply-curve
-                slope: AMM_CURVE_SLOPE,
-                intercept: AMM_CURVE_INTERCEPT,
-            },
+        Self {
+            sender: member!(1).1,
+            token_id: token!(1),
+            member_id: member!(1).0,
+            amount: DEFAULT_SPLIT_PARTICIPATION,
         }
     }
 
-    pub fn with_token_id(self, token_id: TokenId) -> Self {
-        Self { token_id, ..self }
-    }
-
-    pub fn with_member_id(self, member_id: MemberId) -> Self {
-        Self { member_id, ..self }
+    pub fn with_member(self, (member_id, sender): (MemberId, AccountId)) -> Self {
+        Self {
+            member_id,
+            sender,
+            ..self
+        }
     }
 
-    pub fn with_linear_function_params(self, a: Balance, b: Balance) -> Self {
-        let params = AmmParams {
-            slope: a,
-            intercept: b,
-        };
-        Self { params, ..self }
+    pub fn with_amount(self, amount: Balance) -> Self {
+        Self { amount, ..self }
     }
 
     pub fn execute_call(&self) -> DispatchResult {
         let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
-        let result = Token::activate_amm(self.token_id, self.member_id, self.params.clone());
+        let result = Token::unstake_from_revenue(
+            RuntimeOrigin::signed(self.sender),
+            self.token_id,
+            self.member_id,
+            self.amount,
+        );
         let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
 
         // no-op in case of error
@@ -1088,57 +1110,35 @@ impl ActivateAmmFixture {
     }
 }
 
-pub struct AmmBuyFixture {
+pub struct ClaimStakingRewardsFixture {
     sender: AccountId,
     token_id: TokenId,
     member_id: MemberId,
-    amount: Balance,
-    slippage_tolerance: Option<(Permill, Balance)>,
 }
 
-impl AmmBuyFixture {
+impl ClaimStakingRewardsFixture {
     pub fn default() -> Self {
-        let (member_id, sender) = member!(2);
         Self {
-            sender,
-            token_id: One::one(),
-            member_id,
-            amount: Balance::from(DEFAULT_AMM_BUY_AMOUNT),
-            slippage_tolerance: None,
+            sender: member!(1).1,
+            token_id: token!(1),
+            member_id: member!(1).0,
         }
     }
 
-    pub fn with_amount(self, amount: Balance) -> Self {
-        Self { amount, ..self }
-    }
-
-    pub fn with_token_id(self, token_id: TokenId) -> Self {
-        Self { token_id, ..self }
-    }
-
-    pub fn with_sender(self, sender: AccountId) -> Self {
-        Self { sender, ..self }
-    }
-
-    pub fn with_member_id(self, member_id: MemberId) -> Self {
-        Self { member_id, ..self }
-    }
-
-    pub fn with_slippage_tolerance(self, tolerance: (Permill, Balance)) -> Self {
+    pub fn with_member(self, (member_id, sender): (MemberId, AccountId)) -> Self {
         Self {
-            slippage_tolerance: Some(tolerance),
+            member_id,
+            sender,
             ..self
         }
     }
 
-    pub fn execute_call(self) -> DispatchResult {
+    pub fn execute_call(&self) -> DispatchResult {
         let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
-        let result = Token::buy_on_amm(
+        let result = Token::claim_staking_rewards(
             RuntimeOrigin::signed(self.sender),
             self.token_id,
             self.member_id,
-            self.amount,
-            self.slippage_tolerance,
         );
         let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
 
@@ -1151,57 +1151,64 @@ impl AmmBuyFixture {
     }
 }
 
-pub struct AmmSellFixture {
+pub struct PlaceLimitOrderFixture {
     sender: AccountId,
     token_id: TokenId,
     member_id: MemberId,
-    amount: Balance,
-    slippage_tolerance: Option<(Permill, Balance)>,
+    side: OrderSide,
+    quantity: Balance,
+    price: JoyBalance,
 }
 
-impl AmmSellFixture {
+impl PlaceLimitOrderFixture {
     pub fn default() -> Self {
-        let (member_id, sender) = member!(2);
         Self {
-            sender,
-            token_id: One::one(),
+            sender: member!(1).1,
+            token_id: token!(1),
+            member_id: member!(1).0,
+            side: OrderSide::Sell,
+            quantity: DEFAULT_ORDER_QUANTITY,
+            price: DEFAULT_ORDER_PRICE,
+        }
+    }
+
+    pub fn with_member(self, (member_id, sender): (MemberId, AccountId)) -> Self {
+        Self {
             member_id,
-            amount: Balance::from(DEFAULT_AMM_SELL_AMOUNT),
-            slippage_tolerance: None,
+            sender,
+            ..self
         }
     }
 
-    pub fn with_amount(self, amount: Balance) -> Self {
-        Self { amount, ..self }
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
     }
 
     pub fn with_token_id(self, token_id: TokenId) -> Self {
         Self { token_id, ..self }
     }
 
-    pub fn with_sender(self, sender: AccountId) -> Self {
-        Self { sender, ..self }
+    pub fn with_side(self, side: OrderSide) -> Self {
+        Self { side, ..self }
     }
 
-    pub fn with_member_id(self, member_id: MemberId) -> Self {
-        Self { member_id, ..self }
+    pub fn with_quantity(self, quantity: Balance) -> Self {
+        Self { quantity, ..self }
     }
 
-    pub fn with_slippage_tolerance(self, tolerance: (Permill, Balance)) -> Self {
-        Self {
-            slippage_tolerance: Some(tolerance),
-            ..self
-        }
+    pub fn with_price(self, price: JoyBalance) -> Self {
+        Self { price, ..self }
     }
 
-    pub fn execute_call(self) -> DispatchResult {
+    pub fn execute_call(&self) -> DispatchResult {
         let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
-        let result = Token::sell_on_amm(
+        let result = Token::place_limit_order(
             RuntimeOrigin::signed(self.sender),
             self.token_id,
             self.member_id,
-            self.amount,
-            self.slippage_tolerance,
+            self.side,
+            self.quantity,
+            self.price,
         );
         let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
 
@@ -1214,16 +1221,1026 @@ impl AmmSellFixture {
     }
 }
 
-pub struct DeactivateAmmFixture {
+pub struct CancelLimitOrderFixture {
+    sender: AccountId,
     token_id: TokenId,
     member_id: MemberId,
+    order_id: OrderId,
 }
 
-impl DeactivateAmmFixture {
+impl CancelLimitOrderFixture {
     pub fn default() -> Self {
-        let (member_id, _) = member!(1);
         Self {
-            token_id: TokenId::one(),
+            sender: member!(1).1,
+            token_id: token!(1),
+            member_id: member!(1).0,
+            order_id: 0,
+        }
+    }
+
+    pub fn with_member(self, (member_id, sender): (MemberId, AccountId)) -> Self {
+        Self {
+            member_id,
+            sender,
+            ..self
+        }
+    }
+
+    pub fn with_order_id(self, order_id: OrderId) -> Self {
+        Self { order_id, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::cancel_limit_order(
+            RuntimeOrigin::signed(self.sender),
+            self.token_id,
+            self.member_id,
+            self.order_id,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct MatchLimitOrdersFixture {
+    sender: AccountId,
+    token_id: TokenId,
+}
+
+impl MatchLimitOrdersFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(3).1,
+            token_id: token!(1),
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::match_limit_orders(RuntimeOrigin::signed(self.sender), self.token_id);
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+/// Finalize Revenue Split
+pub struct FinalizeRevenueSplitFixture {
+    token_id: TokenId,
+    account_id: AccountId,
+}
+
+impl FinalizeRevenueSplitFixture {
+    pub fn default() -> Self {
+        Self {
+            token_id: TokenId::one(),
+            account_id: member!(1).1,
+        }
+    }
+
+    pub fn with_token_id(self, id: u64) -> Self {
+        Self {
+            token_id: id.into(),
+            ..self
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::finalize_revenue_split(self.token_id, self.account_id);
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct ParticipateInSplitFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    member_id: MemberId,
+    amount: Balance,
+}
+
+impl ParticipateInSplitFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(2).1,
+            token_id: TokenId::one(),
+            member_id: member!(2).0,
+            amount: DEFAULT_SPLIT_PARTICIPATION.into(),
+        }
+    }
+
+    pub fn with_amount(self, amount: u128) -> Self {
+        Self {
+            amount: amount.into(),
+            ..self
+        }
+    }
+
+    pub fn with_token_id(self, token_id: u64) -> Self {
+        Self {
+            token_id: token_id.into(),
+            ..self
+        }
+    }
+
+    pub fn with_sender(self, account_id: u64) -> Self {
+        Self {
+            sender: account_id.into(),
+            ..self
+        }
+    }
+
+    pub fn with_member_id(self, member_id: u64) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::participate_in_split(
+            RuntimeOrigin::signed(self.sender),
+            self.token_id,
+            self.member_id,
+            self.amount,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct TransferFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    src_member_id: MemberId,
+    outputs: TransferOutputsOf<Test>,
+    metadata: Vec<u8>,
+}
+
+impl TransferFixture {
+    pub fn default() -> Self {
+        let outputs = new_transfers(vec![(member!(2).0, DEFAULT_SPLIT_PARTICIPATION)]);
+        Self {
+            sender: member!(1).1,
+            token_id: 1u64.into(),
+            src_member_id: member!(1).0,
+            outputs,
+            metadata: "metadata".as_bytes().to_vec(),
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::transfer(
+            RuntimeOrigin::signed(self.sender),
+            self.src_member_id,
+            self.token_id,
+            self.outputs.clone(),
+            self.metadata.clone(),
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct ApproveFixture {
+    sender: AccountId,
+    owner_member_id: MemberId,
+    token_id: TokenId,
+    spender: AllowanceSpender,
+    amount: Balance,
+    expires_at: Option<BlockNumber>,
+}
+
+impl ApproveFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(1).1,
+            owner_member_id: member!(1).0,
+            token_id: token!(1),
+            spender: AllowanceSpender::Member(member!(2).0),
+            amount: DEFAULT_ALLOWANCE_AMOUNT,
+            expires_at: None,
+        }
+    }
+
+    pub fn with_owner(self, (owner_member_id, sender): (MemberId, AccountId)) -> Self {
+        Self {
+            owner_member_id,
+            sender,
+            ..self
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_spender(self, spender: AllowanceSpender) -> Self {
+        Self { spender, ..self }
+    }
+
+    pub fn with_amount(self, amount: Balance) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn with_expires_at(self, expires_at: BlockNumber) -> Self {
+        Self {
+            expires_at: Some(expires_at),
+            ..self
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::approve(
+            RuntimeOrigin::signed(self.sender),
+            self.owner_member_id,
+            self.token_id,
+            self.spender.clone(),
+            self.amount,
+            self.expires_at,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct RevokeAllowanceFixture {
+    sender: AccountId,
+    owner_member_id: MemberId,
+    token_id: TokenId,
+    spender: AllowanceSpender,
+}
+
+impl RevokeAllowanceFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(1).1,
+            owner_member_id: member!(1).0,
+            token_id: token!(1),
+            spender: AllowanceSpender::Member(member!(2).0),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_spender(self, spender: AllowanceSpender) -> Self {
+        Self { spender, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::revoke_allowance(
+            RuntimeOrigin::signed(self.sender),
+            self.owner_member_id,
+            self.token_id,
+            self.spender.clone(),
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct TransferFromFixture {
+    sender: AccountId,
+    spender: AllowanceSpender,
+    src_member_id: MemberId,
+    token_id: TokenId,
+    outputs: TransferOutputsOf<Test>,
+    metadata: Vec<u8>,
+}
+
+impl TransferFromFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(2).1,
+            spender: AllowanceSpender::Member(member!(2).0),
+            src_member_id: member!(1).0,
+            token_id: token!(1),
+            outputs: new_transfers(vec![(member!(3).0, DEFAULT_ALLOWANCE_TRANSFER_AMOUNT)]),
+            metadata: "metadata".as_bytes().to_vec(),
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_spender(self, spender: AllowanceSpender) -> Self {
+        Self { spender, ..self }
+    }
+
+    pub fn with_src_member_id(self, src_member_id: MemberId) -> Self {
+        Self {
+            src_member_id,
+            ..self
+        }
+    }
+
+    pub fn with_outputs(self, outputs: Vec<(MemberId, Balance)>) -> Self {
+        Self {
+            outputs: new_transfers(outputs),
+            ..self
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::transfer_from(
+            RuntimeOrigin::signed(self.sender),
+            self.spender.clone(),
+            self.src_member_id,
+            self.token_id,
+            self.outputs.clone(),
+            self.metadata.clone(),
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub fn default_airdrop_claims() -> Vec<(MemberId, Balance, Option<VestingScheduleParams>)> {
+    vec![
+        (member!(2).0, DEFAULT_AIRDROP_CLAIM_AMOUNT, None),
+        (
+            member!(3).0,
+            DEFAULT_AIRDROP_CLAIM_AMOUNT,
+            Some(VestingScheduleParams {
+                blocks_before_cliff: 100,
+                cliff_amount_percentage: Permill::from_percent(10),
+                linear_vesting_duration: 100,
+            }),
+        ),
+    ]
+}
+
+pub struct CreateAirdropFixture {
+    member_id: MemberId,
+    token_id: TokenId,
+    params: AirdropParams,
+}
+
+impl CreateAirdropFixture {
+    pub fn default() -> Self {
+        Self {
+            member_id: member!(1).0,
+            token_id: token!(1),
+            params: AirdropParams {
+                commitment: generate_merkle_root_helper::<Test, _>(&default_airdrop_claims())
+                    .pop()
+                    .unwrap(),
+                amount: DEFAULT_AIRDROP_AMOUNT,
+                duration: DEFAULT_AIRDROP_DURATION,
+            },
+        }
+    }
+
+    pub fn with_member_id(self, member_id: MemberId) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_amount(self, amount: Balance) -> Self {
+        Self {
+            params: AirdropParams {
+                amount,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn with_duration(self, duration: BlockNumber) -> Self {
+        Self {
+            params: AirdropParams {
+                duration,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::create_airdrop(self.token_id, self.member_id, self.params.clone());
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct ClaimAirdropFixture {
+    sender: AccountId,
+    member_id: MemberId,
+    token_id: TokenId,
+    airdrop_id: AirdropId,
+    amount: Balance,
+    vesting_schedule: Option<VestingScheduleParams>,
+    proof: MerkleProofOf<Test>,
+}
+
+impl ClaimAirdropFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(2).1,
+            member_id: member!(2).0,
+            token_id: token!(1),
+            airdrop_id: 0,
+            amount: DEFAULT_AIRDROP_CLAIM_AMOUNT,
+            vesting_schedule: None,
+            proof: MerkleProofOf::<Test>::new(build_merkle_path_helper::<Test, _>(
+                &default_airdrop_claims(),
+                0,
+            )),
+        }
+    }
+
+    pub fn with_claim(self, index: usize) -> Self {
+        let claims = default_airdrop_claims();
+        let (member_id, amount, vesting_schedule) = claims[index].clone();
+        Self {
+            sender: member!(member_id).1,
+            member_id,
+            amount,
+            vesting_schedule,
+            proof: MerkleProofOf::<Test>::new(build_merkle_path_helper::<Test, _>(&claims, index)),
+            ..self
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_amount(self, amount: Balance) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn with_airdrop_id(self, airdrop_id: AirdropId) -> Self {
+        Self { airdrop_id, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::claim_airdrop(
+            RuntimeOrigin::signed(self.sender),
+            self.member_id,
+            self.token_id,
+            self.airdrop_id,
+            self.amount,
+            self.vesting_schedule.clone(),
+            self.proof.clone(),
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct FinalizeAirdropFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    airdrop_id: AirdropId,
+}
+
+impl FinalizeAirdropFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(2).1,
+            token_id: token!(1),
+            airdrop_id: 0,
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::finalize_airdrop(
+            RuntimeOrigin::signed(self.sender),
+            self.token_id,
+            self.airdrop_id,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct CreatePollFixture {
+    member_id: MemberId,
+    token_id: TokenId,
+    params: PollParams,
+}
+
+impl CreatePollFixture {
+    pub fn default() -> Self {
+        Self {
+            member_id: member!(1).0,
+            token_id: token!(1),
+            params: PollParams {
+                options_number: DEFAULT_POLL_OPTIONS_NUMBER,
+                duration: DEFAULT_POLL_DURATION,
+                weighting: PollVoteWeighting::Balance,
+                metadata: b"poll".to_vec(),
+            },
+        }
+    }
+
+    pub fn with_options_number(self, options_number: u32) -> Self {
+        Self {
+            params: PollParams {
+                options_number,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn with_duration(self, duration: BlockNumber) -> Self {
+        Self {
+            params: PollParams {
+                duration,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn with_weighting(self, weighting: PollVoteWeighting) -> Self {
+        Self {
+            params: PollParams {
+                weighting,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn execute_call(&self) -> Result<PollId, DispatchError> {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::create_poll(self.token_id, self.member_id, self.params.clone());
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct VoteInPollFixture {
+    sender: AccountId,
+    member_id: MemberId,
+    token_id: TokenId,
+    poll_id: PollId,
+    option_index: u32,
+}
+
+impl VoteInPollFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(1).1,
+            member_id: member!(1).0,
+            token_id: token!(1),
+            poll_id: 0,
+            option_index: 0,
+        }
+    }
+
+    pub fn with_member(self, (member_id, sender): (MemberId, AccountId)) -> Self {
+        Self {
+            member_id,
+            sender,
+            ..self
+        }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_poll_id(self, poll_id: PollId) -> Self {
+        Self { poll_id, ..self }
+    }
+
+    pub fn with_option_index(self, option_index: u32) -> Self {
+        Self {
+            option_index,
+            ..self
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::vote_in_poll(
+            RuntimeOrigin::signed(self.sender),
+            self.member_id,
+            self.token_id,
+            self.poll_id,
+            self.option_index,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct ExitRevenueSplitFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    member_id: MemberId,
+}
+
+impl ExitRevenueSplitFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(2).1,
+            token_id: TokenId::one(),
+            member_id: member!(2).0,
+        }
+    }
+
+    pub fn with_account(self, account_id: u64) -> Self {
+        Self {
+            sender: account_id.into(),
+            ..self
+        }
+    }
+
+    pub fn with_token_id(self, token_id: u64) -> Self {
+        Self {
+            token_id: token_id.into(),
+            ..self
+        }
+    }
+
+    pub fn with_member_id(self, member_id: u64) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::exit_revenue_split(
+            RuntimeOrigin::signed(self.sender),
+            self.token_id,
+            self.member_id,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct ActivateAmmFixture {
+    token_id: TokenId,
+    member_id: MemberId,
+    params: AmmParams,
+}
+
+impl ActivateAmmFixture {
+    pub fn default() -> Self {
+        let (creator_member_id, _) = member!(1);
+        ActivateAmmFixture {
+            token_id: TokenId::one(),
+            member_id: creator_member_id,
+            params: AmmParams::Linear {
+                // like Deso: https://docs.deso.org/about-deso-chain/readme#the-creator-coin-supply-curve
+                slope: AMM_CURVE_SLOPE,
+                intercept: AMM_CURVE_INTERCEPT,
+            },
+        }
+    }
+
+    pub fn with_token_id(self, token_id: TokenId) -> Self {
+        Self { token_id, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: MemberId) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_params(self, params: AmmParams) -> Self {
+        Self { params, ..self }
+    }
+
+    pub fn with_linear_function_params(self, a: Balance, b: Balance) -> Self {
+        let params = AmmParams::Linear {
+            slope: a,
+            intercept: b,
+        };
+        Self { params, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::activate_amm(self.token_id, self.member_id, self.params.clone());
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct AmmBuyFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    member_id: MemberId,
+    amount: Balance,
+    slippage_tolerance: Option<(Permill, Balance)>,
+}
+
+impl AmmBuyFixture {
+    pub fn default() -> Self {
+        let (member_id, sender) = member!(2);
+        Self {
+            sender,
+            token_id: One::one(),
+            member_id,
+            amount: Balance::from(DEFAULT_AMM_BUY_AMOUNT),
+            slippage_tolerance: None,
+        }
+    }
+
+    pub fn with_amount(self, amount: Balance) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn with_token_id(self, token_id: TokenId) -> Self {
+        Self { token_id, ..self }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: MemberId) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_slippage_tolerance(self, tolerance: (Permill, Balance)) -> Self {
+        Self {
+            slippage_tolerance: Some(tolerance),
+            ..self
+        }
+    }
+
+    pub fn execute_call(self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::buy_on_amm(
+            RuntimeOrigin::signed(self.sender),
+            self.token_id,
+            self.member_id,
+            self.amount,
+            self.slippage_tolerance,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct AmmSellFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    member_id: MemberId,
+    amount: Balance,
+    slippage_tolerance: Option<(Permill, Balance)>,
+}
+
+impl AmmSellFixture {
+    pub fn default() -> Self {
+        let (member_id, sender) = member!(2);
+        Self {
+            sender,
+            token_id: One::one(),
+            member_id,
+            amount: Balance::from(DEFAULT_AMM_SELL_AMOUNT),
+            slippage_tolerance: None,
+        }
+    }
+
+    pub fn with_amount(self, amount: Balance) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn with_token_id(self, token_id: TokenId) -> Self {
+        Self { token_id, ..self }
+    }
+
+    pub fn with_sender(self, sender: AccountId) -> Self {
+        Self { sender, ..self }
+    }
+
+    pub fn with_member_id(self, member_id: MemberId) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn with_slippage_tolerance(self, tolerance: (Permill, Balance)) -> Self {
+        Self {
+            slippage_tolerance: Some(tolerance),
+            ..self
+        }
+    }
+
+    pub fn execute_call(self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::sell_on_amm(
+            RuntimeOrigin::signed(self.sender),
+            self.token_id,
+            self.member_id,
+            self.amount,
+            self.slippage_tolerance,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct AmmSwapFixture {
+    sender: AccountId,
+    member_id: MemberId,
+    token_in_id: TokenId,
+    amount_in: Balance,
+    token_out_id: TokenId,
+    amount_out: Balance,
+    max_joy_intermediate: JoyBalance,
+    min_joy_intermediate: JoyBalance,
+}
+
+impl AmmSwapFixture {
+    pub fn default() -> Self {
+        let (member_id, sender) = member!(2);
+        Self {
+            sender,
+            member_id,
+            token_in_id: token!(1),
+            amount_in: Balance::from(DEFAULT_AMM_SELL_AMOUNT),
+            token_out_id: token!(2),
+            amount_out: Balance::from(DEFAULT_AMM_BUY_AMOUNT),
+            max_joy_intermediate: JoyBalance::MAX,
+            min_joy_intermediate: Zero::zero(),
+        }
+    }
+
+    pub fn with_amount_in(self, amount_in: Balance) -> Self {
+        Self { amount_in, ..self }
+    }
+
+    pub fn with_amount_out(self, amount_out: Balance) -> Self {
+        Self { amount_out, ..self }
+    }
+
+    pub fn with_token_in_id(self, token_in_id: TokenId) -> Self {
+        Self {
+            token_in_id,
+            ..self
+        }
+    }
+
+    pub fn with_token_out_id(self, token_out_id: TokenId) -> Self {
+        Self {
+            token_out_id,
+            ..self
+        }
+    }
+
+    pub fn with_max_joy_intermediate(self, max_joy_intermediate: JoyBalance) -> Self {
+        Self {
+            max_joy_intermediate,
+            ..self
+        }
+    }
+
+    pub fn with_min_joy_intermediate(self, min_joy_intermediate: JoyBalance) -> Self {
+        Self {
+            min_joy_intermediate,
+            ..self
+        }
+    }
+
+    pub fn execute_call(self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::swap_on_amm(
+            RuntimeOrigin::signed(self.sender),
+            self.member_id,
+            self.token_in_id,
+            self.amount_in,
+            self.token_out_id,
+            self.amount_out,
+            self.max_joy_intermediate,
+            self.min_joy_intermediate,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct DeactivateAmmFixture {
+    token_id: TokenId,
+    member_id: MemberId,
+}
+
+impl DeactivateAmmFixture {
+    pub fn default() -> Self {
+        let (member_id, _) = member!(1);
+        Self {
+            token_id: TokenId::one(),
             member_id,
         }
     }
@@ -1316,3 +2333,178 @@ impl ReducePatronageRateToFixture {
         result
     }
 }
+
+pub struct InitBatchAuctionFixture {
+    token_id: TokenId,
+    member_id: MemberId,
+    earnings_destination: Option<AccountId>,
+    params: TokenSaleParams,
+}
+
+impl InitBatchAuctionFixture {
+    pub fn default() -> Self {
+        Self {
+            token_id: token!(1),
+            member_id: member!(1).0,
+            earnings_destination: Some(member!(1).1),
+            params: default_token_sale_params(),
+        }
+    }
+
+    pub fn with_upper_bound_quantity(self, upper_bound_quantity: Balance) -> Self {
+        Self {
+            params: TokenSaleParams {
+                upper_bound_quantity,
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn with_cap_per_member(self, cap_per_member: Balance) -> Self {
+        Self {
+            params: TokenSaleParams {
+                cap_per_member: Some(cap_per_member),
+                ..self.params
+            },
+            ..self
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::init_batch_auction(
+            self.token_id,
+            self.member_id,
+            self.earnings_destination,
+            self.params.clone(),
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct PlaceBatchAuctionBidFixture {
+    sender: AccountId,
+    member_id: MemberId,
+    token_id: TokenId,
+    quantity: Balance,
+    max_price: JoyBalance,
+}
+
+impl PlaceBatchAuctionBidFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(2).1,
+            member_id: member!(2).0,
+            token_id: token!(1),
+            quantity: DEFAULT_SALE_PURCHASE_AMOUNT,
+            max_price: DEFAULT_SALE_UNIT_PRICE,
+        }
+    }
+
+    pub fn with_member(self, (member_id, sender): (MemberId, AccountId)) -> Self {
+        Self {
+            member_id,
+            sender,
+            ..self
+        }
+    }
+
+    pub fn with_quantity(self, quantity: Balance) -> Self {
+        Self { quantity, ..self }
+    }
+
+    pub fn with_max_price(self, max_price: JoyBalance) -> Self {
+        Self { max_price, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::place_batch_auction_bid(
+            RuntimeOrigin::signed(self.sender),
+            self.token_id,
+            self.member_id,
+            self.quantity,
+            self.max_price,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct SettleBatchAuctionFixture {
+    sender: AccountId,
+    token_id: TokenId,
+}
+
+impl SettleBatchAuctionFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(1).1,
+            token_id: token!(1),
+        }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::settle_batch_auction(RuntimeOrigin::signed(self.sender), self.token_id);
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
+
+pub struct SettleBatchAuctionBidFixture {
+    sender: AccountId,
+    token_id: TokenId,
+    member_id: MemberId,
+}
+
+impl SettleBatchAuctionBidFixture {
+    pub fn default() -> Self {
+        Self {
+            sender: member!(1).1,
+            token_id: token!(1),
+            member_id: member!(2).0,
+        }
+    }
+
+    pub fn with_member_id(self, member_id: MemberId) -> Self {
+        Self { member_id, ..self }
+    }
+
+    pub fn execute_call(&self) -> DispatchResult {
+        let state_pre = sp_io::storage::root(sp_storage::StateVersion::V1);
+        let result = Token::settle_batch_auction_bid(
+            RuntimeOrigin::signed(self.sender),
+            self.token_id,
+            self.member_id,
+        );
+        let state_post = sp_io::storage::root(sp_storage::StateVersion::V1);
+
+        // no-op in case of error
+        if result.is_err() {
+            assert_eq!(state_pre, state_post)
+        }
+
+        result
+    }
+}
@@ -54,6 +54,13 @@ pub type Policy = TransferPolicyOf<Test>;
 pub type Hashing = <Test as frame_system::Config>::Hashing;
 pub type VestingSchedule = VestingScheduleOf<Test>;
 pub type AmmParams = AmmParamsOf<Test>;
+pub type AllowanceSpender = AllowanceSpenderOf<Test>;
+pub type TokenAllowance = TokenAllowanceOf<Test>;
+pub type AirdropParams = AirdropParamsOf<Test>;
+pub type Airdrop = AirdropOf<Test>;
+pub type PollParams = PollParamsOf<Test>;
+pub type Poll = PollOf<Test>;
+pub type BatchAuctionBid = BatchAuctionBidOf<Test>;
 pub type MemberId = u64;
 
 #[macro_export]
@@ -86,6 +93,14 @@ parameter_types! {
     pub const MaxVestingSchedulesPerAccountPerToken: u32 = 3;
     pub const BlocksPerYear: u32 = 5259487; // blocks every 6s
     pub const MaxOutputs: u32 = 256;
+    pub const MaxPollOptions: u32 = 4;
+    pub const MaxBatchAuctionBids: u32 = 4;
+    pub const RevenueStakingUnstakingPeriod: u64 = 10;
+    pub const MaxOrdersPerBookSide: u32 = 10;
+    pub const MaxOrderMatches: u32 = 3;
+    pub const MaxAllowancesPerAccount: u32 = 3;
+    pub const MaxAirdropClaimsRemovedPerCall: u32 = 2;
+    pub const MaxPollVotesRemovedPerCall: u32 = 2;
     // --------- balances::Config parameters ---------------------------
     pub const ExistentialDeposit: u128 = 10;
     // constants for storage::Config
@@ -102,9 +117,14 @@ parameter_types! {
     pub const MaxDataObjectSize: u64 = 1_000_000_000;
     pub const MinStorageBucketsPerBag: u32 = 3;
     pub const MaxStorageBucketsPerBag: u32 = 10;
+    pub const MaxStorageBucketsScannedOnAssignment: u32 = 100;
     pub const MinDistributionBucketsPerBag: u32 = 3;
     pub const MaxDistributionBucketsPerBag: u32 = 10;
     pub const MaxNumberOfOperatorsPerDistributionBucket: u32 = 5;
+    pub const BandwidthReportDisputePeriod: u64 = 10;
+    pub const BandwidthReportPeriod: u64 = 5;
+    pub const MaxBytesServedPerBandwidthReport: u64 = 100_000_000_000;
+    pub const BandwidthReportBond: u64 = 100;
     // constants for membership::Config
     pub const DefaultMembershipPrice: u64 = 100;
     pub const CandidateStake: u64 = 100;
@@ -143,6 +163,7 @@ impl storage::Config for Test {
     type ModuleId = StorageModuleId;
     type MinStorageBucketsPerBag = MinStorageBucketsPerBag;
     type MaxStorageBucketsPerBag = MaxStorageBucketsPerBag;
+    type MaxStorageBucketsScannedOnAssignment = MaxStorageBucketsScannedOnAssignment;
     type MinDistributionBucketsPerBag = MinDistributionBucketsPerBag;
     type MaxDistributionBucketsPerBag = MaxDistributionBucketsPerBag;
     type DefaultMemberDynamicBagNumberOfStorageBuckets =
@@ -158,6 +179,10 @@ impl storage::Config for Test {
     type StorageWorkingGroup = StorageWG;
     type DistributionWorkingGroup = DistributionWG;
     type ModuleAccountInitialBalance = ExistentialDeposit;
+    type BandwidthReportDisputePeriod = BandwidthReportDisputePeriod;
+    type BandwidthReportPeriod = BandwidthReportPeriod;
+    type MaxBytesServedPerBandwidthReport = MaxBytesServedPerBandwidthReport;
+    type BandwidthReportBond = BandwidthReportBond;
     type WeightInfo = ();
 }
 
@@ -186,6 +211,14 @@ impl Config for Test {
     type MemberOriginValidator = TestMemberships;
     type MembershipInfoProvider = TestMemberships;
     type MaxOutputs = MaxOutputs;
+    type MaxPollOptions = MaxPollOptions;
+    type MaxBatchAuctionBids = MaxBatchAuctionBids;
+    type RevenueStakingUnstakingPeriod = RevenueStakingUnstakingPeriod;
+    type MaxOrdersPerBookSide = MaxOrdersPerBookSide;
+    type MaxOrderMatches = MaxOrderMatches;
+    type MaxAllowancesPerAccount = MaxAllowancesPerAccount;
+    type MaxAirdropClaimsRemovedPerCall = MaxAirdropClaimsRemovedPerCall;
+    type MaxPollVotesRemovedPerCall = MaxPollVotesRemovedPerCall;
 }
 
 // Working group integration
@@ -254,6 +287,12 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for StorageWG {
         unimplemented!()
     }
 
+    fn get_worker_reward_account_id(
+        _: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Test as frame_system::Config>::AccountId> {
+        unimplemented!()
+    }
+
     fn is_leader_account_id(_account_id: &<Test as frame_system::Config>::AccountId) -> bool {
         unimplemented!()
     }
@@ -315,6 +354,12 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for DistributionWG {
         unimplemented!()
     }
 
+    fn get_worker_reward_account_id(
+        _: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Test as frame_system::Config>::AccountId> {
+        unimplemented!()
+    }
+
     fn is_leader_account_id(_account_id: &<Test as frame_system::Config>::AccountId) -> bool {
         unimplemented!()
     }
@@ -445,6 +490,12 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for Wg {
         unimplemented!()
     }
 
+    fn get_worker_reward_account_id(
+        _: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Test as frame_system::Config>::AccountId> {
+        unimplemented!()
+    }
+
     fn is_leader_account_id(_account_id: &<Test as frame_system::Config>::AccountId) -> bool {
         unimplemented!()
     }
@@ -517,6 +568,8 @@ pub struct GenesisConfigBuilder {
     pub(crate) amm_deactivation_threshold: Permill,
     pub(crate) bond_tx_fees: Permill,
     pub(crate) unbond_tx_fees: Permill,
+    pub(crate) order_book_tx_fees: Permill,
+    pub(crate) min_order_value: JoyBalance,
     pub(crate) max_yearly_patronage_rate: YearlyRate,
     pub(crate) min_amm_slope_parameter: Balance,
 }
@@ -659,6 +712,19 @@ pub const DEFAULT_SPLIT_RATE: Permill = Permill::from_percent(10);
 pub const DEFAULT_SPLIT_DURATION: u64 = 100;
 pub const DEFAULT_SPLIT_PARTICIPATION: u128 = DEFAULT_SPLIT_REVENUE / 100;
 
+// ------ Allowance Constants ----------------
+pub const DEFAULT_ALLOWANCE_AMOUNT: u128 = 1000;
+pub const DEFAULT_ALLOWANCE_TRANSFER_AMOUNT: u128 = 400;
+
+// ------ Airdrop Constants ------------------
+pub const DEFAULT_AIRDROP_CLAIM_AMOUNT: u128 = 1000;
+pub const DEFAULT_AIRDROP_AMOUNT: u128 = 2 * DEFAULT_AIRDROP_CLAIM_AMOUNT;
+pub const DEFAULT_AIRDROP_DURATION: u64 = 100;
+
+// ------ Poll Constants ------------------
+pub const DEFAULT_POLL_OPTIONS_NUMBER: u32 = 2;
+pub const DEFAULT_POLL_DURATION: u64 = 100;
+
 // ------ Bonding Curve Constants ------------
 pub const DEFAULT_AMM_BUY_AMOUNT: u128 = 1000;
 pub const DEFAULT_AMM_SELL_AMOUNT: u128 = 100;
@@ -667,6 +733,15 @@ pub const AMM_CURVE_INTERCEPT: u128 = 1000;
 pub const DEFAULT_AMM_BUY_FEES: Permill = Permill::from_percent(1);
 pub const DEFAULT_AMM_SELL_FEES: Permill = Permill::from_percent(10);
 
+// ------ Order Book Constants ------------------
+pub const DEFAULT_ORDER_QUANTITY: u128 = 1000;
+pub const DEFAULT_ORDER_PRICE: JoyBalance = 10;
+pub const DEFAULT_ORDER_BOOK_TX_FEES: Permill = Permill::from_perthousand(3);
+pub const MIN_ORDER_VALUE: JoyBalance = 100;
+
+// ------ Bridging Constants ------------------
+pub const DEFAULT_BRIDGED_AMOUNT: u128 = 1000;
+
 // ------ Storage Constants ------------------
 pub const STORAGE_WG_LEADER_ACCOUNT_ID: u64 = 100001;
 pub const DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID: u64 = 100002;
@@ -1,11 +1,19 @@
 #![cfg(test)]
 
+mod airdrop;
+mod allowance;
 mod amm;
+mod batch_auction;
+mod bridging;
 mod canonical;
 mod fixtures;
 pub mod mock;
+mod order_book;
 mod patronage;
+mod polls;
+mod revenue_staking;
 mod sale;
 mod split;
 pub mod test_utils;
 mod transfer;
+mod vesting_revocation;
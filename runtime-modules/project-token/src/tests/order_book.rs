@@ -0,0 +1,461 @@
+#![cfg(test)]
+
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::types::{Joy, LimitOrder, OrderSide};
+use crate::{last_event_eq, member, origin, token, Error, RawEvent};
+use frame_support::traits::{Currency, Get};
+use frame_support::{assert_err, assert_ok};
+use sp_runtime::DispatchError;
+
+const DEFAULT_ORDER_AMOUNT: JoyBalance = DEFAULT_ORDER_QUANTITY * DEFAULT_ORDER_PRICE;
+
+fn place_buy_order(member: u64, quantity: Balance, price: JoyBalance) {
+    increase_account_balance(&member!(member).1, ed() + quantity * price);
+    PlaceLimitOrderFixture::default()
+        .with_member(member!(member))
+        .with_side(OrderSide::Buy)
+        .with_quantity(quantity)
+        .with_price(price)
+        .execute_call()
+        .unwrap();
+}
+
+fn place_sell_order(quantity: Balance, price: JoyBalance) {
+    PlaceLimitOrderFixture::default()
+        .with_quantity(quantity)
+        .with_price(price)
+        .execute_call()
+        .unwrap();
+}
+
+// --------------------- place_limit_order -------------------------------
+
+#[test]
+fn place_limit_order_fails_with_invalid_origin() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = PlaceLimitOrderFixture::default()
+            .with_sender(member!(2).1)
+            .execute_call();
+
+        assert_err!(
+            result,
+            DispatchError::Other("origin signer not a member controller account")
+        );
+    })
+}
+
+#[test]
+fn place_limit_order_fails_with_zero_quantity() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = PlaceLimitOrderFixture::default()
+            .with_quantity(0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::OrderQuantityIsZero);
+    })
+}
+
+#[test]
+fn place_limit_order_fails_with_zero_price() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = PlaceLimitOrderFixture::default()
+            .with_price(0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::OrderPriceIsZero);
+    })
+}
+
+#[test]
+fn place_limit_order_fails_with_value_below_minimum() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = PlaceLimitOrderFixture::default()
+            .with_quantity(1)
+            .with_price(MIN_ORDER_VALUE - 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::OrderValueTooLow);
+    })
+}
+
+#[test]
+fn place_limit_order_fails_with_non_existing_token() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = PlaceLimitOrderFixture::default()
+            .with_token_id(token!(2))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn place_limit_order_fails_with_full_order_book_side() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        let max_orders: u32 = MaxOrdersPerBookSide::get();
+        for i in 0..max_orders {
+            place_sell_order(DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE + i as u128);
+        }
+
+        let result = PlaceLimitOrderFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::MaxOrdersPerBookSideReached);
+    })
+}
+
+#[test]
+fn place_sell_limit_order_fails_with_non_existing_account() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = PlaceLimitOrderFixture::default()
+            .with_member(member!(2))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn place_sell_limit_order_fails_with_insufficient_transferrable_balance() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default()
+            .with_amount(DEFAULT_INITIAL_ISSUANCE)
+            .execute_call()
+            .unwrap();
+
+        let result = PlaceLimitOrderFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientTransferrableBalance);
+    })
+}
+
+#[test]
+fn place_buy_limit_order_fails_with_insufficient_joy_balance() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        increase_account_balance(&member!(2).1, ed() + DEFAULT_ORDER_AMOUNT - 1);
+
+        let result = PlaceLimitOrderFixture::default()
+            .with_member(member!(2))
+            .with_side(OrderSide::Buy)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientJoyBalance);
+    })
+}
+
+#[test]
+fn place_sell_limit_order_ok() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        PlaceLimitOrderFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Token::limit_order(token!(1), 0),
+            Some(LimitOrder {
+                member_id: member!(1).0,
+                account: member!(1).1,
+                side: OrderSide::Sell,
+                price: DEFAULT_ORDER_PRICE,
+                quantity: DEFAULT_ORDER_QUANTITY,
+                filled_quantity: 0,
+            })
+        );
+        assert_eq!(
+            Token::order_book(token!(1)).asks.to_vec(),
+            vec![(DEFAULT_ORDER_PRICE, 0)]
+        );
+        assert_eq!(Token::open_orders_number(token!(1), member!(1).0), 1);
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_ORDER_QUANTITY
+        );
+        last_event_eq!(RawEvent::LimitOrderPlaced(
+            token!(1),
+            0,
+            member!(1).0,
+            OrderSide::Sell,
+            DEFAULT_ORDER_QUANTITY,
+            DEFAULT_ORDER_PRICE,
+        ));
+    })
+}
+
+#[test]
+fn place_buy_limit_order_ok() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        let treasury_balance_pre = Joy::<Test>::usable_balance(Token::module_treasury_account());
+
+        place_buy_order(2, DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE);
+
+        assert_eq!(Joy::<Test>::usable_balance(member!(2).1), ed());
+        assert_eq!(
+            Joy::<Test>::usable_balance(Token::module_treasury_account()),
+            treasury_balance_pre + DEFAULT_ORDER_AMOUNT
+        );
+        assert!(Token::ensure_account_data_exists(token!(1), &member!(2).0).is_ok());
+        assert_eq!(
+            Token::order_book(token!(1)).bids.to_vec(),
+            vec![(DEFAULT_ORDER_PRICE, 0)]
+        );
+        last_event_eq!(RawEvent::LimitOrderPlaced(
+            token!(1),
+            0,
+            member!(2).0,
+            OrderSide::Buy,
+            DEFAULT_ORDER_QUANTITY,
+            DEFAULT_ORDER_PRICE,
+        ));
+    })
+}
+
+#[test]
+fn order_book_keeps_price_time_priority() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        place_sell_order(DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE + 1);
+        place_sell_order(DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE);
+        place_sell_order(DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE + 1);
+        place_buy_order(2, DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE - 2);
+        place_buy_order(3, DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE - 1);
+
+        let order_book = Token::order_book(token!(1));
+        assert_eq!(
+            order_book.asks.to_vec(),
+            vec![
+                (DEFAULT_ORDER_PRICE, 1),
+                (DEFAULT_ORDER_PRICE + 1, 0),
+                (DEFAULT_ORDER_PRICE + 1, 2)
+            ]
+        );
+        assert_eq!(
+            order_book.bids.to_vec(),
+            vec![(DEFAULT_ORDER_PRICE - 1, 4), (DEFAULT_ORDER_PRICE - 2, 3)]
+        );
+    })
+}
+
+#[test]
+fn crossing_limit_orders_matched_at_earlier_order_price() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        place_sell_order(DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE);
+        let seller_balance_pre = Joy::<Test>::usable_balance(member!(1).1);
+        let issuance_pre = Joy::<Test>::total_issuance();
+
+        place_buy_order(2, DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE + 2);
+
+        let fee = Token::order_book_tx_fees().mul_floor(DEFAULT_ORDER_AMOUNT);
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(1).1),
+            seller_balance_pre + DEFAULT_ORDER_AMOUNT - fee
+        );
+        // price difference refunded to the buyer
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(2).1),
+            ed() + DEFAULT_ORDER_QUANTITY * 2
+        );
+        // fee burned
+        assert_eq!(
+            Joy::<Test>::total_issuance(),
+            issuance_pre + ed() + DEFAULT_ORDER_QUANTITY * (DEFAULT_ORDER_PRICE + 2) - fee
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(2).0).amount,
+            DEFAULT_ORDER_QUANTITY
+        );
+        assert!(Token::limit_order(token!(1), 0).is_none());
+        assert!(Token::limit_order(token!(1), 1).is_none());
+        assert_eq!(Token::order_book(token!(1)), Default::default());
+        assert_eq!(Token::open_orders_number(token!(1), member!(1).0), 0);
+        assert_eq!(Token::open_orders_number(token!(1), member!(2).0), 0);
+        last_event_eq!(RawEvent::LimitOrdersMatched(
+            token!(1),
+            1,
+            0,
+            DEFAULT_ORDER_QUANTITY,
+            DEFAULT_ORDER_PRICE,
+            fee,
+        ));
+    })
+}
+
+#[test]
+fn crossing_limit_orders_partially_filled() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        place_sell_order(DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE);
+
+        place_buy_order(2, DEFAULT_ORDER_QUANTITY / 4, DEFAULT_ORDER_PRICE);
+
+        assert!(Token::limit_order(token!(1), 1).is_none());
+        assert_eq!(
+            Token::limit_order(token!(1), 0)
+                .unwrap()
+                .remaining_quantity(),
+            DEFAULT_ORDER_QUANTITY - DEFAULT_ORDER_QUANTITY / 4
+        );
+        assert_eq!(
+            Token::order_book(token!(1)).asks.to_vec(),
+            vec![(DEFAULT_ORDER_PRICE, 0)]
+        );
+        assert!(Token::order_book(token!(1)).bids.is_empty());
+        assert_eq!(Token::open_orders_number(token!(1), member!(1).0), 1);
+    })
+}
+
+#[test]
+fn crossing_limit_orders_matched_in_bounded_steps() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        let max_matches: u32 = MaxOrderMatches::get();
+        let orders_number = (max_matches + 1) as u128;
+        for _ in 0..orders_number {
+            place_sell_order(DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE);
+        }
+
+        place_buy_order(
+            2,
+            orders_number * DEFAULT_ORDER_QUANTITY,
+            DEFAULT_ORDER_PRICE,
+        );
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(2).0).amount,
+            max_matches as u128 * DEFAULT_ORDER_QUANTITY
+        );
+        assert!(Token::order_book(token!(1)).crossing_orders().is_some());
+
+        assert_ok!(MatchLimitOrdersFixture::default().execute_call());
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(2).0).amount,
+            orders_number * DEFAULT_ORDER_QUANTITY
+        );
+        assert_eq!(Token::order_book(token!(1)), Default::default());
+    })
+}
+
+// --------------------- match_limit_orders -------------------------------
+
+#[test]
+fn match_limit_orders_fails_with_order_book_not_crossed() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        place_sell_order(DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE);
+        place_buy_order(2, DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE - 1);
+
+        let result = MatchLimitOrdersFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::OrderBookNotCrossed);
+    })
+}
+
+// --------------------- cancel_limit_order -------------------------------
+
+#[test]
+fn cancel_limit_order_fails_with_non_existing_order() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = CancelLimitOrderFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::OrderDoesNotExist);
+    })
+}
+
+#[test]
+fn cancel_limit_order_fails_with_order_not_owned_by_member() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        place_sell_order(DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE);
+
+        let result = CancelLimitOrderFixture::default()
+            .with_member(member!(2))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::OrderNotOwnedByMember);
+    })
+}
+
+#[test]
+fn cancel_sell_limit_order_ok() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        place_sell_order(DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE);
+        place_buy_order(2, DEFAULT_ORDER_QUANTITY / 4, DEFAULT_ORDER_PRICE);
+
+        CancelLimitOrderFixture::default().execute_call().unwrap();
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - DEFAULT_ORDER_QUANTITY / 4
+        );
+        assert!(Token::limit_order(token!(1), 0).is_none());
+        assert_eq!(Token::order_book(token!(1)), Default::default());
+        assert_eq!(Token::open_orders_number(token!(1), member!(1).0), 0);
+        last_event_eq!(RawEvent::LimitOrderCancelled(
+            token!(1),
+            0,
+            member!(1).0,
+            DEFAULT_ORDER_QUANTITY - DEFAULT_ORDER_QUANTITY / 4,
+        ));
+    })
+}
+
+#[test]
+fn cancel_buy_limit_order_ok() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        place_buy_order(2, DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE);
+
+        CancelLimitOrderFixture::default()
+            .with_member(member!(2))
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(2).1),
+            ed() + DEFAULT_ORDER_AMOUNT
+        );
+        assert!(Token::limit_order(token!(1), 0).is_none());
+        assert_eq!(Token::order_book(token!(1)), Default::default());
+        last_event_eq!(RawEvent::LimitOrderCancelled(
+            token!(1),
+            0,
+            member!(2).0,
+            DEFAULT_ORDER_QUANTITY,
+        ));
+    })
+}
+
+// --------------------- dust_account -------------------------------
+
+#[test]
+fn dust_account_fails_with_open_orders() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        place_buy_order(2, DEFAULT_ORDER_QUANTITY, DEFAULT_ORDER_PRICE);
+
+        let result = Token::dust_account(origin!(member!(2).1), token!(1), member!(2).0);
+
+        assert_err!(result, Error::<Test>::AttemptToRemoveAccountWithOpenOrders);
+    })
+}
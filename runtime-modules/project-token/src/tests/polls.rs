@@ -0,0 +1,363 @@
+#![cfg(test)]
+
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::traits::PalletToken;
+use crate::types::{PollVote, PollVoteWeighting};
+use crate::{last_event_eq, member, token, Error, PollVotes, Polls, RawEvent};
+use frame_support::traits::Get;
+use frame_support::{assert_err, assert_ok, IterableStorageDoubleMap, StorageDoubleMap};
+use sp_runtime::DispatchError;
+
+// --------------------- create_poll -------------------------------
+
+#[test]
+fn create_poll_fails_with_non_existing_token() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        let result = CreatePollFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn create_poll_fails_with_less_than_two_options() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = CreatePollFixture::default()
+            .with_options_number(1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InvalidPollOptionsNumber);
+    })
+}
+
+#[test]
+fn create_poll_fails_with_too_many_options() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = CreatePollFixture::default()
+            .with_options_number(MaxPollOptions::get() + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InvalidPollOptionsNumber);
+    })
+}
+
+#[test]
+fn create_poll_fails_with_zero_duration() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = CreatePollFixture::default().with_duration(0).execute_call();
+
+        assert_err!(result, Error::<Test>::PollDurationIsZero);
+    })
+}
+
+#[test]
+fn create_poll_ok() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        let current_block = System::block_number();
+
+        let result = CreatePollFixture::default().execute_call();
+
+        assert_ok!(result, 0);
+        let poll = Token::poll(token!(1), 0);
+        assert_eq!(poll.creator_member_id, member!(1).0);
+        assert_eq!(poll.ends_at, current_block + DEFAULT_POLL_DURATION);
+        assert_eq!(
+            poll.tallies.to_vec(),
+            vec![0; DEFAULT_POLL_OPTIONS_NUMBER as usize]
+        );
+        assert_eq!(Token::next_poll_id(), 1);
+    })
+}
+
+#[test]
+fn create_poll_ok_with_event_deposited() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        CreatePollFixture::default().execute_call().unwrap();
+
+        last_event_eq!(RawEvent::PollCreated(
+            token!(1),
+            0,
+            Token::poll(token!(1), 0),
+            b"poll".to_vec()
+        ));
+    })
+}
+
+// --------------------- vote_in_poll -------------------------------
+
+#[test]
+fn vote_in_poll_fails_with_invalid_origin() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreatePollFixture::default().execute_call().unwrap();
+
+        let result = VoteInPollFixture::default()
+            .with_sender(member!(2).1)
+            .execute_call();
+
+        assert_err!(
+            result,
+            DispatchError::Other("origin signer not a member controller account")
+        );
+    })
+}
+
+#[test]
+fn vote_in_poll_fails_with_non_existing_poll() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = VoteInPollFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::PollDoesNotExist);
+    })
+}
+
+#[test]
+fn vote_in_poll_fails_after_poll_end() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreatePollFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_POLL_DURATION);
+
+        let result = VoteInPollFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::PollEnded);
+    })
+}
+
+#[test]
+fn vote_in_poll_fails_with_invalid_option() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreatePollFixture::default().execute_call().unwrap();
+
+        let result = VoteInPollFixture::default()
+            .with_option_index(DEFAULT_POLL_OPTIONS_NUMBER)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InvalidPollOption);
+    })
+}
+
+#[test]
+fn vote_in_poll_fails_when_already_voted() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreatePollFixture::default().execute_call().unwrap();
+        VoteInPollFixture::default().execute_call().unwrap();
+
+        let result = VoteInPollFixture::default()
+            .with_option_index(1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::PollAlreadyVoted);
+    })
+}
+
+#[test]
+fn vote_in_poll_fails_with_non_existing_account() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreatePollFixture::default().execute_call().unwrap();
+
+        let result = VoteInPollFixture::default()
+            .with_member(member!(2))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn vote_in_poll_fails_without_staked_balance_in_staked_poll() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreatePollFixture::default()
+            .with_weighting(PollVoteWeighting::StakedBalance)
+            .execute_call()
+            .unwrap();
+
+        let result = VoteInPollFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::NoPollVotingPower);
+    })
+}
+
+#[test]
+fn vote_in_poll_ok_with_balance_weight() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreatePollFixture::default().execute_call().unwrap();
+
+        VoteInPollFixture::default()
+            .with_option_index(1)
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(
+            Token::poll(token!(1), 0).tallies.to_vec(),
+            vec![0, DEFAULT_INITIAL_ISSUANCE]
+        );
+        assert_eq!(
+            Token::poll_vote(0, member!(1).0),
+            PollVote {
+                option_index: 1,
+                weight: DEFAULT_INITIAL_ISSUANCE
+            }
+        );
+        last_event_eq!(RawEvent::VoteCastInPoll(
+            token!(1),
+            0,
+            member!(1).0,
+            1,
+            DEFAULT_INITIAL_ISSUANCE
+        ));
+    })
+}
+
+#[test]
+fn vote_in_poll_ok_with_staked_balance_weight() {
+    build_default_test_externalities_with_balances(vec![(
+        member!(1).1,
+        DEFAULT_SPLIT_REVENUE + ExistentialDeposit::get() + DEFAULT_BLOAT_BOND,
+    )])
+    .execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        IssueRevenueSplitFixture::default().execute_call().unwrap();
+        increase_block_number_by(MIN_REVENUE_SPLIT_TIME_TO_START);
+        ParticipateInSplitFixture::default().execute_call().unwrap();
+        CreatePollFixture::default()
+            .with_weighting(PollVoteWeighting::StakedBalance)
+            .execute_call()
+            .unwrap();
+
+        VoteInPollFixture::default()
+            .with_member(member!(2))
+            .execute_call()
+            .unwrap();
+
+        assert_eq!(
+            Token::poll(token!(1), 0).tallies.to_vec(),
+            vec![DEFAULT_SPLIT_PARTICIPATION, 0]
+        );
+    })
+}
+
+#[test]
+fn vote_in_poll_locks_voting_power_until_poll_end() {
+    build_default_test_externalities_with_balances(vec![(
+        member!(1).1,
+        ExistentialDeposit::get() + DEFAULT_BLOAT_BOND,
+    )])
+    .execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreatePollFixture::default().execute_call().unwrap();
+        VoteInPollFixture::default().execute_call().unwrap();
+
+        let result = TransferFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientTransferrableBalance);
+
+        increase_block_number_by(DEFAULT_POLL_DURATION);
+
+        assert_ok!(TransferFixture::default().execute_call());
+    })
+}
+
+// --------------------- poll_outcome -------------------------------
+
+#[test]
+fn poll_outcome_fails_before_poll_end() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreatePollFixture::default().execute_call().unwrap();
+
+        let result = Token::poll_outcome(token!(1), 0);
+
+        assert_err!(result, Error::<Test>::PollNotEnded);
+    })
+}
+
+#[test]
+fn poll_outcome_ok_with_winning_option() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreatePollFixture::default().execute_call().unwrap();
+        VoteInPollFixture::default()
+            .with_option_index(1)
+            .execute_call()
+            .unwrap();
+        increase_block_number_by(DEFAULT_POLL_DURATION);
+
+        let result = Token::poll_outcome(token!(1), 0);
+
+        assert_ok!(result, Some(1));
+    })
+}
+
+#[test]
+fn poll_outcome_ok_without_votes() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreatePollFixture::default().execute_call().unwrap();
+        increase_block_number_by(DEFAULT_POLL_DURATION);
+
+        let result = Token::poll_outcome(token!(1), 0);
+
+        assert_ok!(result, None);
+    })
+}
+
+// --------------------- finalize_poll -------------------------------
+
+#[test]
+fn finalize_poll_fails_before_poll_end() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreatePollFixture::default().execute_call().unwrap();
+
+        let result = Token::finalize_poll(token!(1), 0);
+
+        assert_err!(result, Error::<Test>::PollNotEnded);
+    })
+}
+
+#[test]
+fn finalize_poll_ok_with_poll_and_votes_removed_in_bounded_batches() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        CreatePollFixture::default().execute_call().unwrap();
+        VoteInPollFixture::default().execute_call().unwrap();
+        for i in 0..MaxPollVotesRemovedPerCall::get() {
+            PollVotes::<Test>::insert(
+                0,
+                member!(10 + i).0,
+                PollVote {
+                    option_index: 0,
+                    weight: 1,
+                },
+            );
+        }
+        increase_block_number_by(DEFAULT_POLL_DURATION);
+
+        assert_ok!(Token::finalize_poll(token!(1), 0), false);
+        assert!(Polls::<Test>::contains_key(token!(1), 0));
+
+        assert_ok!(Token::finalize_poll(token!(1), 0), true);
+        assert!(!Polls::<Test>::contains_key(token!(1), 0));
+        assert!(PollVotes::<Test>::iter_prefix(0).next().is_none());
+        last_event_eq!(RawEvent::PollRemoved(token!(1), 0));
+    })
+}
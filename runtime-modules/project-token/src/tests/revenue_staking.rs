@@ -0,0 +1,413 @@
+#![cfg(test)]
+
+use crate::tests::fixtures::*;
+use crate::tests::mock::*;
+use crate::types::Joy;
+use crate::{last_event_eq, member, origin, token, Error, RawEvent};
+use frame_support::traits::Get;
+use frame_support::{assert_err, assert_ok};
+use sp_runtime::{DispatchError, FixedPointNumber, FixedU128};
+
+const DEFAULT_STAKING_ALLOCATION: u128 = 10_000_000; // DEFAULT_SPLIT_RATE * DEFAULT_SPLIT_REVENUE
+
+fn build_test_externalities_with_revenue() -> sp_io::TestExternalities {
+    build_default_test_externalities_with_balances(vec![(
+        member!(1).1,
+        // endow enough revenue for 2 deposits
+        DEFAULT_SPLIT_REVENUE + DEFAULT_SPLIT_REVENUE + ExistentialDeposit::get(),
+    )])
+}
+
+// --------------------- stake_for_revenue -------------------------------
+
+#[test]
+fn stake_for_revenue_fails_with_invalid_origin() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = StakeForRevenueFixture::default()
+            .with_sender(member!(2).1)
+            .execute_call();
+
+        assert_err!(
+            result,
+            DispatchError::Other("origin signer not a member controller account")
+        );
+    })
+}
+
+#[test]
+fn stake_for_revenue_fails_with_zero_amount() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = StakeForRevenueFixture::default()
+            .with_amount(0)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::RevenueStakingAmountIsZero);
+    })
+}
+
+#[test]
+fn stake_for_revenue_fails_with_non_existing_token() {
+    build_default_test_externalities().execute_with(|| {
+        let result = StakeForRevenueFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn stake_for_revenue_fails_with_non_existing_account() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = StakeForRevenueFixture::default()
+            .with_member(member!(2))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::AccountInformationDoesNotExist);
+    })
+}
+
+#[test]
+fn stake_for_revenue_fails_with_insufficient_unstaked_balance() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default()
+            .with_amount(DEFAULT_INITIAL_ISSUANCE)
+            .execute_call()
+            .unwrap();
+
+        let result = StakeForRevenueFixture::default()
+            .with_amount(1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientBalanceForRevenueStaking);
+    })
+}
+
+#[test]
+fn stake_for_revenue_ok() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = StakeForRevenueFixture::default().execute_call();
+
+        assert_ok!(result);
+        assert_eq!(
+            Token::revenue_stake(token!(1), member!(1).0).amount,
+            DEFAULT_SPLIT_PARTICIPATION
+        );
+        assert_eq!(
+            Token::revenue_staking_pool(token!(1)).total_staked,
+            DEFAULT_SPLIT_PARTICIPATION
+        );
+        last_event_eq!(RawEvent::TokensStakedForRevenue(
+            token!(1),
+            member!(1).0,
+            DEFAULT_SPLIT_PARTICIPATION
+        ));
+    })
+}
+
+#[test]
+fn revenue_staked_tokens_cannot_be_transferred() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default()
+            .with_amount(DEFAULT_INITIAL_ISSUANCE)
+            .execute_call()
+            .unwrap();
+
+        let result = TransferFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientTransferrableBalance);
+    })
+}
+
+#[test]
+fn revenue_staked_tokens_cannot_be_burned() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default()
+            .with_amount(DEFAULT_INITIAL_ISSUANCE)
+            .execute_call()
+            .unwrap();
+
+        let result = Token::burn(origin!(member!(1).1), token!(1), member!(1).0, 1);
+
+        assert_err!(result, Error::<Test>::CannotBurnRevenueStakedTokens);
+    })
+}
+
+// --------------------- deposit_staking_revenue -------------------------------
+
+#[test]
+fn deposit_staking_revenue_fails_with_no_tokens_staked() {
+    build_test_externalities_with_revenue().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        let result = DepositStakingRevenueFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::NoTokensStakedForRevenue);
+    })
+}
+
+#[test]
+fn deposit_staking_revenue_fails_with_zero_allocation() {
+    build_test_externalities_with_revenue().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default().execute_call().unwrap();
+
+        let result = DepositStakingRevenueFixture::default()
+            .with_revenue_amount(1)
+            .execute_call();
+
+        assert_err!(
+            result,
+            Error::<Test>::CannotIssueSplitWithZeroAllocationAmount
+        );
+    })
+}
+
+#[test]
+fn deposit_staking_revenue_ok() {
+    build_test_externalities_with_revenue().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default().execute_call().unwrap();
+        let treasury_balance_pre = Joy::<Test>::usable_balance(Token::module_treasury_account());
+
+        let result = DepositStakingRevenueFixture::default().execute_call();
+
+        assert_ok!(result, DEFAULT_SPLIT_REVENUE - DEFAULT_STAKING_ALLOCATION);
+        assert_eq!(
+            Joy::<Test>::usable_balance(Token::module_treasury_account()),
+            treasury_balance_pre + DEFAULT_STAKING_ALLOCATION
+        );
+        assert_eq!(
+            Token::revenue_staking_pool(token!(1)).reward_per_token,
+            FixedU128::saturating_from_rational(
+                DEFAULT_STAKING_ALLOCATION,
+                DEFAULT_SPLIT_PARTICIPATION
+            )
+        );
+        last_event_eq!(RawEvent::StakingRevenueDeposited(
+            token!(1),
+            DEFAULT_STAKING_ALLOCATION,
+            DEFAULT_SPLIT_PARTICIPATION
+        ));
+    })
+}
+
+#[test]
+fn deposit_staking_revenue_ok_with_revenue_split_active() {
+    build_test_externalities_with_revenue().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default().execute_call().unwrap();
+        IssueRevenueSplitFixture::default().execute_call().unwrap();
+
+        let result = DepositStakingRevenueFixture::default().execute_call();
+
+        assert_ok!(result, DEFAULT_SPLIT_REVENUE - DEFAULT_STAKING_ALLOCATION);
+    })
+}
+
+#[test]
+fn staking_revenue_accrues_pro_rata_to_the_stakes() {
+    build_test_externalities_with_revenue().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default()
+            .with_amount(3 * DEFAULT_SPLIT_PARTICIPATION)
+            .execute_call()
+            .unwrap();
+        StakeForRevenueFixture::default()
+            .with_member(member!(2))
+            .execute_call()
+            .unwrap();
+
+        DepositStakingRevenueFixture::default()
+            .execute_call()
+            .unwrap();
+
+        let pool = Token::revenue_staking_pool(token!(1));
+        assert_eq!(
+            Token::revenue_stake(token!(1), member!(1).0).claimable_rewards(&pool),
+            DEFAULT_STAKING_ALLOCATION * 3 / 4
+        );
+        assert_eq!(
+            Token::revenue_stake(token!(1), member!(2).0).claimable_rewards(&pool),
+            DEFAULT_STAKING_ALLOCATION / 4
+        );
+    })
+}
+
+#[test]
+fn staking_revenue_does_not_accrue_to_stakes_added_after_the_deposit() {
+    build_test_externalities_with_revenue().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default().execute_call().unwrap();
+        DepositStakingRevenueFixture::default()
+            .execute_call()
+            .unwrap();
+
+        StakeForRevenueFixture::default()
+            .with_member(member!(2))
+            .execute_call()
+            .unwrap();
+
+        let result = ClaimStakingRewardsFixture::default()
+            .with_member(member!(2))
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::NoStakingRewardsToClaim);
+    })
+}
+
+// --------------------- claim_staking_rewards -------------------------------
+
+#[test]
+fn claim_staking_rewards_fails_with_no_rewards() {
+    build_test_externalities_with_revenue().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default().execute_call().unwrap();
+
+        let result = ClaimStakingRewardsFixture::default().execute_call();
+
+        assert_err!(result, Error::<Test>::NoStakingRewardsToClaim);
+    })
+}
+
+#[test]
+fn claim_staking_rewards_ok() {
+    build_test_externalities_with_revenue().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default().execute_call().unwrap();
+        DepositStakingRevenueFixture::default()
+            .execute_call()
+            .unwrap();
+        let balance_pre = Joy::<Test>::usable_balance(member!(1).1);
+
+        let result = ClaimStakingRewardsFixture::default().execute_call();
+
+        assert_ok!(result);
+        assert_eq!(
+            Joy::<Test>::usable_balance(member!(1).1),
+            balance_pre + DEFAULT_STAKING_ALLOCATION
+        );
+        let stake = Token::revenue_stake(token!(1), member!(1).0);
+        assert_eq!(stake.pending_rewards, 0);
+        assert_eq!(stake.amount, DEFAULT_SPLIT_PARTICIPATION);
+        last_event_eq!(RawEvent::StakingRewardsClaimed(
+            token!(1),
+            member!(1).0,
+            DEFAULT_STAKING_ALLOCATION
+        ));
+    })
+}
+
+// --------------------- unstake_from_revenue -------------------------------
+
+#[test]
+fn unstake_from_revenue_fails_with_insufficient_stake() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default().execute_call().unwrap();
+
+        let result = UnstakeFromRevenueFixture::default()
+            .with_amount(DEFAULT_SPLIT_PARTICIPATION + 1)
+            .execute_call();
+
+        assert_err!(result, Error::<Test>::InsufficientRevenueStake);
+    })
+}
+
+#[test]
+fn unstake_from_revenue_ok() {
+    build_test_externalities_with_revenue().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default().execute_call().unwrap();
+        DepositStakingRevenueFixture::default()
+            .execute_call()
+            .unwrap();
+        let unstaking_until = System::block_number() + RevenueStakingUnstakingPeriod::get();
+
+        let result = UnstakeFromRevenueFixture::default().execute_call();
+
+        assert_ok!(result);
+        let stake = Token::revenue_stake(token!(1), member!(1).0);
+        assert_eq!(stake.amount, 0);
+        assert_eq!(stake.unstaking_amount, DEFAULT_SPLIT_PARTICIPATION);
+        assert_eq!(stake.unstaking_until, unstaking_until);
+        // rewards accrued before unstaking are kept
+        assert_eq!(stake.pending_rewards, DEFAULT_STAKING_ALLOCATION);
+        assert_eq!(Token::revenue_staking_pool(token!(1)).total_staked, 0);
+        last_event_eq!(RawEvent::TokensUnstakedFromRevenue(
+            token!(1),
+            member!(1).0,
+            DEFAULT_SPLIT_PARTICIPATION,
+            unstaking_until
+        ));
+    })
+}
+
+#[test]
+fn unstaked_tokens_remain_locked_until_the_end_of_the_cooldown() {
+    build_default_test_externalities().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default()
+            .with_amount(DEFAULT_INITIAL_ISSUANCE)
+            .execute_call()
+            .unwrap();
+        UnstakeFromRevenueFixture::default()
+            .with_amount(DEFAULT_INITIAL_ISSUANCE)
+            .execute_call()
+            .unwrap();
+
+        increase_block_number_by(RevenueStakingUnstakingPeriod::get() - 1);
+        assert_err!(
+            TransferFixture::default().execute_call(),
+            Error::<Test>::InsufficientTransferrableBalance
+        );
+
+        increase_block_number_by(1);
+        assert_ok!(TransferFixture::default().execute_call());
+    })
+}
+
+#[test]
+fn dust_account_fails_with_unclaimed_staking_rewards() {
+    build_test_externalities_with_revenue().execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        TransferFixture::default().execute_call().unwrap();
+        StakeForRevenueFixture::default()
+            .with_member(member!(2))
+            .execute_call()
+            .unwrap();
+        DepositStakingRevenueFixture::default()
+            .execute_call()
+            .unwrap();
+        UnstakeFromRevenueFixture::default()
+            .with_member(member!(2))
+            .execute_call()
+            .unwrap();
+        increase_block_number_by(RevenueStakingUnstakingPeriod::get());
+        Token::burn(
+            origin!(member!(2).1),
+            token!(1),
+            member!(2).0,
+            DEFAULT_SPLIT_PARTICIPATION,
+        )
+        .unwrap();
+
+        let result = Token::dust_account(origin!(member!(2).1), token!(1), member!(2).0);
+
+        assert_err!(
+            result,
+            Error::<Test>::AttemptToRemoveAccountWithUnclaimedStakingRewards
+        );
+    })
+}
@@ -107,6 +107,8 @@ impl GenesisConfigBuilder {
             amm_deactivation_threshold: Permill::from_percent(5),
             bond_tx_fees: DEFAULT_AMM_BUY_FEES,
             unbond_tx_fees: DEFAULT_AMM_SELL_FEES,
+            order_book_tx_fees: DEFAULT_ORDER_BOOK_TX_FEES,
+            min_order_value: MIN_ORDER_VALUE,
             max_yearly_patronage_rate: DEFAULT_MAX_YEARLY_PATRONAGE_RATE.into(),
             min_amm_slope_parameter: 10u32.into(),
         }
@@ -183,6 +185,8 @@ impl GenesisConfigBuilder {
             amm_deactivation_threshold: self.amm_deactivation_threshold,
             amm_buy_tx_fees: self.bond_tx_fees,
             amm_sell_tx_fees: self.unbond_tx_fees,
+            order_book_tx_fees: self.order_book_tx_fees,
+            min_order_value: self.min_order_value,
             max_yearly_patronage_rate: self.max_yearly_patronage_rate,
             min_amm_slope_parameter: self.min_amm_slope_parameter,
         }
@@ -297,6 +301,26 @@ pub fn new_issuer_transfers(
                 PaymentWithVesting {
                     amount,
                     vesting_schedule,
+                    revocable: false,
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    BoundedVec::<_, _>::try_from(tmp).ok().unwrap()
+}
+
+pub fn new_revocable_issuer_transfers(
+    v: Vec<(MemberId, Balance, VestingScheduleParams)>,
+) -> BoundedVec<(MemberId, PaymentWithVesting<Balance, VestingScheduleParams>), MaxOutputs> {
+    let tmp = v
+        .into_iter()
+        .map(|(member_id, amount, vesting_schedule)| {
+            (
+                member_id,
+                PaymentWithVesting {
+                    amount,
+                    vesting_schedule: Some(vesting_schedule),
+                    revocable: true,
                 },
             )
         })
@@ -330,6 +354,7 @@ where
                                 payment: PaymentWithVesting::<Balance, VestingScheduleParams> {
                                     amount,
                                     vesting_schedule,
+                                    revocable: false,
                                 },
                                 vesting_cleanup_candidate,
                             },
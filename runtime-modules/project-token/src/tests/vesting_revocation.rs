@@ -0,0 +1,246 @@
+#![cfg(test)]
+
+use crate::tests::mock::*;
+use crate::tests::test_utils::{new_issuer_transfers, new_revocable_issuer_transfers};
+use crate::traits::PalletToken;
+use crate::types::VestingSource;
+use crate::{last_event_eq, member, token, Error, RawEvent, RevocableVestingSchedules};
+use frame_support::{assert_noop, assert_ok, StorageDoubleMap};
+use sp_runtime::{DispatchResult, Permill};
+
+const GRANT_AMOUNT: u128 = 1000;
+
+fn grant_vesting_schedule_params() -> VestingScheduleParams {
+    VestingScheduleParams {
+        blocks_before_cliff: 100,
+        cliff_amount_percentage: Permill::zero(),
+        linear_vesting_duration: 100,
+    }
+}
+
+fn grant_revocable_vesting_schedule() -> DispatchResult {
+    Token::issuer_transfer(
+        token!(1),
+        member!(1).0,
+        member!(1).1,
+        new_revocable_issuer_transfers(vec![(
+            member!(2).0,
+            GRANT_AMOUNT,
+            grant_vesting_schedule_params(),
+        )]),
+        vec![],
+    )
+}
+
+fn revoke_vesting_schedule(issuer_member_id: MemberId) -> DispatchResult {
+    Token::revoke_vesting_schedule(token!(1), member!(2).0, 0, issuer_member_id, member!(1).1)
+}
+
+#[test]
+fn issuer_transfer_ok_with_revocable_vesting_schedule_recorded() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        assert_ok!(grant_revocable_vesting_schedule());
+
+        assert_eq!(
+            Token::revocable_vesting_schedule_issuer((token!(1), member!(2).0), 0),
+            member!(1).0
+        );
+    })
+}
+
+#[test]
+fn issuer_transfer_ok_without_revocable_flag_not_recorded() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+
+        assert_ok!(Token::issuer_transfer(
+            token!(1),
+            member!(1).0,
+            member!(1).1,
+            new_issuer_transfers(vec![(
+                member!(2).0,
+                GRANT_AMOUNT,
+                Some(grant_vesting_schedule_params())
+            )]),
+            vec![],
+        ));
+
+        assert!(!RevocableVestingSchedules::<Test>::contains_key(
+            (token!(1), member!(2).0),
+            0
+        ));
+    })
+}
+
+#[test]
+fn revoke_vesting_schedule_fails_with_non_existing_token() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        let result = revoke_vesting_schedule(member!(1).0);
+
+        assert_noop!(result, Error::<Test>::TokenDoesNotExist);
+    })
+}
+
+#[test]
+fn revoke_vesting_schedule_fails_with_non_revocable_schedule() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        assert_ok!(Token::issuer_transfer(
+            token!(1),
+            member!(1).0,
+            member!(1).1,
+            new_issuer_transfers(vec![(
+                member!(2).0,
+                GRANT_AMOUNT,
+                Some(grant_vesting_schedule_params())
+            )]),
+            vec![],
+        ));
+
+        let result = revoke_vesting_schedule(member!(1).0);
+
+        assert_noop!(result, Error::<Test>::VestingScheduleNotRevocable);
+    })
+}
+
+#[test]
+fn revoke_vesting_schedule_fails_when_already_revoked() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        grant_revocable_vesting_schedule().unwrap();
+        revoke_vesting_schedule(member!(1).0).unwrap();
+
+        let result = revoke_vesting_schedule(member!(1).0);
+
+        assert_noop!(result, Error::<Test>::VestingScheduleNotRevocable);
+    })
+}
+
+#[test]
+fn revoke_vesting_schedule_ok_before_cliff() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        grant_revocable_vesting_schedule().unwrap();
+
+        assert_ok!(revoke_vesting_schedule(member!(1).0));
+
+        let holder_account = Token::account_info_by_token_and_member(token!(1), member!(2).0);
+        assert_eq!(holder_account.amount, 0);
+        assert!(holder_account.vesting_schedules.is_empty());
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE
+        );
+        assert!(!RevocableVestingSchedules::<Test>::contains_key(
+            (token!(1), member!(2).0),
+            0
+        ));
+    })
+}
+
+#[test]
+fn revoke_vesting_schedule_ok_during_linear_vesting() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        grant_revocable_vesting_schedule().unwrap();
+        increase_block_number_by(150);
+
+        assert_ok!(revoke_vesting_schedule(member!(1).0));
+
+        let holder_account = Token::account_info_by_token_and_member(token!(1), member!(2).0);
+        assert_eq!(holder_account.amount, GRANT_AMOUNT / 2);
+        assert_eq!(
+            holder_account.transferrable::<Test>(System::block_number()),
+            GRANT_AMOUNT / 2
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - GRANT_AMOUNT / 2
+        );
+    })
+}
+
+#[test]
+fn revoke_vesting_schedule_ok_after_vesting_end() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        grant_revocable_vesting_schedule().unwrap();
+        increase_block_number_by(200);
+
+        assert_ok!(revoke_vesting_schedule(member!(1).0));
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(2).0).amount,
+            GRANT_AMOUNT
+        );
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(1).0).amount,
+            DEFAULT_INITIAL_ISSUANCE - GRANT_AMOUNT
+        );
+    })
+}
+
+#[test]
+fn revoke_vesting_schedule_ok_with_event_deposited() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        grant_revocable_vesting_schedule().unwrap();
+
+        revoke_vesting_schedule(member!(1).0).unwrap();
+
+        last_event_eq!(RawEvent::VestingScheduleRevoked(
+            token!(1),
+            member!(2).0,
+            VestingSource::IssuerTransfer(0),
+            member!(1).0,
+            GRANT_AMOUNT
+        ));
+    })
+}
+
+#[test]
+fn revoke_vesting_schedule_ok_with_current_issuer_account_created() {
+    build_test_externalities(GenesisConfigBuilder::new_empty().build()).execute_with(|| {
+        IssueTokenFixture::default().execute_call().unwrap();
+        grant_revocable_vesting_schedule().unwrap();
+        let accounts_number = Token::token_info_by_id(token!(1)).accounts_number;
+
+        assert_ok!(revoke_vesting_schedule(member!(3).0));
+
+        assert_eq!(
+            Token::account_info_by_token_and_member(token!(1), member!(3).0).amount,
+            GRANT_AMOUNT
+        );
+        assert_eq!(
+            Token::token_info_by_id(token!(1)).accounts_number,
+            accounts_number + 1
+        );
+        last_event_eq!(RawEvent::VestingScheduleRevoked(
+            token!(1),
+            member!(2).0,
+            VestingSource::IssuerTransfer(0),
+            member!(3).0,
+            GRANT_AMOUNT
+        ));
+    })
+}
+
+#[test]
+fn revoke_vesting_schedule_fails_with_insufficient_funds_for_issuer_account_bloat_bond() {
+    let config = GenesisConfigBuilder::new_empty()
+        .with_bloat_bond(joy!(100))
+        .build();
+
+    build_test_externalities(config).execute_with(|| {
+        // Covers the bloat bonds of the issuer and holder accounts only
+        increase_account_balance(&member!(1).1, ExistentialDeposit::get() + joy!(200));
+        IssueTokenFixture::default().execute_call().unwrap();
+        grant_revocable_vesting_schedule().unwrap();
+
+        let result = revoke_vesting_schedule(member!(3).0);
+
+        assert_noop!(result, Error::<Test>::InsufficientJoyBalance);
+    })
+}
@@ -2,7 +2,7 @@ use frame_support::dispatch::DispatchResult;
 use sp_runtime::DispatchError;
 use sp_std::vec::Vec;
 
-use crate::types::YearlyRate;
+use crate::types::{PollId, YearlyRate};
 
 pub trait PalletToken<
     TokenId,
@@ -15,6 +15,8 @@ pub trait PalletToken<
     UploadContext,
     TransfersWithVesting,
     AmmParams,
+    AirdropParams,
+    PollParams,
 >
 {
     /// Issue token with specified characteristics
@@ -34,6 +36,37 @@ pub trait PalletToken<
         metadata: Vec<u8>,
     ) -> DispatchResult;
 
+    /// Revoke a revocable vesting schedule created through an issuer transfer: the vested
+    /// amount stays with the holder, while the unvested amount returns to the current issuer
+    /// member account (created if it doesn't exist)
+    fn revoke_vesting_schedule(
+        token_id: TokenId,
+        member_id: MemberId,
+        transfer_id: u64,
+        issuer_member_id: MemberId,
+        bloat_bond_payer: AccountId,
+    ) -> DispatchResult;
+
+    /// Create an airdrop funded from the issuer account, claimable with a merkle proof
+    fn create_airdrop(
+        token_id: TokenId,
+        member_id: MemberId,
+        params: AirdropParams,
+    ) -> DispatchResult;
+
+    /// Open a token holder poll
+    fn create_poll(
+        token_id: TokenId,
+        member_id: MemberId,
+        params: PollParams,
+    ) -> Result<PollId, DispatchError>;
+
+    /// Winning option of an ended poll (None in case of a tie or no votes)
+    fn poll_outcome(token_id: TokenId, poll_id: PollId) -> Result<Option<u32>, DispatchError>;
+
+    /// Remove an ended poll and its votes in bounded batches (true once the poll is removed)
+    fn finalize_poll(token_id: TokenId, poll_id: PollId) -> Result<bool, DispatchError>;
+
     /// Update existing, upcoming token sale
     fn update_upcoming_sale(
         token_id: TokenId,
@@ -50,6 +83,15 @@ pub trait PalletToken<
         sale_params: TokenSaleParams,
     ) -> DispatchResult;
 
+    /// Initialize new token sale in batch auction mode: bids are filled at a single
+    /// clearing price once the sale ends, with `unit_price` acting as the reserve price
+    fn init_batch_auction(
+        token_id: TokenId,
+        member_id: MemberId,
+        earnings_destination: Option<AccountId>,
+        sale_params: TokenSaleParams,
+    ) -> DispatchResult;
+
     /// Remove token data from storage
     fn deissue_token(token_id: TokenId) -> DispatchResult;
 
@@ -74,6 +116,16 @@ pub trait PalletToken<
     /// Finalize split by sending back eventual JOYs leftover
     fn finalize_revenue_split(token_id: TokenId, account_id: AccountId) -> DispatchResult;
 
+    /// Deposit revenue to be streamed to the token's revenue staking pool stakers
+    fn deposit_staking_revenue(
+        token_id: TokenId,
+        revenue_source_account: AccountId,
+        revenue_amount: JoyBalance,
+    ) -> Result<JoyBalance, DispatchError>;
+
+    /// Enable / disable bridging the token to remote chains
+    fn update_token_bridging(token_id: TokenId, enabled: bool) -> DispatchResult;
+
     /// Finalize creator token sale and recover unsold tokens
     fn finalize_token_sale(token_id: TokenId) -> Result<JoyBalance, DispatchError>;
 
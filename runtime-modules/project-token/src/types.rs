@@ -3,21 +3,24 @@ use sp_std::iter::Sum;
 use codec::{Decode, Encode, MaxEncodedLen};
 use common::{
     bloat_bond::RepayableBloatBond,
-    numerical::{amm_eval_inner, one_plus_interest_pow_fixed},
+    numerical::{amm_eval_inner, checked_pow_fixed, one_plus_interest_pow_fixed},
     MembershipTypes,
 };
 use frame_support::{
     dispatch::{fmt::Debug, DispatchError, DispatchResult},
-    ensure,
+    ensure, parameter_types,
     traits::{tokens::Balance as BalanceTrait, Get},
     BoundedBTreeMap, BoundedVec,
 };
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_arithmetic::traits::{AtLeast32BitUnsigned, One, Saturating, Zero};
+use sp_arithmetic::traits::{
+    AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, Saturating, Zero,
+};
 use sp_runtime::{
     traits::Hash, FixedPointNumber, FixedPointOperand, FixedU128, Permill, Perquintill,
+    SaturatedConversion,
 };
 use sp_std::{
     borrow::ToOwned,
@@ -58,6 +61,8 @@ pub struct TokenConstraints<Balance, BlockNumber, JoyBalance> {
     pub amm_buy_tx_fees: Option<Permill>,
     pub amm_sell_tx_fees: Option<Permill>,
     pub bloat_bond: Option<JoyBalance>,
+    pub order_book_tx_fees: Option<Permill>,
+    pub min_order_value: Option<JoyBalance>,
 }
 
 /// Source of tokens subject to vesting that were acquired by an account
@@ -600,26 +605,306 @@ impl<
     }
 }
 
-/// Represents token's amm with linear pricing function y = ax + b
+/// Batch auction sale mode of the current token sale: instead of being purchased at a fixed
+/// `unit_price`, tokens are bid for during the sale and all winning bids are filled at a
+/// single clearing price once the sale ends (`unit_price` acts as the reserve price)
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-#[derive(Default, Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq, MaxEncodedLen)]
-pub struct AmmParams<Balance> {
-    /// Slope parameter : a
-    pub slope: Balance,
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo, MaxEncodedLen)]
+pub struct BatchAuction<JoyBalance, Balance> {
+    /// Number of bids placed and not yet settled
+    pub bids_number: u32,
 
-    /// Intercept : b
-    pub intercept: Balance,
+    /// Clearing of the auction, determined once the sale has ended
+    pub clearing: Option<BatchAuctionClearing<JoyBalance, Balance>>,
 }
 
-/// Represents token's amm curve with linear pricing function y = ax + b
+/// Uniform clearing price of a batch auction
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-#[derive(Default, Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq, MaxEncodedLen)]
-pub struct AmmCurve<Balance> {
-    /// Slope parameter : a
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo, MaxEncodedLen)]
+pub struct BatchAuctionClearing<JoyBalance, Balance> {
+    /// Price (in JOY) paid for each token by all winning bids
+    pub price: JoyBalance,
+
+    /// Quantity of tokens shared pro-rata among the bids placed exactly at the clearing price
+    pub marginal_supply: Balance,
+
+    /// Total quantity requested by the bids placed exactly at the clearing price
+    pub marginal_demand: Balance,
+}
+
+impl<JoyBalance: Copy + Ord, Balance: TokenBalanceTrait> BatchAuctionClearing<JoyBalance, Balance> {
+    /// Compute the clearing of `supply` tokens given the `(max_price, quantity)` bids:
+    /// the clearing price is the lowest max price at which demand covers the supply
+    /// (or the lowest bid price if the whole demand can be filled)
+    pub(crate) fn compute(
+        bids: impl Iterator<Item = (JoyBalance, Balance)>,
+        supply: Balance,
+        reserve_price: JoyBalance,
+    ) -> Self {
+        let mut demand_by_price = BTreeMap::<JoyBalance, Balance>::new();
+        for (price, quantity) in bids {
+            let demand = demand_by_price.entry(price).or_insert_with(Balance::zero);
+            *demand = demand.saturating_add(quantity);
+        }
+
+        let mut clearing = Self {
+            price: reserve_price,
+            marginal_supply: Balance::zero(),
+            marginal_demand: Balance::zero(),
+        };
+        let mut remaining_supply = supply;
+        for (price, demand) in demand_by_price.into_iter().rev() {
+            clearing = Self {
+                price,
+                marginal_supply: min(demand, remaining_supply),
+                marginal_demand: demand,
+            };
+            if demand >= remaining_supply {
+                break;
+            }
+            remaining_supply = remaining_supply.saturating_sub(demand);
+        }
+
+        clearing
+    }
+
+    /// Quantity of tokens filled for a bid of `quantity` tokens at `max_price`
+    pub(crate) fn filled_quantity(&self, quantity: Balance, max_price: JoyBalance) -> Balance {
+        if max_price < self.price {
+            Balance::zero()
+        } else if max_price > self.price || self.marginal_supply >= self.marginal_demand {
+            quantity
+        } else {
+            Perquintill::from_rational(self.marginal_supply, self.marginal_demand)
+                .mul_floor(quantity)
+        }
+    }
+}
+
+/// Bid placed in a batch auction, with `quantity * max_price` JOY locked in the treasury
+/// account until the bid is settled
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct BatchAuctionBid<AccountId, JoyBalance, Balance> {
+    /// Account the locked JOY is refunded to
+    pub bidder_account: AccountId,
+
+    /// Quantity of tokens requested
+    pub quantity: Balance,
+
+    /// Maximum price (in JOY) the bidder is willing to pay for each token
+    pub max_price: JoyBalance,
+}
+
+impl<AccountId, JoyBalance: Saturating + Copy, Balance: Into<JoyBalance> + Copy>
+    BatchAuctionBid<AccountId, JoyBalance, Balance>
+{
+    /// Amount of JOY locked by the bid
+    pub(crate) fn locked_amount(&self) -> JoyBalance {
+        self.max_price.saturating_mul(self.quantity.into())
+    }
+}
+
+/// Side of a limit order
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum OrderSide {
+    /// Buy tokens for JOY
+    Buy,
+
+    /// Sell tokens for JOY
+    Sell,
+}
+
+/// Limit order placed in a token order book.
+/// Buy orders lock `price * remaining quantity` JOY in the treasury account,
+/// sell orders lock the remaining quantity of tokens, moved out of the seller account.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct LimitOrder<MemberId, AccountId, Balance, JoyBalance> {
+    /// Member that placed the order
+    pub member_id: MemberId,
+
+    /// Account JOY is paid out / refunded to
+    pub account: AccountId,
+
+    /// Order side
+    pub side: OrderSide,
+
+    /// Price (in JOY) per token
+    pub price: JoyBalance,
+
+    /// Quantity of tokens ordered
+    pub quantity: Balance,
+
+    /// Quantity of tokens filled so far
+    pub filled_quantity: Balance,
+}
+
+impl<MemberId, AccountId, Balance: TokenBalanceTrait, JoyBalance: JoyTokenBalanceTrait>
+    LimitOrder<MemberId, AccountId, Balance, JoyBalance>
+where
+    Balance: Into<JoyBalance>,
+{
+    /// Quantity of tokens not filled yet
+    pub fn remaining_quantity(&self) -> Balance {
+        self.quantity.saturating_sub(self.filled_quantity)
+    }
+
+    /// Amount of JOY locked by the order (always 0 for sell orders)
+    pub(crate) fn locked_amount(&self) -> JoyBalance {
+        match self.side {
+            OrderSide::Buy => self.price.saturating_mul(self.remaining_quantity().into()),
+            OrderSide::Sell => JoyBalance::zero(),
+        }
+    }
+
+    pub(crate) fn fill(&mut self, quantity: Balance) {
+        self.filled_quantity = self.filled_quantity.saturating_add(quantity);
+    }
+}
+
+/// Order book of a token: open orders indexed by price, with the best price first.
+/// Orders with the same price are ordered by id (time priority).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo, MaxEncodedLen)]
+pub struct OrderBook<OrderEntries> {
+    /// Buy orders (highest price first)
+    pub bids: OrderEntries,
+
+    /// Sell orders (lowest price first)
+    pub asks: OrderEntries,
+}
+
+impl<JoyBalance: Ord + Copy, MaxOrders: Get<u32>>
+    OrderBook<BoundedVec<(JoyBalance, OrderId), MaxOrders>>
+{
+    /// Whether the order book `side` can accept another order
+    pub fn is_full(&self, side: OrderSide) -> bool {
+        let entries = match side {
+            OrderSide::Buy => &self.bids,
+            OrderSide::Sell => &self.asks,
+        };
+        entries.len() >= MaxOrders::get() as usize
+    }
+
+    /// Best (highest) bid and best (lowest) ask, provided they cross
+    pub fn crossing_orders(&self) -> Option<((JoyBalance, OrderId), (JoyBalance, OrderId))> {
+        match (self.bids.first(), self.asks.first()) {
+            (Some(bid), Some(ask)) if bid.0 >= ask.0 => Some((*bid, *ask)),
+            _ => None,
+        }
+    }
+
+    /// Insert an order entry keeping the price-time priority.
+    /// Preconditions:
+    /// - order book `side` is not full
+    pub(crate) fn insert<T: Config>(
+        &mut self,
+        side: OrderSide,
+        price: JoyBalance,
+        order_id: OrderId,
+    ) -> DispatchResult {
+        let entries = match side {
+            OrderSide::Buy => &mut self.bids,
+            OrderSide::Sell => &mut self.asks,
+        };
+        let position = entries
+            .iter()
+            .position(|(p, _)| match side {
+                OrderSide::Buy => *p < price,
+                OrderSide::Sell => *p > price,
+            })
+            .unwrap_or_else(|| entries.len());
+        let mut updated = entries.to_vec();
+        updated.insert(position, (price, order_id));
+        *entries = updated
+            .try_into()
+            .map_err(|_| Error::<T>::MaxOrdersPerBookSideReached)?;
+        Ok(())
+    }
+
+    pub(crate) fn remove(&mut self, order_id: OrderId) {
+        self.bids.retain(|(_, id)| *id != order_id);
+        self.asks.retain(|(_, id)| *id != order_id);
+    }
+}
+
+parameter_types! {
+    /// Maximum number of breakpoints of the piecewise linear amm curve
+    pub const MaxAmmCurveBreakpoints: u32 = 10;
+}
+
+/// Breakpoint of the piecewise linear amm curve:
+/// price grows with `slope` starting from the `supply` amount provided by the amm
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Default, Encode, Decode, TypeInfo, Clone, Copy, Debug, Eq, PartialEq, MaxEncodedLen)]
+pub struct AmmCurveBreakpoint<Balance> {
+    /// Amm provided supply at which the segment starts
+    pub supply: Balance,
+
+    /// Slope parameter of the segment
     pub slope: Balance,
+}
 
-    /// Intercept : b
-    pub intercept: Balance,
+/// Represents token's amm pricing function kind
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq, MaxEncodedLen)]
+pub enum AmmCurveKind<Balance, Breakpoints> {
+    /// Linear pricing function y = ax + b
+    Linear {
+        /// Slope parameter : a
+        slope: Balance,
+
+        /// Intercept : b
+        intercept: Balance,
+    },
+
+    /// Exponential pricing function y = b * (1 + r)^floor(x / s)
+    Exponential {
+        /// Price of the first supply step : b
+        initial_price: Balance,
+
+        /// Price growth per supply step : r
+        growth_rate: Permill,
+
+        /// Supply step : s
+        supply_step: Balance,
+    },
+
+    /// Constant product pricing function (J + y) * (T - x) = J * T
+    /// against the JOY reserve
+    ConstantProduct {
+        /// Initial (virtual) JOY reserve : J
+        joy_reserve: Balance,
+
+        /// Initial (virtual) token reserve : T
+        token_reserve: Balance,
+    },
+
+    /// Piecewise linear pricing function starting at the intercept
+    PiecewiseLinear {
+        /// Price of the first token
+        intercept: Balance,
+
+        /// Segment breakpoints ordered by supply, the first one starting at zero supply
+        breakpoints: Breakpoints,
+    },
+}
+
+/// Represents token's amm curve parameters
+pub type AmmParams<Balance> = AmmCurveKind<Balance, Vec<AmmCurveBreakpoint<Balance>>>;
+
+/// Represents token's amm pricing function kind with bounded breakpoints
+pub type AmmCurveKindOf<Balance> =
+    AmmCurveKind<Balance, BoundedVec<AmmCurveBreakpoint<Balance>, MaxAmmCurveBreakpoints>>;
+
+/// Represents token's amm curve
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, TypeInfo, Clone, Debug, Eq, PartialEq, MaxEncodedLen)]
+pub struct AmmCurve<Balance> {
+    /// Pricing function
+    pub kind: AmmCurveKindOf<Balance>,
 
     // amount of token added to circulation by the AMM so far
     pub provided_supply: Balance,
@@ -630,13 +915,220 @@ pub(crate) enum AmmOperation {
     Sell,
     Buy,
 }
+
+/// Rounding direction of the amm cumulative price
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum AmmRounding {
+    Up,
+    Down,
+}
+
+// Multiplies the fixed point number by the integer, rounding the product in the given direction
+fn checked_mul_int_rounded<Balance: TokenBalanceTrait>(
+    x: FixedU128,
+    n: Balance,
+    rounding: AmmRounding,
+) -> Option<Balance> {
+    let product = x.checked_mul_int(n)?;
+    let is_exact = x
+        .frac()
+        .into_inner()
+        .checked_mul(n.saturated_into::<u128>())
+        .and_then(|frac_product| frac_product.checked_rem(FixedU128::DIV))
+        .map_or(false, |remainder| remainder.is_zero());
+
+    match rounding {
+        AmmRounding::Up if !is_exact => product.checked_add(&Balance::one()),
+        _ => Some(product),
+    }
+}
+
+impl<Balance: TokenBalanceTrait> AmmCurveKindOf<Balance> {
+    // Validates the curve parameters and bounds the breakpoints
+    pub(crate) fn try_from_params<T: Config>(
+        params: AmmParams<Balance>,
+        min_slope: Balance,
+    ) -> Result<Self, DispatchError> {
+        match params {
+            AmmParams::Linear { slope, intercept } => {
+                ensure!(slope >= min_slope, Error::<T>::CurveSlopeParametersTooLow);
+
+                Ok(Self::Linear { slope, intercept })
+            }
+            AmmParams::Exponential {
+                initial_price,
+                growth_rate,
+                supply_step,
+            } => {
+                ensure!(
+                    !initial_price.is_zero() && !supply_step.is_zero(),
+                    Error::<T>::InvalidCurveParameters
+                );
+
+                Ok(Self::Exponential {
+                    initial_price,
+                    growth_rate,
+                    supply_step,
+                })
+            }
+            AmmParams::ConstantProduct {
+                joy_reserve,
+                token_reserve,
+            } => {
+                ensure!(
+                    !joy_reserve.is_zero() && !token_reserve.is_zero(),
+                    Error::<T>::InvalidCurveParameters
+                );
+                ensure!(
+                    joy_reserve.checked_mul(&token_reserve).is_some(),
+                    Error::<T>::InvalidCurveParameters
+                );
+
+                Ok(Self::ConstantProduct {
+                    joy_reserve,
+                    token_reserve,
+                })
+            }
+            AmmParams::PiecewiseLinear {
+                intercept,
+                breakpoints,
+            } => {
+                ensure!(
+                    breakpoints
+                        .first()
+                        .map_or(false, |breakpoint| breakpoint.supply.is_zero()),
+                    Error::<T>::InvalidAmmCurveBreakpoints
+                );
+                ensure!(
+                    breakpoints
+                        .windows(2)
+                        .all(|pair| pair.first().map(|b| b.supply) < pair.last().map(|b| b.supply)),
+                    Error::<T>::InvalidAmmCurveBreakpoints
+                );
+                ensure!(
+                    breakpoints
+                        .iter()
+                        .all(|breakpoint| breakpoint.slope >= min_slope),
+                    Error::<T>::CurveSlopeParametersTooLow
+                );
+
+                let breakpoints = breakpoints
+                    .try_into()
+                    .map_err(|_| Error::<T>::MaxAmmCurveBreakpointsExceeded)?;
+
+                Ok(Self::PiecewiseLinear {
+                    intercept,
+                    breakpoints,
+                })
+            }
+        }
+    }
+
+    // Total JOY amount required to provide the `supply` amount of tokens starting from
+    // the zero supply. The price of any amm operation is the difference of the values
+    // before and after the operation, so the amm treasury always holds the value for the
+    // current provided supply. The exponential price is not an integer: it is rounded in the
+    // given direction.
+    pub(crate) fn cumulative_price(
+        &self,
+        supply: Balance,
+        rounding: AmmRounding,
+    ) -> Option<Balance> {
+        match self {
+            Self::Linear { slope, intercept } => {
+                amm_eval_inner::<Balance>(Balance::zero(), supply, *slope, *intercept)
+            }
+            Self::Exponential {
+                initial_price,
+                growth_rate,
+                supply_step,
+            } => {
+                let steps = supply.checked_div(supply_step)?;
+                let partial_step = supply.checked_rem(supply_step)?;
+
+                let growth = FixedU128::one().checked_add(&FixedU128::from(*growth_rate))?;
+                let current_step_growth =
+                    checked_pow_fixed(growth, steps.saturated_into::<u128>())?;
+
+                // b * s * ((1 + r)^k - 1) / r, or b * s * k with no growth
+                let full_steps_price = if *growth_rate == Permill::zero() {
+                    initial_price
+                        .checked_mul(supply_step)?
+                        .checked_mul(&steps)?
+                } else {
+                    let full_steps_growth = current_step_growth
+                        .checked_sub(&FixedU128::one())?
+                        .checked_div(&FixedU128::from(*growth_rate))?;
+
+                    checked_mul_int_rounded(full_steps_growth, *initial_price, rounding)?
+                        .checked_mul(supply_step)?
+                };
+
+                let partial_step_price =
+                    checked_mul_int_rounded(current_step_growth, *initial_price, rounding)?
+                        .checked_mul(&partial_step)?;
+
+                full_steps_price.checked_add(&partial_step_price)
+            }
+            Self::ConstantProduct {
+                joy_reserve,
+                token_reserve,
+            } => {
+                let invariant = joy_reserve.checked_mul(token_reserve)?;
+                let remaining_token_reserve = token_reserve.checked_sub(&supply)?;
+
+                invariant
+                    .checked_div(&remaining_token_reserve)?
+                    .checked_sub(joy_reserve)
+            }
+            Self::PiecewiseLinear {
+                intercept,
+                breakpoints,
+            } => {
+                let mut segment_start_price = *intercept;
+                let mut total_price = Balance::zero();
+
+                for (index, breakpoint) in breakpoints.iter().enumerate() {
+                    if supply <= breakpoint.supply {
+                        break;
+                    }
+
+                    let segment_end = breakpoints
+                        .get(index.saturating_add(1))
+                        .map_or(supply, |next| min(next.supply, supply));
+                    let segment_length = segment_end.checked_sub(&breakpoint.supply)?;
+
+                    // integral of the segment: p * l + a * l^2 / 2
+                    let segment_price = segment_start_price
+                        .checked_mul(&segment_length)?
+                        .checked_add(
+                            &breakpoint
+                                .slope
+                                .checked_mul(&segment_length)?
+                                .checked_mul(&segment_length)?
+                                .checked_div(&Balance::from(2u32))?,
+                        )?;
+
+                    total_price = total_price.checked_add(&segment_price)?;
+                    segment_start_price = segment_start_price
+                        .checked_add(&breakpoint.slope.checked_mul(&segment_length)?)?;
+                }
+
+                Some(total_price)
+            }
+        }
+    }
+}
+
 impl<Balance: TokenBalanceTrait> AmmCurve<Balance> {
-    pub(crate) fn from_params<T: Config>(params: AmmParams<Balance>) -> Self {
-        Self {
-            slope: params.slope,
-            intercept: params.intercept,
+    pub(crate) fn from_params<T: Config>(
+        params: AmmParams<Balance>,
+        min_slope: Balance,
+    ) -> Result<Self, DispatchError> {
+        Ok(Self {
+            kind: AmmCurveKindOf::<Balance>::try_from_params::<T>(params, min_slope)?,
             provided_supply: Balance::zero(),
-        }
+        })
     }
 
     pub(crate) fn increase_amm_bought_amount_by(&mut self, amount: Balance) {
@@ -660,16 +1152,35 @@ impl<Balance: TokenBalanceTrait> AmmCurve<Balance> {
             );
             self.provided_supply.sub(amount)
         } else {
-            self.provided_supply.add(amount)
+            self.provided_supply
+                .checked_add(&amount)
+                .ok_or(Error::<T>::ArithmeticError)?
         };
 
-        amm_eval_inner::<Balance>(
-            provided_supply_pre,
-            provided_supply_post,
-            self.slope,
-            self.intercept,
-        )
-        .ok_or_else(|| Error::<T>::ArithmeticError.into())
+        if let AmmCurveKind::ConstantProduct { token_reserve, .. } = self.kind {
+            ensure!(
+                provided_supply_post < token_reserve,
+                Error::<T>::InsufficientAmmTokenReserve
+            );
+        }
+
+        // The price before the operation is rounded down and the price after the operation is
+        // rounded up: buys are rounded up and sells are rounded down, so a buy followed by a sell
+        // of the same amount never yields a profit.
+        let price_pre = self
+            .kind
+            .cumulative_price(provided_supply_pre, AmmRounding::Down);
+        let price_post = self
+            .kind
+            .cumulative_price(provided_supply_post, AmmRounding::Up);
+
+        price_pre
+            .zip(price_post)
+            .map(|(pre, post)| match bond_operation {
+                AmmOperation::Buy => post.saturating_sub(pre),
+                AmmOperation::Sell => pre.saturating_sub(post),
+            })
+            .ok_or_else(|| Error::<T>::ArithmeticError.into())
     }
 }
 
@@ -877,6 +1388,10 @@ pub struct PaymentWithVesting<Balance, VestingScheduleParams> {
 
     /// Optional vesting schedule to be applied on the transferred tokens
     pub vesting_schedule: Option<VestingScheduleParams>,
+
+    /// Whether the issuer can revoke the vesting schedule
+    /// (only applies to issuer transfers with a vesting schedule)
+    pub revocable: bool,
 }
 
 impl<Balance, VestingScheduleParams> From<Balance>
@@ -886,6 +1401,7 @@ impl<Balance, VestingScheduleParams> From<Balance>
         Self {
             amount,
             vesting_schedule: None,
+            revocable: false,
         }
     }
 }
@@ -1152,6 +1668,17 @@ where
             .saturating_sub(max(self.unvested::<T>(b), self.staked()))
     }
 
+    /// Calculate account's transferrable balance at block `b`, given the amount
+    /// of tokens locked outside of the account data (poll votes, revenue staking)
+    pub fn transferrable_with_locks<T: Config<Balance = Balance, BlockNumber = BlockNumber>>(
+        &self,
+        b: BlockNumber,
+        locked: Balance,
+    ) -> Balance {
+        self.amount
+            .saturating_sub(max(max(self.unvested::<T>(b), self.staked()), locked))
+    }
+
     pub fn staked(&self) -> Balance {
         self.split_staking_status
             .as_ref()
@@ -1231,19 +1758,6 @@ where
         self.amount = self.amount.saturating_sub(amount);
     }
 
-    /// Ensure that given amount of tokens can be transferred from the account at block `b`
-    pub fn ensure_can_transfer<T: Config<Balance = Balance, BlockNumber = BlockNumber>>(
-        &self,
-        b: BlockNumber,
-        amount: Balance,
-    ) -> DispatchResult {
-        ensure!(
-            self.transferrable::<T>(b) >= amount,
-            crate::Error::<T>::InsufficientTransferrableBalance,
-        );
-        Ok(())
-    }
-
     /// Process changes related to new sale purchase
     pub fn process_sale_purchase<T: Config>(
         &mut self,
@@ -1314,6 +1828,21 @@ where
 
         Ok(())
     }
+
+    /// Remove the vesting schedule by `source` along with its `unvested_amount` of tokens
+    pub(crate) fn revoke_vesting_schedule(
+        &mut self,
+        source: &VestingSource,
+        unvested_amount: Balance,
+    ) {
+        self.vesting_schedules.remove(source);
+        self.decrease_amount_by(unvested_amount);
+        // Staked tokens cannot exceed the remaining amount
+        let amount = self.amount;
+        if let Some(staking_status) = self.split_staking_status.as_mut() {
+            staking_status.amount = min(staking_status.amount, amount);
+        }
+    }
 }
 /// Token Data implementation
 impl<
@@ -1537,6 +2066,338 @@ impl<MemberId: Ord, Payment, MaxOutputs: Get<u32>> From<BoundedVec<(MemberId, Pa
     }
 }
 
+/// Parameters of an airdrop created by the token issuer
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo)]
+pub struct AirdropParams<Hash, Balance, BlockNumber> {
+    /// Merkle root of the `(member_id, amount, vesting_schedule_params)` claims
+    pub commitment: Hash,
+
+    /// Total amount of tokens reserved from the issuer account for the claims
+    pub amount: Balance,
+
+    /// Number of blocks during which the airdrop can be claimed
+    pub duration: BlockNumber,
+}
+
+/// Issuer funded airdrop claimable by recipients with a merkle proof
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Airdrop<MemberId, Hash, Balance, BlockNumber> {
+    /// Member whose account funded the airdrop
+    pub issuer_member_id: MemberId,
+
+    /// Merkle root of the `(member_id, amount, vesting_schedule_params)` claims
+    pub commitment: Hash,
+
+    /// Amount of tokens not claimed yet
+    pub remaining_amount: Balance,
+
+    /// Block at which the airdrop expires
+    pub expires_at: BlockNumber,
+}
+
+impl<MemberId, Hash, Balance: Saturating + Copy, BlockNumber: PartialOrd + Copy>
+    Airdrop<MemberId, Hash, Balance, BlockNumber>
+{
+    pub(crate) fn is_expired(&self, now: BlockNumber) -> bool {
+        now >= self.expires_at
+    }
+
+    pub(crate) fn decrease_remaining_amount_by(&mut self, amount: Balance) {
+        self.remaining_amount = self.remaining_amount.saturating_sub(amount);
+    }
+}
+
+/// Party allowed to transfer tokens on behalf of a token holder
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, TypeInfo, MaxEncodedLen)]
+pub enum AllowanceSpender<MemberId, AccountId> {
+    /// Spender authenticated as the member controller account
+    Member(MemberId),
+
+    /// Spender authenticated as the account itself
+    Account(AccountId),
+}
+
+/// Amount of tokens a spender is allowed to transfer from holder's account
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo, MaxEncodedLen)]
+pub struct TokenAllowance<Balance, BlockNumber, RepayableBloatBond> {
+    /// Remaining amount of tokens that can be transferred
+    pub amount: Balance,
+
+    /// Block after which the allowance can no longer be used
+    pub expires_at: Option<BlockNumber>,
+
+    /// Bloat bond (in 'JOY's) deposited into treasury upon allowance creation
+    pub bloat_bond: RepayableBloatBond,
+}
+
+impl<Balance: Saturating + Zero + Copy, BlockNumber: PartialOrd, RepayableBloatBond>
+    TokenAllowance<Balance, BlockNumber, RepayableBloatBond>
+{
+    pub(crate) fn is_expired(&self, now: BlockNumber) -> bool {
+        self.expires_at
+            .as_ref()
+            .map_or(false, |expires_at| now > *expires_at)
+    }
+
+    pub(crate) fn decrease_amount_by(&mut self, amount: Balance) {
+        self.amount = self.amount.saturating_sub(amount);
+    }
+}
+
+/// How the votes cast in a token holder poll are weighted
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum PollVoteWeighting {
+    /// Vote weight is the total amount of tokens held by the voter
+    Balance,
+
+    /// Vote weight is the amount of tokens the voter has staked in a revenue split
+    StakedBalance,
+}
+
+impl Default for PollVoteWeighting {
+    fn default() -> Self {
+        PollVoteWeighting::Balance
+    }
+}
+
+/// Parameters of a token holder poll opened by the token issuer
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo)]
+pub struct PollParams<BlockNumber> {
+    /// Number of options voters can choose from
+    pub options_number: u32,
+
+    /// Number of blocks during which votes can be cast
+    pub duration: BlockNumber,
+
+    /// How the votes are weighted
+    pub weighting: PollVoteWeighting,
+
+    /// Poll metadata (question and options description)
+    pub metadata: Vec<u8>,
+}
+
+/// Token holder poll
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Poll<MemberId, BlockNumber, Tallies> {
+    /// Member who opened the poll
+    pub creator_member_id: MemberId,
+
+    /// Block at which the poll was opened
+    pub created_at: BlockNumber,
+
+    /// Block at which voting ends
+    pub ends_at: BlockNumber,
+
+    /// How the votes are weighted
+    pub weighting: PollVoteWeighting,
+
+    /// Total vote weight cast for each option
+    pub tallies: Tallies,
+}
+
+impl<MemberId, BlockNumber: PartialOrd, Balance: TokenBalanceTrait, MaxOptions: Get<u32>>
+    Poll<MemberId, BlockNumber, BoundedVec<Balance, MaxOptions>>
+{
+    pub(crate) fn is_ended(&self, now: BlockNumber) -> bool {
+        now >= self.ends_at
+    }
+
+    pub(crate) fn add_vote(&mut self, option_index: u32, weight: Balance) {
+        if let Some(tally) = self.tallies.get_mut(option_index as usize) {
+            *tally = tally.saturating_add(weight);
+        }
+    }
+
+    /// Option with the strictly highest non-zero tally, if any
+    pub fn winning_option(&self) -> Option<u32> {
+        let (mut winner, mut winner_tally, mut tied) = (None, Balance::zero(), false);
+        for (index, tally) in self.tallies.iter().enumerate() {
+            if *tally > winner_tally {
+                winner = Some(index as u32);
+                winner_tally = *tally;
+                tied = false;
+            } else if !tally.is_zero() && *tally == winner_tally {
+                tied = true;
+            }
+        }
+        winner.filter(|_| !tied)
+    }
+}
+
+/// Vote cast by a token holder in a poll
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PollVote<Balance> {
+    /// Index of the chosen option
+    pub option_index: u32,
+
+    /// Weight of the vote
+    pub weight: Balance,
+}
+
+/// Tokens locked in an account until the end of the polls the account voted in,
+/// so that the same tokens cannot be counted twice after a transfer
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PollVotingLock<Balance, BlockNumber> {
+    /// Amount of tokens locked
+    pub amount: Balance,
+
+    /// Block at which the lock is released
+    pub locked_until: BlockNumber,
+}
+
+impl<Balance: TokenBalanceTrait, BlockNumber: BlockNumberTrait>
+    PollVotingLock<Balance, BlockNumber>
+{
+    /// Amount of tokens locked at block `b`
+    pub fn locks(&self, b: BlockNumber) -> Balance {
+        if b < self.locked_until {
+            self.amount
+        } else {
+            Balance::zero()
+        }
+    }
+
+    /// Extend the lock to cover `amount` tokens until `locked_until`
+    pub(crate) fn extend(&mut self, amount: Balance, locked_until: BlockNumber, b: BlockNumber) {
+        self.amount = max(self.locks(b), amount);
+        self.locked_until = max(self.locked_until, locked_until);
+    }
+}
+
+/// Bridging status of a token
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo, MaxEncodedLen)]
+pub struct TokenBridging<Balance> {
+    /// Whether the issuer enabled bridging the token to remote chains
+    pub enabled: bool,
+
+    /// Amount of tokens escrowed by outbound bridge transfers
+    pub escrowed_amount: Balance,
+}
+
+/// Continuous revenue staking pool of a token: JOY revenue deposited into the pool
+/// accrues to all the stakers pro-rata to their stake through a reward-per-token accumulator
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo, MaxEncodedLen)]
+pub struct RevenueStakingPool<Balance> {
+    /// Total amount of tokens staked in the pool
+    pub total_staked: Balance,
+
+    /// JOY rewards accrued per single staked token since the pool was created
+    pub reward_per_token: FixedU128,
+}
+
+impl<Balance: TokenBalanceTrait> RevenueStakingPool<Balance> {
+    /// Distribute `revenue` among the currently staked tokens.
+    /// Preconditions:
+    /// - `self.total_staked` > 0
+    pub(crate) fn accrue<JoyBalance: JoyTokenBalanceTrait>(&mut self, revenue: JoyBalance) {
+        let increment = FixedU128::saturating_from_rational::<u128, u128>(
+            revenue.saturated_into(),
+            self.total_staked.saturated_into(),
+        );
+        self.reward_per_token = self.reward_per_token.saturating_add(increment);
+    }
+
+    pub(crate) fn increase_stake_by(&mut self, amount: Balance) {
+        self.total_staked = self.total_staked.saturating_add(amount);
+    }
+
+    pub(crate) fn decrease_stake_by(&mut self, amount: Balance) {
+        self.total_staked = self.total_staked.saturating_sub(amount);
+    }
+}
+
+/// Account's stake in the token's continuous revenue staking pool
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, Debug, TypeInfo, MaxEncodedLen)]
+pub struct RevenueStake<Balance, JoyBalance, BlockNumber> {
+    /// Amount of tokens staked in the pool
+    pub amount: Balance,
+
+    /// Pool's `reward_per_token` at the time the account's rewards were last settled
+    pub reward_per_token_paid: FixedU128,
+
+    /// Settled JOY rewards that were not claimed yet
+    pub pending_rewards: JoyBalance,
+
+    /// Amount of unstaked tokens that remain locked until the end of the unstaking cooldown
+    pub unstaking_amount: Balance,
+
+    /// Block at which the unstaking cooldown ends
+    pub unstaking_until: BlockNumber,
+}
+
+impl<
+        Balance: TokenBalanceTrait,
+        JoyBalance: JoyTokenBalanceTrait,
+        BlockNumber: BlockNumberTrait,
+    > RevenueStake<Balance, JoyBalance, BlockNumber>
+{
+    /// Amount of tokens locked by the stake at block `b`
+    pub fn locks(&self, b: BlockNumber) -> Balance {
+        self.amount.saturating_add(self.unstaking(b))
+    }
+
+    /// Amount of tokens still in the unstaking cooldown at block `b`
+    pub fn unstaking(&self, b: BlockNumber) -> Balance {
+        if b < self.unstaking_until {
+            self.unstaking_amount
+        } else {
+            Balance::zero()
+        }
+    }
+
+    /// Rewards the account is entitled to given the pool state
+    pub fn claimable_rewards(&self, pool: &RevenueStakingPool<Balance>) -> JoyBalance {
+        let accrued = pool
+            .reward_per_token
+            .saturating_sub(self.reward_per_token_paid)
+            .saturating_mul_int(self.amount.saturated_into::<u128>());
+        self.pending_rewards
+            .saturating_add(accrued.saturated_into())
+    }
+
+    /// Move the rewards accrued since the last settlement into `pending_rewards`
+    pub(crate) fn settle(&mut self, pool: &RevenueStakingPool<Balance>) {
+        self.pending_rewards = self.claimable_rewards(pool);
+        self.reward_per_token_paid = pool.reward_per_token;
+    }
+
+    pub(crate) fn stake(&mut self, amount: Balance) {
+        self.amount = self.amount.saturating_add(amount);
+    }
+
+    /// Unstake `amount` tokens, locking them (together with the tokens still in cooldown)
+    /// until `unstaking_until`
+    pub(crate) fn unstake(
+        &mut self,
+        amount: Balance,
+        b: BlockNumber,
+        unstaking_until: BlockNumber,
+    ) {
+        self.amount = self.amount.saturating_sub(amount);
+        self.unstaking_amount = self.unstaking(b).saturating_add(amount);
+        self.unstaking_until = unstaking_until;
+    }
+
+    /// Reduce the staked amount to at most `amount`, returning the removed stake
+    pub(crate) fn cap_amount_to(&mut self, amount: Balance) -> Balance {
+        let removed = self.amount.saturating_sub(amount);
+        self.amount = min(self.amount, amount);
+        removed
+    }
+}
+
 // ------ Aliases ---------------------------------------------
 
 /// Creator token balance
@@ -1628,6 +2489,25 @@ pub(crate) type TokenSaleOf<T> = TokenSale<
     <T as frame_system::Config>::AccountId,
 >;
 
+/// Alias for BatchAuction
+pub type BatchAuctionOf<T> = BatchAuction<JoyBalanceOf<T>, TokenBalanceOf<T>>;
+
+/// Alias for BatchAuctionBid
+pub type BatchAuctionBidOf<T> =
+    BatchAuctionBid<<T as frame_system::Config>::AccountId, JoyBalanceOf<T>, TokenBalanceOf<T>>;
+
+/// Alias for LimitOrder
+pub type LimitOrderOf<T> = LimitOrder<
+    <T as MembershipTypes>::MemberId,
+    <T as frame_system::Config>::AccountId,
+    TokenBalanceOf<T>,
+    JoyBalanceOf<T>,
+>;
+
+/// Alias for OrderBook
+pub type OrderBookOf<T> =
+    OrderBook<BoundedVec<(JoyBalanceOf<T>, OrderId), <T as Config>::MaxOrdersPerBookSide>>;
+
 /// Alias for OfferingState
 pub(crate) type OfferingStateOf<T> = OfferingState<TokenSaleOf<T>, AmmCurveOf<T>>;
 
@@ -1640,6 +2520,15 @@ pub(crate) type TokenSaleId = u32;
 /// RevenueSplitId
 pub(crate) type RevenueSplitId = u32;
 
+/// AirdropId
+pub type AirdropId = u64;
+
+/// PollId
+pub type PollId = u64;
+
+/// OrderId
+pub type OrderId = u64;
+
 /// Alias for PaymentWithVesting
 pub type PaymentWithVestingOf<T> =
     PaymentWithVesting<TokenBalanceOf<T>, VestingScheduleParamsOf<T>>;
@@ -1694,6 +2583,59 @@ pub type AmmCurveOf<T> = AmmCurve<TokenBalanceOf<T>>;
 /// Alias for the amm params
 pub type AmmParamsOf<T> = AmmParams<TokenBalanceOf<T>>;
 
+/// Alias for AirdropParams
+pub type AirdropParamsOf<T> = AirdropParams<
+    <T as frame_system::Config>::Hash,
+    TokenBalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+/// Alias for Airdrop
+pub type AirdropOf<T> = Airdrop<
+    <T as MembershipTypes>::MemberId,
+    <T as frame_system::Config>::Hash,
+    TokenBalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+/// Alias for PollParams
+pub type PollParamsOf<T> = PollParams<<T as frame_system::Config>::BlockNumber>;
+
+/// Alias for Poll
+pub type PollOf<T> = Poll<
+    <T as MembershipTypes>::MemberId,
+    <T as frame_system::Config>::BlockNumber,
+    BoundedVec<TokenBalanceOf<T>, <T as Config>::MaxPollOptions>,
+>;
+
+/// Alias for PollVote
+pub type PollVoteOf<T> = PollVote<TokenBalanceOf<T>>;
+
+/// Alias for PollVotingLock
+pub type PollVotingLockOf<T> =
+    PollVotingLock<TokenBalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Alias for RevenueStakingPool
+pub type RevenueStakingPoolOf<T> = RevenueStakingPool<TokenBalanceOf<T>>;
+
+/// Alias for RevenueStake
+pub type RevenueStakeOf<T> =
+    RevenueStake<TokenBalanceOf<T>, JoyBalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Alias for TokenBridging
+pub type TokenBridgingOf<T> = TokenBridging<TokenBalanceOf<T>>;
+
+/// Alias for AllowanceSpender
+pub type AllowanceSpenderOf<T> =
+    AllowanceSpender<<T as MembershipTypes>::MemberId, <T as frame_system::Config>::AccountId>;
+
+/// Alias for TokenAllowance
+pub type TokenAllowanceOf<T> = TokenAllowance<
+    TokenBalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+    RepayableBloatBondOf<T>,
+>;
+
 /// Alias for the governance parameters
 pub type TokenConstraintsOf<T> =
     TokenConstraints<TokenBalanceOf<T>, <T as frame_system::Config>::BlockNumber, JoyBalanceOf<T>>;
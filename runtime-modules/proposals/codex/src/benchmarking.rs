@@ -26,8 +26,8 @@ use sp_std::iter::FromIterator;
 use sp_std::prelude::*;
 use token::types::YearlyRate;
 use working_group::{
-    ApplicationById, ApplicationId, ApplyOnOpeningParameters, OpeningById, OpeningId, OpeningType,
-    StakeParameters, StakePolicy, WorkerById,
+    ApplicationById, ApplicationId, ApplyOnOpeningParameters, DeputyPermissions, OpeningById,
+    OpeningId, OpeningType, StakeParameters, StakePolicy, WorkerById,
 };
 
 const SEED: u32 = 0;
@@ -208,6 +208,124 @@ where
     (worker_id, lead_acc_id)
 }
 
+fn insert_regular_worker<T, I>() -> (
+    working_group::WorkerId<T>,
+    T::AccountId,
+    T::AccountId,
+    T::MemberId,
+)
+where
+    T: Config + membership::Config + working_group::Config<I> + balances::Config,
+    I: Instance,
+{
+    let (_, lead_acc_id) = insert_leader::<T, I>();
+
+    let opening_id = working_group::Module::<T, I>::next_opening_id();
+    working_group::Module::<T, I>::add_opening(
+        RawOrigin::Signed(lead_acc_id.clone()).into(),
+        vec![],
+        OpeningType::Regular,
+        StakePolicy {
+            stake_amount: <T as working_group::Config<I>>::MinimumApplicationStake::get(),
+            leaving_unstaking_period: <T as working_group::Config<I>>::MinUnstakingPeriodLimit::get(
+            ) + One::one(),
+        },
+        Some(One::one()),
+    )
+    .unwrap();
+
+    // The worker stake is locked on a separate account, so the controller account
+    // stays available for staking on the proposal.
+    let (account_id, member_id) = member_funded_account::<T>();
+    let staking_account_id =
+        account::<T::AccountId>("worker_staking", member_id.saturated_into(), SEED);
+    let _ = Balances::<T>::make_free_balance_be(&staking_account_id, BalanceOf::<T>::max_value());
+
+    Membership::<T>::add_staking_account_candidate(
+        RawOrigin::Signed(staking_account_id.clone()).into(),
+        member_id,
+    )
+    .unwrap();
+    Membership::<T>::confirm_staking_account(
+        RawOrigin::Signed(account_id.clone()).into(),
+        member_id,
+        staking_account_id.clone(),
+    )
+    .unwrap();
+
+    let application_id = working_group::Module::<T, I>::next_application_id();
+    working_group::Module::<T, I>::apply_on_opening(
+        RawOrigin::Signed(account_id.clone()).into(),
+        ApplyOnOpeningParameters::<T> {
+            member_id,
+            opening_id,
+            role_account_id: account_id.clone(),
+            reward_account_id: account_id.clone(),
+            description: vec![],
+            stake_parameters: StakeParameters {
+                stake: <T as working_group::Config<I>>::MinimumApplicationStake::get(),
+                staking_account_id,
+            },
+        },
+    )
+    .unwrap();
+
+    let worker_id = working_group::NextWorkerId::<T, I>::get();
+    working_group::Module::<T, I>::fill_opening(
+        RawOrigin::Signed(lead_acc_id.clone()).into(),
+        opening_id,
+        BTreeSet::<ApplicationId>::from_iter(vec![application_id]),
+    )
+    .unwrap();
+
+    (worker_id, lead_acc_id, account_id, member_id)
+}
+
+fn insert_reviewed_worker<T, I>() -> (working_group::WorkerId<T>, T::AccountId, T::MemberId)
+where
+    T: Config + membership::Config + working_group::Config<I> + balances::Config,
+    I: Instance,
+{
+    let (worker_id, lead_acc_id, account_id, member_id) = insert_regular_worker::<T, I>();
+
+    working_group::Module::<T, I>::review_worker(
+        RawOrigin::Signed(lead_acc_id).into(),
+        worker_id,
+        0,
+        T::Hash::default(),
+        false,
+    )
+    .unwrap();
+
+    assert!(working_group::WorkerReviews::<T, I>::contains_key(
+        worker_id, 0
+    ));
+
+    (worker_id, account_id, member_id)
+}
+
+fn insert_deputy<T, I>() -> (working_group::WorkerId<T>, T::AccountId, T::MemberId)
+where
+    T: Config + membership::Config + working_group::Config<I> + balances::Config,
+    I: Instance,
+{
+    let (worker_id, lead_acc_id, account_id, member_id) = insert_regular_worker::<T, I>();
+
+    working_group::Module::<T, I>::appoint_deputy(
+        RawOrigin::Signed(lead_acc_id).into(),
+        worker_id,
+        DeputyPermissions {
+            fill_openings: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    assert!(working_group::Deputies::<T, I>::contains_key(worker_id));
+
+    (worker_id, account_id, member_id)
+}
+
 fn add_and_apply_on_lead_opening<
     T: Config + membership::Config + working_group::Config<I>,
     I: Instance,
@@ -942,6 +1060,8 @@ benchmarks! {
                 amm_buy_tx_fees: Some(Permill::from_percent(1)),
                 amm_sell_tx_fees: Some(Permill::from_percent(1)),
                 bloat_bond: Some(1000u32.into()),
+                order_book_tx_fees: Some(Permill::from_percent(1)),
+                min_order_value: Some(1000u32.into()),
             }
         );
     }: create_proposal(
@@ -975,7 +1095,10 @@ benchmarks! {
                 pauser_accounts: Some(pauser_accounts),
                 bridging_fee: Some(100u32.into()),
                 thawn_duration: Some(1u32.into()),
-                remote_chains: Some(chains.try_into().unwrap())
+                remote_chains: Some(chains.try_into().unwrap()),
+                relayer_accounts: None,
+                relayer_threshold: None,
+                mint_limits: None
             }
         );
     }: create_proposal(
@@ -1035,6 +1158,112 @@ benchmarks! {
             proposal_details
         );
     }
+
+    create_proposal_overturn_content_takedown {
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::OverturnContentTakedown(0);
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_dispute_worker_review {
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (worker_id, account_id, member_id) =
+            insert_reviewed_worker::<T, ForumWorkingGroupInstance>();
+
+        let general_proposal_paramters = GeneralProposalParameters::<T> {
+            member_id,
+            title: vec![0u8; (t * 1000).try_into().unwrap()],
+            description: vec![0u8; (d * 1000).try_into().unwrap()],
+            staking_account_id: Some(account_id.clone()),
+            exact_execution_block: None,
+        };
+
+        let proposal_details =
+            ProposalDetails::DisputeWorkerReview(worker_id, 0, WorkingGroup::Forum);
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_revoke_working_group_deputy {
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (worker_id, account_id, member_id) =
+            insert_deputy::<T, ForumWorkingGroupInstance>();
+
+        let general_proposal_paramters = GeneralProposalParameters::<T> {
+            member_id,
+            title: vec![0u8; (t * 1000).try_into().unwrap()],
+            description: vec![0u8; (d * 1000).try_into().unwrap()],
+            staking_account_id: Some(account_id.clone()),
+            exact_execution_block: None,
+        };
+
+        let proposal_details =
+            ProposalDetails::RevokeWorkingGroupDeputy(worker_id, WorkingGroup::Forum);
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
+
+    create_proposal_resolve_bounty_judgment_dispute {
+        let t in 1 .. to_kb(T::TitleMaxLength::get());
+        let d in 1 .. to_kb(T::DescriptionMaxLength::get());
+
+        let (account_id, member_id, general_proposal_paramters) =
+            create_proposal_parameters::<T>(t, d);
+
+        let proposal_details = ProposalDetails::ResolveBountyJudgmentDispute(0, true, None);
+    }: create_proposal(
+        RawOrigin::Signed(account_id.clone()),
+        general_proposal_paramters.clone(),
+        proposal_details.clone()
+    )
+    verify {
+        create_proposal_verify::<T>(
+            account_id,
+            member_id,
+            general_proposal_paramters,
+            proposal_details
+        );
+    }
 }
 
 #[cfg(test)]
@@ -1240,4 +1469,36 @@ mod tests {
             assert_ok!(ProposalsCodex::test_benchmark_create_proposal_decrease_council_budget());
         });
     }
+
+    #[test]
+    fn test_create_proposal_overturn_content_takedown() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(ProposalsCodex::test_benchmark_create_proposal_overturn_content_takedown());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_dispute_worker_review() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(ProposalsCodex::test_benchmark_create_proposal_dispute_worker_review());
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_revoke_working_group_deputy() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(
+                ProposalsCodex::test_benchmark_create_proposal_revoke_working_group_deputy()
+            );
+        });
+    }
+
+    #[test]
+    fn test_create_proposal_resolve_bounty_judgment_dispute() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(
+                ProposalsCodex::test_benchmark_create_proposal_resolve_bounty_judgment_dispute()
+            );
+        });
+    }
 }
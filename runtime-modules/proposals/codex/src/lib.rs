@@ -90,7 +90,7 @@ pub use types::{
     CreateOpeningParameters, FillOpeningParameters, GeneralProposalParams, ProposalDetails,
     ProposalDetailsOf, ProposalEncoder, TerminateRoleParameters,
 };
-use working_group::{ApplicationId, OpeningId, OpeningType, WorkerId};
+use working_group::{ApplicationId, OpeningId, OpeningType, ReviewId, WorkerId};
 
 type WeightInfoCodex<T> = <T as Config>::WeightInfo;
 
@@ -299,6 +299,26 @@ pub trait Config:
     type DecreaseCouncilBudgetProposalParameters: Get<
         ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
     >;
+
+    /// `Overturn Content Takedown` proposal parameters
+    type OverturnContentTakedownProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Dispute Worker Review` proposal parameters
+    type DisputeWorkerReviewProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Revoke Working Group Deputy` proposal parameters
+    type RevokeWorkingGroupDeputyProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
+
+    /// `Resolve Bounty Judgment Dispute` proposal parameters
+    type ResolveBountyJudgmentDisputeProposalParameters: Get<
+        ProposalParameters<Self::BlockNumber, BalanceOf<Self>>,
+    >;
 }
 
 /// Specialized alias of GeneralProposalParams
@@ -408,6 +428,12 @@ decl_error! {
 
         /// Reduction Amount Zero
         ReductionAmountZero,
+
+        /// Provided worker review cannot be disputed by the proposer
+        InvalidDisputedWorkerReview,
+
+        /// Invalid working group deputy worker id provided
+        InvalidDeputyWorkerId,
     }
 }
 
@@ -546,6 +572,22 @@ decl_module! {
         const SetEraPayoutDampingFactorProposalParameters:
             ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::SetEraPayoutDampingFactorProposalParameters::get();
 
+        /// Overturn content takedown
+        const OverturnContentTakedownProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::OverturnContentTakedownProposalParameters::get();
+
+        /// Dispute worker review
+        const DisputeWorkerReviewProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::DisputeWorkerReviewProposalParameters::get();
+
+        /// Revoke working group deputy
+        const RevokeWorkingGroupDeputyProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::RevokeWorkingGroupDeputyProposalParameters::get();
+
+        /// Resolve bounty judgment dispute
+        const ResolveBountyJudgmentDisputeProposalParameters:
+            ProposalParameters<T::BlockNumber, BalanceOf<T>> = T::ResolveBountyJudgmentDisputeProposalParameters::get();
+
 
         /// Create a proposal, the type of proposal depends on the `proposal_details` variant
         ///
@@ -570,7 +612,10 @@ decl_module! {
             general_proposal_parameters: GeneralProposalParameters<T>,
             proposal_details: ProposalDetailsOf<T>,
         ) {
-            Self::ensure_details_checks(&proposal_details)?;
+            Self::ensure_details_checks(
+                &proposal_details,
+                general_proposal_parameters.member_id
+            )?;
 
             let account_id =
                 T::MembershipOriginValidator::ensure_member_controller_account_origin(
@@ -666,6 +711,111 @@ impl<T: Config> Module<T> {
         Ok(())
     }
 
+    fn is_disputable_worker_review<I: Instance>(
+        worker_id: &WorkerId<T>,
+        review_id: ReviewId,
+        member_id: MemberId<T>,
+    ) -> bool
+    where
+        T: working_group::Config<I>,
+    {
+        let is_reviewed_worker = working_group::Module::<T, I>::worker_by_id(worker_id)
+            .map(|worker| worker.member_id == member_id)
+            .unwrap_or(false);
+
+        let is_review_active = working_group::Module::<T, I>::worker_review(worker_id, review_id)
+            .map(|review| !review.overturned)
+            .unwrap_or(false);
+
+        is_reviewed_worker && is_review_active
+    }
+
+    // Ensure the review exists, is not overturned and the proposer is the reviewed worker
+    fn ensure_disputable_worker_review(
+        wg: &WorkingGroup,
+        worker_id: &WorkerId<T>,
+        review_id: ReviewId,
+        member_id: MemberId<T>,
+    ) -> DispatchResult {
+        let is_review_disputable =
+            match wg {
+                WorkingGroup::Forum => {
+                    Self::is_disputable_worker_review::<ForumWorkingGroupInstance>(
+                        worker_id, review_id, member_id,
+                    )
+                }
+                WorkingGroup::Storage => Self::is_disputable_worker_review::<
+                    StorageWorkingGroupInstance,
+                >(worker_id, review_id, member_id),
+                WorkingGroup::Content => Self::is_disputable_worker_review::<
+                    ContentWorkingGroupInstance,
+                >(worker_id, review_id, member_id),
+                WorkingGroup::OperationsAlpha => Self::is_disputable_worker_review::<
+                    OperationsWorkingGroupInstanceAlpha,
+                >(worker_id, review_id, member_id),
+                WorkingGroup::App => Self::is_disputable_worker_review::<AppWorkingGroupInstance>(
+                    worker_id, review_id, member_id,
+                ),
+                WorkingGroup::Membership => Self::is_disputable_worker_review::<
+                    MembershipWorkingGroupInstance,
+                >(worker_id, review_id, member_id),
+                WorkingGroup::OperationsBeta => Self::is_disputable_worker_review::<
+                    OperationsWorkingGroupInstanceBeta,
+                >(worker_id, review_id, member_id),
+                WorkingGroup::OperationsGamma => Self::is_disputable_worker_review::<
+                    OperationsWorkingGroupInstanceGamma,
+                >(worker_id, review_id, member_id),
+                WorkingGroup::Distribution => Self::is_disputable_worker_review::<
+                    DistributionWorkingGroupInstance,
+                >(worker_id, review_id, member_id),
+            };
+        ensure!(
+            is_review_disputable,
+            Error::<T>::InvalidDisputedWorkerReview
+        );
+        Ok(())
+    }
+
+    fn is_deputy_worker_id<I: Instance>(worker_id: &WorkerId<T>) -> bool
+    where
+        T: working_group::Config<I>,
+    {
+        working_group::Deputies::<T, I>::contains_key(worker_id)
+    }
+
+    // Ensure worker is a deputy in given working group
+    fn ensure_valid_deputy_worker_id(wg: &WorkingGroup, worker_id: &WorkerId<T>) -> DispatchResult {
+        let is_deputy_id_valid = match wg {
+            WorkingGroup::Forum => {
+                Self::is_deputy_worker_id::<ForumWorkingGroupInstance>(worker_id)
+            }
+            WorkingGroup::Storage => {
+                Self::is_deputy_worker_id::<StorageWorkingGroupInstance>(worker_id)
+            }
+            WorkingGroup::Content => {
+                Self::is_deputy_worker_id::<ContentWorkingGroupInstance>(worker_id)
+            }
+            WorkingGroup::OperationsAlpha => {
+                Self::is_deputy_worker_id::<OperationsWorkingGroupInstanceAlpha>(worker_id)
+            }
+            WorkingGroup::App => Self::is_deputy_worker_id::<AppWorkingGroupInstance>(worker_id),
+            WorkingGroup::Membership => {
+                Self::is_deputy_worker_id::<MembershipWorkingGroupInstance>(worker_id)
+            }
+            WorkingGroup::OperationsBeta => {
+                Self::is_deputy_worker_id::<OperationsWorkingGroupInstanceBeta>(worker_id)
+            }
+            WorkingGroup::OperationsGamma => {
+                Self::is_deputy_worker_id::<OperationsWorkingGroupInstanceGamma>(worker_id)
+            }
+            WorkingGroup::Distribution => {
+                Self::is_deputy_worker_id::<DistributionWorkingGroupInstance>(worker_id)
+            }
+        };
+        ensure!(is_deputy_id_valid, Error::<T>::InvalidDeputyWorkerId);
+        Ok(())
+    }
+
     fn is_lead_opening_id<I: Instance>(opening_id: &OpeningId) -> bool
     where
         T: working_group::Config<I>,
@@ -774,7 +924,10 @@ impl<T: Config> Module<T> {
     }
 
     // Ensure that the proposal details respects all the checks
-    fn ensure_details_checks(details: &ProposalDetailsOf<T>) -> DispatchResult {
+    fn ensure_details_checks(
+        details: &ProposalDetailsOf<T>,
+        member_id: MemberId<T>,
+    ) -> DispatchResult {
         match details {
             ProposalDetails::Signal(ref signal) => {
                 ensure!(!signal.is_empty(), Error::<T>::SignalProposalIsEmpty);
@@ -932,6 +1085,23 @@ impl<T: Config> Module<T> {
             ProposalDetails::SetEraPayoutDampingFactor(..) => {
                 // Note: No checks for this proposal for now
             }
+            ProposalDetails::OverturnContentTakedown(..) => {
+                // Note: No checks for this proposal for now
+            }
+            ProposalDetails::DisputeWorkerReview(ref worker_id, review_id, ref working_group) => {
+                Self::ensure_disputable_worker_review(
+                    working_group,
+                    worker_id,
+                    *review_id,
+                    member_id,
+                )?;
+            }
+            ProposalDetails::RevokeWorkingGroupDeputy(ref worker_id, ref working_group) => {
+                Self::ensure_valid_deputy_worker_id(working_group, worker_id)?;
+            }
+            ProposalDetails::ResolveBountyJudgmentDispute(..) => {
+                // Note: No checks for this proposal for now
+            }
         }
 
         Ok(())
@@ -1014,6 +1184,18 @@ impl<T: Config> Module<T> {
             ProposalDetails::SetEraPayoutDampingFactor(..) => {
                 T::SetEraPayoutDampingFactorProposalParameters::get()
             }
+            ProposalDetails::OverturnContentTakedown(..) => {
+                T::OverturnContentTakedownProposalParameters::get()
+            }
+            ProposalDetails::DisputeWorkerReview(..) => {
+                T::DisputeWorkerReviewProposalParameters::get()
+            }
+            ProposalDetails::RevokeWorkingGroupDeputy(..) => {
+                T::RevokeWorkingGroupDeputyProposalParameters::get()
+            }
+            ProposalDetails::ResolveBountyJudgmentDispute(..) => {
+                T::ResolveBountyJudgmentDisputeProposalParameters::get()
+            }
         }
     }
 
@@ -1205,6 +1387,30 @@ impl<T: Config> Module<T> {
                     to_kb(description_length.saturated_into()),
                 )
             }
+            ProposalDetails::OverturnContentTakedown(..) => {
+                WeightInfoCodex::<T>::create_proposal_overturn_content_takedown(
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+            }
+            ProposalDetails::DisputeWorkerReview(..) => {
+                WeightInfoCodex::<T>::create_proposal_dispute_worker_review(
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+            }
+            ProposalDetails::RevokeWorkingGroupDeputy(..) => {
+                WeightInfoCodex::<T>::create_proposal_revoke_working_group_deputy(
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+            }
+            ProposalDetails::ResolveBountyJudgmentDispute(..) => {
+                WeightInfoCodex::<T>::create_proposal_resolve_bounty_judgment_dispute(
+                    to_kb(title_length.saturated_into()),
+                    to_kb(description_length.saturated_into()),
+                )
+            }
         }
     }
 }
@@ -332,6 +332,14 @@ parameter_types! {
     pub const MaxVestingSchedulesPerAccountPerToken: u32 = 3;
     pub const BlocksPerYear: u32 = 5259487; // blocks every 6s
     pub const MaxOutputs: u32 = 256;
+    pub const MaxPollOptions: u32 = 4;
+    pub const MaxBatchAuctionBids: u32 = 4;
+    pub const RevenueStakingUnstakingPeriod: u64 = 10;
+    pub const MaxOrdersPerBookSide: u32 = 10;
+    pub const MaxOrderMatches: u32 = 3;
+    pub const MaxAllowancesPerAccount: u32 = 3;
+    pub const MaxAirdropClaimsRemovedPerCall: u32 = 2;
+    pub const MaxPollVotesRemovedPerCall: u32 = 2;
     // constants for storage::Config
     pub const MaxNumberOfDataObjectsPerBag: u64 = 4;
     pub const MaxDistributionBucketFamilyNumber: u64 = 4;
@@ -346,12 +354,20 @@ parameter_types! {
     pub const MaxDataObjectSize: u64 = 1_000_000_000;
     pub const MinStorageBucketsPerBag: u32 = 3;
     pub const MaxStorageBucketsPerBag: u32 = 10;
+    pub const MaxStorageBucketsScannedOnAssignment: u32 = 100;
     pub const MinDistributionBucketsPerBag: u32 = 3;
     pub const MaxDistributionBucketsPerBag: u32 = 10;
     pub const MaxNumberOfOperatorsPerDistributionBucket: u32 = 5;
+    pub const BandwidthReportDisputePeriod: u64 = 10;
+    pub const BandwidthReportPeriod: u64 = 5;
+    pub const MaxBytesServedPerBandwidthReport: u64 = 100_000_000_000;
+    pub const BandwidthReportBond: u64 = 100;
     /// constants for argo_bridge::config
     pub const MaxPauserAccounts: u32 = 10;
     pub const DefaultBridgingFee: Balance = 1;
+    pub const OutboundTransferRetentionPeriod: BlockNumber = 10;
+    pub const MaxPrunedTransfersPerBlock: u32 = 5;
+    pub const InboundTransfersWindow: u64 = 2;
 }
 
 impl storage::Config for Test {
@@ -366,6 +382,7 @@ impl storage::Config for Test {
     type ModuleId = StorageModuleId;
     type MinStorageBucketsPerBag = MinStorageBucketsPerBag;
     type MaxStorageBucketsPerBag = MaxStorageBucketsPerBag;
+    type MaxStorageBucketsScannedOnAssignment = MaxStorageBucketsScannedOnAssignment;
     type MinDistributionBucketsPerBag = MinDistributionBucketsPerBag;
     type MaxDistributionBucketsPerBag = MaxDistributionBucketsPerBag;
     type DefaultMemberDynamicBagNumberOfStorageBuckets =
@@ -381,6 +398,10 @@ impl storage::Config for Test {
     type StorageWorkingGroup = Wg;
     type DistributionWorkingGroup = Wg;
     type ModuleAccountInitialBalance = ExistentialDeposit;
+    type BandwidthReportDisputePeriod = BandwidthReportDisputePeriod;
+    type BandwidthReportPeriod = BandwidthReportPeriod;
+    type MaxBytesServedPerBandwidthReport = MaxBytesServedPerBandwidthReport;
+    type BandwidthReportBond = BandwidthReportBond;
     type WeightInfo = ();
 }
 
@@ -397,6 +418,14 @@ impl token::Config for Test {
     type MemberOriginValidator = membership::Module<Self>;
     type MembershipInfoProvider = membership::Module<Self>;
     type MaxOutputs = MaxOutputs;
+    type MaxPollOptions = MaxPollOptions;
+    type MaxBatchAuctionBids = MaxBatchAuctionBids;
+    type RevenueStakingUnstakingPeriod = RevenueStakingUnstakingPeriod;
+    type MaxOrdersPerBookSide = MaxOrdersPerBookSide;
+    type MaxOrderMatches = MaxOrderMatches;
+    type MaxAllowancesPerAccount = MaxAllowancesPerAccount;
+    type MaxAirdropClaimsRemovedPerCall = MaxAirdropClaimsRemovedPerCall;
+    type MaxPollVotesRemovedPerCall = MaxPollVotesRemovedPerCall;
 }
 
 impl argo_bridge::Config for Test {
@@ -404,6 +433,10 @@ impl argo_bridge::Config for Test {
     type MaxPauserAccounts = MaxPauserAccounts;
     type WeightInfo = argo_bridge::weights::SubstrateWeight<Test>;
     type DefaultBridgingFee = DefaultBridgingFee;
+    type CreatorTokens = token::Module<Self>;
+    type OutboundTransferRetentionPeriod = OutboundTransferRetentionPeriod;
+    type MaxPrunedTransfersPerBlock = MaxPrunedTransfersPerBlock;
+    type InboundTransfersWindow = InboundTransfersWindow;
 }
 
 pub struct Wg;
@@ -448,6 +481,12 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for Wg {
         unimplemented!()
     }
 
+    fn get_worker_reward_account_id(
+        _: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Test as frame_system::Config>::AccountId> {
+        unimplemented!()
+    }
+
     fn is_leader_account_id(_account_id: &<Test as frame_system::Config>::AccountId) -> bool {
         unimplemented!()
     }
@@ -628,7 +667,6 @@ impl working_group::Config<ForumWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -642,7 +680,6 @@ impl working_group::Config<StorageWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -656,7 +693,6 @@ impl working_group::Config<ContentWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -670,7 +706,6 @@ impl working_group::Config<OperationsWorkingGroupInstanceAlpha> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -684,7 +719,6 @@ impl working_group::Config<AppWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -698,7 +732,6 @@ impl working_group::Config<MembershipWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -712,7 +745,6 @@ impl working_group::Config<OperationsWorkingGroupInstanceBeta> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -726,7 +758,6 @@ impl working_group::Config<OperationsWorkingGroupInstanceGamma> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -740,7 +771,6 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -800,6 +830,10 @@ impl crate::Config for Test {
     type UpdateArgoBridgeConstraints = DefaultProposalParameters;
     type SetEraPayoutDampingFactorProposalParameters = DefaultProposalParameters;
     type DecreaseCouncilBudgetProposalParameters = DefaultProposalParameters;
+    type OverturnContentTakedownProposalParameters = DefaultProposalParameters;
+    type DisputeWorkerReviewProposalParameters = DefaultProposalParameters;
+    type RevokeWorkingGroupDeputyProposalParameters = DefaultProposalParameters;
+    type ResolveBountyJudgmentDisputeProposalParameters = DefaultProposalParameters;
 }
 
 parameter_types! {
@@ -22,8 +22,8 @@ use crate::{Error, ProposalDetails};
 pub use mock::*;
 use sp_runtime::traits::One;
 use working_group::{
-    ApplicationById, ApplicationId, ApplyOnOpeningParameters, OpeningById, OpeningId, OpeningType,
-    StakeParameters, WorkerId,
+    ApplicationById, ApplicationId, ApplyOnOpeningParameters, DeputyPermissions, OpeningById,
+    OpeningId, OpeningType, StakeParameters, WorkerId,
 };
 
 use strum::IntoEnumIterator;
@@ -175,6 +175,130 @@ fn setup_lead(working_group: WorkingGroup) -> WorkerId<Test> {
     }
 }
 
+fn insert_regular_worker<I: Instance>() -> WorkerId<Test>
+where
+    Test: working_group::Config<I>,
+{
+    insert_leader::<I>();
+
+    let opening_id = working_group::Module::<Test, I>::next_opening_id();
+    working_group::Module::<Test, I>::add_opening(
+        RawOrigin::Signed(LEADER_ACCOUNT_ID).into(),
+        vec![],
+        OpeningType::Regular,
+        StakePolicy {
+            stake_amount: <Test as working_group::Config<I>>::MinimumApplicationStake::get(),
+            leaving_unstaking_period:
+                <Test as working_group::Config<I>>::MinUnstakingPeriodLimit::get() + 1,
+        },
+        Some(One::one()),
+    )
+    .unwrap();
+
+    // The worker stake is locked on a separate account, so account 1 stays available
+    // for staking on the proposal.
+    let stake = <Test as working_group::Config<I>>::MinimumApplicationStake::get();
+    increase_total_balance_issuance_using_account_id(2, stake);
+
+    let application_id = working_group::Module::<Test, I>::next_application_id();
+    working_group::Module::<Test, I>::apply_on_opening(
+        RawOrigin::Signed(1).into(),
+        ApplyOnOpeningParameters::<Test> {
+            member_id: 1,
+            opening_id,
+            role_account_id: 1,
+            reward_account_id: 1,
+            description: vec![],
+            stake_parameters: StakeParameters {
+                stake,
+                staking_account_id: 2,
+            },
+        },
+    )
+    .unwrap();
+
+    let worker_id = working_group::NextWorkerId::<Test, I>::get();
+    working_group::Module::<Test, I>::fill_opening(
+        RawOrigin::Signed(LEADER_ACCOUNT_ID).into(),
+        opening_id,
+        BTreeSet::<ApplicationId>::from_iter(vec![application_id]),
+    )
+    .unwrap();
+
+    worker_id
+}
+
+fn insert_reviewed_worker<I: Instance>() -> WorkerId<Test>
+where
+    Test: working_group::Config<I>,
+{
+    let worker_id = insert_regular_worker::<I>();
+
+    working_group::Module::<Test, I>::review_worker(
+        RawOrigin::Signed(LEADER_ACCOUNT_ID).into(),
+        worker_id,
+        10,
+        <Test as frame_system::Config>::Hashing::hash(b"rationale"),
+        false,
+    )
+    .unwrap();
+
+    worker_id
+}
+
+fn setup_reviewed_worker(working_group: WorkingGroup) -> WorkerId<Test> {
+    match working_group {
+        WorkingGroup::Forum => insert_reviewed_worker::<ForumWorkingGroupInstance>(),
+        WorkingGroup::Storage => insert_reviewed_worker::<StorageWorkingGroupInstance>(),
+        WorkingGroup::Content => insert_reviewed_worker::<ContentWorkingGroupInstance>(),
+        WorkingGroup::OperationsAlpha => {
+            insert_reviewed_worker::<OperationsWorkingGroupInstanceAlpha>()
+        }
+        WorkingGroup::App => insert_reviewed_worker::<AppWorkingGroupInstance>(),
+        WorkingGroup::Membership => insert_reviewed_worker::<MembershipWorkingGroupInstance>(),
+        WorkingGroup::OperationsBeta => {
+            insert_reviewed_worker::<OperationsWorkingGroupInstanceBeta>()
+        }
+        WorkingGroup::OperationsGamma => {
+            insert_reviewed_worker::<OperationsWorkingGroupInstanceGamma>()
+        }
+        WorkingGroup::Distribution => insert_reviewed_worker::<DistributionWorkingGroupInstance>(),
+    }
+}
+
+fn insert_deputy<I: Instance>() -> WorkerId<Test>
+where
+    Test: working_group::Config<I>,
+{
+    let worker_id = insert_regular_worker::<I>();
+
+    working_group::Module::<Test, I>::appoint_deputy(
+        RawOrigin::Signed(LEADER_ACCOUNT_ID).into(),
+        worker_id,
+        DeputyPermissions {
+            fill_openings: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    worker_id
+}
+
+fn setup_deputy(working_group: WorkingGroup) -> WorkerId<Test> {
+    match working_group {
+        WorkingGroup::Forum => insert_deputy::<ForumWorkingGroupInstance>(),
+        WorkingGroup::Storage => insert_deputy::<StorageWorkingGroupInstance>(),
+        WorkingGroup::Content => insert_deputy::<ContentWorkingGroupInstance>(),
+        WorkingGroup::OperationsAlpha => insert_deputy::<OperationsWorkingGroupInstanceAlpha>(),
+        WorkingGroup::App => insert_deputy::<AppWorkingGroupInstance>(),
+        WorkingGroup::Membership => insert_deputy::<MembershipWorkingGroupInstance>(),
+        WorkingGroup::OperationsBeta => insert_deputy::<OperationsWorkingGroupInstanceBeta>(),
+        WorkingGroup::OperationsGamma => insert_deputy::<OperationsWorkingGroupInstanceGamma>(),
+        WorkingGroup::Distribution => insert_deputy::<DistributionWorkingGroupInstance>(),
+    }
+}
+
 fn assert_last_event(generic_event: <Test as Config>::RuntimeEvent) {
     let events = System::events();
     let system_event: <Test as frame_system::Config>::RuntimeEvent = generic_event;
@@ -1689,6 +1813,299 @@ fn run_create_set_working_group_leader_reward_proposal_with_invalid_worker_id_fa
     });
 }
 
+#[test]
+fn create_dispute_worker_review_proposal_common_checks_succeed() {
+    // This uses strum crate for enum iteration
+    for group in WorkingGroup::iter() {
+        run_create_dispute_worker_review_proposal_common_checks_succeed(group);
+    }
+}
+
+fn run_create_dispute_worker_review_proposal_common_checks_succeed(working_group: WorkingGroup) {
+    initial_test_ext().execute_with(|| {
+        let worker_id = setup_reviewed_worker(working_group);
+
+        let general_proposal_parameters_no_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: None,
+            exact_execution_block: None,
+        };
+
+        let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
+            exact_execution_block: None,
+        };
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        let proposal_details = ProposalDetails::DisputeWorkerReview(worker_id, 0, working_group);
+        let proposal_fixture = ProposalTestFixture {
+            general_proposal_parameters: general_proposal_parameters.clone(),
+            proposal_details: proposal_details.clone(),
+            insufficient_rights_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::None.into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            invalid_stake_account_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_incorrect_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            successful_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            proposal_parameters:
+                <Test as crate::Config>::DisputeWorkerReviewProposalParameters::get(),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_dispute_worker_review_proposal_with_invalid_review_fails() {
+    // This uses strum crate for enum iteration
+    for group in WorkingGroup::iter() {
+        run_create_dispute_worker_review_proposal_with_invalid_review_fails(group);
+    }
+}
+
+fn run_create_dispute_worker_review_proposal_with_invalid_review_fails(
+    working_group: WorkingGroup,
+) {
+    initial_test_ext().execute_with(|| {
+        let worker_id = setup_reviewed_worker(working_group);
+
+        increase_total_balance_issuance_using_account_id(1, 500000);
+        increase_total_balance_issuance_using_account_id(3, 500000);
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        // Non-existing review
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                general_proposal_parameters.clone(),
+                ProposalDetails::DisputeWorkerReview(worker_id, 1, working_group)
+            ),
+            Err(Error::<Test>::InvalidDisputedWorkerReview.into())
+        );
+
+        // Non-existing worker
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                general_proposal_parameters.clone(),
+                ProposalDetails::DisputeWorkerReview(worker_id + 1, 0, working_group)
+            ),
+            Err(Error::<Test>::InvalidDisputedWorkerReview.into())
+        );
+
+        // The proposer is not the reviewed worker
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(3).into(),
+                GeneralProposalParameters::<Test> {
+                    member_id: 3,
+                    staking_account_id: Some(3),
+                    ..general_proposal_parameters.clone()
+                },
+                ProposalDetails::DisputeWorkerReview(worker_id, 0, working_group)
+            ),
+            Err(Error::<Test>::InvalidDisputedWorkerReview.into())
+        );
+    });
+}
+
+#[test]
+fn create_dispute_worker_review_proposal_fails_for_overturned_review() {
+    initial_test_ext().execute_with(|| {
+        let worker_id = insert_reviewed_worker::<ForumWorkingGroupInstance>();
+
+        working_group::Module::<Test, ForumWorkingGroupInstance>::overturn_worker_review(
+            RawOrigin::Root.into(),
+            worker_id,
+            0,
+        )
+        .unwrap();
+
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                general_proposal_parameters,
+                ProposalDetails::DisputeWorkerReview(worker_id, 0, WorkingGroup::Forum)
+            ),
+            Err(Error::<Test>::InvalidDisputedWorkerReview.into())
+        );
+    });
+}
+
+#[test]
+fn create_revoke_working_group_deputy_proposal_common_checks_succeed() {
+    // This uses strum crate for enum iteration
+    for group in WorkingGroup::iter() {
+        run_create_revoke_working_group_deputy_proposal_common_checks_succeed(group);
+    }
+}
+
+fn run_create_revoke_working_group_deputy_proposal_common_checks_succeed(
+    working_group: WorkingGroup,
+) {
+    initial_test_ext().execute_with(|| {
+        let worker_id = setup_deputy(working_group);
+
+        let general_proposal_parameters_no_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: None,
+            exact_execution_block: None,
+        };
+
+        let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
+            exact_execution_block: None,
+        };
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        let proposal_details = ProposalDetails::RevokeWorkingGroupDeputy(worker_id, working_group);
+        let proposal_fixture = ProposalTestFixture {
+            general_proposal_parameters: general_proposal_parameters.clone(),
+            proposal_details: proposal_details.clone(),
+            insufficient_rights_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::None.into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            invalid_stake_account_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_incorrect_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            successful_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            proposal_parameters:
+                <Test as crate::Config>::RevokeWorkingGroupDeputyProposalParameters::get(),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_revoke_working_group_deputy_proposal_with_invalid_worker_id_fails() {
+    // This uses strum crate for enum iteration
+    for group in WorkingGroup::iter() {
+        run_create_revoke_working_group_deputy_proposal_with_invalid_worker_id_fails(group);
+    }
+}
+
+fn run_create_revoke_working_group_deputy_proposal_with_invalid_worker_id_fails(
+    working_group: WorkingGroup,
+) {
+    initial_test_ext().execute_with(|| {
+        let worker_id = setup_deputy(working_group);
+        let lead_id = worker_id - 1;
+
+        increase_total_balance_issuance_using_account_id(1, 500000);
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        // The leader is not a deputy
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                general_proposal_parameters.clone(),
+                ProposalDetails::RevokeWorkingGroupDeputy(lead_id, working_group)
+            ),
+            Err(Error::<Test>::InvalidDeputyWorkerId.into())
+        );
+
+        // Non-existing worker
+        assert_eq!(
+            ProposalsCodex::create_proposal(
+                RawOrigin::Signed(1).into(),
+                general_proposal_parameters,
+                ProposalDetails::RevokeWorkingGroupDeputy(worker_id + 1, working_group)
+            ),
+            Err(Error::<Test>::InvalidDeputyWorkerId.into())
+        );
+    });
+}
+
 #[test]
 fn create_terminate_working_group_leader_role_proposal_common_checks_succeed() {
     // This uses strum crate for enum iteration
@@ -2491,6 +2908,140 @@ fn create_update_global_nft_limit_proposal_common_checks_succeed() {
     });
 }
 
+#[test]
+fn create_overturn_content_takedown_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let general_proposal_parameters_no_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: None,
+            exact_execution_block: None,
+        };
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
+            exact_execution_block: None,
+        };
+
+        let proposal_details = ProposalDetails::OverturnContentTakedown(0);
+
+        let proposal_fixture = ProposalTestFixture {
+            general_proposal_parameters: general_proposal_parameters.clone(),
+            proposal_details: proposal_details.clone(),
+            insufficient_rights_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::None.into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            invalid_stake_account_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_incorrect_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            successful_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            proposal_parameters:
+                <Test as crate::Config>::OverturnContentTakedownProposalParameters::get(),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_resolve_bounty_judgment_dispute_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let general_proposal_parameters_no_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: None,
+            exact_execution_block: None,
+        };
+
+        let general_proposal_parameters = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(1),
+            exact_execution_block: None,
+        };
+
+        let general_proposal_parameters_incorrect_staking = GeneralProposalParameters::<Test> {
+            member_id: 1,
+            title: b"title".to_vec(),
+            description: b"body".to_vec(),
+            staking_account_id: Some(STAKING_ACCOUNT_ID_NOT_BOUND_TO_MEMBER),
+            exact_execution_block: None,
+        };
+
+        let proposal_details = ProposalDetails::ResolveBountyJudgmentDispute(1, true, None);
+
+        let proposal_fixture = ProposalTestFixture {
+            general_proposal_parameters: general_proposal_parameters.clone(),
+            proposal_details: proposal_details.clone(),
+            insufficient_rights_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::None.into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            invalid_stake_account_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_incorrect_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            empty_stake_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters_no_staking.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            successful_call: || {
+                ProposalsCodex::create_proposal(
+                    RawOrigin::Signed(1).into(),
+                    general_proposal_parameters.clone(),
+                    proposal_details.clone(),
+                )
+            },
+            proposal_parameters:
+                <Test as crate::Config>::ResolveBountyJudgmentDisputeProposalParameters::get(),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
 #[test]
 fn create_update_channel_payouts_common_checks_succeed() {
     initial_test_ext().execute_with(|| {
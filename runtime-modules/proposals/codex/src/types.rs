@@ -12,8 +12,9 @@ use common::BalanceKind;
 use common::FundingRequestParameters;
 use sp_runtime::Percent;
 
-use content::NftLimitPeriod;
-use working_group::StakePolicy;
+use bounty::OracleJudgment;
+use content::{NftLimitPeriod, TakedownRequestId};
+use working_group::{ReviewId, StakePolicy};
 
 /// Encodes proposal using its details information.
 pub trait ProposalEncoder<T: crate::Config> {
@@ -139,6 +140,19 @@ pub enum ProposalDetails<
 
     /// `DecreaseCouncilBudget` proposal
     DecreaseCouncilBudget(Balance),
+
+    /// `OverturnContentTakedown` proposal
+    OverturnContentTakedown(TakedownRequestId),
+
+    /// `DisputeWorkerReview` proposal
+    DisputeWorkerReview(WorkerId, ReviewId, WorkingGroup),
+
+    /// `RevokeWorkingGroupDeputy` proposal
+    RevokeWorkingGroupDeputy(WorkerId, WorkingGroup),
+
+    /// `ResolveBountyJudgmentDispute` proposal: bounty ID, whether to overturn the judgment
+    /// and the corrected judgment to apply instead of the overturned one
+    ResolveBountyJudgmentDispute(u64, bool, Option<OracleJudgment<u64, Balance>>),
 }
 
 impl<
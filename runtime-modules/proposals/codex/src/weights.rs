@@ -72,6 +72,10 @@ pub trait WeightInfo {
 	fn create_proposal_update_argo_bridge_constraints(_t: u32, _d: u32, ) -> Weight;
 	fn create_proposal_set_era_payout_damping_factor(_t: u32, _d: u32, ) -> Weight;
 	fn create_proposal_decrease_council_budget(_t: u32, _d: u32, ) -> Weight;
+	fn create_proposal_overturn_content_takedown(_t: u32, _d: u32, ) -> Weight;
+	fn create_proposal_dispute_worker_review(_t: u32, _d: u32, ) -> Weight;
+	fn create_proposal_revoke_working_group_deputy(_t: u32, _d: u32, ) -> Weight;
+	fn create_proposal_resolve_bounty_judgment_dispute(_t: u32, _d: u32, ) -> Weight;
 }
 
 /// Weights for proposals_codex using the Substrate node and recommended hardware.
@@ -1153,6 +1157,164 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ActiveProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Proof: ProposalDiscussion ThreadCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Proof: ProposalsCodex ThreadIdByProposalId (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Proof: ProposalDiscussion ThreadById (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
+	/// The range of component `t` is `[1, 20]`.
+	/// The range of component `d` is `[1, 20]`.
+	fn create_proposal_overturn_content_takedown(t: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `651`
+		//  Estimated: `19940`
+		// Minimum execution time: 101_906 nanoseconds.
+		Weight::from_parts(80_682_155, 0u64)
+			.saturating_add(Weight::from_parts(0, 19940))
+			// Standard Error: 19_338
+			.saturating_add(Weight::from_parts(1_102_318, 0u64).saturating_mul(t.into()))
+			// Standard Error: 22_660
+			.saturating_add(Weight::from_parts(1_291_640, 0u64).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// Storage: Instance1WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance1WorkingGroup WorkerById (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
+	// Storage: Instance1WorkingGroup WorkerReviews (r:1 w:0)
+	// Proof: Instance1WorkingGroup WorkerReviews (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ActiveProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Proof: ProposalDiscussion ThreadCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Proof: ProposalsCodex ThreadIdByProposalId (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Proof: ProposalDiscussion ThreadById (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
+	/// The range of component `t` is `[1, 20]`.
+	/// The range of component `d` is `[1, 20]`.
+	fn create_proposal_dispute_worker_review(t: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1108`
+		//  Estimated: `25190`
+		// Minimum execution time: 112_437 nanoseconds.
+		Weight::from_parts(91_204_338, 0u64)
+			.saturating_add(Weight::from_parts(0, 25190))
+			// Standard Error: 19_338
+			.saturating_add(Weight::from_parts(1_102_318, 0u64).saturating_mul(t.into()))
+			// Standard Error: 22_660
+			.saturating_add(Weight::from_parts(1_291_640, 0u64).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// Storage: Instance1WorkingGroup Deputies (r:1 w:0)
+	// Proof: Instance1WorkingGroup Deputies (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ActiveProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Proof: ProposalDiscussion ThreadCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Proof: ProposalsCodex ThreadIdByProposalId (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Proof: ProposalDiscussion ThreadById (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
+	/// The range of component `t` is `[1, 20]`.
+	/// The range of component `d` is `[1, 20]`.
+	fn create_proposal_revoke_working_group_deputy(t: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1047`
+		//  Estimated: `19938`
+		// Minimum execution time: 107_856 nanoseconds.
+		Weight::from_parts(88_921_507, 0u64)
+			.saturating_add(Weight::from_parts(0, 19938))
+			// Standard Error: 19_079
+			.saturating_add(Weight::from_parts(1_087_554, 0u64).saturating_mul(t.into()))
+			// Standard Error: 22_400
+			.saturating_add(Weight::from_parts(1_276_802, 0u64).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// Storage: Membership MembershipById (r:1 w:0)
+	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ActiveProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ActiveProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Membership StakingAccountIdMemberStatus (r:1 w:0)
+	// Proof: Membership StakingAccountIdMemberStatus (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadCount (r:1 w:1)
+	// Proof: ProposalDiscussion ThreadCount (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: ProposalEngine ProposalCount (r:1 w:1)
+	// Proof: ProposalEngine ProposalCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: ProposalsCodex ThreadIdByProposalId (r:0 w:1)
+	// Proof: ProposalsCodex ThreadIdByProposalId (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: ProposalDiscussion ThreadById (r:0 w:1)
+	// Proof: ProposalDiscussion ThreadById (max_values: None, max_size: Some(198), added: 2673, mode: MaxEncodedLen)
+	// Storage: ProposalEngine Proposals (r:0 w:1)
+	// Proof: ProposalEngine Proposals (max_values: None, max_size: Some(140), added: 2615, mode: MaxEncodedLen)
+	// Storage: ProposalEngine DispatchableCallCode (r:0 w:1)
+	// Proof: ProposalEngine DispatchableCallCode (max_values: None, max_size: Some(3145752), added: 3148227, mode: MaxEncodedLen)
+	/// The range of component `t` is `[1, 20]`.
+	/// The range of component `d` is `[1, 20]`.
+	fn create_proposal_resolve_bounty_judgment_dispute(t: u32, d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `651`
+		//  Estimated: `19940`
+		// Minimum execution time: 101_522 nanoseconds.
+		Weight::from_parts(80_317_209, 0u64)
+			.saturating_add(Weight::from_parts(0, 19940))
+			// Standard Error: 19_272
+			.saturating_add(Weight::from_parts(1_098_532, 0u64).saturating_mul(t.into()))
+			// Standard Error: 22_580
+			.saturating_add(Weight::from_parts(1_287_104, 0u64).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
 }
 
 // Default implementation for tests
@@ -1241,4 +1403,16 @@ impl WeightInfo for () {
 	fn create_proposal_decrease_council_budget(t: u32, d: u32, ) -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn create_proposal_overturn_content_takedown(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn create_proposal_dispute_worker_review(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn create_proposal_revoke_working_group_deputy(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn create_proposal_resolve_bounty_judgment_dispute(t: u32, d: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
 }
@@ -15,6 +15,8 @@ use sp_std::iter::FromIterator;
 use sp_std::vec;
 use sp_std::vec::Vec;
 
+use common::working_group::{WorkingGroupAuthenticator, WorkingGroupBudgetHandler};
+
 use frame_system::Pallet as System;
 use membership::Module as Membership;
 use working_group::{
@@ -23,10 +25,14 @@ use working_group::{
 };
 
 use crate::{
-    BagId, Balances, Base58Multihash, Blacklist, Call, Config, DataObjectCreationParameters,
-    DataObjectStorage, DistributionBucketByFamilyIdById, DistributionBucketFamilyById,
-    DistributionBucketId, DynamicBagType, Module, Module as Pallet, RawEvent, StaticBagId,
-    StorageBucketById, StorageBucketOperatorStatus, UploadParameters,
+    BagId, BagStoragePolicy, Balances, BandwidthReportId, BandwidthTier, Base58Multihash,
+    Blacklist, Call, Config, DataObjectCreationParameters, DataObjectStorage,
+    DistributionBucketByFamilyIdById, DistributionBucketFamilyById, DistributionBucketId,
+    DistributionDescriptor, DistributionDescriptorParameters, DistributionOperatorEndpoint,
+    DistributionOperatorEndpointMaxLength, DistributionProtocol, DynamicBagType,
+    MaxStorageBucketTags, Module, Module as Pallet, RawEvent, RegionCodeMaxLength, StaticBagId,
+    StorageBucketById, StorageBucketOperatorStatus, StorageBucketTagMaxLength, StorageBucketTags,
+    UploadParameters, BYTES_IN_GIGABYTE,
 };
 use frame_support::sp_runtime::SaturatedConversion;
 
@@ -400,6 +406,49 @@ fn set_storage_operator<T: Config>(
     .unwrap();
 }
 
+fn create_storage_bucket_tags(i: u32) -> BTreeSet<Vec<u8>> {
+    (0..i)
+        .map(|idx| {
+            let mut tag = vec![0u8; StorageBucketTagMaxLength::get() as usize];
+            tag[0] = idx as u8;
+            tag
+        })
+        .collect()
+}
+
+fn to_storage_bucket_tags(tags: BTreeSet<Vec<u8>>) -> StorageBucketTags {
+    tags.into_iter()
+        .map(|tag| tag.try_into().unwrap())
+        .collect::<BTreeSet<_>>()
+        .try_into()
+        .unwrap()
+}
+
+fn create_distribution_descriptor() -> DistributionDescriptorParameters {
+    DistributionDescriptorParameters {
+        region: vec![1u8; RegionCodeMaxLength::get() as usize],
+        bandwidth_tier: BandwidthTier::Premium,
+        protocols: vec![
+            DistributionProtocol::Http,
+            DistributionProtocol::Https,
+            DistributionProtocol::Ipfs,
+            DistributionProtocol::WebSocket,
+        ]
+        .into_iter()
+        .collect(),
+    }
+}
+
+fn to_distribution_descriptor(
+    descriptor: DistributionDescriptorParameters,
+) -> DistributionDescriptor {
+    DistributionDescriptor {
+        region: descriptor.region.try_into().unwrap(),
+        bandwidth_tier: descriptor.bandwidth_tier,
+        protocols: descriptor.protocols.try_into().unwrap(),
+    }
+}
+
 fn create_distribution_bucket_helper<T: Config>(
     lead_account_id: T::AccountId,
 ) -> DistributionBucketId<T> {
@@ -462,6 +511,68 @@ fn create_distribution_buckets<T: Config>(
         .collect::<_>()
 }
 
+fn submit_bandwidth_report_helper<
+    T: Config
+        + membership::Config
+        + working_group::Config<DistributionWorkingGroupInstance>
+        + balances::Config,
+>(
+    lead_account_id: T::AccountId,
+) -> BandwidthReportId
+where
+    T::AccountId: CreateAccountId,
+{
+    let (worker_account_id, worker_id) = insert_distribution_worker::<T>(
+        lead_account_id.clone(),
+        DEFAULT_DISTRIBUTION_WORKER_ACCOUNT_ID,
+    );
+    let bucket_id = create_distribution_bucket_helper::<T>(lead_account_id.clone());
+    let bag_id = BagId::<T>::Static(StaticBagId::Council);
+
+    Module::<T>::update_distribution_buckets_for_bag(
+        RawOrigin::Signed(lead_account_id.clone()).into(),
+        bag_id.clone(),
+        bucket_id.distribution_bucket_family_id,
+        BTreeSet::from_iter(vec![bucket_id.distribution_bucket_index]),
+        BTreeSet::new(),
+    )
+    .unwrap();
+
+    Module::<T>::invite_distribution_bucket_operator(
+        RawOrigin::Signed(lead_account_id.clone()).into(),
+        bucket_id.clone(),
+        worker_id,
+    )
+    .unwrap();
+
+    Module::<T>::accept_distribution_bucket_invitation(
+        RawOrigin::Signed(worker_account_id.clone()).into(),
+        worker_id,
+        bucket_id.clone(),
+    )
+    .unwrap();
+
+    Module::<T>::update_distribution_reward_per_gigabyte(
+        RawOrigin::Signed(lead_account_id).into(),
+        1000u64.saturated_into(),
+    )
+    .unwrap();
+
+    let report_id = Module::<T>::next_bandwidth_report_id();
+
+    Module::<T>::submit_bandwidth_report(
+        RawOrigin::Signed(worker_account_id).into(),
+        worker_id,
+        bucket_id,
+        bag_id,
+        BYTES_IN_GIGABYTE,
+        Some(T::Hash::default()),
+    )
+    .unwrap();
+
+    report_id
+}
+
 const DISTRIBUTION_BUCKET_FAMILIES_NUMBER: u32 = 7;
 const MAX_KILOBYTES_METADATA: u32 = 1000;
 const OBJECT_COUNT: u32 = 400;
@@ -1399,6 +1510,340 @@ benchmarks! {
             RawEvent::DistributionOperatorRemarked(worker_id, bucket_id, msg).into()
         );
     }
+
+    update_preferred_tags_in_dynamic_bag_creation_policy {
+        let i in 1 .. MaxStorageBucketTags::get();
+
+        let lead_account_id = insert_storage_leader::<T>(STORAGE_WG_LEADER_ACCOUNT_ID);
+        let dynamic_bag_type = DynamicBagType::Channel;
+        let tags = create_storage_bucket_tags(i);
+    }: _ (RawOrigin::Signed(lead_account_id), dynamic_bag_type, tags.clone())
+    verify {
+        let tags = to_storage_bucket_tags(tags);
+
+        assert_eq!(Module::<T>::preferred_storage_bucket_tags(dynamic_bag_type), tags);
+
+        assert_last_event::<T>(
+            RawEvent::PreferredTagsInDynamicBagCreationPolicyUpdated(dynamic_bag_type, tags)
+                .into()
+        );
+    }
+
+    rebalance_storage_buckets_for_bag {
+        let i in 1 .. T::MaxStorageBucketsPerBag::get();
+
+        let b in (2 * T::MaxStorageBucketsPerBag::get()) ..
+            T::MaxStorageBucketsScannedOnAssignment::get();
+
+        let lead_account_id = insert_storage_leader::<T>(STORAGE_WG_LEADER_ACCOUNT_ID);
+        let (worker_account_id, worker_id) =
+            insert_storage_worker::<T>(lead_account_id.clone(), SECOND_WORKER_ACCOUNT_ID);
+        let bag_id = BagId::<T>::Static(StaticBagId::Council);
+
+        Module::<T>::update_storage_buckets_per_bag_limit(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            T::MaxStorageBucketsPerBag::get(),
+        ).unwrap();
+
+        // Buckets with zero limits are considered full.
+        let remove_buckets = create_storage_buckets::<T>(lead_account_id.clone(), i);
+
+        Module::<T>::update_storage_buckets_for_bag(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            bag_id.clone(),
+            remove_buckets.clone(),
+            Default::default(),
+        )
+        .unwrap();
+
+        Module::<T>::update_storage_buckets_voucher_max_limits(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            u64::MAX,
+            u64::MAX,
+        )
+        .unwrap();
+
+        // Candidate buckets scanned on the rebalancing (the bag buckets have no operator and
+        // aren't scanned): the first `i` of them (lowest ids) replace the bag buckets.
+        let candidates = (0..b)
+            .map(|_| {
+                let bucket_id = Module::<T>::next_storage_bucket_id();
+
+                Module::<T>::create_storage_bucket(
+                    RawOrigin::Signed(lead_account_id.clone()).into(),
+                    None,
+                    true,
+                    1000,
+                    100,
+                )
+                .unwrap();
+
+                set_storage_operator::<T>(
+                    lead_account_id.clone(),
+                    bucket_id,
+                    worker_id,
+                    worker_account_id.clone(),
+                );
+
+                bucket_id
+            })
+            .collect::<BTreeSet<_>>();
+
+        let add_buckets = candidates
+            .into_iter()
+            .take(i.saturated_into())
+            .collect::<BTreeSet<_>>();
+
+    }: _ (RawOrigin::Signed(lead_account_id), bag_id.clone(), i)
+    verify {
+        let bag = Module::<T>::bag(bag_id.clone());
+        assert_eq!(BTreeSet::from(bag.stored_by), add_buckets);
+
+        assert_last_event::<T>(
+            RawEvent::StorageBucketsUpdatedForBag(bag_id, add_buckets, remove_buckets).into()
+        );
+    }
+
+    set_storage_operator_tags {
+        let i in 1 .. MaxStorageBucketTags::get();
+
+        let lead_account_id = insert_storage_leader::<T>(STORAGE_WG_LEADER_ACCOUNT_ID);
+        let (worker_account_id, worker_id) =
+            insert_storage_worker::<T>(lead_account_id.clone(), SECOND_WORKER_ACCOUNT_ID);
+        let bucket_id = create_storage_bucket_helper::<T>(lead_account_id.clone());
+
+        set_storage_operator::<T>(
+            lead_account_id,
+            bucket_id,
+            worker_id,
+            worker_account_id.clone()
+        );
+
+        let tags = create_storage_bucket_tags(i);
+    }: _ (RawOrigin::Signed(worker_account_id), worker_id, bucket_id, tags.clone())
+    verify {
+        let tags = to_storage_bucket_tags(tags);
+
+        assert_eq!(Module::<T>::storage_bucket_operator_tags(bucket_id), tags);
+
+        assert_last_event::<T>(
+            RawEvent::StorageOperatorTagsSet(bucket_id, worker_id, tags).into()
+        );
+    }
+
+    update_bag_storage_policy {
+        let lead_account_id = insert_storage_leader::<T>(STORAGE_WG_LEADER_ACCOUNT_ID);
+        let bag_id = BagId::<T>::Static(StaticBagId::Council);
+        let buckets_number = T::MaxStorageBucketsPerBag::get();
+
+        Module::<T>::update_storage_buckets_per_bag_limit(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            buckets_number,
+        ).unwrap();
+
+        let buckets = create_storage_buckets::<T>(lead_account_id.clone(), buckets_number);
+
+        Module::<T>::update_storage_buckets_for_bag(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            bag_id.clone(),
+            buckets,
+            Default::default(),
+        )
+        .unwrap();
+
+        let parity_shards = buckets_number.saturating_div(2);
+        let storage_policy = BagStoragePolicy::ErasureCoding {
+            data_shards: buckets_number.saturating_sub(parity_shards),
+            parity_shards,
+        };
+    }: _ (RawOrigin::Signed(lead_account_id), bag_id.clone(), storage_policy)
+    verify {
+        assert_eq!(Module::<T>::bag_storage_policy(bag_id.clone()), storage_policy);
+
+        assert_last_event::<T>(
+            RawEvent::BagStoragePolicyUpdated(bag_id, storage_policy).into()
+        );
+    }
+
+    set_distribution_bucket_family_descriptor {
+        let lead_account_id = insert_distribution_leader::<T>(DISTRIBUTION_WG_LEADER_ACCOUNT_ID);
+        let family_id = create_distribution_family::<T>(lead_account_id.clone());
+        let descriptor = create_distribution_descriptor();
+    }: _ (RawOrigin::Signed(lead_account_id), family_id, descriptor.clone())
+    verify {
+        let descriptor = to_distribution_descriptor(descriptor);
+
+        assert_eq!(
+            Module::<T>::distribution_bucket_family_descriptor(family_id),
+            Some(descriptor.clone())
+        );
+
+        assert_last_event::<T>(
+            RawEvent::DistributionBucketFamilyDescriptorSet(family_id, descriptor).into()
+        );
+    }
+
+    set_distribution_bucket_descriptor {
+        let lead_account_id = insert_distribution_leader::<T>(DISTRIBUTION_WG_LEADER_ACCOUNT_ID);
+        let bucket_id = create_distribution_bucket_helper::<T>(lead_account_id.clone());
+        let descriptor = create_distribution_descriptor();
+    }: _ (RawOrigin::Signed(lead_account_id), bucket_id.clone(), descriptor.clone())
+    verify {
+        let descriptor = to_distribution_descriptor(descriptor);
+
+        assert_eq!(
+            Module::<T>::distribution_bucket_descriptor(&bucket_id),
+            Some(descriptor.clone())
+        );
+
+        assert_last_event::<T>(
+            RawEvent::DistributionBucketDescriptorSet(bucket_id, descriptor).into()
+        );
+    }
+
+    set_distribution_operator_endpoint {
+        let lead_account_id = insert_distribution_leader::<T>(DISTRIBUTION_WG_LEADER_ACCOUNT_ID);
+        let (worker_account_id, worker_id) = insert_distribution_worker::<T>(
+            lead_account_id.clone(),
+            DEFAULT_DISTRIBUTION_WORKER_ACCOUNT_ID
+        );
+        let bucket_id = create_distribution_bucket_helper::<T>(lead_account_id.clone());
+
+        Module::<T>::invite_distribution_bucket_operator(
+            RawOrigin::Signed(lead_account_id).into(),
+            bucket_id.clone(),
+            worker_id,
+        )
+        .unwrap();
+
+        Module::<T>::accept_distribution_bucket_invitation(
+            RawOrigin::Signed(worker_account_id.clone()).into(),
+            worker_id,
+            bucket_id.clone(),
+        )
+        .unwrap();
+
+        let endpoint = vec![1u8; DistributionOperatorEndpointMaxLength::get() as usize];
+    }: _ (RawOrigin::Signed(worker_account_id), worker_id, bucket_id.clone(), endpoint.clone())
+    verify {
+        let endpoint: DistributionOperatorEndpoint = endpoint.try_into().unwrap();
+
+        assert_eq!(
+            Module::<T>::distribution_operator_endpoint(&bucket_id, worker_id),
+            Some(endpoint.clone())
+        );
+
+        assert_last_event::<T>(
+            RawEvent::DistributionOperatorEndpointSet(worker_id, bucket_id, endpoint).into()
+        );
+    }
+
+    update_distribution_reward_per_gigabyte {
+        let lead_account_id = insert_distribution_leader::<T>(DISTRIBUTION_WG_LEADER_ACCOUNT_ID);
+        let new_reward: crate::BalanceOf<T> = 1000u64.saturated_into();
+    }: _ (RawOrigin::Signed(lead_account_id), new_reward)
+    verify {
+        assert_eq!(Module::<T>::distribution_reward_per_gigabyte(), new_reward);
+
+        assert_last_event::<T>(
+            RawEvent::DistributionRewardPerGigabyteUpdated(new_reward).into()
+        );
+    }
+
+    submit_bandwidth_report {
+        let lead_account_id = insert_distribution_leader::<T>(DISTRIBUTION_WG_LEADER_ACCOUNT_ID);
+        let (worker_account_id, worker_id) = insert_distribution_worker::<T>(
+            lead_account_id.clone(),
+            DEFAULT_DISTRIBUTION_WORKER_ACCOUNT_ID
+        );
+        let bucket_id = create_distribution_bucket_helper::<T>(lead_account_id.clone());
+        let bag_id = BagId::<T>::Static(StaticBagId::Council);
+
+        Module::<T>::update_distribution_buckets_for_bag(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            bag_id.clone(),
+            bucket_id.distribution_bucket_family_id,
+            BTreeSet::from_iter(vec![bucket_id.distribution_bucket_index]),
+            BTreeSet::new(),
+        )
+        .unwrap();
+
+        Module::<T>::invite_distribution_bucket_operator(
+            RawOrigin::Signed(lead_account_id.clone()).into(),
+            bucket_id.clone(),
+            worker_id,
+        )
+        .unwrap();
+
+        Module::<T>::accept_distribution_bucket_invitation(
+            RawOrigin::Signed(worker_account_id.clone()).into(),
+            worker_id,
+            bucket_id.clone(),
+        )
+        .unwrap();
+
+        let receipts_root = Some(T::Hash::default());
+        let report_id = Module::<T>::next_bandwidth_report_id();
+    }: _ (
+        RawOrigin::Signed(worker_account_id),
+        worker_id,
+        bucket_id,
+        bag_id,
+        BYTES_IN_GIGABYTE,
+        receipts_root
+    )
+    verify {
+        let report = Module::<T>::bandwidth_report(report_id).unwrap();
+
+        assert_last_event::<T>(
+            RawEvent::BandwidthReportSubmitted(report_id, report).into()
+        );
+    }
+
+    reject_bandwidth_report {
+        let i in 1 .. MAX_KILOBYTES_METADATA;
+        let rationale = iter::repeat(1).take((i * 1000) as usize).collect::<Vec<_>>();
+
+        let lead_account_id = insert_distribution_leader::<T>(DISTRIBUTION_WG_LEADER_ACCOUNT_ID);
+        let report_id = submit_bandwidth_report_helper::<T>(lead_account_id.clone());
+    }: _ (RawOrigin::Signed(lead_account_id), report_id, rationale.clone())
+    verify {
+        assert!(Module::<T>::bandwidth_report(report_id).is_none());
+
+        assert_last_event::<T>(
+            RawEvent::BandwidthReportRejected(report_id, rationale).into()
+        );
+    }
+
+    settle_bandwidth_report {
+        let lead_account_id = insert_distribution_leader::<T>(DISTRIBUTION_WG_LEADER_ACCOUNT_ID);
+        let report_id = submit_bandwidth_report_helper::<T>(lead_account_id.clone());
+        let report = Module::<T>::bandwidth_report(report_id).unwrap();
+
+        T::DistributionWorkingGroup::set_budget(report.reward);
+
+        // The report period is over as well: the last submission record is removed.
+        System::<T>::set_block_number(
+            System::<T>::block_number()
+                + T::BandwidthReportDisputePeriod::get()
+                + T::BandwidthReportPeriod::get()
+        );
+
+        let account_id = account::<T::AccountId>("caller", 0, 0);
+        let reward_account_id =
+            T::DistributionWorkingGroup::get_worker_reward_account_id(&report.worker_id).unwrap();
+    }: _ (RawOrigin::Signed(account_id), report_id)
+    verify {
+        assert!(Module::<T>::bandwidth_report(report_id).is_none());
+        assert!(Module::<T>::last_bandwidth_report_submission(
+            report.worker_id,
+            (report.bucket_id.clone(), report.bag_id.clone())
+        ).is_none());
+
+        assert_last_event::<T>(
+            RawEvent::BandwidthReportSettled(report_id, reward_account_id, report.reward).into()
+        );
+    }
 }
 
 #[cfg(test)]
@@ -1652,4 +2097,82 @@ mod tests {
             assert_ok!(Storage::test_benchmark_distribution_operator_remark());
         });
     }
+
+    #[test]
+    fn update_preferred_tags_in_dynamic_bag_creation_policy() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(
+                Storage::test_benchmark_update_preferred_tags_in_dynamic_bag_creation_policy()
+            );
+        });
+    }
+
+    #[test]
+    fn rebalance_storage_buckets_for_bag() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_rebalance_storage_buckets_for_bag());
+        });
+    }
+
+    #[test]
+    fn set_storage_operator_tags() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_set_storage_operator_tags());
+        });
+    }
+    #[test]
+    fn update_bag_storage_policy() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_update_bag_storage_policy());
+        });
+    }
+
+    #[test]
+    fn set_distribution_bucket_family_descriptor() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_set_distribution_bucket_family_descriptor());
+        });
+    }
+
+    #[test]
+    fn set_distribution_bucket_descriptor() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_set_distribution_bucket_descriptor());
+        });
+    }
+
+    #[test]
+    fn set_distribution_operator_endpoint() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_set_distribution_operator_endpoint());
+        });
+    }
+
+    #[test]
+    fn update_distribution_reward_per_gigabyte() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_update_distribution_reward_per_gigabyte());
+        });
+    }
+
+    #[test]
+    fn submit_bandwidth_report() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_submit_bandwidth_report());
+        });
+    }
+
+    #[test]
+    fn reject_bandwidth_report() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_reject_bandwidth_report());
+        });
+    }
+
+    #[test]
+    fn settle_bandwidth_report() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(Storage::test_benchmark_settle_bandwidth_report());
+        });
+    }
 }
@@ -41,6 +41,10 @@
 //! updates whether new bags are being accepted for storage.
 //! - [set_storage_bucket_voucher_limits](./struct.Module.html#method.set_storage_bucket_voucher_limits) -
 //! sets storage bucket voucher limits.
+//! - [update_preferred_tags_in_dynamic_bag_creation_policy](./struct.Module.html#method.update_preferred_tags_in_dynamic_bag_creation_policy) -
+//! updates storage bucket tags preferred on the automatic bucket assignment for new dynamic bags.
+//! - [rebalance_storage_buckets_for_bag](./struct.Module.html#method.rebalance_storage_buckets_for_bag) -
+//! moves a bag from its most loaded storage buckets to less loaded ones.
 //!
 //!
 //! #### Storage provider extrinsics
@@ -48,6 +52,8 @@
 //! accepts the storage bucket invitation.
 //! - [set_storage_operator_metadata](./struct.Module.html#method.set_storage_operator_metadata) -
 //! sets storage operator metadata.
+//! - [set_storage_operator_tags](./struct.Module.html#method.set_storage_operator_tags) -
+//! sets storage operator tags (eg.: region) used on the automatic bucket assignment.
 //! - [accept_pending_data_objects](./struct.Module.html#method.accept_pending_data_objects) - a
 //! storage provider signals that the data object was successfully uploaded to its storage.
 //!
@@ -103,6 +109,7 @@
 //! - StorageBucketsPerBagValueConstraint
 //! - DefaultMemberDynamicBagNumberOfStorageBuckets
 //! - DefaultChannelDynamicBagNumberOfStorageBuckets
+//! - MaxStorageBucketsScannedOnAssignment
 //! - MaxDistributionBucketFamilyNumber
 //! - DistributionBucketsPerBagValueConstraint
 //! - MaxNumberOfPendingInvitationsPerDistributionBucket
@@ -140,41 +147,47 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
+pub mod runtime_api;
 pub mod weights;
 use frame_support::BoundedBTreeMap;
 pub use weights::WeightInfo;
 
 use codec::{Codec, Decode, Encode, MaxEncodedLen};
 use frame_support::dispatch::{DispatchError, DispatchResult};
-use frame_support::traits::{Currency, Get};
+use frame_support::traits::{Currency, Get, StorageVersion};
 
 use frame_support::{
     decl_error, decl_event, decl_module, decl_storage, ensure, parameter_types,
     storage::{bounded_btree_set::BoundedBTreeSet, bounded_vec::BoundedVec},
-    IterableStorageDoubleMap, PalletId, Parameter,
+    IterableStorageDoubleMap, IterableStorageMap, PalletId, Parameter,
 };
 use frame_system::ensure_signed;
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
+use sp_arithmetic::Perbill;
 use sp_runtime::traits::{AccountIdConversion, MaybeSerialize, Member, Saturating};
 use sp_runtime::SaturatedConversion;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
-use sp_std::convert::TryInto;
+use sp_std::convert::{TryFrom, TryInto};
 use sp_std::iter;
 use sp_std::marker::PhantomData;
 use sp_std::{vec, vec::Vec};
 
 use common::bloat_bond::{RepayableBloatBond, RepayableBloatBondOf};
-use common::costs::{has_sufficient_balance_for_fees, pay_fee};
+use common::costs::{burn_from_usable, has_sufficient_balance_for_fees, pay_fee};
 use common::to_kb;
 use common::working_group::WorkingGroup;
 use common::working_group::WorkingGroupAuthenticator;
 
 type WeightInfoStorage<T> = <T as Config>::WeightInfo;
 
+// Storage version of the pallet.
+const CURRENT_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 type DataObjAndStateBloatBondAndObjSize<T> =
     Result<(Vec<DataObjectOf<T>>, BalanceOf<T>, u64), DispatchError>;
 
@@ -260,6 +273,29 @@ pub trait DataObjectStorage<T: Config> {
         objects: BTreeSet<T::DataObjectId>,
     ) -> DispatchResult;
 
+    /// Add and remove hashes to the current blacklist.
+    ///
+    /// PRECONDITIONS:
+    /// - ALL hashes must be valid base58 multihashes or InvalidCidLength error returned
+    /// - updated blacklist size must not exceed BlacklistSizeLimit or BlacklistSizeLimitExceeded error returned
+    ///
+    /// POSTCONDITIONS:
+    /// - existing `remove_hashes` are removed from the blacklist
+    /// - nonexisting `add_hashes` are added to the blacklist
+    fn update_content_blacklist(
+        remove_hashes: BTreeSet<Vec<u8>>,
+        add_hashes: BTreeSet<Vec<u8>>,
+    ) -> DispatchResult;
+
+    /// Validates the blacklist update parameters.
+    ///
+    /// PRECONDITIONS:
+    /// - update_content_blacklist::PRECONDITIONS
+    fn can_update_content_blacklist(
+        remove_hashes: &BTreeSet<Vec<u8>>,
+        add_hashes: &BTreeSet<Vec<u8>>,
+    ) -> DispatchResult;
+
     /// Delete dynamic bag. Updates related storage bucket vouchers.
     /// PRECONDITIONS:
     /// - bag_id must exists or BagDoesntExist error returned
@@ -274,8 +310,12 @@ pub trait DataObjectStorage<T: Config> {
     fn delete_dynamic_bag(account_id: &T::AccountId, bag_id: DynamicBagId<T>) -> DispatchResult;
 
     /// Creates dynamic bag. BagId should provide the caller
+    /// If params.storage_buckets is empty the storage buckets are selected automatically
+    /// according to the dynamic bag creation policy: see `pick_storage_buckets_for_dynamic_bag`.
     /// PRECONDITIONS:
     /// - params.bag_id must not exist yet or DynamicBagExists error returned
+    /// - if storage buckets are not specified, enough storage buckets must be available for the
+    /// automatic assignment or NotEnoughStorageBucketsForAutomaticAssignment error returned
     /// - if objects to upload are specified:
     ///   - global uploading block not enabled or UploadingBlocked error returned
     ///   - size of each objects less than MaxDataObjectSize or MaxDataObjectSizeExceeded error returned
@@ -419,6 +459,10 @@ pub trait Config: frame_system::Config + balances::Config + common::MembershipTy
     /// Maximum number of storage buckets per bag
     type MaxStorageBucketsPerBag: Get<u32>;
 
+    /// Maximum number of storage buckets scanned for candidates on the automatic storage
+    /// buckets assignment (dynamic bag creation and bag rebalancing).
+    type MaxStorageBucketsScannedOnAssignment: Get<u32>;
+
     /// Minimum number of distribution buckets per bag
     type MinDistributionBucketsPerBag: Get<u32>;
 
@@ -455,6 +499,19 @@ pub trait Config: frame_system::Config + balances::Config + common::MembershipTy
 
     /// Module account initial balance (existential deposit).
     type ModuleAccountInitialBalance: Get<BalanceOf<Self>>;
+
+    /// Period during which the distribution lead can reject the submitted bandwidth report.
+    type BandwidthReportDisputePeriod: Get<Self::BlockNumber>;
+
+    /// Period covered by a single bandwidth report: the distribution operator can submit
+    /// only one report per bucket and bag within the period.
+    type BandwidthReportPeriod: Get<Self::BlockNumber>;
+
+    /// Max number of the served bytes in a single bandwidth report.
+    type MaxBytesServedPerBandwidthReport: Get<u64>;
+
+    /// Bond locked on the bandwidth report submission. Slashed when the report is rejected.
+    type BandwidthReportBond: Get<BalanceOf<Self>>;
 }
 
 /// Operations with local pallet account.
@@ -548,9 +605,282 @@ pub struct DataObject<RepayableBloatBond> {
 parameter_types! { pub const Base58MultihashLen: u32 = 46; }
 pub type Base58Multihash = BoundedVec<u8, Base58MultihashLen>;
 
+parameter_types! {
+    pub const StorageBucketTagMaxLength: u32 = 32;
+    pub const MaxStorageBucketTags: u32 = 10;
+}
+
+/// Storage bucket tag set by the bucket operator (eg.: region code).
+pub type StorageBucketTag = BoundedVec<u8, StorageBucketTagMaxLength>;
+
+/// Type alias for bounded storage bucket tags set.
+pub type StorageBucketTags = BoundedBTreeSet<StorageBucketTag, MaxStorageBucketTags>;
+
+parameter_types! {
+    pub const RegionCodeMaxLength: u32 = 16;
+    pub const MaxDistributionProtocols: u32 = 8;
+    pub const DistributionOperatorEndpointMaxLength: u32 = 256;
+}
+
+/// Region code (eg.: "eu-central").
+pub type RegionCode = BoundedVec<u8, RegionCodeMaxLength>;
+
+/// Set of the protocols supported by the distribution bucket.
+pub type DistributionProtocols = BoundedBTreeSet<DistributionProtocol, MaxDistributionProtocols>;
+
+/// Distribution operator endpoint (eg.: "https://distributor.example.com/").
+pub type DistributionOperatorEndpoint = BoundedVec<u8, DistributionOperatorEndpointMaxLength>;
+
+/// Bandwidth tier of the distribution bucket family or the distribution bucket.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, PartialOrd, Ord, TypeInfo, MaxEncodedLen,
+)]
+pub enum BandwidthTier {
+    /// Basic bandwidth.
+    Basic,
+
+    /// Standard bandwidth.
+    Standard,
+
+    /// Premium bandwidth.
+    Premium,
+}
+
+impl Default for BandwidthTier {
+    fn default() -> Self {
+        Self::Basic
+    }
+}
+
+/// Protocol supported by the distribution bucket.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+    Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, PartialOrd, Ord, TypeInfo, MaxEncodedLen,
+)]
+pub enum DistributionProtocol {
+    /// Plain HTTP.
+    Http,
+
+    /// HTTP over TLS.
+    Https,
+
+    /// IPFS gateway.
+    Ipfs,
+
+    /// WebSocket.
+    WebSocket,
+}
+
+/// Typed descriptor of the distribution bucket family or the distribution bucket.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct DistributionDescriptorRecord<Region, Protocols> {
+    /// Region code.
+    pub region: Region,
+
+    /// Bandwidth tier.
+    pub bandwidth_tier: BandwidthTier,
+
+    /// Supported protocols.
+    pub protocols: Protocols,
+}
+
+/// Type alias for the DistributionDescriptorRecord.
+pub type DistributionDescriptor = DistributionDescriptorRecord<RegionCode, DistributionProtocols>;
+
+/// Type alias for the DistributionDescriptorRecord extrinsic parameters.
+pub type DistributionDescriptorParameters =
+    DistributionDescriptorRecord<Vec<u8>, BTreeSet<DistributionProtocol>>;
+
+/// Distribution bucket serving the bag with its operator endpoints.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct DistributionRouteRecord<DistributionBucketId> {
+    /// Distribution bucket ID.
+    pub bucket_id: DistributionBucketId,
+
+    /// Effective descriptor of the distribution bucket: the bucket descriptor if set or the
+    /// descriptor of its family otherwise.
+    pub descriptor: DistributionDescriptor,
+
+    /// Endpoints of the distribution bucket operators.
+    pub endpoints: Vec<DistributionOperatorEndpoint>,
+}
+
+/// Type alias for the DistributionRouteRecord.
+pub type DistributionRoute<T> = DistributionRouteRecord<DistributionBucketId<T>>;
+
+/// Type identifier for the bandwidth report.
+pub type BandwidthReportId = u64;
+
+/// Number of bytes in a gigabyte used for the bandwidth reward calculation.
+pub const BYTES_IN_GIGABYTE: u64 = 1_000_000_000;
+
+/// Aggregated bandwidth report submitted by the distribution operator for a bag.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct BandwidthReportRecord<
+    WorkerId,
+    DistributionBucketId,
+    BagId,
+    Hash,
+    Balance,
+    BlockNumber,
+    AccountId,
+    RepayableBloatBond,
+> {
+    /// Distribution operator (worker) ID.
+    pub worker_id: WorkerId,
+
+    /// Distribution bucket that served the traffic.
+    pub bucket_id: DistributionBucketId,
+
+    /// Bag that was served.
+    pub bag_id: BagId,
+
+    /// Aggregated number of the served bytes.
+    pub bytes_served: u64,
+
+    /// Merkle root of the client-signed receipts.
+    pub receipts_root: Option<Hash>,
+
+    /// Reward to settle.
+    pub reward: Balance,
+
+    /// Block number of the report submission.
+    pub submitted_at: BlockNumber,
+
+    /// Account that provided the report bond.
+    pub bond_account_id: AccountId,
+
+    /// Report bond: returned on the settlement, slashed on the rejection.
+    pub bond: RepayableBloatBond,
+}
+
+/// Type alias for the BandwidthReportRecord.
+pub type BandwidthReport<T> = BandwidthReportRecord<
+    WorkerId<T>,
+    DistributionBucketId<T>,
+    BagId<T>,
+    <T as frame_system::Config>::Hash,
+    BalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+    <T as frame_system::Config>::AccountId,
+    RepayableBloatBondOf<T>,
+>;
+
 /// Type alias for DataObject.
 pub type DataObjectOf<T> = DataObject<RepayableBloatBondOf<T>>;
 
+/// Defines how the data objects of a bag are stored by the bag storage buckets.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum BagStoragePolicy {
+    /// Each storage bucket of the bag stores a full copy of every data object.
+    Replication,
+
+    /// Each data object is split into `data_shards` data shards extended with `parity_shards`
+    /// parity shards. Each storage bucket of the bag stores a single shard of every data object.
+    ErasureCoding {
+        /// Number of data shards (the number of shards required to reconstruct an object).
+        data_shards: u32,
+
+        /// Number of parity shards.
+        parity_shards: u32,
+    },
+}
+
+impl Default for BagStoragePolicy {
+    fn default() -> Self {
+        Self::Replication
+    }
+}
+
+impl BagStoragePolicy {
+    // Returns the total number of shards for the erasure coding policy.
+    fn shards_number(&self) -> Option<u32> {
+        match self {
+            Self::Replication => None,
+            Self::ErasureCoding {
+                data_shards,
+                parity_shards,
+            } => Some(data_shards.saturating_add(*parity_shards)),
+        }
+    }
+
+    /// Number of bytes stored by a single bag storage bucket for the data object of given size.
+    pub fn bucket_object_size(&self, size: u64) -> u64 {
+        match self {
+            Self::Replication => size,
+            Self::ErasureCoding { data_shards, .. } => {
+                let data_shards = u64::from(*data_shards);
+                // Shard size is rounded up.
+                size.saturating_add(data_shards.saturating_sub(1))
+                    .checked_div(data_shards)
+                    .unwrap_or(size)
+            }
+        }
+    }
+}
+
+/// Shard layout of the erasure coded bag.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct BagShardLayoutRecord<ShardBuckets> {
+    /// Storage buckets assigned to the shards by shard index (data shards go first).
+    pub shard_buckets: ShardBuckets,
+
+    /// Total size of the bag data objects shards stored by a single bag storage bucket.
+    pub shards_total_size: u64,
+}
+
+impl<StorageBucketId: PartialEq, MaxShards: Get<u32>>
+    BagShardLayoutRecord<BoundedVec<StorageBucketId, MaxShards>>
+{
+    // Returns the index of the shard assigned to the storage bucket.
+    fn shard_index(&self, bucket_id: &StorageBucketId) -> Option<u32> {
+        self.shard_buckets
+            .iter()
+            .position(|id| id == bucket_id)
+            .map(|index| index.saturated_into())
+    }
+}
+
+/// Type alias for the BagShardLayoutRecord.
+pub type BagShardLayout<T> = BagShardLayoutRecord<
+    BoundedVec<<T as Config>::StorageBucketId, <T as Config>::MaxStorageBucketsPerBag>,
+>;
+
+/// Shard layout of the data object stored in the erasure coded bag.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct DataObjectShardLayoutRecord<AcceptedShards> {
+    /// Single shard size in bytes.
+    pub shard_size: u64,
+
+    /// Storage buckets that accepted the shards by shard index. The shard acceptance is reset
+    /// by replacing its storage bucket in the bag shard layout.
+    pub accepted_shards: AcceptedShards,
+}
+
+impl<StorageBucketId: PartialEq, MaxShards: Get<u32>>
+    DataObjectShardLayoutRecord<BoundedBTreeMap<u32, StorageBucketId, MaxShards>>
+{
+    // Whether all shards were accepted by the storage buckets currently assigned to them.
+    fn all_shards_accepted(&self, shard_buckets: &[StorageBucketId]) -> bool {
+        shard_buckets.iter().enumerate().all(|(index, bucket_id)| {
+            let index: u32 = index.saturated_into();
+            self.accepted_shards.get(&index) == Some(bucket_id)
+        })
+    }
+}
+
+/// Type alias for the DataObjectShardLayoutRecord.
+pub type DataObjectShardLayout<T> = DataObjectShardLayoutRecord<
+    BoundedBTreeMap<u32, <T as Config>::StorageBucketId, <T as Config>::MaxStorageBucketsPerBag>,
+>;
+
 /// Type alias for bounded storage bucket ids set
 pub type StorageBucketIdsSet<T> =
     BoundedBTreeSet<<T as Config>::StorageBucketId, <T as Config>::MaxStorageBucketsPerBag>;
@@ -1113,6 +1443,10 @@ decl_storage! { generate_storage_info
         pub StorageBucketById get (fn storage_bucket_by_id): map hasher(blake2_128_concat)
             T::StorageBucketId => Option<StorageBucket<T>>;
 
+        /// Storage buckets accepting new bags with an active operator: the candidates for
+        /// the automatic bag assignment.
+        pub AssignableStorageBuckets: map hasher(blake2_128_concat) T::StorageBucketId => ();
+
         /// Blacklisted data object hashes.
         pub Blacklist get (fn blacklist): map hasher(blake2_128_concat) Base58Multihash => ();
 
@@ -1161,6 +1495,57 @@ decl_storage! { generate_storage_info
 
         /// "Distribution buckets per bag" number limit.
         pub DistributionBucketsPerBagLimit get (fn distribution_buckets_per_bag_limit): u32;
+
+        /// Storage bucket tags set by the bucket operators (eg.: region).
+        pub StorageBucketOperatorTags get (fn storage_bucket_operator_tags): map
+            hasher(blake2_128_concat) T::StorageBucketId => StorageBucketTags;
+
+        /// Storage bucket tags preferred on the automatic bucket assignment by dynamic bag type.
+        pub PreferredStorageBucketTags get (fn preferred_storage_bucket_tags): map
+            hasher(blake2_128_concat) DynamicBagType => StorageBucketTags;
+
+        /// Storage policies of the bags (replication by default).
+        pub BagStoragePolicies get (fn bag_storage_policy): map
+            hasher(blake2_128_concat) BagId<T> => BagStoragePolicy;
+
+        /// Shard layouts of the erasure coded bags.
+        pub BagShardLayouts get (fn bag_shard_layout): map
+            hasher(blake2_128_concat) BagId<T> => Option<BagShardLayout<T>>;
+
+        /// Shard layouts of the data objects stored in the erasure coded bags.
+        pub DataObjectShardLayouts get (fn data_object_shard_layout): double_map
+            hasher(blake2_128_concat) BagId<T>,
+            hasher(blake2_128_concat) T::DataObjectId => Option<DataObjectShardLayout<T>>;
+
+        /// Typed descriptors of the distribution bucket families.
+        pub DistributionBucketFamilyDescriptors get (fn distribution_bucket_family_descriptor):
+            map hasher(blake2_128_concat) T::DistributionBucketFamilyId =>
+            Option<DistributionDescriptor>;
+
+        /// Typed descriptors of the distribution buckets (override the family descriptors).
+        pub DistributionBucketDescriptors get (fn distribution_bucket_descriptor): map
+            hasher(blake2_128_concat) DistributionBucketId<T> => Option<DistributionDescriptor>;
+
+        /// Distribution operator endpoints by distribution bucket.
+        pub DistributionOperatorEndpoints get (fn distribution_operator_endpoint): double_map
+            hasher(blake2_128_concat) DistributionBucketId<T>,
+            hasher(blake2_128_concat) WorkerId<T> => Option<DistributionOperatorEndpoint>;
+
+        /// Reward per gigabyte served by the distribution operators.
+        pub DistributionRewardPerGigabyte get (fn distribution_reward_per_gigabyte): BalanceOf<T>;
+
+        /// Bandwidth report identifier value to be used for the next report.
+        pub NextBandwidthReportId get (fn next_bandwidth_report_id): BandwidthReportId;
+
+        /// Bandwidth reports awaiting the settlement.
+        pub BandwidthReports get (fn bandwidth_report): map
+            hasher(blake2_128_concat) BandwidthReportId => Option<BandwidthReport<T>>;
+
+        /// Submission block of the last bandwidth report by distribution operator and
+        /// (bucket, bag) pair within the current report period.
+        pub LastBandwidthReportSubmission get (fn last_bandwidth_report_submission): double_map
+            hasher(blake2_128_concat) WorkerId<T>,
+            hasher(blake2_128_concat) (DistributionBucketId<T>, BagId<T>) => Option<T::BlockNumber>;
     }
     add_extra_genesis {
         build(|_| {
@@ -1195,7 +1580,8 @@ decl_event! {
         <T as Config>::DistributionBucketFamilyId,
         DistributionBucketId = DistributionBucketId<T>,
         <T as Config>::DistributionBucketIndex,
-        DynamicBagCreationParameters = DynBagCreationParameters<T>
+        DynamicBagCreationParameters = DynBagCreationParameters<T>,
+        BandwidthReport = BandwidthReport<T>
     {
         /// Emits on creating the storage bucket.
         /// Params
@@ -1497,6 +1883,73 @@ decl_event! {
             Vec<u8>,
         ),
 
+        /// Emits on setting the storage operator tags.
+        /// Params
+        /// - storage bucket ID
+        /// - operator worker ID
+        /// - new tags
+        StorageOperatorTagsSet(StorageBucketId, WorkerId, StorageBucketTags),
+
+        /// Emits on updating the preferred storage bucket tags in dynamic bag creation policy.
+        /// Params
+        /// - dynamic bag type
+        /// - new preferred tags
+        PreferredTagsInDynamicBagCreationPolicyUpdated(DynamicBagType, StorageBucketTags),
+
+        /// Emits on updating the bag storage policy.
+        /// Params
+        /// - bag ID
+        /// - new storage policy
+        BagStoragePolicyUpdated(BagId, BagStoragePolicy),
+
+        /// Emits on setting the distribution bucket family descriptor.
+        /// Params
+        /// - distribution bucket family ID
+        /// - descriptor
+        DistributionBucketFamilyDescriptorSet(DistributionBucketFamilyId, DistributionDescriptor),
+
+        /// Emits on setting the distribution bucket descriptor.
+        /// Params
+        /// - distribution bucket ID
+        /// - descriptor
+        DistributionBucketDescriptorSet(DistributionBucketId, DistributionDescriptor),
+
+        /// Emits on setting the distribution operator endpoint.
+        /// Params
+        /// - worker ID (distribution provider ID)
+        /// - distribution bucket ID
+        /// - endpoint
+        DistributionOperatorEndpointSet(WorkerId, DistributionBucketId, DistributionOperatorEndpoint),
+
+        /// Emits on updating the distribution reward per gigabyte.
+        /// Params
+        /// - new reward per gigabyte
+        DistributionRewardPerGigabyteUpdated(Balance),
+
+        /// Emits on submitting the bandwidth report.
+        /// Params
+        /// - bandwidth report ID
+        /// - bandwidth report
+        BandwidthReportSubmitted(BandwidthReportId, BandwidthReport),
+
+        /// Emits on rejecting the bandwidth report.
+        /// Params
+        /// - bandwidth report ID
+        /// - rationale
+        BandwidthReportRejected(BandwidthReportId, Vec<u8>),
+
+        /// Emits on settling the bandwidth report.
+        /// Params
+        /// - bandwidth report ID
+        /// - operator reward account ID
+        /// - paid reward
+        BandwidthReportSettled(BandwidthReportId, AccountId, Balance),
+
+        /// Emits on pruning the bandwidth report of the distribution operator
+        /// that left the working group.
+        /// Params
+        /// - bandwidth report ID
+        BandwidthReportPruned(BandwidthReportId),
 
     }
 }
@@ -1700,6 +2153,73 @@ decl_error! {
 
         /// Call Disabled
         CallDisabled,
+
+        /// Storage bucket tag is too long.
+        StorageBucketTagTooLong,
+
+        /// Max number of storage bucket tags exceeded.
+        MaxStorageBucketTagsExceeded,
+
+        /// Not enough storage buckets available for the automatic bag assignment.
+        NotEnoughStorageBucketsForAutomaticAssignment,
+
+        /// Invalid number of storage buckets to replace on rebalancing.
+        InvalidStorageBucketsRebalanceBatchSize,
+
+        /// No less loaded storage buckets were found to rebalance the bag.
+        NoStorageBucketsToRebalanceBag,
+
+        /// Bag storage policy can be changed only for the bag without data objects.
+        BagStoragePolicyCannotBeChangedForNonEmptyBag,
+
+        /// Erasure coding policy requires at least one data shard.
+        InvalidErasureCodingParameters,
+
+        /// Number of the erasure coded bag storage buckets must match the total number of shards.
+        ErasureCodedBagBucketsNumberMismatch,
+
+        /// Storage buckets of the erasure coded bag with data objects can only be replaced one
+        /// for another.
+        ErasureCodedBagStorageBucketsLocked,
+
+        /// Data objects of the erasure coded bags cannot be moved.
+        ErasureCodedBagDataObjectsCannotBeMoved,
+
+        /// Region code length exceeds the limit.
+        RegionCodeTooLong,
+
+        /// Max number of the distribution protocols exceeded.
+        MaxDistributionProtocolsExceeded,
+
+        /// Distribution operator endpoint length exceeds the limit.
+        DistributionOperatorEndpointTooLong,
+
+        /// Bandwidth report must contain served bytes.
+        ZeroBytesServedInBandwidthReport,
+
+        /// Bandwidth report doesn't exist.
+        BandwidthReportDoesntExist,
+
+        /// Bandwidth report dispute period has expired.
+        BandwidthReportDisputePeriodExpired,
+
+        /// Bandwidth report dispute period has not expired yet.
+        BandwidthReportDisputePeriodNotExpired,
+
+        /// Reward account of the distribution operator was not found.
+        DistributionOperatorRewardAccountNotFound,
+
+        /// Distribution working group budget is insufficient for the settlement.
+        InsufficientDistributionWorkingGroupBudget,
+
+        /// Bandwidth report exceeds the max number of the served bytes.
+        MaxBytesServedPerBandwidthReportExceeded,
+
+        /// Bandwidth report for the bucket and bag was already submitted within the period.
+        BandwidthReportAlreadySubmittedInPeriod,
+
+        /// Insufficient balance for the bandwidth report bond.
+        InsufficientBalanceForBandwidthReportBond,
     }
 }
 
@@ -1712,6 +2232,8 @@ decl_module! {
         /// Predefined errors.
         type Error = Error<T>;
 
+        type StorageVersion = CURRENT_STORAGE_VERSION;
+
         /// Exports const - maximum size of the "hash blacklist" collection.
         const BlacklistSizeLimit: u64 = T::BlacklistSizeLimit::get();
 
@@ -1721,6 +2243,11 @@ decl_module! {
         /// Exports const - maximum number of storage buckets per bag.
         const MaxStorageBucketsPerBag: u32 = T::MaxStorageBucketsPerBag::get();
 
+        /// Exports const - maximum number of storage buckets scanned for candidates on the
+        /// automatic storage buckets assignment.
+        const MaxStorageBucketsScannedOnAssignment: u32 =
+            T::MaxStorageBucketsScannedOnAssignment::get();
+
         /// Exports const - minimum number of distribution buckets per bag.
         const MinDistributionBucketsPerBag: u32 = T::MinDistributionBucketsPerBag::get();
 
@@ -1751,6 +2278,18 @@ decl_module! {
         /// Exports const - max data object size in bytes.
         const MaxDataObjectSize: u64 = T::MaxDataObjectSize::get();
 
+        /// Exports const - period during which the bandwidth report can be rejected.
+        const BandwidthReportDisputePeriod: T::BlockNumber = T::BandwidthReportDisputePeriod::get();
+
+        /// Exports const - period covered by a single bandwidth report.
+        const BandwidthReportPeriod: T::BlockNumber = T::BandwidthReportPeriod::get();
+
+        /// Exports const - max number of the served bytes in a single bandwidth report.
+        const MaxBytesServedPerBandwidthReport: u64 = T::MaxBytesServedPerBandwidthReport::get();
+
+        /// Exports const - bandwidth report bond.
+        const BandwidthReportBond: BalanceOf<T> = T::BandwidthReportBond::get();
+
         // ===== Storage Lead actions =====
 
         /// Delete storage bucket. Must be empty. Storage operator must be missing.
@@ -1783,6 +2322,8 @@ decl_module! {
             //
 
             <StorageBucketById<T>>::remove(storage_bucket_id);
+            <AssignableStorageBuckets<T>>::remove(storage_bucket_id);
+            <StorageBucketOperatorTags<T>>::remove(storage_bucket_id);
 
             Self::deposit_event(
                 RawEvent::StorageBucketDeleted(storage_bucket_id)
@@ -1978,36 +2519,7 @@ decl_module! {
         ){
             <T as Config>::StorageWorkingGroup::ensure_leader_origin(origin)?;
 
-            // Get only hashes that exist in the blacklist.
-            let verified_remove_hashes = Self::get_existing_hashes(&remove_hashes)?;
-
-            // Get only hashes that doesn't exist in the blacklist.
-            let verified_add_hashes = Self::get_nonexisting_hashes(&add_hashes)?;
-
-            let updated_blacklist_size: u64 = Self::current_blacklist_size()
-                .saturating_add(verified_add_hashes.len().saturated_into::<u64>())
-                .saturating_sub(verified_remove_hashes.len().saturated_into::<u64>());
-
-            ensure!(
-                updated_blacklist_size <= T::BlacklistSizeLimit::get(),
-                Error::<T>::BlacklistSizeLimitExceeded
-            );
-
-            //
-            // == MUTATION SAFE ==
-            //
-
-            for cid in verified_remove_hashes.iter() {
-                Blacklist::remove(cid);
-            }
-
-            for cid in verified_add_hashes.iter() {
-                Blacklist::insert(cid, ());
-            }
-
-            CurrentBlacklistSize::put(updated_blacklist_size);
-
-            Self::deposit_event(RawEvent::UpdateBlacklist(remove_hashes, add_hashes));
+            Self::update_content_blacklist(remove_hashes, add_hashes)?;
         }
 
         /// Create storage bucket.
@@ -2077,6 +2589,8 @@ decl_module! {
         /// - `V` is the number of items in `remove_buckets`
         /// - DB:
         ///    - `O(V + W)` - from the the generated weights
+        ///    - the shard bucket replacement of the erasure coded bag also resets the acceptance
+        ///      of its data objects (not covered by the generated weights)
         /// # </weight>
         #[weight = WeightInfoStorage::<T>::update_storage_buckets_for_bag(
             add_buckets.len().saturated_into(),
@@ -2102,32 +2616,66 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
-            // Update vouchers.
-            if !add_buckets.is_empty() {
-                Self::change_storage_buckets_vouchers(
-                    &add_buckets,
-                    &voucher_update,
-                    OperationType::Increase
-                );
-            }
-            if !remove_buckets.is_empty() {
-                Self::change_storage_buckets_vouchers(
-                    &remove_buckets,
-                    &voucher_update,
-                    OperationType::Decrease
+            Self::perform_storage_buckets_update_for_bag(
+                &bag_id,
+                &add_buckets,
+                &remove_buckets,
+                &voucher_update,
+            )?;
+
+            Self::deposit_event(
+                RawEvent::StorageBucketsUpdatedForBag(bag_id, add_buckets, remove_buckets)
+            );
+        }
+
+        /// Updates the storage policy of the bag. The bag must contain no data objects.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::update_bag_storage_policy()]
+        pub fn update_bag_storage_policy(
+            origin,
+            bag_id: BagId<T>,
+            storage_policy: BagStoragePolicy,
+        ) {
+            <T as Config>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+
+            let bag = Self::ensure_bag_exists(&bag_id)?;
+
+            ensure!(
+                bag.objects_number == 0,
+                Error::<T>::BagStoragePolicyCannotBeChangedForNonEmptyBag
+            );
+
+            if let BagStoragePolicy::ErasureCoding { data_shards, parity_shards } = storage_policy {
+                ensure!(data_shards > 0, Error::<T>::InvalidErasureCodingParameters);
+
+                let shards_number = data_shards
+                    .checked_add(parity_shards)
+                    .ok_or(Error::<T>::InvalidErasureCodingParameters)?;
+
+                ensure!(
+                    bag.stored_by.len().saturated_into::<u32>() == shards_number,
+                    Error::<T>::ErasureCodedBagBucketsNumberMismatch
                 );
             }
 
-            // Update bag counters.
-            Self::change_bag_assignments_for_storage_buckets(&add_buckets, &remove_buckets);
+            //
+            // == MUTATION SAFE ==
+            //
 
-            Bags::<T>::try_mutate(&bag_id, |bag| {
-                bag.update_storage_buckets::<T>(&mut add_buckets.clone(), &remove_buckets)
-            })?;
+            <BagStoragePolicies<T>>::insert(&bag_id, storage_policy);
 
-            Self::deposit_event(
-                RawEvent::StorageBucketsUpdatedForBag(bag_id, add_buckets, remove_buckets)
-            );
+            match Self::create_bag_shard_layout(&storage_policy, &bag.stored_by) {
+                Some(layout) => <BagShardLayouts<T>>::insert(&bag_id, layout),
+                None => <BagShardLayouts<T>>::remove(&bag_id),
+            }
+
+            Self::deposit_event(RawEvent::BagStoragePolicyUpdated(bag_id, storage_policy));
         }
 
         /// Cancel pending storage bucket invite. An invitation must be pending.
@@ -2223,6 +2771,8 @@ decl_module! {
                 operator_status:StorageBucketOperatorStatus::Missing,
                 ..bucket
             });
+            <AssignableStorageBuckets<T>>::remove(storage_bucket_id);
+            <StorageBucketOperatorTags<T>>::remove(storage_bucket_id);
 
             Self::deposit_event(
                 RawEvent::StorageBucketOperatorRemoved(storage_bucket_id)
@@ -2251,10 +2801,13 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
-            <StorageBucketById<T>>::insert(storage_bucket_id, StorageBucket::<T> {
+            let bucket = StorageBucket::<T> {
                 accepting_new_bags,
                 ..bucket
-            });
+            };
+
+            Self::update_assignable_storage_bucket(&storage_bucket_id, &bucket);
+            <StorageBucketById<T>>::insert(storage_bucket_id, bucket);
 
             Self::deposit_event(
                 RawEvent::StorageBucketStatusUpdated(storage_bucket_id, accepting_new_bags)
@@ -2312,7 +2865,97 @@ decl_module! {
             );
         }
 
-        // ===== Storage Operator actions =====
+        /// Updates storage bucket tags preferred on the automatic bucket assignment for the
+        /// given dynamic bag type.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the number of items in `tags`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::update_preferred_tags_in_dynamic_bag_creation_policy(
+            tags.len().saturated_into()
+        )]
+        pub fn update_preferred_tags_in_dynamic_bag_creation_policy(
+            origin,
+            dynamic_bag_type: DynamicBagType,
+            tags: BTreeSet<Vec<u8>>,
+        ) {
+            <T as Config>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+
+            let tags = Self::ensure_valid_storage_bucket_tags(&tags)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            PreferredStorageBucketTags::insert(dynamic_bag_type, tags.clone());
+
+            Self::deposit_event(
+                RawEvent::PreferredTagsInDynamicBagCreationPolicyUpdated(dynamic_bag_type, tags)
+            );
+        }
+
+        /// Moves the bag from up to `max_replacements` of its most loaded storage buckets to
+        /// less loaded ones. A bucket is replaced only if the candidate bucket load after
+        /// accepting the bag stays below the replaced bucket load.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W + B)` where:
+        /// - `W` is the `max_replacements` value
+        /// - `B` is the `MaxStorageBucketsScannedOnAssignment` value
+        /// - DB:
+        ///    - `O(W + B)` - from the the generated weights
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::rebalance_storage_buckets_for_bag(
+            (*max_replacements).min(T::MaxStorageBucketsPerBag::get()),
+            T::MaxStorageBucketsScannedOnAssignment::get(),
+        )]
+        pub fn rebalance_storage_buckets_for_bag(
+            origin,
+            bag_id: BagId<T>,
+            max_replacements: u32,
+        ) {
+            <T as Config>::StorageWorkingGroup::ensure_leader_origin(origin)?;
+
+            ensure!(
+                max_replacements > 0 && max_replacements <= T::MaxStorageBucketsPerBag::get(),
+                Error::<T>::InvalidStorageBucketsRebalanceBatchSize
+            );
+
+            let bag = Self::ensure_bag_exists(&bag_id)?;
+
+            let (add_buckets, remove_buckets) =
+                Self::get_storage_buckets_rebalance_for_bag(&bag_id, &bag, max_replacements);
+
+            ensure!(!add_buckets.is_empty(), Error::<T>::NoStorageBucketsToRebalanceBag);
+
+            let voucher_update = Self::validate_update_storage_buckets_for_bag_params(
+                &bag_id,
+                &add_buckets,
+                &remove_buckets,
+            )?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::perform_storage_buckets_update_for_bag(
+                &bag_id,
+                &add_buckets,
+                &remove_buckets,
+                &voucher_update,
+            )?;
+
+            Self::deposit_event(
+                RawEvent::StorageBucketsUpdatedForBag(bag_id, add_buckets, remove_buckets)
+            );
+        }
+
+        // ===== Storage Operator actions =====
 
         /// Accept the storage bucket invitation. An invitation must match the worker_id parameter.
         /// It accepts an additional account ID (transactor) for accepting data objects to prevent
@@ -2341,14 +2984,17 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
-            <StorageBucketById<T>>::insert(storage_bucket_id, StorageBucket::<T> {
+            let bucket = StorageBucket::<T> {
                 operator_status:
                     StorageBucketOperatorStatus::StorageWorker(
                         worker_id,
                         transactor_account_id.clone()
                     ),
                 ..bucket
-            });
+            };
+
+            Self::update_assignable_storage_bucket(&storage_bucket_id, &bucket);
+            <StorageBucketById<T>>::insert(storage_bucket_id, bucket);
 
             Self::deposit_event(
                 RawEvent::StorageBucketInvitationAccepted(
@@ -2393,6 +3039,42 @@ decl_module! {
             );
         }
 
+        /// Sets storage operator tags (eg.: region). Tags are used to prefer the bucket on the
+        /// automatic bucket assignment for new dynamic bags.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is the number of items in `tags`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::set_storage_operator_tags(tags.len().saturated_into())]
+        pub fn set_storage_operator_tags(
+            origin,
+            worker_id: WorkerId<T>,
+            storage_bucket_id: T::StorageBucketId,
+            tags: BTreeSet<Vec<u8>>,
+        ) {
+            <T as Config>::StorageWorkingGroup::ensure_worker_origin(origin, &worker_id)?;
+
+            let bucket = Self::ensure_storage_bucket_exists(&storage_bucket_id)?;
+
+            Self::ensure_bucket_invitation_accepted(&bucket, worker_id)?;
+
+            let tags = Self::ensure_valid_storage_bucket_tags(&tags)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            StorageBucketOperatorTags::<T>::insert(storage_bucket_id, tags.clone());
+
+            Self::deposit_event(
+                RawEvent::StorageOperatorTagsSet(storage_bucket_id, worker_id, tags)
+            );
+        }
+
         /// A storage provider signals that the data object was successfully uploaded to its storage.
         /// <weight>
         ///
@@ -2429,11 +3111,37 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            let bag_shard_layout = Self::bag_shard_layout(&bag_id);
+
             // Accept data objects for a bag.
             for data_object_id in data_objects.iter() {
-                DataObjectsById::<T>::mutate(&bag_id, data_object_id, |data_object| {
-                    data_object.accepted = true;
-                });
+                // Erasure coded data objects are accepted once all of their shards are accepted.
+                let accepted = match (
+                    Self::data_object_shard_layout(&bag_id, data_object_id),
+                    &bag_shard_layout,
+                ) {
+                    (Some(mut layout), Some(bag_layout)) => {
+                        if let Some(shard_index) = bag_layout.shard_index(&storage_bucket_id) {
+                            // The map is bounded by the number of shards.
+                            let _ = layout
+                                .accepted_shards
+                                .try_insert(shard_index, storage_bucket_id);
+                        }
+
+                        let accepted = layout.all_shards_accepted(&bag_layout.shard_buckets);
+
+                        DataObjectShardLayouts::<T>::insert(&bag_id, data_object_id, layout);
+
+                        accepted
+                    }
+                    _ => true,
+                };
+
+                if accepted {
+                    DataObjectsById::<T>::mutate(&bag_id, data_object_id, |data_object| {
+                        data_object.accepted = true;
+                    });
+                }
             }
 
             Self::deposit_event(
@@ -2516,6 +3224,7 @@ decl_module! {
             Self::decrement_distribution_family_number()?;
 
             <DistributionBucketFamilyById<T>>::remove(family_id);
+            <DistributionBucketFamilyDescriptors<T>>::remove(family_id);
 
             Self::deposit_event(RawEvent::DistributionBucketFamilyDeleted(family_id));
         }
@@ -2630,6 +3339,7 @@ decl_module! {
                 bucket_id.distribution_bucket_family_id,
                 bucket_id.distribution_bucket_index
             );
+            <DistributionBucketDescriptors<T>>::remove(&bucket_id);
 
             Self::deposit_event(
                 RawEvent::DistributionBucketDeleted(bucket_id)
@@ -2924,6 +3634,8 @@ decl_module! {
                 }
             );
 
+            <DistributionOperatorEndpoints<T>>::remove(&bucket_id, operator_worker_id);
+
             Self::deposit_event(
                 RawEvent::DistributionBucketOperatorRemoved(bucket_id, operator_worker_id)
             );
@@ -2964,6 +3676,128 @@ decl_module! {
             );
         }
 
+        /// Set typed distribution bucket family descriptor: region, bandwidth tier and supported
+        /// protocols.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::set_distribution_bucket_family_descriptor()]
+        pub fn set_distribution_bucket_family_descriptor(
+            origin,
+            family_id: T::DistributionBucketFamilyId,
+            descriptor: DistributionDescriptorParameters,
+        ) {
+            <T as Config>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+
+            Self::ensure_distribution_bucket_family_exists(&family_id)?;
+
+            let descriptor = Self::ensure_valid_distribution_descriptor(descriptor)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <DistributionBucketFamilyDescriptors<T>>::insert(family_id, descriptor.clone());
+
+            Self::deposit_event(
+                RawEvent::DistributionBucketFamilyDescriptorSet(family_id, descriptor)
+            );
+        }
+
+        /// Set typed distribution bucket descriptor. It overrides the bucket family descriptor.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::set_distribution_bucket_descriptor()]
+        pub fn set_distribution_bucket_descriptor(
+            origin,
+            bucket_id: DistributionBucketId<T>,
+            descriptor: DistributionDescriptorParameters,
+        ) {
+            <T as Config>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+
+            Self::ensure_distribution_bucket_exists(&bucket_id)?;
+
+            let descriptor = Self::ensure_valid_distribution_descriptor(descriptor)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <DistributionBucketDescriptors<T>>::insert(&bucket_id, descriptor.clone());
+
+            Self::deposit_event(
+                RawEvent::DistributionBucketDescriptorSet(bucket_id, descriptor)
+            );
+        }
+
+        /// Updates the reward per gigabyte served by the distribution operators.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::update_distribution_reward_per_gigabyte()]
+        pub fn update_distribution_reward_per_gigabyte(origin, new_reward: BalanceOf<T>) {
+            <T as Config>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            DistributionRewardPerGigabyte::<T>::put(new_reward);
+
+            Self::deposit_event(RawEvent::DistributionRewardPerGigabyteUpdated(new_reward));
+        }
+
+        /// Rejects the fraudulent bandwidth report during the dispute period.
+        /// The report bond is slashed.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is size of `rationale` in kilobytes
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::reject_bandwidth_report(
+            to_kb(rationale.len().saturated_into())
+        )]
+        pub fn reject_bandwidth_report(
+            origin,
+            report_id: BandwidthReportId,
+            rationale: Vec<u8>,
+        ) {
+            <T as Config>::DistributionWorkingGroup::ensure_leader_origin(origin)?;
+
+            let report = Self::ensure_bandwidth_report_exists(report_id)?;
+
+            ensure!(
+                !Self::bandwidth_report_dispute_period_expired(&report),
+                Error::<T>::BandwidthReportDisputePeriodExpired
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            burn_from_usable::<T>(&StorageTreasury::<T>::module_account_id(), report.bond.amount)?;
+
+            Self::remove_bandwidth_report(report_id, &report);
+
+            Self::deposit_event(RawEvent::BandwidthReportRejected(report_id, rationale));
+        }
+
 
         // ===== Distribution Operator actions =====
 
@@ -3002,50 +3836,251 @@ decl_module! {
                 }
             )?;
 
-            //
-            // == MUTATION SAFE ==
-            //
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::deposit_event(
+                RawEvent::DistributionBucketInvitationAccepted(worker_id, bucket_id)
+            );
+        }
+
+        /// Set distribution operator metadata for the distribution bucket.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (W)` where:
+        /// - `W` is size of `metadata` in kilobytes
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight =
+            WeightInfoStorage::<T>::set_distribution_operator_metadata(
+                to_kb(metadata.len().saturated_into())
+            )
+        ]
+        pub fn set_distribution_operator_metadata(
+            origin,
+            worker_id: WorkerId<T>,
+            bucket_id: DistributionBucketId<T>,
+            metadata: Vec<u8>,
+        ) {
+            <T as Config>::DistributionWorkingGroup::ensure_worker_origin(origin, &worker_id)?;
+
+            let bucket = Self::ensure_distribution_bucket_exists(&bucket_id)?;
+
+            ensure!(
+                bucket.operators.contains(&worker_id),
+                Error::<T>::MustBeDistributionProviderOperatorForBucket
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::deposit_event(
+                RawEvent::DistributionBucketMetadataSet(worker_id, bucket_id, metadata)
+            );
+        }
+
+        /// Set distribution operator endpoint for the distribution bucket. Endpoints are used to
+        /// route the client requests.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::set_distribution_operator_endpoint()]
+        pub fn set_distribution_operator_endpoint(
+            origin,
+            worker_id: WorkerId<T>,
+            bucket_id: DistributionBucketId<T>,
+            endpoint: Vec<u8>,
+        ) {
+            <T as Config>::DistributionWorkingGroup::ensure_worker_origin(origin, &worker_id)?;
+
+            let bucket = Self::ensure_distribution_bucket_exists(&bucket_id)?;
+
+            ensure!(
+                bucket.operators.contains(&worker_id),
+                Error::<T>::MustBeDistributionProviderOperatorForBucket
+            );
+
+            let endpoint = DistributionOperatorEndpoint::try_from(endpoint)
+                .map_err(|_| Error::<T>::DistributionOperatorEndpointTooLong)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <DistributionOperatorEndpoints<T>>::insert(&bucket_id, worker_id, endpoint.clone());
+
+            Self::deposit_event(
+                RawEvent::DistributionOperatorEndpointSet(worker_id, bucket_id, endpoint)
+            );
+        }
+
+        /// Submits the aggregated bandwidth report for the bag served by the distribution bucket.
+        /// The reward is calculated using the current reward per gigabyte and settled after the
+        /// dispute period. Only one report per bucket and bag can be submitted within the report
+        /// period. The report bond is transferred to the storage treasury.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoStorage::<T>::submit_bandwidth_report()]
+        pub fn submit_bandwidth_report(
+            origin,
+            worker_id: WorkerId<T>,
+            bucket_id: DistributionBucketId<T>,
+            bag_id: BagId<T>,
+            bytes_served: u64,
+            receipts_root: Option<T::Hash>,
+        ) {
+            let account_id = ensure_signed(origin.clone())?;
+
+            <T as Config>::DistributionWorkingGroup::ensure_worker_origin(origin, &worker_id)?;
+
+            let bucket = Self::ensure_distribution_bucket_exists(&bucket_id)?;
+
+            ensure!(
+                bucket.operators.contains(&worker_id),
+                Error::<T>::MustBeDistributionProviderOperatorForBucket
+            );
+
+            let bag = Self::ensure_bag_exists(&bag_id)?;
+
+            ensure!(
+                bag.distributed_by.contains(&bucket_id),
+                Error::<T>::DistributionBucketIsNotBoundToBag
+            );
+
+            ensure!(bytes_served > 0, Error::<T>::ZeroBytesServedInBandwidthReport);
+
+            ensure!(
+                bytes_served <= T::MaxBytesServedPerBandwidthReport::get(),
+                Error::<T>::MaxBytesServedPerBandwidthReportExceeded
+            );
+
+            let now = frame_system::Pallet::<T>::block_number();
+
+            let report_key = (bucket_id.clone(), bag_id.clone());
+
+            if let Some(last_submitted_at) =
+                Self::last_bandwidth_report_submission(worker_id, &report_key)
+            {
+                ensure!(
+                    now >= last_submitted_at.saturating_add(T::BandwidthReportPeriod::get()),
+                    Error::<T>::BandwidthReportAlreadySubmittedInPeriod
+                );
+            }
+
+            let bond = T::BandwidthReportBond::get();
+
+            ensure!(
+                has_sufficient_balance_for_fees::<T>(&account_id, bond),
+                Error::<T>::InsufficientBalanceForBandwidthReportBond
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let locked_balance_used = pay_fee::<T>(
+                &account_id,
+                Some(&StorageTreasury::<T>::module_account_id()),
+                bond,
+            )?;
+
+            let repayable_bond = match locked_balance_used.is_zero() {
+                true => RepayableBloatBond::new(bond, None),
+                false => RepayableBloatBond::new(bond, Some(account_id.clone())),
+            };
+
+            let report = BandwidthReport::<T> {
+                worker_id,
+                bucket_id,
+                bag_id,
+                bytes_served,
+                receipts_root,
+                reward: Self::calculate_bandwidth_reward(bytes_served),
+                submitted_at: now,
+                bond_account_id: account_id,
+                bond: repayable_bond,
+            };
+
+            let report_id = Self::next_bandwidth_report_id();
 
-            Self::deposit_event(
-                RawEvent::DistributionBucketInvitationAccepted(worker_id, bucket_id)
-            );
+            NextBandwidthReportId::put(report_id.saturating_add(1));
+
+            BandwidthReports::<T>::insert(report_id, report.clone());
+
+            LastBandwidthReportSubmission::<T>::insert(worker_id, report_key, now);
+
+            Self::deposit_event(RawEvent::BandwidthReportSubmitted(report_id, report));
         }
 
-        /// Set distribution operator metadata for the distribution bucket.
+        /// Settles the bandwidth report after the dispute period: pays the reward from the
+        /// distribution working group budget to the operator reward account and returns the
+        /// report bond. The report of the operator that left the working group is pruned
+        /// without the reward payment.
         /// <weight>
         ///
         /// ## Weight
-        /// `O (W)` where:
-        /// - `W` is size of `metadata` in kilobytes
+        /// `O (1)`
         /// - DB:
         ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight =
-            WeightInfoStorage::<T>::set_distribution_operator_metadata(
-                to_kb(metadata.len().saturated_into())
-            )
-        ]
-        pub fn set_distribution_operator_metadata(
-            origin,
-            worker_id: WorkerId<T>,
-            bucket_id: DistributionBucketId<T>,
-            metadata: Vec<u8>,
-        ) {
-            <T as Config>::DistributionWorkingGroup::ensure_worker_origin(origin, &worker_id)?;
+        #[weight = WeightInfoStorage::<T>::settle_bandwidth_report()]
+        pub fn settle_bandwidth_report(origin, report_id: BandwidthReportId) {
+            ensure_signed(origin)?;
 
-            let bucket = Self::ensure_distribution_bucket_exists(&bucket_id)?;
+            let report = Self::ensure_bandwidth_report_exists(report_id)?;
 
             ensure!(
-                bucket.operators.contains(&worker_id),
-                Error::<T>::MustBeDistributionProviderOperatorForBucket
+                Self::bandwidth_report_dispute_period_expired(&report),
+                Error::<T>::BandwidthReportDisputePeriodNotExpired
+            );
+
+            let reward_account_id =
+                T::DistributionWorkingGroup::get_worker_reward_account_id(&report.worker_id);
+
+            ensure!(
+                reward_account_id.is_none()
+                    || T::DistributionWorkingGroup::get_budget() >= report.reward,
+                Error::<T>::InsufficientDistributionWorkingGroupBudget
             );
 
             //
             // == MUTATION SAFE ==
             //
 
+            report.bond.repay::<T>(
+                &StorageTreasury::<T>::module_account_id(),
+                &report.bond_account_id,
+                false,
+            )?;
+
+            Self::remove_bandwidth_report(report_id, &report);
+
+            let reward_account_id = match reward_account_id {
+                Some(reward_account_id) => reward_account_id,
+                None => {
+                    Self::deposit_event(RawEvent::BandwidthReportPruned(report_id));
+
+                    return Ok(());
+                }
+            };
+
+            T::DistributionWorkingGroup::try_withdraw(&reward_account_id, report.reward)?;
+
             Self::deposit_event(
-                RawEvent::DistributionBucketMetadataSet(worker_id, bucket_id, metadata)
+                RawEvent::BandwidthReportSettled(report_id, reward_account_id, report.reward)
             );
         }
 
@@ -3229,6 +4264,39 @@ impl<T: Config> DataObjectStorage<T> for Module<T> {
         Ok(())
     }
 
+    fn update_content_blacklist(
+        remove_hashes: BTreeSet<Vec<u8>>,
+        add_hashes: BTreeSet<Vec<u8>>,
+    ) -> DispatchResult {
+        let (verified_remove_hashes, verified_add_hashes, updated_blacklist_size) =
+            Self::validate_content_blacklist_update(&remove_hashes, &add_hashes)?;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        for cid in verified_remove_hashes.iter() {
+            Blacklist::remove(cid);
+        }
+
+        for cid in verified_add_hashes.iter() {
+            Blacklist::insert(cid, ());
+        }
+
+        CurrentBlacklistSize::put(updated_blacklist_size);
+
+        Self::deposit_event(RawEvent::UpdateBlacklist(remove_hashes, add_hashes));
+
+        Ok(())
+    }
+
+    fn can_update_content_blacklist(
+        remove_hashes: &BTreeSet<Vec<u8>>,
+        add_hashes: &BTreeSet<Vec<u8>>,
+    ) -> DispatchResult {
+        Self::validate_content_blacklist_update(remove_hashes, add_hashes).map(|_| ())
+    }
+
     fn upload_and_delete_data_objects(
         upload_parameters: UploadParameters<T>,
         objects_to_remove: BTreeSet<T::DataObjectId>,
@@ -3275,7 +4343,7 @@ impl<T: Config> DataObjectStorage<T> for Module<T> {
     }
 
     fn create_dynamic_bag(
-        params: DynBagCreationParameters<T>,
+        mut params: DynBagCreationParameters<T>,
     ) -> Result<(Bag<T>, BTreeSet<T::DataObjectId>), DispatchError> {
         if !params.object_creation_list.is_empty() {
             // ensure data object state bloat bond
@@ -3292,6 +4360,13 @@ impl<T: Config> DataObjectStorage<T> for Module<T> {
             );
         }
 
+        if params.storage_buckets.is_empty() {
+            params.storage_buckets = Self::pick_storage_buckets_for_dynamic_bag(
+                params.bag_id.clone().into(),
+                &params.object_creation_list,
+            )?;
+        }
+
         Self::validate_storage_buckets_for_dynamic_bag_type(
             params.bag_id.clone().into(),
             &params.storage_buckets,
@@ -3339,6 +4414,61 @@ impl<T: Config> DataObjectStorage<T> for Module<T> {
 }
 
 impl<T: Config> Module<T> {
+    /// Returns the `distributing` distribution buckets of the bag with their operator endpoints.
+    /// Buckets from the client region go first, then buckets are ordered by the bandwidth tier
+    /// (highest first) and by the bucket ID.
+    pub fn distribution_route(
+        bag_id: &BagId<T>,
+        client_region: &[u8],
+    ) -> Vec<DistributionRoute<T>> {
+        let mut routes = Self::bag(bag_id)
+            .distributed_by
+            .into_iter()
+            .filter_map(|bucket_id| {
+                let bucket = Self::distribution_bucket_by_family_id_by_index(
+                    bucket_id.distribution_bucket_family_id,
+                    bucket_id.distribution_bucket_index,
+                );
+
+                if !bucket.distributing {
+                    return None;
+                }
+
+                let descriptor = Self::distribution_bucket_descriptor(&bucket_id)
+                    .or_else(|| {
+                        Self::distribution_bucket_family_descriptor(
+                            bucket_id.distribution_bucket_family_id,
+                        )
+                    })
+                    .unwrap_or_default();
+
+                let endpoints = bucket
+                    .operators
+                    .iter()
+                    .filter_map(|worker_id| {
+                        Self::distribution_operator_endpoint(&bucket_id, worker_id)
+                    })
+                    .collect();
+
+                Some(DistributionRoute::<T> {
+                    bucket_id,
+                    descriptor,
+                    endpoints,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Stable sort keeps the bucket ID order within the same region and bandwidth tier.
+        routes.sort_by_key(|route| {
+            (
+                route.descriptor.region.as_slice() != client_region,
+                sp_std::cmp::Reverse(route.descriptor.bandwidth_tier),
+            )
+        });
+
+        routes
+    }
+
     // Increment distribution family number in the storage.
     fn increment_distribution_family_number() -> DispatchResult {
         let incremented = Self::distribution_bucket_family_number()
@@ -3528,6 +4658,20 @@ impl<T: Config> Module<T> {
             Error::<T>::StorageBucketPerBagLimitExceeded
         );
 
+        // Each storage bucket of the erasure coded bag stores a single shard of every data object.
+        if let Some(shards_number) = Self::bag_storage_policy(bag_id).shards_number() {
+            // Shards stored by the replaced storage bucket are moved to the new storage bucket.
+            ensure!(
+                bag.objects_number == 0 || (add_buckets.len() == 1 && remove_buckets.len() == 1),
+                Error::<T>::ErasureCodedBagStorageBucketsLocked
+            );
+
+            ensure!(
+                new_bucket_number == shards_number,
+                Error::<T>::ErasureCodedBagBucketsNumberMismatch
+            );
+        }
+
         for bucket_id in remove_buckets.iter() {
             ensure!(
                 <StorageBucketById<T>>::contains_key(bucket_id),
@@ -3554,9 +4698,13 @@ impl<T: Config> Module<T> {
             );
         }
 
+        // Storage buckets of the erasure coded bag store the data objects shards.
+        let objects_total_size = Self::bag_shard_layout(bag_id)
+            .map_or(bag.objects_total_size, |layout| layout.shards_total_size);
+
         let voucher_update = VoucherUpdate {
             objects_number: bag.objects_number,
-            objects_total_size: bag.objects_total_size,
+            objects_total_size,
         };
 
         Self::check_buckets_for_overflow(add_buckets, &voucher_update)?;
@@ -3583,6 +4731,13 @@ impl<T: Config> Module<T> {
         Self::ensure_bag_exists(src_bag_id)?;
         let dest_bag = Self::ensure_bag_exists(dest_bag_id)?;
 
+        for bag_id in [src_bag_id, dest_bag_id] {
+            ensure!(
+                Self::bag_storage_policy(bag_id) == BagStoragePolicy::Replication,
+                Error::<T>::ErasureCodedBagDataObjectsCannotBeMoved
+            );
+        }
+
         let mut bag_change = BagUpdate::<BalanceOf<T>>::default();
 
         for object_id in object_ids.iter() {
@@ -3596,6 +4751,34 @@ impl<T: Config> Module<T> {
         Ok(bag_change)
     }
 
+    // Validates the blacklist update. Returns hashes to remove (existing in the blacklist),
+    // hashes to add (nonexisting in the blacklist) and the updated blacklist size.
+    fn validate_content_blacklist_update(
+        remove_hashes: &BTreeSet<Vec<u8>>,
+        add_hashes: &BTreeSet<Vec<u8>>,
+    ) -> Result<(BTreeSet<Base58Multihash>, BTreeSet<Base58Multihash>, u64), DispatchError> {
+        // Get only hashes that exist in the blacklist.
+        let verified_remove_hashes = Self::get_existing_hashes(remove_hashes)?;
+
+        // Get only hashes that doesn't exist in the blacklist.
+        let verified_add_hashes = Self::get_nonexisting_hashes(add_hashes)?;
+
+        let updated_blacklist_size: u64 = Self::current_blacklist_size()
+            .saturating_add(verified_add_hashes.len().saturated_into::<u64>())
+            .saturating_sub(verified_remove_hashes.len().saturated_into::<u64>());
+
+        ensure!(
+            updated_blacklist_size <= T::BlacklistSizeLimit::get(),
+            Error::<T>::BlacklistSizeLimitExceeded
+        );
+
+        Ok((
+            verified_remove_hashes,
+            verified_add_hashes,
+            updated_blacklist_size,
+        ))
+    }
+
     // Returns only existing hashes in the blacklist from the original collection.
     #[allow(clippy::redundant_closure)] // doesn't work with Substrate storage functions.
     fn get_existing_hashes(
@@ -3641,6 +4824,350 @@ impl<T: Config> Module<T> {
         Ok(filtered_cids)
     }
 
+    // Validates and converts raw storage bucket tags.
+    fn ensure_valid_storage_bucket_tags(
+        tags: &BTreeSet<Vec<u8>>,
+    ) -> Result<StorageBucketTags, DispatchError> {
+        let tags = tags
+            .iter()
+            .map(|tag| {
+                StorageBucketTag::try_from(tag.clone())
+                    .map_err(|_| DispatchError::from(Error::<T>::StorageBucketTagTooLong))
+            })
+            .collect::<Result<BTreeSet<_>, DispatchError>>()?;
+
+        StorageBucketTags::try_from(tags)
+            .map_err(|_| Error::<T>::MaxStorageBucketTagsExceeded.into())
+    }
+
+    // Ensures the existence of the bandwidth report.
+    fn ensure_bandwidth_report_exists(
+        report_id: BandwidthReportId,
+    ) -> Result<BandwidthReport<T>, Error<T>> {
+        Self::bandwidth_report(report_id).ok_or(Error::<T>::BandwidthReportDoesntExist)
+    }
+
+    // Removes the bandwidth report. The last submission record is removed as well once the
+    // report period is over: it no longer restricts the next submission.
+    fn remove_bandwidth_report(report_id: BandwidthReportId, report: &BandwidthReport<T>) {
+        BandwidthReports::<T>::remove(report_id);
+
+        let report_key = (report.bucket_id.clone(), report.bag_id.clone());
+        let report_period_end = report
+            .submitted_at
+            .saturating_add(T::BandwidthReportPeriod::get());
+
+        if Self::last_bandwidth_report_submission(report.worker_id, &report_key)
+            == Some(report.submitted_at)
+            && frame_system::Pallet::<T>::block_number() >= report_period_end
+        {
+            LastBandwidthReportSubmission::<T>::remove(report.worker_id, report_key);
+        }
+    }
+
+    // Whether the dispute period of the bandwidth report has expired.
+    fn bandwidth_report_dispute_period_expired(report: &BandwidthReport<T>) -> bool {
+        let dispute_period_end = report
+            .submitted_at
+            .saturating_add(T::BandwidthReportDisputePeriod::get());
+
+        frame_system::Pallet::<T>::block_number() >= dispute_period_end
+    }
+
+    // Calculates the bandwidth reward using the current reward per gigabyte.
+    fn calculate_bandwidth_reward(bytes_served: u64) -> BalanceOf<T> {
+        Self::distribution_reward_per_gigabyte()
+            .saturating_mul(bytes_served.saturated_into())
+            .checked_div(&BYTES_IN_GIGABYTE.saturated_into())
+            .unwrap_or_default()
+    }
+
+    // Validates and converts raw distribution descriptor.
+    fn ensure_valid_distribution_descriptor(
+        descriptor: DistributionDescriptorParameters,
+    ) -> Result<DistributionDescriptor, DispatchError> {
+        let region =
+            RegionCode::try_from(descriptor.region).map_err(|_| Error::<T>::RegionCodeTooLong)?;
+
+        let protocols = DistributionProtocols::try_from(descriptor.protocols)
+            .map_err(|_| Error::<T>::MaxDistributionProtocolsExceeded)?;
+
+        Ok(DistributionDescriptor {
+            region,
+            bandwidth_tier: descriptor.bandwidth_tier,
+            protocols,
+        })
+    }
+
+    // Returns storage bucket load: the greatest of the used size and used objects number ratios.
+    // Buckets with zero limits are considered full.
+    fn storage_bucket_load(voucher: &Voucher) -> Perbill {
+        Perbill::from_rational(voucher.size_used, voucher.size_limit).max(Perbill::from_rational(
+            voucher.objects_used,
+            voucher.objects_limit,
+        ))
+    }
+
+    // Keeps the storage bucket among the automatic bag assignment candidates while it accepts
+    // new bags and has an active operator.
+    fn update_assignable_storage_bucket(
+        storage_bucket_id: &T::StorageBucketId,
+        bucket: &StorageBucket<T>,
+    ) {
+        if Self::is_assignable_storage_bucket(bucket) {
+            <AssignableStorageBuckets<T>>::insert(storage_bucket_id, ());
+        } else {
+            <AssignableStorageBuckets<T>>::remove(storage_bucket_id);
+        }
+    }
+
+    // Defines whether the storage bucket accepts new bags and has an active operator.
+    pub(crate) fn is_assignable_storage_bucket(bucket: &StorageBucket<T>) -> bool {
+        bucket.accepting_new_bags
+            && matches!(
+                bucket.operator_status,
+                StorageBucketOperatorStatus::StorageWorker(..)
+            )
+    }
+
+    // Returns storage buckets able to store a bag with the given voucher update, ordered by
+    // preference: buckets matching more of the preferred tags go first, then the least loaded
+    // ones (load is calculated with the bag assigned). Only the buckets accepting new bags with
+    // an active operator are scanned, and a bucket qualifies if it has enough voucher capacity
+    // left. No more than MaxStorageBucketsScannedOnAssignment storage buckets are scanned.
+    fn get_storage_bucket_candidates(
+        voucher_update: &VoucherUpdate,
+        preferred_tags: &StorageBucketTags,
+        excluded_buckets: &BTreeSet<T::StorageBucketId>,
+    ) -> Vec<(T::StorageBucketId, Perbill)> {
+        let mut candidates = AssignableStorageBuckets::<T>::iter_keys()
+            .filter(|id| !excluded_buckets.contains(id))
+            .take(T::MaxStorageBucketsScannedOnAssignment::get() as usize)
+            .filter_map(|id| Self::storage_bucket_by_id(id).map(|bucket| (id, bucket)))
+            .filter_map(|(id, bucket)| {
+                let voucher = Voucher {
+                    objects_used: bucket
+                        .voucher
+                        .objects_used
+                        .checked_add(voucher_update.objects_number)?,
+                    size_used: bucket
+                        .voucher
+                        .size_used
+                        .checked_add(voucher_update.objects_total_size)?,
+                    ..bucket.voucher
+                };
+
+                let fits = voucher.objects_used <= voucher.objects_limit
+                    && voucher.size_used <= voucher.size_limit;
+
+                fits.then(|| {
+                    let matched_tags = Self::storage_bucket_operator_tags(id)
+                        .iter()
+                        .filter(|tag| preferred_tags.contains(tag))
+                        .count();
+
+                    (id, matched_tags, Self::storage_bucket_load(&voucher))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        candidates.sort_by(|(id1, tags1, load1), (id2, tags2, load2)| {
+            tags2
+                .cmp(tags1)
+                .then_with(|| load1.cmp(load2))
+                .then_with(|| id1.cmp(id2))
+        });
+
+        candidates
+            .into_iter()
+            .map(|(id, _, load)| (id, load))
+            .collect()
+    }
+
+    // Selects storage buckets for a new dynamic bag according to the dynamic bag creation policy
+    // (number of buckets and preferred tags) and the data objects to upload.
+    pub(crate) fn pick_storage_buckets_for_dynamic_bag(
+        dynamic_bag_type: DynamicBagType,
+        object_creation_list: &[DataObjectCreationParameters],
+    ) -> Result<BTreeSet<T::StorageBucketId>, DispatchError> {
+        let creation_policy = Self::get_dynamic_bag_creation_policy(dynamic_bag_type);
+        let number_of_storage_buckets = creation_policy.number_of_storage_buckets as usize;
+
+        let voucher_update = object_creation_list
+            .iter()
+            .fold(VoucherUpdate::default(), |update, obj| {
+                update.add_object(obj.size)
+            });
+
+        let selected_buckets = Self::get_storage_bucket_candidates(
+            &voucher_update,
+            &Self::preferred_storage_bucket_tags(dynamic_bag_type),
+            &BTreeSet::new(),
+        )
+        .into_iter()
+        .take(number_of_storage_buckets)
+        .map(|(id, _)| id)
+        .collect::<BTreeSet<_>>();
+
+        ensure!(
+            selected_buckets.len() == number_of_storage_buckets,
+            Error::<T>::NotEnoughStorageBucketsForAutomaticAssignment
+        );
+
+        Ok(selected_buckets)
+    }
+
+    // Calculates storage buckets to add and to remove on the bag rebalancing. The most loaded
+    // bag buckets are paired with the best candidates and replaced only if the candidate
+    // stays less loaded than the replaced bucket.
+    fn get_storage_buckets_rebalance_for_bag(
+        bag_id: &BagId<T>,
+        bag: &Bag<T>,
+        max_replacements: u32,
+    ) -> (BTreeSet<T::StorageBucketId>, BTreeSet<T::StorageBucketId>) {
+        let voucher_update = VoucherUpdate {
+            objects_number: bag.objects_number,
+            objects_total_size: bag.objects_total_size,
+        };
+
+        let preferred_tags = match bag_id {
+            BagId::<T>::Dynamic(dynamic_bag_id) => {
+                let dynamic_bag_type: DynamicBagType = dynamic_bag_id.clone().into();
+                Self::preferred_storage_bucket_tags(dynamic_bag_type)
+            }
+            BagId::<T>::Static(_) => Default::default(),
+        };
+
+        let mut current_buckets = bag
+            .stored_by
+            .iter()
+            .filter_map(|id| {
+                Self::storage_bucket_by_id(id)
+                    .map(|bucket| (*id, Self::storage_bucket_load(&bucket.voucher)))
+            })
+            .collect::<Vec<_>>();
+        current_buckets
+            .sort_by(|(id1, load1), (id2, load2)| load2.cmp(load1).then_with(|| id1.cmp(id2)));
+
+        let candidates = Self::get_storage_bucket_candidates(
+            &voucher_update,
+            &preferred_tags,
+            &bag.stored_by.clone().into_inner(),
+        );
+
+        current_buckets
+            .into_iter()
+            .zip(candidates)
+            .take(max_replacements as usize)
+            .filter(|((_, current_load), (_, candidate_load))| candidate_load < current_load)
+            .map(|((removed_id, _), (added_id, _))| (added_id, removed_id))
+            .unzip()
+    }
+
+    // Updates bag storage buckets along with their vouchers and bag assignment counters.
+    fn perform_storage_buckets_update_for_bag(
+        bag_id: &BagId<T>,
+        add_buckets: &BTreeSet<T::StorageBucketId>,
+        remove_buckets: &BTreeSet<T::StorageBucketId>,
+        voucher_update: &VoucherUpdate,
+    ) -> DispatchResult {
+        // Update vouchers.
+        if !add_buckets.is_empty() {
+            Self::change_storage_buckets_vouchers(
+                add_buckets,
+                voucher_update,
+                OperationType::Increase,
+            );
+        }
+        if !remove_buckets.is_empty() {
+            Self::change_storage_buckets_vouchers(
+                remove_buckets,
+                voucher_update,
+                OperationType::Decrease,
+            );
+        }
+
+        // Update bag counters.
+        Self::change_bag_assignments_for_storage_buckets(add_buckets, remove_buckets);
+
+        let bag = Bags::<T>::try_mutate(bag_id, |bag| {
+            bag.update_storage_buckets::<T>(&mut add_buckets.clone(), remove_buckets)
+                .map(|_| bag.clone())
+        })?;
+
+        if let Some(layout) = Self::bag_shard_layout(bag_id) {
+            BagShardLayouts::<T>::insert(
+                bag_id,
+                Self::update_bag_shard_buckets(
+                    bag_id,
+                    layout,
+                    &bag.stored_by,
+                    add_buckets,
+                    remove_buckets,
+                ),
+            );
+        }
+
+        Ok(())
+    }
+
+    // Updates the storage buckets assigned to the erasure coded bag shards. A single replaced
+    // storage bucket passes its shard index to the new storage bucket: the acceptance of the
+    // data objects shards with this index and of the data objects themselves is reset.
+    // Otherwise (the bag has no data objects) shards are reassigned to the bag storage buckets.
+    fn update_bag_shard_buckets(
+        bag_id: &BagId<T>,
+        mut layout: BagShardLayout<T>,
+        stored_by: &StorageBucketIdsSet<T>,
+        add_buckets: &BTreeSet<T::StorageBucketId>,
+        remove_buckets: &BTreeSet<T::StorageBucketId>,
+    ) -> BagShardLayout<T> {
+        let replacement = match (add_buckets.len(), remove_buckets.len()) {
+            (1, 1) => add_buckets.iter().next().zip(
+                remove_buckets
+                    .iter()
+                    .next()
+                    .and_then(|bucket_id| layout.shard_index(bucket_id)),
+            ),
+            _ => None,
+        };
+
+        match replacement {
+            Some((new_bucket_id, shard_index)) => {
+                if let Some(bucket_id) = layout
+                    .shard_buckets
+                    .get_mut(shard_index.saturated_into::<usize>())
+                {
+                    *bucket_id = *new_bucket_id;
+                }
+
+                Self::reset_data_objects_shard_acceptance(bag_id, shard_index);
+            }
+            None => {
+                // The number of bag storage buckets never exceeds its bound.
+                if let Ok(shard_buckets) = stored_by.iter().cloned().collect::<Vec<_>>().try_into()
+                {
+                    layout.shard_buckets = shard_buckets;
+                }
+            }
+        }
+
+        layout
+    }
+
+    // Resets the acceptance of the bag data objects shards with the provided index: the data
+    // objects become pending until the new storage bucket accepts their shards.
+    fn reset_data_objects_shard_acceptance(bag_id: &BagId<T>, shard_index: u32) {
+        for (data_object_id, mut layout) in DataObjectShardLayouts::<T>::iter_prefix(bag_id) {
+            layout.accepted_shards.remove(&shard_index);
+            DataObjectShardLayouts::<T>::insert(bag_id, data_object_id, layout);
+
+            DataObjectsById::<T>::mutate(bag_id, data_object_id, |data_object| {
+                data_object.accepted = false;
+            });
+        }
+    }
+
     // Ensure the new bucket could be created. It also validates some parameters.
     fn can_create_storage_bucket(
         voucher: &Voucher,
@@ -4347,13 +5874,24 @@ impl<T: Config> Module<T> {
         let upload_objs_num = objects_to_upload.len() as u64;
         let remove_objs_num = objects_to_remove.len() as u64;
 
+        // Storage bucket vouchers are charged by the size stored by a single bucket.
+        let storage_policy = Self::bag_storage_policy(&bag_id);
+        let bucket_upload_objs_size = Self::get_bucket_objects_size(
+            &storage_policy,
+            objects_to_upload.iter().map(|obj| obj.size),
+        );
+        let bucket_remove_objs_size = Self::get_bucket_objects_size(
+            &storage_policy,
+            remove_objs.iter().map(|(_, obj)| obj.size),
+        );
+
         // Get updated storage buckets: vouchers
         let updated_storage_buckets = Self::get_updated_storage_buckets_bag_update(
             &bag.stored_by,
             upload_objs_num,
-            upload_objs_size,
+            bucket_upload_objs_size,
             remove_objs_num,
-            remove_objs_size,
+            bucket_remove_objs_size,
         )?;
 
         // check that user or treasury account have enough balance
@@ -4383,17 +5921,32 @@ impl<T: Config> Module<T> {
         let module_account_id = StorageTreasury::<T>::module_account_id();
         for (id, obj) in remove_objs {
             DataObjectsById::<T>::remove(&bag_id, id);
+            DataObjectShardLayouts::<T>::remove(&bag_id, id);
             // repay the bloat bond
             obj.state_bloat_bond
                 .repay::<T>(&module_account_id, &account_id, false)?;
         }
 
+        BagShardLayouts::<T>::mutate(&bag_id, |layout| {
+            if let Some(layout) = layout {
+                layout.shards_total_size = layout
+                    .shards_total_size
+                    .saturating_add(bucket_upload_objs_size)
+                    .saturating_sub(bucket_remove_objs_size);
+            }
+        });
+
         // Add data objects
         let created_objects_ids: BTreeSet<T::DataObjectId> = objects_to_insert
             .iter()
             .map(|obj| {
                 let obj_id = NextDataObjectId::<T>::get();
                 DataObjectsById::<T>::insert(&bag_id, obj_id, obj);
+                if let Some(layout) =
+                    Self::create_data_object_shard_layout(&storage_policy, obj.size)
+                {
+                    DataObjectShardLayouts::<T>::insert(&bag_id, obj_id, layout);
+                }
                 NextDataObjectId::<T>::put(obj_id.saturating_add(One::one()));
                 obj_id
             })
@@ -4422,12 +5975,16 @@ impl<T: Config> Module<T> {
         let bag = Self::ensure_bag_exists(&bag_id)?;
         let (remove_objs, remove_objs_size) = Self::validate_objects_to_remove(&bag_id, None)?;
         let remove_objs_num = bag.objects_number;
+        let bucket_remove_objs_size = Self::get_bucket_objects_size(
+            &Self::bag_storage_policy(&bag_id),
+            remove_objs.iter().map(|(_, obj)| obj.size),
+        );
 
         // Get updated storage buckets: vouchers and bag counters
         let updated_storage_buckets = Self::get_updated_storage_buckets_bag_removal(
             &bag.stored_by,
             remove_objs_num,
-            remove_objs_size,
+            bucket_remove_objs_size,
         )?;
         // Get updated distribution buckets: bag counters
         let updated_distribution_buckets =
@@ -4456,6 +6013,7 @@ impl<T: Config> Module<T> {
         let module_account_id = StorageTreasury::<T>::module_account_id();
         for (id, obj) in remove_objs {
             DataObjectsById::<T>::remove(&bag_id, id);
+            DataObjectShardLayouts::<T>::remove(&bag_id, id);
             // Repay the bloat bond
             obj.state_bloat_bond
                 .repay::<T>(&module_account_id, account_id, false)?;
@@ -4463,10 +6021,57 @@ impl<T: Config> Module<T> {
 
         // Remove bag
         Bags::<T>::remove(&bag_id);
+        BagStoragePolicies::<T>::remove(&bag_id);
+        BagShardLayouts::<T>::remove(&bag_id);
 
         Ok(())
     }
 
+    // Sums the sizes stored by a single bag storage bucket for the data objects of given sizes.
+    fn get_bucket_objects_size(
+        storage_policy: &BagStoragePolicy,
+        objects_sizes: impl Iterator<Item = u64>,
+    ) -> u64 {
+        objects_sizes.fold(0u64, |acc, size| {
+            acc.saturating_add(storage_policy.bucket_object_size(size))
+        })
+    }
+
+    // Creates the shard layout for the erasure coded bag: shards are assigned to the bag storage
+    // buckets one per bucket.
+    fn create_bag_shard_layout(
+        storage_policy: &BagStoragePolicy,
+        stored_by: &StorageBucketIdsSet<T>,
+    ) -> Option<BagShardLayout<T>> {
+        storage_policy.shards_number()?;
+
+        // The number of bag storage buckets never exceeds its bound.
+        let shard_buckets = stored_by
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>()
+            .try_into()
+            .ok()?;
+
+        Some(BagShardLayout::<T> {
+            shard_buckets,
+            shards_total_size: 0,
+        })
+    }
+
+    // Creates the shard layout for the data object stored in the erasure coded bag.
+    fn create_data_object_shard_layout(
+        storage_policy: &BagStoragePolicy,
+        size: u64,
+    ) -> Option<DataObjectShardLayout<T>> {
+        storage_policy.shards_number()?;
+
+        Some(DataObjectShardLayout::<T> {
+            shard_size: storage_policy.bucket_object_size(size),
+            accepted_shards: Default::default(),
+        })
+    }
+
     //Sums the accumulated obj_state_bloat_bond and size to the new object in the iteration.
     fn calculate_acc_size_and_acc_obj_state_bloat_bond(
         mut acc_obj: Vec<DataObjectOf<T>>,
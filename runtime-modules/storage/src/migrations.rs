@@ -0,0 +1,68 @@
+// Migrations for Storage Pallet
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+use frame_support::weights::Weight;
+
+pub mod v1 {
+    use super::*;
+
+    /// Collects the storage buckets accepting new bags with an active operator as the
+    /// candidates for the automatic bag assignment.
+    pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            let onchain = Module::<T>::on_chain_storage_version();
+
+            ensure!(onchain < 1, "this migration can be deleted");
+
+            let assignable_bucket_count = StorageBucketById::<T>::iter_values()
+                .filter(|bucket| Module::<T>::is_assignable_storage_bucket(bucket))
+                .count() as u64;
+
+            Ok(assignable_bucket_count.encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let onchain = Module::<T>::on_chain_storage_version();
+            let current = Module::<T>::current_storage_version();
+
+            if onchain > 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let mut scanned = 0u64;
+            let mut assignable = 0u64;
+
+            StorageBucketById::<T>::iter().for_each(|(bucket_id, bucket)| {
+                scanned = scanned.saturating_add(1);
+
+                if Module::<T>::is_assignable_storage_bucket(&bucket) {
+                    assignable = assignable.saturating_add(1);
+                    <AssignableStorageBuckets<T>>::insert(bucket_id, ());
+                }
+            });
+
+            current.put::<Module<T>>();
+
+            T::DbWeight::get().reads_writes(scanned.saturating_add(1), assignable.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let onchain = Module::<T>::on_chain_storage_version();
+            ensure!(onchain == 1, "this migration needs to be run");
+
+            let pre_upgrade_assignable_bucket_count =
+                u64::decode(&mut state.as_slice()).map_err(|_| "invalid pre_upgrade state")?;
+            let assignable_bucket_count = AssignableStorageBuckets::<T>::iter_keys().count() as u64;
+            ensure!(
+                pre_upgrade_assignable_bucket_count == assignable_bucket_count,
+                "assignable storage bucket counts differ post_upgrade"
+            );
+
+            Ok(())
+        }
+    }
+}
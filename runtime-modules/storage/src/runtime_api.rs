@@ -0,0 +1,17 @@
+//! Runtime API of the storage pallet.
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Storage pallet runtime API.
+    pub trait StorageApi<BagId, DistributionRoute>
+    where
+        BagId: Codec,
+        DistributionRoute: Codec,
+    {
+        /// Returns the `distributing` distribution buckets of the bag with their operator
+        /// endpoints ordered by the preference for the client region.
+        fn distribution_route(bag_id: BagId, client_region: Vec<u8>) -> Vec<DistributionRoute>;
+    }
+}
@@ -6,26 +6,33 @@ use frame_support::storage::StorageMap;
 use frame_support::traits::{Currency, OnFinalize, OnInitialize};
 use frame_support::{assert_noop, assert_ok};
 use frame_system::{EventRecord, Phase, RawOrigin};
+use sp_core::H256;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::iter::FromIterator;
 
+use common::working_group::WorkingGroupAuthenticator;
+
 use crate::sp_api_hidden_includes_decl_storage::hidden_include::{
     IterableStorageDoubleMap, StorageDoubleMap, StorageValue,
 };
 
 use super::mocks::{
-    create_cid, Balances, CollectiveFlip, RuntimeEvent as TestEvent, Storage, System, Test,
-    DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID, DEFAULT_MEMBER_ACCOUNT_ID, DEFAULT_MEMBER_ID,
+    create_cid, Balances, BandwidthReportBond, CollectiveFlip, DistributionWG,
+    RuntimeEvent as TestEvent, Storage, System, Test, DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID,
+    DEFAULT_DISTRIBUTION_PROVIDER_ID, DEFAULT_MEMBER_ACCOUNT_ID, DEFAULT_MEMBER_ID,
     DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT, DEFAULT_STORAGE_BUCKET_SIZE_LIMIT,
-    DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID, DISTRIBUTION_WG_LEADER_ACCOUNT_ID,
-    STORAGE_WG_LEADER_ACCOUNT_ID, VOUCHER_OBJECTS_LIMIT, VOUCHER_SIZE_LIMIT,
+    DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID, DEFAULT_STORAGE_PROVIDER_ID,
+    DISTRIBUTION_WG_LEADER_ACCOUNT_ID, STORAGE_WG_LEADER_ACCOUNT_ID, VOUCHER_OBJECTS_LIMIT,
+    VOUCHER_SIZE_LIMIT,
 };
 
 use crate::{
-    BagId, DataObjectCreationParameters, DataObjectPerMegabyteFee, DataObjectStateBloatBondValue,
-    DataObjectStorage, DistributionBucket, DistributionBucketId, DynBagCreationParameters,
-    DynamicBagId, DynamicBagType, RawEvent, StorageBucketOperatorStatus, UploadParameters,
+    BagId, BagStoragePolicy, BandwidthReport, BandwidthReportId, DataObjectCreationParameters,
+    DataObjectPerMegabyteFee, DataObjectStateBloatBondValue, DataObjectStorage, DistributionBucket,
+    DistributionBucketId, DistributionDescriptorParameters, DynBagCreationParameters, DynamicBagId,
+    DynamicBagType, ModuleAccount, RawEvent, StaticBagId, StorageBucketOperatorStatus,
+    StorageTreasury, UploadParameters,
 };
 
 // Recommendation from Parity on testing on_finalize
@@ -46,6 +53,10 @@ pub fn increase_account_balance(account_id: &u64, balance: u64) {
     let _ = Balances::deposit_creating(account_id, balance);
 }
 
+pub fn treasury_account_id() -> u64 {
+    <StorageTreasury<Test>>::module_account_id()
+}
+
 pub fn set_data_object_per_mega_byte_fee(mb_fee: u64) {
     DataObjectPerMegabyteFee::<Test>::put(mb_fee);
 }
@@ -151,6 +162,7 @@ impl EventFixture {
             DistributionBucketId<Test>,
             u64,
             DynBagCreationParameters<Test>,
+            BandwidthReport<Test>,
         >,
     ) {
         let converted_event = TestEvent::Storage(expected_raw_event);
@@ -172,6 +184,7 @@ impl EventFixture {
             DistributionBucketId<Test>,
             u64,
             DynBagCreationParameters<Test>,
+            BandwidthReport<Test>,
         >,
     ) {
         let converted_event = TestEvent::Storage(expected_raw_event);
@@ -371,6 +384,14 @@ impl UploadFixture {
             .object_creation_list
             .iter()
             .fold(0u64, |acc, param| acc.saturating_add(param.size));
+        let storage_policy = Storage::bag_storage_policy(&self.params.bag_id);
+        let bucket_size_added = self
+            .params
+            .object_creation_list
+            .iter()
+            .fold(0u64, |acc, param| {
+                acc.saturating_add(storage_policy.bucket_object_size(param.size))
+            });
         let total_number_added = self.params.object_creation_list.len() as u64;
         let upload_fee = Storage::calculate_data_storage_fee(total_size_added);
 
@@ -418,7 +439,7 @@ impl UploadFixture {
                 .zip(buckets_post.iter())
                 .all(
                     |(pre, post)| post.voucher.size_used.saturating_sub(pre.voucher.size_used)
-                        == total_size_added
+                        == bucket_size_added
                 ));
 
             // storage bucket voucher have obj number increased
@@ -530,6 +551,154 @@ impl SetStorageOperatorMetadataFixture {
     }
 }
 
+#[derive(Fixture, new)]
+pub struct SetStorageOperatorTagsFixture {
+    #[new(value = "RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(value = "DEFAULT_STORAGE_PROVIDER_ID")]
+    worker_id: u64,
+
+    #[new(default)]
+    storage_bucket_id: u64,
+
+    #[new(default)]
+    tags: BTreeSet<Vec<u8>>,
+}
+
+impl SetStorageOperatorTagsFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_tags = Storage::storage_bucket_operator_tags(self.storage_bucket_id);
+
+        let actual_result = Storage::set_storage_operator_tags(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.storage_bucket_id,
+            self.tags.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_tags = Storage::storage_bucket_operator_tags(self.storage_bucket_id);
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_tags
+                    .iter()
+                    .map(|tag| tag.to_vec())
+                    .collect::<BTreeSet<_>>(),
+                self.tags
+            );
+        } else {
+            assert_eq!(old_tags, new_tags);
+        }
+    }
+}
+
+#[derive(Fixture, new)]
+pub struct UpdatePreferredTagsInDynamicBagCreationPolicyFixture {
+    #[new(value = "RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(value = "DynamicBagType::Member")]
+    dynamic_bag_type: DynamicBagType,
+
+    #[new(default)]
+    tags: BTreeSet<Vec<u8>>,
+}
+
+impl UpdatePreferredTagsInDynamicBagCreationPolicyFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_tags = Storage::preferred_storage_bucket_tags(self.dynamic_bag_type);
+
+        let actual_result = Storage::update_preferred_tags_in_dynamic_bag_creation_policy(
+            self.origin.clone().into(),
+            self.dynamic_bag_type,
+            self.tags.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_tags = Storage::preferred_storage_bucket_tags(self.dynamic_bag_type);
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_tags
+                    .iter()
+                    .map(|tag| tag.to_vec())
+                    .collect::<BTreeSet<_>>(),
+                self.tags
+            );
+        } else {
+            assert_eq!(old_tags, new_tags);
+        }
+    }
+}
+
+#[derive(Fixture, new)]
+pub struct RebalanceStorageBucketsForBagFixture {
+    #[new(value = "RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(default)]
+    bag_id: BagId<Test>,
+
+    #[new(value = "1")]
+    max_replacements: u32,
+}
+
+impl RebalanceStorageBucketsForBagFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_bag = Storage::bag(&self.bag_id);
+
+        let actual_result = Storage::rebalance_storage_buckets_for_bag(
+            self.origin.clone().into(),
+            self.bag_id.clone(),
+            self.max_replacements,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_bag = Storage::bag(&self.bag_id);
+        if actual_result.is_ok() {
+            assert_eq!(old_bag.stored_by.len(), new_bag.stored_by.len());
+        } else {
+            assert_eq!(old_bag, new_bag);
+        }
+    }
+}
+
+#[derive(Fixture, new)]
+pub struct UpdateBagStoragePolicyFixture {
+    #[new(value = "RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(default)]
+    bag_id: BagId<Test>,
+
+    #[new(default)]
+    storage_policy: BagStoragePolicy,
+}
+
+impl UpdateBagStoragePolicyFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_policy = Storage::bag_storage_policy(&self.bag_id);
+
+        let actual_result = Storage::update_bag_storage_policy(
+            self.origin.clone().into(),
+            self.bag_id.clone(),
+            self.storage_policy,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_policy = Storage::bag_storage_policy(&self.bag_id);
+        if actual_result.is_ok() {
+            assert_eq!(new_policy, self.storage_policy);
+        } else {
+            assert_eq!(old_policy, new_policy);
+        }
+    }
+}
+
 #[derive(Fixture, new)]
 pub struct AcceptPendingDataObjectsFixture {
     #[new(value = "RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID)")]
@@ -1789,6 +1958,318 @@ impl SetDistributionBucketFamilyMetadataFixture {
     }
 }
 
+#[derive(new, Fixture)]
+pub struct SetDistributionBucketFamilyDescriptorFixture {
+    #[new(value = "RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(default)]
+    family_id: u64,
+
+    #[new(default)]
+    descriptor: DistributionDescriptorParameters,
+}
+
+impl SetDistributionBucketFamilyDescriptorFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_descriptor = Storage::distribution_bucket_family_descriptor(self.family_id);
+
+        let actual_result = Storage::set_distribution_bucket_family_descriptor(
+            self.origin.clone().into(),
+            self.family_id,
+            self.descriptor.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_descriptor = Storage::distribution_bucket_family_descriptor(self.family_id);
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_descriptor.map(|descriptor| descriptor.region.into_inner()),
+                Some(self.descriptor.region.clone())
+            );
+        } else {
+            assert_eq!(old_descriptor, new_descriptor);
+        }
+    }
+}
+
+#[derive(new, Fixture)]
+pub struct SetDistributionBucketDescriptorFixture {
+    #[new(value = "RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(default)]
+    family_id: u64,
+
+    #[new(default)]
+    bucket_index: u64,
+
+    #[new(default)]
+    descriptor: DistributionDescriptorParameters,
+}
+
+impl SetDistributionBucketDescriptorFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let bucket_id = Storage::create_distribution_bucket_id(self.family_id, self.bucket_index);
+        let old_descriptor = Storage::distribution_bucket_descriptor(&bucket_id);
+
+        let actual_result = Storage::set_distribution_bucket_descriptor(
+            self.origin.clone().into(),
+            bucket_id.clone(),
+            self.descriptor.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_descriptor = Storage::distribution_bucket_descriptor(&bucket_id);
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_descriptor.map(|descriptor| descriptor.region.into_inner()),
+                Some(self.descriptor.region.clone())
+            );
+        } else {
+            assert_eq!(old_descriptor, new_descriptor);
+        }
+    }
+}
+
+#[derive(new, Fixture)]
+pub struct SetDistributionOperatorEndpointFixture {
+    #[new(value = "RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(default)]
+    family_id: u64,
+
+    #[new(default)]
+    bucket_index: u64,
+
+    #[new(value = "DEFAULT_DISTRIBUTION_PROVIDER_ID")]
+    worker_id: u64,
+
+    #[new(default)]
+    endpoint: Vec<u8>,
+}
+
+impl SetDistributionOperatorEndpointFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let bucket_id = Storage::create_distribution_bucket_id(self.family_id, self.bucket_index);
+
+        let actual_result = Storage::set_distribution_operator_endpoint(
+            self.origin.clone().into(),
+            self.worker_id,
+            bucket_id.clone(),
+            self.endpoint.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                Storage::distribution_operator_endpoint(&bucket_id, self.worker_id)
+                    .map(|endpoint| endpoint.into_inner()),
+                Some(self.endpoint.clone())
+            );
+        }
+    }
+}
+
+#[derive(new, Fixture)]
+pub struct UpdateDistributionRewardPerGigabyteFixture {
+    #[new(value = "RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(default)]
+    new_reward: u64,
+}
+
+impl UpdateDistributionRewardPerGigabyteFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_reward = Storage::distribution_reward_per_gigabyte();
+
+        let actual_result = Storage::update_distribution_reward_per_gigabyte(
+            self.origin.clone().into(),
+            self.new_reward,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_reward = Storage::distribution_reward_per_gigabyte();
+        if actual_result.is_ok() {
+            assert_eq!(new_reward, self.new_reward);
+        } else {
+            assert_eq!(new_reward, old_reward);
+        }
+    }
+}
+
+#[derive(new, Fixture)]
+pub struct SubmitBandwidthReportFixture {
+    #[new(value = "RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(value = "DEFAULT_DISTRIBUTION_PROVIDER_ID")]
+    worker_id: u64,
+
+    #[new(default)]
+    family_id: u64,
+
+    #[new(default)]
+    bucket_index: u64,
+
+    #[new(value = "StaticBagId::Council.into()")]
+    bag_id: BagId<Test>,
+
+    #[new(default)]
+    bytes_served: u64,
+
+    #[new(default)]
+    receipts_root: Option<H256>,
+}
+
+impl SubmitBandwidthReportFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) -> Option<BandwidthReportId> {
+        let bucket_id = Storage::create_distribution_bucket_id(self.family_id, self.bucket_index);
+        let report_id = Storage::next_bandwidth_report_id();
+        let account_id = match self.origin.clone() {
+            RawOrigin::Signed(account_id) => account_id,
+            _ => Default::default(),
+        };
+        let old_balance = Balances::usable_balance(&account_id);
+        let old_treasury_balance = Balances::usable_balance(&treasury_account_id());
+
+        let actual_result = Storage::submit_bandwidth_report(
+            self.origin.clone().into(),
+            self.worker_id,
+            bucket_id.clone(),
+            self.bag_id.clone(),
+            self.bytes_served,
+            self.receipts_root,
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(Storage::next_bandwidth_report_id(), report_id + 1);
+
+            let report = Storage::bandwidth_report(report_id).unwrap();
+            assert_eq!(report.worker_id, self.worker_id);
+            assert_eq!(report.bucket_id, bucket_id);
+            assert_eq!(report.bytes_served, self.bytes_served);
+            assert_eq!(report.bond.amount, BandwidthReportBond::get());
+            assert_eq!(
+                Storage::last_bandwidth_report_submission(
+                    self.worker_id,
+                    (bucket_id, self.bag_id.clone())
+                ),
+                Some(report.submitted_at)
+            );
+            assert_eq!(
+                Balances::usable_balance(&account_id),
+                old_balance - BandwidthReportBond::get()
+            );
+            assert_eq!(
+                Balances::usable_balance(&treasury_account_id()),
+                old_treasury_balance + BandwidthReportBond::get()
+            );
+
+            Some(report_id)
+        } else {
+            assert_eq!(Storage::next_bandwidth_report_id(), report_id);
+            assert_eq!(Balances::usable_balance(&account_id), old_balance);
+
+            None
+        }
+    }
+}
+
+#[derive(new, Fixture)]
+pub struct RejectBandwidthReportFixture {
+    #[new(value = "RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(default)]
+    report_id: BandwidthReportId,
+
+    #[new(default)]
+    rationale: Vec<u8>,
+}
+
+impl RejectBandwidthReportFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_report = Storage::bandwidth_report(self.report_id);
+        let old_treasury_balance = Balances::usable_balance(&treasury_account_id());
+
+        let actual_result = Storage::reject_bandwidth_report(
+            self.origin.clone().into(),
+            self.report_id,
+            self.rationale.clone(),
+        );
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_report = Storage::bandwidth_report(self.report_id);
+        let new_treasury_balance = Balances::usable_balance(&treasury_account_id());
+        if actual_result.is_ok() {
+            assert!(new_report.is_none());
+            // the bond is slashed
+            assert_eq!(
+                new_treasury_balance,
+                old_treasury_balance
+                    - old_report
+                        .map(|report| report.bond.amount)
+                        .unwrap_or_default()
+            );
+        } else {
+            assert_eq!(new_report, old_report);
+            assert_eq!(new_treasury_balance, old_treasury_balance);
+        }
+    }
+}
+
+#[derive(new, Fixture)]
+pub struct SettleBandwidthReportFixture {
+    #[new(value = "RawOrigin::Signed(DEFAULT_MEMBER_ACCOUNT_ID)")]
+    origin: RawOrigin<u64>,
+
+    #[new(default)]
+    report_id: BandwidthReportId,
+}
+
+impl SettleBandwidthReportFixture {
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_report = Storage::bandwidth_report(self.report_id);
+        let old_balance = Balances::usable_balance(&DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID);
+
+        let actual_result =
+            Storage::settle_bandwidth_report(self.origin.clone().into(), self.report_id);
+
+        assert_eq!(actual_result, expected_result);
+
+        let new_report = Storage::bandwidth_report(self.report_id);
+        let new_balance = Balances::usable_balance(&DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID);
+        if actual_result.is_ok() {
+            assert!(new_report.is_none());
+            // the reward is paid only to the operators that are still in the working group
+            let payout = old_report
+                .map(|report| {
+                    let reward =
+                        match DistributionWG::get_worker_reward_account_id(&report.worker_id) {
+                            Some(_) => report.reward,
+                            None => 0,
+                        };
+                    reward + report.bond.amount
+                })
+                .unwrap_or_default();
+            assert_eq!(new_balance, old_balance + payout);
+        } else {
+            assert_eq!(new_report, old_report);
+            assert_eq!(new_balance, old_balance);
+        }
+    }
+}
+
 // helper methods
 impl CreateStorageBucketFixture {
     pub fn create_several(&self, bucket_number: u32) -> BTreeSet<u64> {
@@ -7,7 +7,7 @@ use common::locks::{
 pub use frame_support::traits::LockIdentifier;
 use frame_support::{
     ensure, parameter_types,
-    traits::{ConstU16, ConstU32, ConstU64, WithdrawReasons},
+    traits::{ConstU16, ConstU32, ConstU64, Currency, WithdrawReasons},
     PalletId,
 };
 use frame_system::ensure_signed;
@@ -117,12 +117,17 @@ parameter_types! {
     pub const MaxNumberOfPendingInvitationsPerDistributionBucket: u32 = 1;
     pub const MinStorageBucketsPerBag: u32 = 3;
     pub const MaxStorageBucketsPerBag: u32 = 10;
+    pub const MaxStorageBucketsScannedOnAssignment: u32 = 100;
     pub const MinDistributionBucketsPerBag: u32 = 2;
     pub const MaxDistributionBucketsPerBag: u32 = 9;
     pub const DefaultMemberDynamicBagNumberOfStorageBuckets: u32 = 3;
     pub const DefaultChannelDynamicBagNumberOfStorageBuckets: u32 = 4;
     pub const MaxDataObjectSize: u64 = u64::MAX - 1000;
     pub const MaxNumberOfOperatorsPerDistributionBucket: u32 = 5;
+    pub const BandwidthReportDisputePeriod: u64 = 10;
+    pub const BandwidthReportPeriod: u64 = 5;
+    pub const MaxBytesServedPerBandwidthReport: u64 = 100_000_000_000;
+    pub const BandwidthReportBond: u64 = 100;
 }
 
 pub const STORAGE_WG_LEADER_ACCOUNT_ID: u64 = 100001;
@@ -161,6 +166,7 @@ impl crate::Config for Test {
     type ModuleId = StorageModuleId;
     type MinStorageBucketsPerBag = MinStorageBucketsPerBag;
     type MaxStorageBucketsPerBag = MaxStorageBucketsPerBag;
+    type MaxStorageBucketsScannedOnAssignment = MaxStorageBucketsScannedOnAssignment;
     type MinDistributionBucketsPerBag = MinDistributionBucketsPerBag;
     type MaxDistributionBucketsPerBag = MaxDistributionBucketsPerBag;
     type DefaultMemberDynamicBagNumberOfStorageBuckets =
@@ -177,6 +183,10 @@ impl crate::Config for Test {
     type StorageWorkingGroup = StorageWG;
     type DistributionWorkingGroup = DistributionWG;
     type ModuleAccountInitialBalance = ExistentialDeposit;
+    type BandwidthReportDisputePeriod = BandwidthReportDisputePeriod;
+    type BandwidthReportPeriod = BandwidthReportPeriod;
+    type MaxBytesServedPerBandwidthReport = MaxBytesServedPerBandwidthReport;
+    type BandwidthReportBond = BandwidthReportBond;
 }
 
 pub const DEFAULT_MEMBER_ID: u64 = 100;
@@ -210,7 +220,6 @@ impl working_group::Config<StorageWorkingGroupInstance> for Test {
     type StakingHandler = staking_handler::StakingManager<Self, StorageWorkingGroupLockId>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -225,7 +234,6 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
     type StakingHandler = staking_handler::StakingManager<Self, DistributionWorkingGroupLockId>;
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -264,6 +272,12 @@ pub const WORKING_GROUP_BUDGET: u64 = 100;
 
 thread_local! {
     pub static WG_BUDGET: RefCell<u64> = RefCell::new(WORKING_GROUP_BUDGET);
+    pub static DISTRIBUTION_WG_BUDGET: RefCell<u64> = RefCell::new(WORKING_GROUP_BUDGET);
+    pub static LEFT_DISTRIBUTION_WORKERS: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+}
+
+pub(crate) fn leave_distribution_working_group(worker_id: u64) {
+    LEFT_DISTRIBUTION_WORKERS.with(|val| val.borrow_mut().push(worker_id));
 }
 
 pub struct MembershipWG;
@@ -307,6 +321,12 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for MembershipWG {
         unimplemented!()
     }
 
+    fn get_worker_reward_account_id(
+        _: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Test as frame_system::Config>::AccountId> {
+        unimplemented!()
+    }
+
     fn is_leader_account_id(_account_id: &<Test as frame_system::Config>::AccountId) -> bool {
         true
     }
@@ -417,6 +437,12 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for StorageWG {
         unimplemented!()
     }
 
+    fn get_worker_reward_account_id(
+        _: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Test as frame_system::Config>::AccountId> {
+        unimplemented!()
+    }
+
     fn is_leader_account_id(_account_id: &<Test as frame_system::Config>::AccountId) -> bool {
         unimplemented!()
     }
@@ -492,6 +518,16 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for DistributionWG {
         unimplemented!()
     }
 
+    fn get_worker_reward_account_id(
+        worker_id: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Test as frame_system::Config>::AccountId> {
+        if Self::worker_exists(worker_id) {
+            Some(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID)
+        } else {
+            None
+        }
+    }
+
     fn is_leader_account_id(_account_id: &<Test as frame_system::Config>::AccountId) -> bool {
         unimplemented!()
     }
@@ -519,7 +555,9 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for DistributionWG {
         .cloned()
         .collect::<Vec<_>>();
 
-        if !allowed_providers.contains(worker_id) {
+        let has_left = LEFT_DISTRIBUTION_WORKERS.with(|val| val.borrow().contains(worker_id));
+
+        if !allowed_providers.contains(worker_id) || has_left {
             Err(DispatchError::Other("Invalid worker"))
         } else {
             Ok(())
@@ -543,15 +581,26 @@ impl common::working_group::WorkingGroupBudgetHandler<u64, u64> for StorageWG {
 
 impl common::working_group::WorkingGroupBudgetHandler<u64, u64> for DistributionWG {
     fn get_budget() -> u64 {
-        unimplemented!()
+        DISTRIBUTION_WG_BUDGET.with(|val| *val.borrow())
     }
 
-    fn set_budget(_new_value: u64) {
-        unimplemented!()
+    fn set_budget(new_value: u64) {
+        DISTRIBUTION_WG_BUDGET.with(|val| {
+            *val.borrow_mut() = new_value;
+        });
     }
 
-    fn try_withdraw(_account_id: &u64, _amount: u64) -> DispatchResult {
-        unimplemented!()
+    fn try_withdraw(account_id: &u64, amount: u64) -> DispatchResult {
+        ensure!(
+            Self::get_budget() >= amount,
+            DispatchError::Other("Invalid balance")
+        );
+
+        let _ = Balances::deposit_creating(account_id, amount);
+
+        Self::decrease_budget(amount);
+
+        Ok(())
     }
 }
 
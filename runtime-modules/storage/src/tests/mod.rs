@@ -4,27 +4,34 @@ mod fixtures;
 pub(crate) mod mocks;
 
 use frame_support::dispatch::{DispatchError, DispatchResult};
+use frame_support::traits::Currency;
 use frame_support::{assert_err, assert_ok, StorageDoubleMap, StorageMap, StorageValue};
 use frame_system::RawOrigin;
+use sp_core::H256;
 use sp_runtime::SaturatedConversion;
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::convert::TryInto;
 use sp_std::iter::{repeat, FromIterator};
 
-use common::working_group::WorkingGroup;
+use common::working_group::{WorkingGroup, WorkingGroupBudgetHandler};
 
 use crate::{
-    BagId, Base58Multihash, Config, DataObject, DataObjectCreationParameters, DataObjectStorage,
-    DistributionBucketFamily, DistributionBucketId, DynamicBagId, DynamicBagType, Error,
-    ModuleAccount, RawEvent, RepayableBloatBond, StaticBagId, StorageBucketOperatorStatus,
-    StorageTreasury, UploadParameters, Voucher,
+    AssignableStorageBuckets, BagId, BagStoragePolicy, BandwidthReportId, BandwidthTier,
+    Base58Multihash, Config, DataObject, DataObjectCreationParameters, DataObjectStorage,
+    DistributionBucketFamily, DistributionBucketId, DistributionDescriptorParameters,
+    DistributionOperatorEndpointMaxLength, DistributionProtocol, DynBagCreationParameters,
+    DynamicBagId, DynamicBagType, Error, MaxStorageBucketTags, ModuleAccount, RawEvent,
+    RegionCodeMaxLength, RepayableBloatBond, StaticBagId, StorageBucketOperatorStatus,
+    StorageBucketTagMaxLength, StorageTreasury, UploadParameters, Voucher, BYTES_IN_GIGABYTE,
 };
 
 use mocks::{
-    build_test_externalities, create_cid, Balances, BlacklistSizeLimit,
+    build_test_externalities, create_cid, leave_distribution_working_group, Balances,
+    BandwidthReportBond, BandwidthReportDisputePeriod, BandwidthReportPeriod, BlacklistSizeLimit,
     DefaultChannelDynamicBagNumberOfStorageBuckets, DefaultMemberDynamicBagNumberOfStorageBuckets,
-    ExistentialDeposit, MaxDataObjectSize, MaxDistributionBucketFamilyNumber, Storage, Test,
+    DistributionWG, ExistentialDeposit, MaxBytesServedPerBandwidthReport, MaxDataObjectSize,
+    MaxDistributionBucketFamilyNumber, MaxStorageBucketsPerBag, Storage, Test,
     ANOTHER_DISTRIBUTION_PROVIDER_ID, ANOTHER_STORAGE_PROVIDER_ID,
     DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID, DEFAULT_DISTRIBUTION_PROVIDER_ID,
     DEFAULT_MEMBER_ACCOUNT_ID, DEFAULT_MEMBER_ID, DEFAULT_STORAGE_BUCKETS_NUMBER,
@@ -1515,6 +1522,103 @@ fn set_storage_operator_metadata_fails_with_invalid_storage_association() {
     });
 }
 
+#[test]
+fn set_storage_operator_tags_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_id = create_storage_buckets_with_operator(1, true)
+            .into_iter()
+            .next()
+            .unwrap();
+
+        let tags = BTreeSet::from_iter(vec![b"eu".to_vec(), b"ssd".to_vec()]);
+
+        SetStorageOperatorTagsFixture::new()
+            .with_storage_bucket_id(bucket_id)
+            .with_tags(tags.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageOperatorTagsSet(
+            bucket_id,
+            DEFAULT_STORAGE_PROVIDER_ID,
+            Storage::storage_bucket_operator_tags(bucket_id),
+        ));
+    });
+}
+
+#[test]
+fn set_storage_operator_tags_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        SetStorageOperatorTagsFixture::new()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn set_storage_operator_tags_fails_with_invalid_storage_association() {
+    build_test_externalities().execute_with(|| {
+        // Missing invitation
+        let bucket_id = CreateStorageBucketFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        SetStorageOperatorTagsFixture::new()
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Err(Error::<Test>::StorageProviderMustBeSet.into()));
+
+        // Not accepted invitation
+        let bucket_id = CreateStorageBucketFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_invite_worker(Some(DEFAULT_STORAGE_PROVIDER_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        SetStorageOperatorTagsFixture::new()
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Err(Error::<Test>::InvalidStorageProvider.into()));
+    });
+}
+
+#[test]
+fn set_storage_operator_tags_fails_with_too_long_tag() {
+    build_test_externalities().execute_with(|| {
+        let bucket_id = create_storage_buckets_with_operator(1, true)
+            .into_iter()
+            .next()
+            .unwrap();
+
+        let too_long_tag = vec![1u8; StorageBucketTagMaxLength::get() as usize + 1];
+
+        SetStorageOperatorTagsFixture::new()
+            .with_storage_bucket_id(bucket_id)
+            .with_tags(BTreeSet::from_iter(vec![too_long_tag]))
+            .call_and_assert(Err(Error::<Test>::StorageBucketTagTooLong.into()));
+    });
+}
+
+#[test]
+fn set_storage_operator_tags_fails_with_exceeding_max_tags_number() {
+    build_test_externalities().execute_with(|| {
+        let bucket_id = create_storage_buckets_with_operator(1, true)
+            .into_iter()
+            .next()
+            .unwrap();
+
+        let tags = (0..=MaxStorageBucketTags::get())
+            .map(|idx| vec![idx as u8])
+            .collect::<BTreeSet<_>>();
+
+        SetStorageOperatorTagsFixture::new()
+            .with_storage_bucket_id(bucket_id)
+            .with_tags(tags)
+            .call_and_assert(Err(Error::<Test>::MaxStorageBucketTagsExceeded.into()));
+    });
+}
+
 #[test]
 fn accept_pending_data_objects_succeeded() {
     build_test_externalities().execute_with(|| {
@@ -3245,6 +3349,51 @@ fn remove_storage_bucket_operator_succeeded() {
     });
 }
 
+#[test]
+fn assignable_storage_buckets_follow_bucket_status_and_operator() {
+    build_test_externalities().execute_with(|| {
+        let storage_provider_id = DEFAULT_STORAGE_PROVIDER_ID;
+
+        let bucket_id = CreateStorageBucketFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_invite_worker(Some(storage_provider_id))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        // The bucket without an active operator is not assignable.
+        assert!(!AssignableStorageBuckets::<Test>::contains_key(bucket_id));
+
+        AcceptStorageBucketInvitationFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .with_worker_id(storage_provider_id)
+            .call_and_assert(Ok(()));
+
+        assert!(AssignableStorageBuckets::<Test>::contains_key(bucket_id));
+
+        let update_status_fixture = UpdateStorageBucketStatusFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .with_new_status(false);
+        update_status_fixture.call_and_assert(Ok(()));
+
+        assert!(!AssignableStorageBuckets::<Test>::contains_key(bucket_id));
+
+        update_status_fixture
+            .with_new_status(true)
+            .call_and_assert(Ok(()));
+
+        assert!(AssignableStorageBuckets::<Test>::contains_key(bucket_id));
+
+        RemoveStorageBucketOperatorFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_storage_bucket_id(bucket_id)
+            .call_and_assert(Ok(()));
+
+        assert!(!AssignableStorageBuckets::<Test>::contains_key(bucket_id));
+    });
+}
+
 #[test]
 fn remove_storage_bucket_operator_fails_with_non_leader_origin() {
     build_test_externalities().execute_with(|| {
@@ -3723,597 +3872,738 @@ fn create_dynamic_bag_failed_with_existing_bag() {
     });
 }
 
-fn create_dynamic_bag(dynamic_bag_id: &DynamicBagId<Test>, storage_buckets: BTreeSet<u64>) {
-    CreateDynamicBagFixture::default()
-        .with_bag_id(dynamic_bag_id.clone())
-        .with_storage_buckets(storage_buckets)
-        .with_expected_data_object_state_bloat_bond(Storage::data_object_state_bloat_bond_value())
-        .call_and_assert(Ok(()));
-}
-
 #[test]
-fn update_number_of_storage_buckets_in_dynamic_bag_creation_policy_succeeded() {
+fn create_dynamic_bag_succeeded_with_automatic_storage_bucket_assignment() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let dynamic_bag_type = DynamicBagType::Channel;
-        let new_bucket_number = 10;
+        // Buckets without an operator are skipped.
+        create_storage_buckets(1);
+        let storage_bucket_ids = create_storage_buckets_with_operator(
+            DefaultMemberDynamicBagNumberOfStorageBuckets::get(),
+            true,
+        );
 
-        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::new()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_new_storage_buckets_number(new_bucket_number)
-            .with_dynamic_bag_type(dynamic_bag_type)
-            .call_and_assert(Ok(()));
+        let fixture = CreateDynamicBagFixture::default();
+        fixture.call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(
-            RawEvent::NumberOfStorageBucketsInDynamicBagCreationPolicyUpdated(
-                dynamic_bag_type,
-                new_bucket_number,
-            ),
-        );
+        let bag_id: BagId<Test> = fixture.get_params().bag_id.into();
+        let bag = Storage::bag(&bag_id);
+        assert_eq!(BTreeSet::from(bag.stored_by), storage_bucket_ids);
+
+        EventFixture::assert_last_crate_event(RawEvent::DynamicBagCreated(
+            DynBagCreationParameters::<Test> {
+                storage_buckets: storage_bucket_ids,
+                ..fixture.get_params()
+            },
+            BTreeSet::new(),
+        ));
     });
 }
 
 #[test]
-fn update_number_of_storage_buckets_in_dynamic_bag_creation_policy_failed_with_constraint_violation(
-) {
+fn create_dynamic_bag_with_automatic_storage_bucket_assignment_prefers_tagged_buckets() {
     build_test_externalities().execute_with(|| {
-        let dynamic_bag_type = DynamicBagType::Channel;
+        let storage_bucket_ids = create_storage_buckets_with_operator(
+            DefaultMemberDynamicBagNumberOfStorageBuckets::get() + 1,
+            true,
+        );
+        let tagged_bucket_id = *storage_bucket_ids.iter().max().unwrap();
+        let tags = BTreeSet::from_iter(vec![b"eu".to_vec()]);
 
-        let less_than_allowed_new_bucket_number = 1;
-        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::new()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_new_storage_buckets_number(less_than_allowed_new_bucket_number)
-            .with_dynamic_bag_type(dynamic_bag_type)
-            .call_and_assert(Err(
-                Error::<Test>::NumberOfStorageBucketsOutsideOfAllowedContraints.into(),
-            ));
+        SetStorageOperatorTagsFixture::new()
+            .with_storage_bucket_id(tagged_bucket_id)
+            .with_tags(tags.clone())
+            .call_and_assert(Ok(()));
 
-        let more_than_allowed_new_bucket_number = 11;
-        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::new()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_new_storage_buckets_number(more_than_allowed_new_bucket_number)
-            .with_dynamic_bag_type(dynamic_bag_type)
-            .call_and_assert(Err(
-                Error::<Test>::NumberOfStorageBucketsOutsideOfAllowedContraints.into(),
-            ));
+        UpdatePreferredTagsInDynamicBagCreationPolicyFixture::new()
+            .with_dynamic_bag_type(DynamicBagType::Member)
+            .with_tags(tags)
+            .call_and_assert(Ok(()));
+
+        let fixture = CreateDynamicBagFixture::default();
+        fixture.call_and_assert(Ok(()));
+
+        let bag_id: BagId<Test> = fixture.get_params().bag_id.into();
+        let bag = Storage::bag(&bag_id);
+        assert_eq!(
+            bag.stored_by.len(),
+            DefaultMemberDynamicBagNumberOfStorageBuckets::get() as usize
+        );
+        assert!(bag.stored_by.contains(&tagged_bucket_id));
     });
 }
 
 #[test]
-fn update_number_of_storage_buckets_in_dynamic_bag_creation_policy_fails_with_bad_origin() {
+fn create_dynamic_bag_with_automatic_storage_bucket_assignment_fails_with_not_enough_buckets() {
     build_test_externalities().execute_with(|| {
-        let non_leader_id = 1;
+        create_storage_buckets_with_operator(
+            DefaultMemberDynamicBagNumberOfStorageBuckets::get() - 1,
+            true,
+        );
+        // Buckets not accepting new bags are skipped.
+        create_storage_buckets_with_operator(1, false);
 
-        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::new()
-            .with_origin(RawOrigin::Signed(non_leader_id))
-            .call_and_assert(Err(DispatchError::BadOrigin));
+        CreateDynamicBagFixture::default().call_and_assert(Err(
+            Error::<Test>::NotEnoughStorageBucketsForAutomaticAssignment.into(),
+        ));
     });
 }
 
+fn create_dynamic_bag(dynamic_bag_id: &DynamicBagId<Test>, storage_buckets: BTreeSet<u64>) {
+    CreateDynamicBagFixture::default()
+        .with_bag_id(dynamic_bag_id.clone())
+        .with_storage_buckets(storage_buckets)
+        .with_expected_data_object_state_bloat_bond(Storage::data_object_state_bloat_bond_value())
+        .call_and_assert(Ok(()));
+}
+
 #[test]
-fn dynamic_bag_creation_policy_defaults_and_updates_succeeded() {
+fn update_preferred_tags_in_dynamic_bag_creation_policy_succeeded() {
     build_test_externalities().execute_with(|| {
-        let new_bucket_number = 10;
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        // Change member dynamic bag creation policy.
-        let dynamic_bag_type = DynamicBagType::Member;
-        let policy = Storage::get_dynamic_bag_creation_policy(dynamic_bag_type);
-        assert_eq!(
-            policy.number_of_storage_buckets,
-            DefaultMemberDynamicBagNumberOfStorageBuckets::get()
-        );
+        let dynamic_bag_type = DynamicBagType::Channel;
+        let tags = BTreeSet::from_iter(vec![b"eu".to_vec(), b"ssd".to_vec()]);
 
-        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::new()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_new_storage_buckets_number(new_bucket_number)
+        UpdatePreferredTagsInDynamicBagCreationPolicyFixture::new()
             .with_dynamic_bag_type(dynamic_bag_type)
+            .with_tags(tags)
             .call_and_assert(Ok(()));
 
-        let policy = Storage::get_dynamic_bag_creation_policy(dynamic_bag_type);
-        assert_eq!(policy.number_of_storage_buckets, new_bucket_number);
-
-        // Change channel dynamic bag creation policy.
-        let dynamic_bag_type = DynamicBagType::Channel;
-        let policy = Storage::get_dynamic_bag_creation_policy(dynamic_bag_type);
-        assert_eq!(
-            policy.number_of_storage_buckets,
-            DefaultChannelDynamicBagNumberOfStorageBuckets::get()
+        EventFixture::assert_last_crate_event(
+            RawEvent::PreferredTagsInDynamicBagCreationPolicyUpdated(
+                dynamic_bag_type,
+                Storage::preferred_storage_bucket_tags(dynamic_bag_type),
+            ),
         );
+    });
+}
 
-        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::new()
-            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
-            .with_new_storage_buckets_number(new_bucket_number)
-            .with_dynamic_bag_type(dynamic_bag_type)
-            .call_and_assert(Ok(()));
+#[test]
+fn update_preferred_tags_in_dynamic_bag_creation_policy_fails_with_bad_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
 
-        let policy = Storage::get_dynamic_bag_creation_policy(dynamic_bag_type);
-        assert_eq!(policy.number_of_storage_buckets, new_bucket_number);
+        UpdatePreferredTagsInDynamicBagCreationPolicyFixture::new()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
 #[test]
-fn create_distribution_bucket_family_succeeded() {
+fn rebalance_storage_buckets_for_bag_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        set_default_update_storage_buckets_per_bag_limit();
+
+        // Buckets with zero limits are considered full.
+        let full_bucket_id = CreateStorageBucketFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        let bucket_family = Storage::distribution_bucket_family_by_id(family_id);
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_add_bucket_ids(BTreeSet::from_iter(vec![full_bucket_id]))
+            .call_and_assert(Ok(()));
 
-        assert_eq!(bucket_family, DistributionBucketFamily::<Test>::default());
+        let new_bucket_ids = create_storage_buckets_with_operator(1, true);
 
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketFamilyCreated(family_id));
+        RebalanceStorageBucketsForBagFixture::new()
+            .with_bag_id(bag_id.clone())
+            .call_and_assert(Ok(()));
+
+        let bag = Storage::bag(&bag_id);
+        assert_eq!(BTreeSet::from(bag.stored_by), new_bucket_ids);
+
+        EventFixture::assert_last_crate_event(RawEvent::StorageBucketsUpdatedForBag(
+            bag_id,
+            new_bucket_ids,
+            BTreeSet::from_iter(vec![full_bucket_id]),
+        ));
     });
 }
 
 #[test]
-fn create_distribution_bucket_family_fails_with_non_signed_origin() {
+fn rebalance_storage_buckets_for_bag_fails_with_bad_origin() {
     build_test_externalities().execute_with(|| {
-        CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::None)
+        let non_leader_id = 1;
+
+        RebalanceStorageBucketsForBagFixture::new()
+            .with_origin(RawOrigin::Signed(non_leader_id))
             .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
 #[test]
-fn create_distribution_bucket_family_fails_with_exceeding_family_number_limit() {
+fn rebalance_storage_buckets_for_bag_fails_with_invalid_batch_size() {
     build_test_externalities().execute_with(|| {
-        for _ in 0..MaxDistributionBucketFamilyNumber::get() {
-            CreateDistributionBucketFamilyFixture::new()
-                .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-                .call_and_assert(Ok(()));
-        }
+        RebalanceStorageBucketsForBagFixture::new()
+            .with_max_replacements(0)
+            .call_and_assert(Err(
+                Error::<Test>::InvalidStorageBucketsRebalanceBatchSize.into()
+            ));
 
-        CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        RebalanceStorageBucketsForBagFixture::new()
+            .with_max_replacements(MaxStorageBucketsPerBag::get() + 1)
             .call_and_assert(Err(
-                Error::<Test>::MaxDistributionBucketFamilyNumberLimitExceeded.into(),
+                Error::<Test>::InvalidStorageBucketsRebalanceBatchSize.into()
             ));
     });
 }
 
 #[test]
-fn delete_distribution_bucket_family_succeeded() {
+fn rebalance_storage_buckets_for_bag_fails_with_no_better_buckets() {
     build_test_externalities().execute_with(|| {
-        let starting_block = 1;
-        run_to_block(starting_block);
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        set_default_update_storage_buckets_per_bag_limit();
 
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let bucket_ids = create_storage_buckets_with_operator(1, true);
 
-        DeleteDistributionBucketFamilyFixture::new()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_add_bucket_ids(bucket_ids)
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketFamilyDeleted(family_id));
+        // Equally loaded candidate bucket.
+        create_storage_buckets_with_operator(1, true);
+
+        RebalanceStorageBucketsForBagFixture::new()
+            .with_bag_id(bag_id)
+            .call_and_assert(Err(Error::<Test>::NoStorageBucketsToRebalanceBag.into()));
     });
 }
 
-#[test]
-fn delete_distribution_bucket_family_fails_with_assgined_bags() {
-    build_test_externalities().execute_with(|| {
-        set_default_distribution_buckets_per_bag_limit();
+fn setup_erasure_coded_council_bag(data_shards: u32, parity_shards: u32) -> BTreeSet<u64> {
+    let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+    set_default_update_storage_buckets_per_bag_limit();
 
-        let static_bag_id = StaticBagId::Council;
-        let bag_id: BagId<Test> = static_bag_id.into();
+    let bucket_ids = create_storage_buckets_with_operator(data_shards + parity_shards, true);
 
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+    UpdateStorageBucketForBagsFixture::new()
+        .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+        .with_bag_id(bag_id.clone())
+        .with_add_bucket_ids(bucket_ids.clone())
+        .call_and_assert(Ok(()));
 
-        let bucket_id = CreateDistributionBucketFixture::new()
-            .with_family_id(family_id)
-            .with_accept_new_bags(true)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+    UpdateBagStoragePolicyFixture::new()
+        .with_bag_id(bag_id)
+        .with_storage_policy(BagStoragePolicy::ErasureCoding {
+            data_shards,
+            parity_shards,
+        })
+        .call_and_assert(Ok(()));
 
-        let add_buckets_ids = BTreeSet::from_iter(vec![bucket_id]);
+    bucket_ids
+}
 
-        UpdateDistributionBucketForBagsFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bag_id(bag_id.clone())
-            .with_family_id(family_id)
-            .with_add_bucket_indices(add_buckets_ids.clone())
-            .call_and_assert(Ok(()));
+fn upload_single_data_object_to_council_bag() {
+    increase_account_balance(&DEFAULT_MEMBER_ACCOUNT_ID, INITIAL_BALANCE);
 
-        let add_buckets = add_buckets_ids
-            .iter()
-            .map(|idx| Storage::create_distribution_bucket_id(family_id, *idx))
-            .collect::<BTreeSet<_>>();
-        let bag = Storage::bag(&bag_id);
-        assert_eq!(bag.distributed_by, add_buckets);
+    let upload_params = UploadParameters::<Test> {
+        bag_id: BagId::<Test>::Static(StaticBagId::Council),
+        state_bloat_bond_source_account_id: DEFAULT_MEMBER_ACCOUNT_ID,
+        object_creation_list: create_single_data_object(),
+        expected_data_size_fee: Storage::data_object_per_mega_byte_fee(),
+        expected_data_object_state_bloat_bond: Storage::data_object_state_bloat_bond_value(),
+        ..Default::default()
+    };
 
-        DeleteDistributionBucketFamilyFixture::new()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Err(Error::<Test>::DistributionBucketIsBoundToBag.into()));
-    });
+    UploadFixture::default()
+        .with_params(upload_params)
+        .call_and_assert(Ok(()));
 }
 
 #[test]
-fn delete_distribution_bucket_family_fails_with_bound_member_dynamic_bag_creation_policy() {
+fn update_bag_storage_policy_succeeded() {
     build_test_externalities().execute_with(|| {
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        let new_bucket_number = 7;
-        let families = BTreeMap::from_iter(vec![(family_id, new_bucket_number)]);
-        let dynamic_bag_type = DynamicBagType::Member;
+        setup_erasure_coded_council_bag(2, 1);
 
-        UpdateFamiliesInDynamicBagCreationPolicyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_families(families)
-            .with_dynamic_bag_type(dynamic_bag_type)
-            .call_and_assert(Ok(()));
+        EventFixture::assert_last_crate_event(RawEvent::BagStoragePolicyUpdated(
+            BagId::<Test>::Static(StaticBagId::Council),
+            BagStoragePolicy::ErasureCoding {
+                data_shards: 2,
+                parity_shards: 1,
+            },
+        ));
+    });
+}
 
-        DeleteDistributionBucketFamilyFixture::new()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Err(
-                Error::<Test>::DistributionFamilyBoundToBagCreationPolicy.into(),
-            ));
+#[test]
+fn update_bag_storage_policy_fails_with_bad_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
+
+        UpdateBagStoragePolicyFixture::new()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
 #[test]
-fn delete_distribution_bucket_family_fails_with_bound_channel_dynamic_bag_creation_policy() {
+fn update_bag_storage_policy_fails_with_non_empty_bag() {
     build_test_externalities().execute_with(|| {
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        set_default_update_storage_buckets_per_bag_limit();
 
-        let new_bucket_number = 7;
-        let families = BTreeMap::from_iter(vec![(family_id, new_bucket_number)]);
-        let dynamic_bag_type = DynamicBagType::Channel;
+        let bucket_ids = create_storage_buckets_with_operator(2, true);
 
-        UpdateFamiliesInDynamicBagCreationPolicyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_families(families)
-            .with_dynamic_bag_type(dynamic_bag_type)
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_add_bucket_ids(bucket_ids)
             .call_and_assert(Ok(()));
 
-        DeleteDistributionBucketFamilyFixture::new()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        upload_single_data_object_to_council_bag();
+
+        UpdateBagStoragePolicyFixture::new()
+            .with_bag_id(bag_id)
+            .with_storage_policy(BagStoragePolicy::ErasureCoding {
+                data_shards: 1,
+                parity_shards: 1,
+            })
             .call_and_assert(Err(
-                Error::<Test>::DistributionFamilyBoundToBagCreationPolicy.into(),
+                Error::<Test>::BagStoragePolicyCannotBeChangedForNonEmptyBag.into(),
             ));
     });
 }
 
 #[test]
-fn delete_distribution_bucket_family_fails_with_non_signed_origin() {
+fn update_bag_storage_policy_fails_with_zero_data_shards() {
     build_test_externalities().execute_with(|| {
-        DeleteDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::None)
-            .call_and_assert(Err(DispatchError::BadOrigin));
+        UpdateBagStoragePolicyFixture::new()
+            .with_bag_id(BagId::<Test>::Static(StaticBagId::Council))
+            .with_storage_policy(BagStoragePolicy::ErasureCoding {
+                data_shards: 0,
+                parity_shards: 1,
+            })
+            .call_and_assert(Err(Error::<Test>::InvalidErasureCodingParameters.into()));
     });
 }
 
 #[test]
-fn delete_distribution_bucket_family_fails_with_non_existing_family() {
+fn update_bag_storage_policy_fails_with_buckets_number_mismatch() {
     build_test_externalities().execute_with(|| {
-        DeleteDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        set_default_update_storage_buckets_per_bag_limit();
+
+        let bucket_ids = create_storage_buckets_with_operator(2, true);
+
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_add_bucket_ids(bucket_ids)
+            .call_and_assert(Ok(()));
+
+        UpdateBagStoragePolicyFixture::new()
+            .with_bag_id(bag_id)
+            .with_storage_policy(BagStoragePolicy::ErasureCoding {
+                data_shards: 2,
+                parity_shards: 1,
+            })
             .call_and_assert(Err(
-                Error::<Test>::DistributionBucketFamilyDoesntExist.into()
+                Error::<Test>::ErasureCodedBagBucketsNumberMismatch.into()
             ));
     });
 }
 
 #[test]
-fn create_distribution_bucket_succeeded() {
+fn upload_succeeded_with_erasure_coded_bag() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let accept_new_bags = true;
+        let bucket_ids = setup_erasure_coded_council_bag(2, 1);
 
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        // Bucket vouchers are checked by the fixture against the shard size.
+        upload_single_data_object_to_council_bag();
 
-        let bucket_id = CreateDistributionBucketFixture::new()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_accept_new_bags(accept_new_bags)
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        let data_object_id = 0u64;
+        let layout = Storage::data_object_shard_layout(&bag_id, data_object_id).unwrap();
+        let bag_layout = Storage::bag_shard_layout(&bag_id).unwrap();
 
-        assert!(
-            crate::DistributionBucketByFamilyIdById::<Test>::contains_key(family_id, bucket_id)
+        assert_eq!(layout.shard_size, (DEFAULT_DATA_OBJECTS_SIZE + 1) / 2);
+        assert!(layout.accepted_shards.is_empty());
+        assert_eq!(
+            bag_layout.shard_buckets.into_inner(),
+            bucket_ids.into_iter().collect::<Vec<_>>()
         );
+        assert_eq!(bag_layout.shards_total_size, layout.shard_size);
 
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketCreated(
-            family_id,
-            accept_new_bags,
-            Storage::create_distribution_bucket_id(family_id, bucket_id),
-        ));
+        // The bag accounts the full data objects size.
+        assert_eq!(
+            Storage::bag(&bag_id).objects_total_size,
+            DEFAULT_DATA_OBJECTS_SIZE
+        );
     });
 }
 
 #[test]
-fn create_distribution_bucket_fails_with_non_signed_origin() {
+fn accept_pending_data_objects_succeeded_with_erasure_coded_bag() {
     build_test_externalities().execute_with(|| {
-        CreateDistributionBucketFixture::new()
-            .with_origin(RawOrigin::None)
-            .call_and_assert(Err(DispatchError::BadOrigin));
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let bucket_ids = setup_erasure_coded_council_bag(2, 1);
+        upload_single_data_object_to_council_bag();
+
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        let data_object_id = 0u64;
+        let data_object_ids = BTreeSet::from_iter(vec![data_object_id]);
+
+        for (index, bucket_id) in bucket_ids.iter().enumerate() {
+            // Data object is accepted only after the last shard acceptance.
+            assert!(!Storage::data_object_by_id(&bag_id, data_object_id).accepted);
+
+            AcceptPendingDataObjectsFixture::new()
+                .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+                .with_storage_bucket_id(*bucket_id)
+                .with_bag_id(bag_id.clone())
+                .with_data_object_ids(data_object_ids.clone())
+                .call_and_assert(Ok(()));
+
+            let layout = Storage::data_object_shard_layout(&bag_id, data_object_id).unwrap();
+            assert_eq!(layout.accepted_shards.len(), index + 1);
+        }
+
+        assert!(Storage::data_object_by_id(&bag_id, data_object_id).accepted);
     });
 }
 
 #[test]
-fn create_distribution_bucket_fails_with_non_existing_family() {
+fn update_storage_buckets_for_erasure_coded_bag_fails_with_buckets_number_mismatch() {
     build_test_externalities().execute_with(|| {
-        CreateDistributionBucketFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        setup_erasure_coded_council_bag(2, 1);
+
+        let bucket_ids = create_storage_buckets_with_operator(1, true);
+
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(BagId::<Test>::Static(StaticBagId::Council))
+            .with_add_bucket_ids(bucket_ids)
             .call_and_assert(Err(
-                Error::<Test>::DistributionBucketFamilyDoesntExist.into()
+                Error::<Test>::ErasureCodedBagBucketsNumberMismatch.into()
             ));
     });
 }
 
 #[test]
-fn update_distribution_bucket_status_succeeded() {
+fn update_storage_buckets_for_erasure_coded_bag_succeeded_with_bucket_replacement() {
     build_test_externalities().execute_with(|| {
-        let starting_block = 1;
-        run_to_block(starting_block);
+        let bucket_ids = setup_erasure_coded_council_bag(2, 1);
+        upload_single_data_object_to_council_bag();
 
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let bag_id = BagId::<Test>::Static(StaticBagId::Council);
+        let data_object_id = 0u64;
+        let data_object_ids = BTreeSet::from_iter(vec![data_object_id]);
 
-        let bucket_index = CreateDistributionBucketFixture::new()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
+        for bucket_id in bucket_ids.iter() {
+            AcceptPendingDataObjectsFixture::new()
+                .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+                .with_storage_bucket_id(*bucket_id)
+                .with_bag_id(bag_id.clone())
+                .with_data_object_ids(data_object_ids.clone())
+                .call_and_assert(Ok(()));
+        }
+
+        let replaced_bucket_id = *bucket_ids.iter().nth(1).unwrap();
+        let new_bucket_id = *create_storage_buckets_with_operator(1, true)
+            .iter()
+            .next()
             .unwrap();
 
-        let new_status = true;
-        UpdateDistributionBucketStatusFixture::new()
-            .with_family_id(family_id)
-            .with_distribution_bucket_index(bucket_index)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_new_status(new_status)
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_add_bucket_ids(BTreeSet::from_iter(vec![new_bucket_id]))
+            .with_remove_bucket_ids(BTreeSet::from_iter(vec![replaced_bucket_id]))
             .call_and_assert(Ok(()));
 
+        // The new bucket takes the shard index of the replaced one.
+        let mut expected_shard_buckets = bucket_ids.into_iter().collect::<Vec<_>>();
+        expected_shard_buckets[1] = new_bucket_id;
         assert_eq!(
-            Storage::distribution_bucket_by_family_id_by_index(family_id, &bucket_index)
-                .accepting_new_bags,
-            new_status
+            Storage::bag_shard_layout(&bag_id)
+                .unwrap()
+                .shard_buckets
+                .into_inner(),
+            expected_shard_buckets
         );
 
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketStatusUpdated(
-            Storage::create_distribution_bucket_id(family_id, bucket_index),
-            new_status,
-        ));
+        // The new bucket stores the shards only.
+        let layout = Storage::data_object_shard_layout(&bag_id, data_object_id).unwrap();
+        assert_eq!(
+            Storage::storage_bucket_by_id(new_bucket_id)
+                .unwrap()
+                .voucher
+                .size_used,
+            layout.shard_size
+        );
+
+        // The shard acceptance and the data object acceptance are reset.
+        assert!(!layout.all_shards_accepted(&expected_shard_buckets));
+        assert!(!layout.accepted_shards.contains_key(&1));
+        assert!(!Storage::data_object_by_id(&bag_id, data_object_id).accepted);
+
+        AcceptPendingDataObjectsFixture::new()
+            .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+            .with_storage_bucket_id(new_bucket_id)
+            .with_bag_id(bag_id.clone())
+            .with_data_object_ids(data_object_ids)
+            .call_and_assert(Ok(()));
+
+        let layout = Storage::data_object_shard_layout(&bag_id, data_object_id).unwrap();
+        assert!(layout.all_shards_accepted(&expected_shard_buckets));
+        assert!(Storage::data_object_by_id(&bag_id, data_object_id).accepted);
     });
 }
 
 #[test]
-fn update_distribution_bucket_status_fails_with_invalid_origin() {
+fn update_storage_buckets_for_erasure_coded_bag_fails_with_data_objects() {
     build_test_externalities().execute_with(|| {
-        UpdateDistributionBucketStatusFixture::new()
-            .with_origin(RawOrigin::Root)
-            .call_and_assert(Err(DispatchError::BadOrigin));
+        let bucket_ids = setup_erasure_coded_council_bag(2, 1);
+        upload_single_data_object_to_council_bag();
+
+        let new_bucket_ids = create_storage_buckets_with_operator(2, true);
+
+        UpdateStorageBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(BagId::<Test>::Static(StaticBagId::Council))
+            .with_add_bucket_ids(new_bucket_ids)
+            .with_remove_bucket_ids(BTreeSet::from_iter(bucket_ids.into_iter().take(2)))
+            .call_and_assert(Err(
+                Error::<Test>::ErasureCodedBagStorageBucketsLocked.into()
+            ));
     });
 }
 
 #[test]
-fn update_distribution_bucket_status_fails_with_invalid_distribution_bucket() {
+fn move_data_objects_fails_with_erasure_coded_bag() {
     build_test_externalities().execute_with(|| {
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        setup_erasure_coded_council_bag(2, 1);
+        upload_single_data_object_to_council_bag();
 
-        UpdateDistributionBucketStatusFixture::new()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+        MoveDataObjectsFixture::default()
+            .with_src_bag_id(BagId::<Test>::Static(StaticBagId::Council))
+            .with_dest_bag_id(BagId::<Test>::Static(StaticBagId::WorkingGroup(
+                WorkingGroup::Storage,
+            )))
+            .with_data_object_ids(BTreeSet::from_iter(vec![0u64]))
+            .call_and_assert(Err(
+                Error::<Test>::ErasureCodedBagDataObjectsCannotBeMoved.into()
+            ));
     });
 }
 
+fn create_storage_buckets_with_operator(
+    buckets_number: u32,
+    accepting_new_bags: bool,
+) -> BTreeSet<u64> {
+    set_max_voucher_limits();
+
+    (0..buckets_number)
+        .map(|_| {
+            let bucket_id = CreateStorageBucketFixture::new()
+                .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+                .with_invite_worker(Some(DEFAULT_STORAGE_PROVIDER_ID))
+                .with_accepting_new_bags(accepting_new_bags)
+                .with_objects_limit(DEFAULT_STORAGE_BUCKET_OBJECTS_LIMIT)
+                .with_size_limit(DEFAULT_STORAGE_BUCKET_SIZE_LIMIT)
+                .call_and_assert(Ok(()))
+                .unwrap();
+
+            AcceptStorageBucketInvitationFixture::new()
+                .with_origin(RawOrigin::Signed(DEFAULT_STORAGE_PROVIDER_ACCOUNT_ID))
+                .with_storage_bucket_id(bucket_id)
+                .with_worker_id(DEFAULT_STORAGE_PROVIDER_ID)
+                .call_and_assert(Ok(()));
+
+            bucket_id
+        })
+        .collect()
+}
+
 #[test]
-fn delete_distribution_bucket_succeeded() {
+fn update_number_of_storage_buckets_in_dynamic_bag_creation_policy_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
-
-        let bucket_index = CreateDistributionBucketFixture::new()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let dynamic_bag_type = DynamicBagType::Channel;
+        let new_bucket_number = 10;
 
-        DeleteDistributionBucketFixture::new()
-            .with_distribution_bucket_index(bucket_index)
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_new_storage_buckets_number(new_bucket_number)
+            .with_dynamic_bag_type(dynamic_bag_type)
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketDeleted(
-            Storage::create_distribution_bucket_id(family_id, bucket_index),
-        ));
+        EventFixture::assert_last_crate_event(
+            RawEvent::NumberOfStorageBucketsInDynamicBagCreationPolicyUpdated(
+                dynamic_bag_type,
+                new_bucket_number,
+            ),
+        );
     });
 }
 
 #[test]
-fn delete_distribution_bucket_fails_with_assgined_bags() {
+fn update_number_of_storage_buckets_in_dynamic_bag_creation_policy_failed_with_constraint_violation(
+) {
     build_test_externalities().execute_with(|| {
-        set_default_distribution_buckets_per_bag_limit();
+        let dynamic_bag_type = DynamicBagType::Channel;
 
-        let static_bag_id = StaticBagId::Council;
-        let bag_id: BagId<Test> = static_bag_id.into();
+        let less_than_allowed_new_bucket_number = 1;
+        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_new_storage_buckets_number(less_than_allowed_new_bucket_number)
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Err(
+                Error::<Test>::NumberOfStorageBucketsOutsideOfAllowedContraints.into(),
+            ));
 
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let more_than_allowed_new_bucket_number = 11;
+        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_new_storage_buckets_number(more_than_allowed_new_bucket_number)
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Err(
+                Error::<Test>::NumberOfStorageBucketsOutsideOfAllowedContraints.into(),
+            ));
+    });
+}
 
-        let bucket_index = CreateDistributionBucketFixture::new()
-            .with_family_id(family_id)
-            .with_accept_new_bags(true)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+#[test]
+fn update_number_of_storage_buckets_in_dynamic_bag_creation_policy_fails_with_bad_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1;
 
-        let add_buckets_indices = BTreeSet::from_iter(vec![bucket_index]);
+        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::new()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
 
-        UpdateDistributionBucketForBagsFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bag_id(bag_id.clone())
-            .with_family_id(family_id)
-            .with_add_bucket_indices(add_buckets_indices.clone())
+#[test]
+fn dynamic_bag_creation_policy_defaults_and_updates_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let new_bucket_number = 10;
+
+        // Change member dynamic bag creation policy.
+        let dynamic_bag_type = DynamicBagType::Member;
+        let policy = Storage::get_dynamic_bag_creation_policy(dynamic_bag_type);
+        assert_eq!(
+            policy.number_of_storage_buckets,
+            DefaultMemberDynamicBagNumberOfStorageBuckets::get()
+        );
+
+        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_new_storage_buckets_number(new_bucket_number)
+            .with_dynamic_bag_type(dynamic_bag_type)
             .call_and_assert(Ok(()));
 
-        let add_buckets = add_buckets_indices
-            .iter()
-            .map(|idx| Storage::create_distribution_bucket_id(family_id, *idx))
-            .collect::<BTreeSet<_>>();
-        let bag = Storage::bag(&bag_id);
-        assert_eq!(bag.distributed_by, add_buckets);
+        let policy = Storage::get_dynamic_bag_creation_policy(dynamic_bag_type);
+        assert_eq!(policy.number_of_storage_buckets, new_bucket_number);
 
-        DeleteDistributionBucketFixture::new()
-            .with_distribution_bucket_index(bucket_index)
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Err(Error::<Test>::DistributionBucketIsBoundToBag.into()));
+        // Change channel dynamic bag creation policy.
+        let dynamic_bag_type = DynamicBagType::Channel;
+        let policy = Storage::get_dynamic_bag_creation_policy(dynamic_bag_type);
+        assert_eq!(
+            policy.number_of_storage_buckets,
+            DefaultChannelDynamicBagNumberOfStorageBuckets::get()
+        );
+
+        UpdateNumberOfStorageBucketsInDynamicBagCreationPolicyFixture::new()
+            .with_origin(RawOrigin::Signed(STORAGE_WG_LEADER_ACCOUNT_ID))
+            .with_new_storage_buckets_number(new_bucket_number)
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Ok(()));
+
+        let policy = Storage::get_dynamic_bag_creation_policy(dynamic_bag_type);
+        assert_eq!(policy.number_of_storage_buckets, new_bucket_number);
     });
 }
 
 #[test]
-fn delete_distribution_bucket_failed_with_existing_operators() {
+fn create_distribution_bucket_family_succeeded() {
     build_test_externalities().execute_with(|| {
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        let bucket_index = CreateDistributionBucketFixture::new()
-            .with_family_id(family_id)
+        let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        InviteDistributionBucketOperatorFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bucket_index(bucket_index)
-            .with_family_id(family_id)
-            .with_operator_worker_id(DEFAULT_DISTRIBUTION_PROVIDER_ID)
-            .call_and_assert(Ok(()));
+        let bucket_family = Storage::distribution_bucket_family_by_id(family_id);
 
-        AcceptDistributionBucketInvitationFixture::new()
-            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
-            .with_family_id(family_id)
-            .with_bucket_index(bucket_index)
-            .with_worker_id(DEFAULT_DISTRIBUTION_PROVIDER_ID)
-            .call_and_assert(Ok(()));
+        assert_eq!(bucket_family, DistributionBucketFamily::<Test>::default());
 
-        DeleteDistributionBucketFixture::new()
-            .with_distribution_bucket_index(bucket_index)
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Err(Error::<Test>::DistributionProviderOperatorSet.into()));
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketFamilyCreated(family_id));
     });
 }
 
 #[test]
-fn delete_distribution_bucket_fails_with_non_leader_origin() {
+fn create_distribution_bucket_family_fails_with_non_signed_origin() {
     build_test_externalities().execute_with(|| {
-        let non_leader_id = 1111;
-
-        DeleteDistributionBucketFixture::new()
-            .with_origin(RawOrigin::Signed(non_leader_id))
+        CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::None)
             .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
 #[test]
-fn delete_distribution_bucket_fails_with_non_existing_distribution_bucket() {
+fn create_distribution_bucket_family_fails_with_exceeding_family_number_limit() {
     build_test_externalities().execute_with(|| {
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        for _ in 0..MaxDistributionBucketFamilyNumber::get() {
+            CreateDistributionBucketFamilyFixture::new()
+                .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+                .call_and_assert(Ok(()));
+        }
 
-        DeleteDistributionBucketFixture::new()
-            .with_family_id(family_id)
+        CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+            .call_and_assert(Err(
+                Error::<Test>::MaxDistributionBucketFamilyNumberLimitExceeded.into(),
+            ));
     });
 }
 
 #[test]
-fn update_distribution_buckets_for_bags_succeeded() {
+fn delete_distribution_bucket_family_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        set_default_distribution_buckets_per_bag_limit();
-
-        let static_bag_id = StaticBagId::Council;
-        let bag_id: BagId<Test> = static_bag_id.into();
-
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        let bucket_id = CreateDistributionBucketFixture::new()
+        DeleteDistributionBucketFamilyFixture::new()
             .with_family_id(family_id)
-            .with_accept_new_bags(true)
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
-
-        let add_buckets_ids = BTreeSet::from_iter(vec![bucket_id]);
-
-        UpdateDistributionBucketForBagsFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bag_id(bag_id.clone())
-            .with_family_id(family_id)
-            .with_add_bucket_indices(add_buckets_ids.clone())
             .call_and_assert(Ok(()));
 
-        let add_buckets = add_buckets_ids
-            .iter()
-            .map(|idx| Storage::create_distribution_bucket_id(family_id, *idx))
-            .collect::<BTreeSet<_>>();
-        let bag = Storage::bag(&bag_id);
-        assert_eq!(bag.distributed_by, add_buckets);
-
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketsUpdatedForBag(
-            bag_id,
-            family_id,
-            add_buckets_ids,
-            BTreeSet::new(),
-        ));
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketFamilyDeleted(family_id));
     });
 }
 
 #[test]
-fn update_distribution_buckets_for_bags_succeeded_with_additioonal_checks_on_adding_and_removing() {
+fn delete_distribution_bucket_family_fails_with_assgined_bags() {
     build_test_externalities().execute_with(|| {
         set_default_distribution_buckets_per_bag_limit();
 
@@ -4341,7 +4631,6 @@ fn update_distribution_buckets_for_bags_succeeded_with_additioonal_checks_on_add
             .with_add_bucket_indices(add_buckets_ids.clone())
             .call_and_assert(Ok(()));
 
-        // Add check
         let add_buckets = add_buckets_ids
             .iter()
             .map(|idx| Storage::create_distribution_bucket_id(family_id, *idx))
@@ -4349,56 +4638,232 @@ fn update_distribution_buckets_for_bags_succeeded_with_additioonal_checks_on_add
         let bag = Storage::bag(&bag_id);
         assert_eq!(bag.distributed_by, add_buckets);
 
-        let bucket = Storage::distribution_bucket_by_family_id_by_index(family_id, &bucket_id);
-        assert_eq!(bucket.assigned_bags, 1);
-
-        // ******
-
-        UpdateDistributionBucketForBagsFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bag_id(bag_id.clone())
+        DeleteDistributionBucketFamilyFixture::new()
             .with_family_id(family_id)
-            .with_remove_bucket_indices(add_buckets_ids)
-            .call_and_assert(Ok(()));
-
-        let bag = Storage::bag(&bag_id);
-        assert_eq!(bag.distributed_by.len(), 0);
-
-        let bucket = Storage::distribution_bucket_by_family_id_by_index(family_id, &bucket_id);
-        assert_eq!(bucket.assigned_bags, 0);
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::DistributionBucketIsBoundToBag.into()));
     });
 }
 
 #[test]
-fn update_distribution_buckets_for_bags_fails_with_non_existing_dynamic_bag() {
+fn delete_distribution_bucket_family_fails_with_bound_member_dynamic_bag_creation_policy() {
     build_test_externalities().execute_with(|| {
-        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
-        let bag_id: BagId<Test> = dynamic_bag_id.into();
-
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        let bucket_id = CreateDistributionBucketFixture::new()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
-
-        let add_buckets = BTreeSet::from_iter(vec![bucket_id]);
+        let new_bucket_number = 7;
+        let families = BTreeMap::from_iter(vec![(family_id, new_bucket_number)]);
+        let dynamic_bag_type = DynamicBagType::Member;
 
-        UpdateDistributionBucketForBagsFixture::new()
+        UpdateFamiliesInDynamicBagCreationPolicyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_family_id(family_id)
-            .with_bag_id(bag_id)
-            .with_add_bucket_indices(add_buckets)
-            .call_and_assert(Err(Error::<Test>::DynamicBagDoesntExist.into()));
+            .with_families(families)
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Ok(()));
+
+        DeleteDistributionBucketFamilyFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(
+                Error::<Test>::DistributionFamilyBoundToBagCreationPolicy.into(),
+            ));
     });
 }
 
 #[test]
-fn update_distribution_buckets_for_bags_fails_with_non_accepting_new_bags_bucket() {
+fn delete_distribution_bucket_family_fails_with_bound_channel_dynamic_bag_creation_policy() {
+    build_test_externalities().execute_with(|| {
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let new_bucket_number = 7;
+        let families = BTreeMap::from_iter(vec![(family_id, new_bucket_number)]);
+        let dynamic_bag_type = DynamicBagType::Channel;
+
+        UpdateFamiliesInDynamicBagCreationPolicyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_families(families)
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Ok(()));
+
+        DeleteDistributionBucketFamilyFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(
+                Error::<Test>::DistributionFamilyBoundToBagCreationPolicy.into(),
+            ));
+    });
+}
+
+#[test]
+fn delete_distribution_bucket_family_fails_with_non_signed_origin() {
+    build_test_externalities().execute_with(|| {
+        DeleteDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::None)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn delete_distribution_bucket_family_fails_with_non_existing_family() {
+    build_test_externalities().execute_with(|| {
+        DeleteDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(
+                Error::<Test>::DistributionBucketFamilyDoesntExist.into()
+            ));
+    });
+}
+
+#[test]
+fn create_distribution_bucket_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let accept_new_bags = true;
+
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_id = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_accept_new_bags(accept_new_bags)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        assert!(
+            crate::DistributionBucketByFamilyIdById::<Test>::contains_key(family_id, bucket_id)
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketCreated(
+            family_id,
+            accept_new_bags,
+            Storage::create_distribution_bucket_id(family_id, bucket_id),
+        ));
+    });
+}
+
+#[test]
+fn create_distribution_bucket_fails_with_non_signed_origin() {
+    build_test_externalities().execute_with(|| {
+        CreateDistributionBucketFixture::new()
+            .with_origin(RawOrigin::None)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn create_distribution_bucket_fails_with_non_existing_family() {
+    build_test_externalities().execute_with(|| {
+        CreateDistributionBucketFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(
+                Error::<Test>::DistributionBucketFamilyDoesntExist.into()
+            ));
+    });
+}
+
+#[test]
+fn update_distribution_bucket_status_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_index = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let new_status = true;
+        UpdateDistributionBucketStatusFixture::new()
+            .with_family_id(family_id)
+            .with_distribution_bucket_index(bucket_index)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_new_status(new_status)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Storage::distribution_bucket_by_family_id_by_index(family_id, &bucket_index)
+                .accepting_new_bags,
+            new_status
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketStatusUpdated(
+            Storage::create_distribution_bucket_id(family_id, bucket_index),
+            new_status,
+        ));
+    });
+}
+
+#[test]
+fn update_distribution_bucket_status_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        UpdateDistributionBucketStatusFixture::new()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn update_distribution_bucket_status_fails_with_invalid_distribution_bucket() {
+    build_test_externalities().execute_with(|| {
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        UpdateDistributionBucketStatusFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+    });
+}
+
+#[test]
+fn delete_distribution_bucket_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_index = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        DeleteDistributionBucketFixture::new()
+            .with_distribution_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketDeleted(
+            Storage::create_distribution_bucket_id(family_id, bucket_index),
+        ));
+    });
+}
+
+#[test]
+fn delete_distribution_bucket_fails_with_assgined_bags() {
     build_test_externalities().execute_with(|| {
         set_default_distribution_buckets_per_bag_limit();
 
@@ -4410,143 +4875,780 @@ fn update_distribution_buckets_for_bags_fails_with_non_accepting_new_bags_bucket
             .call_and_assert(Ok(()))
             .unwrap();
 
-        let bucket_id = CreateDistributionBucketFixture::new()
+        let bucket_index = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_accept_new_bags(true)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let add_buckets_indices = BTreeSet::from_iter(vec![bucket_index]);
+
+        UpdateDistributionBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_family_id(family_id)
+            .with_add_bucket_indices(add_buckets_indices.clone())
+            .call_and_assert(Ok(()));
+
+        let add_buckets = add_buckets_indices
+            .iter()
+            .map(|idx| Storage::create_distribution_bucket_id(family_id, *idx))
+            .collect::<BTreeSet<_>>();
+        let bag = Storage::bag(&bag_id);
+        assert_eq!(bag.distributed_by, add_buckets);
+
+        DeleteDistributionBucketFixture::new()
+            .with_distribution_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::DistributionBucketIsBoundToBag.into()));
+    });
+}
+
+#[test]
+fn delete_distribution_bucket_failed_with_existing_operators() {
+    build_test_externalities().execute_with(|| {
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_index = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        InviteDistributionBucketOperatorFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_operator_worker_id(DEFAULT_DISTRIBUTION_PROVIDER_ID)
+            .call_and_assert(Ok(()));
+
+        AcceptDistributionBucketInvitationFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .with_worker_id(DEFAULT_DISTRIBUTION_PROVIDER_ID)
+            .call_and_assert(Ok(()));
+
+        DeleteDistributionBucketFixture::new()
+            .with_distribution_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::DistributionProviderOperatorSet.into()));
+    });
+}
+
+#[test]
+fn delete_distribution_bucket_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 1111;
+
+        DeleteDistributionBucketFixture::new()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn delete_distribution_bucket_fails_with_non_existing_distribution_bucket() {
+    build_test_externalities().execute_with(|| {
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        DeleteDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+    });
+}
+
+#[test]
+fn update_distribution_buckets_for_bags_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        set_default_distribution_buckets_per_bag_limit();
+
+        let static_bag_id = StaticBagId::Council;
+        let bag_id: BagId<Test> = static_bag_id.into();
+
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_id = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_accept_new_bags(true)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let add_buckets_ids = BTreeSet::from_iter(vec![bucket_id]);
+
+        UpdateDistributionBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_family_id(family_id)
+            .with_add_bucket_indices(add_buckets_ids.clone())
+            .call_and_assert(Ok(()));
+
+        let add_buckets = add_buckets_ids
+            .iter()
+            .map(|idx| Storage::create_distribution_bucket_id(family_id, *idx))
+            .collect::<BTreeSet<_>>();
+        let bag = Storage::bag(&bag_id);
+        assert_eq!(bag.distributed_by, add_buckets);
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketsUpdatedForBag(
+            bag_id,
+            family_id,
+            add_buckets_ids,
+            BTreeSet::new(),
+        ));
+    });
+}
+
+#[test]
+fn update_distribution_buckets_for_bags_succeeded_with_additioonal_checks_on_adding_and_removing() {
+    build_test_externalities().execute_with(|| {
+        set_default_distribution_buckets_per_bag_limit();
+
+        let static_bag_id = StaticBagId::Council;
+        let bag_id: BagId<Test> = static_bag_id.into();
+
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_id = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_accept_new_bags(true)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let add_buckets_ids = BTreeSet::from_iter(vec![bucket_id]);
+
+        UpdateDistributionBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_family_id(family_id)
+            .with_add_bucket_indices(add_buckets_ids.clone())
+            .call_and_assert(Ok(()));
+
+        // Add check
+        let add_buckets = add_buckets_ids
+            .iter()
+            .map(|idx| Storage::create_distribution_bucket_id(family_id, *idx))
+            .collect::<BTreeSet<_>>();
+        let bag = Storage::bag(&bag_id);
+        assert_eq!(bag.distributed_by, add_buckets);
+
+        let bucket = Storage::distribution_bucket_by_family_id_by_index(family_id, &bucket_id);
+        assert_eq!(bucket.assigned_bags, 1);
+
+        // ******
+
+        UpdateDistributionBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_family_id(family_id)
+            .with_remove_bucket_indices(add_buckets_ids)
+            .call_and_assert(Ok(()));
+
+        let bag = Storage::bag(&bag_id);
+        assert_eq!(bag.distributed_by.len(), 0);
+
+        let bucket = Storage::distribution_bucket_by_family_id_by_index(family_id, &bucket_id);
+        assert_eq!(bucket.assigned_bags, 0);
+    });
+}
+
+#[test]
+fn update_distribution_buckets_for_bags_fails_with_non_existing_dynamic_bag() {
+    build_test_externalities().execute_with(|| {
+        let dynamic_bag_id = DynamicBagId::<Test>::Member(DEFAULT_MEMBER_ID);
+        let bag_id: BagId<Test> = dynamic_bag_id.into();
+
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_id = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let add_buckets = BTreeSet::from_iter(vec![bucket_id]);
+
+        UpdateDistributionBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bag_id(bag_id)
+            .with_add_bucket_indices(add_buckets)
+            .call_and_assert(Err(Error::<Test>::DynamicBagDoesntExist.into()));
+    });
+}
+
+#[test]
+fn update_distribution_buckets_for_bags_fails_with_non_accepting_new_bags_bucket() {
+    build_test_externalities().execute_with(|| {
+        set_default_distribution_buckets_per_bag_limit();
+
+        let static_bag_id = StaticBagId::Council;
+        let bag_id: BagId<Test> = static_bag_id.into();
+
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_id = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_accept_new_bags(false)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let add_buckets = BTreeSet::from_iter(vec![bucket_id]);
+
+        UpdateDistributionBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bag_id(bag_id)
+            .with_add_bucket_indices(add_buckets)
+            .call_and_assert(Err(
+                Error::<Test>::DistributionBucketDoesntAcceptNewBags.into()
+            ));
+    });
+}
+
+#[test]
+fn update_distribution_buckets_for_bags_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 11;
+
+        UpdateDistributionBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn update_distribution_buckets_for_bags_fails_with_empty_params() {
+    build_test_externalities().execute_with(|| {
+        UpdateDistributionBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(
+                Error::<Test>::DistributionBucketIdCollectionsAreEmpty.into()
+            ));
+    });
+}
+
+#[test]
+fn update_distribution_buckets_for_bags_fails_with_non_existing_distribution_buckets() {
+    build_test_externalities().execute_with(|| {
+        set_default_distribution_buckets_per_bag_limit();
+
+        let invalid_bucket_id = 11000;
+        let buckets = BTreeSet::from_iter(vec![invalid_bucket_id]);
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
+
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        // Invalid added bucket ID.
+        UpdateDistributionBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_family_id(family_id)
+            .with_add_bucket_indices(buckets.clone())
+            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+
+        // Invalid removed bucket ID.
+        UpdateDistributionBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id)
+            .with_family_id(family_id)
+            .with_remove_bucket_indices(buckets)
+            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+    });
+}
+
+fn set_default_distribution_buckets_per_bag_limit() {
+    crate::DistributionBucketsPerBagLimit::put(5);
+}
+
+#[test]
+fn update_distribution_buckets_per_bag_limit_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let new_limit = 4;
+
+        UpdateDistributionBucketsPerBagLimitFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_new_limit(new_limit)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketsPerBagLimitUpdated(
+            new_limit,
+        ));
+    });
+}
+
+#[test]
+fn update_distribution_buckets_per_bag_limit_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 11;
+
+        UpdateDistributionBucketsPerBagLimitFixture::new()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn update_distribution_buckets_per_bag_limit_fails_with_incorrect_value() {
+    build_test_externalities().execute_with(|| {
+        let new_limit = 0;
+
+        UpdateDistributionBucketsPerBagLimitFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_new_limit(new_limit)
+            .call_and_assert(Err(
+                Error::<Test>::DistributionBucketsPerBagLimitTooLow.into()
+            ));
+
+        let new_limit = 100;
+
+        UpdateDistributionBucketsPerBagLimitFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_new_limit(new_limit)
+            .call_and_assert(Err(
+                Error::<Test>::DistributionBucketsPerBagLimitTooHigh.into()
+            ));
+    });
+}
+
+#[test]
+fn update_distribution_bucket_mode_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_index = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let distributing = false;
+        UpdateDistributionBucketModeFixture::new()
+            .with_family_id(family_id)
+            .with_distribution_bucket_index(bucket_index)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_distributing(distributing)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            Storage::distribution_bucket_by_family_id_by_index(family_id, &bucket_index)
+                .accepting_new_bags,
+            distributing
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketModeUpdated(
+            Storage::create_distribution_bucket_id(family_id, bucket_index),
+            distributing,
+        ));
+    });
+}
+
+#[test]
+fn update_distribution_bucket_mode_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        UpdateDistributionBucketModeFixture::new()
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn update_distribution_bucket_mode_fails_with_invalid_distribution_bucket() {
+    build_test_externalities().execute_with(|| {
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        UpdateDistributionBucketModeFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+    });
+}
+
+#[test]
+fn update_families_in_dynamic_bag_creation_policy_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let dynamic_bag_type = DynamicBagType::Channel;
+        let new_bucket_number = 7;
+
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let families = BTreeMap::from_iter(vec![(family_id, new_bucket_number)]);
+
+        UpdateFamiliesInDynamicBagCreationPolicyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_families(families.clone())
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::FamiliesInDynamicBagCreationPolicyUpdated(
+            dynamic_bag_type,
+            families,
+        ));
+    });
+}
+
+#[test]
+fn update_families_in_dynamic_bag_creation_policy_fails_with_bad_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 11;
+
+        UpdateFamiliesInDynamicBagCreationPolicyFixture::new()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn update_families_in_dynamic_bag_creation_policy_fails_with_invalid_family_id() {
+    build_test_externalities().execute_with(|| {
+        let dynamic_bag_type = DynamicBagType::Channel;
+        let new_bucket_number = 7;
+        let invalid_family_id = 111;
+
+        let families = BTreeMap::from_iter(vec![(invalid_family_id, new_bucket_number)]);
+
+        UpdateFamiliesInDynamicBagCreationPolicyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_families(families)
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Err(
+                Error::<Test>::DistributionBucketFamilyDoesntExist.into()
+            ));
+    });
+}
+
+#[test]
+fn update_families_in_dynamic_bag_creation_policy_fails_with_too_many_buckets_per_bag() {
+    build_test_externalities().execute_with(|| {
+        let dynamic_bag_type = DynamicBagType::Channel;
+
+        for _ in 0..2 {
+            CreateDistributionBucketFamilyFixture::new()
+                .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+                .call_and_assert(Ok(()));
+        }
+
+        let min_buckets_per_bag: u32 = <Test as crate::Config>::MinDistributionBucketsPerBag::get();
+        let families = BTreeMap::from_iter(vec![
+            (Storage::next_distribution_bucket_family_id() - 1, u32::MAX), // u32 overflow case test
+            (
+                Storage::next_distribution_bucket_family_id() - 2,
+                min_buckets_per_bag,
+            ),
+        ]);
+
+        UpdateFamiliesInDynamicBagCreationPolicyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_families(families)
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Err(
+                Error::<Test>::NumberOfDistributionBucketsOutsideOfAllowedContraints.into(),
+            ));
+    });
+}
+
+#[test]
+fn update_families_in_dynamic_bag_creation_policy_fails_with_not_enough_buckets_per_bag() {
+    build_test_externalities().execute_with(|| {
+        let dynamic_bag_type = DynamicBagType::Channel;
+
+        CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()));
+
+        let min_buckets_per_bag: u32 = <Test as crate::Config>::MinDistributionBucketsPerBag::get();
+        let families = BTreeMap::from_iter(vec![(
+            Storage::next_distribution_bucket_family_id() - 1,
+            min_buckets_per_bag.saturating_sub(1),
+        )]);
+
+        UpdateFamiliesInDynamicBagCreationPolicyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_families(families)
+            .with_dynamic_bag_type(dynamic_bag_type)
+            .call_and_assert(Err(
+                Error::<Test>::NumberOfDistributionBucketsOutsideOfAllowedContraints.into(),
+            ));
+    });
+}
+
+fn create_distribution_bucket_family_with_buckets(
+    bucket_number: u64,
+) -> (u64, Vec<DistributionBucketId<Test>>) {
+    let family_id = CreateDistributionBucketFamilyFixture::new()
+        .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        .call_and_assert(Ok(()))
+        .unwrap();
+
+    let bucket_ids = repeat(family_id)
+        .take(bucket_number as usize)
+        .map(|fam_id| {
+            let bucket_index = CreateDistributionBucketFixture::new()
+                .with_family_id(fam_id)
+                .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+                .with_accept_new_bags(true)
+                .call_and_assert(Ok(()))
+                .unwrap();
+
+            Storage::create_distribution_bucket_id(fam_id, bucket_index)
+        })
+        .collect::<Vec<_>>();
+
+    (family_id, bucket_ids)
+}
+
+#[test]
+fn invite_distribution_bucket_operator_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let provider_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
+
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_index = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        InviteDistributionBucketOperatorFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_operator_worker_id(provider_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketOperatorInvited(
+            Storage::create_distribution_bucket_id(family_id, bucket_index),
+            provider_id,
+        ));
+    });
+}
+
+#[test]
+fn invite_distribution_bucket_operator_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_id = 11;
+
+        InviteDistributionBucketOperatorFixture::new()
+            .with_origin(RawOrigin::Signed(non_leader_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn invite_distribution_bucket_operator_fails_with_non_existing_distribution_bucket() {
+    build_test_externalities().execute_with(|| {
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        InviteDistributionBucketOperatorFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+    });
+}
+
+#[test]
+fn invite_distribution_bucket_operator_fails_with_non_missing_invitation() {
+    build_test_externalities().execute_with(|| {
+        let invited_worker_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
+
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_index = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        InviteDistributionBucketOperatorFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_operator_worker_id(invited_worker_id)
+            .call_and_assert(Ok(()));
+
+        InviteDistributionBucketOperatorFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_operator_worker_id(invited_worker_id)
+            .call_and_assert(Err(
+                Error::<Test>::DistributionProviderOperatorAlreadyInvited.into(),
+            ));
+    });
+}
+
+#[test]
+fn invite_distribution_bucket_operator_fails_with_exceeding_the_limit_of_pending_invitations() {
+    build_test_externalities().execute_with(|| {
+        let invited_worker_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
+        let another_worker_id = ANOTHER_DISTRIBUTION_PROVIDER_ID;
+
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_index = CreateDistributionBucketFixture::new()
             .with_family_id(family_id)
-            .with_accept_new_bags(false)
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        let add_buckets = BTreeSet::from_iter(vec![bucket_id]);
-
-        UpdateDistributionBucketForBagsFixture::new()
+        InviteDistributionBucketOperatorFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bucket_index(bucket_index)
             .with_family_id(family_id)
-            .with_bag_id(bag_id)
-            .with_add_bucket_indices(add_buckets)
-            .call_and_assert(Err(
-                Error::<Test>::DistributionBucketDoesntAcceptNewBags.into()
-            ));
-    });
-}
-
-#[test]
-fn update_distribution_buckets_for_bags_fails_with_non_leader_origin() {
-    build_test_externalities().execute_with(|| {
-        let non_leader_id = 11;
-
-        UpdateDistributionBucketForBagsFixture::new()
-            .with_origin(RawOrigin::Signed(non_leader_id))
-            .call_and_assert(Err(DispatchError::BadOrigin));
-    });
-}
+            .with_operator_worker_id(invited_worker_id)
+            .call_and_assert(Ok(()));
 
-#[test]
-fn update_distribution_buckets_for_bags_fails_with_empty_params() {
-    build_test_externalities().execute_with(|| {
-        UpdateDistributionBucketForBagsFixture::new()
+        InviteDistributionBucketOperatorFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_operator_worker_id(another_worker_id)
             .call_and_assert(Err(
-                Error::<Test>::DistributionBucketIdCollectionsAreEmpty.into()
+                Error::<Test>::MaxNumberOfPendingInvitationsLimitForDistributionBucketReached
+                    .into(),
             ));
     });
 }
 
 #[test]
-fn update_distribution_buckets_for_bags_fails_with_non_existing_distribution_buckets() {
+fn invite_distribution_bucket_operator_fails_with_already_set_operator() {
     build_test_externalities().execute_with(|| {
-        set_default_distribution_buckets_per_bag_limit();
-
-        let invalid_bucket_id = 11000;
-        let buckets = BTreeSet::from_iter(vec![invalid_bucket_id]);
-        let bag_id: BagId<Test> = StaticBagId::Council.into();
+        let invited_worker_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
 
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        // Invalid added bucket ID.
-        UpdateDistributionBucketForBagsFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bag_id(bag_id.clone())
+        let bucket_index = CreateDistributionBucketFixture::new()
             .with_family_id(family_id)
-            .with_add_bucket_indices(buckets.clone())
-            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
-
-        // Invalid removed bucket ID.
-        UpdateDistributionBucketForBagsFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bag_id(bag_id)
-            .with_family_id(family_id)
-            .with_remove_bucket_indices(buckets)
-            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
-    });
-}
-
-fn set_default_distribution_buckets_per_bag_limit() {
-    crate::DistributionBucketsPerBagLimit::put(5);
-}
-
-#[test]
-fn update_distribution_buckets_per_bag_limit_succeeded() {
-    build_test_externalities().execute_with(|| {
-        let starting_block = 1;
-        run_to_block(starting_block);
-
-        let new_limit = 4;
+            .call_and_assert(Ok(()))
+            .unwrap();
 
-        UpdateDistributionBucketsPerBagLimitFixture::new()
+        InviteDistributionBucketOperatorFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_new_limit(new_limit)
+            .with_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_operator_worker_id(invited_worker_id)
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketsPerBagLimitUpdated(
-            new_limit,
-        ));
-    });
-}
-
-#[test]
-fn update_distribution_buckets_per_bag_limit_origin() {
-    build_test_externalities().execute_with(|| {
-        let non_leader_id = 11;
+        AcceptDistributionBucketInvitationFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_worker_id(invited_worker_id)
+            .call_and_assert(Ok(()));
 
-        UpdateDistributionBucketsPerBagLimitFixture::new()
-            .with_origin(RawOrigin::Signed(non_leader_id))
-            .call_and_assert(Err(DispatchError::BadOrigin));
+        InviteDistributionBucketOperatorFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_operator_worker_id(invited_worker_id)
+            .call_and_assert(Err(Error::<Test>::DistributionProviderOperatorSet.into()));
     });
 }
 
 #[test]
-fn update_distribution_buckets_per_bag_limit_fails_with_incorrect_value() {
+fn invite_distribution_bucket_operator_fails_with_invalid_distribution_provider_id() {
     build_test_externalities().execute_with(|| {
-        let new_limit = 0;
+        let invalid_provider_id = 155;
 
-        UpdateDistributionBucketsPerBagLimitFixture::new()
+        let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_new_limit(new_limit)
-            .call_and_assert(Err(
-                Error::<Test>::DistributionBucketsPerBagLimitTooLow.into()
-            ));
+            .call_and_assert(Ok(()))
+            .unwrap();
 
-        let new_limit = 100;
+        let bucket_index = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
 
-        UpdateDistributionBucketsPerBagLimitFixture::new()
+        InviteDistributionBucketOperatorFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_new_limit(new_limit)
+            .with_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_operator_worker_id(invalid_provider_id)
             .call_and_assert(Err(
-                Error::<Test>::DistributionBucketsPerBagLimitTooHigh.into()
+                Error::<Test>::DistributionProviderOperatorDoesntExist.into()
             ));
     });
 }
 
 #[test]
-fn update_distribution_bucket_mode_succeeded() {
+fn cancel_distribution_bucket_operator_invite_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
+        let provider_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
+
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
@@ -4558,45 +5660,47 @@ fn update_distribution_bucket_mode_succeeded() {
             .call_and_assert(Ok(()))
             .unwrap();
 
-        let distributing = false;
-        UpdateDistributionBucketModeFixture::new()
-            .with_family_id(family_id)
-            .with_distribution_bucket_index(bucket_index)
+        InviteDistributionBucketOperatorFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_distributing(distributing)
+            .with_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_operator_worker_id(provider_id)
             .call_and_assert(Ok(()));
 
-        assert_eq!(
-            Storage::distribution_bucket_by_family_id_by_index(family_id, &bucket_index)
-                .accepting_new_bags,
-            distributing
-        );
+        CancelDistributionBucketInvitationFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .with_operator_worker_id(provider_id)
+            .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketModeUpdated(
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketInvitationCancelled(
             Storage::create_distribution_bucket_id(family_id, bucket_index),
-            distributing,
+            provider_id,
         ));
     });
 }
 
 #[test]
-fn update_distribution_bucket_mode_fails_with_invalid_origin() {
+fn cancel_distribution_bucket_operator_invite_fails_with_non_leader_origin() {
     build_test_externalities().execute_with(|| {
-        UpdateDistributionBucketModeFixture::new()
-            .with_origin(RawOrigin::Root)
+        let non_leader_account_id = 11111;
+
+        CancelDistributionBucketInvitationFixture::new()
+            .with_origin(RawOrigin::Signed(non_leader_account_id))
             .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
 #[test]
-fn update_distribution_bucket_mode_fails_with_invalid_distribution_bucket() {
+fn cancel_distribution_bucket_operator_invite_fails_with_non_existing_distribution_bucket() {
     build_test_externalities().execute_with(|| {
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        UpdateDistributionBucketModeFixture::new()
+        CancelDistributionBucketInvitationFixture::new()
             .with_family_id(family_id)
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
@@ -4604,151 +5708,165 @@ fn update_distribution_bucket_mode_fails_with_invalid_distribution_bucket() {
 }
 
 #[test]
-fn update_families_in_dynamic_bag_creation_policy_succeeded() {
+fn cancel_distribution_bucket_operator_invite_fails_with_non_invited_distribution_provider() {
+    build_test_externalities().execute_with(|| {
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let bucket_index = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        CancelDistributionBucketInvitationFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .call_and_assert(Err(Error::<Test>::NoDistributionBucketInvitation.into()));
+    });
+}
+
+#[test]
+fn accept_distribution_bucket_operator_invite_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let dynamic_bag_type = DynamicBagType::Channel;
-        let new_bucket_number = 7;
+        let provider_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
 
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        let families = BTreeMap::from_iter(vec![(family_id, new_bucket_number)]);
+        let bucket_index = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
 
-        UpdateFamiliesInDynamicBagCreationPolicyFixture::new()
+        InviteDistributionBucketOperatorFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_families(families.clone())
-            .with_dynamic_bag_type(dynamic_bag_type)
+            .with_bucket_index(bucket_index)
+            .with_family_id(family_id)
+            .with_operator_worker_id(provider_id)
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::FamiliesInDynamicBagCreationPolicyUpdated(
-            dynamic_bag_type,
-            families,
-        ));
-    });
-}
-
-#[test]
-fn update_families_in_dynamic_bag_creation_policy_fails_with_bad_origin() {
-    build_test_externalities().execute_with(|| {
-        let non_leader_id = 11;
+        AcceptDistributionBucketInvitationFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .with_worker_id(provider_id)
+            .call_and_assert(Ok(()));
 
-        UpdateFamiliesInDynamicBagCreationPolicyFixture::new()
-            .with_origin(RawOrigin::Signed(non_leader_id))
-            .call_and_assert(Err(DispatchError::BadOrigin));
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketInvitationAccepted(
+            provider_id,
+            Storage::create_distribution_bucket_id(family_id, bucket_index),
+        ));
     });
 }
 
 #[test]
-fn update_families_in_dynamic_bag_creation_policy_fails_with_invalid_family_id() {
-    build_test_externalities().execute_with(|| {
-        let dynamic_bag_type = DynamicBagType::Channel;
-        let new_bucket_number = 7;
-        let invalid_family_id = 111;
-
-        let families = BTreeMap::from_iter(vec![(invalid_family_id, new_bucket_number)]);
-
-        UpdateFamiliesInDynamicBagCreationPolicyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_families(families)
-            .with_dynamic_bag_type(dynamic_bag_type)
-            .call_and_assert(Err(
-                Error::<Test>::DistributionBucketFamilyDoesntExist.into()
-            ));
+fn accept_distribution_bucket_operator_invite_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let invalid_account_id = 11111;
+
+        AcceptDistributionBucketInvitationFixture::new()
+            .with_origin(RawOrigin::Signed(invalid_account_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
 #[test]
-fn update_families_in_dynamic_bag_creation_policy_fails_with_too_many_buckets_per_bag() {
+fn accept_distribution_bucket_operator_invite_fails_with_non_existing_distribution_bucket() {
     build_test_externalities().execute_with(|| {
-        let dynamic_bag_type = DynamicBagType::Channel;
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
 
-        for _ in 0..2 {
-            CreateDistributionBucketFamilyFixture::new()
-                .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-                .call_and_assert(Ok(()));
-        }
+        AcceptDistributionBucketInvitationFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+    });
+}
 
-        let min_buckets_per_bag: u32 = <Test as crate::Config>::MinDistributionBucketsPerBag::get();
-        let families = BTreeMap::from_iter(vec![
-            (Storage::next_distribution_bucket_family_id() - 1, u32::MAX), // u32 overflow case test
-            (
-                Storage::next_distribution_bucket_family_id() - 2,
-                min_buckets_per_bag,
-            ),
-        ]);
+#[test]
+fn accept_distribution_bucket_operator_invite_fails_with_non_invited_distribution_provider() {
+    build_test_externalities().execute_with(|| {
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
 
-        UpdateFamiliesInDynamicBagCreationPolicyFixture::new()
+        let bucket_index = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_families(families)
-            .with_dynamic_bag_type(dynamic_bag_type)
-            .call_and_assert(Err(
-                Error::<Test>::NumberOfDistributionBucketsOutsideOfAllowedContraints.into(),
-            ));
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        AcceptDistributionBucketInvitationFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .call_and_assert(Err(Error::<Test>::NoDistributionBucketInvitation.into()));
     });
 }
 
 #[test]
-fn update_families_in_dynamic_bag_creation_policy_fails_with_not_enough_buckets_per_bag() {
+fn accept_distribution_bucket_operator_invite_fails_with_max_number_of_operators_reached() {
     build_test_externalities().execute_with(|| {
-        let dynamic_bag_type = DynamicBagType::Channel;
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        CreateDistributionBucketFamilyFixture::new()
+        let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()));
-
-        let min_buckets_per_bag: u32 = <Test as crate::Config>::MinDistributionBucketsPerBag::get();
-        let families = BTreeMap::from_iter(vec![(
-            Storage::next_distribution_bucket_family_id() - 1,
-            min_buckets_per_bag.saturating_sub(1),
-        )]);
+            .call_and_assert(Ok(()))
+            .unwrap();
 
-        UpdateFamiliesInDynamicBagCreationPolicyFixture::new()
+        let bucket_index = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_families(families)
-            .with_dynamic_bag_type(dynamic_bag_type)
-            .call_and_assert(Err(
-                Error::<Test>::NumberOfDistributionBucketsOutsideOfAllowedContraints.into(),
-            ));
-    });
-}
-
-fn create_distribution_bucket_family_with_buckets(
-    bucket_number: u64,
-) -> (u64, Vec<DistributionBucketId<Test>>) {
-    let family_id = CreateDistributionBucketFamilyFixture::new()
-        .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-        .call_and_assert(Ok(()))
-        .unwrap();
+            .call_and_assert(Ok(()))
+            .unwrap();
 
-    let bucket_ids = repeat(family_id)
-        .take(bucket_number as usize)
-        .map(|fam_id| {
-            let bucket_index = CreateDistributionBucketFixture::new()
-                .with_family_id(fam_id)
+        for i in 0..=<Test as Config>::MaxNumberOfOperatorsPerDistributionBucket::get() {
+            let operator_id = DISTRIBUTION_PROVIDER_IDS[i as usize];
+            let expected_result = match i
+                == <Test as Config>::MaxNumberOfOperatorsPerDistributionBucket::get()
+            {
+                true => Err(Error::<Test>::MaxNumberOfOperatorsPerDistributionBucketReached.into()),
+                false => DispatchResult::Ok(()),
+            };
+            InviteDistributionBucketOperatorFixture::new()
                 .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-                .with_accept_new_bags(true)
-                .call_and_assert(Ok(()))
-                .unwrap();
-
-            Storage::create_distribution_bucket_id(fam_id, bucket_index)
-        })
-        .collect::<Vec<_>>();
+                .with_bucket_index(bucket_index)
+                .with_family_id(family_id)
+                .with_operator_worker_id(operator_id)
+                .call_and_assert(Ok(()));
 
-    (family_id, bucket_ids)
+            AcceptDistributionBucketInvitationFixture::new()
+                .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+                .with_family_id(family_id)
+                .with_bucket_index(bucket_index)
+                .with_worker_id(operator_id)
+                .call_and_assert(expected_result);
+        }
+    });
 }
 
 #[test]
-fn invite_distribution_bucket_operator_succeeded() {
+fn set_distribution_operator_metadata_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
         let provider_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
+        let metadata = b"Metadata".to_vec();
 
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
@@ -4768,44 +5886,58 @@ fn invite_distribution_bucket_operator_succeeded() {
             .with_operator_worker_id(provider_id)
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketOperatorInvited(
-            Storage::create_distribution_bucket_id(family_id, bucket_index),
+        AcceptDistributionBucketInvitationFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .with_worker_id(provider_id)
+            .call_and_assert(Ok(()));
+
+        SetDistributionBucketMetadataFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .with_worker_id(provider_id)
+            .with_metadata(metadata.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketMetadataSet(
             provider_id,
+            Storage::create_distribution_bucket_id(family_id, bucket_index),
+            metadata,
         ));
     });
 }
 
 #[test]
-fn invite_distribution_bucket_operator_fails_with_non_leader_origin() {
+fn set_distribution_operator_metadata_fails_with_non_leader_origin() {
     build_test_externalities().execute_with(|| {
-        let non_leader_id = 11;
+        let invalid_account_id = 11111;
 
-        InviteDistributionBucketOperatorFixture::new()
-            .with_origin(RawOrigin::Signed(non_leader_id))
+        SetDistributionBucketMetadataFixture::new()
+            .with_origin(RawOrigin::Signed(invalid_account_id))
             .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
 #[test]
-fn invite_distribution_bucket_operator_fails_with_non_existing_distribution_bucket() {
+fn set_distribution_operator_metadata_fails_with_non_existing_distribution_bucket() {
     build_test_externalities().execute_with(|| {
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        InviteDistributionBucketOperatorFixture::new()
+        SetDistributionBucketMetadataFixture::new()
             .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
             .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
     });
 }
 
 #[test]
-fn invite_distribution_bucket_operator_fails_with_non_missing_invitation() {
+fn set_distribution_operator_metadata_fails_with_non_distribution_provider() {
     build_test_externalities().execute_with(|| {
-        let invited_worker_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
-
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
@@ -4817,29 +5949,23 @@ fn invite_distribution_bucket_operator_fails_with_non_missing_invitation() {
             .call_and_assert(Ok(()))
             .unwrap();
 
-        InviteDistributionBucketOperatorFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bucket_index(bucket_index)
+        SetDistributionBucketMetadataFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
             .with_family_id(family_id)
-            .with_operator_worker_id(invited_worker_id)
-            .call_and_assert(Ok(()));
-
-        InviteDistributionBucketOperatorFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .with_bucket_index(bucket_index)
-            .with_family_id(family_id)
-            .with_operator_worker_id(invited_worker_id)
             .call_and_assert(Err(
-                Error::<Test>::DistributionProviderOperatorAlreadyInvited.into(),
+                Error::<Test>::MustBeDistributionProviderOperatorForBucket.into(),
             ));
     });
 }
 
 #[test]
-fn invite_distribution_bucket_operator_fails_with_exceeding_the_limit_of_pending_invitations() {
+fn remove_distribution_bucket_operator_succeeded() {
     build_test_externalities().execute_with(|| {
-        let invited_worker_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
-        let another_worker_id = ANOTHER_DISTRIBUTION_PROVIDER_ID;
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let operator_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
 
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
@@ -4856,25 +5982,59 @@ fn invite_distribution_bucket_operator_fails_with_exceeding_the_limit_of_pending
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .with_bucket_index(bucket_index)
             .with_family_id(family_id)
-            .with_operator_worker_id(invited_worker_id)
+            .with_operator_worker_id(operator_id)
             .call_and_assert(Ok(()));
 
-        InviteDistributionBucketOperatorFixture::new()
+        AcceptDistributionBucketInvitationFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .with_worker_id(operator_id)
+            .call_and_assert(Ok(()));
+
+        RemoveDistributionBucketOperatorFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_family_id(family_id)
             .with_bucket_index(bucket_index)
+            .with_operator_worker_id(operator_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketOperatorRemoved(
+            Storage::create_distribution_bucket_id(family_id, bucket_index),
+            operator_id,
+        ));
+    });
+}
+
+#[test]
+fn remove_distribution_bucket_operator_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let non_leader_account = 11;
+        RemoveDistributionBucketOperatorFixture::new()
+            .with_origin(RawOrigin::Signed(non_leader_account))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn remove_distribution_bucket_operator_fails_with_non_existing_distribution_bucket() {
+    build_test_externalities().execute_with(|| {
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        RemoveDistributionBucketOperatorFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .with_family_id(family_id)
-            .with_operator_worker_id(another_worker_id)
-            .call_and_assert(Err(
-                Error::<Test>::MaxNumberOfPendingInvitationsLimitForDistributionBucketReached
-                    .into(),
-            ));
+            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
     });
 }
 
 #[test]
-fn invite_distribution_bucket_operator_fails_with_already_set_operator() {
+fn remove_distribution_bucket_operator_fails_with_non_accepted_distribution_provider() {
     build_test_externalities().execute_with(|| {
-        let invited_worker_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
+        let operator_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
 
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
@@ -4887,125 +6047,187 @@ fn invite_distribution_bucket_operator_fails_with_already_set_operator() {
             .call_and_assert(Ok(()))
             .unwrap();
 
-        InviteDistributionBucketOperatorFixture::new()
+        RemoveDistributionBucketOperatorFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bucket_index(bucket_index)
             .with_family_id(family_id)
-            .with_operator_worker_id(invited_worker_id)
-            .call_and_assert(Ok(()));
+            .with_bucket_index(bucket_index)
+            .with_operator_worker_id(operator_id)
+            .call_and_assert(Err(
+                Error::<Test>::MustBeDistributionProviderOperatorForBucket.into(),
+            ));
 
-        AcceptDistributionBucketInvitationFixture::new()
-            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+        InviteDistributionBucketOperatorFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .with_bucket_index(bucket_index)
             .with_family_id(family_id)
-            .with_worker_id(invited_worker_id)
+            .with_operator_worker_id(operator_id)
             .call_and_assert(Ok(()));
 
-        InviteDistributionBucketOperatorFixture::new()
+        RemoveDistributionBucketOperatorFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bucket_index(bucket_index)
             .with_family_id(family_id)
-            .with_operator_worker_id(invited_worker_id)
-            .call_and_assert(Err(Error::<Test>::DistributionProviderOperatorSet.into()));
+            .with_bucket_index(bucket_index)
+            .with_operator_worker_id(operator_id)
+            .call_and_assert(Err(
+                Error::<Test>::MustBeDistributionProviderOperatorForBucket.into(),
+            ));
     });
 }
 
 #[test]
-fn invite_distribution_bucket_operator_fails_with_invalid_distribution_provider_id() {
+fn set_distribution_bucket_family_metadata_succeeded() {
     build_test_externalities().execute_with(|| {
-        let invalid_provider_id = 155;
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let metadata = b"Metadata".to_vec();
 
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        let bucket_index = CreateDistributionBucketFixture::new()
-            .with_family_id(family_id)
+        SetDistributionBucketFamilyMetadataFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+            .with_family_id(family_id)
+            .with_metadata(metadata.clone())
+            .call_and_assert(Ok(()));
 
-        InviteDistributionBucketOperatorFixture::new()
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketFamilyMetadataSet(
+            family_id, metadata,
+        ));
+    });
+}
+
+#[test]
+fn set_distribution_bucket_family_metadata_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        let invalid_account_id = 11111;
+
+        SetDistributionBucketFamilyMetadataFixture::new()
+            .with_origin(RawOrigin::Signed(invalid_account_id))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn set_distribution_bucket_family_metadata_fails_with_invalid_distribution_bucket_family() {
+    build_test_externalities().execute_with(|| {
+        SetDistributionBucketFamilyMetadataFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bucket_index(bucket_index)
-            .with_family_id(family_id)
-            .with_operator_worker_id(invalid_provider_id)
             .call_and_assert(Err(
-                Error::<Test>::DistributionProviderOperatorDoesntExist.into()
+                Error::<Test>::DistributionBucketFamilyDoesntExist.into()
             ));
     });
 }
 
+fn create_distribution_descriptor(
+    region: &[u8],
+    bandwidth_tier: BandwidthTier,
+) -> DistributionDescriptorParameters {
+    DistributionDescriptorParameters {
+        region: region.to_vec(),
+        bandwidth_tier,
+        protocols: BTreeSet::from_iter(vec![DistributionProtocol::Https]),
+    }
+}
+
+fn create_distribution_bucket_with_operator(family_id: u64) -> u64 {
+    let bucket_index = CreateDistributionBucketFixture::new()
+        .with_family_id(family_id)
+        .with_accept_new_bags(true)
+        .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        .call_and_assert(Ok(()))
+        .unwrap();
+
+    InviteDistributionBucketOperatorFixture::new()
+        .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        .with_bucket_index(bucket_index)
+        .with_family_id(family_id)
+        .with_operator_worker_id(DEFAULT_DISTRIBUTION_PROVIDER_ID)
+        .call_and_assert(Ok(()));
+
+    AcceptDistributionBucketInvitationFixture::new()
+        .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+        .with_family_id(family_id)
+        .with_bucket_index(bucket_index)
+        .with_worker_id(DEFAULT_DISTRIBUTION_PROVIDER_ID)
+        .call_and_assert(Ok(()));
+
+    bucket_index
+}
+
 #[test]
-fn cancel_distribution_bucket_operator_invite_succeeded() {
+fn set_distribution_bucket_family_descriptor_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let provider_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
-
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        let bucket_index = CreateDistributionBucketFixture::new()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
-
-        InviteDistributionBucketOperatorFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bucket_index(bucket_index)
-            .with_family_id(family_id)
-            .with_operator_worker_id(provider_id)
-            .call_and_assert(Ok(()));
+        let descriptor = create_distribution_descriptor(b"eu", BandwidthTier::Premium);
 
-        CancelDistributionBucketInvitationFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        SetDistributionBucketFamilyDescriptorFixture::new()
             .with_family_id(family_id)
-            .with_bucket_index(bucket_index)
-            .with_operator_worker_id(provider_id)
+            .with_descriptor(descriptor)
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketInvitationCancelled(
-            Storage::create_distribution_bucket_id(family_id, bucket_index),
-            provider_id,
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketFamilyDescriptorSet(
+            family_id,
+            Storage::distribution_bucket_family_descriptor(family_id).unwrap(),
         ));
     });
 }
 
 #[test]
-fn cancel_distribution_bucket_operator_invite_fails_with_non_leader_origin() {
+fn set_distribution_bucket_family_descriptor_fails_with_non_leader_origin() {
     build_test_externalities().execute_with(|| {
-        let non_leader_account_id = 11111;
+        let invalid_account_id = 11111;
 
-        CancelDistributionBucketInvitationFixture::new()
-            .with_origin(RawOrigin::Signed(non_leader_account_id))
+        SetDistributionBucketFamilyDescriptorFixture::new()
+            .with_origin(RawOrigin::Signed(invalid_account_id))
             .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
 #[test]
-fn cancel_distribution_bucket_operator_invite_fails_with_non_existing_distribution_bucket() {
+fn set_distribution_bucket_family_descriptor_fails_with_invalid_distribution_bucket_family() {
+    build_test_externalities().execute_with(|| {
+        SetDistributionBucketFamilyDescriptorFixture::new().call_and_assert(Err(
+            Error::<Test>::DistributionBucketFamilyDoesntExist.into(),
+        ));
+    });
+}
+
+#[test]
+fn set_distribution_bucket_family_descriptor_fails_with_too_long_region_code() {
     build_test_externalities().execute_with(|| {
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        CancelDistributionBucketInvitationFixture::new()
+        let region = vec![1u8; RegionCodeMaxLength::get() as usize + 1];
+
+        SetDistributionBucketFamilyDescriptorFixture::new()
             .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+            .with_descriptor(create_distribution_descriptor(
+                &region,
+                BandwidthTier::Basic,
+            ))
+            .call_and_assert(Err(Error::<Test>::RegionCodeTooLong.into()));
     });
 }
 
 #[test]
-fn cancel_distribution_bucket_operator_invite_fails_with_non_invited_distribution_provider() {
+fn set_distribution_bucket_descriptor_succeeded() {
     build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
@@ -5017,402 +6239,572 @@ fn cancel_distribution_bucket_operator_invite_fails_with_non_invited_distributio
             .call_and_assert(Ok(()))
             .unwrap();
 
-        CancelDistributionBucketInvitationFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        SetDistributionBucketDescriptorFixture::new()
             .with_family_id(family_id)
             .with_bucket_index(bucket_index)
-            .call_and_assert(Err(Error::<Test>::NoDistributionBucketInvitation.into()));
+            .with_descriptor(create_distribution_descriptor(
+                b"us",
+                BandwidthTier::Standard,
+            ))
+            .call_and_assert(Ok(()));
+
+        let bucket_id = Storage::create_distribution_bucket_id(family_id, bucket_index);
+        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketDescriptorSet(
+            bucket_id.clone(),
+            Storage::distribution_bucket_descriptor(&bucket_id).unwrap(),
+        ));
     });
 }
 
 #[test]
-fn accept_distribution_bucket_operator_invite_succeeded() {
+fn set_distribution_bucket_descriptor_fails_with_non_existing_distribution_bucket() {
+    build_test_externalities().execute_with(|| {
+        SetDistributionBucketDescriptorFixture::new()
+            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+    });
+}
+
+#[test]
+fn set_distribution_operator_endpoint_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let provider_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
-
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        let bucket_index = CreateDistributionBucketFixture::new()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
-
-        InviteDistributionBucketOperatorFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bucket_index(bucket_index)
-            .with_family_id(family_id)
-            .with_operator_worker_id(provider_id)
-            .call_and_assert(Ok(()));
+        let bucket_index = create_distribution_bucket_with_operator(family_id);
+        let endpoint = b"https://distributor.example.com/".to_vec();
 
-        AcceptDistributionBucketInvitationFixture::new()
-            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+        SetDistributionOperatorEndpointFixture::new()
             .with_family_id(family_id)
             .with_bucket_index(bucket_index)
-            .with_worker_id(provider_id)
+            .with_endpoint(endpoint.clone())
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketInvitationAccepted(
-            provider_id,
+        EventFixture::assert_last_crate_event(RawEvent::DistributionOperatorEndpointSet(
+            DEFAULT_DISTRIBUTION_PROVIDER_ID,
             Storage::create_distribution_bucket_id(family_id, bucket_index),
+            endpoint.try_into().unwrap(),
         ));
     });
 }
 
 #[test]
-fn accept_distribution_bucket_operator_invite_fails_with_non_leader_origin() {
+fn set_distribution_operator_endpoint_fails_with_non_operator() {
     build_test_externalities().execute_with(|| {
-        let invalid_account_id = 11111;
+        let family_id = CreateDistributionBucketFamilyFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
 
-        AcceptDistributionBucketInvitationFixture::new()
-            .with_origin(RawOrigin::Signed(invalid_account_id))
-            .call_and_assert(Err(DispatchError::BadOrigin));
+        let bucket_index = CreateDistributionBucketFixture::new()
+            .with_family_id(family_id)
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        SetDistributionOperatorEndpointFixture::new()
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .call_and_assert(Err(
+                Error::<Test>::MustBeDistributionProviderOperatorForBucket.into(),
+            ));
     });
 }
 
 #[test]
-fn accept_distribution_bucket_operator_invite_fails_with_non_existing_distribution_bucket() {
+fn set_distribution_operator_endpoint_fails_with_too_long_endpoint() {
     build_test_externalities().execute_with(|| {
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        AcceptDistributionBucketInvitationFixture::new()
+        let bucket_index = create_distribution_bucket_with_operator(family_id);
+
+        SetDistributionOperatorEndpointFixture::new()
             .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
-            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+            .with_bucket_index(bucket_index)
+            .with_endpoint(vec![
+                1u8;
+                DistributionOperatorEndpointMaxLength::get() as usize
+                    + 1
+            ])
+            .call_and_assert(Err(
+                Error::<Test>::DistributionOperatorEndpointTooLong.into()
+            ));
     });
 }
 
 #[test]
-fn accept_distribution_bucket_operator_invite_fails_with_non_invited_distribution_provider() {
+fn distribution_operator_endpoint_removed_with_operator() {
     build_test_externalities().execute_with(|| {
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        let bucket_index = CreateDistributionBucketFixture::new()
+        let bucket_index = create_distribution_bucket_with_operator(family_id);
+
+        SetDistributionOperatorEndpointFixture::new()
             .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+            .with_bucket_index(bucket_index)
+            .with_endpoint(b"https://distributor.example.com/".to_vec())
+            .call_and_assert(Ok(()));
 
-        AcceptDistributionBucketInvitationFixture::new()
-            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+        RemoveDistributionBucketOperatorFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .with_family_id(family_id)
             .with_bucket_index(bucket_index)
-            .call_and_assert(Err(Error::<Test>::NoDistributionBucketInvitation.into()));
+            .with_operator_worker_id(DEFAULT_DISTRIBUTION_PROVIDER_ID)
+            .call_and_assert(Ok(()));
+
+        let bucket_id = Storage::create_distribution_bucket_id(family_id, bucket_index);
+        assert_eq!(
+            Storage::distribution_operator_endpoint(&bucket_id, DEFAULT_DISTRIBUTION_PROVIDER_ID),
+            None
+        );
     });
 }
 
 #[test]
-fn accept_distribution_bucket_operator_invite_fails_with_max_number_of_operators_reached() {
+fn distribution_route_orders_buckets_by_region_and_bandwidth_tier() {
     build_test_externalities().execute_with(|| {
-        let starting_block = 1;
-        run_to_block(starting_block);
+        set_default_distribution_buckets_per_bag_limit();
+
+        let bag_id: BagId<Test> = StaticBagId::Council.into();
 
         let family_id = CreateDistributionBucketFamilyFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .call_and_assert(Ok(()))
             .unwrap();
 
-        let bucket_index = CreateDistributionBucketFixture::new()
+        let bucket_indices = (0..4)
+            .map(|_| create_distribution_bucket_with_operator(family_id))
+            .collect::<Vec<_>>();
+
+        UpdateDistributionBucketForBagsFixture::new()
+            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bag_id(bag_id.clone())
+            .with_family_id(family_id)
+            .with_add_bucket_indices(bucket_indices.iter().cloned().collect())
+            .call_and_assert(Ok(()));
+
+        // The last two buckets fall back to the family descriptor.
+        SetDistributionBucketFamilyDescriptorFixture::new()
+            .with_family_id(family_id)
+            .with_descriptor(create_distribution_descriptor(
+                b"eu",
+                BandwidthTier::Premium,
+            ))
+            .call_and_assert(Ok(()));
+
+        SetDistributionBucketDescriptorFixture::new()
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_indices[0])
+            .with_descriptor(create_distribution_descriptor(
+                b"us",
+                BandwidthTier::Premium,
+            ))
+            .call_and_assert(Ok(()));
+
+        SetDistributionBucketDescriptorFixture::new()
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_indices[1])
+            .with_descriptor(create_distribution_descriptor(b"eu", BandwidthTier::Basic))
+            .call_and_assert(Ok(()));
+
+        let endpoint = b"https://distributor.example.com/".to_vec();
+        SetDistributionOperatorEndpointFixture::new()
             .with_family_id(family_id)
+            .with_bucket_index(bucket_indices[1])
+            .with_endpoint(endpoint.clone())
+            .call_and_assert(Ok(()));
+
+        UpdateDistributionBucketModeFixture::new()
             .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+            .with_family_id(family_id)
+            .with_distribution_bucket_index(bucket_indices[3])
+            .with_distributing(false)
+            .call_and_assert(Ok(()));
 
-        for i in 0..=<Test as Config>::MaxNumberOfOperatorsPerDistributionBucket::get() {
-            let operator_id = DISTRIBUTION_PROVIDER_IDS[i as usize];
-            let expected_result = match i
-                == <Test as Config>::MaxNumberOfOperatorsPerDistributionBucket::get()
-            {
-                true => Err(Error::<Test>::MaxNumberOfOperatorsPerDistributionBucketReached.into()),
-                false => DispatchResult::Ok(()),
-            };
-            InviteDistributionBucketOperatorFixture::new()
-                .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-                .with_bucket_index(bucket_index)
-                .with_family_id(family_id)
-                .with_operator_worker_id(operator_id)
-                .call_and_assert(Ok(()));
+        let route = Storage::distribution_route(&bag_id, b"eu");
+
+        assert_eq!(
+            route
+                .iter()
+                .map(|route| route.bucket_id.distribution_bucket_index)
+                .collect::<Vec<_>>(),
+            vec![bucket_indices[2], bucket_indices[1], bucket_indices[0]]
+        );
+        assert_eq!(route[1].endpoints, vec![endpoint.try_into().unwrap()]);
+        assert!(route[0].endpoints.is_empty());
+    });
+}
+
+const BANDWIDTH_REWARD_PER_GIGABYTE: u64 = 10;
+const BANDWIDTH_REPORT_BYTES_SERVED: u64 = 3 * BYTES_IN_GIGABYTE;
+
+fn setup_distribution_bucket_for_bandwidth_report() -> (u64, u64) {
+    set_default_distribution_buckets_per_bag_limit();
+    increase_account_balance(&DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID, INITIAL_BALANCE);
+
+    let family_id = CreateDistributionBucketFamilyFixture::new()
+        .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        .call_and_assert(Ok(()))
+        .unwrap();
+
+    let bucket_index = create_distribution_bucket_with_operator(family_id);
+
+    UpdateDistributionBucketForBagsFixture::new()
+        .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        .with_bag_id(StaticBagId::Council.into())
+        .with_family_id(family_id)
+        .with_add_bucket_indices(BTreeSet::from_iter(vec![bucket_index]))
+        .call_and_assert(Ok(()));
+
+    UpdateDistributionRewardPerGigabyteFixture::new()
+        .with_new_reward(BANDWIDTH_REWARD_PER_GIGABYTE)
+        .call_and_assert(Ok(()));
+
+    (family_id, bucket_index)
+}
+
+fn submit_default_bandwidth_report() -> BandwidthReportId {
+    let (family_id, bucket_index) = setup_distribution_bucket_for_bandwidth_report();
+
+    SubmitBandwidthReportFixture::new()
+        .with_family_id(family_id)
+        .with_bucket_index(bucket_index)
+        .with_bytes_served(BANDWIDTH_REPORT_BYTES_SERVED)
+        .call_and_assert(Ok(()))
+        .unwrap()
+}
 
-            AcceptDistributionBucketInvitationFixture::new()
-                .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
-                .with_family_id(family_id)
-                .with_bucket_index(bucket_index)
-                .with_worker_id(operator_id)
-                .call_and_assert(expected_result);
-        }
+#[test]
+fn update_distribution_reward_per_gigabyte_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        UpdateDistributionRewardPerGigabyteFixture::new()
+            .with_new_reward(BANDWIDTH_REWARD_PER_GIGABYTE)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DistributionRewardPerGigabyteUpdated(
+            BANDWIDTH_REWARD_PER_GIGABYTE,
+        ));
     });
 }
 
 #[test]
-fn set_distribution_operator_metadata_succeeded() {
+fn update_distribution_reward_per_gigabyte_fails_with_non_leader_origin() {
+    build_test_externalities().execute_with(|| {
+        UpdateDistributionRewardPerGigabyteFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_new_reward(BANDWIDTH_REWARD_PER_GIGABYTE)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn submit_bandwidth_report_succeeded() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let provider_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
-        let metadata = b"Metadata".to_vec();
-
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let (family_id, bucket_index) = setup_distribution_bucket_for_bandwidth_report();
+        let receipts_root = Some(H256::repeat_byte(1));
 
-        let bucket_index = CreateDistributionBucketFixture::new()
+        let report_id = SubmitBandwidthReportFixture::new()
             .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+            .with_bucket_index(bucket_index)
+            .with_bytes_served(BANDWIDTH_REPORT_BYTES_SERVED)
+            .with_receipts_root(receipts_root)
             .call_and_assert(Ok(()))
             .unwrap();
 
-        InviteDistributionBucketOperatorFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bucket_index(bucket_index)
-            .with_family_id(family_id)
-            .with_operator_worker_id(provider_id)
-            .call_and_assert(Ok(()));
+        let report = Storage::bandwidth_report(report_id).unwrap();
+        assert_eq!(report.reward, 3 * BANDWIDTH_REWARD_PER_GIGABYTE);
+        assert_eq!(report.receipts_root, receipts_root);
+        assert_eq!(report.submitted_at, starting_block);
 
-        AcceptDistributionBucketInvitationFixture::new()
-            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+        EventFixture::assert_last_crate_event(RawEvent::BandwidthReportSubmitted(
+            report_id, report,
+        ));
+    });
+}
+
+#[test]
+fn submit_bandwidth_report_fails_with_non_operator_worker() {
+    build_test_externalities().execute_with(|| {
+        let (family_id, bucket_index) = setup_distribution_bucket_for_bandwidth_report();
+
+        SubmitBandwidthReportFixture::new()
+            .with_worker_id(ANOTHER_DISTRIBUTION_PROVIDER_ID)
             .with_family_id(family_id)
             .with_bucket_index(bucket_index)
-            .with_worker_id(provider_id)
-            .call_and_assert(Ok(()));
+            .with_bytes_served(BANDWIDTH_REPORT_BYTES_SERVED)
+            .call_and_assert(Err(
+                Error::<Test>::MustBeDistributionProviderOperatorForBucket.into(),
+            ));
+    });
+}
 
-        SetDistributionBucketMetadataFixture::new()
-            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+#[test]
+fn submit_bandwidth_report_fails_with_bucket_not_bound_to_bag() {
+    build_test_externalities().execute_with(|| {
+        let (family_id, bucket_index) = setup_distribution_bucket_for_bandwidth_report();
+
+        SubmitBandwidthReportFixture::new()
             .with_family_id(family_id)
             .with_bucket_index(bucket_index)
-            .with_worker_id(provider_id)
-            .with_metadata(metadata.clone())
-            .call_and_assert(Ok(()));
-
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketMetadataSet(
-            provider_id,
-            Storage::create_distribution_bucket_id(family_id, bucket_index),
-            metadata,
-        ));
+            .with_bag_id(StaticBagId::WorkingGroup(WorkingGroup::Storage).into())
+            .with_bytes_served(BANDWIDTH_REPORT_BYTES_SERVED)
+            .call_and_assert(Err(Error::<Test>::DistributionBucketIsNotBoundToBag.into()));
     });
 }
 
 #[test]
-fn set_distribution_operator_metadata_fails_with_non_leader_origin() {
+fn submit_bandwidth_report_fails_with_zero_bytes_served() {
     build_test_externalities().execute_with(|| {
-        let invalid_account_id = 11111;
+        let (family_id, bucket_index) = setup_distribution_bucket_for_bandwidth_report();
 
-        SetDistributionBucketMetadataFixture::new()
-            .with_origin(RawOrigin::Signed(invalid_account_id))
-            .call_and_assert(Err(DispatchError::BadOrigin));
+        SubmitBandwidthReportFixture::new()
+            .with_family_id(family_id)
+            .with_bucket_index(bucket_index)
+            .call_and_assert(Err(Error::<Test>::ZeroBytesServedInBandwidthReport.into()));
     });
 }
 
 #[test]
-fn set_distribution_operator_metadata_fails_with_non_existing_distribution_bucket() {
+fn submit_bandwidth_report_fails_with_exceeded_bytes_served() {
     build_test_externalities().execute_with(|| {
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let (family_id, bucket_index) = setup_distribution_bucket_for_bandwidth_report();
 
-        SetDistributionBucketMetadataFixture::new()
+        SubmitBandwidthReportFixture::new()
             .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
-            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+            .with_bucket_index(bucket_index)
+            .with_bytes_served(MaxBytesServedPerBandwidthReport::get() + 1)
+            .call_and_assert(Err(
+                Error::<Test>::MaxBytesServedPerBandwidthReportExceeded.into()
+            ));
     });
 }
 
 #[test]
-fn set_distribution_operator_metadata_fails_with_non_distribution_provider() {
+fn submit_bandwidth_report_fails_with_insufficient_balance_for_bond() {
     build_test_externalities().execute_with(|| {
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let (family_id, bucket_index) = setup_distribution_bucket_for_bandwidth_report();
 
-        let bucket_index = CreateDistributionBucketFixture::new()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let _ = Balances::slash(&DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID, INITIAL_BALANCE);
 
-        SetDistributionBucketMetadataFixture::new()
-            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+        SubmitBandwidthReportFixture::new()
             .with_family_id(family_id)
             .with_bucket_index(bucket_index)
+            .with_bytes_served(BANDWIDTH_REPORT_BYTES_SERVED)
             .call_and_assert(Err(
-                Error::<Test>::MustBeDistributionProviderOperatorForBucket.into(),
+                Error::<Test>::InsufficientBalanceForBandwidthReportBond.into(),
             ));
     });
 }
 
 #[test]
-fn remove_distribution_bucket_operator_succeeded() {
+fn submit_bandwidth_report_fails_twice_within_report_period() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let operator_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
-
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let (family_id, bucket_index) = setup_distribution_bucket_for_bandwidth_report();
 
-        let bucket_index = CreateDistributionBucketFixture::new()
+        let submit_report_fixture = SubmitBandwidthReportFixture::new()
             .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
-
-        InviteDistributionBucketOperatorFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
             .with_bucket_index(bucket_index)
-            .with_family_id(family_id)
-            .with_operator_worker_id(operator_id)
-            .call_and_assert(Ok(()));
+            .with_bytes_served(BANDWIDTH_REPORT_BYTES_SERVED);
 
-        AcceptDistributionBucketInvitationFixture::new()
-            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
-            .with_family_id(family_id)
-            .with_bucket_index(bucket_index)
-            .with_worker_id(operator_id)
-            .call_and_assert(Ok(()));
+        submit_report_fixture.call_and_assert(Ok(()));
 
-        RemoveDistributionBucketOperatorFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_family_id(family_id)
-            .with_bucket_index(bucket_index)
-            .with_operator_worker_id(operator_id)
+        run_to_block(starting_block + BandwidthReportPeriod::get() - 1);
+
+        submit_report_fixture.call_and_assert(Err(
+            Error::<Test>::BandwidthReportAlreadySubmittedInPeriod.into(),
+        ));
+
+        run_to_block(starting_block + BandwidthReportPeriod::get());
+
+        submit_report_fixture.call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn reject_bandwidth_report_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let report_id = submit_default_bandwidth_report();
+        let rationale = b"fraudulent report".to_vec();
+
+        RejectBandwidthReportFixture::new()
+            .with_report_id(report_id)
+            .with_rationale(rationale.clone())
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketOperatorRemoved(
-            Storage::create_distribution_bucket_id(family_id, bucket_index),
-            operator_id,
+        assert_eq!(
+            Balances::usable_balance(&DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID),
+            INITIAL_BALANCE - BandwidthReportBond::get()
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::BandwidthReportRejected(
+            report_id, rationale,
         ));
     });
 }
 
 #[test]
-fn remove_distribution_bucket_operator_fails_with_non_leader_origin() {
+fn reject_bandwidth_report_fails_with_non_leader_origin() {
     build_test_externalities().execute_with(|| {
-        let non_leader_account = 11;
-        RemoveDistributionBucketOperatorFixture::new()
-            .with_origin(RawOrigin::Signed(non_leader_account))
+        let report_id = submit_default_bandwidth_report();
+
+        RejectBandwidthReportFixture::new()
+            .with_origin(RawOrigin::Signed(DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID))
+            .with_report_id(report_id)
             .call_and_assert(Err(DispatchError::BadOrigin));
     });
 }
 
 #[test]
-fn remove_distribution_bucket_operator_fails_with_non_existing_distribution_bucket() {
+fn reject_bandwidth_report_fails_with_non_existing_report() {
     build_test_externalities().execute_with(|| {
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
-
-        RemoveDistributionBucketOperatorFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_family_id(family_id)
-            .call_and_assert(Err(Error::<Test>::DistributionBucketDoesntExist.into()));
+        RejectBandwidthReportFixture::new()
+            .call_and_assert(Err(Error::<Test>::BandwidthReportDoesntExist.into()));
     });
 }
 
 #[test]
-fn remove_distribution_bucket_operator_fails_with_non_accepted_distribution_provider() {
+fn reject_bandwidth_report_fails_after_dispute_period() {
     build_test_externalities().execute_with(|| {
-        let operator_id = DEFAULT_DISTRIBUTION_PROVIDER_ID;
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        let report_id = submit_default_bandwidth_report();
 
-        let bucket_index = CreateDistributionBucketFixture::new()
-            .with_family_id(family_id)
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        run_to_block(starting_block + BandwidthReportDisputePeriod::get());
 
-        RemoveDistributionBucketOperatorFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_family_id(family_id)
-            .with_bucket_index(bucket_index)
-            .with_operator_worker_id(operator_id)
+        RejectBandwidthReportFixture::new()
+            .with_report_id(report_id)
             .call_and_assert(Err(
-                Error::<Test>::MustBeDistributionProviderOperatorForBucket.into(),
+                Error::<Test>::BandwidthReportDisputePeriodExpired.into()
             ));
+    });
+}
 
-        InviteDistributionBucketOperatorFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_bucket_index(bucket_index)
-            .with_family_id(family_id)
-            .with_operator_worker_id(operator_id)
+#[test]
+fn settle_bandwidth_report_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let report_id = submit_default_bandwidth_report();
+        let reward = Storage::bandwidth_report(report_id).unwrap().reward;
+
+        run_to_block(starting_block + BandwidthReportDisputePeriod::get());
+
+        SettleBandwidthReportFixture::new()
+            .with_report_id(report_id)
             .call_and_assert(Ok(()));
 
-        RemoveDistributionBucketOperatorFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_family_id(family_id)
-            .with_bucket_index(bucket_index)
-            .with_operator_worker_id(operator_id)
-            .call_and_assert(Err(
-                Error::<Test>::MustBeDistributionProviderOperatorForBucket.into(),
-            ));
+        EventFixture::assert_last_crate_event(RawEvent::BandwidthReportSettled(
+            report_id,
+            DEFAULT_DISTRIBUTION_PROVIDER_ACCOUNT_ID,
+            reward,
+        ));
     });
 }
 
 #[test]
-fn set_distribution_bucket_family_metadata_succeeded() {
+fn settle_bandwidth_report_removes_last_submission_after_report_period() {
     build_test_externalities().execute_with(|| {
         let starting_block = 1;
         run_to_block(starting_block);
 
-        let metadata = b"Metadata".to_vec();
+        let report_id = submit_default_bandwidth_report();
+        let report = Storage::bandwidth_report(report_id).unwrap();
 
-        let family_id = CreateDistributionBucketFamilyFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .call_and_assert(Ok(()))
-            .unwrap();
+        run_to_block(
+            starting_block + BandwidthReportDisputePeriod::get() + BandwidthReportPeriod::get(),
+        );
 
-        SetDistributionBucketFamilyMetadataFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
-            .with_family_id(family_id)
-            .with_metadata(metadata.clone())
+        SettleBandwidthReportFixture::new()
+            .with_report_id(report_id)
             .call_and_assert(Ok(()));
 
-        EventFixture::assert_last_crate_event(RawEvent::DistributionBucketFamilyMetadataSet(
-            family_id, metadata,
-        ));
+        assert_eq!(
+            Storage::last_bandwidth_report_submission(
+                report.worker_id,
+                (report.bucket_id, report.bag_id)
+            ),
+            None
+        );
     });
 }
 
 #[test]
-fn set_distribution_bucket_family_metadata_fails_with_non_leader_origin() {
+fn settle_bandwidth_report_prunes_report_of_left_operator() {
     build_test_externalities().execute_with(|| {
-        let invalid_account_id = 11111;
+        let starting_block = 1;
+        run_to_block(starting_block);
 
-        SetDistributionBucketFamilyMetadataFixture::new()
-            .with_origin(RawOrigin::Signed(invalid_account_id))
-            .call_and_assert(Err(DispatchError::BadOrigin));
+        let report_id = submit_default_bandwidth_report();
+        let old_budget = DistributionWG::get_budget();
+
+        leave_distribution_working_group(DEFAULT_DISTRIBUTION_PROVIDER_ID);
+        run_to_block(starting_block + BandwidthReportDisputePeriod::get());
+
+        SettleBandwidthReportFixture::new()
+            .with_report_id(report_id)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(DistributionWG::get_budget(), old_budget);
+
+        EventFixture::assert_last_crate_event(RawEvent::BandwidthReportPruned(report_id));
     });
 }
 
 #[test]
-fn set_distribution_bucket_family_metadata_fails_with_invalid_distribution_bucket_family() {
+fn settle_bandwidth_report_fails_during_dispute_period() {
     build_test_externalities().execute_with(|| {
-        SetDistributionBucketFamilyMetadataFixture::new()
-            .with_origin(RawOrigin::Signed(DISTRIBUTION_WG_LEADER_ACCOUNT_ID))
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let report_id = submit_default_bandwidth_report();
+
+        SettleBandwidthReportFixture::new()
+            .with_report_id(report_id)
             .call_and_assert(Err(
-                Error::<Test>::DistributionBucketFamilyDoesntExist.into()
+                Error::<Test>::BandwidthReportDisputePeriodNotExpired.into()
+            ));
+    });
+}
+
+#[test]
+fn settle_bandwidth_report_fails_with_insufficient_budget() {
+    build_test_externalities().execute_with(|| {
+        let starting_block = 1;
+        run_to_block(starting_block);
+
+        let report_id = submit_default_bandwidth_report();
+
+        DistributionWG::set_budget(0);
+        run_to_block(starting_block + BandwidthReportDisputePeriod::get());
+
+        SettleBandwidthReportFixture::new()
+            .with_report_id(report_id)
+            .call_and_assert(Err(
+                Error::<Test>::InsufficientDistributionWorkingGroupBudget.into(),
             ));
     });
 }
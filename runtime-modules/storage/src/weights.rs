@@ -79,6 +79,17 @@ pub trait WeightInfo {
 	fn set_distribution_operator_metadata(_i: u32, ) -> Weight;
 	fn storage_operator_remark(_i: u32, ) -> Weight;
 	fn distribution_operator_remark(_i: u32, ) -> Weight;
+	fn update_preferred_tags_in_dynamic_bag_creation_policy(_i: u32, ) -> Weight;
+	fn rebalance_storage_buckets_for_bag(_i: u32, _b: u32, ) -> Weight;
+	fn set_storage_operator_tags(_i: u32, ) -> Weight;
+	fn update_bag_storage_policy() -> Weight;
+	fn set_distribution_bucket_family_descriptor() -> Weight;
+	fn set_distribution_bucket_descriptor() -> Weight;
+	fn set_distribution_operator_endpoint() -> Weight;
+	fn update_distribution_reward_per_gigabyte() -> Weight;
+	fn reject_bandwidth_report(_i: u32, ) -> Weight;
+	fn submit_bandwidth_report() -> Weight;
+	fn settle_bandwidth_report() -> Weight;
 }
 
 /// Weights for storage using the Substrate node and recommended hardware.
@@ -90,6 +101,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: Instance2WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
 	// Storage: Storage StorageBucketById (r:1 w:1)
 	// Proof: Storage StorageBucketById (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	// Storage: Storage AssignableStorageBuckets (r:0 w:1)
+	// Proof: Storage AssignableStorageBuckets (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// TODO: adjust weight, the assignable storage bucket write is added without rerunning the benchmark.
 	fn delete_storage_bucket() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `634`
@@ -98,7 +112,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(29_580_000, 0u64)
 			.saturating_add(Weight::from_parts(0, 8704))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: Instance2WorkingGroup CurrentLead (r:1 w:0)
 	// Proof: Instance2WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
@@ -257,6 +271,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: Storage Bags (max_values: None, max_size: Some(964), added: 3439, mode: MaxEncodedLen)
 	// Storage: Storage StorageBucketsPerBagLimit (r:1 w:0)
 	// Proof: Storage StorageBucketsPerBagLimit (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Storage BagStoragePolicies (r:1 w:0)
+	// Proof: Storage BagStoragePolicies (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	// Storage: Storage BagShardLayouts (r:1 w:1)
+	// Proof: Storage BagShardLayouts (max_values: None, max_size: Some(154), added: 2629, mode: MaxEncodedLen)
 	// Storage: Storage StorageBucketById (r:26 w:26)
 	// Proof: Storage StorageBucketById (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 13]`.
@@ -264,18 +282,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn update_storage_buckets_for_bag(i: u32, j: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `635 + i * (73 ±0) + j * (81 ±0)`
-		//  Estimated: `12041 + i * (2581 ±0) + j * (2581 ±0)`
+		//  Estimated: `17195 + i * (2581 ±0) + j * (2581 ±0)`
 		// Minimum execution time: 227_609 nanoseconds.
-		Weight::from_parts(41_036_350, 0u64)
-			.saturating_add(Weight::from_parts(0, 12041))
+		Weight::from_parts(48_217_604, 0u64)
+			.saturating_add(Weight::from_parts(0, 17195))
 			// Standard Error: 23_573
 			.saturating_add(Weight::from_parts(15_050_989, 0u64).saturating_mul(i.into()))
 			// Standard Error: 23_573
 			.saturating_add(Weight::from_parts(13_560_682, 0u64).saturating_mul(j.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(i.into())))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(j.into())))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(j.into())))
 			.saturating_add(Weight::from_parts(0, 2581).saturating_mul(i.into()))
@@ -319,6 +337,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: Instance2WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
 	// Storage: Storage StorageBucketById (r:1 w:1)
 	// Proof: Storage StorageBucketById (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	// Storage: Storage AssignableStorageBuckets (r:0 w:1)
+	// Proof: Storage AssignableStorageBuckets (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// TODO: adjust weight, the assignable storage bucket write is added without rerunning the benchmark.
 	fn remove_storage_bucket_operator() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `712`
@@ -327,7 +348,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(31_912_000, 0u64)
 			.saturating_add(Weight::from_parts(0, 8704))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: Instance2WorkingGroup CurrentLead (r:1 w:0)
 	// Proof: Instance2WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
@@ -335,6 +356,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: Instance2WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
 	// Storage: Storage StorageBucketById (r:1 w:1)
 	// Proof: Storage StorageBucketById (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	// Storage: Storage AssignableStorageBuckets (r:0 w:1)
+	// Proof: Storage AssignableStorageBuckets (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// TODO: adjust weight, the assignable storage bucket write is added without rerunning the benchmark.
 	fn update_storage_bucket_status() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `634`
@@ -343,7 +367,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(29_371_000, 0u64)
 			.saturating_add(Weight::from_parts(0, 8704))
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: Instance2WorkingGroup CurrentLead (r:1 w:0)
 	// Proof: Instance2WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
@@ -369,6 +393,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: Instance2WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
 	// Storage: Storage StorageBucketById (r:1 w:1)
 	// Proof: Storage StorageBucketById (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	// Storage: Storage AssignableStorageBuckets (r:0 w:1)
+	// Proof: Storage AssignableStorageBuckets (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// TODO: adjust weight, the assignable storage bucket write is added without rerunning the benchmark.
 	fn accept_storage_bucket_invitation() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `679`
@@ -377,7 +404,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(30_542_000, 0u64)
 			.saturating_add(Weight::from_parts(0, 7211))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
 	// Proof: Instance2WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
@@ -399,19 +426,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: Storage StorageBucketById (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	// Storage: Storage Bags (r:1 w:0)
 	// Proof: Storage Bags (max_values: None, max_size: Some(964), added: 3439, mode: MaxEncodedLen)
+	// Storage: Storage BagShardLayouts (r:1 w:0)
+	// Proof: Storage BagShardLayouts (max_values: None, max_size: Some(154), added: 2629, mode: MaxEncodedLen)
 	// Storage: Storage DataObjectsById (r:400 w:400)
 	// Proof: Storage DataObjectsById (max_values: None, max_size: Some(155), added: 2630, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 400]`.
 	fn accept_pending_data_objects(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `419 + i * (104 ±0)`
-		//  Estimated: `8990 + i * (2630 ±0)`
-		// Minimum execution time: 38_120 nanoseconds.
-		Weight::from_parts(38_662_000, 0u64)
-			.saturating_add(Weight::from_parts(0, 8990))
+		//  Estimated: `11619 + i * (2630 ±0)`
+		// Minimum execution time: 41_307 nanoseconds.
+		Weight::from_parts(41_954_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 11619))
 			// Standard Error: 36_011
 			.saturating_add(Weight::from_parts(11_664_089, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(i.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
 			.saturating_add(Weight::from_parts(0, 2630).saturating_mul(i.into()))
@@ -725,6 +754,242 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(1_123_267, 0u64).saturating_mul(i.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
+	// Storage: Instance2WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance2WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance2WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Storage PreferredStorageBucketTags (r:0 w:1)
+	// Proof: Storage PreferredStorageBucketTags (max_values: None, max_size: Some(342), added: 2817, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 10]`.
+	fn update_preferred_tags_in_dynamic_bag_creation_policy(i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `405`
+		//  Estimated: `5133`
+		// Minimum execution time: 24_310 nanoseconds.
+		Weight::from_parts(23_492_817, 0u64)
+			.saturating_add(Weight::from_parts(0, 5133))
+			// Standard Error: 69_613
+			.saturating_add(Weight::from_parts(1_183_421, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Instance2WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance2WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance2WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Storage Bags (r:1 w:1)
+	// Proof: Storage Bags (max_values: None, max_size: Some(964), added: 3439, mode: MaxEncodedLen)
+	// Storage: Storage PreferredStorageBucketTags (r:1 w:0)
+	// Proof: Storage PreferredStorageBucketTags (max_values: None, max_size: Some(342), added: 2817, mode: MaxEncodedLen)
+	// Storage: Storage AssignableStorageBuckets (r:100 w:0)
+	// Proof: Storage AssignableStorageBuckets (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// Storage: Storage StorageBucketById (r:113 w:26)
+	// Proof: Storage StorageBucketById (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	// Storage: Storage StorageBucketOperatorTags (r:100 w:0)
+	// Proof: Storage StorageBucketOperatorTags (max_values: None, max_size: Some(350), added: 2825, mode: MaxEncodedLen)
+	// Storage: Storage StorageBucketsPerBagLimit (r:1 w:0)
+	// Proof: Storage StorageBucketsPerBagLimit (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// TODO: adjust weight, placeholder until the benchmark is rerun. The base and the per bag bucket
+	// execution times are measured before the scan was bounded, the per scanned bucket execution
+	// time and the DB access are estimated for the worst case.
+	/// The range of component `i` is `[1, 13]`.
+	/// The range of component `b` is `[26, 100]`.
+	fn rebalance_storage_buckets_for_bag(i: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1201 + i * (154 ±0)`
+		//  Estimated: `17658 + i * (2581 ±0) + b * (7905 ±0)`
+		// Minimum execution time: 118_412 nanoseconds.
+		Weight::from_parts(87_306_554, 0u64)
+			.saturating_add(Weight::from_parts(0, 17658))
+			// Standard Error: 1_461_241
+			.saturating_add(Weight::from_parts(24_841_102, 0u64).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(2_071_254, 0u64).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(i.into())))
+			.saturating_add(Weight::from_parts(0, 2581).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(0, 7905).saturating_mul(b.into()))
+	}
+	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance2WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Storage StorageBucketById (r:1 w:0)
+	// Proof: Storage StorageBucketById (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
+	// Storage: Storage StorageBucketOperatorTags (r:0 w:1)
+	// Proof: Storage StorageBucketOperatorTags (max_values: None, max_size: Some(350), added: 2825, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 10]`.
+	fn set_storage_operator_tags(i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `7211`
+		// Minimum execution time: 31_094 nanoseconds.
+		Weight::from_parts(29_871_224, 0u64)
+			.saturating_add(Weight::from_parts(0, 7211))
+			// Standard Error: 71_446
+			.saturating_add(Weight::from_parts(1_214_587, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Instance2WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance2WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance2WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance2WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Storage Bags (r:1 w:0)
+	// Proof: Storage Bags (max_values: None, max_size: Some(964), added: 3439, mode: MaxEncodedLen)
+	// Storage: Storage BagStoragePolicies (r:0 w:1)
+	// Proof: Storage BagStoragePolicies (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	// Storage: Storage BagShardLayouts (r:0 w:1)
+	// Proof: Storage BagShardLayouts (max_values: None, max_size: Some(154), added: 2629, mode: MaxEncodedLen)
+	fn update_bag_storage_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `621`
+		//  Estimated: `11130`
+		// Minimum execution time: 43_572 nanoseconds.
+		Weight::from_parts(44_809_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 11130))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Instance9WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance9WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance9WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance9WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Storage DistributionBucketFamilyById (r:1 w:0)
+	// Proof: Storage DistributionBucketFamilyById (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	// Storage: Storage DistributionBucketFamilyDescriptors (r:0 w:1)
+	// Proof: Storage DistributionBucketFamilyDescriptors (max_values: None, max_size: Some(77), added: 2552, mode: MaxEncodedLen)
+	fn set_distribution_bucket_family_descriptor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `10652`
+		// Minimum execution time: 38_219 nanoseconds.
+		Weight::from_parts(39_307_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 10652))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Instance9WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance9WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance9WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance9WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Storage DistributionBucketByFamilyIdById (r:1 w:0)
+	// Proof: Storage DistributionBucketByFamilyIdById (max_values: None, max_size: Some(380), added: 2855, mode: MaxEncodedLen)
+	// Storage: Storage DistributionBucketDescriptors (r:0 w:1)
+	// Proof: Storage DistributionBucketDescriptors (max_values: None, max_size: Some(109), added: 2584, mode: MaxEncodedLen)
+	fn set_distribution_bucket_descriptor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `596`
+		//  Estimated: `11032`
+		// Minimum execution time: 39_877 nanoseconds.
+		Weight::from_parts(41_052_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 11032))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Instance9WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance9WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Storage DistributionBucketByFamilyIdById (r:1 w:0)
+	// Proof: Storage DistributionBucketByFamilyIdById (max_values: None, max_size: Some(380), added: 2855, mode: MaxEncodedLen)
+	// Storage: Storage DistributionOperatorEndpoints (r:0 w:1)
+	// Proof: Storage DistributionOperatorEndpoints (max_values: None, max_size: Some(342), added: 2817, mode: MaxEncodedLen)
+	fn set_distribution_operator_endpoint() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `614`
+		//  Estimated: `7015`
+		// Minimum execution time: 35_412 nanoseconds.
+		Weight::from_parts(36_580_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 7015))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Instance9WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance9WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance9WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance9WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Storage DistributionRewardPerGigabyte (r:0 w:1)
+	// Proof: Storage DistributionRewardPerGigabyte (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn update_distribution_reward_per_gigabyte() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `356`
+		//  Estimated: `4643`
+		// Minimum execution time: 24_311 nanoseconds.
+		Weight::from_parts(25_102_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 4643))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Instance9WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance9WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance9WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance9WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Storage BandwidthReports (r:1 w:1)
+	// Proof: Storage BandwidthReports (max_values: None, max_size: Some(184), added: 2659, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Balances TotalIssuance (r:1 w:1)
+	// Proof: Balances TotalIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Storage LastBandwidthReportSubmission (r:1 w:1)
+	// Proof: Storage LastBandwidthReportSubmission (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 1000]`.
+	fn reject_bandwidth_report(i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `787`
+		//  Estimated: `14459`
+		// Minimum execution time: 52_613 nanoseconds.
+		Weight::from_parts(50_874_192, 0u64)
+			.saturating_add(Weight::from_parts(0, 14459))
+			// Standard Error: 22
+			.saturating_add(Weight::from_parts(1_281, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	// Storage: Instance9WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance9WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Storage DistributionBucketByFamilyIdById (r:1 w:0)
+	// Proof: Storage DistributionBucketByFamilyIdById (max_values: None, max_size: Some(380), added: 2855, mode: MaxEncodedLen)
+	// Storage: Storage Bags (r:1 w:0)
+	// Proof: Storage Bags (max_values: None, max_size: Some(964), added: 3439, mode: MaxEncodedLen)
+	// Storage: Storage DistributionRewardPerGigabyte (r:1 w:0)
+	// Proof: Storage DistributionRewardPerGigabyte (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Storage LastBandwidthReportSubmission (r:1 w:1)
+	// Proof: Storage LastBandwidthReportSubmission (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Storage NextBandwidthReportId (r:1 w:1)
+	// Proof: Storage NextBandwidthReportId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Storage BandwidthReports (r:0 w:1)
+	// Proof: Storage BandwidthReports (max_values: None, max_size: Some(184), added: 2659, mode: MaxEncodedLen)
+	fn submit_bandwidth_report() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1339`
+		//  Estimated: `19029`
+		// Minimum execution time: 83_107 nanoseconds.
+		Weight::from_parts(85_291_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 19029))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: Storage BandwidthReports (r:1 w:1)
+	// Proof: Storage BandwidthReports (max_values: None, max_size: Some(184), added: 2659, mode: MaxEncodedLen)
+	// Storage: Instance9WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance9WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance9WorkingGroup Budget (r:1 w:1)
+	// Proof: Instance9WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: System Account (r:3 w:3)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Storage LastBandwidthReportSubmission (r:1 w:1)
+	// Proof: Storage LastBandwidthReportSubmission (max_values: None, max_size: Some(70), added: 2545, mode: MaxEncodedLen)
+	fn settle_bandwidth_report() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1024`
+		//  Estimated: `18194`
+		// Minimum execution time: 88_426 nanoseconds.
+		Weight::from_parts(90_312_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 18194))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // Default implementation for tests
@@ -834,4 +1099,37 @@ impl WeightInfo for () {
 	fn distribution_operator_remark(i: u32, ) -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn update_preferred_tags_in_dynamic_bag_creation_policy(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn rebalance_storage_buckets_for_bag(i: u32, b: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn set_storage_operator_tags(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn update_bag_storage_policy() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn set_distribution_bucket_family_descriptor() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn set_distribution_bucket_descriptor() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn set_distribution_operator_endpoint() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn update_distribution_reward_per_gigabyte() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn reject_bandwidth_report(i: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn submit_bandwidth_report() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn settle_bandwidth_report() -> Weight {
+		Weight::from_parts(0, 0)
+	}
 }
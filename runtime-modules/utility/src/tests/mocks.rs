@@ -255,6 +255,12 @@ impl common::working_group::WorkingGroupAuthenticator<Test> for Wg {
         unimplemented!()
     }
 
+    fn get_worker_reward_account_id(
+        _: &<Test as common::membership::MembershipTypes>::ActorId,
+    ) -> Option<<Test as frame_system::Config>::AccountId> {
+        unimplemented!()
+    }
+
     fn is_leader_account_id(_account_id: &<Test as frame_system::Config>::AccountId) -> bool {
         unimplemented!()
     }
@@ -327,7 +333,6 @@ impl working_group::Config<ForumWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -341,7 +346,6 @@ impl working_group::Config<StorageWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -355,7 +359,6 @@ impl working_group::Config<ContentWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -369,7 +372,6 @@ impl working_group::Config<OperationsWorkingGroupInstanceAlpha> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -383,7 +385,6 @@ impl working_group::Config<AppWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -397,7 +398,6 @@ impl working_group::Config<MembershipWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -411,7 +411,6 @@ impl working_group::Config<OperationsWorkingGroupInstanceBeta> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -425,7 +424,6 @@ impl working_group::Config<OperationsWorkingGroupInstanceGamma> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -439,7 +437,6 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = ();
-    type RewardPeriod = ();
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -462,7 +459,6 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type StakingAccountValidator = membership::Module<Test>;
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
-//     type RewardPeriod = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
 //     type LeaderOpeningStake = LeaderOpeningStake;
@@ -475,7 +471,6 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type StakingAccountValidator = membership::Module<Test>;
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
-//     type RewardPeriod = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
 //     type LeaderOpeningStake = LeaderOpeningStake;
@@ -488,7 +483,6 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type StakingAccountValidator = membership::Module<Test>;
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
-//     type RewardPeriod = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
 //     type LeaderOpeningStake = LeaderOpeningStake;
@@ -501,7 +495,6 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type StakingAccountValidator = membership::Module<Test>;
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
-//     type RewardPeriod = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
 //     type LeaderOpeningStake = LeaderOpeningStake;
@@ -514,7 +507,6 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type StakingAccountValidator = membership::Module<Test>;
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
-//     type RewardPeriod = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
 //     type LeaderOpeningStake = LeaderOpeningStake;
@@ -527,7 +519,6 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type StakingAccountValidator = membership::Module<Test>;
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
-//     type RewardPeriod = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
 //     type LeaderOpeningStake = LeaderOpeningStake;
@@ -540,7 +531,6 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type StakingAccountValidator = membership::Module<Test>;
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
-//     type RewardPeriod = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
 //     type LeaderOpeningStake = LeaderOpeningStake;
@@ -553,7 +543,6 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type StakingAccountValidator = membership::Module<Test>;
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
-//     type RewardPeriod = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
 //     type LeaderOpeningStake = LeaderOpeningStake;
@@ -566,7 +555,6 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Test {
 //     type StakingAccountValidator = membership::Module<Test>;
 //     type MemberOriginValidator = ();
 //     type MinUnstakingPeriodLimit = ();
-//     type RewardPeriod = ();
 //     type WeightInfo = ();
 //     type MinimumApplicationStake = MinimumApplicationStake;
 //     type LeaderOpeningStake = LeaderOpeningStake;
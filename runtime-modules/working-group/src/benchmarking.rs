@@ -192,11 +192,18 @@ fn member_funded_account<T: Config<I> + membership::Config, I: Instance>(
 }
 
 fn force_missed_reward<T: Config<I>, I: Instance>() {
-    let curr_block_number =
-        System::<T>::block_number().saturating_add(T::RewardPeriod::get().into());
-    System::<T>::set_block_number(curr_block_number);
     WorkingGroup::<T, _>::set_budget(RawOrigin::Root.into(), Zero::zero()).unwrap();
-    WorkingGroup::<T, _>::on_initialize(curr_block_number);
+    let curr_block_number = System::<T>::block_number().saturating_add(One::one());
+    System::<T>::set_block_number(curr_block_number);
+
+    // Accrue the unfunded reward of every worker
+    WorkerById::<T, I>::iter().for_each(|(worker_id, worker)| {
+        WorkingGroup::<T, _>::claim_worker_reward(
+            RawOrigin::Signed(worker.role_account_id).into(),
+            worker_id,
+        )
+        .unwrap();
+    });
 }
 
 pub fn insert_a_worker<T: Config<I> + membership::Config, I: Instance>(
@@ -254,6 +261,66 @@ pub fn complete_opening<T: Config<I> + membership::Config, I: Instance>(
     worker_id
 }
 
+// Appoints the maximal number of the deputies (within the worker number limit), the first one
+// gets the `first_id` worker id. Returns the role account of the last appointed deputy.
+fn appoint_max_deputies<T: Config<I> + membership::Config, I: Instance>(
+    lead_id: &T::AccountId,
+    first_id: u32,
+    permissions: DeputyPermissionsOf<T>,
+) -> T::AccountId
+where
+    WorkingGroup<T, I>: OnInitialize<T::BlockNumber>,
+{
+    let deputy_number = MAX_DEPUTIES.min(T::MaxWorkerNumberLimit::get().saturating_sub(first_id));
+
+    let mut deputy_account_id = lead_id.clone();
+    for id in first_id..first_id + deputy_number {
+        let (account_id, worker_id) =
+            insert_a_worker::<T, I>(OpeningType::Regular, id, Some(lead_id.clone()));
+
+        WorkingGroup::<T, I>::appoint_deputy(
+            RawOrigin::Signed(lead_id.clone()).into(),
+            worker_id,
+            permissions.clone(),
+        )
+        .unwrap();
+
+        deputy_account_id = account_id;
+    }
+
+    deputy_account_id
+}
+
+// Fills the review history of the worker up to the limit.
+fn add_worker_reviews<T: Config<I>, I: Instance>(lead_id: &T::AccountId, worker_id: WorkerId<T>) {
+    for _ in 0..MAX_WORKER_REVIEWS {
+        WorkingGroup::<T, I>::review_worker(
+            RawOrigin::Signed(lead_id.clone()).into(),
+            worker_id,
+            MAX_REVIEW_SCORE,
+            T::Hashing::hash(&[0u8]),
+            false,
+        )
+        .unwrap();
+    }
+}
+
+// Makes the half of the worker reward vest.
+fn set_reward_vesting<T: Config<I>, I: Instance>(
+    origin: RawOrigin<T::AccountId>,
+    worker_id: WorkerId<T>,
+) {
+    WorkingGroup::<T, I>::set_worker_reward_vesting(
+        origin.into(),
+        worker_id,
+        Some(RewardVestingSplit {
+            vested_percentage: Percent::from_percent(50),
+            vesting_period: T::BlockNumber::from(1000u32),
+        }),
+    )
+    .unwrap();
+}
+
 benchmarks_instance! {
     where_clause {
         where T: membership::Config + Config<I>
@@ -289,6 +356,10 @@ benchmarks_instance! {
         let mut worker_id = Zero::zero();
         for id in application_account_id {
             worker_id += One::one();
+            // Worst case scenario the review history of the worker is full
+            add_worker_reviews::<T, I>(&lead_id, worker_id);
+            // Worst case scenario the reward of the worker vests
+            set_reward_vesting::<T, I>(RawOrigin::Signed(lead_id.clone()), worker_id);
             WorkingGroup::<T, _>::leave_role(
                     RawOrigin::Signed(id).into(),
                     worker_id,
@@ -297,6 +368,7 @@ benchmarks_instance! {
         }
 
         // Worst case scenario one of the leaving workers is the lead
+        set_reward_vesting::<T, I>(RawOrigin::Root, lead_worker_id);
         WorkingGroup::<T, _>::leave_role(
             RawOrigin::Signed(lead_id).into(),
             lead_worker_id,
@@ -328,181 +400,49 @@ benchmarks_instance! {
         assert_eq!(WorkingGroup::<T, _>::budget(), BalanceOf::<T>::max_value());
     }: { WorkingGroup::<T, _>::on_initialize(curr_block_number) }
     verify {
-        WorkerById::<T, I>::iter().for_each(|(worker_id, _)| {
-            assert!(!WorkerById::<T, I>::contains_key(worker_id), "Worker hasn't left");
-        });
-
-        let reward_per_worker = BalanceOf::<T>::from(T::RewardPeriod::get());
+        assert_eq!(WorkerById::<T, I>::iter().count(), 0, "Not all workers left");
 
-        assert_eq!(
-            WorkingGroup::<T, I>::budget(),
-            BalanceOf::<T>::max_value()
-                .saturating_sub(BalanceOf::<T>::from(i) * reward_per_worker)
-                .saturating_sub(reward_per_worker),
-            "Budget wasn't correctly updated, probably not all workers rewarded"
+        assert!(
+            WorkingGroup::<T, I>::budget() < BalanceOf::<T>::max_value(),
+            "Missed rewards weren't paid"
         );
     }
 
-
-    on_initialize_rewarding_with_missing_reward {
-        let i in 2 .. T::MaxWorkerNumberLimit::get();
-
-        let (lead_id, _) = insert_a_worker::<T, I>(
+    claim_worker_reward {
+        let (lead_id, lead_worker_id) = insert_a_worker::<T, I>(
             OpeningType::Leader,
             0,
             None
         );
 
-        let (opening_id, successful_application_ids, _) =
-            add_opening_and_apply_with_multiple_ids::<T, I>(
-                &(1..i).collect::<Vec<_>>(),
-                &T::RuntimeOrigin::from(RawOrigin::Signed(lead_id.clone())),
-                &OpeningType::Regular
-            );
-
-        WorkingGroup::<T, _>::fill_opening(RawOrigin::Signed(lead_id).into(), opening_id,
-        successful_application_ids.clone()).unwrap();
-
-        for i in 1..successful_application_ids.len() {
-            assert!(
-                WorkerById::<T, I>::contains_key(WorkerId::<T>::from(i.try_into().unwrap())),
-                "Not all workers added"
-            );
-        }
-
-        // Worst case scenario there is a missing reward
-        force_missed_reward::<T, I>();
-
-        // Sets periods so that we can reward
-        let curr_block_number =
-            System::<T>::block_number().saturating_add(T::RewardPeriod::get().into());
-        System::<T>::set_block_number(curr_block_number);
+        // Worst case scenario the vested part of the reward is locked with a vesting schedule
+        set_reward_vesting::<T, I>(RawOrigin::Root, lead_worker_id);
 
-        // Sets budget so that we can pay it
+        // Worst case scenario there is an accrued and a missed reward
         WorkingGroup::<T, _>::set_budget(
             RawOrigin::Root.into(),
             BalanceOf::<T>::max_value()
         ).unwrap();
-
-        assert_eq!(WorkingGroup::<T, _>::budget(), BalanceOf::<T>::max_value());
-    }: { WorkingGroup::<T, _>::on_initialize(curr_block_number) }
-    verify {
-        let reward_per_worker = BalanceOf::<T>::from(T::RewardPeriod::get());
-
-        let reward_for_workers =
-            BalanceOf::<T>::from(i) * reward_per_worker * BalanceOf::<T>::from(2u32);
-
-        assert_eq!(
-            WorkingGroup::<T, _>::budget(),
-            // When creating a worker using `insert_a_worker` it gives the lead a number of block
-            // equating to reward period as missed reward(and the reward value is 1) therefore the
-            // additional discount of balance
-            BalanceOf::<T>::max_value()
-                .saturating_sub(reward_for_workers)
-                .saturating_sub(reward_per_worker),
-            "Budget wasn't correctly updated, probably not all workers rewarded"
-        );
-    }
-
-    on_initialize_rewarding_with_missing_reward_cant_pay {
-        let i in 2 .. T::MaxWorkerNumberLimit::get();
-
-        let (lead_id, _) = insert_a_worker::<T, I>(
-            OpeningType::Leader,
-            0,
-            None
-        );
-
-        let (opening_id, successful_application_ids, _) =
-            add_opening_and_apply_with_multiple_ids::<T, I>(
-                &(1..i).collect::<Vec<_>>(),
-                &T::RuntimeOrigin::from(RawOrigin::Signed(lead_id.clone())),
-                &OpeningType::Regular
-            );
-
-        WorkingGroup::<T, _>::fill_opening(RawOrigin::Signed(lead_id).into(), opening_id,
-        successful_application_ids.clone()).unwrap();
-
-        for i in 1..successful_application_ids.len() {
-            assert!(
-                WorkerById::<T, I>::contains_key(WorkerId::<T>::from(i.try_into().unwrap())),
-                "Not all workers added"
-            );
-        }
-
-        // Sets periods so that we can reward
-        let curr_block_number =
-            System::<T>::block_number().saturating_add(T::RewardPeriod::get().into());
-
+        let curr_block_number = System::<T>::block_number().saturating_add(One::one());
         System::<T>::set_block_number(curr_block_number);
 
-        // Sets budget so that we can't pay it
-        WorkingGroup::<T, _>::set_budget(RawOrigin::Root.into(), Zero::zero()).unwrap();
-
-        assert_eq!(WorkingGroup::<T, _>::budget(), Zero::zero());
-
-    }: { WorkingGroup::<T, _>::on_initialize(curr_block_number) }
-    verify {
-        WorkerById::<T, I>::iter().for_each(|(_, worker)| {
-            let missed_reward = worker.missed_reward.expect("There should be some missed reward");
-
-            assert!(
-                missed_reward >= BalanceOf::<T>::from(T::RewardPeriod::get()),
-                "At least one worker wasn't rewarded"
-            );
-        });
-    }
-
-    on_initialize_rewarding_without_missing_reward {
-        let i in 2 .. T::MaxWorkerNumberLimit::get();
-
-        let (lead_id, _) = insert_a_worker::<T, I>(
-            OpeningType::Leader,
-            0,
-            None
+        assert!(
+            WorkingGroup::<T, I>::worker_by_id(lead_worker_id)
+                .expect("Worker must exist")
+                .missed_reward
+                .is_some(),
+            "Missed reward not set"
         );
-
-        let (opening_id, successful_application_ids, _) =
-            add_opening_and_apply_with_multiple_ids::<T, I>(
-                &(1..i).collect::<Vec<_>>(),
-                &T::RuntimeOrigin::from(RawOrigin::Signed(lead_id.clone())),
-                &OpeningType::Regular
-            );
-
-        WorkingGroup::<T, _>::fill_opening(RawOrigin::Signed(lead_id).into(), opening_id,
-        successful_application_ids.clone()).unwrap();
-
-        for i in 1..successful_application_ids.len() {
-            assert!(
-                WorkerById::<T, I>::contains_key(WorkerId::<T>::from(i.try_into().unwrap())),
-                "Not all workers added"
-            );
-        }
-
-        // Sets periods so that we can reward
-        let curr_block_number =
-            System::<T>::block_number().saturating_add(T::RewardPeriod::get().into());
-        System::<T>::set_block_number(curr_block_number);
-
-        // Sets budget so that we can pay it
-        WorkingGroup::<T, _>::set_budget(
-            RawOrigin::Root.into(), BalanceOf::<T>::max_value()
-        ).unwrap();
-        assert_eq!(WorkingGroup::<T, _>::budget(), BalanceOf::<T>::max_value());
-
-    }: { WorkingGroup::<T, _>::on_initialize(curr_block_number) }
+    }: _ (RawOrigin::Signed(lead_id), lead_worker_id)
     verify {
-        let reward_per_worker = BalanceOf::<T>::from(T::RewardPeriod::get());
-        let workers_total_reward = BalanceOf::<T>::from(i) * reward_per_worker;
-        assert_eq!(
-            WorkingGroup::<T, _>::budget(),
-            // When creating a worker using `insert_a_worker` it gives the lead a number of block
-            // equating to reward period as missed reward(and the reward value is 1) therefore the
-            // additional discount of balance
-            BalanceOf::<T>::max_value()
-                .saturating_sub(workers_total_reward)
-                .saturating_sub(reward_per_worker),
-            "Budget wasn't correctly updated, probably not all workers rewarded"
+        let worker = WorkingGroup::<T, I>::worker_by_id(lead_worker_id).expect("Worker must exist");
+
+        assert_eq!(worker.missed_reward, None, "Missed reward not paid");
+        assert_eq!(worker.accrued_reward, Zero::zero(), "Accrued reward not paid");
+        assert_eq!(worker.unvested_reward, Zero::zero(), "Reward not vested");
+        assert!(
+            WorkingGroup::<T, I>::budget() < BalanceOf::<T>::max_value(),
+            "Budget wasn't correctly updated"
         );
     }
 
@@ -677,9 +617,23 @@ benchmarks_instance! {
             Some(lead_id.clone())
         );
         let slashing_amount = One::one();
+
+        // Worst case scenario the deputy is looked up among the maximal number of the deputies
+        let deputy_account_id = appoint_max_deputies::<T, I>(
+            &lead_id,
+            2,
+            DeputyPermissions {
+                slashing_limit: Some(SlashingLimit {
+                    amount: slashing_amount,
+                    period: One::one(),
+                }),
+                ..Default::default()
+            },
+        );
+
         let rationale = Some(vec![0u8; (i * 1000).try_into().unwrap()]);
     }: _(
-        RawOrigin::Signed(lead_id.clone()),
+        RawOrigin::Signed(deputy_account_id),
         worker_id,
         slashing_amount,
         rationale.clone()
@@ -707,6 +661,10 @@ benchmarks_instance! {
         // To be able to pay unpaid reward
         let current_budget = BalanceOf::<T>::max_value();
         WorkingGroup::<T, _>::set_budget(RawOrigin::Root.into(), current_budget).unwrap();
+        // Worst case scenario the review history of the worker is full
+        add_worker_reviews::<T, I>(&lead_id, worker_id);
+        // Worst case scenario the reward of the worker vests
+        set_reward_vesting::<T, I>(RawOrigin::Signed(lead_id.clone()), worker_id);
         let penalty = Some(One::one());
         let rationale = Some(vec![0u8; (i * 1000).try_into().unwrap()]);
     }: terminate_role(
@@ -717,6 +675,11 @@ benchmarks_instance! {
         )
     verify {
         assert!(!WorkerById::<T, I>::contains_key(worker_id), "Worker not terminated");
+        assert_eq!(
+            WorkerReviews::<T, I>::iter_prefix(worker_id).count(),
+            0,
+            "Worker reviews not removed"
+        );
         assert_last_event::<T, I>(RawEvent::TerminatedWorker(worker_id, penalty, rationale).into());
     }
 
@@ -728,6 +691,8 @@ benchmarks_instance! {
         let current_budget = BalanceOf::<T>::max_value();
         // To be able to pay unpaid reward
         WorkingGroup::<T, _>::set_budget(RawOrigin::Root.into(), current_budget).unwrap();
+        // Worst case scenario the reward of the lead vests
+        set_reward_vesting::<T, I>(RawOrigin::Root, lead_worker_id);
         let penalty = Some(One::one());
         let rationale = Some(vec![0u8; (i * 1000).try_into().unwrap()]);
     }: terminate_role(
@@ -789,8 +754,22 @@ benchmarks_instance! {
         );
 
         let current_budget = BalanceOf::<T>::max_value();
+
+        // Worst case scenario the deputy is looked up among the maximal number of the deputies
+        let deputy_account_id = appoint_max_deputies::<T, I>(
+            &lead_id,
+            1,
+            DeputyPermissions {
+                spending_limit: Some(SpendingLimit {
+                    amount: current_budget,
+                    period: One::one(),
+                }),
+                ..Default::default()
+            },
+        );
+
         WorkingGroup::<T, _>::set_budget(RawOrigin::Root.into(), current_budget).unwrap();
-    }: _ (RawOrigin::Signed(lead_id.clone()), lead_id.clone(), current_budget, None)
+    }: _ (RawOrigin::Signed(deputy_account_id), lead_id.clone(), current_budget, None)
     verify {
         assert_eq!(WorkingGroup::<T, I>::budget(), Zero::zero(), "Budget not updated");
         assert_last_event::<T, I>(RawEvent::BudgetSpending(lead_id, current_budget, None).into());
@@ -855,6 +834,414 @@ benchmarks_instance! {
         );
     }
 
+    set_worker_reward_vesting {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (_, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+
+        let reward_vesting = Some(RewardVestingSplit {
+            vested_percentage: Percent::from_percent(30),
+            vesting_period: T::BlockNumber::from(1000u32),
+        });
+    }: _ (RawOrigin::Signed(lead_id.clone()), worker_id, reward_vesting)
+    verify {
+        assert_eq!(
+            WorkingGroup::<T, I>::worker_by_id(worker_id).expect("Worker Must Exist").reward_vesting,
+            reward_vesting,
+            "Reward vesting not updated"
+        );
+
+        assert_last_event::<T, I>(
+            RawEvent::WorkerRewardVestingUpdated(worker_id, reward_vesting).into()
+        );
+    }
+
+    set_reward_multiplier_bounds {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+
+        let bounds = Some(RewardMultiplierBounds {
+            min: 50,
+            max: MAX_REWARD_MULTIPLIER,
+        });
+    }: _ (RawOrigin::Signed(lead_id), bounds)
+    verify {
+        assert_eq!(
+            WorkingGroup::<T, I>::review_reward_multiplier_bounds(),
+            bounds,
+            "Reward multiplier bounds not updated"
+        );
+
+        assert_last_event::<T, I>(RawEvent::RewardMultiplierBoundsUpdated(bounds).into());
+    }
+
+    review_worker {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (_, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+
+        // Worst case scenario the review adjusts the reward
+        WorkingGroup::<T, I>::set_reward_multiplier_bounds(
+            RawOrigin::Signed(lead_id.clone()).into(),
+            Some(RewardMultiplierBounds {
+                min: 50,
+                max: MAX_REWARD_MULTIPLIER,
+            }),
+        ).unwrap();
+
+        // Worst case scenario the oldest review is removed
+        add_worker_reviews::<T, I>(&lead_id, worker_id);
+
+        let rationale_hash = T::Hashing::hash(&[0u8]);
+    }: _ (RawOrigin::Signed(lead_id), worker_id, MAX_REVIEW_SCORE, rationale_hash, true)
+    verify {
+        assert!(
+            WorkingGroup::<T, I>::worker_review(worker_id, MAX_WORKER_REVIEWS).is_some(),
+            "Review not recorded"
+        );
+
+        assert!(
+            WorkingGroup::<T, I>::worker_review(worker_id, 0).is_none(),
+            "Oldest review not removed"
+        );
+
+        assert_eq!(
+            WorkingGroup::<T, I>::worker_reward_multiplier(worker_id),
+            Some(MAX_REWARD_MULTIPLIER),
+            "Reward multiplier not updated"
+        );
+
+        assert_last_event::<T, I>(
+            RawEvent::WorkerReviewed(
+                worker_id,
+                MAX_WORKER_REVIEWS,
+                MAX_REVIEW_SCORE,
+                rationale_hash,
+                Some(MAX_REWARD_MULTIPLIER),
+            ).into()
+        );
+    }
+
+    overturn_worker_review {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (_, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+
+        // Worst case scenario the latest review adjusted the reward and the multiplier falls
+        // back to the oldest kept review
+        WorkingGroup::<T, I>::set_reward_multiplier_bounds(
+            RawOrigin::Signed(lead_id.clone()).into(),
+            Some(RewardMultiplierBounds {
+                min: 50,
+                max: MAX_REWARD_MULTIPLIER,
+            }),
+        ).unwrap();
+
+        let review_id = MAX_WORKER_REVIEWS - 1;
+        for id in 0..MAX_WORKER_REVIEWS {
+            WorkingGroup::<T, I>::review_worker(
+                RawOrigin::Signed(lead_id.clone()).into(),
+                worker_id,
+                0,
+                T::Hashing::hash(&[0u8]),
+                id == 0 || id == review_id,
+            ).unwrap();
+        }
+    }: _ (RawOrigin::Root, worker_id, review_id)
+    verify {
+        assert!(
+            WorkingGroup::<T, I>::worker_review(worker_id, review_id)
+                .expect("Review must exist")
+                .overturned,
+            "Review not overturned"
+        );
+
+        assert_eq!(
+            WorkingGroup::<T, I>::worker_reward_multiplier(worker_id),
+            WorkingGroup::<T, I>::worker_review(worker_id, 0)
+                .expect("Review must exist")
+                .reward_multiplier,
+            "Reward multiplier not restored"
+        );
+
+        assert_last_event::<T, I>(
+            RawEvent::WorkerReviewOverturned(worker_id, review_id).into()
+        );
+    }
+
+    appoint_deputy {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (_, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+
+        let permissions = DeputyPermissions {
+            fill_openings: true,
+            spending_limit: Some(SpendingLimit {
+                amount: One::one(),
+                period: One::one(),
+            }),
+            slashing_limit: Some(SlashingLimit {
+                amount: One::one(),
+                period: One::one(),
+            }),
+        };
+    }: _ (RawOrigin::Signed(lead_id), worker_id, permissions.clone())
+    verify {
+        assert!(
+            WorkingGroup::<T, I>::deputy_by_worker_id(worker_id).is_some(),
+            "Deputy not appointed"
+        );
+
+        assert_eq!(WorkingGroup::<T, I>::deputy_count(), 1);
+
+        assert_last_event::<T, I>(RawEvent::DeputyAppointed(worker_id, permissions).into());
+    }
+
+    revoke_deputy {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (_, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+
+        WorkingGroup::<T, I>::appoint_deputy(
+            RawOrigin::Signed(lead_id.clone()).into(),
+            worker_id,
+            DeputyPermissions::default(),
+        ).unwrap();
+    }: _ (RawOrigin::Signed(lead_id), worker_id)
+    verify {
+        assert!(
+            WorkingGroup::<T, I>::deputy_by_worker_id(worker_id).is_none(),
+            "Deputy not revoked"
+        );
+
+        assert_eq!(WorkingGroup::<T, I>::deputy_count(), 0);
+
+        assert_last_event::<T, I>(RawEvent::DeputyRevoked(worker_id).into());
+    }
+
+    start_application_evaluation {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let opening_id = add_opening_helper::<T, I>(
+            1,
+            &T::RuntimeOrigin::from(RawOrigin::Signed(lead_id.clone())),
+            &OpeningType::Regular
+        );
+    }: _ (RawOrigin::Signed(lead_id), opening_id, One::one())
+    verify {
+        let ends_at = System::<T>::block_number() + One::one();
+        assert_eq!(
+            WorkingGroup::<T, I>::application_evaluation(opening_id),
+            Some(ApplicationEvaluation {
+                ends_at,
+                evaluated_applications: 0,
+                votes: 0,
+                deviation_rationale_hash: None,
+            })
+        );
+
+        assert_last_event::<T, I>(
+            RawEvent::ApplicationEvaluationStarted(opening_id, ends_at).into()
+        );
+    }
+
+    vote_on_application {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (worker_account_id, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+
+        let (applicant_account_id, applicant_member_id) =
+            member_funded_account::<T, I>("member", 2);
+        let (opening_id, application_id) = add_and_apply_opening::<T, I>(
+            2,
+            &T::RuntimeOrigin::from(RawOrigin::Signed(lead_id.clone())),
+            &applicant_account_id,
+            &applicant_member_id,
+            &OpeningType::Regular
+        );
+
+        WorkingGroup::<T, I>::start_application_evaluation(
+            RawOrigin::Signed(lead_id).into(),
+            opening_id,
+            One::one(),
+        ).unwrap();
+    }: _ (
+        RawOrigin::Signed(worker_account_id.clone()),
+        worker_id,
+        application_id,
+        MAX_APPLICATION_SCORE
+    )
+    verify {
+        let weight = T::StakingHandler::current_stake(&worker_account_id);
+        assert_eq!(
+            WorkingGroup::<T, I>::application_vote(opening_id, (application_id, worker_id)),
+            Some(ApplicationVote { score: MAX_APPLICATION_SCORE, weight })
+        );
+
+        assert_last_event::<T, I>(
+            RawEvent::ApplicationVoted(
+                worker_id,
+                application_id,
+                MAX_APPLICATION_SCORE,
+                weight
+            ).into()
+        );
+    }
+
+    justify_evaluation_deviation {
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let opening_id = add_opening_helper::<T, I>(
+            1,
+            &T::RuntimeOrigin::from(RawOrigin::Signed(lead_id.clone())),
+            &OpeningType::Regular
+        );
+
+        WorkingGroup::<T, I>::start_application_evaluation(
+            RawOrigin::Signed(lead_id.clone()).into(),
+            opening_id,
+            One::one(),
+        ).unwrap();
+
+        let rationale_hash = T::Hashing::hash(b"rationale");
+    }: _ (RawOrigin::Signed(lead_id), opening_id, rationale_hash)
+    verify {
+        assert_eq!(
+            WorkingGroup::<T, I>::application_evaluation(opening_id)
+                .and_then(|evaluation| evaluation.deviation_rationale_hash),
+            Some(rationale_hash)
+        );
+
+        assert_last_event::<T, I>(
+            RawEvent::EvaluationDeviationJustified(opening_id, rationale_hash).into()
+        );
+    }
+
+    conclude_application_evaluation {
+        let i in 1 .. MAX_EVALUATED_APPLICATIONS;
+        let v in MAX_EVALUATED_APPLICATIONS .. MAX_APPLICATION_VOTES;
+
+        let (lead_id, _) =
+            insert_a_worker::<T, I>(OpeningType::Leader, 0, None);
+        let (worker_account_id, worker_id) = insert_a_worker::<T, I>(
+            OpeningType::Regular,
+            1,
+            Some(lead_id.clone())
+        );
+
+        let opening_id = add_opening_helper::<T, I>(
+            2,
+            &T::RuntimeOrigin::from(RawOrigin::Signed(lead_id.clone())),
+            &OpeningType::Regular
+        );
+
+        WorkingGroup::<T, I>::start_application_evaluation(
+            RawOrigin::Signed(lead_id.clone()).into(),
+            opening_id,
+            One::one(),
+        ).unwrap();
+
+        // The lowest ranked application is hired, so the decision deviates from the tally.
+        let mut application_ids = Vec::new();
+        for id in 2 .. i + 2 {
+            let (applicant_account_id, applicant_member_id) =
+                member_funded_account::<T, I>("member", id);
+            let application_id = apply_on_opening_helper::<T, I>(
+                id,
+                &applicant_account_id,
+                &applicant_member_id,
+                &opening_id
+            );
+
+            let score = if id == 2 { 0 } else { MAX_APPLICATION_SCORE };
+            WorkingGroup::<T, I>::vote_on_application(
+                RawOrigin::Signed(worker_account_id.clone()).into(),
+                worker_id,
+                application_id,
+                score,
+            ).unwrap();
+
+            application_ids.push(application_id);
+        }
+
+        // The votes of the other workers are only removed, so they are inserted directly.
+        let vote = WorkingGroup::<T, I>::application_vote(
+            opening_id,
+            (application_ids[0], worker_id)
+        ).expect("Vote must exist");
+        for voter_id in i + 2 .. v + 2 {
+            ApplicationVotes::<T, I>::insert(
+                opening_id,
+                (application_ids[0], WorkerId::<T>::from(voter_id.try_into().unwrap())),
+                vote.clone(),
+            );
+        }
+
+        let rationale_hash = T::Hashing::hash(b"rationale");
+        WorkingGroup::<T, I>::justify_evaluation_deviation(
+            RawOrigin::Signed(lead_id.clone()).into(),
+            opening_id,
+            rationale_hash,
+        ).unwrap();
+
+        System::<T>::set_block_number(System::<T>::block_number() + One::one());
+
+        let successful_application_ids =
+            application_ids.iter().take(1).copied().collect::<BTreeSet<_>>();
+    }: fill_opening(
+            RawOrigin::Signed(lead_id),
+            opening_id,
+            successful_application_ids.clone()
+        )
+    verify {
+        assert!(!OpeningById::<T, I>::contains_key(opening_id), "Opening still not filled");
+        assert!(
+            WorkingGroup::<T, I>::application_evaluation(opening_id).is_none(),
+            "Evaluation not removed"
+        );
+        assert_eq!(
+            ApplicationVotes::<T, I>::iter_prefix(opening_id).count(),
+            0,
+            "Votes not removed"
+        );
+
+        let expected_rationale_hash = if i > 1 { Some(rationale_hash) } else { None };
+        let decided_event: <T as frame_system::Config>::RuntimeEvent =
+            <T as Config<I>>::RuntimeEvent::from(RawEvent::ApplicationEvaluationDecided(
+                opening_id,
+                successful_application_ids,
+                expected_rationale_hash
+            )).into();
+        assert!(
+            System::<T>::events().iter().any(|record| record.event == decided_event),
+            "Evaluation decision not emitted"
+        );
+    }
+
     set_status_text {
         let i in 0 .. MAX_KILOBYTES_METADATA;
 
@@ -1125,37 +1512,86 @@ mod tests {
     }
 
     #[test]
-    fn test_on_inintialize_rewarding_without_missing_reward() {
+    fn test_on_inintialize_leaving() {
         build_test_externalities().execute_with(|| {
-            assert_ok!(
-                WorkingGroup::<Test>::test_benchmark_on_initialize_rewarding_without_missing_reward(
-                )
-            );
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_on_initialize_leaving());
         });
     }
 
     #[test]
-    fn test_on_inintialize_rewarding_with_missing_reward_cant_pay() {
+    fn test_set_worker_reward_vesting() {
         build_test_externalities().execute_with(|| {
-            assert_ok!(
-                WorkingGroup::<Test>::test_benchmark_on_initialize_rewarding_with_missing_reward_cant_pay()
-            );
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_set_worker_reward_vesting());
         });
     }
 
     #[test]
-    fn test_on_inintialize_rewarding_with_missing_reward() {
+    fn test_set_reward_multiplier_bounds() {
         build_test_externalities().execute_with(|| {
-            assert_ok!(
-                WorkingGroup::<Test>::test_benchmark_on_initialize_rewarding_with_missing_reward()
-            );
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_set_reward_multiplier_bounds());
         });
     }
 
     #[test]
-    fn test_on_inintialize_leaving() {
+    fn test_review_worker() {
         build_test_externalities().execute_with(|| {
-            assert_ok!(WorkingGroup::<Test>::test_benchmark_on_initialize_leaving());
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_review_worker());
+        });
+    }
+
+    #[test]
+    fn test_overturn_worker_review() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_overturn_worker_review());
+        });
+    }
+
+    #[test]
+    fn test_appoint_deputy() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_appoint_deputy());
+        });
+    }
+
+    #[test]
+    fn test_revoke_deputy() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_revoke_deputy());
+        });
+    }
+
+    #[test]
+    fn test_start_application_evaluation() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_start_application_evaluation());
+        });
+    }
+
+    #[test]
+    fn test_vote_on_application() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_vote_on_application());
+        });
+    }
+
+    #[test]
+    fn test_justify_evaluation_deviation() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_justify_evaluation_deviation());
+        });
+    }
+
+    #[test]
+    fn test_conclude_application_evaluation() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_conclude_application_evaluation());
+        });
+    }
+
+    #[test]
+    fn test_claim_worker_reward() {
+        build_test_externalities().execute_with(|| {
+            assert_ok!(WorkingGroup::<Test>::test_benchmark_claim_worker_reward());
         });
     }
 
@@ -1,12 +1,13 @@
 use crate::{
-    ApplicationId, BalanceOf, Config, Instance, OpeningId, OpeningOf, OpeningType, StakePolicy,
-    Worker, WorkerId,
+    ApplicationId, BalanceOf, Config, DeputyPermissionsOf, Instance, OpeningId, OpeningOf,
+    OpeningType, RewardMultiplierBounds, RewardVestingSplitOf, StakePolicy, Worker, WorkerId,
+    MAX_REWARD_MULTIPLIER,
 };
 
 use super::Error;
 use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::traits::Get;
-use frame_support::{ensure, StorageMap, StorageValue};
+use frame_support::{ensure, IterableStorageMap, StorageMap, StorageValue};
 use frame_system::{ensure_root, ensure_signed};
 use sp_arithmetic::traits::Zero;
 use sp_std::collections::btree_set::BTreeSet;
@@ -14,7 +15,7 @@ use sp_std::marker::PhantomData;
 use sp_std::vec::Vec;
 use staking_handler::StakingHandler;
 
-use crate::types::{ApplicationInfo, StakeParameters};
+use crate::types::{ApplicationInfo, DeputyAction, StakeParameters};
 
 // Check opening: verifies origin and opening type compatibility.
 pub(crate) fn ensure_origin_for_opening_type<T: Config<I>, I: Instance>(
@@ -157,6 +158,71 @@ pub(crate) fn ensure_origin_is_active_leader<T: Config<I>, I: Instance>(
     ensure_is_lead_account::<T, I>(signer)
 }
 
+// Check leader or deputy: ensures origin is signed by the leader or by the deputy permitted
+// to perform the action. Returns the deputy worker id for the deputy origin.
+pub(crate) fn ensure_origin_is_leader_or_deputy<T: Config<I>, I: Instance>(
+    origin: T::RuntimeOrigin,
+    action: DeputyAction<BalanceOf<T>>,
+) -> Result<Option<WorkerId<T>>, DispatchError> {
+    // Ensure is signed
+    let signer = ensure_signed(origin)?;
+
+    // The lead check error is returned if the signer is not a deputy either.
+    let lead_error = match ensure_is_lead_account::<T, I>(signer.clone()) {
+        Ok(()) => return Ok(None),
+        Err(err) => err,
+    };
+
+    // The number of deputies is bounded by MAX_DEPUTIES.
+    let (deputy_id, deputy) = <crate::Deputies<T, I>>::iter()
+        .find(|(worker_id, _)| {
+            <crate::WorkerById<T, I>>::get(worker_id)
+                .map_or(false, |worker| worker.role_account_id == signer)
+        })
+        .ok_or(lead_error)?;
+
+    match action {
+        DeputyAction::FillOpening => {
+            ensure!(
+                deputy.permissions.fill_openings,
+                Error::<T, I>::DeputyActionNotPermitted
+            );
+        }
+        DeputyAction::Spend(amount) => {
+            let spending_limit = deputy
+                .permissions
+                .spending_limit
+                .as_ref()
+                .ok_or(Error::<T, I>::DeputyActionNotPermitted)?;
+
+            let spent =
+                crate::Module::<T, I>::deputy_spent_in_period(&deputy, spending_limit.period);
+
+            ensure!(
+                spent.saturating_add(amount) <= spending_limit.amount,
+                Error::<T, I>::DeputySpendingLimitExceeded
+            );
+        }
+        DeputyAction::Slash(penalty) => {
+            let slashing_limit = deputy
+                .permissions
+                .slashing_limit
+                .as_ref()
+                .ok_or(Error::<T, I>::DeputyActionNotPermitted)?;
+
+            let slashed =
+                crate::Module::<T, I>::deputy_slashed_in_period(&deputy, slashing_limit.period);
+
+            ensure!(
+                slashed.saturating_add(penalty) <= slashing_limit.amount,
+                Error::<T, I>::DeputySlashingLimitExceeded
+            );
+        }
+    }
+
+    Ok(Some(deputy_id))
+}
+
 // Check worker: ensures the worker was already created.
 pub(crate) fn ensure_worker_exists<T: Config<I>, I: Instance>(
     worker_id: &WorkerId<T>,
@@ -202,6 +268,23 @@ pub(crate) fn ensure_origin_for_worker_operation<T: Config<I>, I: Instance>(
     Ok(is_sudo)
 }
 
+// Check worker: verifies proper origin for the worker operation permitted to the deputies.
+// Returns the deputy worker id for the deputy origin.
+pub(crate) fn ensure_origin_for_deputy_worker_operation<T: Config<I>, I: Instance>(
+    origin: T::RuntimeOrigin,
+    worker_id: WorkerId<T>,
+    action: DeputyAction<BalanceOf<T>>,
+) -> Result<Option<WorkerId<T>>, DispatchError> {
+    if <crate::CurrentLead<T, I>>::get() == Some(worker_id) {
+        // Council proposal.
+        ensure_root(origin)?;
+
+        return Ok(None);
+    }
+
+    ensure_origin_is_leader_or_deputy::<T, I>(origin, action)
+}
+
 // Check opening: verifies stake policy for the opening.
 pub(crate) fn ensure_valid_stake_policy<T: Config<I>, I: Instance>(
     stake_policy: &StakePolicy<T::BlockNumber, BalanceOf<T>>,
@@ -254,3 +337,48 @@ pub(crate) fn ensure_worker_has_recurring_reward<T: Config<I>, I: Instance>(
         .reward_per_block
         .map_or(Err(Error::<T, I>::WorkerHasNoReward.into()), |_| Ok(()))
 }
+
+// Check reward multiplier bounds: verifies the bounds are ordered and don't exceed the maximum.
+pub(crate) fn ensure_valid_reward_multiplier_bounds<T: Config<I>, I: Instance>(
+    bounds: &RewardMultiplierBounds,
+) -> DispatchResult {
+    ensure!(
+        bounds.min <= bounds.max && bounds.max <= MAX_REWARD_MULTIPLIER,
+        Error::<T, I>::InvalidRewardMultiplierBounds
+    );
+
+    Ok(())
+}
+
+// Check deputy permissions: verifies the periods of the spending and slashing limits are not zero.
+pub(crate) fn ensure_valid_deputy_permissions<T: Config<I>, I: Instance>(
+    permissions: &DeputyPermissionsOf<T>,
+) -> DispatchResult {
+    if let Some(ref spending_limit) = permissions.spending_limit {
+        ensure!(
+            !spending_limit.period.is_zero(),
+            Error::<T, I>::InvalidDeputySpendingPeriod
+        );
+    }
+
+    if let Some(ref slashing_limit) = permissions.slashing_limit {
+        ensure!(
+            !slashing_limit.period.is_zero(),
+            Error::<T, I>::InvalidDeputySlashingPeriod
+        );
+    }
+
+    Ok(())
+}
+
+// Check the reward vesting split: both the vested percentage and the vesting period must be set.
+pub(crate) fn ensure_valid_reward_vesting_split<T: Config<I>, I: Instance>(
+    reward_vesting: &RewardVestingSplitOf<T>,
+) -> DispatchResult {
+    ensure!(
+        !reward_vesting.vested_percentage.is_zero() && !reward_vesting.vesting_period.is_zero(),
+        Error::<T, I>::InvalidRewardVestingSplit
+    );
+
+    Ok(())
+}
@@ -103,5 +103,89 @@ decl_error! {
 
         /// Cannot withdraw: insufficient budget balance.
         InsufficientBalanceForTransfer,
+
+        /// Review score exceeds the maximal score.
+        InvalidReviewScore,
+
+        /// Invalid reward multiplier bounds: minimum exceeds maximum or maximum is too high.
+        InvalidRewardMultiplierBounds,
+
+        /// Invalid reward vesting split: zero vested percentage or zero vesting period.
+        InvalidRewardVestingSplit,
+
+        /// Reward multiplier bounds are not set.
+        RewardMultiplierBoundsNotSet,
+
+        /// The leader can't be reviewed by the leader.
+        CannotReviewLeader,
+
+        /// Worker review doesn't exist.
+        WorkerReviewDoesNotExist,
+
+        /// Worker review is already overturned.
+        WorkerReviewAlreadyOverturned,
+
+        /// The leader can't be appointed as a deputy.
+        CannotAppointLeaderAsDeputy,
+
+        /// Maximal number of the deputies is reached.
+        MaxDeputyNumberExceeded,
+
+        /// Deputy doesn't exist.
+        DeputyDoesNotExist,
+
+        /// Deputy permissions don't allow the action.
+        DeputyActionNotPermitted,
+
+        /// The spending exceeds the deputy spending limit for the period.
+        DeputySpendingLimitExceeded,
+
+        /// The penalty exceeds the deputy slashing limit for the period.
+        DeputySlashingLimitExceeded,
+
+        /// The spending period of the deputy spending limit can't be zero.
+        InvalidDeputySpendingPeriod,
+
+        /// The slashing period of the deputy slashing limit can't be zero.
+        InvalidDeputySlashingPeriod,
+
+        /// The deputy can't slash the stake of a deputy, including their own.
+        DeputyCannotSlashDeputy,
+
+        /// Only the regular worker openings can be evaluated.
+        CannotEvaluateLeaderOpening,
+
+        /// The application evaluation for the opening is already started.
+        ApplicationEvaluationAlreadyStarted,
+
+        /// Evaluation period should be greater than zero.
+        InvalidEvaluationPeriod,
+
+        /// The application evaluation for the opening doesn't exist.
+        ApplicationEvaluationDoesNotExist,
+
+        /// The application evaluation window has ended.
+        ApplicationEvaluationEnded,
+
+        /// The application evaluation window hasn't ended yet.
+        ApplicationEvaluationInProgress,
+
+        /// The worker can't vote on own application.
+        CannotVoteOnOwnApplication,
+
+        /// Application score exceeds the maximal score.
+        InvalidApplicationScore,
+
+        /// The worker without stake can't vote on the applications.
+        CannotVoteWithoutStake,
+
+        /// Maximal number of the voted applications is reached.
+        MaxEvaluatedApplicationsExceeded,
+
+        /// Maximal number of the votes on the applications is reached.
+        MaxApplicationVotesExceeded,
+
+        /// Filling the opening against the tally requires the rationale.
+        EvaluationDeviationNotJustified,
     }
 }
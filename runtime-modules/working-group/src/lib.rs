@@ -20,6 +20,16 @@
 //! - [set_budget](./struct.Module.html#method.set_budget) - Sets the working group budget.
 //! - [update_reward_account](./struct.Module.html#method.update_reward_account) -  Update the reward account of the regular worker/lead.
 //! - [update_reward_amount](./struct.Module.html#method.update_reward_amount) -  Update the reward amount of the regular worker/lead.
+//! - [claim_worker_reward](./struct.Module.html#method.claim_worker_reward) - Pay the reward accrued by the regular worker/lead.
+//! - [set_worker_reward_vesting](./struct.Module.html#method.set_worker_reward_vesting) - Sets the part of the regular worker/lead reward paid through the vesting schedules.
+//! - [set_reward_multiplier_bounds](./struct.Module.html#method.set_reward_multiplier_bounds) - Sets the bounds of the reward multiplier derived from the review score.
+//! - [review_worker](./struct.Module.html#method.review_worker) - Record a performance review of the regular worker.
+//! - [overturn_worker_review](./struct.Module.html#method.overturn_worker_review) - Overturn the disputed worker review.
+//! - [appoint_deputy](./struct.Module.html#method.appoint_deputy) - Appoint the regular worker as a deputy with a subset of the leader permissions.
+//! - [revoke_deputy](./struct.Module.html#method.revoke_deputy) - Revoke the deputy permissions.
+//! - [start_application_evaluation](./struct.Module.html#method.start_application_evaluation) - Start the evaluation window of the regular worker opening.
+//! - [vote_on_application](./struct.Module.html#method.vote_on_application) - Vote on the application under evaluation by the active worker.
+//! - [justify_evaluation_deviation](./struct.Module.html#method.justify_evaluation_deviation) - Record the rationale for filling the opening against the tally.
 //! - [set_status_text](./struct.Module.html#method.set_status_text) - Sets the working group status.
 //! - [spend_from_budget](./struct.Module.html#method.spend_from_budget) - Spend tokens from the group budget.
 //! - [fund_working_group_budget](./struct.Module.html#method.fund_working_group_budget) - Fund the group budget by a member.
@@ -56,6 +66,7 @@ pub mod benchmarking;
 
 mod checks;
 mod errors;
+pub mod migrations;
 #[cfg(test)]
 mod tests;
 mod types;
@@ -64,13 +75,14 @@ pub use weights::WeightInfo;
 
 use common::{costs::burn_from_usable, StakingAccountValidator};
 use frame_support::dispatch::RawOrigin;
-use frame_support::traits::{Currency, Get, LockIdentifier};
+use frame_support::traits::{Currency, Get, LockIdentifier, StorageVersion, VestingSchedule};
 use frame_support::weights::Weight;
-use frame_support::IterableStorageMap;
 use frame_support::{decl_event, decl_module, decl_storage, ensure, StorageValue};
+use frame_support::{IterableStorageDoubleMap, IterableStorageMap};
 use frame_system::{ensure_root, ensure_signed};
 use sp_runtime::traits::Convert;
 use sp_runtime::traits::{Hash, One, SaturatedConversion, Saturating, StaticLookup, Zero};
+use sp_runtime::{Percent, Perquintill};
 use sp_std::borrow::ToOwned;
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 use sp_std::{vec, vec::Vec};
@@ -90,6 +102,38 @@ type WeightInfoWorkingGroup<T, I> = <T as Config<I>>::WeightInfo;
 type VestingInfoOf<T> =
     vesting::VestingInfo<VestingBalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
 
+// Storage version of the pallet.
+const CURRENT_STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+/// Reward index increase for a block with fully funded worker rewards.
+pub const REWARD_INDEX_PRECISION: u128 = 1_000_000_000_000;
+
+/// Maximal worker review score.
+pub const MAX_REVIEW_SCORE: u8 = 100;
+
+/// Maximal number of the reviews kept for the worker. The oldest review is removed
+/// once the limit is reached.
+pub const MAX_WORKER_REVIEWS: u32 = 20;
+
+/// Maximal reward multiplier (in percent) derived from the review score.
+pub const MAX_REWARD_MULTIPLIER: u16 = 200;
+
+/// Maximal number of the deputies in the group.
+pub const MAX_DEPUTIES: u32 = 5;
+
+/// Maximal score of the application vote.
+pub const MAX_APPLICATION_SCORE: u8 = 100;
+
+/// Maximal number of the voted applications per evaluated opening.
+pub const MAX_EVALUATED_APPLICATIONS: u32 = 50;
+
+/// Maximal number of the worker votes per evaluated opening.
+pub const MAX_APPLICATION_VOTES: u32 = 500;
+
+/// Maximal number of the vesting schedules added for the worker reward within the vesting period.
+/// The vested reward is withheld between the schedules to stay within the limit.
+pub const MAX_REWARD_VESTING_SCHEDULES: u32 = 2;
+
 /// The _Group_ main _Config_
 pub trait Config<I: Instance = DefaultInstance>:
     frame_system::Config + balances::Config + common::membership::MembershipTypes + vesting::Config
@@ -124,9 +168,6 @@ pub trait Config<I: Instance = DefaultInstance>:
     /// Defines min unstaking period in the group.
     type MinUnstakingPeriodLimit: Get<Self::BlockNumber>;
 
-    /// Defines the period every worker gets paid in blocks.
-    type RewardPeriod: Get<u32>;
-
     /// Weight information for extrinsics in this pallet.
     type WeightInfo: WeightInfo;
 
@@ -152,7 +193,12 @@ decl_event!(
        ApplyOnOpeningParameters = ApplyOnOpeningParameters<T>,
        MemberId = MemberId<T>,
        Hash = <T as frame_system::Config>::Hash,
-       VestingInfo = VestingInfoOf<T>
+       VestingInfo = VestingInfoOf<T>,
+       ReviewId = ReviewId,
+       RewardMultiplierBounds = RewardMultiplierBounds,
+       DeputyPermissions = DeputyPermissionsOf<T>,
+       RewardVestingSplit = RewardVestingSplitOf<T>,
+       BlockNumber = <T as frame_system::Config>::BlockNumber
     {
         /// Emits on adding new job opening.
         /// Params:
@@ -287,9 +333,25 @@ decl_event!(
         /// Params:
         /// - Id of the worker.
         /// - Receiver Account Id.
-        /// - Reward
+        /// - Liquid part of the reward.
+        /// - Vested part of the reward withheld until it's locked with a vesting schedule.
         /// - Payment type (missed reward or regular one)
-        RewardPaid(WorkerId, AccountId, Balance, RewardPaymentType),
+        RewardPaid(WorkerId, AccountId, Balance, Balance, RewardPaymentType),
+
+        /// Emits on locking the vested part of the paid reward.
+        /// Params:
+        /// - Id of the worker.
+        /// - Receiver Account Id.
+        /// - Vesting schedule of the vested part.
+        RewardVested(WorkerId, AccountId, VestingInfo),
+
+        /// Emits on paying the withheld reward of the removed worker as liquid
+        /// when it can't be locked with a vesting schedule.
+        /// Params:
+        /// - Id of the worker.
+        /// - Receiver Account Id.
+        /// - Paid amount.
+        UnvestedRewardPaid(WorkerId, AccountId, Balance),
 
         /// Emits on reaching new missed reward.
         /// Params:
@@ -314,6 +376,76 @@ decl_event!(
         /// - worker
         /// - message
         WorkerRemarked(WorkerId, Vec<u8>),
+
+        /// Emits on updating the vested part of the worker reward.
+        /// Params:
+        /// - Id of the worker.
+        /// - Reward vesting split (optional). None means the reward is fully liquid.
+        WorkerRewardVestingUpdated(WorkerId, Option<RewardVestingSplit>),
+
+        /// Emits on updating the reward multiplier bounds.
+        /// Params:
+        /// - New bounds (optional). None means reviews can't adjust the rewards.
+        RewardMultiplierBoundsUpdated(Option<RewardMultiplierBounds>),
+
+        /// Emits on recording the worker review.
+        /// Params:
+        /// - Worker ID.
+        /// - Review ID.
+        /// - Score.
+        /// - Rationale hash.
+        /// - Reward multiplier (optional). None means the reward wasn't adjusted.
+        WorkerReviewed(WorkerId, ReviewId, u8, Hash, Option<u16>),
+
+        /// Emits on overturning the worker review.
+        /// Params:
+        /// - Worker ID.
+        /// - Review ID.
+        WorkerReviewOverturned(WorkerId, ReviewId),
+
+        /// Emits on appointing the deputy or updating the deputy permissions.
+        /// Params:
+        /// - Worker ID.
+        /// - Deputy permissions.
+        DeputyAppointed(WorkerId, DeputyPermissions),
+
+        /// Emits on revoking the deputy.
+        /// Params:
+        /// - Worker ID.
+        DeputyRevoked(WorkerId),
+
+        /// Emits on starting the application evaluation.
+        /// Params:
+        /// - Opening ID.
+        /// - Block at which the evaluation window ends.
+        ApplicationEvaluationStarted(OpeningId, BlockNumber),
+
+        /// Emits on voting on the application.
+        /// Params:
+        /// - Worker ID.
+        /// - Application ID.
+        /// - Score.
+        /// - Vote weight (worker stake).
+        ApplicationVoted(WorkerId, ApplicationId, u8, Balance),
+
+        /// Emits on recording the rationale for filling the opening against the tally.
+        /// Params:
+        /// - Opening ID.
+        /// - Rationale hash.
+        EvaluationDeviationJustified(OpeningId, Hash),
+
+        /// Emits on filling the evaluated opening.
+        /// Params:
+        /// - Opening ID.
+        /// - Applications ranked by the weighted score tally.
+        ApplicationEvaluationTallied(OpeningId, Vec<(ApplicationId, Balance)>),
+
+        /// Emits on filling the evaluated opening.
+        /// Params:
+        /// - Opening ID.
+        /// - Application ids used to fill the opening.
+        /// - Rationale hash (optional). Some means the decision deviates from the tally.
+        ApplicationEvaluationDecided(OpeningId, BTreeSet<ApplicationId>, Option<Hash>),
     }
 );
 
@@ -351,6 +483,59 @@ decl_storage! { generate_storage_info
 
         /// Status text hash.
         pub StatusTextHash get(fn status_text_hash) : T::Hash;
+
+        /// Cumulative number of blocks for which the worker rewards were funded by the budget,
+        /// scaled by `REWARD_INDEX_PRECISION`. Partially funded blocks count fractionally.
+        pub RewardIndex get(fn reward_index) : u128;
+
+        /// Block of the last reward index update.
+        pub RewardIndexUpdatedAt get(fn reward_index_updated_at) : T::BlockNumber;
+
+        /// Sum of the reward per block of all the workers.
+        pub TotalRewardPerBlock get(fn total_reward_per_block) : BalanceOf<T>;
+
+        /// Workers leaving the group at the block once their unstaking period is finished.
+        pub LeavingWorkers get(fn leaving_workers) : double_map
+            hasher(blake2_128_concat) T::BlockNumber,
+            hasher(blake2_128_concat) WorkerId<T> => ();
+
+        /// Number of the reviews recorded for the worker.
+        pub WorkerReviewCount get(fn worker_review_count) : map hasher(blake2_128_concat)
+            WorkerId<T> => ReviewId;
+
+        /// Review history of the workers, limited to the latest `MAX_WORKER_REVIEWS` reviews.
+        pub WorkerReviews get(fn worker_review) : double_map
+            hasher(blake2_128_concat) WorkerId<T>,
+            hasher(blake2_128_concat) ReviewId => Option<WorkerReviewOf<T>>;
+
+        /// Bounds of the reward multiplier derived from the review score.
+        pub ReviewRewardMultiplierBounds get(fn review_reward_multiplier_bounds) :
+            Option<RewardMultiplierBounds>;
+
+        /// Reward multiplier (in percent) of the worker set by the latest review.
+        pub WorkerRewardMultiplier get(fn worker_reward_multiplier) : map hasher(blake2_128_concat)
+            WorkerId<T> => Option<u16>;
+
+        /// Deputies appointed by the leader.
+        pub Deputies get(fn deputy_by_worker_id) : map hasher(blake2_128_concat)
+            WorkerId<T> => Option<DeputyOf<T>>;
+
+        /// Count of the appointed deputies.
+        pub DeputyCount get(fn deputy_count) : u32;
+
+        /// Evaluation windows of the regular worker openings.
+        pub ApplicationEvaluations get(fn application_evaluation) : map hasher(blake2_128_concat)
+            OpeningId => Option<ApplicationEvaluationOf<T>>;
+
+        /// Weighted score tallies of the applications under evaluation.
+        pub ApplicationTallies get(fn application_tally) : double_map
+            hasher(blake2_128_concat) OpeningId,
+            hasher(blake2_128_concat) ApplicationId => BalanceOf<T>;
+
+        /// Votes of the workers on the applications under evaluation.
+        pub ApplicationVotes get(fn application_vote) : double_map
+            hasher(blake2_128_concat) OpeningId,
+            hasher(blake2_128_concat) (ApplicationId, WorkerId<T>) => Option<ApplicationVoteOf<T>>;
     }
 }
 
@@ -363,6 +548,8 @@ decl_module! {
         /// Predefined errors
         type Error = Error<T, I>;
 
+        type StorageVersion = CURRENT_STORAGE_VERSION;
+
         /// Exports const
 
         /// Max simultaneous active worker number.
@@ -377,23 +564,20 @@ decl_module! {
         /// Stake needed to create an opening.
         const LeaderOpeningStake: T::Balance = T::LeaderOpeningStake::get();
 
-        /// Defines the period every worker gets paid in blocks.
-        const RewardPeriod: u32 = T::RewardPeriod::get();
-
         /// Staking handler lock id.
         const StakingHandlerLockId: LockIdentifier = T::StakingHandler::lock_id();
 
         /// # <weight>
         ///
         /// ## Weight
-        /// `O (W)` where:
-        /// - `W` is the number of workers currently present in the `working_group`
+        /// `O (L)` where:
+        /// - `L` is the number of workers finishing their unstaking period at the current block
         /// - DB:
-        ///    - O(W)
+        ///    - O(L)
         /// # </weight>
         fn on_initialize() -> Weight {
             let leaving_workers = Self::get_workers_with_finished_unstaking_period();
-            let mut biggest_number_of_processed_workers = leaving_workers.len();
+            let number_of_leaving_workers = leaving_workers.len();
 
             leaving_workers.iter().for_each(|wi| {
                 Self::remove_worker(
@@ -403,19 +587,9 @@ decl_module! {
                 );
             });
 
-            if Self::is_reward_block() {
-                // We count the number of workers that will be processed to not be so pessimistic
-                // when calculating the weight for this function
-                let mut count_number_of_workers = 0;
-                WorkerById::<T, I>::iter().for_each(|(worker_id, worker)| {
-                    Self::reward_worker(&worker_id, &worker);
-                    count_number_of_workers = count_number_of_workers.saturating_add(1);
-                });
-
-                biggest_number_of_processed_workers = biggest_number_of_processed_workers.max(count_number_of_workers);
-            }
-
-            Self::calculate_weight_on_initialize(biggest_number_of_processed_workers.saturated_into())
+            WeightInfoWorkingGroup::<T, I>::on_initialize_leaving(
+                number_of_leaving_workers.saturated_into()
+            )
         }
 
         /// Add a job opening for a regular worker/lead role.
@@ -591,6 +765,12 @@ decl_module! {
                 successful_application_ids.len().saturated_into()
             )
             .max(WeightInfoWorkingGroup::<T, I>::fill_opening_lead())
+            .saturating_add(
+                WeightInfoWorkingGroup::<T, I>::conclude_application_evaluation(
+                    MAX_EVALUATED_APPLICATIONS,
+                    MAX_APPLICATION_VOTES,
+                )
+            )
         ]
         pub fn fill_opening(
             origin,
@@ -600,7 +780,15 @@ decl_module! {
             // Ensure job opening exists.
             let opening = checks::ensure_opening_exists::<T, I>(opening_id)?;
 
-            checks::ensure_origin_for_opening_type::<T, I>(origin, opening.opening_type)?;
+            // Regular openings can be filled by the deputies as well.
+            if opening.opening_type == OpeningType::Regular {
+                checks::ensure_origin_is_leader_or_deputy::<T, I>(
+                    origin,
+                    DeputyAction::FillOpening,
+                )?;
+            } else {
+                checks::ensure_origin_for_opening_type::<T, I>(origin, opening.opening_type)?;
+            }
 
             // Ensure we're not exceeding the maximum worker number.
             let potential_worker_number = Self::active_worker_count()
@@ -639,19 +827,46 @@ decl_module! {
                 );
             }
 
+            // The evaluated opening can be filled against the tally only with a rationale.
+            let evaluation_outcome = match Self::application_evaluation(opening_id) {
+                Some(evaluation) => {
+                    ensure!(
+                        Self::current_block() >= evaluation.ends_at,
+                        Error::<T, I>::ApplicationEvaluationInProgress
+                    );
+
+                    let ranked_tally = Self::ranked_application_tally(opening_id);
+                    let rationale_hash =
+                        if Self::deviates_from_tally(&ranked_tally, &successful_application_ids) {
+                            Some(
+                                evaluation
+                                    .deviation_rationale_hash
+                                    .ok_or(Error::<T, I>::EvaluationDeviationNotJustified)?,
+                            )
+                        } else {
+                            None
+                        };
+
+                    Some((ranked_tally, rationale_hash))
+                }
+                None => None,
+            };
+
             //
             // == MUTATION SAFE ==
             //
 
             if opening.opening_type == OpeningType::Regular {
-                // Lead must be set for ensure_origin_for_openig_type in the
-                // case of regular.
-                let lead = crate::Module::<T, I>::worker_by_id(checks::ensure_lead_is_set::<T, I>()?).ok_or(Error::<T, I>::WorkerDoesNotExist)?;
-                let current_stake = T::StakingHandler::current_stake(&lead.staking_account_id);
-                T::StakingHandler::set_stake(
-                    &lead.staking_account_id,
-                    current_stake.saturating_sub(opening.creation_stake)
-                )?;
+                // The deputy can fill the opening in the absence of the lead, the stake of
+                // the former lead was unlocked on leaving.
+                if let Some(lead) = Self::current_lead().and_then(Self::worker_by_id) {
+                    let current_stake =
+                        T::StakingHandler::current_stake(&lead.staking_account_id);
+                    T::StakingHandler::set_stake(
+                        &lead.staking_account_id,
+                        current_stake.saturating_sub(opening.creation_stake)
+                    )?;
+                }
             }
 
             // Process successful applications
@@ -663,6 +878,20 @@ decl_module! {
             // Remove the opening.
             <OpeningById::<T, I>>::remove(opening_id);
 
+            if let Some((ranked_tally, rationale_hash)) = evaluation_outcome {
+                Self::remove_application_evaluation(opening_id);
+
+                Self::deposit_event(RawEvent::ApplicationEvaluationTallied(
+                    opening_id,
+                    ranked_tally
+                ));
+                Self::deposit_event(RawEvent::ApplicationEvaluationDecided(
+                    opening_id,
+                    successful_application_ids.clone(),
+                    rationale_hash
+                ));
+            }
+
             // Trigger event
             Self::deposit_event(RawEvent::OpeningFilled(
                     opening_id,
@@ -734,6 +963,11 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            // Workers leaving right away exit at the next block.
+            let leaving_at = Self::current_block()
+                .saturating_add(worker.job_unstaking_period.max(One::one()));
+            <LeavingWorkers<T, I>>::insert(leaving_at, worker_id, ());
+
             WorkerById::<T, I>::insert(worker_id, Worker::<T> {
                 started_leaving_at: Some(Self::current_block()),
                 ..worker
@@ -802,8 +1036,18 @@ decl_module! {
             penalty: BalanceOf<T>,
             rationale: Option<Vec<u8>>
         ) {
-            // Ensure lead is set or it is the council slashing the leader.
-            checks::ensure_origin_for_worker_operation::<T,I>(origin, worker_id)?;
+            // Ensure it is the lead or the deputy, or the council slashing the leader.
+            let deputy_id = checks::ensure_origin_for_deputy_worker_operation::<T,I>(
+                origin,
+                worker_id,
+                DeputyAction::Slash(penalty),
+            )?;
+
+            // The deputies can't slash the deputies, themselves included.
+            ensure!(
+                deputy_id.is_none() || !<Deputies<T, I>>::contains_key(worker_id),
+                Error::<T, I>::DeputyCannotSlashDeputy
+            );
 
             // Ensuring worker actually exists.
             let worker = checks::ensure_worker_exists::<T,I>(&worker_id)?;
@@ -817,6 +1061,10 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            if let Some(deputy_id) = deputy_id {
+                Self::record_deputy_slashing(&deputy_id, penalty);
+            }
+
             Self::slash(worker_id, &worker.staking_account_id, penalty, rationale)
         }
 
@@ -965,7 +1213,14 @@ decl_module! {
         /// - DB:
         ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoWorkingGroup::<T, I>::cancel_opening()]
+        #[weight = WeightInfoWorkingGroup::<T, I>::cancel_opening()
+            .saturating_add(
+                WeightInfoWorkingGroup::<T, I>::conclude_application_evaluation(
+                    MAX_EVALUATED_APPLICATIONS,
+                    MAX_APPLICATION_VOTES,
+                )
+            )
+        ]
         pub fn cancel_opening(
             origin,
             opening_id: OpeningId,
@@ -994,6 +1249,8 @@ decl_module! {
             // Remove the opening.
             <OpeningById::<T, I>>::remove(opening_id);
 
+            Self::remove_application_evaluation(opening_id);
+
             // Trigger event
             Self::deposit_event(RawEvent::OpeningCanceled(opening_id));
         }
@@ -1087,89 +1344,100 @@ decl_module! {
             // == MUTATION SAFE ==
             //
 
+            // Accrue the reward at the previous rate.
+            Self::update_reward_index();
+            let worker = Self::accrue_worker_reward(&worker_id, worker);
+            let old_reward_per_block = Self::worker_reward_per_block(&worker_id, &worker);
+
             // Update worker reward amount.
-            WorkerById::<T, I>::insert(worker_id, Worker::<T> {
+            let worker = Worker::<T> {
                 reward_per_block,
                 ..worker
-            });
+            };
+            Self::update_total_reward_per_block(
+                old_reward_per_block,
+                Self::worker_reward_per_block(&worker_id, &worker),
+            );
+            WorkerById::<T, I>::insert(worker_id, worker);
 
             // Trigger event
             Self::deposit_event(RawEvent::WorkerRewardAmountUpdated(worker_id, reward_per_block));
         }
 
-        /// Sets a new status text for the working group.
-        /// Requires root origin.
+        /// Pay the reward accrued by the active worker to its reward account, along with as much
+        /// of its missed reward as the group budget allows. Can be called by any account.
         ///
         /// # <weight>
         ///
         /// ## Weight
-        /// `O (S)` where:
-        /// - `S` is the size of the contents of `status_text` in kilobytes when it is not none
-        ///
+        /// `O (1)`
         /// - DB:
         ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoWorkingGroup::<T, I>::set_status_text(
-            to_kb(
-                status_text
-                    .as_ref()
-                    .map(|status_text| status_text.len().saturated_into())
-                    .unwrap_or_default()
-            )
-        )]
-        pub fn set_status_text(
+        #[weight = WeightInfoWorkingGroup::<T, I>::claim_worker_reward()]
+        pub fn claim_worker_reward(
             origin,
-            status_text: Option<Vec<u8>>,
+            worker_id: WorkerId<T>,
         ) {
-            // Ensure group leader privilege.
-            checks::ensure_origin_is_active_leader::<T,I>(origin)?;
+            ensure_signed(origin)?;
+
+            // Ensuring worker actually exists
+            let worker = checks::ensure_worker_exists::<T,I>(&worker_id)?;
 
             //
             // == MUTATION SAFE ==
             //
 
-            let status_text_hash = status_text
-                .as_ref()
-                .map(|status_text| { T::Hashing::hash(status_text) })
-                .unwrap_or_default();
-
-            // Update the status text hash.
-            <StatusTextHash<T, I>>::put(status_text_hash);
+            Self::update_reward_index();
+            let worker = Self::accrue_worker_reward(&worker_id, worker);
+            let worker = Self::pay_worker_reward(&worker_id, worker);
 
-            // Trigger event
-            Self::deposit_event(RawEvent::StatusTextChanged(status_text_hash, status_text));
+            WorkerById::<T, I>::insert(worker_id, worker);
         }
-        #[weight = WeightInfoWorkingGroup::<T, I>::spend_from_budget()]
-        pub fn spend_from_budget(
+
+        /// Sets the part of the worker reward paid through the vesting schedules.
+        /// None makes the reward fully liquid. Require signed leader origin or the root
+        /// (to update leader's reward vesting).
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::set_worker_reward_vesting()]
+        pub fn set_worker_reward_vesting(
             origin,
-            account_id: T::AccountId,
-            amount: BalanceOf<T>,
-            rationale: Option<Vec<u8>>,
+            worker_id: WorkerId<T>,
+            reward_vesting: Option<RewardVestingSplitOf<T>>,
         ) {
-            // Ensure group leader privilege.
-            checks::ensure_origin_is_active_leader::<T,I>(origin)?;
+            // Ensure lead is set or it is the council setting the leader's reward vesting.
+            checks::ensure_origin_for_worker_operation::<T,I>(origin, worker_id)?;
 
-            ensure!(amount > Zero::zero(), Error::<T, I>::CannotSpendZero);
+            // Ensuring worker actually exists
+            let worker = checks::ensure_worker_exists::<T,I>(&worker_id)?;
 
-            // Ensures that the budget is sufficient for the spending of specified amount
-            let (_, potential_missed_payment) = Self::calculate_possible_payment(amount);
-            ensure!(
-                potential_missed_payment == Zero::zero(),
-                Error::<T, I>::InsufficientBudgetForSpending
-            );
+            if let Some(ref reward_vesting) = reward_vesting {
+                checks::ensure_valid_reward_vesting_split::<T,I>(reward_vesting)?;
+            }
 
             //
             // == MUTATION SAFE ==
             //
 
-            Self::pay_from_budget(&account_id, amount);
+            let worker = Worker::<T> {
+                reward_vesting,
+                ..worker
+            };
+            WorkerById::<T, I>::insert(worker_id, worker);
 
             // Trigger event
-            Self::deposit_event(RawEvent::BudgetSpending(account_id, amount, rationale));
+            Self::deposit_event(RawEvent::WorkerRewardVestingUpdated(worker_id, reward_vesting));
         }
 
-        /// Transfers specified amount to any account.
-        /// Requires leader origin.
+        /// Sets the bounds of the reward multiplier derived from the review score.
+        /// None disables the reward adjustment by the reviews. Require signed leader origin.
         ///
         /// # <weight>
         ///
@@ -1178,97 +1446,634 @@ decl_module! {
         /// - DB:
         ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoWorkingGroup::<T, I>::vested_spend_from_budget()]
-        pub fn vested_spend_from_budget(
+        #[weight = WeightInfoWorkingGroup::<T, I>::set_reward_multiplier_bounds()]
+        pub fn set_reward_multiplier_bounds(
             origin,
-            account_id: T::AccountId,
-            vesting_schedule: VestingInfoOf<T>,
-            rationale: Option<Vec<u8>>,
+            bounds: Option<RewardMultiplierBounds>,
         ) {
-            let amount = T::VestingBalanceToBalance::convert(vesting_schedule.locked());
-
             // Ensure group leader privilege.
             checks::ensure_origin_is_active_leader::<T,I>(origin)?;
 
-            ensure!(amount > Zero::zero(), Error::<T, I>::CannotSpendZero);
-
-            // Ensures that the budget is sufficient for the spending of specified amount
-            let (_, potential_missed_payment) = Self::calculate_possible_payment(amount);
-            ensure!(
-                potential_missed_payment == Zero::zero(),
-                Error::<T, I>::InsufficientBudgetForSpending
-            );
+            if let Some(ref bounds) = bounds {
+                checks::ensure_valid_reward_multiplier_bounds::<T,I>(bounds)?;
+            }
 
             //
             // == MUTATION SAFE ==
             //
 
-            Self::pay_from_budget(&account_id, amount);
-            vesting::Pallet::<T>::force_vested_transfer(
-                RawOrigin::Root.into(),
-                <<T as frame_system::Config>::Lookup as StaticLookup>::unlookup(account_id.clone()),
-                <<T as frame_system::Config>::Lookup as StaticLookup>::unlookup(account_id.clone()),
-                vesting_schedule,
-            )?;
-            // Trigger event
-            Self::deposit_event(RawEvent::VestedBudgetSpending(account_id, vesting_schedule, rationale));
-        }
+            <ReviewRewardMultiplierBounds<I>>::set(bounds);
 
+            Self::deposit_event(RawEvent::RewardMultiplierBoundsUpdated(bounds));
+        }
 
-        /// Fund working group budget by a member.
-        /// <weight>
+        /// Record a performance review of the active regular worker.
+        /// Only the latest `MAX_WORKER_REVIEWS` reviews of the worker are kept.
+        /// Optionally adjusts the worker reward by the multiplier derived from the score
+        /// within the reward multiplier bounds. Require signed leader origin.
+        ///
+        /// # <weight>
         ///
         /// ## Weight
-        /// `O (1)` Doesn't depend on the state or parameters
+        /// `O (1)`
         /// - DB:
         ///    - O(1) doesn't depend on the state or parameters
         /// # </weight>
-        #[weight = WeightInfoWorkingGroup::<T, I>::fund_working_group_budget()]
-        pub fn fund_working_group_budget(
+        #[weight = WeightInfoWorkingGroup::<T, I>::review_worker()]
+        pub fn review_worker(
             origin,
-            member_id: MemberId<T>,
-            amount: BalanceOf<T>,
-            rationale: Vec<u8>,
+            worker_id: WorkerId<T>,
+            score: u8,
+            rationale_hash: T::Hash,
+            adjust_reward: bool,
         ) {
-            let account_id =
-                T::MemberOriginValidator::ensure_member_controller_account_origin(origin, member_id)?;
+            // Ensure group leader privilege.
+            checks::ensure_origin_is_active_leader::<T,I>(origin)?;
 
-            ensure!(amount > Zero::zero(), Error::<T, I>::ZeroTokensFunding);
+            // Ensuring worker actually exists
+            let worker = checks::ensure_worker_exists::<T,I>(&worker_id)?;
+
+            // The leader is reviewed by the council.
             ensure!(
-                Balances::<T>::usable_balance(&account_id) >= amount,
-                Error::<T, I>::InsufficientTokensForFunding
+                Self::current_lead() != Some(worker_id),
+                Error::<T, I>::CannotReviewLeader
             );
 
+            // Ensure the worker is active.
+            ensure!(!worker.is_leaving(), Error::<T, I>::WorkerIsLeaving);
+
+            ensure!(score <= MAX_REVIEW_SCORE, Error::<T, I>::InvalidReviewScore);
+
+            let reward_multiplier = if adjust_reward {
+                checks::ensure_worker_has_recurring_reward::<T, I>(&worker)?;
+
+                let bounds = Self::review_reward_multiplier_bounds()
+                    .ok_or(Error::<T, I>::RewardMultiplierBoundsNotSet)?;
+
+                Some(Self::calculate_reward_multiplier(&bounds, score))
+            } else {
+                None
+            };
+
             //
             // == MUTATION SAFE ==
             //
 
-            // An account is allowed to die when funding working group budget
-            burn_from_usable::<T>(&account_id, amount)?;
+            let review_id = Self::worker_review_count(worker_id);
+            let review = WorkerReview {
+                score,
+                rationale_hash,
+                reviewed_at: Self::current_block(),
+                reward_multiplier,
+                overturned: false,
+            };
+            if review_id >= MAX_WORKER_REVIEWS {
+                <WorkerReviews<T, I>>::remove(
+                    worker_id,
+                    review_id.saturating_sub(MAX_WORKER_REVIEWS),
+                );
+            }
+            <WorkerReviews<T, I>>::insert(worker_id, review_id, review);
+            <WorkerReviewCount<T, I>>::insert(worker_id, review_id.saturating_add(1));
 
-            Self::increase_working_group_budget(amount);
+            if reward_multiplier.is_some() {
+                Self::set_worker_reward_multiplier(&worker_id, worker, reward_multiplier);
+            }
 
-            Self::deposit_event(
-                RawEvent::WorkingGroupBudgetFunded(
-                    member_id,
-                    amount,
-                    rationale
-                )
-            );
+            Self::deposit_event(RawEvent::WorkerReviewed(
+                worker_id,
+                review_id,
+                score,
+                rationale_hash,
+                reward_multiplier,
+            ));
         }
 
-        /// Lead remark message
+        /// Overturn the worker review disputed by the worker. Overturning the review which set
+        /// the current reward multiplier restores the multiplier of the previous review adjusting
+        /// the reward, or resets it if there is none. Require root origin.
         ///
         /// # <weight>
         ///
         /// ## Weight
-        /// `O (M)` where:
-        /// - `M` is the size of `msg` in kilobytes
+        /// `O (1)`
         /// - DB:
-        ///    - O(1) doesn't depend on the state or parameters
+        ///    - O(MAX_WORKER_REVIEWS) - the kept reviews of the worker are read
         /// # </weight>
-        #[weight = WeightInfoWorkingGroup::<T,I>::lead_remark(to_kb(msg.len() as u32))]
-        pub fn lead_remark(origin, msg: Vec<u8>) {
+        #[weight = WeightInfoWorkingGroup::<T, I>::overturn_worker_review()]
+        pub fn overturn_worker_review(
+            origin,
+            worker_id: WorkerId<T>,
+            review_id: ReviewId,
+        ) {
+            ensure_root(origin)?;
+
+            let review = Self::worker_review(worker_id, review_id)
+                .ok_or(Error::<T, I>::WorkerReviewDoesNotExist)?;
+
+            ensure!(!review.overturned, Error::<T, I>::WorkerReviewAlreadyOverturned);
+
+            let sets_reward_multiplier = Self::latest_reward_adjusting_review(&worker_id)
+                .map_or(false, |(latest_review_id, _)| latest_review_id == review_id);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <WorkerReviews<T, I>>::insert(worker_id, review_id, WorkerReview {
+                overturned: true,
+                ..review
+            });
+
+            if sets_reward_multiplier {
+                let reward_multiplier = Self::latest_reward_adjusting_review(&worker_id)
+                    .and_then(|(_, review)| review.reward_multiplier);
+
+                if let Some(worker) = Self::worker_by_id(worker_id) {
+                    Self::set_worker_reward_multiplier(&worker_id, worker, reward_multiplier);
+                }
+            }
+
+            Self::deposit_event(RawEvent::WorkerReviewOverturned(worker_id, review_id));
+        }
+
+        /// Appoint the active regular worker as a deputy with a subset of the leader
+        /// permissions, or update the permissions of the existing deputy.
+        /// Require signed leader origin.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::appoint_deputy()]
+        pub fn appoint_deputy(
+            origin,
+            worker_id: WorkerId<T>,
+            permissions: DeputyPermissionsOf<T>,
+        ) {
+            // Ensure group leader privilege.
+            checks::ensure_origin_is_active_leader::<T,I>(origin)?;
+
+            // Ensuring worker actually exists
+            let worker = checks::ensure_worker_exists::<T,I>(&worker_id)?;
+
+            ensure!(
+                Self::current_lead() != Some(worker_id),
+                Error::<T, I>::CannotAppointLeaderAsDeputy
+            );
+
+            // Ensure the worker is active.
+            ensure!(!worker.is_leaving(), Error::<T, I>::WorkerIsLeaving);
+
+            checks::ensure_valid_deputy_permissions::<T, I>(&permissions)?;
+
+            let existing_deputy = Self::deputy_by_worker_id(worker_id);
+
+            if existing_deputy.is_none() {
+                ensure!(
+                    Self::deputy_count() < MAX_DEPUTIES,
+                    Error::<T, I>::MaxDeputyNumberExceeded
+                );
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Updating the permissions keeps the spending and the slashing of the current period.
+            let deputy = match existing_deputy {
+                Some(deputy) => Deputy {
+                    permissions: permissions.clone(),
+                    ..deputy
+                },
+                None => {
+                    <DeputyCount<I>>::put(Self::deputy_count().saturating_add(1));
+
+                    Deputy {
+                        permissions: permissions.clone(),
+                        spent_in_period: Zero::zero(),
+                        spending_period_start: Self::current_block(),
+                        slashed_in_period: Zero::zero(),
+                        slashing_period_start: Self::current_block(),
+                    }
+                }
+            };
+
+            <Deputies<T, I>>::insert(worker_id, deputy);
+
+            Self::deposit_event(RawEvent::DeputyAppointed(worker_id, permissions));
+        }
+
+        /// Revoke the deputy permissions of the worker.
+        /// Require signed leader origin or the root (council proposal).
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::revoke_deputy()]
+        pub fn revoke_deputy(origin, worker_id: WorkerId<T>) {
+            // Ensure group leader privilege or the council.
+            if ensure_root(origin.clone()).is_err() {
+                checks::ensure_origin_is_active_leader::<T,I>(origin)?;
+            }
+
+            ensure!(
+                <Deputies<T, I>>::contains_key(worker_id),
+                Error::<T, I>::DeputyDoesNotExist
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::remove_deputy(&worker_id);
+
+            Self::deposit_event(RawEvent::DeputyRevoked(worker_id));
+        }
+
+        /// Start the evaluation window of the regular worker opening. The active workers vote
+        /// on the applications during the window, the opening can be filled after it ends.
+        /// Require signed leader origin.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::start_application_evaluation()]
+        pub fn start_application_evaluation(
+            origin,
+            opening_id: OpeningId,
+            evaluation_period: T::BlockNumber,
+        ) {
+            // Ensure group leader privilege.
+            checks::ensure_origin_is_active_leader::<T,I>(origin)?;
+
+            // Ensure job opening exists.
+            let opening = checks::ensure_opening_exists::<T, I>(opening_id)?;
+
+            // The leader openings are decided by the council.
+            ensure!(
+                opening.opening_type == OpeningType::Regular,
+                Error::<T, I>::CannotEvaluateLeaderOpening
+            );
+
+            ensure!(
+                !<ApplicationEvaluations<T, I>>::contains_key(opening_id),
+                Error::<T, I>::ApplicationEvaluationAlreadyStarted
+            );
+
+            ensure!(!evaluation_period.is_zero(), Error::<T, I>::InvalidEvaluationPeriod);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let ends_at = Self::current_block().saturating_add(evaluation_period);
+            <ApplicationEvaluations<T, I>>::insert(opening_id, ApplicationEvaluation {
+                ends_at,
+                evaluated_applications: 0,
+                votes: 0,
+                deviation_rationale_hash: None,
+            });
+
+            Self::deposit_event(RawEvent::ApplicationEvaluationStarted(opening_id, ends_at));
+        }
+
+        /// Vote on the application during the evaluation window. The vote is weighted by the
+        /// worker stake, voting again replaces the previous vote.
+        /// Require signed active worker origin.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::vote_on_application()]
+        pub fn vote_on_application(
+            origin,
+            worker_id: WorkerId<T>,
+            application_id: ApplicationId,
+            score: u8,
+        ) {
+            let worker = checks::ensure_worker_signed::<T, I>(origin, &worker_id)?;
+
+            // Ensure the worker is active.
+            ensure!(!worker.is_leaving(), Error::<T, I>::WorkerIsLeaving);
+
+            // Ensuring worker application actually exists
+            let application_info = checks::ensure_application_exists::<T, I>(&application_id)?;
+            let opening_id = application_info.application.opening_id;
+
+            let evaluation = Self::application_evaluation(opening_id)
+                .ok_or(Error::<T, I>::ApplicationEvaluationDoesNotExist)?;
+
+            ensure!(
+                Self::current_block() < evaluation.ends_at,
+                Error::<T, I>::ApplicationEvaluationEnded
+            );
+
+            ensure!(
+                worker.member_id != application_info.application.member_id,
+                Error::<T, I>::CannotVoteOnOwnApplication
+            );
+
+            ensure!(score <= MAX_APPLICATION_SCORE, Error::<T, I>::InvalidApplicationScore);
+
+            let weight = T::StakingHandler::current_stake(&worker.staking_account_id);
+            ensure!(!weight.is_zero(), Error::<T, I>::CannotVoteWithoutStake);
+
+            let is_first_vote =
+                !<ApplicationTallies<T, I>>::contains_key(opening_id, application_id);
+            if is_first_vote {
+                ensure!(
+                    evaluation.evaluated_applications < MAX_EVALUATED_APPLICATIONS,
+                    Error::<T, I>::MaxEvaluatedApplicationsExceeded
+                );
+            }
+
+            let previous_vote = Self::application_vote(opening_id, (application_id, worker_id));
+            if previous_vote.is_none() {
+                ensure!(
+                    evaluation.votes < MAX_APPLICATION_VOTES,
+                    Error::<T, I>::MaxApplicationVotesExceeded
+                );
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let previous_weighted_score = previous_vote
+                .map_or(Zero::zero(), |vote| vote.weight.saturating_mul(vote.score.into()));
+
+            <ApplicationTallies<T, I>>::mutate(opening_id, application_id, |tally| {
+                *tally = tally
+                    .saturating_sub(previous_weighted_score)
+                    .saturating_add(weight.saturating_mul(score.into()));
+            });
+
+            <ApplicationVotes<T, I>>::insert(
+                opening_id,
+                (application_id, worker_id),
+                ApplicationVote { score, weight }
+            );
+
+            if previous_vote.is_none() {
+                let evaluated_applications = if is_first_vote {
+                    evaluation.evaluated_applications.saturating_add(1)
+                } else {
+                    evaluation.evaluated_applications
+                };
+
+                <ApplicationEvaluations<T, I>>::insert(opening_id, ApplicationEvaluation {
+                    evaluated_applications,
+                    votes: evaluation.votes.saturating_add(1),
+                    ..evaluation
+                });
+            }
+
+            Self::deposit_event(RawEvent::ApplicationVoted(
+                worker_id,
+                application_id,
+                score,
+                weight
+            ));
+        }
+
+        /// Record the rationale for filling the evaluated opening against the tally.
+        /// Require signed leader origin or the deputy permitted to fill the openings.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::justify_evaluation_deviation()]
+        pub fn justify_evaluation_deviation(
+            origin,
+            opening_id: OpeningId,
+            rationale_hash: T::Hash,
+        ) {
+            checks::ensure_origin_is_leader_or_deputy::<T, I>(
+                origin,
+                DeputyAction::FillOpening,
+            )?;
+
+            let evaluation = Self::application_evaluation(opening_id)
+                .ok_or(Error::<T, I>::ApplicationEvaluationDoesNotExist)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <ApplicationEvaluations<T, I>>::insert(opening_id, ApplicationEvaluation {
+                deviation_rationale_hash: Some(rationale_hash),
+                ..evaluation
+            });
+
+            Self::deposit_event(RawEvent::EvaluationDeviationJustified(opening_id, rationale_hash));
+        }
+
+        /// Sets a new status text for the working group.
+        /// Requires root origin.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (S)` where:
+        /// - `S` is the size of the contents of `status_text` in kilobytes when it is not none
+        ///
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::set_status_text(
+            to_kb(
+                status_text
+                    .as_ref()
+                    .map(|status_text| status_text.len().saturated_into())
+                    .unwrap_or_default()
+            )
+        )]
+        pub fn set_status_text(
+            origin,
+            status_text: Option<Vec<u8>>,
+        ) {
+            // Ensure group leader privilege.
+            checks::ensure_origin_is_active_leader::<T,I>(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let status_text_hash = status_text
+                .as_ref()
+                .map(|status_text| { T::Hashing::hash(status_text) })
+                .unwrap_or_default();
+
+            // Update the status text hash.
+            <StatusTextHash<T, I>>::put(status_text_hash);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::StatusTextChanged(status_text_hash, status_text));
+        }
+        #[weight = WeightInfoWorkingGroup::<T, I>::spend_from_budget()]
+        pub fn spend_from_budget(
+            origin,
+            account_id: T::AccountId,
+            amount: BalanceOf<T>,
+            rationale: Option<Vec<u8>>,
+        ) {
+            // Ensure group leader or deputy privilege.
+            let deputy_id = checks::ensure_origin_is_leader_or_deputy::<T,I>(
+                origin,
+                DeputyAction::Spend(amount),
+            )?;
+
+            ensure!(amount > Zero::zero(), Error::<T, I>::CannotSpendZero);
+
+            // Ensures that the budget is sufficient for the spending of specified amount
+            let (_, potential_missed_payment) = Self::calculate_possible_payment(amount);
+            ensure!(
+                potential_missed_payment == Zero::zero(),
+                Error::<T, I>::InsufficientBudgetForSpending
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some(deputy_id) = deputy_id {
+                Self::record_deputy_spending(&deputy_id, amount);
+            }
+
+            Self::pay_from_budget(&account_id, amount);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::BudgetSpending(account_id, amount, rationale));
+        }
+
+        /// Transfers specified amount to any account.
+        /// Requires leader origin.
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (1)`
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::vested_spend_from_budget()]
+        pub fn vested_spend_from_budget(
+            origin,
+            account_id: T::AccountId,
+            vesting_schedule: VestingInfoOf<T>,
+            rationale: Option<Vec<u8>>,
+        ) {
+            let amount = T::VestingBalanceToBalance::convert(vesting_schedule.locked());
+
+            // Ensure group leader or deputy privilege.
+            let deputy_id = checks::ensure_origin_is_leader_or_deputy::<T,I>(
+                origin,
+                DeputyAction::Spend(amount),
+            )?;
+
+            ensure!(amount > Zero::zero(), Error::<T, I>::CannotSpendZero);
+
+            // Ensures that the budget is sufficient for the spending of specified amount
+            let (_, potential_missed_payment) = Self::calculate_possible_payment(amount);
+            ensure!(
+                potential_missed_payment == Zero::zero(),
+                Error::<T, I>::InsufficientBudgetForSpending
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            if let Some(deputy_id) = deputy_id {
+                Self::record_deputy_spending(&deputy_id, amount);
+            }
+
+            Self::pay_from_budget(&account_id, amount);
+            vesting::Pallet::<T>::force_vested_transfer(
+                RawOrigin::Root.into(),
+                <<T as frame_system::Config>::Lookup as StaticLookup>::unlookup(account_id.clone()),
+                <<T as frame_system::Config>::Lookup as StaticLookup>::unlookup(account_id.clone()),
+                vesting_schedule,
+            )?;
+            // Trigger event
+            Self::deposit_event(RawEvent::VestedBudgetSpending(account_id, vesting_schedule, rationale));
+        }
+
+
+        /// Fund working group budget by a member.
+        /// <weight>
+        ///
+        /// ## Weight
+        /// `O (1)` Doesn't depend on the state or parameters
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T, I>::fund_working_group_budget()]
+        pub fn fund_working_group_budget(
+            origin,
+            member_id: MemberId<T>,
+            amount: BalanceOf<T>,
+            rationale: Vec<u8>,
+        ) {
+            let account_id =
+                T::MemberOriginValidator::ensure_member_controller_account_origin(origin, member_id)?;
+
+            ensure!(amount > Zero::zero(), Error::<T, I>::ZeroTokensFunding);
+            ensure!(
+                Balances::<T>::usable_balance(&account_id) >= amount,
+                Error::<T, I>::InsufficientTokensForFunding
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // An account is allowed to die when funding working group budget
+            burn_from_usable::<T>(&account_id, amount)?;
+
+            Self::increase_working_group_budget(amount);
+
+            Self::deposit_event(
+                RawEvent::WorkingGroupBudgetFunded(
+                    member_id,
+                    amount,
+                    rationale
+                )
+            );
+        }
+
+        /// Lead remark message
+        ///
+        /// # <weight>
+        ///
+        /// ## Weight
+        /// `O (M)` where:
+        /// - `M` is the size of `msg` in kilobytes
+        /// - DB:
+        ///    - O(1) doesn't depend on the state or parameters
+        /// # </weight>
+        #[weight = WeightInfoWorkingGroup::<T,I>::lead_remark(to_kb(msg.len() as u32))]
+        pub fn lead_remark(origin, msg: Vec<u8>) {
             checks::ensure_origin_is_active_leader::<T, I>(origin)?;
 
             //
@@ -1303,28 +2108,6 @@ decl_module! {
 }
 
 impl<T: Config<I>, I: Instance> Module<T, I> {
-    // Calculate weight for on_initialize
-    // We assume worst case scenario in a safe manner
-    // We take the most number of workers that will be processed and use it as input of the most costly function
-    fn calculate_weight_on_initialize(workers: u32) -> Weight {
-        WeightInfoWorkingGroup::<T, I>::on_initialize_rewarding_without_missing_reward(
-            workers,
-        )
-        .max(
-            WeightInfoWorkingGroup::<T, I>::on_initialize_rewarding_with_missing_reward_cant_pay(
-                workers,
-            ),
-        )
-        .max(
-            WeightInfoWorkingGroup::<T, I>::on_initialize_rewarding_with_missing_reward(
-                workers,
-            ),
-        )
-        .max(WeightInfoWorkingGroup::<T, I>::on_initialize_leaving(
-            workers,
-        ))
-    }
-
     // Calculate weight for `leave_role`
     fn leave_role_weight(rationale: &Option<Vec<u8>>) -> Weight {
         WeightInfoWorkingGroup::<T, I>::leave_role(to_kb(
@@ -1380,6 +2163,132 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
         <ActiveWorkerCount<I>>::put(next_active_worker_count_value);
     }
 
+    // Removes the deputy and decreases the deputy counter (saturating).
+    fn remove_deputy(worker_id: &WorkerId<T>) {
+        <Deputies<T, I>>::remove(worker_id);
+        <DeputyCount<I>>::put(Self::deputy_count().saturating_sub(1));
+    }
+
+    /// Returns the applications under evaluation ranked by the weighted score tally,
+    /// ties are ranked by the application id.
+    pub fn ranked_application_tally(opening_id: OpeningId) -> Vec<(ApplicationId, BalanceOf<T>)> {
+        let mut ranked_tally = <ApplicationTallies<T, I>>::iter_prefix(opening_id)
+            .filter(|(application_id, _)| <ApplicationById<T, I>>::contains_key(application_id))
+            .collect::<Vec<_>>();
+
+        ranked_tally
+            .sort_by(|(id_a, tally_a), (id_b, tally_b)| tally_b.cmp(tally_a).then(id_a.cmp(id_b)));
+
+        ranked_tally
+    }
+
+    // Checks whether any rejected application has a higher tally than some of the successful
+    // applications.
+    fn deviates_from_tally(
+        ranked_tally: &[(ApplicationId, BalanceOf<T>)],
+        successful_application_ids: &BTreeSet<ApplicationId>,
+    ) -> bool {
+        let lowest_successful_tally = successful_application_ids
+            .iter()
+            .map(|application_id| {
+                ranked_tally
+                    .iter()
+                    .find(|(id, _)| id == application_id)
+                    .map_or(Zero::zero(), |(_, tally)| *tally)
+            })
+            .min();
+
+        let highest_rejected_tally = ranked_tally
+            .iter()
+            .filter(|(id, _)| !successful_application_ids.contains(id))
+            .map(|(_, tally)| *tally)
+            .max();
+
+        match (lowest_successful_tally, highest_rejected_tally) {
+            (Some(lowest_successful), Some(highest_rejected)) => {
+                highest_rejected > lowest_successful
+            }
+            _ => false,
+        }
+    }
+
+    // Removes the evaluation window with the votes and the tallies of the opening.
+    fn remove_application_evaluation(opening_id: OpeningId) {
+        <ApplicationEvaluations<T, I>>::remove(opening_id);
+        #[allow(deprecated)]
+        <ApplicationTallies<T, I>>::remove_prefix(opening_id, Some(MAX_EVALUATED_APPLICATIONS));
+        #[allow(deprecated)]
+        <ApplicationVotes<T, I>>::remove_prefix(opening_id, Some(MAX_APPLICATION_VOTES));
+    }
+
+    // Returns the amount spent by the deputy within the current spending period.
+    pub(crate) fn deputy_spent_in_period(
+        deputy: &DeputyOf<T>,
+        spending_period: T::BlockNumber,
+    ) -> BalanceOf<T> {
+        let period_end = deputy.spending_period_start.saturating_add(spending_period);
+
+        if Self::current_block() >= period_end {
+            Zero::zero()
+        } else {
+            deputy.spent_in_period
+        }
+    }
+
+    // Adds the amount to the deputy spending, starts a new spending period if the
+    // previous one has ended.
+    fn record_deputy_spending(deputy_id: &WorkerId<T>, amount: BalanceOf<T>) {
+        <Deputies<T, I>>::mutate_exists(deputy_id, |deputy| {
+            if let Some(deputy) = deputy {
+                let spending_period = deputy
+                    .permissions
+                    .spending_limit
+                    .as_ref()
+                    .map_or(Zero::zero(), |limit| limit.period);
+
+                let spent = Self::deputy_spent_in_period(deputy, spending_period);
+                if spent.is_zero() {
+                    deputy.spending_period_start = Self::current_block();
+                }
+                deputy.spent_in_period = spent.saturating_add(amount);
+            }
+        });
+    }
+
+    // Returns the penalty slashed by the deputy within the current slashing period.
+    pub(crate) fn deputy_slashed_in_period(
+        deputy: &DeputyOf<T>,
+        slashing_period: T::BlockNumber,
+    ) -> BalanceOf<T> {
+        let period_end = deputy.slashing_period_start.saturating_add(slashing_period);
+
+        if Self::current_block() >= period_end {
+            Zero::zero()
+        } else {
+            deputy.slashed_in_period
+        }
+    }
+
+    // Adds the penalty to the deputy slashing, starts a new slashing period if the
+    // previous one has ended.
+    fn record_deputy_slashing(deputy_id: &WorkerId<T>, penalty: BalanceOf<T>) {
+        <Deputies<T, I>>::mutate_exists(deputy_id, |deputy| {
+            if let Some(deputy) = deputy {
+                let slashing_period = deputy
+                    .permissions
+                    .slashing_limit
+                    .as_ref()
+                    .map_or(Zero::zero(), |limit| limit.period);
+
+                let slashed = Self::deputy_slashed_in_period(deputy, slashing_period);
+                if slashed.is_zero() {
+                    deputy.slashing_period_start = Self::current_block();
+                }
+                deputy.slashed_in_period = slashed.saturating_add(penalty);
+            }
+        });
+    }
+
     // Processes successful application during the fill_opening().
     fn fulfill_successful_applications(
         opening: &OpeningOf<T>,
@@ -1411,6 +2320,12 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
         // Get worker id.
         let new_worker_id = <NextWorkerId<T, I>>::get();
 
+        // The worker starts accruing the reward from the current reward index.
+        Self::update_reward_index();
+        <TotalRewardPerBlock<T, I>>::mutate(|total| {
+            *total = total.saturating_add(opening.reward_per_block.unwrap_or_default())
+        });
+
         // Construct a worker.
         let worker = Worker::<T>::new(
             &application_info.application.member_id,
@@ -1419,6 +2334,7 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
             &application_info.application.staking_account_id,
             opening.stake_policy.leaving_unstaking_period,
             opening.reward_per_block,
+            Self::reward_index(),
             Self::current_block(),
         );
 
@@ -1465,7 +2381,30 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
             }
         }
 
-        Self::try_to_pay_missed_reward(worker_id, worker);
+        // Pay the reward accrued until the worker removal.
+        Self::update_reward_index();
+        let worker = Self::accrue_worker_reward(worker_id, worker.to_owned());
+        let worker = Self::pay_worker_reward(worker_id, worker);
+        let worker = Self::release_unvested_reward(worker_id, worker);
+        Self::update_total_reward_per_block(
+            Self::worker_reward_per_block(worker_id, &worker),
+            None,
+        );
+        <WorkerRewardMultiplier<T, I>>::remove(worker_id);
+
+        <WorkerReviewCount<T, I>>::remove(worker_id);
+        #[allow(deprecated)]
+        <WorkerReviews<T, I>>::remove_prefix(worker_id, Some(MAX_WORKER_REVIEWS));
+
+        if <Deputies<T, I>>::contains_key(worker_id) {
+            Self::remove_deputy(worker_id);
+        }
+
+        if let Some(started_leaving_at) = worker.started_leaving_at {
+            let leaving_at =
+                started_leaving_at.saturating_add(worker.job_unstaking_period.max(One::one()));
+            <LeavingWorkers<T, I>>::remove(leaving_at, worker_id);
+        }
 
         // Remove the worker from the storage.
         WorkerById::<T, I>::remove(worker_id);
@@ -1492,83 +2431,365 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
         ));
     }
 
-    // Reward a worker using reward presets and working group budget.
-    fn reward_worker(worker_id: &WorkerId<T>, worker: &Worker<T>) {
-        // If reward period is not set.
-        let mut rewarding_period: u32 = T::RewardPeriod::get();
-        if rewarding_period == 0u32 {
-            rewarding_period = One::one();
-        }
+    // Funds the rewards of all the workers from the budget up to the current block.
+    // When the budget is insufficient the blocks are funded partially and the rest of
+    // the reward becomes missed reward of the workers.
+    fn update_reward_index() {
+        let (funded_reward, index_increase) = Self::calculate_reward_funding();
 
-        // Modify rewarding period for new workers.
-        let block_from_worker_creation: u128 =
-            (Self::current_block() - worker.created_at).saturated_into();
-        if block_from_worker_creation < rewarding_period.into() {
-            rewarding_period = block_from_worker_creation.saturated_into();
-        }
+        <Budget<T, I>>::mutate(|budget| *budget = budget.saturating_sub(funded_reward));
+        <RewardIndex<I>>::mutate(|index| *index = index.saturating_add(index_increase));
+        <RewardIndexUpdatedAt<T, I>>::put(Self::current_block());
+    }
 
-        if let Some(reward_per_block) = worker.reward_per_block {
-            let reward = reward_per_block * rewarding_period.into();
+    // Returns the reward funded by the budget since the last reward index update
+    // and the corresponding reward index increase.
+    fn calculate_reward_funding() -> (BalanceOf<T>, u128) {
+        let blocks: u128 = Self::current_block()
+            .saturating_sub(Self::reward_index_updated_at())
+            .saturated_into();
+        let owed_reward = Self::total_reward_per_block().saturating_mul(blocks.saturated_into());
+        let funded_reward = owed_reward.min(Self::budget());
+
+        let max_index_increase = blocks.saturating_mul(REWARD_INDEX_PRECISION);
+        let index_increase = if owed_reward.is_zero() {
+            max_index_increase
+        } else {
+            Perquintill::from_rational(funded_reward, owed_reward).mul_floor(max_index_increase)
+        };
 
-            let (actual_reward, missed_reward) = Self::calculate_possible_payment(reward);
+        (funded_reward, index_increase)
+    }
 
-            // Check whether the budget is not zero.
-            if actual_reward > Zero::zero() {
-                Self::pay_reward(
-                    worker_id,
-                    &worker.reward_account_id,
-                    actual_reward,
-                    RewardPaymentType::RegularReward,
-                );
-            }
+    // Returns the budget left after funding the worker rewards up to the current block.
+    fn available_budget() -> BalanceOf<T> {
+        let (funded_reward, _) = Self::calculate_reward_funding();
 
-            // Check whether the budget is insufficient.
-            if missed_reward > Zero::zero() {
-                Self::save_missed_reward(worker_id, worker, missed_reward);
-            } else {
-                Self::try_to_pay_missed_reward(worker_id, worker);
-            }
+        Self::budget().saturating_sub(funded_reward)
+    }
+
+    // Returns the worker reward per block adjusted by the reward multiplier.
+    fn worker_reward_per_block(
+        worker_id: &WorkerId<T>,
+        worker: &Worker<T>,
+    ) -> Option<BalanceOf<T>> {
+        worker.reward_per_block.map(|reward_per_block| {
+            Self::worker_reward_multiplier(worker_id).map_or(reward_per_block, |multiplier| {
+                reward_per_block
+                    .saturating_mul(multiplier.into())
+                    .checked_div(&100u32.into())
+                    .unwrap_or_default()
+            })
+        })
+    }
+
+    // Replaces the worker reward per block in the total reward per block.
+    fn update_total_reward_per_block(
+        old_reward_per_block: Option<BalanceOf<T>>,
+        new_reward_per_block: Option<BalanceOf<T>>,
+    ) {
+        <TotalRewardPerBlock<T, I>>::mutate(|total| {
+            *total = total
+                .saturating_sub(old_reward_per_block.unwrap_or_default())
+                .saturating_add(new_reward_per_block.unwrap_or_default())
+        });
+    }
+
+    // Maps the review score linearly to the reward multiplier within the bounds.
+    fn calculate_reward_multiplier(bounds: &RewardMultiplierBounds, score: u8) -> u16 {
+        let score_ratio = Percent::from_rational(u32::from(score), u32::from(MAX_REVIEW_SCORE));
+
+        bounds
+            .min
+            .saturating_add(score_ratio.mul_floor(bounds.max.saturating_sub(bounds.min)))
+    }
+
+    // Sets the worker reward multiplier, the reward is accrued at the previous rate first.
+    // Returns the latest kept review of the worker which adjusted the reward and wasn't
+    // overturned: the review which set the current reward multiplier.
+    fn latest_reward_adjusting_review(
+        worker_id: &WorkerId<T>,
+    ) -> Option<(ReviewId, WorkerReviewOf<T>)> {
+        let review_count = Self::worker_review_count(worker_id);
+
+        (review_count.saturating_sub(MAX_WORKER_REVIEWS)..review_count)
+            .rev()
+            .filter_map(|review_id| {
+                Self::worker_review(worker_id, review_id).map(|review| (review_id, review))
+            })
+            .find(|(_, review)| review.reward_multiplier.is_some() && !review.overturned)
+    }
+
+    fn set_worker_reward_multiplier(
+        worker_id: &WorkerId<T>,
+        worker: Worker<T>,
+        reward_multiplier: Option<u16>,
+    ) {
+        Self::update_reward_index();
+        let worker = Self::accrue_worker_reward(worker_id, worker);
+        let old_reward_per_block = Self::worker_reward_per_block(worker_id, &worker);
+
+        <WorkerRewardMultiplier<T, I>>::set(worker_id, reward_multiplier);
+
+        Self::update_total_reward_per_block(
+            old_reward_per_block,
+            Self::worker_reward_per_block(worker_id, &worker),
+        );
+        WorkerById::<T, I>::insert(worker_id, worker);
+    }
+
+    // Accrues the worker reward up to the current block. The unfunded part of the reward
+    // is added to the missed reward. Requires an up to date reward index.
+    fn accrue_worker_reward(worker_id: &WorkerId<T>, worker: Worker<T>) -> Worker<T> {
+        let reward_index = Self::reward_index();
+        let current_block = Self::current_block();
+
+        let (funded_reward, missed_reward) = Self::worker_reward_per_block(worker_id, &worker)
+            .map_or((Zero::zero(), Zero::zero()), |reward_per_block| {
+                let blocks: u128 = current_block
+                    .saturating_sub(worker.reward_accrued_at)
+                    .saturated_into();
+                let reward = reward_per_block.saturating_mul(blocks.saturated_into());
+                // Funded blocks are split into the whole and the fractional part.
+                let funded_blocks = reward_index.saturating_sub(worker.reward_index);
+                let whole_blocks = funded_blocks
+                    .checked_div(REWARD_INDEX_PRECISION)
+                    .unwrap_or_default();
+                let block_fraction = funded_blocks
+                    .checked_rem(REWARD_INDEX_PRECISION)
+                    .unwrap_or_default();
+                let funded_reward = reward_per_block
+                    .saturating_mul(whole_blocks.saturated_into())
+                    .saturating_add(
+                        Perquintill::from_rational(block_fraction, REWARD_INDEX_PRECISION)
+                            .mul_floor(reward_per_block),
+                    )
+                    .min(reward);
+
+                (funded_reward, reward.saturating_sub(funded_reward))
+            });
+
+        let worker = Worker::<T> {
+            accrued_reward: worker.accrued_reward.saturating_add(funded_reward),
+            reward_index,
+            reward_accrued_at: current_block,
+            ..worker
+        };
+
+        if missed_reward > Zero::zero() {
+            Self::save_missed_reward(worker_id, worker, missed_reward)
+        } else {
+            worker
         }
     }
 
+    // Pays the accrued reward and as much of the missed reward as the budget allows.
+    fn pay_worker_reward(worker_id: &WorkerId<T>, worker: Worker<T>) -> Worker<T> {
+        let accrued_reward = worker.accrued_reward;
+        let worker = Worker::<T> {
+            accrued_reward: Zero::zero(),
+            ..worker
+        };
+
+        let worker = if accrued_reward > Zero::zero() {
+            // Accrued reward was already withdrawn from the budget.
+            Self::deposit_reward(
+                worker_id,
+                worker,
+                accrued_reward,
+                RewardPaymentType::RegularReward,
+            )
+        } else {
+            worker
+        };
+
+        Self::try_to_pay_missed_reward(worker_id, worker)
+    }
+
     // Transfers the tokens if budget is sufficient. Infallible!
     // Should be accompanied with previous budget check.
     fn pay_from_budget(account_id: &T::AccountId, amount: BalanceOf<T>) {
+        Self::withdraw_from_budget(amount);
+
+        let _ = <balances::Pallet<T>>::deposit_creating(account_id, amount);
+    }
+
+    // Decreases the budget by the paid amount. Infallible!
+    // Should be accompanied with previous budget check.
+    fn withdraw_from_budget(amount: BalanceOf<T>) {
+        Self::update_reward_index();
+
         let budget = Self::budget();
 
         let new_budget = budget.saturating_sub(amount);
         <Budget<T, I>>::put(new_budget);
-
-        let _ = <balances::Pallet<T>>::deposit_creating(account_id, amount);
     }
 
     // Helper-function joining the reward payment with the event.
     fn pay_reward(
         worker_id: &WorkerId<T>,
-        account_id: &T::AccountId,
+        worker: Worker<T>,
         amount: BalanceOf<T>,
         reward_payment_type: RewardPaymentType,
-    ) {
-        Self::pay_from_budget(account_id, amount);
+    ) -> Worker<T> {
+        Self::withdraw_from_budget(amount);
+
+        Self::deposit_reward(worker_id, worker, amount, reward_payment_type)
+    }
+
+    // Deposits the paid reward on the reward account. The vested part of the reward is withheld
+    // and locked with a vesting schedule together with the reward withheld before.
+    fn deposit_reward(
+        worker_id: &WorkerId<T>,
+        worker: Worker<T>,
+        amount: BalanceOf<T>,
+        reward_payment_type: RewardPaymentType,
+    ) -> Worker<T> {
+        let vested_part = worker
+            .reward_vesting
+            .map_or(Zero::zero(), |reward_vesting| {
+                reward_vesting.vested_percentage * amount
+            });
+
+        let liquid_part = amount.saturating_sub(vested_part);
+
+        let _ = <balances::Pallet<T>>::deposit_creating(&worker.reward_account_id, liquid_part);
         Self::deposit_event(RawEvent::RewardPaid(
             *worker_id,
-            account_id.clone(),
-            amount,
+            worker.reward_account_id.clone(),
+            liquid_part,
+            vested_part,
             reward_payment_type,
         ));
+
+        let worker = Worker::<T> {
+            unvested_reward: worker.unvested_reward.saturating_add(vested_part),
+            ..worker
+        };
+
+        Self::vest_unvested_reward(worker_id, worker, false)
+    }
+
+    // Locks the withheld reward on the reward account with a new vesting schedule.
+    // Unless forced, the schedule is added at most once per the vesting period divided by
+    // `MAX_REWARD_VESTING_SCHEDULES`, which bounds the reward schedules of the account.
+    // The reward stays withheld if the schedule can't be added. Once the reward vesting
+    // is disabled for the worker, the withheld reward is paid as it is.
+    fn vest_unvested_reward(worker_id: &WorkerId<T>, worker: Worker<T>, force: bool) -> Worker<T> {
+        if worker.unvested_reward.is_zero() {
+            return worker;
+        }
+
+        let account_id = worker.reward_account_id.clone();
+
+        let reward_vesting = if let Some(reward_vesting) = worker.reward_vesting {
+            reward_vesting
+        } else {
+            let _ = <balances::Pallet<T>>::deposit_creating(&account_id, worker.unvested_reward);
+
+            return Worker::<T> {
+                unvested_reward: Zero::zero(),
+                ..worker
+            };
+        };
+
+        let current_block = Self::current_block();
+        let vesting_period = reward_vesting.vesting_period.saturated_into::<u128>();
+
+        if !force {
+            let vesting_interval = vesting_period
+                .checked_div(MAX_REWARD_VESTING_SCHEDULES.into())
+                .unwrap_or_default();
+            let vested_recently = worker.reward_vested_at.map_or(false, |vested_at| {
+                current_block < vested_at.saturating_add(vesting_interval.saturated_into())
+            });
+
+            if vested_recently {
+                return worker;
+            }
+        }
+
+        let locked = worker.unvested_reward.saturated_into::<u128>();
+        let per_block = locked
+            .checked_div(vesting_period)
+            .unwrap_or(locked)
+            .max(1u128);
+        let vesting_schedule = VestingInfoOf::<T>::new(
+            locked.saturated_into(),
+            per_block.saturated_into(),
+            current_block,
+        );
+
+        let can_add_schedule =
+            <vesting::Pallet<T> as VestingSchedule<T::AccountId>>::can_add_vesting_schedule(
+                &account_id,
+                vesting_schedule.locked(),
+                vesting_schedule.per_block(),
+                current_block,
+            );
+        if can_add_schedule.is_err() {
+            return worker;
+        }
+
+        let _ = <balances::Pallet<T>>::deposit_creating(&account_id, worker.unvested_reward);
+        // Can't fail after the check above.
+        let _ = <vesting::Pallet<T> as VestingSchedule<T::AccountId>>::add_vesting_schedule(
+            &account_id,
+            vesting_schedule.locked(),
+            vesting_schedule.per_block(),
+            current_block,
+        );
+
+        Self::deposit_event(RawEvent::RewardVested(
+            *worker_id,
+            account_id,
+            vesting_schedule,
+        ));
+
+        Worker::<T> {
+            unvested_reward: Zero::zero(),
+            reward_vested_at: Some(current_block),
+            ..worker
+        }
+    }
+
+    // Locks the withheld reward of the removed worker with a vesting schedule. Pays it
+    // as liquid if the schedule can't be added: the earned reward is never confiscated.
+    fn release_unvested_reward(worker_id: &WorkerId<T>, worker: Worker<T>) -> Worker<T> {
+        let worker = Self::vest_unvested_reward(worker_id, worker, true);
+
+        if worker.unvested_reward.is_zero() {
+            return worker;
+        }
+
+        let _ = <balances::Pallet<T>>::deposit_creating(
+            &worker.reward_account_id,
+            worker.unvested_reward,
+        );
+
+        Self::deposit_event(RawEvent::UnvestedRewardPaid(
+            *worker_id,
+            worker.reward_account_id.clone(),
+            worker.unvested_reward,
+        ));
+
+        Worker::<T> {
+            unvested_reward: Zero::zero(),
+            ..worker
+        }
     }
 
     // Tries to pay missed reward if the reward is enabled for worker and there is enough of group budget.
-    fn try_to_pay_missed_reward(worker_id: &WorkerId<T>, worker: &Worker<T>) {
+    fn try_to_pay_missed_reward(worker_id: &WorkerId<T>, worker: Worker<T>) -> Worker<T> {
         if let Some(missed_reward) = worker.missed_reward {
             let (could_be_paid_reward, insufficient_amount) =
                 Self::calculate_possible_payment(missed_reward);
 
             // Checks if the budget allows any payment.
             if could_be_paid_reward > Zero::zero() {
-                Self::pay_reward(
+                let worker = Self::pay_reward(
                     worker_id,
-                    &worker.reward_account_id,
+                    worker,
                     could_be_paid_reward,
                     RewardPaymentType::MissedReward,
                 );
@@ -1579,9 +2800,11 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
                     None
                 };
 
-                Self::update_worker_missed_reward(worker_id, worker.to_owned(), new_missed_reward);
+                return Self::update_worker_missed_reward(worker_id, worker, new_missed_reward);
             }
         }
+
+        worker
     }
 
     // Update worker missed reward.
@@ -1589,34 +2812,35 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
         worker_id: &WorkerId<T>,
         worker: Worker<T>,
         new_missed_reward: Option<BalanceOf<T>>,
-    ) {
-        WorkerById::<T, I>::insert(
-            worker_id,
-            Worker::<T> {
-                missed_reward: new_missed_reward,
-                ..worker
-            },
-        );
-
+    ) -> Worker<T> {
         Self::deposit_event(RawEvent::NewMissedRewardLevelReached(
             *worker_id,
             new_missed_reward,
         ));
+
+        Worker::<T> {
+            missed_reward: new_missed_reward,
+            ..worker
+        }
     }
 
     // Saves missed reward for a worker.
-    fn save_missed_reward(worker_id: &WorkerId<T>, worker: &Worker<T>, reward: BalanceOf<T>) {
+    fn save_missed_reward(
+        worker_id: &WorkerId<T>,
+        worker: Worker<T>,
+        reward: BalanceOf<T>,
+    ) -> Worker<T> {
         // Save unpaid reward.
         let missed_reward_so_far = worker.missed_reward.map_or(Zero::zero(), |val| val);
 
-        let new_missed_reward = missed_reward_so_far + reward;
+        let new_missed_reward = missed_reward_so_far.saturating_add(reward);
 
-        Self::update_worker_missed_reward(worker_id, worker.to_owned(), Some(new_missed_reward));
+        Self::update_worker_missed_reward(worker_id, worker, Some(new_missed_reward))
     }
 
     // Returns allowed payment by the group budget and possible missed payment
     fn calculate_possible_payment(amount: BalanceOf<T>) -> (BalanceOf<T>, BalanceOf<T>) {
-        let budget = Self::budget();
+        let budget = Self::available_budget();
 
         if budget >= amount {
             (amount, Zero::zero())
@@ -1627,46 +2851,24 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 
     // Returns a collection of workers with finished unstaking period.
     fn get_workers_with_finished_unstaking_period() -> Vec<WorkerInfo<T>> {
-        WorkerById::<T, I>::iter()
-            .filter_map(|(worker_id, worker)| {
-                if let Some(started_leaving_at) = worker.started_leaving_at {
-                    if started_leaving_at + worker.job_unstaking_period <= Self::current_block() {
-                        return Some((worker_id, worker).into());
-                    }
-                }
-
-                None
+        <LeavingWorkers<T, I>>::drain_prefix(Self::current_block())
+            .filter_map(|(worker_id, _)| {
+                Self::worker_by_id(worker_id).map(|worker| (worker_id, worker).into())
             })
             .collect::<Vec<_>>()
     }
 
-    // Defines whether the current block is a reward block.
-    fn is_reward_block() -> bool {
-        let current_block = Self::current_block();
-
-        // Don't reward at genesis.
-        if current_block == Zero::zero() {
-            return false;
-        }
-
-        let reward_period: u32 = T::RewardPeriod::get();
-
-        // Special case for not set reward_period. Treats as reward_period == 1.
-        if reward_period == 0u32 {
-            return true;
-        }
-
-        // Check whether current block is a reward block.
-        current_block % reward_period.into() == Zero::zero()
-    }
-
     // Sets the working group budget.
+    // The rewards are funded with the previous budget up to the current block.
     fn set_working_group_budget(new_budget: BalanceOf<T>) {
+        Self::update_reward_index();
         <Budget<T, I>>::put(new_budget);
     }
 
     // Increases working group budget.
+    // The rewards are funded with the previous budget up to the current block.
     fn increase_working_group_budget(amount: BalanceOf<T>) {
+        Self::update_reward_index();
         <Budget<T, I>>::mutate(|b| *b = b.saturating_add(amount));
     }
 
@@ -1701,6 +2903,12 @@ impl<T: Config<I>, I: Instance> common::working_group::WorkingGroupAuthenticator
             .ok()
     }
 
+    fn get_worker_reward_account_id(worker_id: &WorkerId<T>) -> Option<T::AccountId> {
+        checks::ensure_worker_exists::<T, I>(worker_id)
+            .map(|worker| worker.reward_account_id)
+            .ok()
+    }
+
     fn is_leader_account_id(account_id: &T::AccountId) -> bool {
         checks::ensure_is_lead_account::<T, I>(account_id.clone()).is_ok()
     }
@@ -1726,7 +2934,7 @@ impl<T: Config<I>, I: Instance>
     common::working_group::WorkingGroupBudgetHandler<T::AccountId, BalanceOf<T>> for Module<T, I>
 {
     fn get_budget() -> BalanceOf<T> {
-        Self::budget()
+        Self::available_budget()
     }
 
     fn set_budget(new_value: BalanceOf<T>) {
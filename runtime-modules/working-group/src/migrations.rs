@@ -0,0 +1,127 @@
+// Migrations for Working Group Pallet
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade};
+use frame_support::weights::Weight;
+
+pub mod v1 {
+    use super::*;
+    use codec::{Decode, Encode};
+
+    // Worker paid by the periodic reward payments
+    #[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
+    struct GroupWorkerV0<AccountId, MemberId, BlockNumber, Balance> {
+        member_id: MemberId,
+        role_account_id: AccountId,
+        staking_account_id: AccountId,
+        reward_account_id: AccountId,
+        started_leaving_at: Option<BlockNumber>,
+        job_unstaking_period: BlockNumber,
+        reward_per_block: Option<Balance>,
+        missed_reward: Option<Balance>,
+        created_at: BlockNumber,
+    }
+
+    type WorkerV0<T> = GroupWorkerV0<
+        <T as frame_system::Config>::AccountId,
+        MemberId<T>,
+        <T as frame_system::Config>::BlockNumber,
+        BalanceOf<T>,
+    >;
+
+    /// Converts the stored workers into the workers accruing the reward lazily:
+    /// - the workers start accruing the reward at the upgrade block
+    /// - the reward of the workers is fully liquid (no reward vesting split is set)
+    /// - the total reward per block is seeded from the rewards of the workers
+    /// - the leaving workers are scheduled to exit once their unstaking period is finished
+    ///   (at the next block if it is already finished)
+    pub struct MigrateToV1<T, I>(sp_std::marker::PhantomData<(T, I)>);
+    impl<T: Config<I>, I: Instance> OnRuntimeUpgrade for MigrateToV1<T, I> {
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            let onchain = Module::<T, I>::on_chain_storage_version();
+
+            ensure!(onchain < 1, "this migration can be deleted");
+
+            let worker_count = WorkerById::<T, I>::iter_keys().count() as u64;
+
+            Ok(worker_count.encode())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            let onchain = Module::<T, I>::on_chain_storage_version();
+            let current = Module::<T, I>::current_storage_version();
+
+            if onchain > 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            let reward_index = Module::<T, I>::reward_index();
+            let mut translated = 0u64;
+            let mut leaving = 0u64;
+            let mut total_reward_per_block = BalanceOf::<T>::zero();
+
+            WorkerById::<T, I>::translate(|worker_id, old: WorkerV0<T>| {
+                translated = translated.saturating_add(1);
+
+                total_reward_per_block =
+                    total_reward_per_block.saturating_add(old.reward_per_block.unwrap_or_default());
+
+                if let Some(started_leaving_at) = old.started_leaving_at {
+                    leaving = leaving.saturating_add(1);
+
+                    let leaving_at = started_leaving_at
+                        .saturating_add(old.job_unstaking_period.max(One::one()))
+                        .max(now.saturating_add(One::one()));
+                    <LeavingWorkers<T, I>>::insert(leaving_at, worker_id, ());
+                }
+
+                Some(Worker::<T> {
+                    member_id: old.member_id,
+                    role_account_id: old.role_account_id,
+                    staking_account_id: old.staking_account_id,
+                    reward_account_id: old.reward_account_id,
+                    started_leaving_at: old.started_leaving_at,
+                    job_unstaking_period: old.job_unstaking_period,
+                    reward_per_block: old.reward_per_block,
+                    missed_reward: old.missed_reward,
+                    accrued_reward: Zero::zero(),
+                    reward_index,
+                    reward_accrued_at: now,
+                    created_at: old.created_at,
+                    reward_vesting: None,
+                    unvested_reward: Zero::zero(),
+                    reward_vested_at: None,
+                })
+            });
+
+            <TotalRewardPerBlock<T, I>>::put(total_reward_per_block);
+            // No rewards are owed for the blocks before the upgrade
+            <RewardIndexUpdatedAt<T, I>>::put(now);
+
+            current.put::<Module<T, I>>();
+
+            T::DbWeight::get().reads_writes(
+                translated.saturating_add(2),
+                translated.saturating_add(leaving).saturating_add(3),
+            )
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let onchain = Module::<T, I>::on_chain_storage_version();
+            ensure!(onchain == 1, "this migration needs to be run");
+
+            let pre_upgrade_worker_count =
+                u64::decode(&mut state.as_slice()).map_err(|_| "invalid pre_upgrade state")?;
+            let worker_count = WorkerById::<T, I>::iter_values().count() as u64;
+            ensure!(
+                pre_upgrade_worker_count == worker_count,
+                "worker counts differ post_upgrade"
+            );
+
+            Ok(())
+        }
+    }
+}
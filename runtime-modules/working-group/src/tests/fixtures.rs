@@ -4,6 +4,7 @@ use frame_support::traits::{Currency, WithdrawReasons};
 use frame_support::StorageMap;
 use frame_system::{EventRecord, Phase, RawOrigin};
 use sp_runtime::traits::Hash;
+use sp_runtime::Percent;
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 
 use super::hiring_workflow::HiringWorkflow;
@@ -11,8 +12,10 @@ use super::mock::{Balances, LockId, RuntimeEvent, System, Test, TestWorkingGroup
 use crate::tests::mock::BlockNumberToBalance;
 use crate::types::StakeParameters;
 use crate::{
-    Application, ApplyOnOpeningParameters, BalanceOf, Config, DefaultInstance, Opening,
-    OpeningType, RawEvent, StakePolicy, VestingInfoOf, Worker,
+    Application, ApplicationEvaluation, ApplicationId, ApplyOnOpeningParameters, BalanceOf, Config,
+    DefaultInstance, DeputyPermissions, DeputyPermissionsOf, Opening, OpeningId, OpeningType,
+    RawEvent, ReviewId, RewardMultiplierBounds, RewardVestingSplit, RewardVestingSplitOf,
+    SlashingLimit, SpendingLimit, StakePolicy, VestingInfoOf, Worker,
 };
 use staking_handler::StakingHandler;
 
@@ -43,6 +46,11 @@ impl EventFixture {
             u64,
             <Test as frame_system::Config>::Hash,
             VestingInfoOf<Test>,
+            ReviewId,
+            RewardMultiplierBounds,
+            DeputyPermissionsOf<Test>,
+            RewardVestingSplitOf<Test>,
+            u64,
             DefaultInstance,
         >,
     ) {
@@ -75,6 +83,11 @@ impl EventFixture {
             u64,
             <Test as frame_system::Config>::Hash,
             VestingInfoOf<Test>,
+            ReviewId,
+            RewardMultiplierBounds,
+            DeputyPermissionsOf<Test>,
+            RewardVestingSplitOf<Test>,
+            u64,
             DefaultInstance,
         >,
     ) {
@@ -423,7 +436,13 @@ impl FillOpeningFixture {
                 job_unstaking_period: self.stake_policy.leaving_unstaking_period,
                 reward_per_block: self.reward_per_block,
                 missed_reward: None,
+                accrued_reward: 0,
+                reward_index: TestWorkingGroup::reward_index(),
+                reward_accrued_at: self.created_at,
                 created_at: self.created_at,
+                reward_vesting: None,
+                unvested_reward: 0,
+                reward_vested_at: None,
             };
 
             let actual_worker = TestWorkingGroup::worker_by_id(worker_id);
@@ -1111,6 +1130,511 @@ impl UpdateRewardAmountFixture {
     }
 }
 
+pub struct SetWorkerRewardVestingFixture {
+    worker_id: u64,
+    reward_vesting: Option<RewardVestingSplitOf<Test>>,
+    origin: RawOrigin<u64>,
+}
+
+impl SetWorkerRewardVestingFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            worker_id,
+            reward_vesting: Some(RewardVestingSplit {
+                vested_percentage: Percent::from_percent(30),
+                vesting_period: 10,
+            }),
+            origin: RawOrigin::Signed(lead_account_id),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_reward_vesting(self, reward_vesting: Option<RewardVestingSplitOf<Test>>) -> Self {
+        Self {
+            reward_vesting,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_worker = TestWorkingGroup::worker_by_id(self.worker_id);
+
+        let actual_result = TestWorkingGroup::set_worker_reward_vesting(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.reward_vesting,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let worker = TestWorkingGroup::worker_by_id(self.worker_id);
+        if actual_result.is_ok() {
+            assert_eq!(
+                worker.expect("Worker Must Exist").reward_vesting,
+                self.reward_vesting
+            );
+        } else {
+            assert_eq!(worker, old_worker);
+        }
+    }
+}
+
+pub struct ClaimWorkerRewardFixture {
+    worker_id: u64,
+    origin: RawOrigin<u64>,
+}
+
+impl ClaimWorkerRewardFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        Self {
+            worker_id,
+            origin: RawOrigin::Signed(1),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result =
+            TestWorkingGroup::claim_worker_reward(self.origin.clone().into(), self.worker_id);
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            let worker = TestWorkingGroup::worker_by_id(self.worker_id).expect("Worker Must Exist");
+
+            assert_eq!(worker.accrued_reward, 0);
+            assert_eq!(
+                worker.reward_accrued_at,
+                <frame_system::Pallet<Test>>::block_number()
+            );
+        }
+    }
+}
+
+pub struct SetRewardMultiplierBoundsFixture {
+    origin: RawOrigin<u64>,
+    bounds: Option<RewardMultiplierBounds>,
+}
+
+impl Default for SetRewardMultiplierBoundsFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            bounds: Some(RewardMultiplierBounds { min: 50, max: 150 }),
+        }
+    }
+}
+
+impl SetRewardMultiplierBoundsFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_bounds(self, bounds: Option<RewardMultiplierBounds>) -> Self {
+        Self { bounds, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_bounds = TestWorkingGroup::review_reward_multiplier_bounds();
+
+        let actual_result =
+            TestWorkingGroup::set_reward_multiplier_bounds(self.origin.clone().into(), self.bounds);
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_bounds = TestWorkingGroup::review_reward_multiplier_bounds();
+
+        if actual_result.is_ok() {
+            assert_eq!(new_bounds, self.bounds);
+        } else {
+            assert_eq!(new_bounds, old_bounds);
+        }
+    }
+}
+
+pub struct ReviewWorkerFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    score: u8,
+    rationale_hash: <Test as frame_system::Config>::Hash,
+    adjust_reward: bool,
+}
+
+impl ReviewWorkerFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            worker_id,
+            score: 50,
+            rationale_hash: <Test as frame_system::Config>::Hashing::hash(b"rationale"),
+            adjust_reward: false,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_score(self, score: u8) -> Self {
+        Self { score, ..self }
+    }
+
+    pub fn with_adjust_reward(self, adjust_reward: bool) -> Self {
+        Self {
+            adjust_reward,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_review_count = TestWorkingGroup::worker_review_count(self.worker_id);
+        let old_multiplier = TestWorkingGroup::worker_reward_multiplier(self.worker_id);
+
+        let actual_result = TestWorkingGroup::review_worker(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.score,
+            self.rationale_hash,
+            self.adjust_reward,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_review_count = TestWorkingGroup::worker_review_count(self.worker_id);
+        let new_multiplier = TestWorkingGroup::worker_reward_multiplier(self.worker_id);
+
+        if actual_result.is_ok() {
+            assert_eq!(new_review_count, old_review_count + 1);
+
+            let review = TestWorkingGroup::worker_review(self.worker_id, old_review_count)
+                .expect("Review Must Exist");
+
+            assert_eq!(review.score, self.score);
+            assert_eq!(review.rationale_hash, self.rationale_hash);
+            assert_eq!(review.reviewed_at, System::block_number());
+            assert!(!review.overturned);
+
+            if self.adjust_reward {
+                assert!(review.reward_multiplier.is_some());
+                assert_eq!(new_multiplier, review.reward_multiplier);
+            } else {
+                assert_eq!(review.reward_multiplier, None);
+                assert_eq!(new_multiplier, old_multiplier);
+            }
+        } else {
+            assert_eq!(new_review_count, old_review_count);
+            assert_eq!(new_multiplier, old_multiplier);
+        }
+    }
+}
+
+pub struct OverturnWorkerReviewFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    review_id: ReviewId,
+}
+
+impl OverturnWorkerReviewFixture {
+    pub fn default_for_review(worker_id: u64, review_id: ReviewId) -> Self {
+        Self {
+            origin: RawOrigin::Root,
+            worker_id,
+            review_id,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_review = TestWorkingGroup::worker_review(self.worker_id, self.review_id);
+
+        let actual_result = TestWorkingGroup::overturn_worker_review(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.review_id,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_review = TestWorkingGroup::worker_review(self.worker_id, self.review_id);
+
+        if actual_result.is_ok() {
+            assert!(new_review.expect("Review Must Exist").overturned);
+        } else {
+            assert_eq!(new_review, old_review);
+        }
+    }
+}
+
+pub struct AppointDeputyFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    permissions: DeputyPermissionsOf<Test>,
+}
+
+impl AppointDeputyFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            worker_id,
+            permissions: DeputyPermissions {
+                fill_openings: true,
+                spending_limit: Some(SpendingLimit {
+                    amount: 100,
+                    period: 10,
+                }),
+                slashing_limit: Some(SlashingLimit {
+                    amount: 10,
+                    period: 10,
+                }),
+            },
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_permissions(self, permissions: DeputyPermissionsOf<Test>) -> Self {
+        Self {
+            permissions,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_deputy = TestWorkingGroup::deputy_by_worker_id(self.worker_id);
+        let old_deputy_count = TestWorkingGroup::deputy_count();
+
+        let actual_result = TestWorkingGroup::appoint_deputy(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.permissions.clone(),
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_deputy = TestWorkingGroup::deputy_by_worker_id(self.worker_id);
+        let new_deputy_count = TestWorkingGroup::deputy_count();
+
+        if actual_result.is_ok() {
+            let deputy = new_deputy.expect("Deputy Must Exist");
+            assert_eq!(deputy.permissions, self.permissions);
+
+            let expected_deputy_count = if old_deputy.is_some() {
+                old_deputy_count
+            } else {
+                old_deputy_count + 1
+            };
+            assert_eq!(new_deputy_count, expected_deputy_count);
+        } else {
+            assert_eq!(new_deputy, old_deputy);
+            assert_eq!(new_deputy_count, old_deputy_count);
+        }
+    }
+}
+
+pub struct RevokeDeputyFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+}
+
+impl RevokeDeputyFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            worker_id,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_deputy_count = TestWorkingGroup::deputy_count();
+
+        let actual_result =
+            TestWorkingGroup::revoke_deputy(self.origin.clone().into(), self.worker_id);
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            assert!(TestWorkingGroup::deputy_by_worker_id(self.worker_id).is_none());
+            assert_eq!(TestWorkingGroup::deputy_count(), old_deputy_count - 1);
+        } else {
+            assert_eq!(TestWorkingGroup::deputy_count(), old_deputy_count);
+        }
+    }
+}
+
+pub struct StartApplicationEvaluationFixture {
+    origin: RawOrigin<u64>,
+    opening_id: OpeningId,
+    evaluation_period: u64,
+}
+
+impl StartApplicationEvaluationFixture {
+    pub fn default_for_opening_id(opening_id: OpeningId) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            opening_id,
+            evaluation_period: 10,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_evaluation_period(self, evaluation_period: u64) -> Self {
+        Self {
+            evaluation_period,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_evaluation = TestWorkingGroup::application_evaluation(self.opening_id);
+
+        let actual_result = TestWorkingGroup::start_application_evaluation(
+            self.origin.clone().into(),
+            self.opening_id,
+            self.evaluation_period,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_evaluation = TestWorkingGroup::application_evaluation(self.opening_id);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_evaluation,
+                Some(ApplicationEvaluation {
+                    ends_at: System::block_number() + self.evaluation_period,
+                    evaluated_applications: 0,
+                    votes: 0,
+                    deviation_rationale_hash: None,
+                })
+            );
+        } else {
+            assert_eq!(new_evaluation, old_evaluation);
+        }
+    }
+}
+
+pub struct VoteOnApplicationFixture {
+    origin: RawOrigin<u64>,
+    worker_id: u64,
+    application_id: ApplicationId,
+    score: u8,
+}
+
+impl VoteOnApplicationFixture {
+    pub fn default_for_vote(worker_id: u64, application_id: ApplicationId) -> Self {
+        Self {
+            origin: RawOrigin::Signed(2),
+            worker_id,
+            application_id,
+            score: 50,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_score(self, score: u8) -> Self {
+        Self { score, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let opening_id = TestWorkingGroup::application_by_id(self.application_id)
+            .map_or(0, |application| application.opening_id);
+        let vote_key = (self.application_id, self.worker_id);
+        let old_vote = TestWorkingGroup::application_vote(opening_id, vote_key);
+        let old_tally = TestWorkingGroup::application_tally(opening_id, self.application_id);
+
+        let actual_result = TestWorkingGroup::vote_on_application(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.application_id,
+            self.score,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_vote = TestWorkingGroup::application_vote(opening_id, vote_key);
+        let new_tally = TestWorkingGroup::application_tally(opening_id, self.application_id);
+
+        if actual_result.is_ok() {
+            let vote = new_vote.expect("Vote Must Exist");
+            assert_eq!(vote.score, self.score);
+
+            let old_weighted_score = old_vote.map_or(0, |vote| vote.weight * vote.score as u64);
+            assert_eq!(
+                new_tally,
+                old_tally - old_weighted_score + vote.weight * self.score as u64
+            );
+        } else {
+            assert_eq!(new_vote, old_vote);
+            assert_eq!(new_tally, old_tally);
+        }
+    }
+}
+
+pub struct JustifyEvaluationDeviationFixture {
+    origin: RawOrigin<u64>,
+    opening_id: OpeningId,
+    rationale_hash: <Test as frame_system::Config>::Hash,
+}
+
+impl JustifyEvaluationDeviationFixture {
+    pub fn default_for_opening_id(opening_id: OpeningId) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            opening_id,
+            rationale_hash: <Test as frame_system::Config>::Hashing::hash(b"rationale"),
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_evaluation = TestWorkingGroup::application_evaluation(self.opening_id);
+
+        let actual_result = TestWorkingGroup::justify_evaluation_deviation(
+            self.origin.clone().into(),
+            self.opening_id,
+            self.rationale_hash,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_evaluation = TestWorkingGroup::application_evaluation(self.opening_id);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                new_evaluation.and_then(|evaluation| evaluation.deviation_rationale_hash),
+                Some(self.rationale_hash)
+            );
+        } else {
+            assert_eq!(new_evaluation, old_evaluation);
+        }
+    }
+}
+
 pub struct SetStatusTextFixture {
     origin: RawOrigin<u64>,
     new_status_text: Option<Vec<u8>>,
@@ -1212,6 +1736,7 @@ impl VestedSpendFromBudgetFixture {
 
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let old_budget = TestWorkingGroup::budget();
+        let available_budget = TestWorkingGroup::available_budget();
         let old_balance = Balances::usable_balance(&self.account_id);
 
         let actual_result = self.call().map(|_| ());
@@ -1225,7 +1750,7 @@ impl VestedSpendFromBudgetFixture {
         let amount = self.vesting_schedule.locked();
 
         if actual_result.is_ok() {
-            assert_eq!(new_budget, old_budget - amount);
+            assert_eq!(new_budget, available_budget - amount);
         } else {
             assert_eq!(old_budget, new_budget);
             assert_eq!(old_balance, new_balance);
@@ -1275,6 +1800,7 @@ impl SpendFromBudgetFixture {
 
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let old_budget = TestWorkingGroup::budget();
+        let available_budget = TestWorkingGroup::available_budget();
         let old_balance = Balances::usable_balance(&self.account_id);
 
         let actual_result = self.call().map(|_| ());
@@ -1286,7 +1812,7 @@ impl SpendFromBudgetFixture {
         let amount = self.amount;
 
         if actual_result.is_ok() {
-            assert_eq!(new_budget, old_budget - amount);
+            assert_eq!(new_budget, available_budget - amount);
             assert_eq!(new_balance, old_balance + amount);
         } else {
             assert_eq!(old_budget, new_budget);
@@ -1332,6 +1858,7 @@ impl FundWorkingGroupBudgetFixture {
 
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let old_budget = TestWorkingGroup::budget();
+        let available_budget = TestWorkingGroup::available_budget();
 
         let actual_result = TestWorkingGroup::fund_working_group_budget(
             self.origin.clone().into(),
@@ -1345,7 +1872,7 @@ impl FundWorkingGroupBudgetFixture {
         let new_budget = TestWorkingGroup::budget();
 
         if actual_result.is_ok() {
-            assert_eq!(new_budget, old_budget + self.amount);
+            assert_eq!(new_budget, available_budget + self.amount);
         } else {
             assert_eq!(old_budget, new_budget);
         }
@@ -134,7 +134,6 @@ impl LockComparator<<Test as balances::Config>::Balance> for Test {
 }
 
 parameter_types! {
-    pub const RewardPeriod: u32 = 2;
     pub const MaxWorkerNumberLimit: u32 = 3;
     pub const MinUnstakingPeriodLimit: u64 = 3;
     pub const MinimumApplicationStake: u64 = 50;
@@ -151,7 +150,6 @@ impl Config for Test {
     type StakingAccountValidator = ();
     type MemberOriginValidator = ();
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = RewardPeriod;
     type WeightInfo = ();
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -6,13 +6,17 @@ pub use mock::{build_test_externalities, Test, DEFAULT_WORKER_ACCOUNT_ID};
 
 use frame_system::RawOrigin;
 use sp_arithmetic::traits::Zero;
+use sp_runtime::Percent;
 use vesting::VestingInfo;
 
 use crate::tests::fixtures::{
-    get_current_lead_account_id, set_invitation_lock, CancelOpeningFixture,
-    DecreaseWorkerStakeFixture, FundWorkingGroupBudgetFixture, IncreaseWorkerStakeFixture,
-    SetBudgetFixture, SetStatusTextFixture, SlashWorkerStakeFixture, SpendFromBudgetFixture,
-    UpdateRewardAccountFixture, UpdateRewardAmountFixture, VestedSpendFromBudgetFixture,
+    get_current_lead_account_id, set_invitation_lock, AppointDeputyFixture, CancelOpeningFixture,
+    ClaimWorkerRewardFixture, DecreaseWorkerStakeFixture, FundWorkingGroupBudgetFixture,
+    IncreaseWorkerStakeFixture, JustifyEvaluationDeviationFixture, OverturnWorkerReviewFixture,
+    ReviewWorkerFixture, RevokeDeputyFixture, SetBudgetFixture, SetRewardMultiplierBoundsFixture,
+    SetStatusTextFixture, SetWorkerRewardVestingFixture, SlashWorkerStakeFixture,
+    SpendFromBudgetFixture, StartApplicationEvaluationFixture, UpdateRewardAccountFixture,
+    UpdateRewardAmountFixture, VestedSpendFromBudgetFixture, VoteOnApplicationFixture,
     WithdrawApplicationFixture,
 };
 use crate::tests::hiring_workflow::HiringWorkflow;
@@ -22,8 +26,11 @@ use crate::tests::mock::{
 };
 use crate::types::StakeParameters;
 use crate::{
-    Config, DefaultInstance, Error, OpeningType, RawEvent, RewardPaymentType, StakePolicy,
-    VestingInfoOf, Worker,
+    Config, DefaultInstance, DeputyPermissions, Error, OpeningType, RawEvent,
+    RewardMultiplierBounds, RewardPaymentType, RewardVestingSplit, SlashingLimit, SpendingLimit,
+    StakePolicy, VestingInfoOf, Worker, WorkerReviews, MAX_APPLICATION_SCORE,
+    MAX_APPLICATION_VOTES, MAX_DEPUTIES, MAX_REVIEW_SCORE, MAX_REWARD_MULTIPLIER,
+    MAX_REWARD_VESTING_SCHEDULES, MAX_WORKER_REVIEWS,
 };
 use common::working_group::WorkingGroupAuthenticator;
 use fixtures::{
@@ -33,11 +40,12 @@ use fixtures::{
 };
 use frame_support::dispatch::DispatchError;
 use frame_support::traits::Currency;
-use frame_support::StorageMap;
+use frame_support::traits::VestingSchedule;
 use frame_support::{assert_noop, assert_ok};
-use mock::{run_to_block, Balances, RewardPeriod, TestWorkingGroup, ACTOR_ORIGIN_ERROR};
+use frame_support::{StorageMap, StorageValue};
+use mock::{run_to_block, Balances, TestWorkingGroup, ACTOR_ORIGIN_ERROR};
 use sp_runtime::traits::Hash;
-use sp_std::collections::btree_map::BTreeMap;
+use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 
 #[test]
 fn add_opening_succeeded() {
@@ -630,8 +638,7 @@ fn leave_worker_role_succeeds_with_paying_missed_reward() {
             .with_reward_per_block(Some(reward_per_block))
             .hire();
 
-        let reward_period: u64 = <Test as Config>::RewardPeriod::get().into();
-        let missed_reward_block_number = reward_period * 2;
+        let missed_reward_block_number = 4;
 
         run_to_block(missed_reward_block_number);
 
@@ -655,15 +662,14 @@ fn leave_worker_role_succeeds_with_paying_missed_reward() {
             worker_id,
             account_id,
             missed_reward,
+            0,
             RewardPaymentType::MissedReward,
         ));
 
-        // Didn't get the last reward period: leaving earlier than rewarding.
-        let reward_block_count = leaving_block - reward_period;
+        // The reward accrued until leaving is paid on exit.
         assert_eq!(
             Balances::usable_balance(&account_id),
-            reward_block_count * reward_per_block
-                + <Test as Config>::MinimumApplicationStake::get()
+            leaving_block * reward_per_block + <Test as Config>::MinimumApplicationStake::get()
         );
     });
 }
@@ -1942,16 +1948,21 @@ fn rewards_payments_are_successful() {
         let block_number = 10;
         run_to_block(block_number);
 
+        // Rewards accrue until claimed.
+        assert_eq!(Balances::usable_balance(&account_id), 0);
+
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
         assert_eq!(
             Balances::usable_balance(&account_id),
             block_number * reward_per_block
         );
 
-        let reward_period: u64 = <Test as Config>::RewardPeriod::get().into();
         EventFixture::assert_last_crate_event(RawEvent::RewardPaid(
             worker_id,
             account_id,
-            reward_per_block * reward_period,
+            block_number * reward_per_block,
+            0,
             RewardPaymentType::RegularReward,
         ));
     });
@@ -1975,6 +1986,8 @@ fn rewards_payments_with_no_budget() {
         let block_number = 10;
         run_to_block(block_number);
 
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
         assert_eq!(Balances::usable_balance(&account_id), 0);
 
         let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
@@ -2011,6 +2024,8 @@ fn rewards_payments_with_insufficient_budget_and_restored_budget() {
         let block_number = 10;
         run_to_block(block_number);
 
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
         assert_eq!(Balances::usable_balance(&account_id), first_budget);
 
         let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
@@ -2025,10 +2040,86 @@ fn rewards_payments_with_insufficient_budget_and_restored_budget() {
         let block_number2 = 20;
         run_to_block(block_number2);
 
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
         assert_eq!(
             Balances::usable_balance(&account_id),
             block_number2 * reward_per_block
         );
+
+        let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
+        assert_eq!(worker.missed_reward, None);
+    });
+}
+
+#[test]
+fn rewards_payments_with_budget_refilled_before_claim() {
+    build_test_externalities().execute_with(|| {
+        let reward_per_block = 10;
+
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+
+        let account_id = TestWorkingGroup::worker_by_id(worker_id)
+            .expect("Worker Must Exist")
+            .reward_account_id;
+
+        let block_number = 10;
+        run_to_block(block_number);
+
+        // Refilling the budget doesn't fund the reward of the past blocks.
+        let budget = 1000000;
+        SetBudgetFixture::default().with_budget(budget).execute();
+
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let missed_reward = block_number * reward_per_block;
+        EventFixture::contains_crate_event(RawEvent::NewMissedRewardLevelReached(
+            worker_id,
+            Some(missed_reward),
+        ));
+        EventFixture::assert_last_crate_event(RawEvent::NewMissedRewardLevelReached(
+            worker_id, None,
+        ));
+
+        // The missed reward is paid from the refilled budget.
+        assert_eq!(Balances::usable_balance(&account_id), missed_reward);
+        assert_eq!(TestWorkingGroup::budget(), budget - missed_reward);
+    });
+}
+
+#[test]
+fn rewards_payments_with_budget_shared_between_workers() {
+    build_test_externalities().execute_with(|| {
+        let reward_per_block = 10;
+
+        let worker_id2 = HireLeadFixture::default()
+            .with_reward_per_block(Some(2 * reward_per_block))
+            .hire_lead();
+        let worker_id1 = HiringWorkflow::default()
+            .with_setup_environment(false)
+            .with_reward_per_block(Some(reward_per_block))
+            .add_application_full(b"worker_handle".to_vec(), RawOrigin::Signed(2), 2, 2)
+            .execute()
+            .unwrap();
+
+        // Half of the rewards for 10 blocks.
+        let budget = 150;
+        SetBudgetFixture::default().with_budget(budget).execute();
+
+        let block_number = 10;
+        run_to_block(block_number);
+
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id1).call_and_assert(Ok(()));
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id2).call_and_assert(Ok(()));
+
+        let worker1 = TestWorkingGroup::worker_by_id(worker_id1).expect("Worker Must Exist");
+        let worker2 = TestWorkingGroup::worker_by_id(worker_id2).expect("Worker Must Exist");
+
+        assert_eq!(worker1.missed_reward, Some(50));
+        assert_eq!(worker2.missed_reward, Some(100));
+        assert_eq!(TestWorkingGroup::budget(), 0);
     });
 }
 
@@ -2039,7 +2130,6 @@ fn rewards_payments_with_starting_block() {
         run_to_block(starting_block);
 
         let reward_per_block = 10;
-        let reward_period: u64 = RewardPeriod::get().into();
 
         let worker_id = HireRegularWorkerFixture::default()
             .with_reward_per_block(Some(reward_per_block))
@@ -2054,15 +2144,38 @@ fn rewards_payments_with_starting_block() {
         let block_number = 11;
         run_to_block(block_number);
 
-        let effective_paid_blocks =
-            (block_number - starting_block) - (block_number % reward_period);
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
         assert_eq!(
             Balances::usable_balance(&account_id),
-            effective_paid_blocks * reward_per_block
+            (block_number - starting_block) * reward_per_block
         );
     });
 }
 
+#[test]
+fn claim_worker_reward_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::None)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn claim_worker_reward_fails_with_invalid_worker_id() {
+    build_test_externalities().execute_with(|| {
+        HireRegularWorkerFixture::default().hire();
+
+        let invalid_worker_id = 11;
+        ClaimWorkerRewardFixture::default_for_worker_id(invalid_worker_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::WorkerDoesNotExist.into(),
+        ));
+    });
+}
+
 #[test]
 fn set_budget_succeeded() {
     build_test_externalities().execute_with(|| {
@@ -2296,6 +2409,325 @@ fn update_reward_amount_fails_with_invalid_worker_id() {
     });
 }
 
+#[test]
+fn set_worker_reward_vesting_succeeds() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let reward_vesting = Some(RewardVestingSplit {
+            vested_percentage: Percent::from_percent(30),
+            vesting_period: 100,
+        });
+
+        SetWorkerRewardVestingFixture::default_for_worker_id(worker_id)
+            .with_reward_vesting(reward_vesting)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerRewardVestingUpdated(
+            worker_id,
+            reward_vesting,
+        ));
+
+        SetWorkerRewardVestingFixture::default_for_worker_id(worker_id)
+            .with_reward_vesting(None)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerRewardVestingUpdated(
+            worker_id, None,
+        ));
+    });
+}
+
+#[test]
+fn set_worker_reward_vesting_succeeds_for_leader() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireLeadFixture::default().hire_lead();
+
+        SetWorkerRewardVestingFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn set_worker_reward_vesting_fails_with_invalid_origin_signed_account() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetWorkerRewardVestingFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn set_worker_reward_vesting_fails_with_invalid_worker_id() {
+    build_test_externalities().execute_with(|| {
+        HireRegularWorkerFixture::default().hire();
+
+        let invalid_worker_id = 12;
+        SetWorkerRewardVestingFixture::default_for_worker_id(invalid_worker_id).call_and_assert(
+            Err(Error::<Test, DefaultInstance>::WorkerDoesNotExist.into()),
+        );
+    });
+}
+
+#[test]
+fn set_worker_reward_vesting_fails_with_invalid_split() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetWorkerRewardVestingFixture::default_for_worker_id(worker_id)
+            .with_reward_vesting(Some(RewardVestingSplit {
+                vested_percentage: Percent::zero(),
+                vesting_period: 100,
+            }))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidRewardVestingSplit.into(),
+            ));
+
+        SetWorkerRewardVestingFixture::default_for_worker_id(worker_id)
+            .with_reward_vesting(Some(RewardVestingSplit {
+                vested_percentage: Percent::from_percent(30),
+                vesting_period: 0,
+            }))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidRewardVestingSplit.into(),
+            ));
+    });
+}
+
+#[test]
+fn claim_worker_reward_vests_reward_part() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let reward_per_block = 100;
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+        let account_id = TestWorkingGroup::worker_by_id(worker_id)
+            .expect("Worker Must Exist")
+            .reward_account_id;
+
+        SetWorkerRewardVestingFixture::default_for_worker_id(worker_id)
+            .with_reward_vesting(Some(RewardVestingSplit {
+                vested_percentage: Percent::from_percent(30),
+                vesting_period: 100,
+            }))
+            .call_and_assert(Ok(()));
+        SetBudgetFixture::default().with_budget(100000).execute();
+
+        let block_number = 11;
+        run_to_block(block_number);
+
+        let free_balance = Balances::free_balance(&account_id);
+
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        // 30% of the reward for 10 blocks vests over 100 blocks.
+        let vesting_schedule = VestingInfoOf::<Test>::new(300, 3, block_number);
+        EventFixture::assert_last_crate_event(RawEvent::RewardVested(
+            worker_id,
+            account_id,
+            vesting_schedule,
+        ));
+        assert_eq!(
+            Vesting::vesting(&account_id).unwrap().last().copied(),
+            Some(vesting_schedule)
+        );
+        assert_eq!(Balances::free_balance(&account_id), free_balance + 1000);
+    });
+}
+
+#[test]
+fn claim_worker_reward_withholds_vested_reward_between_vesting_schedules() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let reward_per_block = 100;
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+        let account_id = TestWorkingGroup::worker_by_id(worker_id)
+            .expect("Worker Must Exist")
+            .reward_account_id;
+
+        SetWorkerRewardVestingFixture::default_for_worker_id(worker_id)
+            .with_reward_vesting(Some(RewardVestingSplit {
+                vested_percentage: Percent::from_percent(30),
+                vesting_period: 100,
+            }))
+            .call_and_assert(Ok(()));
+        SetBudgetFixture::default().with_budget(100000).execute();
+
+        // The schedules are added at most once per 100 / MAX_REWARD_VESTING_SCHEDULES blocks.
+        for block_number in [11, 21, 31] {
+            run_to_block(block_number);
+            ClaimWorkerRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+        }
+
+        let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
+        assert_eq!(worker.unvested_reward, 600);
+        assert_eq!(worker.reward_vested_at, Some(11));
+        assert_eq!(Vesting::vesting(&account_id).unwrap().len(), 1);
+
+        let block_number = 61;
+        run_to_block(block_number);
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        // The withheld reward is locked together with 30% of the reward for 30 blocks.
+        let vesting_schedule = VestingInfoOf::<Test>::new(1500, 15, block_number);
+        let schedules = Vesting::vesting(&account_id).unwrap();
+        assert_eq!(schedules.len() as u32, MAX_REWARD_VESTING_SCHEDULES);
+        assert_eq!(schedules.last().copied(), Some(vesting_schedule));
+
+        let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
+        assert_eq!(worker.unvested_reward, 0);
+        assert_eq!(worker.reward_vested_at, Some(block_number));
+    });
+}
+
+#[test]
+fn claim_worker_reward_withholds_vested_reward_when_vesting_schedules_are_full() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let reward_per_block = 100;
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+        let account_id = TestWorkingGroup::worker_by_id(worker_id)
+            .expect("Worker Must Exist")
+            .reward_account_id;
+
+        SetWorkerRewardVestingFixture::default_for_worker_id(worker_id)
+            .with_reward_vesting(Some(RewardVestingSplit {
+                vested_percentage: Percent::from_percent(30),
+                vesting_period: 100,
+            }))
+            .call_and_assert(Ok(()));
+        SetBudgetFixture::default().with_budget(100000).execute();
+
+        for _ in 0..<Test as vesting::Config>::MAX_VESTING_SCHEDULES {
+            assert_ok!(<Vesting as VestingSchedule<u64>>::add_vesting_schedule(
+                &account_id,
+                100,
+                1,
+                1000
+            ));
+        }
+
+        run_to_block(11);
+
+        let free_balance = Balances::free_balance(&account_id);
+
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::RewardPaid(
+            worker_id,
+            account_id,
+            700,
+            300,
+            RewardPaymentType::RegularReward,
+        ));
+        // Only the liquid part is paid.
+        assert_eq!(Balances::free_balance(&account_id), free_balance + 700);
+        let worker = TestWorkingGroup::worker_by_id(worker_id).expect("Worker Must Exist");
+        assert_eq!(worker.unvested_reward, 300);
+        assert_eq!(worker.reward_vested_at, None);
+    });
+}
+
+#[test]
+fn terminate_worker_role_vests_unvested_reward() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let reward_per_block = 100;
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+        let account_id = TestWorkingGroup::worker_by_id(worker_id)
+            .expect("Worker Must Exist")
+            .reward_account_id;
+
+        SetWorkerRewardVestingFixture::default_for_worker_id(worker_id)
+            .with_reward_vesting(Some(RewardVestingSplit {
+                vested_percentage: Percent::from_percent(30),
+                vesting_period: 100,
+            }))
+            .call_and_assert(Ok(()));
+        SetBudgetFixture::default().with_budget(100000).execute();
+
+        run_to_block(11);
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let block_number = 21;
+        run_to_block(block_number);
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        // The reward accrued before the termination vests right away.
+        let vesting_schedule = VestingInfoOf::<Test>::new(300, 3, block_number);
+        EventFixture::contains_crate_event(RawEvent::RewardVested(
+            worker_id,
+            account_id,
+            vesting_schedule,
+        ));
+        assert_eq!(
+            Vesting::vesting(&account_id).unwrap().last().copied(),
+            Some(vesting_schedule)
+        );
+    });
+}
+
+#[test]
+fn terminate_worker_role_pays_unvested_reward_when_vesting_schedules_are_full() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let reward_per_block = 100;
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+        let account_id = TestWorkingGroup::worker_by_id(worker_id)
+            .expect("Worker Must Exist")
+            .reward_account_id;
+
+        SetWorkerRewardVestingFixture::default_for_worker_id(worker_id)
+            .with_reward_vesting(Some(RewardVestingSplit {
+                vested_percentage: Percent::from_percent(30),
+                vesting_period: 100,
+            }))
+            .call_and_assert(Ok(()));
+        SetBudgetFixture::default().with_budget(100000).execute();
+
+        for _ in 0..<Test as vesting::Config>::MAX_VESTING_SCHEDULES {
+            assert_ok!(<Vesting as VestingSchedule<u64>>::add_vesting_schedule(
+                &account_id,
+                100,
+                1,
+                1000
+            ));
+        }
+
+        run_to_block(11);
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let free_balance = Balances::free_balance(&account_id);
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        // The withheld reward is paid as liquid, only the reward accrued since the claim vests.
+        EventFixture::contains_crate_event(RawEvent::UnvestedRewardPaid(
+            worker_id, account_id, 300,
+        ));
+        assert_eq!(Balances::free_balance(&account_id), free_balance + 300);
+    });
+}
+
 #[test]
 fn set_status_text_succeeded() {
     build_test_externalities().execute_with(|| {
@@ -2882,3 +3314,1303 @@ fn worker_remark_ok() {
         EventFixture::assert_last_crate_event(RawEvent::WorkerRemarked(worker_id, Vec::new()));
     });
 }
+
+#[test]
+fn set_reward_multiplier_bounds_succeeded() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        run_to_block(1);
+
+        let bounds = Some(RewardMultiplierBounds { min: 80, max: 120 });
+        SetRewardMultiplierBoundsFixture::default()
+            .with_bounds(bounds)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::RewardMultiplierBoundsUpdated(bounds));
+
+        SetRewardMultiplierBoundsFixture::default()
+            .with_bounds(None)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::RewardMultiplierBoundsUpdated(None));
+    });
+}
+
+#[test]
+fn set_reward_multiplier_bounds_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        SetRewardMultiplierBoundsFixture::default()
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn set_reward_multiplier_bounds_fails_with_invalid_bounds() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        SetRewardMultiplierBoundsFixture::default()
+            .with_bounds(Some(RewardMultiplierBounds { min: 120, max: 80 }))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidRewardMultiplierBounds.into(),
+            ));
+
+        SetRewardMultiplierBoundsFixture::default()
+            .with_bounds(Some(RewardMultiplierBounds {
+                min: 100,
+                max: MAX_REWARD_MULTIPLIER + 1,
+            }))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidRewardMultiplierBounds.into(),
+            ));
+    });
+}
+
+#[test]
+fn review_worker_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let review_fixture = ReviewWorkerFixture::default_for_worker_id(worker_id).with_score(70);
+        review_fixture.call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerReviewed(
+            worker_id,
+            0,
+            70,
+            <Test as frame_system::Config>::Hashing::hash(b"rationale"),
+            None,
+        ));
+
+        review_fixture.call_and_assert(Ok(()));
+
+        assert_eq!(TestWorkingGroup::worker_review_count(worker_id), 2);
+        assert_eq!(TestWorkingGroup::worker_reward_multiplier(worker_id), None);
+    });
+}
+
+#[test]
+fn review_worker_adjusts_reward_succeeded() {
+    build_test_externalities().execute_with(|| {
+        let reward_per_block = 10;
+
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(reward_per_block))
+            .hire();
+
+        let account_id = TestWorkingGroup::worker_by_id(worker_id)
+            .expect("Worker Must Exist")
+            .role_account_id;
+
+        SetBudgetFixture::default().execute();
+        SetRewardMultiplierBoundsFixture::default().call_and_assert(Ok(()));
+
+        let review_block = 5;
+        run_to_block(review_block);
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_score(MAX_REVIEW_SCORE)
+            .with_adjust_reward(true)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::worker_reward_multiplier(worker_id),
+            Some(150)
+        );
+
+        let claim_block = 10;
+        run_to_block(claim_block);
+
+        ClaimWorkerRewardFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        // The multiplier applies from the review block only.
+        let adjusted_reward_per_block = reward_per_block * 150 / 100;
+        assert_eq!(
+            Balances::usable_balance(&account_id),
+            review_block * reward_per_block
+                + (claim_block - review_block) * adjusted_reward_per_block
+        );
+    });
+}
+
+#[test]
+fn review_worker_removes_oldest_review_when_limit_reached() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let review_fixture = ReviewWorkerFixture::default_for_worker_id(worker_id);
+        for _ in 0..MAX_WORKER_REVIEWS {
+            review_fixture.call_and_assert(Ok(()));
+        }
+
+        assert!(TestWorkingGroup::worker_review(worker_id, 0).is_some());
+
+        review_fixture.call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::worker_review_count(worker_id),
+            MAX_WORKER_REVIEWS + 1
+        );
+        assert!(TestWorkingGroup::worker_review(worker_id, 0).is_none());
+        assert!(TestWorkingGroup::worker_review(worker_id, MAX_WORKER_REVIEWS).is_some());
+        assert_eq!(
+            WorkerReviews::<Test, DefaultInstance>::iter_prefix(worker_id).count(),
+            MAX_WORKER_REVIEWS as usize
+        );
+    });
+}
+
+#[test]
+fn terminate_worker_role_removes_worker_reviews() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let review_fixture = ReviewWorkerFixture::default_for_worker_id(worker_id);
+        review_fixture.call_and_assert(Ok(()));
+        review_fixture.call_and_assert(Ok(()));
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert_eq!(TestWorkingGroup::worker_review_count(worker_id), 0);
+        assert_eq!(
+            WorkerReviews::<Test, DefaultInstance>::iter_prefix(worker_id).count(),
+            0
+        );
+    });
+}
+
+#[test]
+fn review_worker_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn review_worker_fails_with_invalid_worker_id() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let invalid_worker_id = 22;
+
+        ReviewWorkerFixture::default_for_worker_id(invalid_worker_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::WorkerDoesNotExist.into(),
+        ));
+    });
+}
+
+#[test]
+fn review_worker_fails_for_leader() {
+    build_test_externalities().execute_with(|| {
+        let lead_id = HireLeadFixture::default().hire_lead();
+
+        ReviewWorkerFixture::default_for_worker_id(lead_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::CannotReviewLeader.into(),
+        ));
+    });
+}
+
+#[test]
+fn review_worker_fails_for_leaving_worker() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Err(Error::<
+            Test,
+            DefaultInstance,
+        >::WorkerIsLeaving
+            .into()));
+    });
+}
+
+#[test]
+fn review_worker_fails_with_invalid_score() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_score(MAX_REVIEW_SCORE + 1)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidReviewScore.into()
+            ));
+    });
+}
+
+#[test]
+fn review_worker_adjusting_reward_fails_without_bounds() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(10))
+            .hire();
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_adjust_reward(true)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::RewardMultiplierBoundsNotSet.into(),
+            ));
+    });
+}
+
+#[test]
+fn review_worker_adjusting_reward_fails_without_reward() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(None)
+            .hire();
+
+        SetRewardMultiplierBoundsFixture::default().call_and_assert(Ok(()));
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_adjust_reward(true)
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::WorkerHasNoReward.into()));
+    });
+}
+
+#[test]
+fn overturn_worker_review_resets_reward_multiplier() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(10))
+            .hire();
+
+        SetRewardMultiplierBoundsFixture::default().call_and_assert(Ok(()));
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_score(0)
+            .with_adjust_reward(true)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::worker_reward_multiplier(worker_id),
+            Some(50)
+        );
+
+        OverturnWorkerReviewFixture::default_for_review(worker_id, 0).call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerReviewOverturned(worker_id, 0));
+
+        assert_eq!(TestWorkingGroup::worker_reward_multiplier(worker_id), None);
+    });
+}
+
+#[test]
+fn overturn_outdated_worker_review_keeps_reward_multiplier() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(10))
+            .hire();
+
+        SetRewardMultiplierBoundsFixture::default().call_and_assert(Ok(()));
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_score(0)
+            .with_adjust_reward(true)
+            .call_and_assert(Ok(()));
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_score(MAX_REVIEW_SCORE)
+            .with_adjust_reward(true)
+            .call_and_assert(Ok(()));
+
+        OverturnWorkerReviewFixture::default_for_review(worker_id, 0).call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::worker_reward_multiplier(worker_id),
+            Some(150)
+        );
+    });
+}
+
+#[test]
+fn overturn_worker_review_restores_previous_reward_multiplier() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(10))
+            .hire();
+
+        SetRewardMultiplierBoundsFixture::default().call_and_assert(Ok(()));
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_score(0)
+            .with_adjust_reward(true)
+            .call_and_assert(Ok(()));
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_score(MAX_REVIEW_SCORE)
+            .with_adjust_reward(true)
+            .call_and_assert(Ok(()));
+
+        OverturnWorkerReviewFixture::default_for_review(worker_id, 1).call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::worker_reward_multiplier(worker_id),
+            Some(50)
+        );
+    });
+}
+
+#[test]
+fn overturn_worker_review_resets_reward_multiplier_set_before_latest_review() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default()
+            .with_reward_per_block(Some(10))
+            .hire();
+
+        SetRewardMultiplierBoundsFixture::default().call_and_assert(Ok(()));
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_score(0)
+            .with_adjust_reward(true)
+            .call_and_assert(Ok(()));
+
+        // The latest review doesn't adjust the reward.
+        ReviewWorkerFixture::default_for_worker_id(worker_id)
+            .with_score(MAX_REVIEW_SCORE)
+            .call_and_assert(Ok(()));
+
+        OverturnWorkerReviewFixture::default_for_review(worker_id, 0).call_and_assert(Ok(()));
+
+        assert_eq!(TestWorkingGroup::worker_reward_multiplier(worker_id), None);
+    });
+}
+
+#[test]
+fn overturn_worker_review_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        OverturnWorkerReviewFixture::default_for_review(worker_id, 0)
+            .with_origin(RawOrigin::Signed(1))
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn overturn_worker_review_fails_with_invalid_review_id() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        OverturnWorkerReviewFixture::default_for_review(worker_id, 0).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::WorkerReviewDoesNotExist.into(),
+        ));
+    });
+}
+
+#[test]
+fn overturn_worker_review_fails_for_overturned_review() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        ReviewWorkerFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let overturn_fixture = OverturnWorkerReviewFixture::default_for_review(worker_id, 0);
+        overturn_fixture.call_and_assert(Ok(()));
+
+        overturn_fixture.call_and_assert(Err(
+            Error::<Test, DefaultInstance>::WorkerReviewAlreadyOverturned.into(),
+        ));
+    });
+}
+
+#[test]
+fn appoint_deputy_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let appoint_deputy_fixture = AppointDeputyFixture::default_for_worker_id(worker_id);
+        appoint_deputy_fixture.call_and_assert(Ok(()));
+
+        let permissions = TestWorkingGroup::deputy_by_worker_id(worker_id)
+            .expect("Deputy Must Exist")
+            .permissions;
+
+        EventFixture::assert_last_crate_event(RawEvent::DeputyAppointed(worker_id, permissions));
+
+        // Updating the permissions keeps the deputy count.
+        let new_permissions = DeputyPermissions {
+            fill_openings: false,
+            spending_limit: None,
+            slashing_limit: Some(SlashingLimit {
+                amount: 1,
+                period: 1,
+            }),
+        };
+        appoint_deputy_fixture
+            .with_permissions(new_permissions.clone())
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DeputyAppointed(
+            worker_id,
+            new_permissions,
+        ));
+    });
+}
+
+#[test]
+fn appoint_deputy_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn appoint_deputy_fails_with_invalid_worker_id() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let invalid_worker_id = 22;
+
+        AppointDeputyFixture::default_for_worker_id(invalid_worker_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::WorkerDoesNotExist.into(),
+        ));
+    });
+}
+
+#[test]
+fn appoint_deputy_fails_for_leader() {
+    build_test_externalities().execute_with(|| {
+        let lead_id = HireLeadFixture::default().hire_lead();
+
+        AppointDeputyFixture::default_for_worker_id(lead_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::CannotAppointLeaderAsDeputy.into(),
+        ));
+    });
+}
+
+#[test]
+fn appoint_deputy_fails_for_leaving_worker() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::WorkerIsLeaving.into()));
+    });
+}
+
+#[test]
+fn appoint_deputy_fails_with_exceeding_max_deputy_number() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        <crate::DeputyCount<DefaultInstance>>::put(MAX_DEPUTIES);
+
+        AppointDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::MaxDeputyNumberExceeded.into(),
+        ));
+    });
+}
+
+#[test]
+fn appoint_deputy_fails_with_zero_spending_period() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .with_permissions(DeputyPermissions {
+                spending_limit: Some(SpendingLimit {
+                    amount: 100,
+                    period: 0,
+                }),
+                ..Default::default()
+            })
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidDeputySpendingPeriod.into(),
+            ));
+    });
+}
+
+#[test]
+fn appoint_deputy_fails_with_zero_slashing_period() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .with_permissions(DeputyPermissions {
+                slashing_limit: Some(SlashingLimit {
+                    amount: 10,
+                    period: 0,
+                }),
+                ..Default::default()
+            })
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidDeputySlashingPeriod.into(),
+            ));
+    });
+}
+
+#[test]
+fn revoke_deputy_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        RevokeDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::DeputyRevoked(worker_id));
+    });
+}
+
+#[test]
+fn revoke_deputy_succeeded_by_council() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        RevokeDeputyFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn revoke_deputy_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        RevokeDeputyFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn revoke_deputy_fails_for_non_deputy() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        RevokeDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::DeputyDoesNotExist.into(),
+        ));
+    });
+}
+
+#[test]
+fn deputy_is_removed_with_the_worker() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert!(TestWorkingGroup::deputy_by_worker_id(worker_id).is_none());
+        assert_eq!(TestWorkingGroup::deputy_count(), 0);
+    });
+}
+
+#[test]
+fn deputy_spend_from_budget_succeeded_within_spending_limit() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetBudgetFixture::default().execute();
+
+        // 100 per 10 blocks
+        AppointDeputyFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        let deputy_origin = RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID);
+
+        SpendFromBudgetFixture::default()
+            .with_origin(deputy_origin.clone())
+            .with_amount(60)
+            .call_and_assert(Ok(()));
+
+        SpendFromBudgetFixture::default()
+            .with_origin(deputy_origin.clone())
+            .with_amount(50)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::DeputySpendingLimitExceeded.into(),
+            ));
+
+        SpendFromBudgetFixture::default()
+            .with_origin(deputy_origin.clone())
+            .with_amount(40)
+            .call_and_assert(Ok(()));
+
+        // The limit is restored in the next spending period.
+        run_to_block(10);
+
+        SpendFromBudgetFixture::default()
+            .with_origin(deputy_origin)
+            .with_amount(100)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn deputy_spend_from_budget_fails_without_permission() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        SetBudgetFixture::default().execute();
+
+        AppointDeputyFixture::default_for_worker_id(worker_id)
+            .with_permissions(DeputyPermissions::default())
+            .call_and_assert(Ok(()));
+
+        SpendFromBudgetFixture::default()
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .with_amount(10)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::DeputyActionNotPermitted.into(),
+            ));
+    });
+}
+
+#[test]
+fn deputy_slash_stake_succeeded_within_slashing_limit() {
+    build_test_externalities().execute_with(|| {
+        let lead_id = HireLeadFixture::default().hire_lead();
+
+        let deputy_id = HiringWorkflow::default()
+            .with_setup_environment(false)
+            .add_application(b"deputy".to_vec())
+            .execute()
+            .unwrap();
+
+        let worker_account_id = 3;
+        let worker_id = HiringWorkflow::default()
+            .with_setup_environment(false)
+            .add_application_full(
+                b"worker".to_vec(),
+                RawOrigin::Signed(worker_account_id),
+                worker_account_id,
+                worker_account_id,
+            )
+            .execute()
+            .unwrap();
+
+        // 10 per 10 blocks
+        AppointDeputyFixture::default_for_worker_id(deputy_id).call_and_assert(Ok(()));
+
+        let deputy_origin = RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID);
+
+        SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_origin(deputy_origin.clone())
+            .with_account_id(worker_account_id)
+            .with_penalty(6)
+            .call_and_assert(Ok(()));
+
+        // The penalties slashed within the period are summed up.
+        SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_origin(deputy_origin.clone())
+            .with_account_id(worker_account_id)
+            .with_penalty(5)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::DeputySlashingLimitExceeded.into(),
+            ));
+
+        SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_origin(deputy_origin.clone())
+            .with_account_id(worker_account_id)
+            .with_penalty(4)
+            .call_and_assert(Ok(()));
+
+        // The limit is restored in the next slashing period.
+        run_to_block(10);
+
+        SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_origin(deputy_origin.clone())
+            .with_account_id(worker_account_id)
+            .with_penalty(10)
+            .call_and_assert(Ok(()));
+
+        // Only the council can slash the leader.
+        SlashWorkerStakeFixture::default_for_worker_id(lead_id)
+            .with_origin(deputy_origin)
+            .with_account_id(1)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn deputy_slash_stake_fails_for_deputies() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let deputy_id = HiringWorkflow::default()
+            .with_setup_environment(false)
+            .add_application(b"deputy".to_vec())
+            .execute()
+            .unwrap();
+
+        let other_deputy_account_id = 3;
+        let other_deputy_id = HiringWorkflow::default()
+            .with_setup_environment(false)
+            .add_application_full(
+                b"other deputy".to_vec(),
+                RawOrigin::Signed(other_deputy_account_id),
+                other_deputy_account_id,
+                other_deputy_account_id,
+            )
+            .execute()
+            .unwrap();
+
+        AppointDeputyFixture::default_for_worker_id(deputy_id).call_and_assert(Ok(()));
+        AppointDeputyFixture::default_for_worker_id(other_deputy_id).call_and_assert(Ok(()));
+
+        let deputy_origin = RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID);
+
+        SlashWorkerStakeFixture::default_for_worker_id(other_deputy_id)
+            .with_origin(deputy_origin.clone())
+            .with_account_id(other_deputy_account_id)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::DeputyCannotSlashDeputy.into()
+            ));
+
+        SlashWorkerStakeFixture::default_for_worker_id(deputy_id)
+            .with_origin(deputy_origin)
+            .with_account_id(DEFAULT_WORKER_ACCOUNT_ID)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::DeputyCannotSlashDeputy.into()
+            ));
+
+        // The leader can slash the deputies.
+        SlashWorkerStakeFixture::default_for_worker_id(other_deputy_id)
+            .with_account_id(other_deputy_account_id)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn deputy_fill_opening_succeeded_without_lead() {
+    build_test_externalities().execute_with(|| {
+        let deputy_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(deputy_id).call_and_assert(Ok(()));
+
+        let opening_id = AddOpeningFixture::default().call().unwrap();
+
+        let application_id = ApplyOnOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(3), 3)
+            .call()
+            .unwrap();
+
+        // The lead is away.
+        let lead_id = TestWorkingGroup::current_lead().expect("Lead Must Exist");
+        TerminateWorkerRoleFixture::default_for_worker_id(lead_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+
+        let worker_id = FillOpeningFixture::default_for_ids(opening_id, vec![application_id])
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call()
+            .unwrap();
+
+        assert!(TestWorkingGroup::worker_by_id(worker_id).is_some());
+    });
+}
+
+#[test]
+fn deputy_fill_opening_fails_without_permission() {
+    build_test_externalities().execute_with(|| {
+        let deputy_id = HireRegularWorkerFixture::default().hire();
+
+        AppointDeputyFixture::default_for_worker_id(deputy_id)
+            .with_permissions(DeputyPermissions::default())
+            .call_and_assert(Ok(()));
+
+        let opening_id = AddOpeningFixture::default().call().unwrap();
+
+        let application_id = ApplyOnOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(3), 3)
+            .call()
+            .unwrap();
+
+        FillOpeningFixture::default_for_ids(opening_id, vec![application_id])
+            .with_origin(RawOrigin::Signed(DEFAULT_WORKER_ACCOUNT_ID))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::DeputyActionNotPermitted.into(),
+            ));
+    });
+}
+
+fn add_evaluated_opening_with_applications() -> (u64, u64, u64, u64) {
+    let worker_id = HireRegularWorkerFixture::default().hire();
+
+    let opening_id = AddOpeningFixture::default().call().unwrap();
+
+    let first_application_id = ApplyOnOpeningFixture::default_for_opening_id(opening_id)
+        .with_origin(RawOrigin::Signed(3), 3)
+        .call()
+        .unwrap();
+
+    let second_application_id = ApplyOnOpeningFixture::default_for_opening_id(opening_id)
+        .with_origin(RawOrigin::Signed(4), 4)
+        .call()
+        .unwrap();
+
+    StartApplicationEvaluationFixture::default_for_opening_id(opening_id).call_and_assert(Ok(()));
+
+    (
+        worker_id,
+        opening_id,
+        first_application_id,
+        second_application_id,
+    )
+}
+
+#[test]
+fn start_application_evaluation_succeeded() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        run_to_block(1);
+
+        let opening_id = AddOpeningFixture::default().call().unwrap();
+
+        StartApplicationEvaluationFixture::default_for_opening_id(opening_id)
+            .with_evaluation_period(20)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationEvaluationStarted(
+            opening_id, 21,
+        ));
+    });
+}
+
+#[test]
+fn start_application_evaluation_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let opening_id = AddOpeningFixture::default().call().unwrap();
+
+        StartApplicationEvaluationFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn start_application_evaluation_fails_with_invalid_opening_id() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let invalid_opening_id = 22;
+
+        StartApplicationEvaluationFixture::default_for_opening_id(invalid_opening_id)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::OpeningDoesNotExist.into()
+            ));
+    });
+}
+
+#[test]
+fn start_application_evaluation_fails_for_leader_opening() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let opening_id = AddOpeningFixture::default()
+            .with_opening_type(OpeningType::Leader)
+            .with_origin(RawOrigin::Root)
+            .call()
+            .unwrap();
+
+        StartApplicationEvaluationFixture::default_for_opening_id(opening_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::CannotEvaluateLeaderOpening.into(),
+        ));
+    });
+}
+
+#[test]
+fn start_application_evaluation_fails_when_already_started() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let opening_id = AddOpeningFixture::default().call().unwrap();
+
+        let start_evaluation_fixture =
+            StartApplicationEvaluationFixture::default_for_opening_id(opening_id);
+        start_evaluation_fixture.call_and_assert(Ok(()));
+
+        start_evaluation_fixture.call_and_assert(Err(
+            Error::<Test, DefaultInstance>::ApplicationEvaluationAlreadyStarted.into(),
+        ));
+    });
+}
+
+#[test]
+fn start_application_evaluation_fails_with_zero_period() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let opening_id = AddOpeningFixture::default().call().unwrap();
+
+        StartApplicationEvaluationFixture::default_for_opening_id(opening_id)
+            .with_evaluation_period(0)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidEvaluationPeriod.into()
+            ));
+    });
+}
+
+#[test]
+fn vote_on_application_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (worker_id, opening_id, application_id, _) = add_evaluated_opening_with_applications();
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, application_id)
+            .with_score(30)
+            .call_and_assert(Ok(()));
+
+        let stake = <Test as Config>::MinimumApplicationStake::get();
+        assert_eq!(
+            TestWorkingGroup::application_tally(opening_id, application_id),
+            stake * 30
+        );
+        assert_eq!(
+            TestWorkingGroup::application_evaluation(opening_id)
+                .map(|evaluation| evaluation.evaluated_applications),
+            Some(1)
+        );
+
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationVoted(
+            worker_id,
+            application_id,
+            30,
+            stake,
+        ));
+    });
+}
+
+#[test]
+fn vote_on_application_replaces_previous_vote() {
+    build_test_externalities().execute_with(|| {
+        let (worker_id, opening_id, application_id, _) = add_evaluated_opening_with_applications();
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, application_id)
+            .with_score(30)
+            .call_and_assert(Ok(()));
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, application_id)
+            .with_score(10)
+            .call_and_assert(Ok(()));
+
+        let stake = <Test as Config>::MinimumApplicationStake::get();
+        assert_eq!(
+            TestWorkingGroup::application_tally(opening_id, application_id),
+            stake * 10
+        );
+        assert_eq!(
+            TestWorkingGroup::application_evaluation(opening_id)
+                .map(|evaluation| evaluation.evaluated_applications),
+            Some(1)
+        );
+        assert_eq!(
+            TestWorkingGroup::application_evaluation(opening_id).map(|evaluation| evaluation.votes),
+            Some(1)
+        );
+    });
+}
+
+#[test]
+fn vote_on_application_fails_with_exceeding_max_vote_number() {
+    build_test_externalities().execute_with(|| {
+        let (worker_id, opening_id, application_id, _) = add_evaluated_opening_with_applications();
+
+        <crate::ApplicationEvaluations<Test, DefaultInstance>>::mutate(opening_id, |evaluation| {
+            if let Some(evaluation) = evaluation {
+                evaluation.votes = MAX_APPLICATION_VOTES;
+            }
+        });
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, application_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::MaxApplicationVotesExceeded.into(),
+        ));
+    });
+}
+
+#[test]
+fn vote_on_application_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let (worker_id, _, application_id, _) = add_evaluated_opening_with_applications();
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, application_id)
+            .with_origin(RawOrigin::Signed(3))
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::SignerIsNotWorkerRoleAccount.into(),
+            ));
+    });
+}
+
+#[test]
+fn vote_on_application_fails_without_evaluation() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireRegularWorkerFixture::default().hire();
+
+        let opening_id = AddOpeningFixture::default().call().unwrap();
+
+        let application_id = ApplyOnOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(3), 3)
+            .call()
+            .unwrap();
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, application_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::ApplicationEvaluationDoesNotExist.into(),
+        ));
+    });
+}
+
+#[test]
+fn vote_on_application_fails_after_evaluation_window() {
+    build_test_externalities().execute_with(|| {
+        let (worker_id, _, application_id, _) = add_evaluated_opening_with_applications();
+
+        run_to_block(10);
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, application_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::ApplicationEvaluationEnded.into(),
+        ));
+    });
+}
+
+#[test]
+fn vote_on_application_fails_with_invalid_score() {
+    build_test_externalities().execute_with(|| {
+        let (worker_id, _, application_id, _) = add_evaluated_opening_with_applications();
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, application_id)
+            .with_score(MAX_APPLICATION_SCORE + 1)
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::InvalidApplicationScore.into()
+            ));
+    });
+}
+
+#[test]
+fn vote_on_application_fails_on_own_application() {
+    build_test_externalities().execute_with(|| {
+        let (worker_id, opening_id, _, _) = add_evaluated_opening_with_applications();
+
+        let own_application_id = ApplyOnOpeningFixture::default_for_opening_id(opening_id)
+            .with_stake_parameters(StakeParameters {
+                stake: <Test as Config>::MinimumApplicationStake::get(),
+                staking_account_id: 5,
+            })
+            .call()
+            .unwrap();
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, own_application_id).call_and_assert(
+            Err(Error::<Test, DefaultInstance>::CannotVoteOnOwnApplication.into()),
+        );
+    });
+}
+
+#[test]
+fn vote_on_application_fails_without_stake() {
+    build_test_externalities().execute_with(|| {
+        let (worker_id, _, application_id, _) = add_evaluated_opening_with_applications();
+
+        SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_penalty(<Test as Config>::MinimumApplicationStake::get())
+            .call_and_assert(Ok(()));
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, application_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::CannotVoteWithoutStake.into(),
+        ));
+    });
+}
+
+#[test]
+fn justify_evaluation_deviation_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (_, opening_id, _, _) = add_evaluated_opening_with_applications();
+
+        JustifyEvaluationDeviationFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::EvaluationDeviationJustified(
+            opening_id,
+            <Test as frame_system::Config>::Hashing::hash(b"rationale"),
+        ));
+    });
+}
+
+#[test]
+fn justify_evaluation_deviation_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let (_, opening_id, _, _) = add_evaluated_opening_with_applications();
+
+        JustifyEvaluationDeviationFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(3))
+            .call_and_assert(Err(Error::<Test, DefaultInstance>::IsNotLeadAccount.into()));
+    });
+}
+
+#[test]
+fn justify_evaluation_deviation_fails_without_evaluation() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let opening_id = AddOpeningFixture::default().call().unwrap();
+
+        JustifyEvaluationDeviationFixture::default_for_opening_id(opening_id).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::ApplicationEvaluationDoesNotExist.into(),
+        ));
+    });
+}
+
+#[test]
+fn fill_evaluated_opening_fails_during_evaluation() {
+    build_test_externalities().execute_with(|| {
+        let (_, opening_id, application_id, _) = add_evaluated_opening_with_applications();
+
+        FillOpeningFixture::default_for_ids(opening_id, vec![application_id]).call_and_assert(Err(
+            Error::<Test, DefaultInstance>::ApplicationEvaluationInProgress.into(),
+        ));
+    });
+}
+
+#[test]
+fn fill_evaluated_opening_following_tally_succeeded() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (worker_id, opening_id, first_application_id, second_application_id) =
+            add_evaluated_opening_with_applications();
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, first_application_id)
+            .with_score(80)
+            .call_and_assert(Ok(()));
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, second_application_id)
+            .with_score(20)
+            .call_and_assert(Ok(()));
+
+        run_to_block(11);
+
+        FillOpeningFixture::default_for_ids(opening_id, vec![first_application_id])
+            .call_and_assert(Ok(()));
+
+        let stake = <Test as Config>::MinimumApplicationStake::get();
+        EventFixture::contains_crate_event(RawEvent::ApplicationEvaluationTallied(
+            opening_id,
+            vec![
+                (first_application_id, stake * 80),
+                (second_application_id, stake * 20),
+            ],
+        ));
+        EventFixture::contains_crate_event(RawEvent::ApplicationEvaluationDecided(
+            opening_id,
+            vec![first_application_id]
+                .into_iter()
+                .collect::<BTreeSet<_>>(),
+            None,
+        ));
+
+        assert!(TestWorkingGroup::application_evaluation(opening_id).is_none());
+        assert!(
+            TestWorkingGroup::application_vote(opening_id, (first_application_id, worker_id))
+                .is_none()
+        );
+        assert_eq!(
+            TestWorkingGroup::application_tally(opening_id, second_application_id),
+            0
+        );
+    });
+}
+
+#[test]
+fn fill_evaluated_opening_against_tally_fails_without_rationale() {
+    build_test_externalities().execute_with(|| {
+        let (worker_id, opening_id, first_application_id, second_application_id) =
+            add_evaluated_opening_with_applications();
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, first_application_id)
+            .with_score(80)
+            .call_and_assert(Ok(()));
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, second_application_id)
+            .with_score(20)
+            .call_and_assert(Ok(()));
+
+        run_to_block(10);
+
+        FillOpeningFixture::default_for_ids(opening_id, vec![second_application_id])
+            .call_and_assert(Err(
+                Error::<Test, DefaultInstance>::EvaluationDeviationNotJustified.into(),
+            ));
+    });
+}
+
+#[test]
+fn fill_evaluated_opening_against_tally_succeeded_with_rationale() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let (worker_id, opening_id, first_application_id, second_application_id) =
+            add_evaluated_opening_with_applications();
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, first_application_id)
+            .with_score(80)
+            .call_and_assert(Ok(()));
+
+        JustifyEvaluationDeviationFixture::default_for_opening_id(opening_id)
+            .call_and_assert(Ok(()));
+
+        run_to_block(11);
+
+        FillOpeningFixture::default_for_ids(opening_id, vec![second_application_id])
+            .call_and_assert(Ok(()));
+
+        EventFixture::contains_crate_event(RawEvent::ApplicationEvaluationDecided(
+            opening_id,
+            vec![second_application_id]
+                .into_iter()
+                .collect::<BTreeSet<_>>(),
+            Some(<Test as frame_system::Config>::Hashing::hash(b"rationale")),
+        ));
+    });
+}
+
+#[test]
+fn cancel_evaluated_opening_removes_evaluation() {
+    build_test_externalities().execute_with(|| {
+        let (worker_id, opening_id, application_id, _) = add_evaluated_opening_with_applications();
+
+        VoteOnApplicationFixture::default_for_vote(worker_id, application_id)
+            .call_and_assert(Ok(()));
+
+        CancelOpeningFixture::default_for_opening_id(opening_id).call_and_assert(Ok(()));
+
+        assert!(TestWorkingGroup::application_evaluation(opening_id).is_none());
+        assert!(
+            TestWorkingGroup::application_vote(opening_id, (application_id, worker_id)).is_none()
+        );
+    });
+}
@@ -3,6 +3,7 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::traits::Currency;
 use scale_info::TypeInfo;
+use sp_arithmetic::Percent;
 use sp_std::vec::Vec;
 
 #[cfg(feature = "std")]
@@ -27,6 +28,9 @@ pub type ApplicationId = u64;
 /// Type for an opening id.
 pub type OpeningId = u64;
 
+/// Type for a worker review id, unique per worker.
+pub type ReviewId = u32;
+
 // ApplicationId - Application - helper struct.
 pub(crate) struct ApplicationInfo<T: crate::Config<I>, I: crate::Instance> {
     pub application_id: ApplicationId,
@@ -189,11 +193,29 @@ pub struct GroupWorker<AccountId, MemberId, BlockNumber, Balance> {
     /// Total missed reward amount.
     pub missed_reward: Option<Balance>,
 
+    /// Reward funded by the group budget and not claimed yet.
+    pub accrued_reward: Balance,
+
+    /// Group reward index at the last reward accrual.
+    pub reward_index: u128,
+
+    /// Specifies the block of the last reward accrual.
+    pub reward_accrued_at: BlockNumber,
+
     /// Specifies the block when the worker was created.
     pub created_at: BlockNumber,
+
+    /// Optional part of the reward paid through the vesting schedules.
+    pub reward_vesting: Option<RewardVestingSplit<BlockNumber>>,
+
+    /// Vested part of the paid reward withheld until it can be locked with a vesting schedule.
+    pub unvested_reward: Balance,
+
+    /// Specifies the block when the vesting schedule was last added for the reward (optional).
+    pub reward_vested_at: Option<BlockNumber>,
 }
 
-impl<AccountId: Clone, MemberId: Clone, BlockNumber, Balance>
+impl<AccountId: Clone, MemberId: Clone, BlockNumber: Clone, Balance: Default>
     GroupWorker<AccountId, MemberId, BlockNumber, Balance>
 {
     /// Creates a new _GroupWorker_ using parameters.
@@ -204,6 +226,7 @@ impl<AccountId: Clone, MemberId: Clone, BlockNumber, Balance>
         staking_account_id: &AccountId,
         job_unstaking_period: BlockNumber,
         reward_per_block: Option<Balance>,
+        reward_index: u128,
         created_at: BlockNumber,
     ) -> Self {
         GroupWorker {
@@ -215,7 +238,13 @@ impl<AccountId: Clone, MemberId: Clone, BlockNumber, Balance>
             job_unstaking_period,
             reward_per_block,
             missed_reward: None,
+            accrued_reward: Balance::default(),
+            reward_index,
+            reward_accrued_at: created_at.clone(),
             created_at,
+            reward_vesting: None,
+            unvested_reward: Balance::default(),
+            reward_vested_at: None,
         }
     }
 
@@ -225,6 +254,20 @@ impl<AccountId: Clone, MemberId: Clone, BlockNumber, Balance>
     }
 }
 
+/// Part of the worker reward paid through the vesting schedules.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Default, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct RewardVestingSplit<BlockNumber> {
+    /// Percentage of each reward payment that vests.
+    pub vested_percentage: Percent,
+
+    /// Number of blocks over which the vested part is unlocked.
+    pub vesting_period: BlockNumber,
+}
+
+/// RewardVestingSplit type alias.
+pub type RewardVestingSplitOf<T> = RewardVestingSplit<<T as frame_system::Config>::BlockNumber>;
+
 /// Stake policy for the job opening.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Debug, Clone, Default, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
@@ -288,3 +331,153 @@ pub enum RewardPaymentType {
     /// The reward was paid in time.
     RegularReward,
 }
+
+/// Performance review of the worker recorded by the group leader.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Default, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct WorkerReview<Hash, BlockNumber> {
+    /// Review score, up to `MAX_REVIEW_SCORE`.
+    pub score: u8,
+
+    /// Hash of the review rationale.
+    pub rationale_hash: Hash,
+
+    /// Block at which the review was recorded.
+    pub reviewed_at: BlockNumber,
+
+    /// Reward multiplier (in percent) derived from the score, if the review adjusted the reward.
+    pub reward_multiplier: Option<u16>,
+
+    /// Whether the review was overturned by the council.
+    pub overturned: bool,
+}
+
+/// WorkerReview type alias.
+pub type WorkerReviewOf<T> =
+    WorkerReview<<T as frame_system::Config>::Hash, <T as frame_system::Config>::BlockNumber>;
+
+/// Bounds of the worker reward multiplier derived from the review score.
+/// The lowest score maps to `min` and the highest score maps to `max`.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Default, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct RewardMultiplierBounds {
+    /// Minimal reward multiplier in percent.
+    pub min: u16,
+
+    /// Maximal reward multiplier in percent, up to `MAX_REWARD_MULTIPLIER`.
+    pub max: u16,
+}
+
+/// Budget spending allowed to the deputy within a period.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Default, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SpendingLimit<Balance, BlockNumber> {
+    /// Maximal amount the deputy can spend within the period.
+    pub amount: Balance,
+
+    /// Length of the spending period in blocks.
+    pub period: BlockNumber,
+}
+
+/// Stake slashing allowed to the deputy within a period.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Default, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SlashingLimit<Balance, BlockNumber> {
+    /// Maximal total penalty the deputy can slash within the period.
+    pub amount: Balance,
+
+    /// Length of the slashing period in blocks.
+    pub period: BlockNumber,
+}
+
+/// Subset of the leader permissions granted to the deputy.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Default, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct DeputyPermissions<Balance, BlockNumber> {
+    /// Whether the deputy can fill the regular worker openings.
+    pub fill_openings: bool,
+
+    /// Budget spending allowed to the deputy (optional). None means no spending.
+    pub spending_limit: Option<SpendingLimit<Balance, BlockNumber>>,
+
+    /// Stake slashing of the regular workers allowed to the deputy (optional).
+    /// None means no slashing.
+    pub slashing_limit: Option<SlashingLimit<Balance, BlockNumber>>,
+}
+
+/// DeputyPermissions type alias.
+pub type DeputyPermissionsOf<T> =
+    DeputyPermissions<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+/// Regular worker appointed by the leader to act on behalf of the leader.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Default, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Deputy<Balance, BlockNumber> {
+    /// Leader permissions granted to the deputy.
+    pub permissions: DeputyPermissions<Balance, BlockNumber>,
+
+    /// Amount spent by the deputy within the current spending period.
+    pub spent_in_period: Balance,
+
+    /// Block at which the current spending period started.
+    pub spending_period_start: BlockNumber,
+
+    /// Total penalty slashed by the deputy within the current slashing period.
+    pub slashed_in_period: Balance,
+
+    /// Block at which the current slashing period started.
+    pub slashing_period_start: BlockNumber,
+}
+
+/// Deputy type alias.
+pub type DeputyOf<T> = Deputy<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+// Leader operation performed by the deputy.
+pub(crate) enum DeputyAction<Balance> {
+    // Fill the regular worker opening.
+    FillOpening,
+
+    // Spend the amount from the group budget.
+    Spend(Balance),
+
+    // Slash the penalty from the regular worker stake.
+    Slash(Balance),
+}
+
+/// Evaluation window of the regular worker opening, in which the active workers of the group
+/// vote on the applications with their stake.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Default, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ApplicationEvaluation<BlockNumber, Hash> {
+    /// Block at which the evaluation window ends.
+    pub ends_at: BlockNumber,
+
+    /// Number of the applications with at least one vote.
+    pub evaluated_applications: u32,
+
+    /// Number of the worker votes on the applications, up to `MAX_APPLICATION_VOTES`.
+    pub votes: u32,
+
+    /// Hash of the rationale for filling the opening against the tally (optional).
+    pub deviation_rationale_hash: Option<Hash>,
+}
+
+/// ApplicationEvaluation type alias.
+pub type ApplicationEvaluationOf<T> = ApplicationEvaluation<
+    <T as frame_system::Config>::BlockNumber,
+    <T as frame_system::Config>::Hash,
+>;
+
+/// Vote of the worker on the application.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Default, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ApplicationVote<Balance> {
+    /// Vote score, up to `MAX_APPLICATION_SCORE`.
+    pub score: u8,
+
+    /// Worker stake at the moment of voting.
+    pub weight: Balance,
+}
+
+/// ApplicationVote type alias.
+pub type ApplicationVoteOf<T> = ApplicationVote<BalanceOf<T>>;
@@ -45,9 +45,6 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for working_group.
 pub trait WeightInfo {
 	fn on_initialize_leaving(_i: u32, ) -> Weight;
-	fn on_initialize_rewarding_with_missing_reward(_i: u32, ) -> Weight;
-	fn on_initialize_rewarding_with_missing_reward_cant_pay(_i: u32, ) -> Weight;
-	fn on_initialize_rewarding_without_missing_reward(_i: u32, ) -> Weight;
 	fn apply_on_opening(_i: u32, ) -> Weight;
 	fn fill_opening_lead() -> Weight;
 	fn fill_opening_worker(_i: u32, ) -> Weight;
@@ -70,101 +67,61 @@ pub trait WeightInfo {
 	fn leave_role(_i: u32, ) -> Weight;
 	fn lead_remark(_i: u32, ) -> Weight;
 	fn worker_remark(_i: u32, ) -> Weight;
+	fn claim_worker_reward() -> Weight;
+	fn set_reward_multiplier_bounds() -> Weight;
+	fn review_worker() -> Weight;
+	fn overturn_worker_review() -> Weight;
+	fn appoint_deputy() -> Weight;
+	fn revoke_deputy() -> Weight;
+	fn start_application_evaluation() -> Weight;
+	fn vote_on_application() -> Weight;
+	fn justify_evaluation_deviation() -> Weight;
+	fn conclude_application_evaluation(_i: u32, _v: u32, ) -> Weight;
+	fn set_worker_reward_vesting() -> Weight;
 }
 
 /// Weights for working_group using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Instance3WorkingGroup WorkerById (r:31 w:30)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:1)
 	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
 	// Proof: Instance3WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
-	// Storage: System Account (r:30 w:30)
+	// Storage: System Account (r:60 w:60)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup ActiveWorkerCount (r:1 w:1)
 	// Proof: Instance3WorkingGroup ActiveWorkerCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	// Storage: Balances Locks (r:30 w:30)
+	// Storage: Balances Locks (r:60 w:60)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RewardIndexUpdatedAt (r:1 w:1)
+	// Proof: Instance3WorkingGroup RewardIndexUpdatedAt (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup TotalRewardPerBlock (r:1 w:1)
+	// Proof: Instance3WorkingGroup TotalRewardPerBlock (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RewardIndex (r:1 w:1)
+	// Proof: Instance3WorkingGroup RewardIndex (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Vesting Vesting (r:30 w:30)
+	// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerReviewCount (r:0 w:30)
+	// Proof: Instance3WorkingGroup WorkerReviewCount (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerReviews (r:0 w:600)
+	// Proof: Instance3WorkingGroup WorkerReviews (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
 	/// The range of component `i` is `[2, 30]`.
 	fn on_initialize_leaving(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `260 + i * (421 ±0)`
-		//  Estimated: `10103 + i * (9027 ±0)`
-		// Minimum execution time: 118_090 nanoseconds.
-		Weight::from_parts(30_550_474, 0u64)
-			.saturating_add(Weight::from_parts(0, 10103))
-			// Standard Error: 52_495
-			.saturating_add(Weight::from_parts(43_956_355, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(i.into())))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(i.into())))
-			.saturating_add(Weight::from_parts(0, 9027).saturating_mul(i.into()))
-	}
-	// Storage: Instance3WorkingGroup WorkerById (r:31 w:30)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
-	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
-	// Proof: Instance3WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
-	// Storage: System Account (r:30 w:30)
-	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// The range of component `i` is `[2, 30]`.
-	fn on_initialize_rewarding_with_missing_reward(i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `246 + i * (310 ±0)`
-		//  Estimated: `6131 + i * (5253 ±0)`
-		// Minimum execution time: 101_704 nanoseconds.
-		Weight::from_parts(24_759_966, 0u64)
-			.saturating_add(Weight::from_parts(0, 6131))
-			// Standard Error: 40_561
-			.saturating_add(Weight::from_parts(39_437_648, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(i.into())))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
-			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(i.into())))
-			.saturating_add(Weight::from_parts(0, 5253).saturating_mul(i.into()))
-	}
-	// Storage: Instance3WorkingGroup WorkerById (r:31 w:30)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
-	// Storage: Instance3WorkingGroup Budget (r:1 w:0)
-	// Proof: Instance3WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
-	/// The range of component `i` is `[2, 30]`.
-	fn on_initialize_rewarding_with_missing_reward_cant_pay(i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `230 + i * (162 ±0)`
-		//  Estimated: `5141 + i * (2650 ±0)`
-		// Minimum execution time: 47_828 nanoseconds.
-		Weight::from_parts(24_667_305, 0u64)
-			.saturating_add(Weight::from_parts(0, 5141))
-			// Standard Error: 22_687
-			.saturating_add(Weight::from_parts(13_874_762, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(i.into())))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
-			.saturating_add(Weight::from_parts(0, 2650).saturating_mul(i.into()))
-	}
-	// Storage: Instance3WorkingGroup WorkerById (r:31 w:1)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
-	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
-	// Proof: Instance3WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
-	// Storage: System Account (r:30 w:30)
-	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
-	/// The range of component `i` is `[2, 30]`.
-	fn on_initialize_rewarding_without_missing_reward(i: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `262 + i * (294 ±0)`
-		//  Estimated: `6131 + i * (5253 ±0)`
-		// Minimum execution time: 85_157 nanoseconds.
-		Weight::from_parts(44_564_031, 0u64)
-			.saturating_add(Weight::from_parts(0, 6131))
-			// Standard Error: 24_114
-			.saturating_add(Weight::from_parts(21_808_803, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(i.into())))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
-			.saturating_add(Weight::from_parts(0, 5253).saturating_mul(i.into()))
+		//  Estimated: `11628 + i * (18936 ±0)`
+		// Minimum execution time: 171_322 nanoseconds.
+		Weight::from_parts(37_912_806, 0u64)
+			.saturating_add(Weight::from_parts(0, 11628))
+			// Standard Error: 61_073
+			.saturating_add(Weight::from_parts(70_148_920, 0u64).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((27_u64).saturating_mul(i.into())))
+			.saturating_add(Weight::from_parts(0, 18936).saturating_mul(i.into()))
 	}
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
@@ -204,7 +161,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Instance3WorkingGroup NextWorkerId (r:1 w:1)
 	// Proof: Instance3WorkingGroup NextWorkerId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup WorkerById (r:0 w:1)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
 	fn fill_opening_lead() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `389`
@@ -219,8 +176,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Proof: Instance3WorkingGroup OpeningById (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
 	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	// Storage: Instance3WorkingGroup WorkerById (r:1 w:29)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:6 w:29)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup Deputies (r:6 w:0)
+	// Proof: Instance3WorkingGroup Deputies (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup ActiveWorkerCount (r:1 w:1)
 	// Proof: Instance3WorkingGroup ActiveWorkerCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup ApplicationById (r:29 w:29)
@@ -241,14 +200,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 23684))
 			// Standard Error: 20_186
 			.saturating_add(Weight::from_parts(13_790_028, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(i.into())))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(i.into())))
 			.saturating_add(Weight::from_parts(0, 2378).saturating_mul(i.into()))
 	}
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:1)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
 	fn update_role_account() -> Weight {
@@ -266,7 +225,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
 	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -299,8 +258,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
 	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	// Storage: Instance3WorkingGroup WorkerById (r:2 w:0)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:7 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup Deputies (r:7 w:0)
+	// Proof: Instance3WorkingGroup Deputies (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -315,61 +276,85 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(0, 16140))
 			// Standard Error: 1_552
 			.saturating_add(Weight::from_parts(845_591, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
 	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup WorkerById (r:2 w:1)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
-	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:2 w:2)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RewardIndexUpdatedAt (r:1 w:1)
+	// Proof: Instance3WorkingGroup RewardIndexUpdatedAt (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup TotalRewardPerBlock (r:1 w:1)
+	// Proof: Instance3WorkingGroup TotalRewardPerBlock (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RewardIndex (r:1 w:1)
+	// Proof: Instance3WorkingGroup RewardIndex (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
 	// Proof: Instance3WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup ActiveWorkerCount (r:1 w:1)
 	// Proof: Instance3WorkingGroup ActiveWorkerCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerReviewCount (r:0 w:1)
+	// Proof: Instance3WorkingGroup WorkerReviewCount (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerReviews (r:0 w:20)
+	// Proof: Instance3WorkingGroup WorkerReviews (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
 	fn terminate_role_worker(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `836`
-		//  Estimated: `19130`
-		// Minimum execution time: 110_278 nanoseconds.
-		Weight::from_parts(115_467_173, 0u64)
-			.saturating_add(Weight::from_parts(0, 19130))
+		//  Estimated: `30564`
+		// Minimum execution time: 158_417 nanoseconds.
+		Weight::from_parts(163_902_544, 0u64)
+			.saturating_add(Weight::from_parts(0, 30564))
 			// Standard Error: 4_412
 			.saturating_add(Weight::from_parts(1_592_950, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(32_u64))
 	}
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:1)
 	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:1)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
-	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:2 w:2)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
-	// Storage: System Account (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RewardIndexUpdatedAt (r:1 w:1)
+	// Proof: Instance3WorkingGroup RewardIndexUpdatedAt (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup TotalRewardPerBlock (r:1 w:1)
+	// Proof: Instance3WorkingGroup TotalRewardPerBlock (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RewardIndex (r:1 w:1)
+	// Proof: Instance3WorkingGroup RewardIndex (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
 	// Proof: Instance3WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup ActiveWorkerCount (r:1 w:1)
 	// Proof: Instance3WorkingGroup ActiveWorkerCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerReviewCount (r:0 w:1)
+	// Proof: Instance3WorkingGroup WorkerReviewCount (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerReviews (r:0 w:1)
+	// Proof: Instance3WorkingGroup WorkerReviews (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
 	fn terminate_role_lead(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `691`
-		//  Estimated: `16480`
-		// Minimum execution time: 107_131 nanoseconds.
-		Weight::from_parts(110_987_521, 0u64)
-			.saturating_add(Weight::from_parts(0, 16480))
+		//  Estimated: `27914`
+		// Minimum execution time: 154_862 nanoseconds.
+		Weight::from_parts(159_330_718, 0u64)
+			.saturating_add(Weight::from_parts(0, 27914))
 			// Standard Error: 3_997
 			.saturating_add(Weight::from_parts(1_616_556, 0u64).saturating_mul(i.into()))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
 	}
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
@@ -387,7 +372,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
 	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup WorkerById (r:2 w:0)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -404,8 +389,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
 	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:6 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup Deputies (r:6 w:1)
+	// Proof: Instance3WorkingGroup Deputies (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
 	// Proof: Instance3WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -417,13 +404,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 34_553 nanoseconds.
 		Weight::from_parts(35_932_000, 0u64)
 			.saturating_add(Weight::from_parts(0, 10227))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
 	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
-	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:6 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup Deputies (r:6 w:1)
+	// Proof: Instance3WorkingGroup Deputies (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
 	// Proof: Instance3WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -439,8 +428,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 69_261 nanoseconds.
 		Weight::from_parts(70_732_000, 0u64)
 			.saturating_add(Weight::from_parts(0, 19513))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	// Storage: Membership MembershipById (r:1 w:0)
 	// Proof: Membership MembershipById (max_values: None, max_size: Some(125), added: 2600, mode: MaxEncodedLen)
@@ -461,7 +450,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
 	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup WorkerById (r:2 w:1)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
 	fn update_reward_amount() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `570`
@@ -475,7 +464,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
 	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup StatusTextHash (r:0 w:1)
 	// Proof: Instance3WorkingGroup StatusTextHash (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
@@ -492,7 +481,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:1)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
 	fn update_reward_account() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `388`
@@ -517,7 +506,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
 	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
 	// Storage: Balances Locks (r:1 w:1)
 	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	// Storage: System Account (r:1 w:1)
@@ -540,24 +529,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:1)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup LeavingWorkers (r:0 w:1)
+	// Proof: Instance3WorkingGroup LeavingWorkers (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
 	fn leave_role(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `388`
 		//  Estimated: `3640`
-		// Minimum execution time: 22_287 nanoseconds.
-		Weight::from_parts(23_664_039, 0u64)
+		// Minimum execution time: 25_104 nanoseconds.
+		Weight::from_parts(26_538_417, 0u64)
 			.saturating_add(Weight::from_parts(0, 3640))
 			// Standard Error: 953
 			.saturating_add(Weight::from_parts(833_809, 0u64).saturating_mul(i.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
 	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
 	fn lead_remark(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -571,7 +562,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 	}
 	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
-	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
 	/// The range of component `i` is `[0, 100]`.
 	fn worker_remark(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -584,6 +575,239 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(1_062_627, 0u64).saturating_mul(i.into()))
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:1)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RewardIndexUpdatedAt (r:1 w:1)
+	// Proof: Instance3WorkingGroup RewardIndexUpdatedAt (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup TotalRewardPerBlock (r:1 w:0)
+	// Proof: Instance3WorkingGroup TotalRewardPerBlock (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
+	// Proof: Instance3WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RewardIndex (r:1 w:1)
+	// Proof: Instance3WorkingGroup RewardIndex (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:1)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	fn claim_worker_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1018`
+		//  Estimated: `14631`
+		// Minimum execution time: 71_904 nanoseconds.
+		Weight::from_parts(73_462_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 14631))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ReviewRewardMultiplierBounds (r:0 w:1)
+	// Proof: Instance3WorkingGroup ReviewRewardMultiplierBounds (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
+	fn set_reward_multiplier_bounds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `369`
+		//  Estimated: `4179`
+		// Minimum execution time: 19_674 nanoseconds.
+		Weight::from_parts(20_412_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 4179))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:2 w:1)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ReviewRewardMultiplierBounds (r:1 w:0)
+	// Proof: Instance3WorkingGroup ReviewRewardMultiplierBounds (max_values: Some(1), max_size: Some(5), added: 500, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerReviewCount (r:1 w:1)
+	// Proof: Instance3WorkingGroup WorkerReviewCount (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RewardIndexUpdatedAt (r:1 w:1)
+	// Proof: Instance3WorkingGroup RewardIndexUpdatedAt (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup TotalRewardPerBlock (r:1 w:1)
+	// Proof: Instance3WorkingGroup TotalRewardPerBlock (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
+	// Proof: Instance3WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RewardIndex (r:1 w:1)
+	// Proof: Instance3WorkingGroup RewardIndex (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerRewardMultiplier (r:1 w:1)
+	// Proof: Instance3WorkingGroup WorkerRewardMultiplier (max_values: None, max_size: Some(27), added: 2502, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerReviews (r:0 w:2)
+	// Proof: Instance3WorkingGroup WorkerReviews (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	fn review_worker() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `782`
+		//  Estimated: `13087`
+		// Minimum execution time: 43_519 nanoseconds.
+		Weight::from_parts(44_871_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 13087))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+	}
+	// Storage: Instance3WorkingGroup WorkerReviews (r:20 w:1)
+	// Proof: Instance3WorkingGroup WorkerReviews (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerReviewCount (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerReviewCount (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:1)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RewardIndexUpdatedAt (r:1 w:1)
+	// Proof: Instance3WorkingGroup RewardIndexUpdatedAt (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup TotalRewardPerBlock (r:1 w:1)
+	// Proof: Instance3WorkingGroup TotalRewardPerBlock (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup Budget (r:1 w:1)
+	// Proof: Instance3WorkingGroup Budget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup RewardIndex (r:1 w:1)
+	// Proof: Instance3WorkingGroup RewardIndex (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerRewardMultiplier (r:1 w:1)
+	// Proof: Instance3WorkingGroup WorkerRewardMultiplier (max_values: None, max_size: Some(27), added: 2502, mode: MaxEncodedLen)
+	// TODO: adjust weight, the DB reads of the kept reviews are added without rerunning the benchmark.
+	fn overturn_worker_review() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `671`
+		//  Estimated: `61007`
+		// Minimum execution time: 38_106 nanoseconds.
+		Weight::from_parts(39_254_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 61007))
+			.saturating_add(T::DbWeight::get().reads(27_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:2 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup Deputies (r:1 w:1)
+	// Proof: Instance3WorkingGroup Deputies (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup DeputyCount (r:1 w:1)
+	// Proof: Instance3WorkingGroup DeputyCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn appoint_deputy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `542`
+		//  Estimated: `8883`
+		// Minimum execution time: 27_831 nanoseconds.
+		Weight::from_parts(28_796_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 8883))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup Deputies (r:1 w:1)
+	// Proof: Instance3WorkingGroup Deputies (max_values: None, max_size: Some(83), added: 2558, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup DeputyCount (r:1 w:1)
+	// Proof: Instance3WorkingGroup DeputyCount (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn revoke_deputy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `6246`
+		// Minimum execution time: 23_507 nanoseconds.
+		Weight::from_parts(24_388_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 6246))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup OpeningById (r:1 w:0)
+	// Proof: Instance3WorkingGroup OpeningById (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEvaluations (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationEvaluations (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	fn start_application_evaluation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `9308`
+		// Minimum execution time: 24_906 nanoseconds.
+		Weight::from_parts(25_713_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 9308))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationById (r:1 w:0)
+	// Proof: Instance3WorkingGroup ApplicationById (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEvaluations (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationEvaluations (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	// Storage: Balances Locks (r:1 w:0)
+	// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationTallies (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationTallies (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationVotes (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationVotes (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	fn vote_on_application() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1094`
+		//  Estimated: `16738`
+		// Minimum execution time: 38_412 nanoseconds.
+		Weight::from_parts(39_527_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 16738))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:1 w:0)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationEvaluations (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationEvaluations (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	fn justify_evaluation_deviation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `501`
+		//  Estimated: `6719`
+		// Minimum execution time: 21_374 nanoseconds.
+		Weight::from_parts(22_095_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 6719))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: Instance3WorkingGroup ApplicationEvaluations (r:1 w:1)
+	// Proof: Instance3WorkingGroup ApplicationEvaluations (max_values: None, max_size: Some(69), added: 2544, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationTallies (r:51 w:50)
+	// Proof: Instance3WorkingGroup ApplicationTallies (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationById (r:50 w:0)
+	// Proof: Instance3WorkingGroup ApplicationById (max_values: None, max_size: Some(168), added: 2643, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup ApplicationVotes (r:0 w:500)
+	// Proof: Instance3WorkingGroup ApplicationVotes (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// The range of component `i` is `[1, 50]`.
+	/// The range of component `v` is `[50, 500]`.
+	fn conclude_application_evaluation(i: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `426 + i * (181 ±0)`
+		//  Estimated: `5506 + i * (5182 ±0)`
+		// Minimum execution time: 178_904 nanoseconds.
+		Weight::from_parts(19_655_187, 0u64)
+			.saturating_add(Weight::from_parts(0, 5506))
+			// Standard Error: 85_460
+			.saturating_add(Weight::from_parts(3_953_820, 0u64).saturating_mul(i.into()))
+			// Standard Error: 9_147
+			.saturating_add(Weight::from_parts(918_472, 0u64).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 5182).saturating_mul(i.into()))
+	}
+	// Storage: Instance3WorkingGroup CurrentLead (r:1 w:0)
+	// Proof: Instance3WorkingGroup CurrentLead (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	// Storage: Instance3WorkingGroup WorkerById (r:2 w:1)
+	// Proof: Instance3WorkingGroup WorkerById (max_values: None, max_size: Some(238), added: 2713, mode: MaxEncodedLen)
+	fn set_worker_reward_vesting() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `450`
+		//  Estimated: `6882`
+		// Minimum execution time: 21_387 nanoseconds.
+		Weight::from_parts(22_134_000, 0u64)
+			.saturating_add(Weight::from_parts(0, 6882))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // Default implementation for tests
@@ -591,15 +815,6 @@ impl WeightInfo for () {
 	fn on_initialize_leaving(i: u32, ) -> Weight {
 		Weight::from_parts(0, 0)
 	}
-	fn on_initialize_rewarding_with_missing_reward(i: u32, ) -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn on_initialize_rewarding_with_missing_reward_cant_pay(i: u32, ) -> Weight {
-		Weight::from_parts(0, 0)
-	}
-	fn on_initialize_rewarding_without_missing_reward(i: u32, ) -> Weight {
-		Weight::from_parts(0, 0)
-	}
 	fn apply_on_opening(i: u32, ) -> Weight {
 		Weight::from_parts(0, 0)
 	}
@@ -666,4 +881,37 @@ impl WeightInfo for () {
 	fn worker_remark(i: u32, ) -> Weight {
 		Weight::from_parts(0, 0)
 	}
+	fn claim_worker_reward() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn set_reward_multiplier_bounds() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn review_worker() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn overturn_worker_review() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn appoint_deputy() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn revoke_deputy() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn start_application_evaluation() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn vote_on_application() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn justify_evaluation_deviation() -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn conclude_application_evaluation(i: u32, v: u32, ) -> Weight {
+		Weight::from_parts(0, 0)
+	}
+	fn set_worker_reward_vesting() -> Weight {
+		Weight::from_parts(0, 0)
+	}
 }
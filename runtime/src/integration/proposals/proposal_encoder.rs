@@ -185,6 +185,29 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
             ProposalDetails::DecreaseCouncilBudget(reduction_amount) => {
                 RuntimeCall::Council(council::Call::decrease_council_budget { reduction_amount })
             }
+            ProposalDetails::OverturnContentTakedown(takedown_request_id) => {
+                RuntimeCall::Content(content::Call::overturn_takedown {
+                    takedown_request_id,
+                })
+            }
+            ProposalDetails::DisputeWorkerReview(worker_id, review_id, working_group) => {
+                wrap_working_group_call!(
+                    working_group,
+                    Wg::overturn_worker_review_call(worker_id, review_id)
+                )
+            }
+            ProposalDetails::RevokeWorkingGroupDeputy(worker_id, working_group) => {
+                wrap_working_group_call!(working_group, Wg::revoke_deputy_call(worker_id))
+            }
+            ProposalDetails::ResolveBountyJudgmentDispute(
+                bounty_id,
+                overturn_judgment,
+                corrected_judgment,
+            ) => RuntimeCall::Bounty(bounty::Call::resolve_oracle_judgment_dispute {
+                bounty_id,
+                overturn_judgment,
+                corrected_judgment,
+            }),
         };
 
         call.encode()
@@ -283,4 +306,20 @@ where
     ) -> working_group::Call<T, I> {
         working_group::Call::<T, I>::cancel_opening { opening_id }
     }
+
+    // Generic call constructor for the working group 'overturn worker review'.
+    fn overturn_worker_review_call(
+        worker_id: working_group::WorkerId<T>,
+        review_id: working_group::ReviewId,
+    ) -> working_group::Call<T, I> {
+        working_group::Call::<T, I>::overturn_worker_review {
+            worker_id,
+            review_id,
+        }
+    }
+
+    // Generic call constructor for the working group 'revoke deputy'.
+    fn revoke_deputy_call(worker_id: working_group::WorkerId<T>) -> working_group::Call<T, I> {
+        working_group::Call::<T, I>::revoke_deputy { worker_id }
+    }
 }
@@ -872,6 +872,12 @@ parameter_types! {
     pub const MaxNumberOfAssetsPerChannel: MaxNumber = 10;
     pub const MaxNumberOfAssetsPerVideo: MaxNumber = 20;
     pub const MaxNumberOfCollaboratorsPerChannel: MaxNumber = 10;
+
+    // Content takedowns
+    pub const TakedownRequestBond: Balance = dollars!(50);
+    pub const TakedownRequestDecisionPeriod: BlockNumber = days!(3);
+    pub const TakedownAppealPeriod: BlockNumber = days!(7);
+    pub const TakedownAppealDecisionPeriod: BlockNumber = days!(14);
 }
 
 impl content::Config for Runtime {
@@ -899,6 +905,10 @@ impl content::Config for Runtime {
     type MinimumCashoutAllowedLimit = MinimumCashoutAllowedLimit;
     type MaximumCashoutAllowedLimit = MaximumCashoutAllowedLimit;
     type MaxNftAuctionWhitelistLength = MaxNftAuctionWhitelistLength;
+    type TakedownRequestBond = TakedownRequestBond;
+    type TakedownRequestDecisionPeriod = TakedownRequestDecisionPeriod;
+    type TakedownAppealPeriod = TakedownAppealPeriod;
+    type TakedownAppealDecisionPeriod = TakedownAppealDecisionPeriod;
 }
 
 parameter_types! {
@@ -906,6 +916,14 @@ parameter_types! {
     pub const MaxVestingSchedulesPerAccountPerToken: u32 = 5;
     pub const BlocksPerYear: u32 = 5259600; // 365,25 * 24 * 60 * 60 / 6
     pub const MaxOutputs: u32 = 24; // set according to https://github.com/Joystream/joystream/issues/4947#issuecomment-1778893817
+    pub const MaxPollOptions: u32 = 16;
+    pub const MaxBatchAuctionBids: u32 = 100; // bounds batch auction settlement weight
+    pub const RevenueStakingUnstakingPeriod: BlockNumber = days!(7);
+    pub const MaxOrdersPerBookSide: u32 = 100;
+    pub const MaxOrderMatches: u32 = 10; // bounds limit order matching weight
+    pub const MaxAllowancesPerAccount: u32 = 10; // bounds dust_account weight
+    pub const MaxAirdropClaimsRemovedPerCall: u32 = 100; // bounds finalize_airdrop weight
+    pub const MaxPollVotesRemovedPerCall: u32 = 100; // bounds poll finalization weight
     // Account bloat bond related:
     pub ProjectTokenAccountCleanupTxFee: Balance = compute_fee(
         RuntimeCall::ProjectToken(project_token::Call::<Runtime>::dust_account {
@@ -934,12 +952,23 @@ impl project_token::Config for Runtime {
     type MemberOriginValidator = Members;
     type MembershipInfoProvider = Members;
     type MaxOutputs = MaxOutputs;
+    type MaxPollOptions = MaxPollOptions;
+    type MaxBatchAuctionBids = MaxBatchAuctionBids;
+    type RevenueStakingUnstakingPeriod = RevenueStakingUnstakingPeriod;
+    type MaxOrdersPerBookSide = MaxOrdersPerBookSide;
+    type MaxOrderMatches = MaxOrderMatches;
+    type MaxAllowancesPerAccount = MaxAllowancesPerAccount;
+    type MaxAirdropClaimsRemovedPerCall = MaxAirdropClaimsRemovedPerCall;
+    type MaxPollVotesRemovedPerCall = MaxPollVotesRemovedPerCall;
     type WeightInfo = project_token::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
     pub const MaxPauserAccounts: u32 = 10;
     pub const DefaultBridgingFee: Balance = dollars!(1);
+    pub const OutboundTransferRetentionPeriod: BlockNumber = days!(7);
+    pub const MaxPrunedTransfersPerBlock: u32 = 50;
+    pub const InboundTransfersWindow: u64 = 10_000; // tolerated gap in remote transfer ids
 }
 
 impl argo_bridge::Config for Runtime {
@@ -947,6 +976,10 @@ impl argo_bridge::Config for Runtime {
     type MaxPauserAccounts = MaxPauserAccounts;
     type WeightInfo = argo_bridge::weights::SubstrateWeight<Runtime>;
     type DefaultBridgingFee = DefaultBridgingFee;
+    type CreatorTokens = ProjectToken;
+    type OutboundTransferRetentionPeriod = OutboundTransferRetentionPeriod;
+    type MaxPrunedTransfersPerBlock = MaxPrunedTransfersPerBlock;
+    type InboundTransfersWindow = InboundTransfersWindow;
 }
 
 // The referendum instance alias.
@@ -1139,6 +1172,10 @@ parameter_types! {
     pub const MaxDistributionBucketsPerBag: u32 = 51;
     pub const MaxDataObjectSize: u64 = giga_bytes!(60);
     pub const MaxNumberOfOperatorsPerDistributionBucket: u32 = 20;
+    pub const BandwidthReportDisputePeriod: BlockNumber = days!(3);
+    pub const BandwidthReportPeriod: BlockNumber = days!(1);
+    pub const MaxBytesServedPerBandwidthReport: u64 = giga_bytes!(100_000);
+    pub const BandwidthReportBond: Balance = dollars!(1);
 
     // Data object bloat bond related:
     // To calculate the cost of removing a data object we substract the cost of removing a video
@@ -1173,6 +1210,7 @@ parameter_types! {
 parameter_types! {
     pub const MinStorageBucketsPerBag: u32 = 3;
     pub const MaxStorageBucketsPerBag: u32 = 13;
+    pub const MaxStorageBucketsScannedOnAssignment: u32 = 100;
     pub const DefaultMemberDynamicBagNumberOfStorageBuckets: u32 = 5;
     pub const DefaultChannelDynamicBagNumberOfStorageBuckets: u32 = 5;
 }
@@ -1182,6 +1220,7 @@ parameter_types! {
 parameter_types! {
     pub const MinStorageBucketsPerBag: u32 = 1;
     pub const MaxStorageBucketsPerBag: u32 = 13;
+    pub const MaxStorageBucketsScannedOnAssignment: u32 = 100;
     pub const DefaultMemberDynamicBagNumberOfStorageBuckets: u32 = 1;
     pub const DefaultChannelDynamicBagNumberOfStorageBuckets: u32 = 1;
 }
@@ -1189,6 +1228,7 @@ parameter_types! {
 // Assertions
 const_assert!(MinStorageBucketsPerBag::get() > 0);
 const_assert!(MaxStorageBucketsPerBag::get() >= MinStorageBucketsPerBag::get());
+const_assert!(MaxStorageBucketsScannedOnAssignment::get() >= 2 * MaxStorageBucketsPerBag::get());
 const_assert!(MinDistributionBucketsPerBag::get() > 0);
 const_assert!(MaxDistributionBucketsPerBag::get() >= MinDistributionBucketsPerBag::get());
 
@@ -1203,6 +1243,7 @@ impl storage::Config for Runtime {
     type ModuleId = StorageModuleId;
     type MinStorageBucketsPerBag = MinStorageBucketsPerBag;
     type MaxStorageBucketsPerBag = MaxStorageBucketsPerBag;
+    type MaxStorageBucketsScannedOnAssignment = MaxStorageBucketsScannedOnAssignment;
     type MinDistributionBucketsPerBag = MinDistributionBucketsPerBag;
     type MaxDistributionBucketsPerBag = MaxDistributionBucketsPerBag;
     type DefaultMemberDynamicBagNumberOfStorageBuckets =
@@ -1220,6 +1261,10 @@ impl storage::Config for Runtime {
     type StorageWorkingGroup = StorageWorkingGroup;
     type DistributionWorkingGroup = DistributionWorkingGroup;
     type ModuleAccountInitialBalance = ExistentialDeposit;
+    type BandwidthReportDisputePeriod = BandwidthReportDisputePeriod;
+    type BandwidthReportPeriod = BandwidthReportPeriod;
+    type MaxBytesServedPerBandwidthReport = MaxBytesServedPerBandwidthReport;
+    type BandwidthReportBond = BandwidthReportBond;
 }
 
 impl common::membership::MembershipTypes for Runtime {
@@ -1364,16 +1409,6 @@ parameter_types! {
     pub const DistributionMaxWorkerNumberLimit: u32 = 50;
 
     pub const MinUnstakingPeriodLimit: u32 = days!(20);
-    // FIXME: Periods should be the same, but rewards should start at different blocks
-    pub const ForumWorkingGroupRewardPeriod: u32 = days!(1) + 10;
-    pub const StorageWorkingGroupRewardPeriod: u32 = days!(1) + 20;
-    pub const ContentWorkingGroupRewardPeriod: u32 = days!(1) + 30;
-    pub const MembershipRewardPeriod: u32 = days!(1) + 40;
-    pub const AppRewardPeriod: u32 = days!(1) + 50;
-    pub const OperationsAlphaRewardPeriod: u32 = days!(1) + 60;
-    pub const OperationsBetaRewardPeriod: u32 = days!(1) + 70;
-    pub const OperationsGammaRewardPeriod: u32 = days!(1) + 80;
-    pub const DistributionRewardPeriod: u32 = days!(1) + 90;
     // This should be more costly than `apply_on_opening` fee
     pub const MinimumApplicationStake: Balance = dollars!(20);
     // This should be more costly than `add_opening` fee
@@ -1447,7 +1482,6 @@ impl working_group::Config<ForumWorkingGroupInstance> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = ForumWorkingGroupRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -1461,7 +1495,6 @@ impl working_group::Config<StorageWorkingGroupInstance> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = StorageWorkingGroupRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -1475,7 +1508,6 @@ impl working_group::Config<ContentWorkingGroupInstance> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = ContentWorkingGroupRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -1489,7 +1521,6 @@ impl working_group::Config<MembershipWorkingGroupInstance> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = MembershipRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -1503,7 +1534,6 @@ impl working_group::Config<OperationsWorkingGroupInstanceAlpha> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = OperationsAlphaRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -1517,7 +1547,6 @@ impl working_group::Config<AppWorkingGroupInstance> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = AppRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -1531,7 +1560,6 @@ impl working_group::Config<OperationsWorkingGroupInstanceBeta> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = OperationsBetaRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -1545,7 +1573,6 @@ impl working_group::Config<OperationsWorkingGroupInstanceGamma> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = OperationsGammaRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -1559,7 +1586,6 @@ impl working_group::Config<DistributionWorkingGroupInstance> for Runtime {
     type StakingAccountValidator = Members;
     type MemberOriginValidator = Members;
     type MinUnstakingPeriodLimit = MinUnstakingPeriodLimit;
-    type RewardPeriod = DistributionRewardPeriod;
     type WeightInfo = working_group::weights::SubstrateWeight<Runtime>;
     type MinimumApplicationStake = MinimumApplicationStake;
     type LeaderOpeningStake = LeaderOpeningStake;
@@ -1727,6 +1753,11 @@ impl proposals_codex::Config for Runtime {
     type UpdateGlobalNftLimitProposalParameters = UpdateGlobalNftLimitProposalParameters;
     type UpdateChannelPayoutsProposalParameters = UpdateChannelPayoutsProposalParameters;
     type DecreaseCouncilBudgetProposalParameters = DecreaseCouncilBudgetProposalParameters;
+    type OverturnContentTakedownProposalParameters = OverturnContentTakedownProposalParameters;
+    type DisputeWorkerReviewProposalParameters = DisputeWorkerReviewProposalParameters;
+    type RevokeWorkingGroupDeputyProposalParameters = RevokeWorkingGroupDeputyProposalParameters;
+    type ResolveBountyJudgmentDisputeProposalParameters =
+        ResolveBountyJudgmentDisputeProposalParameters;
     type FundingRequestProposalMaxTotalAmount = FundingRequestProposalMaxTotalAmount;
     type FundingRequestProposalMaxAccounts = FundingRequestProposalMaxAccounts;
     type SetMaxValidatorCountProposalMaxValidators = SetMaxValidatorCountProposalMaxValidators;
@@ -1745,6 +1776,9 @@ impl pallet_constitution::Config for Runtime {
 parameter_types! {
     pub const BountyModuleId: PalletId = PalletId(*b"m:bounty"); // module : bounty
     pub const ClosedContractSizeLimit: u32 = 50;
+    pub const MaxWorkMilestones: u32 = 10;
+    pub const JudgmentDisputeBond: Balance = dollars!(50);
+    pub const JudgmentDisputeDecisionPeriod: BlockNumber = days!(7);
 
     // Bounty work entry stake related:
     pub BountyWorkEntryCleanupTxFee: Balance = compute_fee(
@@ -1803,6 +1837,9 @@ impl bounty::Config for Runtime {
     type MinWorkEntrantStake = MinWorkEntrantStake;
     type FunderStateBloatBondAmount = FunderStateBloatBondAmount;
     type CreatorStateBloatBondAmount = CreatorStateBloatBondAmount;
+    type MaxWorkMilestones = MaxWorkMilestones;
+    type JudgmentDisputeBond = JudgmentDisputeBond;
+    type JudgmentDisputeDecisionPeriod = JudgmentDisputeDecisionPeriod;
 }
 
 parameter_types! {
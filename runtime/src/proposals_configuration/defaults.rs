@@ -413,3 +413,60 @@ pub(crate) fn decrease_council_budget() -> ProposalParameters<BlockNumber, Balan
         constitutionality: 1,
     }
 }
+
+// Proposal parameters for the 'Overturn Content Takedown' proposal
+pub(crate) fn overturn_content_takedown_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: days!(2),
+        grace_period: hours!(2),
+        approval_quorum_percentage: TWO_OUT_OF_THREE,
+        approval_threshold_percentage: TWO_OUT_OF_THREE,
+        slashing_quorum_percentage: ALL,
+        slashing_threshold_percentage: ALL,
+        required_stake: Some(dollars!(100)),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Dispute Worker Review' proposal
+pub(crate) fn dispute_worker_review_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: days!(2),
+        grace_period: hours!(2),
+        approval_quorum_percentage: TWO_OUT_OF_THREE,
+        approval_threshold_percentage: TWO_OUT_OF_THREE,
+        slashing_quorum_percentage: ALL,
+        slashing_threshold_percentage: ALL,
+        required_stake: Some(dollars!(50)),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Revoke Working Group Deputy' proposal
+pub(crate) fn revoke_working_group_deputy_proposal() -> ProposalParameters<BlockNumber, Balance> {
+    ProposalParameters {
+        voting_period: days!(2),
+        grace_period: hours!(2),
+        approval_quorum_percentage: TWO_OUT_OF_THREE,
+        approval_threshold_percentage: TWO_OUT_OF_THREE,
+        slashing_quorum_percentage: ALL,
+        slashing_threshold_percentage: ALL,
+        required_stake: Some(dollars!(50)),
+        constitutionality: 1,
+    }
+}
+
+// Proposal parameters for the 'Resolve Bounty Judgment Dispute' proposal
+pub(crate) fn resolve_bounty_judgment_dispute_proposal() -> ProposalParameters<BlockNumber, Balance>
+{
+    ProposalParameters {
+        voting_period: days!(2),
+        grace_period: hours!(2),
+        approval_quorum_percentage: TWO_OUT_OF_THREE,
+        approval_threshold_percentage: TWO_OUT_OF_THREE,
+        slashing_quorum_percentage: ALL,
+        slashing_threshold_percentage: ALL,
+        required_stake: Some(dollars!(100)),
+        constitutionality: 1,
+    }
+}
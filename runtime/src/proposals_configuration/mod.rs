@@ -121,4 +121,16 @@ parameter_types! {
 
     pub DecreaseCouncilBudgetProposalParameters: ProposalParameters<BlockNumber, Balance> =
         decrease_council_budget();
+
+    pub OverturnContentTakedownProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        overturn_content_takedown_proposal();
+
+    pub DisputeWorkerReviewProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        dispute_worker_review_proposal();
+
+    pub RevokeWorkingGroupDeputyProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        revoke_working_group_deputy_proposal();
+
+    pub ResolveBountyJudgmentDisputeProposalParameters: ProposalParameters<BlockNumber, Balance> =
+        resolve_bounty_judgment_dispute_proposal();
 }
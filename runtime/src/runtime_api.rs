@@ -12,10 +12,14 @@ use sp_runtime::{generic, ApplyExtrinsicResult};
 use sp_std::vec::Vec;
 
 use crate::{
-    AccountId, AllPalletsWithSystem, AuthorityDiscovery, AuthorityDiscoveryId, Babe, Balance,
-    BlockNumber, EpochDuration, Grandpa, GrandpaAuthorityList, GrandpaId, Historical, Index,
-    InherentDataExt, ProposalsEngine, Runtime, RuntimeCall, RuntimeVersion, SessionKeys, Signature,
-    Staking, System, TransactionPayment, BABE_GENESIS_EPOCH_CONFIG, VERSION,
+    AccountId, AllPalletsWithSystem, AppWorkingGroupInstance, AuthorityDiscovery,
+    AuthorityDiscoveryId, Babe, Balance, BlockNumber, ContentWorkingGroupInstance,
+    DistributionWorkingGroupInstance, EpochDuration, ForumWorkingGroupInstance, Grandpa,
+    GrandpaAuthorityList, GrandpaId, Historical, Index, InherentDataExt,
+    MembershipWorkingGroupInstance, OperationsWorkingGroupInstanceAlpha,
+    OperationsWorkingGroupInstanceBeta, OperationsWorkingGroupInstanceGamma, ProposalsEngine,
+    Runtime, RuntimeCall, RuntimeVersion, SessionKeys, Signature, Staking, Storage,
+    StorageWorkingGroupInstance, System, TransactionPayment, BABE_GENESIS_EPOCH_CONFIG, VERSION,
 };
 
 #[cfg(feature = "try-runtime")]
@@ -78,7 +82,21 @@ impl OnRuntimeUpgrade for CancelActiveAndPendingProposals {
 /// Migrations to run on runtime upgrade.
 /// Migrations will run before pallet on_runtime_upgrade hooks
 /// Always include 'CancelActiveAndPendingProposals' as first migration
-pub type Migrations = (CancelActiveAndPendingProposals,);
+pub type Migrations = (
+    CancelActiveAndPendingProposals,
+    project_token::migrations::v1::MigrateToV1<Runtime>,
+    bounty::migrations::v1::MigrateToV1<Runtime>,
+    storage::migrations::v1::MigrateToV1<Runtime>,
+    working_group::migrations::v1::MigrateToV1<Runtime, ForumWorkingGroupInstance>,
+    working_group::migrations::v1::MigrateToV1<Runtime, StorageWorkingGroupInstance>,
+    working_group::migrations::v1::MigrateToV1<Runtime, ContentWorkingGroupInstance>,
+    working_group::migrations::v1::MigrateToV1<Runtime, OperationsWorkingGroupInstanceAlpha>,
+    working_group::migrations::v1::MigrateToV1<Runtime, AppWorkingGroupInstance>,
+    working_group::migrations::v1::MigrateToV1<Runtime, MembershipWorkingGroupInstance>,
+    working_group::migrations::v1::MigrateToV1<Runtime, OperationsWorkingGroupInstanceBeta>,
+    working_group::migrations::v1::MigrateToV1<Runtime, OperationsWorkingGroupInstanceGamma>,
+    working_group::migrations::v1::MigrateToV1<Runtime, DistributionWorkingGroupInstance>,
+);
 
 /// Executive: handles dispatch to the various modules with Migrations.
 pub type Executive = frame_executive::Executive<
@@ -312,6 +330,19 @@ impl_runtime_apis! {
         }
     }
 
+    impl storage::runtime_api::StorageApi<
+        Block,
+        storage::BagId<Runtime>,
+        storage::DistributionRoute<Runtime>,
+    > for Runtime {
+        fn distribution_route(
+            bag_id: storage::BagId<Runtime>,
+            client_region: Vec<u8>,
+        ) -> Vec<storage::DistributionRoute<Runtime>> {
+            Storage::distribution_route(&bag_id, &client_region)
+        }
+    }
+
     impl sp_session::SessionKeys<Block> for Runtime {
         fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
             SessionKeys::generate(seed)